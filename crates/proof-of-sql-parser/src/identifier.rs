@@ -4,6 +4,10 @@ use arrayvec::ArrayString;
 use core::{cmp::Ordering, fmt, ops::Deref, str::FromStr};
 use sqlparser::ast::Ident;
 
+/// The separator between the name of a struct column and the name of one of its fields
+/// in the name of the leaf column the field is flattened to
+pub const NESTED_FIELD_SEPARATOR: &str = "__";
+
 /// Top-level unique identifier.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Ord, PartialOrd, Copy)]
 pub struct Identifier {
@@ -50,6 +54,25 @@ impl Identifier {
     pub fn as_str(&self) -> &str {
         self.name()
     }
+
+    /// The name of the leaf column that the field `field` of the struct column `parent` is flattened to.
+    ///
+    /// A struct column is committed as one leaf column per field, named by joining the name of the struct column
+    /// and the name of the field with [`NESTED_FIELD_SEPARATOR`]. Queries refer to the field as `parent.field`.
+    ///
+    /// # Errors
+    /// Returns an error if the name of the leaf column is longer than 64 bytes.
+    pub fn try_new_nested(parent: Identifier, field: Identifier) -> ParseResult<Self> {
+        let name = format!("{parent}{NESTED_FIELD_SEPARATOR}{field}");
+        if name.len() > 64 {
+            return Err(ParseError::IdentifierParseError {
+                error: format!(
+                    "the leaf column name {name} of a nested field is longer than 64 bytes"
+                ),
+            });
+        }
+        Ok(Identifier::new(name))
+    }
 }
 
 impl FromStr for Identifier {
@@ -195,15 +218,26 @@ mod tests {
             "by",
             "limit",
             "offset",
+            "fetch",
+            "only",
+            "top",
             "group",
             "min",
             "max",
             "count",
             "sum",
+            "distinct",
             "true",
             "false",
             "timestamp",
             "to_timestamp",
+            "row_number",
+            "over",
+            "lag",
+            "lead",
+            "date_trunc",
+            "filter",
+            "approx_count_distinct",
         ];
 
         for keyword in &keywords {
@@ -259,6 +293,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_name_the_leaf_column_of_a_nested_field() {
+        assert_eq!(
+            Identifier::try_new_nested(Identifier::new("address"), Identifier::new("zip")).unwrap(),
+            Identifier::new("address__zip")
+        );
+        assert!(matches!(
+            Identifier::try_new_nested(
+                Identifier::new("a".repeat(31)),
+                Identifier::new("b".repeat(32))
+            ),
+            Err(ParseError::IdentifierParseError { .. })
+        ));
+        assert_eq!(
+            Identifier::try_new_nested(
+                Identifier::new("a".repeat(31)),
+                Identifier::new("b".repeat(31))
+            )
+            .unwrap()
+            .len(),
+            64
+        );
+    }
+
     #[test]
     fn strings_are_lower_case_when_converted_to_names() {
         let raw_str = "sxt";
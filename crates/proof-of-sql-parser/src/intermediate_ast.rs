@@ -4,7 +4,10 @@
 * https://docs.rs/vervolg/latest/vervolg/ast/enum.Statement.html
 ***/
 
-use crate::{posql_time::PoSQLTimestamp, Identifier};
+use crate::{
+    posql_time::{DateTruncUnit, PoSQLTimestamp},
+    Identifier,
+};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use bigdecimal::BigDecimal;
 use core::{
@@ -15,7 +18,7 @@ use core::{
 use serde::{Deserialize, Serialize};
 
 /// Representation of a `SetExpression`, a collection of rows, each having one or more columns.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash)]
 pub enum SetExpression {
     /// Query result as `SetExpression`
     Query {
@@ -28,19 +31,24 @@ pub enum SetExpression {
         where_expr: Option<Box<Expression>>,
         /// Group by expressions e.g. `a` in `SELECT a, COUNT(*) FROM table GROUP BY a`
         group_by: Vec<Identifier>,
+        /// Filter on the aggregated rows e.g. `COUNT(*) > 5` in `SELECT a, COUNT(*) FROM table GROUP BY a HAVING COUNT(*) > 5`
+        /// If None, no filter is applied
+        having: Option<Box<Expression>>,
     },
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash)]
 /// What to select in a query
 pub enum SelectResultExpr {
     /// All columns in a table e.g. `SELECT * FROM table`
     ALL,
+    /// All columns in one of the tables of the `FROM` clause e.g. `SELECT t.* FROM t`
+    QualifiedAll(Identifier),
     /// A single expression e.g. `SELECT a FROM table`
     AliasedResultExpr(AliasedResultExpr),
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash)]
 /// An expression with an alias e.g. `a + 1 AS b`
 pub struct AliasedResultExpr {
     /// The expression e.g. `a + 1`, `COUNT(*)`, etc.
@@ -71,7 +79,7 @@ impl AliasedResultExpr {
 }
 
 /// Representations of base queries
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash)]
 pub enum TableExpression {
     /// The row set of a given table; possibly providing an alias
     Named {
@@ -97,6 +105,12 @@ pub enum BinaryOperator {
     /// Numeric division
     Division,
 
+    /// Numeric remainder
+    Modulo,
+
+    /// String concatenation
+    Concat,
+
     /// Logical And
     And,
 
@@ -118,6 +132,10 @@ pub enum BinaryOperator {
 pub enum UnaryOperator {
     /// Logical inversion
     Not,
+    /// Numerical negation
+    Negate,
+    /// Absolute value
+    Abs,
 }
 
 // Aggregation operators
@@ -132,6 +150,10 @@ pub enum AggregationOperator {
     Sum,
     /// Count
     Count,
+    /// Count of the distinct values
+    CountDistinct,
+    /// `HyperLogLog` estimate of the count of the distinct values
+    ApproxCountDistinct,
     /// Return the first value
     First,
 }
@@ -143,6 +165,8 @@ impl Display for AggregationOperator {
             AggregationOperator::Min => write!(f, "min"),
             AggregationOperator::Sum => write!(f, "sum"),
             AggregationOperator::Count => write!(f, "count"),
+            AggregationOperator::CountDistinct => write!(f, "count distinct"),
+            AggregationOperator::ApproxCountDistinct => write!(f, "approx_count_distinct"),
             AggregationOperator::First => write!(f, "first"),
         }
     }
@@ -184,6 +208,55 @@ pub enum Expression {
         op: AggregationOperator,
         /// The expression to aggregate
         expr: Box<Expression>,
+        /// The condition of a `FILTER (WHERE ...)` clause, if any.
+        /// Only the rows where it holds are aggregated.
+        filter: Option<Box<Expression>>,
+    },
+
+    /// `EXISTS` subquery e.g. `EXISTS (SELECT k FROM b WHERE b.k = 5)`
+    Exists(Box<SetExpression>),
+
+    /// Scalar subquery, which produces a single value, e.g. `(SELECT MAX(b) FROM t2 WHERE t2.k = k)`
+    ///
+    /// The subquery may be correlated, i.e. reference columns of the outer query.
+    ScalarSubquery(Box<SetExpression>),
+
+    /// A numbered query parameter e.g. `$1`, which is replaced by a value when the query is bound
+    Placeholder(u64),
+
+    /// Conditional selection e.g. `CASE WHEN a > 0 THEN a ELSE b END`
+    ///
+    /// A `CASE` with several `WHEN` branches is represented as nested `Case` expressions.
+    Case {
+        /// The condition selecting between the two results
+        condition: Box<Expression>,
+        /// The result for rows where the condition is true
+        then_result: Box<Expression>,
+        /// The result for rows where the condition is false
+        else_result: Box<Expression>,
+    },
+
+    /// The window function `ROW_NUMBER() OVER (ORDER BY <column> [ASC|DESC])`,
+    /// which numbers the rows from 1 in the order of the column
+    RowNumber(OrderBy),
+
+    /// The window functions `LAG(<column>) OVER (ORDER BY <key> [ASC|DESC])` and `LEAD(...)`,
+    /// which take the value of the column in the previous or next row in the order of the key
+    Shift {
+        /// Whether the value of the previous or the next row is taken
+        direction: ShiftDirection,
+        /// The column whose value is taken
+        column: Identifier,
+        /// The order of the rows
+        order_by: OrderBy,
+    },
+
+    /// Timestamp truncation e.g. `DATE_TRUNC('hour', ts)`, which rounds a timestamp down to the start of its unit
+    DateTrunc {
+        /// The unit to truncate to
+        unit: DateTruncUnit,
+        /// The timestamp to truncate
+        expr: Box<Expression>,
     },
 }
 
@@ -194,6 +267,7 @@ impl Expression {
         Box::new(Expression::Aggregation {
             op: AggregationOperator::Sum,
             expr: Box::new(self),
+            filter: None,
         })
     }
 
@@ -203,6 +277,7 @@ impl Expression {
         Box::new(Expression::Aggregation {
             op: AggregationOperator::Max,
             expr: Box::new(self),
+            filter: None,
         })
     }
 
@@ -212,6 +287,7 @@ impl Expression {
         Box::new(Expression::Aggregation {
             op: AggregationOperator::Min,
             expr: Box::new(self),
+            filter: None,
         })
     }
 
@@ -221,6 +297,27 @@ impl Expression {
         Box::new(Expression::Aggregation {
             op: AggregationOperator::Count,
             expr: Box::new(self),
+            filter: None,
+        })
+    }
+
+    /// Create a new `COUNT(DISTINCT)`
+    #[must_use]
+    pub fn count_distinct(self) -> Box<Self> {
+        Box::new(Expression::Aggregation {
+            op: AggregationOperator::CountDistinct,
+            expr: Box::new(self),
+            filter: None,
+        })
+    }
+
+    /// Create a new `APPROX_COUNT_DISTINCT()`
+    #[must_use]
+    pub fn approx_count_distinct(self) -> Box<Self> {
+        Box::new(Expression::Aggregation {
+            op: AggregationOperator::ApproxCountDistinct,
+            expr: Box::new(self),
+            filter: None,
         })
     }
 
@@ -230,6 +327,7 @@ impl Expression {
         Box::new(Expression::Aggregation {
             op: AggregationOperator::First,
             expr: Box::new(self),
+            filter: None,
         })
     }
     /// Create an `AliasedResultExpr` from an `Expression` using the provided alias.
@@ -291,7 +389,7 @@ impl core::ops::Sub<Box<Expression>> for Box<Expression> {
 }
 
 /// `OrderBy`
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash)]
 pub struct OrderBy {
     /// which column to order by
     pub expr: Identifier,
@@ -300,7 +398,7 @@ pub struct OrderBy {
 }
 
 /// `OrderByDirection` values
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum OrderByDirection {
     /// Ascending
     Asc,
@@ -318,6 +416,25 @@ impl Display for OrderByDirection {
     }
 }
 
+/// `ShiftDirection` values, i.e. the window functions that take the value of an adjacent row
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ShiftDirection {
+    /// `LAG`, the value of the previous row
+    Lag,
+    /// `LEAD`, the value of the next row
+    Lead,
+}
+
+impl Display for ShiftDirection {
+    // This trait requires `fmt` with this exact signature.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ShiftDirection::Lag => write!(f, "lag"),
+            ShiftDirection::Lead => write!(f, "lead"),
+        }
+    }
+}
+
 /// Limits for a limit clause
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub struct Slice {
@@ -341,6 +458,8 @@ pub enum Literal {
     Boolean(bool),
     /// i64 Literal
     BigInt(i64),
+    /// u64 Literal
+    UInt64(u64),
     /// i128 Literal
     Int128(i128),
     /// String Literal
@@ -349,6 +468,8 @@ pub enum Literal {
     Decimal(BigDecimal),
     /// Timestamp Literal
     Timestamp(PoSQLTimestamp),
+    /// Fixed-length binary Literal, written in hex as `x'00ff'`
+    FixedBytes(Vec<u8>),
 }
 
 impl From<bool> for Literal {
@@ -376,6 +497,12 @@ impl_int_to_literal!(i32);
 impl_int_to_literal!(u32);
 impl_int_to_literal!(i64);
 
+impl From<u64> for Literal {
+    fn from(val: u64) -> Self {
+        Literal::UInt64(val)
+    }
+}
+
 impl From<i128> for Literal {
     fn from(val: i128) -> Self {
         Literal::Int128(val)
@@ -402,6 +529,12 @@ impl From<BigDecimal> for Literal {
     }
 }
 
+impl From<Vec<u8>> for Literal {
+    fn from(val: Vec<u8>) -> Self {
+        Literal::FixedBytes(val)
+    }
+}
+
 impl From<PoSQLTimestamp> for Literal {
     fn from(time: PoSQLTimestamp) -> Self {
         Literal::Timestamp(time)
@@ -1,11 +1,16 @@
 use crate::{
-    intermediate_ast::OrderByDirection::{Asc, Desc},
+    intermediate_ast::{
+        OrderByDirection::{Asc, Desc},
+        ShiftDirection,
+    },
+    posql_time::DateTruncUnit,
     sql::*,
     utility::*,
     SelectStatement,
 };
 use alloc::{
     borrow::ToOwned,
+    format,
     string::{String, ToString},
     vec,
 };
@@ -172,6 +177,37 @@ fn we_can_parse_a_query_with_a_column_equals_a_simple_bool() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_query_with_a_boolean_literal_as_the_where_clause() {
+    for (sql, value) in [
+        ("SELECT A FROM SXT_TAB WHERE TRUE", true),
+        ("SELECT A FROM SXT_TAB WHERE false", false),
+        ("SELECT A FROM SXT_TAB WHERE True", true),
+    ] {
+        let ast = sql.parse::<SelectStatement>().unwrap();
+        let expected_ast = select(
+            query(cols_res(&["a"]), tab(None, "sxt_tab"), lit(value), vec![]),
+            vec![],
+            None,
+        );
+        assert_eq!(ast, expected_ast);
+    }
+    let ast = "SELECT A FROM SXT_TAB WHERE NOT FALSE AND A = 3"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            and(not(lit(false)), equal(col("a"), lit(3))),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
 #[test]
 fn we_can_parse_a_query_with_a_column_equals_a_simple_integer() {
     let ast = "SELECT A FROM SXT_TAB WHERE A = 3;"
@@ -226,6 +262,43 @@ fn we_can_parse_a_query_with_a_column_equals_a_decimal() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_decimals_in_scientific_notation() {
+    for (literal, expected) in [
+        ("1.5e3", "1500"),
+        ("2E-2", "0.02"),
+        ("-2.5e+1", "-25"),
+        ("1e40", "10000000000000000000000000000000000000000"),
+        (".5E1", "5"),
+        ("12.e-3", "0.012"),
+    ] {
+        let ast = format!("SELECT A FROM SXT_TAB WHERE A = {literal};")
+            .parse::<SelectStatement>()
+            .unwrap();
+        let expected_ast = select(
+            query(
+                cols_res(&["a"]),
+                tab(None, "sxt_tab"),
+                equal(col("a"), lit(expected.parse::<BigDecimal>().unwrap())),
+                vec![],
+            ),
+            vec![],
+            None,
+        );
+        assert_eq!(ast, expected_ast, "{literal}");
+    }
+}
+
+#[test]
+fn we_cannot_parse_decimals_with_an_exponent_out_of_range() {
+    assert_eq!(
+        "select a from tab where b = 1e99999999999999999999".parse::<SelectStatement>(),
+        Err(super::error::ParseError::QueryParseError {
+            error: "decimal out of range".to_string()
+        })
+    );
+}
+
 #[test]
 fn we_can_parse_a_query_with_two_result_columns() {
     let ast = "Select a,  b froM sxt_tab where C = D + 1 and E = F and G"
@@ -250,8 +323,6 @@ fn we_can_parse_a_query_with_two_result_columns() {
     assert_eq!(ast, expected_ast);
 }
 
-//TODO: Add unary negative operator so that it will no longer be necessary to use the minus sign with brackets
-//(PROOF-864)
 #[test]
 fn we_can_parse_a_query_using_select_star() {
     let ast = "SELECT * FROM sxt_Tab WHERE A = -(B);"
@@ -261,7 +332,101 @@ fn we_can_parse_a_query_using_select_star() {
         query(
             vec![col_res_all()],
             tab(None, "sxt_tab"),
-            equal(col("a"), lit(-1_i64) * col("b")),
+            equal(col("a"), neg(col("b"))),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_unary_minus() {
+    let ast = "SELECT -a * b AS c, a - -b AS d, -(a + b) AS e, - -a AS f, -sum(a) AS g FROM sxt_tab WHERE -a = -5"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(neg(col("a")) * col("b"), "c"),
+                col_res(col("a") - neg(col("b")), "d"),
+                col_res(neg(col("a") + col("b")), "e"),
+                col_res(neg(neg(col("a"))), "f"),
+                col_res(neg(sum(col("a"))), "g"),
+            ],
+            tab(None, "sxt_tab"),
+            equal(neg(col("a")), lit(-5_i64)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_abs() {
+    let ast = "SELECT ABS(a) AS c, abs(a - b) * 2 AS d, -abs(-a) AS e, abs(sum(a)) AS f FROM sxt_tab WHERE Abs(a) <= 5"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(abs(col("a")), "c"),
+                col_res(abs(col("a") - col("b")) * lit(2_i64), "d"),
+                col_res(neg(abs(neg(col("a")))), "e"),
+                col_res(abs(sum(col("a"))), "f"),
+            ],
+            tab(None, "sxt_tab"),
+            le(abs(col("a")), lit(5_i64)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert!("SELECT abs FROM sxt_tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_can_parse_a_query_using_a_qualified_select_star() {
+    let ast = "SELECT a, sxt_Tab.* FROM sxt_Tab WHERE A = B"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![col_res(col("a"), "a"), col_res_qualified_all("sxt_tab")],
+            tab(None, "sxt_tab"),
+            equal(col("a"), col("b")),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_a_query_using_a_qualified_select_star_with_an_alias() {
+    assert!("SELECT t.* AS c FROM t".parse::<SelectStatement>().is_err());
+}
+
+#[test]
+fn we_can_parse_a_query_referring_to_fields_of_struct_columns() {
+    let ast = "SELECT Address.Zip, address.city AS c FROM sxt_Tab WHERE address.zip >= 10"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(col("address__zip"), "address__zip"),
+                col_res(col("address__city"), "c"),
+            ],
+            tab(None, "sxt_tab"),
+            ge(col("address__zip"), lit(10)),
             vec![],
         ),
         vec![],
@@ -270,6 +435,15 @@ fn we_can_parse_a_query_using_select_star() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_cannot_parse_a_query_referring_to_a_field_whose_leaf_column_name_is_too_long() {
+    let query = format!("SELECT {}.{} FROM sxt_tab", "a".repeat(40), "b".repeat(40));
+    assert!(query.parse::<SelectStatement>().is_err());
+    assert!("SELECT a.b.c FROM sxt_tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
 #[test]
 fn we_can_parse_a_query_using_select_star_and_a_const() {
     let ast = "SELECT *, 4 as bigint FROM sxt_Tab WHERE A = B + 3"
@@ -386,6 +560,154 @@ fn we_can_parse_a_query_with_one_logical_not_filter_expression() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_query_with_an_exists_filter_expression() {
+    let ast = "select a from sxt.a_tab where exists (select k from sxt.b_tab where k = 5)"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(Some("sxt"), "a_tab"),
+            exists(query(
+                cols_res(&["k"]),
+                tab(Some("sxt"), "b_tab"),
+                equal(col("k"), lit(5)),
+                vec![],
+            )),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_a_negated_exists_filter_expression_combined_with_others() {
+    let ast = "select a from sxt_tab where b = 1 and NOT EXISTS (SELECT k FROM b_tab)"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            and(
+                equal(col("b"), lit(1)),
+                not(exists(query_all(
+                    cols_res(&["k"]),
+                    tab(None, "b_tab"),
+                    vec![],
+                ))),
+            ),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_a_correlated_scalar_subquery() {
+    let ast = "select a, (select max(b) from sxt.t2 where k2 = k) as m from sxt.t"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res(col("a"), "a"),
+                col_res(
+                    scalar_subquery(query(
+                        vec![max_res(col("b"), "__max__")],
+                        tab(Some("sxt"), "t2"),
+                        equal(col("k2"), col("k")),
+                        vec![],
+                    )),
+                    "m",
+                ),
+            ],
+            tab(Some("sxt"), "t"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_scalar_subquery_within_an_expression() {
+    let ast = "select a from tab where a > (select count(*) from u) + 1"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "tab"),
+            not(le(
+                col("a"),
+                add(
+                    scalar_subquery(query_all(
+                        vec![count_all_res("__count__")],
+                        tab(None, "u"),
+                        vec![],
+                    )),
+                    lit(1),
+                ),
+            )),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_an_exists_filter_expression_without_a_subquery() {
+    assert!("select a from sxt_tab where exists (b)"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select a from sxt_tab where exists b"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_can_parse_a_query_with_placeholders() {
+    let ast = "select a from sxt_tab where b >= $1 and c = $12"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            and(
+                ge(col("b"), placeholder(1)),
+                equal(col("c"), placeholder(12)),
+            ),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_placeholders_that_are_not_numbered_from_one() {
+    assert!("select a from sxt_tab where b = $0"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select a from sxt_tab where b = $"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select a from sxt_tab where b = $a"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
 #[test]
 fn we_can_parse_a_query_with_one_logical_and_filter_expression() {
     let ast = "select a from sxt_tab where (b = 3) and c"
@@ -552,15 +874,17 @@ fn we_can_parse_a_query_with_the_maximum_i128_value_as_the_equal_filter_literal(
 }
 
 #[test]
-fn we_can_parse_a_query_and_rename_a_result_column_using_the_as_keyword() {
-    let ast = "select a as a_rename from sxt_tab where b = 4 + d;"
+fn we_can_parse_a_query_with_unsigned_bigint_literals() {
+    let ast = ("select a from sxt_tab where b = ubigint ".to_owned()
+        + &u64::MAX.to_string()
+        + " and c >= UBIGINT 0")
         .parse::<SelectStatement>()
         .unwrap();
     let expected_ast = select(
         query(
-            vec![col_res(col("a"), "a_rename")],
+            cols_res(&["a"]),
             tab(None, "sxt_tab"),
-            equal(col("b"), lit(4) + col("d")),
+            and(equal(col("b"), lit(u64::MAX)), ge(col("c"), lit(0_u64))),
             vec![],
         ),
         vec![],
@@ -570,58 +894,159 @@ fn we_can_parse_a_query_and_rename_a_result_column_using_the_as_keyword() {
 }
 
 #[test]
-fn we_can_parse_a_query_and_rename_a_result_column_without_using_the_as_keyword() {
-    let parsed_ast = "select a a_rename from sxt_tab where b >= c + 4"
+fn we_can_parse_a_query_with_hex_literals() {
+    let ast = "select a from sxt_tab where b = x'00fF' and c = X'1a2B3c'"
         .parse::<SelectStatement>()
         .unwrap();
     let expected_ast = select(
         query(
-            vec![col_res(col("a"), "a_rename")],
+            cols_res(&["a"]),
             tab(None, "sxt_tab"),
-            ge(col("b"), col("c") + lit(4)),
+            and(
+                equal(col("b"), lit(vec![0x00_u8, 0xff])),
+                equal(col("c"), lit(vec![0x1a_u8, 0x2b, 0x3c])),
+            ),
             vec![],
         ),
         vec![],
         None,
     );
-    assert_eq!(parsed_ast, expected_ast);
+    assert_eq!(ast, expected_ast);
 }
 
 #[test]
-fn we_can_parse_logical_not_with_more_precedence_priority_than_logical_and() {
-    let parsed_ast = "select a from sxt_tab where a = 3 and not b = 2.53;"
-        .parse::<SelectStatement>()
-        .unwrap();
-    let expected_ast = "select a from sxt_tab where (a = 3) and (not b = 2.53)"
+fn we_cannot_parse_hex_literals_with_an_odd_or_zero_number_of_digits() {
+    for query in [
+        "select a from tab where b = x'abc'",
+        "select a from tab where b = x''",
+    ] {
+        assert_eq!(
+            query.parse::<SelectStatement>(),
+            Err(super::error::ParseError::QueryParseError {
+                error: "hex literal must have a positive, even number of digits".to_string()
+            })
+        );
+    }
+    assert!("select a from tab where b = x'0g'"
         .parse::<SelectStatement>()
-        .unwrap();
-    assert_eq!(parsed_ast, expected_ast);
+        .is_err());
 }
 
 #[test]
-fn we_can_parse_logical_not_with_less_precedence_priority_than_equal_operator() {
-    let parsed_ast = "select a from sxt_tab where not b = d"
+fn we_can_parse_an_in_list_as_a_chain_of_equalities() {
+    let ast = "select a from tab where b in (1, 2 + 3, c) and d IN (x'01')"
         .parse::<SelectStatement>()
         .unwrap();
     let expected_ast = select(
         query(
             cols_res(&["a"]),
-            tab(None, "sxt_tab"),
-            not(equal(col("b"), col("d"))),
+            tab(None, "tab"),
+            and(
+                or(
+                    or(equal(col("b"), lit(1)), equal(col("b"), lit(2) + lit(3))),
+                    equal(col("b"), col("c")),
+                ),
+                equal(col("d"), lit(vec![0x01_u8])),
+            ),
             vec![],
         ),
         vec![],
         None,
     );
-    assert_eq!(parsed_ast, expected_ast);
+    assert_eq!(ast, expected_ast);
 }
 
 #[test]
-fn we_can_parse_logical_and_with_more_precedence_priority_than_logical_or() {
-    let ast = "select a from sxt_tab where a = -1 or c = -3 and a = 3"
-        .parse::<SelectStatement>()
-        .unwrap();
-    let expected_ast = "select a from sxt_tab where a = -1 or (c = -3 and a = 3);"
+fn we_cannot_parse_an_empty_in_list() {
+    assert!("select a from tab where b in ()"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_cannot_parse_unsigned_bigint_literals_outside_of_u64_range() {
+    assert_eq!(
+        "select a from tab where b = ubigint 18446744073709551616".parse::<SelectStatement>(),
+        Err(super::error::ParseError::QueryParseError {
+            error: "u64 out of range".to_string()
+        })
+    );
+    assert!("select a from tab where b = ubigint -1"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_can_parse_a_query_and_rename_a_result_column_using_the_as_keyword() {
+    let ast = "select a as a_rename from sxt_tab where b = 4 + d;"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![col_res(col("a"), "a_rename")],
+            tab(None, "sxt_tab"),
+            equal(col("b"), lit(4) + col("d")),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_and_rename_a_result_column_without_using_the_as_keyword() {
+    let parsed_ast = "select a a_rename from sxt_tab where b >= c + 4"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![col_res(col("a"), "a_rename")],
+            tab(None, "sxt_tab"),
+            ge(col("b"), col("c") + lit(4)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(parsed_ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_logical_not_with_more_precedence_priority_than_logical_and() {
+    let parsed_ast = "select a from sxt_tab where a = 3 and not b = 2.53;"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = "select a from sxt_tab where (a = 3) and (not b = 2.53)"
+        .parse::<SelectStatement>()
+        .unwrap();
+    assert_eq!(parsed_ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_logical_not_with_less_precedence_priority_than_equal_operator() {
+    let parsed_ast = "select a from sxt_tab where not b = d"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "sxt_tab"),
+            not(equal(col("b"), col("d"))),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(parsed_ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_logical_and_with_more_precedence_priority_than_logical_or() {
+    let ast = "select a from sxt_tab where a = -1 or c = -3 and a = 3"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = "select a from sxt_tab where a = -1 or (c = -3 and a = 3);"
         .parse::<SelectStatement>()
         .unwrap();
     assert_eq!(ast, expected_ast);
@@ -837,24 +1262,40 @@ fn we_cannot_parse_a_query_with_a_filter_value_bigger_than_max_i128_as_it_will_o
 }
 
 #[test]
-fn we_cannot_parse_a_query_with_select_tablename_followed_by_star() {
-    assert!("select tab.* from tab".parse::<SelectStatement>().is_err());
+fn we_cannot_parse_a_query_with_select_schema_and_tablename_followed_by_star() {
+    assert!("select eth.tab.* from eth.tab"
+        .parse::<SelectStatement>()
+        .is_err());
 }
 
 #[test]
 fn we_cannot_parse_a_query_with_schemas_followed_by_column_and_table_names() {
-    assert!("select tab.a from tab".parse::<SelectStatement>().is_err());
-    assert!("select tab.a from eth.tab;"
-        .parse::<SelectStatement>()
-        .is_err());
     assert!("select eth.tab.a from eth.tab"
         .parse::<SelectStatement>()
         .is_err());
-    assert!("select a from eth.tab where tab.b = 3;"
+    assert!("select a from eth.tab where eth.tab.b = 3;"
         .parse::<SelectStatement>()
         .is_err());
 }
 
+#[test]
+fn we_can_parse_a_column_qualified_by_its_table_name_as_a_field_of_a_struct_column() {
+    let ast = "select tab.a from eth.tab where tab.b = 3;"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![col_res(col("tab__a"), "tab__a")],
+            tab(Some("eth"), "tab"),
+            equal(col("tab__b"), lit(3)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
 #[test]
 fn we_cannot_parse_a_query_with_a_subquery() {
     assert!("select a from (select a from tab where b = 4)"
@@ -960,6 +1401,98 @@ fn we_cannot_parse_a_query_having_a_simple_limit_and_offset_clause_preceded_by_w
     );
 }
 
+#[test]
+fn we_can_parse_fetch_first_and_top_as_aliases_of_limit() {
+    let limit_ast = "select a from tab where a = 3 order by a limit 5"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["a"]),
+            tab(None, "tab"),
+            equal(col("a"), lit(3)),
+            vec![],
+        ),
+        order("a", Asc),
+        slice(5, 0),
+    );
+    assert_eq!(limit_ast, expected_ast);
+    for sql in [
+        "select a from tab where a = 3 order by a fetch first 5 rows only",
+        "SELECT a FROM tab WHERE a = 3 ORDER BY a FETCH NEXT 5 ROW ONLY;",
+        "select top 5 a from tab where a = 3 order by a",
+        "SELECT TOP 5 a FROM tab WHERE a = 3 ORDER BY a;",
+    ] {
+        assert_eq!(sql.parse::<SelectStatement>().unwrap(), limit_ast, "{sql}");
+    }
+}
+
+#[test]
+fn we_can_parse_fetch_first_with_an_offset_or_without_a_row_count() {
+    let expected_ast = select(
+        query_all(cols_res(&["a"]), tab(None, "tab"), vec![]),
+        vec![],
+        slice(55, 3),
+    );
+    for sql in [
+        "select a from tab offset 3 rows fetch first 55 rows only",
+        "select a from tab offset 3 fetch next 55 rows only",
+        "select a from tab offset 3 row limit 55",
+    ] {
+        assert_eq!(
+            sql.parse::<SelectStatement>().unwrap(),
+            expected_ast,
+            "{sql}"
+        );
+    }
+    let ast = "select a from tab fetch first row only"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(cols_res(&["a"]), tab(None, "tab"), vec![]),
+        vec![],
+        slice(1, 0),
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn first_next_row_and_rows_remain_valid_identifiers() {
+    let ast = "select first, next from rows where row = 1 offset 2 rows"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            cols_res(&["first", "next"]),
+            tab(None, "rows"),
+            equal(col("row"), lit(1)),
+            vec![],
+        ),
+        vec![],
+        slice(u64::MAX, 2),
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_an_invalid_fetch_or_top_clause() {
+    for sql in [
+        "select a from tab fetch first 5 rows",
+        "select a from tab fetch 5 rows only",
+        "select a from tab fetch last 5 rows only",
+        "select a from tab fetch first 5 cols only",
+        "select a from tab fetch first -5 rows only",
+        "select a from tab limit 5 fetch first 5 rows only",
+        "select a from tab fetch first 5 rows only offset 3",
+        "select a from tab offset 3 cols",
+        "select top -5 a from tab",
+        "select top 5 a from tab limit 3",
+        "select a from tab where exists (select top 1 b from tab)",
+    ] {
+        assert!(sql.parse::<SelectStatement>().is_err(), "{sql}");
+    }
+}
+
 #[test]
 fn we_can_parse_a_query_with_filter_ge() {
     let ast = "select a from tab where b >= 4"
@@ -1041,9 +1574,77 @@ fn we_cannot_parse_a_query_with_inner_join_keyword() {
     );
 }
 
+#[test]
+fn we_cannot_parse_a_query_with_left_join_keyword() {
+    for query in [
+        "select tab1.a, tab2.b from tab1 left join tab2 on tab1.c = tab2.c;",
+        "select tab1.a, tab2.b from tab1 left outer join tab2 on tab1.c = tab2.c;",
+    ] {
+        assert!(query.parse::<SelectStatement>().is_err(), "{query}");
+    }
+}
+
 // Case when
 #[test]
-fn we_cannot_parse_a_query_with_case_when_keyword() {
+fn we_can_parse_a_query_with_case_when() {
+    let ast = "select CASE WHEN a >= 2 THEN b ELSE 5 END as c from tab where case when b then true else a = 1 end"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![col_res(
+                case_when(ge(col("a"), lit(2)), col("b"), lit(5)),
+                "c",
+            )],
+            tab(None, "tab"),
+            case_when(col("b"), lit(true), equal(col("a"), lit(1))),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_with_case_when_with_several_branches_and_arithmetic() {
+    let ast = "select case when a = 1 then b + 1 when a = 2 then b * 2 else 0 end - 3 from tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![col_res(
+                case_when(
+                    equal(col("a"), lit(1)),
+                    col("b") + lit(1),
+                    case_when(equal(col("a"), lit(2)), col("b") * lit(2), lit(0)),
+                ) - lit(3),
+                "__expr__",
+            )],
+            tab(None, "tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_a_case_when_without_else_or_end() {
+    assert!("select case when a = 2 then 3 end from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select case when a = 2 then 3 else 5 from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select case else 5 end from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_cannot_parse_a_query_with_invalid_case_when_syntax() {
     assert!(
         "select case when a == 2 then 3 else 5 from tab where b <= 4;"
             .parse::<SelectStatement>()
@@ -1051,6 +1652,132 @@ fn we_cannot_parse_a_query_with_case_when_keyword() {
     );
 }
 
+// Row number
+#[test]
+fn we_can_parse_a_query_with_row_number() {
+    let ast = "select a, row_number() over (order by a desc) as rn, row_number() over (order by b) from tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res(col("a"), "a"),
+                col_res(row_number("a", Desc), "rn"),
+                col_res(row_number("b", Asc), "__row_number__"),
+            ],
+            tab(None, "tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_a_row_number_without_a_single_order_by_key() {
+    assert!("select row_number() from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select row_number() over () from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select row_number() over (order by a, b) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!(
+        "select row_number() over (partition by a order by b) from tab"
+            .parse::<SelectStatement>()
+            .is_err()
+    );
+    assert!("select row_number(a) over (order by a) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+// Lag and lead
+#[test]
+fn we_can_parse_a_query_with_lag_and_lead() {
+    let ast =
+        "select a, lag(b) over (order by a) as prev_b, LEAD(b) OVER (ORDER BY a DESC) from tab"
+            .parse::<SelectStatement>()
+            .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res(col("a"), "a"),
+                col_res(shift(ShiftDirection::Lag, "b", "a", Asc), "prev_b"),
+                col_res(shift(ShiftDirection::Lead, "b", "a", Desc), "__lead__"),
+            ],
+            tab(None, "tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_a_lag_or_lead_that_is_not_supported() {
+    // a window and a single order by key are required
+    assert!("select lag(b) from tab".parse::<SelectStatement>().is_err());
+    assert!("select lag(b) over () from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select lead(b) over (order by a, c) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select lag(b) over (partition by c order by a) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    // only a column with the default offset
+    assert!("select lag(b, 2) over (order by a) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select lag(b + 1) over (order by a) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select lead() over (order by a) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+// Date truncation
+#[test]
+fn we_can_parse_a_query_with_date_trunc() {
+    let ast = "select date_trunc('HOUR', ts) as h, -date_trunc('day', ts) from tab where date_trunc('minute', ts) = ts"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(date_trunc(DateTruncUnit::Hour, col("ts")), "h"),
+                col_res(neg(date_trunc(DateTruncUnit::Day, col("ts"))), "__expr__"),
+            ],
+            tab(None, "tab"),
+            equal(date_trunc(DateTruncUnit::Minute, col("ts")), col("ts")),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_a_date_trunc_with_an_unsupported_unit() {
+    assert!("select date_trunc('week', ts) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select date_trunc(hour, ts) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select date_trunc('hour') from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
 //////////////////////
 // Invalid SQLs
 //////////////////////
@@ -1149,6 +1876,82 @@ fn we_can_parse_a_simple_group_by_clause_using_the_wildcard() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_count_of_distinct_values() {
+    let ast = "select count(distinct a), COUNT(DISTINCT b) as n from tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res(count_distinct(col("a")), "__count_distinct__"),
+                col_res(count_distinct(col("b")), "n"),
+            ],
+            tab(None, "tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert!("select count(distinct *) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_can_parse_an_approximate_count_of_distinct_values() {
+    let ast = "select approx_count_distinct(a), APPROX_COUNT_DISTINCT(b) as n from tab"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res(approx_count_distinct(col("a")), "__approx_count_distinct__"),
+                col_res(approx_count_distinct(col("b")), "n"),
+            ],
+            tab(None, "tab"),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert!("select approx_count_distinct(distinct a) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
+#[test]
+fn we_can_parse_aggregations_with_a_filter_clause() {
+    let ast = "select a, sum(b) filter (where c >= 1) as s, COUNT(*) FILTER (WHERE c = 1 or d) from tab group by a"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_all(
+            vec![
+                col_res(col("a"), "a"),
+                col_res(agg_filter(sum(col("b")), ge(col("c"), lit(1))), "s"),
+                col_res(
+                    agg_filter(count_all(), or(equal(col("c"), lit(1)), col("d"))),
+                    "__count__",
+                ),
+            ],
+            tab(None, "tab"),
+            group_by(&["a"]),
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+    assert!("select sum(b) filter (c > 0) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+    assert!("select b filter (where c > 0) from tab"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
 #[test]
 fn we_can_parse_a_group_by_clause_containing_multiple_aggregations() {
     let ast = "select min(a), max(a) as max_a, count(a), count(*) count_all from tab group by a, b"
@@ -1196,6 +1999,51 @@ fn we_can_parse_a_group_by_clause_containing_multiple_aggregations_where_clause_
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_a_having_clause_with_aggregations() {
+    let ast = "select a, count(*) as c from tab where d = 3 group by a having count(*) >= 2 and sum(b) <= 10 order by a"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_having(
+            vec![col_res(col("a"), "a"), count_all_res("c")],
+            tab(None, "tab"),
+            Some(equal(col("d"), lit(3))),
+            group_by(&["a"]),
+            and(ge(count_all(), lit(2)), le(sum(col("b")), lit(10))),
+        ),
+        order("a", Asc),
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_having_clause_without_group_by() {
+    let ast = "select count(*) as c from tab having count(*) >= 2"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query_having(
+            vec![count_all_res("c")],
+            tab(None, "tab"),
+            None,
+            vec![],
+            ge(count_all(), lit(2)),
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_parse_a_having_clause_before_group_by() {
+    assert!("select a from tab having count(*) >= 2 group by a"
+        .parse::<SelectStatement>()
+        .is_err());
+}
+
 #[test]
 fn we_cannot_parse_a_group_by_clause_after_order_by() {
     assert!("select a from tab order by a group by a"
@@ -1283,6 +2131,51 @@ fn mul_and_div_operators_have_the_same_precedence_and_left_expressions_are_alway
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn modulo_has_the_same_precedence_as_mul_and_div() {
+    let ast = "select a % 3, a * b % c, a + b % -7 from tab where a % 2 = 1"
+        .parse::<SelectStatement>()
+        .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(modulo(col("a"), lit(3)), "__expr__"),
+                col_res(modulo(col("a") * col("b"), col("c")), "__expr__"),
+                col_res(col("a") + modulo(col("b"), lit(-7)), "__expr__"),
+            ],
+            tab(None, "tab"),
+            equal(modulo(col("a"), lit(2)), lit(1)),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn concat_is_left_associative_and_binds_looser_than_arithmetic() {
+    let ast =
+        "select first || ' ' || last as name, a + b || c, s || '' from tab where s || t = 'xy'"
+            .parse::<SelectStatement>()
+            .unwrap();
+    let expected_ast = select(
+        query(
+            vec![
+                col_res(concat(concat(col("first"), lit(" ")), col("last")), "name"),
+                col_res(concat(col("a") + col("b"), col("c")), "__expr__"),
+                col_res(concat(col("s"), lit("")), "__expr__"),
+            ],
+            tab(None, "tab"),
+            equal(concat(col("s"), col("t")), lit("xy")),
+            vec![],
+        ),
+        vec![],
+        None,
+    );
+    assert_eq!(ast, expected_ast);
+}
+
 #[test]
 fn we_can_parse_a_single_literal_in_the_result_expr() {
     let ast = "select -123 from tab".parse::<SelectStatement>().unwrap();
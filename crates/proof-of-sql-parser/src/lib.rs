@@ -27,7 +27,7 @@ pub(crate) use error::ParseResult;
 
 /// TODO: add docs
 pub(crate) mod identifier;
-pub use identifier::Identifier;
+pub use identifier::{Identifier, NESTED_FIELD_SEPARATOR};
 
 pub mod resource_id;
 pub use resource_id::ResourceId;
@@ -0,0 +1,117 @@
+use super::{PoSQLTimeUnit, PoSQLTimestampError};
+use alloc::string::ToString;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// A unit of time that `DATE_TRUNC` can truncate a timestamp to
+#[derive(Debug, Clone, Copy, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DateTruncUnit {
+    /// Truncate to the start of the second
+    Second,
+    /// Truncate to the start of the minute
+    Minute,
+    /// Truncate to the start of the hour
+    Hour,
+    /// Truncate to the start of the day
+    Day,
+}
+
+impl DateTruncUnit {
+    /// The length of the unit in seconds
+    #[must_use]
+    pub fn seconds(self) -> i64 {
+        match self {
+            DateTruncUnit::Second => 1,
+            DateTruncUnit::Minute => 60,
+            DateTruncUnit::Hour => 60 * 60,
+            DateTruncUnit::Day => 24 * 60 * 60,
+        }
+    }
+
+    /// The length of the unit in ticks of a timestamp with the given precision
+    #[must_use]
+    pub fn ticks(self, time_unit: PoSQLTimeUnit) -> i64 {
+        let ticks_per_second = match time_unit {
+            PoSQLTimeUnit::Second => 1,
+            PoSQLTimeUnit::Millisecond => 1_000,
+            PoSQLTimeUnit::Microsecond => 1_000_000,
+            PoSQLTimeUnit::Nanosecond => 1_000_000_000,
+        };
+        self.seconds() * ticks_per_second
+    }
+}
+
+impl TryFrom<&str> for DateTruncUnit {
+    type Error = PoSQLTimestampError;
+    fn try_from(value: &str) -> Result<Self, PoSQLTimestampError> {
+        match value.to_ascii_lowercase().as_str() {
+            "second" => Ok(DateTruncUnit::Second),
+            "minute" => Ok(DateTruncUnit::Minute),
+            "hour" => Ok(DateTruncUnit::Hour),
+            "day" => Ok(DateTruncUnit::Day),
+            _ => Err(PoSQLTimestampError::InvalidTimeUnit {
+                error: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for DateTruncUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateTruncUnit::Second => write!(f, "second"),
+            DateTruncUnit::Minute => write!(f, "minute"),
+            DateTruncUnit::Hour => write!(f, "hour"),
+            DateTruncUnit::Day => write!(f, "day"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod date_trunc_unit_tests {
+    use super::*;
+
+    #[test]
+    fn we_can_parse_and_display_date_trunc_units() {
+        for (name, unit) in [
+            ("second", DateTruncUnit::Second),
+            ("minute", DateTruncUnit::Minute),
+            ("hour", DateTruncUnit::Hour),
+            ("day", DateTruncUnit::Day),
+        ] {
+            assert_eq!(DateTruncUnit::try_from(name), Ok(unit));
+            assert_eq!(
+                DateTruncUnit::try_from(name.to_uppercase().as_str()),
+                Ok(unit)
+            );
+            assert_eq!(unit.to_string(), name);
+        }
+        for name in ["", "week", "month", "hours", "milliseconds"] {
+            assert!(matches!(
+                DateTruncUnit::try_from(name),
+                Err(PoSQLTimestampError::InvalidTimeUnit { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn date_trunc_units_have_the_expected_lengths() {
+        assert_eq!(DateTruncUnit::Second.seconds(), 1);
+        assert_eq!(DateTruncUnit::Minute.seconds(), 60);
+        assert_eq!(DateTruncUnit::Hour.seconds(), 3_600);
+        assert_eq!(DateTruncUnit::Day.seconds(), 86_400);
+        assert_eq!(DateTruncUnit::Hour.ticks(PoSQLTimeUnit::Second), 3_600);
+        assert_eq!(
+            DateTruncUnit::Minute.ticks(PoSQLTimeUnit::Millisecond),
+            60_000
+        );
+        assert_eq!(
+            DateTruncUnit::Second.ticks(PoSQLTimeUnit::Microsecond),
+            1_000_000
+        );
+        assert_eq!(
+            DateTruncUnit::Day.ticks(PoSQLTimeUnit::Nanosecond),
+            86_400_000_000_000
+        );
+    }
+}
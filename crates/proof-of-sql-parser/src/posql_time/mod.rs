@@ -10,3 +10,6 @@ pub use timezone::PoSQLTimeZone;
 mod unit;
 /// Defines the precision of the timestamp
 pub use unit::PoSQLTimeUnit;
+mod date_trunc_unit;
+/// Defines the units of time that `DATE_TRUNC` truncates to
+pub use date_trunc_unit::DateTruncUnit;
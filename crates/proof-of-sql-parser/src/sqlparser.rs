@@ -1,18 +1,24 @@
 //! This module exists to adapt the current parser to `sqlparser`.
 use crate::{
     intermediate_ast::{
-        AliasedResultExpr, BinaryOperator as PoSqlBinaryOperator, Expression, Literal,
-        OrderBy as PoSqlOrderBy, OrderByDirection, SelectResultExpr, SetExpression,
+        AggregationOperator, AliasedResultExpr, BinaryOperator as PoSqlBinaryOperator, Expression,
+        Literal, OrderBy as PoSqlOrderBy, OrderByDirection, SelectResultExpr, SetExpression,
         TableExpression, UnaryOperator as PoSqlUnaryOperator,
     },
     Identifier, ResourceId, SelectStatement,
 };
-use alloc::{boxed::Box, string::ToString, vec};
-use core::fmt::Display;
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+};
+use core::fmt::{Display, Write};
 use sqlparser::ast::{
     BinaryOperator, DataType, Expr, Function, FunctionArg, FunctionArgExpr, GroupByExpr, Ident,
     ObjectName, Offset, OffsetRows, OrderByExpr, Query, Select, SelectItem, SetExpr, TableFactor,
-    TableWithJoins, TimezoneInfo, UnaryOperator, Value, WildcardAdditionalOptions,
+    TableWithJoins, TimezoneInfo, UnaryOperator, Value, WildcardAdditionalOptions, WindowSpec,
+    WindowType,
 };
 
 /// Convert a number into a [`Expr`].
@@ -67,9 +73,17 @@ impl From<Literal> for Expr {
         match literal {
             Literal::VarChar(s) => Expr::Value(Value::SingleQuotedString(s)),
             Literal::BigInt(n) => Expr::Value(Value::Number(n.to_string(), false)),
+            Literal::UInt64(n) => Expr::Value(Value::Number(n.to_string(), false)),
             Literal::Int128(n) => Expr::Value(Value::Number(n.to_string(), false)),
             Literal::Decimal(n) => Expr::Value(Value::Number(n.to_string(), false)),
             Literal::Boolean(b) => Expr::Value(Value::Boolean(b)),
+            Literal::FixedBytes(bytes) => Expr::Value(Value::HexStringLiteral(bytes.iter().fold(
+                String::new(),
+                |mut hex, byte| {
+                    write!(hex, "{byte:02X}").expect("writing to a string should not fail");
+                    hex
+                },
+            ))),
             Literal::Timestamp(timestamp) => {
                 // We currently exclusively store timestamps in UTC.
                 Expr::TypedString {
@@ -96,6 +110,8 @@ impl From<PoSqlBinaryOperator> for BinaryOperator {
             PoSqlBinaryOperator::Subtract => BinaryOperator::Minus,
             PoSqlBinaryOperator::Multiply => BinaryOperator::Multiply,
             PoSqlBinaryOperator::Division => BinaryOperator::Divide,
+            PoSqlBinaryOperator::Modulo => BinaryOperator::Modulo,
+            PoSqlBinaryOperator::Concat => BinaryOperator::StringConcat,
         }
     }
 }
@@ -104,6 +120,8 @@ impl From<PoSqlUnaryOperator> for UnaryOperator {
     fn from(op: PoSqlUnaryOperator) -> Self {
         match op {
             PoSqlUnaryOperator::Not => UnaryOperator::Not,
+            PoSqlUnaryOperator::Negate => UnaryOperator::Minus,
+            PoSqlUnaryOperator::Abs => UnaryOperator::PGAbs,
         }
     }
 }
@@ -123,6 +141,7 @@ impl From<PoSqlOrderBy> for OrderByExpr {
 }
 
 impl From<Expression> for Expr {
+    #[allow(clippy::too_many_lines)]
     fn from(expr: Expression) -> Self {
         match expr {
             Expression::Literal(literal) => literal.into(),
@@ -137,9 +156,115 @@ impl From<Expression> for Expr {
                 right: Box::new((*right).into()),
             },
             Expression::Wildcard => Expr::Wildcard,
-            Expression::Aggregation { op, expr } => Expr::Function(Function {
-                name: ObjectName(vec![Ident::new(op.to_string())]),
+            Expression::Placeholder(index) => Expr::Value(Value::Placeholder(format!("${index}"))),
+            Expression::Aggregation { op, expr, filter } => Expr::Function(Function {
+                name: ObjectName(vec![Ident::new(match op {
+                    AggregationOperator::CountDistinct => AggregationOperator::Count.to_string(),
+                    _ => op.to_string(),
+                })]),
                 args: vec![FunctionArg::Unnamed((*expr).into())],
+                filter: filter.map(|filter| Box::new((*filter).into())),
+                null_treatment: None,
+                over: None,
+                distinct: op == AggregationOperator::CountDistinct,
+                special: false,
+                order_by: vec![],
+            }),
+            Expression::Exists(subquery) => Expr::Exists {
+                subquery: Box::new(Query {
+                    with: None,
+                    body: Box::new(SetExpr::Select(Box::new((*subquery).into()))),
+                    order_by: vec![],
+                    limit: None,
+                    limit_by: vec![],
+                    offset: None,
+                    fetch: None,
+                    locks: vec![],
+                    for_clause: None,
+                }),
+                negated: false,
+            },
+            Expression::ScalarSubquery(subquery) => Expr::Subquery(Box::new(Query {
+                with: None,
+                body: Box::new(SetExpr::Select(Box::new((*subquery).into()))),
+                order_by: vec![],
+                limit: None,
+                limit_by: vec![],
+                offset: None,
+                fetch: None,
+                locks: vec![],
+                for_clause: None,
+            })),
+            Expression::Case {
+                condition,
+                then_result,
+                else_result,
+            } => {
+                // Nested `CASE` expressions in the `ELSE` branch are flattened into a single `CASE`
+                let mut conditions = vec![(*condition).into()];
+                let mut results = vec![(*then_result).into()];
+                let mut else_result = *else_result;
+                while let Expression::Case {
+                    condition,
+                    then_result,
+                    else_result: next_else_result,
+                } = else_result
+                {
+                    conditions.push((*condition).into());
+                    results.push((*then_result).into());
+                    else_result = *next_else_result;
+                }
+                Expr::Case {
+                    operand: None,
+                    conditions,
+                    results,
+                    else_result: Some(Box::new(else_result.into())),
+                }
+            }
+            Expression::RowNumber(order_by) => Expr::Function(Function {
+                name: ObjectName(vec![Ident::new("row_number")]),
+                args: vec![],
+                filter: None,
+                null_treatment: None,
+                over: Some(WindowType::WindowSpec(WindowSpec {
+                    window_name: None,
+                    partition_by: vec![],
+                    order_by: vec![order_by.into()],
+                    window_frame: None,
+                })),
+                distinct: false,
+                special: false,
+                order_by: vec![],
+            }),
+            Expression::Shift {
+                direction,
+                column,
+                order_by,
+            } => Expr::Function(Function {
+                name: ObjectName(vec![Ident::new(direction.to_string())]),
+                args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
+                    Expr::Identifier(column.into()),
+                ))],
+                filter: None,
+                null_treatment: None,
+                over: Some(WindowType::WindowSpec(WindowSpec {
+                    window_name: None,
+                    partition_by: vec![],
+                    order_by: vec![order_by.into()],
+                    window_frame: None,
+                })),
+                distinct: false,
+                special: false,
+                order_by: vec![],
+            }),
+            Expression::DateTrunc { unit, expr } => Expr::Function(Function {
+                name: ObjectName(vec![Ident::new("date_trunc")]),
+                args: vec![
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                        Value::SingleQuotedString(unit.to_string()),
+                    ))),
+                    FunctionArg::Unnamed((*expr).into()),
+                ],
                 filter: None,
                 null_treatment: None,
                 over: None,
@@ -170,6 +295,15 @@ impl From<SelectResultExpr> for SelectItem {
                 opt_rename: None,
                 opt_replace: None,
             }),
+            SelectResultExpr::QualifiedAll(qualifier) => SelectItem::QualifiedWildcard(
+                ObjectName(vec![qualifier.into()]),
+                WildcardAdditionalOptions {
+                    opt_exclude: None,
+                    opt_except: None,
+                    opt_rename: None,
+                    opt_replace: None,
+                },
+            ),
             SelectResultExpr::AliasedResultExpr(AliasedResultExpr { expr, alias }) => {
                 SelectItem::ExprWithAlias {
                     expr: (*expr).into(),
@@ -188,6 +322,7 @@ impl From<SetExpression> for Select {
                 from,
                 where_expr,
                 group_by,
+                having,
             } => Select {
                 distinct: None,
                 top: None,
@@ -206,7 +341,7 @@ impl From<SetExpression> for Select {
                 cluster_by: vec![],
                 distribute_by: vec![],
                 sort_by: vec![],
-                having: None,
+                having: having.map(|expr| (*expr).into()),
                 named_window: vec![],
                 qualify: None,
                 value_table_mode: None,
@@ -268,6 +403,14 @@ mod test {
             "select timestamp '2024-11-07T04:55:12.345+03:00' as time from t;",
             "select timestamp(3) '2024-11-07 01:55:12.345 UTC' as time from t;",
         );
+        check_posql_intermediate_ast_to_sqlparser_equivalence(
+            "select a from t where b = ubigint 18446744073709551615;",
+            "select a as a from t where b = 18446744073709551615;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equivalence(
+            "select a from t where id in (x'00ff', x'ab01');",
+            "select a as a from t where id = X'00FF' or id = X'AB01';",
+        );
     }
 
     // Check that PoSQL intermediate AST can be converted to SQL parser AST and that the two are equal.
@@ -280,6 +423,7 @@ mod test {
     #[test]
     fn we_can_convert_posql_intermediate_ast_to_sqlparser() {
         check_posql_intermediate_ast_to_sqlparser_equality("SELECT * FROM t");
+        check_posql_intermediate_ast_to_sqlparser_equality("SELECT t.*, a AS b FROM t");
         check_posql_intermediate_ast_to_sqlparser_equality(
             "select a as a, 4.7 * b as b from namespace.table where c = 2.5;",
         );
@@ -299,5 +443,47 @@ mod test {
         check_posql_intermediate_ast_to_sqlparser_equality(
             "select cat as cat, sum(a) as s, count(*) as rows from tab where d = 'Space and Time' group by cat;",
         );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select cat as cat, count(*) as rows from tab group by cat having count(*) >= 2;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select count(distinct a) as c from tab where b = 1;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select approx_count_distinct(a) as c from tab where b = 1;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select cat as cat, sum(a) FILTER (WHERE b >= 0) as s from tab group by cat;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select a as a from t where exists (select k as k from u where k = 3);",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select a as a, (select max(b) as m from u where k = a) as m from t;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select a % 3 as r from t where b % 2 = 1;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select a || ' ' || b as name from t where a || '' = 'x';",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select a as a from t where b = $1 and c = $2;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select CASE WHEN a >= 0 THEN a ELSE b END as c from t;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select CASE WHEN a = 1 THEN 'one' WHEN a = 2 THEN 'two' ELSE 'other' END as c from t;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select a as a, row_number() over (order by a desc) as rn from t where b = 1;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select a as a, lag(b) over (order by a asc) as p, lead(b) over (order by a desc) as n from t;",
+        );
+        check_posql_intermediate_ast_to_sqlparser_equality(
+            "select date_trunc('hour', ts) as h from t where date_trunc('day', ts) = ts;",
+        );
     }
 }
@@ -1,8 +1,10 @@
 use crate::{
     intermediate_ast::{
         AggregationOperator, AliasedResultExpr, BinaryOperator, Expression, Literal, OrderBy,
-        OrderByDirection, SelectResultExpr, SetExpression, Slice, TableExpression, UnaryOperator,
+        OrderByDirection, SelectResultExpr, SetExpression, ShiftDirection, Slice, TableExpression,
+        UnaryOperator,
     },
+    posql_time::DateTruncUnit,
     Identifier, SelectStatement,
 };
 use alloc::{boxed::Box, vec, vec::Vec};
@@ -56,6 +58,24 @@ pub fn not(expr: Box<Expression>) -> Box<Expression> {
     })
 }
 
+/// Construct a new boxed `Expression` -P
+#[must_use]
+pub fn neg(expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Unary {
+        op: UnaryOperator::Negate,
+        expr,
+    })
+}
+
+/// Construct a new boxed `Expression` ABS(P)
+#[must_use]
+pub fn abs(expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Unary {
+        op: UnaryOperator::Abs,
+        expr,
+    })
+}
+
 /// Construct a new boxed `Expression` P AND Q
 #[must_use]
 pub fn and(left: Box<Expression>, right: Box<Expression>) -> Box<Expression> {
@@ -116,6 +136,39 @@ pub fn div(left: Box<Expression>, right: Box<Expression>) -> Box<Expression> {
     })
 }
 
+/// Construct a new boxed `Expression` A % B
+#[must_use]
+pub fn modulo(left: Box<Expression>, right: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Binary {
+        op: BinaryOperator::Modulo,
+        left,
+        right,
+    })
+}
+
+/// Construct a new boxed `Expression` A || B
+#[must_use]
+pub fn concat(left: Box<Expression>, right: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Binary {
+        op: BinaryOperator::Concat,
+        left,
+        right,
+    })
+}
+
+/// Construct a new boxed `Expression` A IN (B, C, ...), which is A = B OR A = C OR ...
+///
+/// # Panics
+///
+/// This function will panic if `list` is empty.
+#[must_use]
+pub fn in_list(expr: &Expression, list: Vec<Box<Expression>>) -> Box<Expression> {
+    list.into_iter()
+        .map(|item| equal(Box::new(expr.clone()), item))
+        .reduce(or)
+        .expect("an IN list should not be empty")
+}
+
 /// Get table from schema and name.
 ///
 /// If the schema is `None`, the table is assumed to be in the default schema.
@@ -151,6 +204,7 @@ pub fn sum(expr: Box<Expression>) -> Box<Expression> {
     Box::new(Expression::Aggregation {
         op: AggregationOperator::Sum,
         expr,
+        filter: None,
     })
 }
 
@@ -160,6 +214,7 @@ pub fn min(expr: Box<Expression>) -> Box<Expression> {
     Box::new(Expression::Aggregation {
         op: AggregationOperator::Min,
         expr,
+        filter: None,
     })
 }
 
@@ -169,6 +224,7 @@ pub fn max(expr: Box<Expression>) -> Box<Expression> {
     Box::new(Expression::Aggregation {
         op: AggregationOperator::Max,
         expr,
+        filter: None,
     })
 }
 
@@ -178,6 +234,27 @@ pub fn count(expr: Box<Expression>) -> Box<Expression> {
     Box::new(Expression::Aggregation {
         op: AggregationOperator::Count,
         expr,
+        filter: None,
+    })
+}
+
+/// Count the distinct values of an expression i.e. COUNT(DISTINCT EXPR)
+#[must_use]
+pub fn count_distinct(expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Aggregation {
+        op: AggregationOperator::CountDistinct,
+        expr,
+        filter: None,
+    })
+}
+
+/// Estimate the number of distinct values of an expression i.e. `APPROX_COUNT_DISTINCT(EXPR)`
+#[must_use]
+pub fn approx_count_distinct(expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::Aggregation {
+        op: AggregationOperator::ApproxCountDistinct,
+        expr,
+        filter: None,
     })
 }
 
@@ -187,6 +264,94 @@ pub fn count_all() -> Box<Expression> {
     count(Box::new(Expression::Wildcard))
 }
 
+/// Restrict an aggregation to the rows where a condition holds i.e. AGG(EXPR) FILTER (WHERE CONDITION)
+///
+/// # Panics
+///
+/// This function will panic if `aggregation` is not an aggregation.
+#[must_use]
+pub fn agg_filter(mut aggregation: Box<Expression>, condition: Box<Expression>) -> Box<Expression> {
+    let Expression::Aggregation { filter, .. } = aggregation.as_mut() else {
+        panic!("FILTER can only be applied to an aggregation");
+    };
+    *filter = Some(condition);
+    aggregation
+}
+
+/// Check whether a subquery returns any rows i.e. EXISTS (SUBQUERY)
+#[must_use]
+pub fn exists(subquery: Box<SetExpression>) -> Box<Expression> {
+    Box::new(Expression::Exists(subquery))
+}
+
+/// A subquery producing a single value i.e. (SUBQUERY)
+#[must_use]
+pub fn scalar_subquery(subquery: Box<SetExpression>) -> Box<Expression> {
+    Box::new(Expression::ScalarSubquery(subquery))
+}
+
+/// A numbered query parameter i.e. $INDEX
+#[must_use]
+pub fn placeholder(index: u64) -> Box<Expression> {
+    Box::new(Expression::Placeholder(index))
+}
+
+/// Select between two expressions i.e. CASE WHEN CONDITION THEN RESULT ELSE OTHER END
+#[must_use]
+pub fn case_when(
+    condition: Box<Expression>,
+    then_result: Box<Expression>,
+    else_result: Box<Expression>,
+) -> Box<Expression> {
+    Box::new(Expression::Case {
+        condition,
+        then_result,
+        else_result,
+    })
+}
+
+/// Number the rows in the order of a column i.e. `ROW_NUMBER() OVER (ORDER BY ID [ASC|DESC])`
+///
+/// # Panics
+///
+/// This function will panic if the `id` cannot be parsed as a valid [Identifier].
+#[must_use]
+pub fn row_number(id: &str, direction: OrderByDirection) -> Box<Expression> {
+    Box::new(Expression::RowNumber(OrderBy {
+        expr: id.parse().unwrap(),
+        direction,
+    }))
+}
+
+/// Take the value of a column in the previous or next row in the order of another column
+/// i.e. `LAG(COLUMN) OVER (ORDER BY ID [ASC|DESC])` or `LEAD(COLUMN) OVER (ORDER BY ID [ASC|DESC])`
+///
+/// # Panics
+///
+/// This function will panic if the `column` or the `id` cannot be parsed as a valid [Identifier].
+#[must_use]
+pub fn shift(
+    direction: ShiftDirection,
+    column: &str,
+    id: &str,
+    order_by_direction: OrderByDirection,
+) -> Box<Expression> {
+    Box::new(Expression::Shift {
+        direction,
+        column: column.parse().unwrap(),
+        order_by: OrderBy {
+            expr: id.parse().unwrap(),
+            direction: order_by_direction,
+        },
+    })
+}
+
+/// Truncate a timestamp to a unit of time i.e. `DATE_TRUNC('<unit>', EXPR)`
+#[must_use]
+pub fn date_trunc(unit: DateTruncUnit, expr: Box<Expression>) -> Box<Expression> {
+    Box::new(Expression::DateTrunc { unit, expr })
+}
+
 /// An expression with an alias i.e. EXPR AS ALIAS
 ///
 /// # Panics
@@ -206,6 +371,16 @@ pub fn col_res_all() -> SelectResultExpr {
     SelectResultExpr::ALL
 }
 
+/// Select all columns from one of the tables i.e. SELECT QUALIFIER.*
+///
+/// # Panics
+///
+/// This function will panic if the `qualifier` cannot be parsed as a valid [Identifier].
+#[must_use]
+pub fn col_res_qualified_all(qualifier: &str) -> SelectResultExpr {
+    SelectResultExpr::QualifiedAll(qualifier.parse().unwrap())
+}
+
 /// Select one column from a table and give it an alias i.e. SELECT COL AS ALIAS
 ///
 /// # Panics
@@ -288,6 +463,7 @@ pub fn count_all_res(alias: &str) -> SelectResultExpr {
         expr: Expression::Aggregation {
             op: AggregationOperator::Count,
             expr: Box::new(Expression::Wildcard),
+            filter: None,
         }
         .into(),
         alias: alias.parse().unwrap(),
@@ -307,6 +483,7 @@ pub fn query(
         from: vec![tab],
         where_expr: Some(where_expr),
         group_by,
+        having: None,
     })
 }
 
@@ -324,6 +501,25 @@ pub fn query_all(
         from: vec![tab],
         where_expr: None,
         group_by,
+        having: None,
+    })
+}
+
+/// Generate a `SetExpression` of the kind SELECT COL1, COL2, ... FROM TAB [WHERE EXPR] GROUP BY ... HAVING EXPR
+#[must_use]
+pub fn query_having(
+    result_exprs: Vec<SelectResultExpr>,
+    tab: Box<TableExpression>,
+    where_expr: Option<Box<Expression>>,
+    group_by: Vec<Identifier>,
+    having: Box<Expression>,
+) -> Box<SetExpression> {
+    Box::new(SetExpression::Query {
+        result_exprs,
+        from: vec![tab],
+        where_expr,
+        group_by,
+        having: Some(having),
     })
 }
 
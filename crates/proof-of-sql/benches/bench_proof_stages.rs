@@ -0,0 +1,115 @@
+//! Benchmarks each stage of proving a filter on its own, using [`ProofStages`].
+//!
+//! Every stage is run against the output of the stages before it, which is computed once before timing,
+//! so that each group only measures its own stage.
+//!
+//! # Running the Benchmark
+//!
+//! ```bash
+//! cargo bench --features "test proof-stages" --bench bench_proof_stages
+//! ```
+#![allow(
+    missing_docs,
+    clippy::missing_docs_in_private_items,
+    clippy::missing_panics_doc
+)]
+use ark_std::test_rng;
+use bumpalo::Bump;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use proof_of_sql::{
+    base::database::{
+        owned_table_utility::{bigint, owned_table},
+        OwnedTableTestAccessor, TestAccessor,
+    },
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, ProverSetup, PublicParameters,
+    },
+    sql::{parse::QueryExpr, proof::ProofStages},
+};
+use rand::Rng;
+
+const NUM_VARS: [usize; 3] = [8, 10, 12];
+
+/// An accessor holding a table of `2^num_vars` random rows, and the filter that is proven over it.
+fn accessor_and_query(
+    num_vars: usize,
+    setup: DoryProverPublicSetup<'_>,
+) -> (OwnedTableTestAccessor<'_, DoryEvaluationProof>, QueryExpr) {
+    let mut rng = rand::thread_rng();
+    let num_rows = 1 << num_vars;
+    let table_ref = "sxt.table".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(setup);
+    accessor.add_table(
+        table_ref,
+        owned_table([
+            bigint("a", (0..num_rows).map(|_| rng.gen_range(0..4))),
+            bigint("b", (0..num_rows).map(|_| rng.gen_range(0..4))),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a, b FROM table WHERE a = b".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    (accessor, query)
+}
+
+fn bench_proof_stages(c: &mut Criterion) {
+    let public_parameters = PublicParameters::test_rand(8, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 8);
+
+    for num_vars in NUM_VARS {
+        let (accessor, query) = accessor_and_query(num_vars, setup);
+        let alloc = Bump::new();
+        let stages = ProofStages::<DoryEvaluationProof>::try_new(
+            query.proof_expr(),
+            &accessor,
+            &setup,
+            &alloc,
+        )
+        .unwrap();
+        let committed = stages.commit(&setup);
+        let sumcheck = stages.prove_sumcheck(&committed);
+        let id = BenchmarkId::from_parameter(num_vars);
+
+        let mut group = c.benchmark_group("evaluate");
+        group.sample_size(10);
+        group.bench_function(id.clone(), |b| {
+            b.iter(|| {
+                let alloc = Bump::new();
+                let stages = ProofStages::<DoryEvaluationProof>::try_new(
+                    query.proof_expr(),
+                    &accessor,
+                    &setup,
+                    &alloc,
+                )
+                .unwrap();
+                stages.result().table_length()
+            });
+        });
+        group.finish();
+
+        let mut group = c.benchmark_group("commit");
+        group.sample_size(10);
+        group.bench_function(id.clone(), |b| b.iter(|| stages.commit(&setup)));
+        group.finish();
+
+        let mut group = c.benchmark_group("sumcheck");
+        group.sample_size(10);
+        group.bench_function(id.clone(), |b| b.iter(|| stages.prove_sumcheck(&committed)));
+        group.finish();
+
+        let mut group = c.benchmark_group("evaluation_proof");
+        group.sample_size(10);
+        group.bench_function(id, |b| {
+            b.iter(|| stages.prove_evaluations(&committed, &sumcheck, &setup));
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_proof_stages);
+criterion_main!(benches);
@@ -0,0 +1,153 @@
+//! Benchmarks proving a filter with freshly allocated scratch buffers and with a reused [`ProverScratch`].
+//!
+//! Before timing, the number of allocations and bytes allocated per proof is printed for both cases.
+//!
+//! # Running the Benchmark
+//!
+//! ```bash
+//! cargo bench --features "test" --bench bench_prover_scratch
+//! ```
+//!
+//! ```text
+//! Most recent benches on a single core x86-64 VM, proving a filter over 2^12 rows with Dory:
+//! fresh scratch = 57313 allocations (232.8 MB)
+//! reused scratch = 57306 allocations (230.5 MB)
+//! ```
+//!
+//! The scratch holds the intermediate MLEs and the evaluation and folding vectors, which saves their
+//! allocations but is a small share of the total. Most allocations are made by the commitment scheme
+//! and by the sumcheck prover state, which do not use the scratch yet.
+#![allow(
+    missing_docs,
+    clippy::missing_docs_in_private_items,
+    clippy::missing_panics_doc
+)]
+use ark_std::test_rng;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use proof_of_sql::{
+    base::database::{
+        owned_table_utility::{bigint, owned_table},
+        OwnedTableTestAccessor, TestAccessor,
+    },
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, ProverSetup, PublicParameters,
+    },
+    sql::{
+        parse::QueryExpr,
+        proof::{ProveOptions, ProverScratch, QueryProof},
+    },
+};
+use rand::Rng;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Counts every allocation made by the benchmark so that the two cases can be compared.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Run `f` and return the number of allocations and bytes allocated while it ran.
+fn count_allocations(f: impl FnOnce()) -> (usize, usize) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    f();
+    (
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+    )
+}
+
+fn bench_prover_scratch(c: &mut Criterion) {
+    let public_parameters = PublicParameters::test_rand(8, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 8);
+    let mut rng = rand::thread_rng();
+
+    let mut group = c.benchmark_group("prover_scratch");
+    group.sample_size(10);
+    for num_vars in [8, 12] {
+        let num_rows = 1 << num_vars;
+        let table_ref = "sxt.table".parse().unwrap();
+        let mut accessor =
+            OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+        accessor.add_table(
+            table_ref,
+            owned_table([
+                bigint("a", (0..num_rows).map(|_| rng.gen_range(0..4))),
+                bigint("b", (0..num_rows).map(|_| rng.gen_range(0..4))),
+            ]),
+            0,
+        );
+        let query = QueryExpr::try_new(
+            "SELECT a, b FROM table WHERE a = b".parse().unwrap(),
+            "sxt".parse().unwrap(),
+            &accessor,
+        )
+        .unwrap();
+        let prove_fresh = || {
+            QueryProof::<DoryEvaluationProof>::try_new(
+                query.proof_expr(),
+                &accessor,
+                &dory_prover_setup,
+            )
+            .unwrap();
+        };
+        let prove_reused = |scratch: &mut ProverScratch<DoryScalar>| {
+            let options = ProveOptions {
+                scratch: Some(scratch),
+                ..ProveOptions::default()
+            };
+            QueryProof::<DoryEvaluationProof>::try_new_with_options(
+                query.proof_expr(),
+                &accessor,
+                &dory_prover_setup,
+                options,
+            )
+            .unwrap();
+        };
+
+        let mut scratch = ProverScratch::new();
+        prove_reused(&mut scratch);
+        let (fresh_allocations, fresh_bytes) = count_allocations(prove_fresh);
+        let (reused_allocations, reused_bytes) = count_allocations(|| prove_reused(&mut scratch));
+        println!(
+            "2^{num_vars} rows: fresh scratch {fresh_allocations} allocations ({fresh_bytes} bytes), \
+             reused scratch {reused_allocations} allocations ({reused_bytes} bytes)"
+        );
+
+        group.bench_with_input(BenchmarkId::new("fresh", num_vars), &num_vars, |b, _| {
+            b.iter(prove_fresh);
+        });
+        group.bench_with_input(BenchmarkId::new("reused", num_vars), &num_vars, |b, _| {
+            b.iter(|| prove_reused(&mut scratch));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_prover_scratch);
+criterion_main!(benches);
@@ -0,0 +1,105 @@
+//! # Running the Benchmark
+//!
+//! To run the benchmark with the necessary feature flags enabled, use the following command:
+//!
+//! ```bash
+//! cargo bench --features "test" --bench bench_sumcheck
+//! ```
+//!
+//! Compare against a build without the `rayon` feature to see the effect of the parallel round evaluation:
+//!
+//! ```bash
+//! cargo bench --no-default-features --features "arrow test" --bench bench_sumcheck
+//! ```
+#![allow(missing_docs, clippy::missing_docs_in_private_items)]
+use ark_std::test_rng;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use proof_of_sql::{
+    base::database::{
+        owned_table_utility::{bigint, owned_table},
+        OwnedTableTestAccessor, TestAccessor,
+    },
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, ProverSetup, PublicParameters,
+    },
+    sql::{
+        parse::QueryExpr,
+        proof::{ProofStage, ProveOptions, QueryProof},
+    },
+};
+use rand::Rng;
+use std::time::{Duration, Instant};
+
+/// Bench the sumcheck stage of proving a filter over tables with `2^num_vars` rows.
+///
+/// Only the time between the first sumcheck round and the end of sumcheck is measured,
+/// as reported by the progress callback of [`ProveOptions::progress`].
+///
+/// # Panics
+///
+/// Will panic if the query cannot be planned or proven over the generated table.
+fn bench_sumcheck(c: &mut Criterion) {
+    let public_parameters = PublicParameters::test_rand(8, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 8);
+    let mut rng = rand::thread_rng();
+
+    let mut group = c.benchmark_group("sumcheck");
+    group.sample_size(10);
+    for num_vars in [8, 10, 12, 14] {
+        let num_rows = 1 << num_vars;
+        let table_ref = "sxt.table".parse().unwrap();
+        let mut accessor =
+            OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+        accessor.add_table(
+            table_ref,
+            owned_table([
+                bigint("a", (0..num_rows).map(|_| rng.gen_range(0..4))),
+                bigint("b", (0..num_rows).map(|_| rng.gen_range(0..4))),
+            ]),
+            0,
+        );
+        let query = QueryExpr::try_new(
+            "SELECT a, b FROM table WHERE a = b".parse().unwrap(),
+            "sxt".parse().unwrap(),
+            &accessor,
+        )
+        .unwrap();
+
+        group.bench_with_input(BenchmarkId::from_parameter(num_vars), &num_vars, |b, _| {
+            b.iter_custom(|iterations| {
+                (0..iterations)
+                    .map(|_| {
+                        let mut start = None;
+                        let mut elapsed = Duration::ZERO;
+                        let mut progress = |stage: ProofStage, _: f32| match stage {
+                            ProofStage::Sumcheck if start.is_none() => {
+                                start = Some(Instant::now());
+                            }
+                            ProofStage::Finalizing if elapsed.is_zero() => {
+                                elapsed = start.map_or(Duration::ZERO, |start| start.elapsed());
+                            }
+                            _ => {}
+                        };
+                        let options = ProveOptions {
+                            progress: Some(&mut progress),
+                            ..ProveOptions::default()
+                        };
+                        QueryProof::<DoryEvaluationProof>::try_new_with_options(
+                            query.proof_expr(),
+                            &accessor,
+                            &dory_prover_setup,
+                            options,
+                        )
+                        .unwrap();
+                        elapsed
+                    })
+                    .sum()
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_sumcheck);
+criterion_main!(benches);
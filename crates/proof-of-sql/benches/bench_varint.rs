@@ -0,0 +1,145 @@
+//! Benchmarks decoding `u64` varints with and without the overflow checks,
+//! and encoding and decoding small varints with and without the general loop.
+//!
+//! # Running the Benchmark
+//!
+//! ```bash
+//! cargo bench --bench bench_varint
+//! ```
+//!
+//! ```text
+//! Most recent benches on a single core x86-64 VM, decoding 2^14 varints:
+//! decode_var = 250 µs
+//! decode_var_unchecked = 350 µs
+//!
+//! Encoding and decoding 2^14 values below 2^14:
+//! encode_var = 86 µs
+//! encode_var_small = 39 µs
+//! decode_var = 47 µs
+//! decode_var_small = 57 µs
+//! ```
+//!
+//! On this machine the bounded loop of the checked decoder optimizes better than the unchecked one,
+//! so check that `decode_var_unchecked` is actually faster on the target hardware before using it.
+//! The same holds for `decode_var_small`, while `encode_var_small` is about twice as fast as `encode_var`.
+#![allow(
+    missing_docs,
+    clippy::missing_docs_in_private_items,
+    clippy::missing_panics_doc
+)]
+use ark_std::test_rng;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use proof_of_sql::base::encode::{
+    decode_var_small, decode_var_unchecked, encode_var_small, VarInt, MAX_SMALL_VARINT,
+};
+use rand::Rng;
+
+const NUM_VALUES: usize = 1 << 14;
+
+/// Encode random values of every length back to back, as they appear in serialized data.
+fn encoded_values() -> Vec<u8> {
+    let mut rng = test_rng();
+    let mut bytes = vec![0; NUM_VALUES * 10];
+    let mut length = 0;
+    for _ in 0..NUM_VALUES {
+        let value = rng.gen::<u64>() >> rng.gen_range(0..64);
+        length += value.encode_var(&mut bytes[length..]);
+    }
+    bytes.truncate(length);
+    bytes
+}
+
+fn bench_decode_var(c: &mut Criterion) {
+    let bytes = encoded_values();
+    let mut group = c.benchmark_group("decode u64 varints");
+    group.bench_function("decode_var", |b| {
+        b.iter(|| {
+            let mut src = black_box(&bytes[..]);
+            let mut sum = 0_u64;
+            for _ in 0..NUM_VALUES {
+                let (value, length) = u64::decode_var(src).unwrap();
+                sum = sum.wrapping_add(value);
+                src = &src[length..];
+            }
+            sum
+        });
+    });
+    group.bench_function("decode_var_unchecked", |b| {
+        b.iter(|| {
+            let mut src = black_box(&bytes[..]);
+            let mut sum = 0_u64;
+            for _ in 0..NUM_VALUES {
+                let (value, length) = decode_var_unchecked(src);
+                sum = sum.wrapping_add(value);
+                src = &src[length..];
+            }
+            sum
+        });
+    });
+    group.finish();
+}
+
+/// Random values that fit in a small varint, mostly in one byte, as column indices and enum tags do.
+fn small_values() -> Vec<u64> {
+    let mut rng = test_rng();
+    (0..NUM_VALUES)
+        .map(|_| rng.gen_range(0..=MAX_SMALL_VARINT) >> rng.gen_range(0..8))
+        .collect()
+}
+
+fn bench_small_var(c: &mut Criterion) {
+    let values = small_values();
+    let mut bytes = vec![0; NUM_VALUES * 2];
+    let mut group = c.benchmark_group("small u64 varints");
+    group.bench_function("encode_var", |b| {
+        b.iter(|| {
+            let mut length = 0;
+            for &value in black_box(&values) {
+                length += value.encode_var(&mut bytes[length..]);
+            }
+            length
+        });
+    });
+    group.bench_function("encode_var_small", |b| {
+        b.iter(|| {
+            let mut length = 0;
+            for &value in black_box(&values) {
+                length += encode_var_small(value, &mut bytes[length..]);
+            }
+            length
+        });
+    });
+    let mut length = 0;
+    for &value in &values {
+        length += value.encode_var(&mut bytes[length..]);
+    }
+    bytes.truncate(length);
+    group.bench_function("decode_var", |b| {
+        b.iter(|| {
+            let mut src = black_box(&bytes[..]);
+            let mut sum = 0_u64;
+            for _ in 0..NUM_VALUES {
+                let (value, length) = u64::decode_var(src).unwrap();
+                sum = sum.wrapping_add(value);
+                src = &src[length..];
+            }
+            sum
+        });
+    });
+    group.bench_function("decode_var_small", |b| {
+        b.iter(|| {
+            let mut src = black_box(&bytes[..]);
+            let mut sum = 0_u64;
+            for _ in 0..NUM_VALUES {
+                let (value, length) = decode_var_small(src).unwrap();
+                sum = sum.wrapping_add(value);
+                src = &src[length..];
+            }
+            sum
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_decode_var, bench_small_var);
+criterion_main!(benches);
@@ -2,9 +2,9 @@ use super::scalar_and_i256_conversions::convert_i256_to_scalar;
 use crate::base::{database::Column, math::decimal::Precision, scalar::Scalar};
 use arrow::{
     array::{
-        Array, ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, StringArray, TimestampMicrosecondArray, TimestampMillisecondArray,
-        TimestampNanosecondArray, TimestampSecondArray,
+        Array, ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, FixedSizeBinaryArray,
+        Int16Array, Int32Array, Int64Array, Int8Array, StringArray, TimestampMicrosecondArray,
+        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt64Array,
     },
     datatypes::{i256, DataType, TimeUnit as ArrowTimeUnit},
 };
@@ -94,6 +94,7 @@ impl ArrayRefExt for ArrayRef {
     /// - Decimal256, converts arrow i256 columns into Decimal75(precision, scale) columns.
     /// - For `DataType::Utf8`, it extracts string values and scalar values (if `precomputed_scals`
     ///   is provided) for the specified range and returns a `VarChar` column.
+    /// - For `DataType::FixedSizeBinary`, it does the same with byte values and returns a `FixedBytes` column.
     ///
     /// # Panics
     /// - When any range is OOB, i.e. indexing 3..6 or 5..5 on array of size 2.
@@ -170,6 +171,15 @@ impl ArrayRefExt for ArrayRef {
                     })
                 }
             }
+            DataType::UInt64 => {
+                if let Some(array) = self.as_any().downcast_ref::<UInt64Array>() {
+                    Ok(Column::UInt64(&array.values()[range.start..range.end]))
+                } else {
+                    Err(ArrowArrayToColumnConversionError::UnsupportedType {
+                        datatype: self.data_type().clone(),
+                    })
+                }
+            }
             DataType::Decimal128(38, 0) => {
                 if let Some(array) = self.as_any().downcast_ref::<Decimal128Array>() {
                     Ok(Column::Int128(&array.values()[range.start..range.end]))
@@ -276,6 +286,30 @@ impl ArrayRefExt for ArrayRef {
                     })
                 }
             }
+            DataType::FixedSizeBinary(width) => {
+                match (
+                    self.as_any().downcast_ref::<FixedSizeBinaryArray>(),
+                    u8::try_from(*width),
+                ) {
+                    (Some(array), Ok(width)) => {
+                        let vals = alloc
+                            .alloc_slice_fill_with(range.end - range.start, |i| -> &'a [u8] {
+                                array.value(range.start + i)
+                            });
+
+                        let scals = if let Some(scals) = precomputed_scals {
+                            &scals[range.start..range.end]
+                        } else {
+                            alloc.alloc_slice_fill_with(vals.len(), |i| -> S { vals[i].into() })
+                        };
+
+                        Ok(Column::FixedBytes(width, (vals, scals)))
+                    }
+                    _ => Err(ArrowArrayToColumnConversionError::UnsupportedType {
+                        datatype: self.data_type().clone(),
+                    }),
+                }
+            }
             data_type => Err(ArrowArrayToColumnConversionError::UnsupportedType {
                 datatype: data_type.clone(),
             }),
@@ -15,11 +15,13 @@ impl From<&ColumnType> for DataType {
             ColumnType::SmallInt => DataType::Int16,
             ColumnType::Int => DataType::Int32,
             ColumnType::BigInt => DataType::Int64,
+            ColumnType::UInt64 => DataType::UInt64,
             ColumnType::Int128 => DataType::Decimal128(38, 0),
             ColumnType::Decimal75(precision, scale) => {
                 DataType::Decimal256(precision.value(), *scale)
             }
             ColumnType::VarChar => DataType::Utf8,
+            ColumnType::FixedBytes(width) => DataType::FixedSizeBinary((*width).into()),
             ColumnType::Scalar => unimplemented!("Cannot convert Scalar type to arrow type"),
             ColumnType::TimestampTZ(timeunit, timezone) => {
                 let arrow_timezone = Some(Arc::from(timezone.to_string()));
@@ -46,6 +48,7 @@ impl TryFrom<DataType> for ColumnType {
             DataType::Int16 => Ok(ColumnType::SmallInt),
             DataType::Int32 => Ok(ColumnType::Int),
             DataType::Int64 => Ok(ColumnType::BigInt),
+            DataType::UInt64 => Ok(ColumnType::UInt64),
             DataType::Decimal128(38, 0) => Ok(ColumnType::Int128),
             DataType::Decimal256(precision, scale) if precision <= 75 => {
                 Ok(ColumnType::Decimal75(Precision::new(precision)?, scale))
@@ -63,6 +66,9 @@ impl TryFrom<DataType> for ColumnType {
                 ))
             }
             DataType::Utf8 => Ok(ColumnType::VarChar),
+            DataType::FixedSizeBinary(width) => u8::try_from(width)
+                .map(ColumnType::FixedBytes)
+                .map_err(|_| format!("Unsupported arrow data type {data_type:?}")),
             _ => Err(format!("Unsupported arrow data type {data_type:?}")),
         }
     }
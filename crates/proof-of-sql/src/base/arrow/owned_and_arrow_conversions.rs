@@ -4,7 +4,9 @@
 //! `OwnedTable` <-> `RecordBatch`
 //! `Boolean` <-> `Boolean`
 //! `BigInt` <-> `Int64`
+//! `UInt64` <-> `UInt64`
 //! `VarChar` <-> `Utf8/String`
+//! `FixedBytes(n)` <-> `FixedSizeBinary(n)`
 //! `Int128` <-> `Decimal128(38,0)`
 //! `Decimal75` <-> `S`
 //!
@@ -22,10 +24,11 @@ use crate::base::{
 use alloc::sync::Arc;
 use arrow::{
     array::{
-        ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, StringArray, TimestampMicrosecondArray, TimestampMillisecondArray,
-        TimestampNanosecondArray, TimestampSecondArray,
+        ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, FixedSizeBinaryArray, Int16Array,
+        Int32Array, Int64Array, Int8Array, StringArray, TimestampMicrosecondArray,
+        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt64Array,
     },
+    buffer::Buffer,
     datatypes::{i256, DataType, Schema, SchemaRef, TimeUnit as ArrowTimeUnit},
     error::ArrowError,
     record_batch::RecordBatch,
@@ -87,6 +90,7 @@ impl<S: Scalar> From<OwnedColumn<S>> for ArrayRef {
             OwnedColumn::SmallInt(col) => Arc::new(Int16Array::from(col)),
             OwnedColumn::Int(col) => Arc::new(Int32Array::from(col)),
             OwnedColumn::BigInt(col) => Arc::new(Int64Array::from(col)),
+            OwnedColumn::UInt64(col) => Arc::new(UInt64Array::from(col)),
             OwnedColumn::Int128(col) => Arc::new(
                 Decimal128Array::from(col)
                     .with_precision_and_scale(38, 0)
@@ -103,6 +107,11 @@ impl<S: Scalar> From<OwnedColumn<S>> for ArrayRef {
             }
             OwnedColumn::Scalar(_) => unimplemented!("Cannot convert Scalar type to arrow type"),
             OwnedColumn::VarChar(col) => Arc::new(StringArray::from(col)),
+            OwnedColumn::FixedBytes(width, col) => Arc::new(FixedSizeBinaryArray::new(
+                width.into(),
+                Buffer::from_vec(col.concat()),
+                None,
+            )),
             OwnedColumn::TimestampTZ(time_unit, _, col) => match time_unit {
                 PoSQLTimeUnit::Second => Arc::new(TimestampSecondArray::from(col)),
                 PoSQLTimeUnit::Millisecond => Arc::new(TimestampMillisecondArray::from(col)),
@@ -146,9 +155,11 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
     /// - `Int16Array` when converting from `DataType::Int16`.
     /// - `Int32Array` when converting from `DataType::Int32`.
     /// - `Int64Array` when converting from `DataType::Int64`.
+    /// - `UInt64Array` when converting from `DataType::UInt64`.
     /// - `Decimal128Array` when converting from `DataType::Decimal128(38, 0)`.
     /// - `Decimal256Array` when converting from `DataType::Decimal256` if precision is less than or equal to 75.
     /// - `StringArray` when converting from `DataType::Utf8`.
+    /// - `FixedSizeBinaryArray` when converting from `DataType::FixedSizeBinary`.
     fn try_from(value: &ArrayRef) -> Result<Self, Self::Error> {
         match &value.data_type() {
             // Arrow uses a bit-packed representation for booleans.
@@ -194,6 +205,14 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
                     .values()
                     .to_vec(),
             )),
+            DataType::UInt64 => Ok(Self::UInt64(
+                value
+                    .as_any()
+                    .downcast_ref::<UInt64Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec(),
+            )),
             DataType::Decimal128(38, 0) => Ok(Self::Int128(
                 value
                     .as_any()
@@ -224,6 +243,19 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
                     .map(|s| s.unwrap().to_string())
                     .collect(),
             )),
+            DataType::FixedSizeBinary(width) => Ok(Self::FixedBytes(
+                u8::try_from(*width).map_err(|_| OwnedArrowConversionError::UnsupportedType {
+                    datatype: value.data_type().clone(),
+                })?,
+                value
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap()
+                    .iter()
+                    .map(|bytes| bytes.map(<[u8]>::to_vec))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or(OwnedArrowConversionError::NullNotSupportedYet)?,
+            )),
             DataType::Timestamp(time_unit, timezone) => match time_unit {
                 ArrowTimeUnit::Second => {
                     let array = value
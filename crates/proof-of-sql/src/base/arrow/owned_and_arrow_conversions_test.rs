@@ -9,7 +9,10 @@ use crate::{
 };
 use alloc::sync::Arc;
 use arrow::{
-    array::{ArrayRef, BooleanArray, Decimal128Array, Float32Array, Int64Array, StringArray},
+    array::{
+        ArrayRef, BooleanArray, Decimal128Array, FixedSizeBinaryArray, Float32Array, Int64Array,
+        StringArray,
+    },
     datatypes::Schema,
     record_batch::RecordBatch,
 };
@@ -70,6 +73,15 @@ fn we_can_convert_between_owned_column_and_array_ref() {
     );
 }
 
+#[test]
+fn we_can_convert_between_fixed_bytes_owned_column_and_array_ref() {
+    let data = vec![vec![0_u8, 1, 2], vec![255, 254, 253]];
+    we_can_convert_between_owned_column_and_array_ref_impl(
+        &OwnedColumn::<TestScalar>::FixedBytes(3, data.clone()),
+        Arc::new(FixedSizeBinaryArray::try_from_iter(data.into_iter()).unwrap()),
+    );
+}
+
 #[test]
 fn we_get_an_unsupported_type_error_when_trying_to_convert_from_a_float32_array_ref_to_an_owned_column(
 ) {
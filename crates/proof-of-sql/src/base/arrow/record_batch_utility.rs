@@ -110,6 +110,12 @@ int_to_arrow_array!(
     arrow::array::Int64Array
 );
 
+int_to_arrow_array!(
+    u64,
+    arrow::datatypes::DataType::UInt64,
+    arrow::array::UInt64Array
+);
+
 impl ToArrow for Vec<i128> {
     fn to_type(&self) -> arrow::datatypes::DataType {
         arrow::datatypes::DataType::Decimal128(38, 0)
@@ -142,4 +142,23 @@ impl BitDistribution {
         }
         panic!("no bits are set");
     }
+
+    /// Check if every absolute value is less than `2^num_bits`, that is,
+    /// no constant or varying bit of the absolute values is at position `num_bits` or above.
+    pub fn abs_values_fit_in_bits(&self, num_bits: usize) -> bool {
+        (0..4).all(|i| {
+            let mut bits = self.or_all[i] | self.vary_mask[i];
+            if i == 3 {
+                bits &= !(1 << 63);
+            }
+            let first_bit = 64 * i;
+            if num_bits <= first_bit {
+                bits == 0
+            } else if num_bits - first_bit >= 64 {
+                true
+            } else {
+                bits >> (num_bits - first_bit) == 0
+            }
+        })
+    }
 }
@@ -289,3 +289,31 @@ fn we_can_detect_invalid_bit_distributions() {
     };
     assert!(!dist.is_valid());
 }
+
+#[test]
+fn we_can_check_whether_the_absolute_values_fit_in_a_number_of_bits() {
+    let data: Vec<i64> = vec![-(1 << 62), 3, i64::MAX];
+    let dist = BitDistribution::new::<TestScalar, _>(&data);
+    assert!(dist.abs_values_fit_in_bits(63));
+    assert!(dist.abs_values_fit_in_bits(64));
+    assert!(dist.abs_values_fit_in_bits(256));
+    assert!(!dist.abs_values_fit_in_bits(62));
+    assert!(!dist.abs_values_fit_in_bits(0));
+
+    let data: Vec<i128> = vec![1, 1 << 100];
+    let dist = BitDistribution::new::<TestScalar, _>(&data);
+    assert!(dist.abs_values_fit_in_bits(101));
+    assert!(!dist.abs_values_fit_in_bits(100));
+    assert!(!dist.abs_values_fit_in_bits(64));
+
+    let data: Vec<i64> = vec![0, 0];
+    let dist = BitDistribution::new::<TestScalar, _>(&data);
+    assert!(dist.abs_values_fit_in_bits(0));
+
+    let dist = BitDistribution {
+        or_all: [0, 0, 0, 1 << 11],
+        vary_mask: [0, 0, 0, 0],
+    };
+    assert!(dist.abs_values_fit_in_bits(64 * 3 + 12));
+    assert!(!dist.abs_values_fit_in_bits(64 * 3 + 11));
+}
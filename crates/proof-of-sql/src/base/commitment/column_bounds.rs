@@ -211,6 +211,8 @@ pub enum ColumnBounds {
     Int(Bounds<i32>),
     /// The bounds of a `BigInt` column.
     BigInt(Bounds<i64>),
+    /// The bounds of a `UInt64` column.
+    UInt64(Bounds<u64>),
     /// The bounds of an Int128 column.
     Int128(Bounds<i128>),
     /// The bounds of a Timestamp column.
@@ -228,6 +230,7 @@ impl ColumnBounds {
             CommittableColumn::SmallInt(ints) => ColumnBounds::SmallInt(Bounds::from_iter(*ints)),
             CommittableColumn::Int(ints) => ColumnBounds::Int(Bounds::from_iter(*ints)),
             CommittableColumn::BigInt(ints) => ColumnBounds::BigInt(Bounds::from_iter(*ints)),
+            CommittableColumn::UInt64(ints) => ColumnBounds::UInt64(Bounds::from_iter(*ints)),
             CommittableColumn::Int128(ints) => ColumnBounds::Int128(Bounds::from_iter(*ints)),
             CommittableColumn::TimestampTZ(_, _, times) => {
                 ColumnBounds::TimestampTZ(Bounds::from_iter(*times))
@@ -236,6 +239,7 @@ impl ColumnBounds {
             | CommittableColumn::Decimal75(_, _, _)
             | CommittableColumn::Scalar(_)
             | CommittableColumn::VarChar(_)
+            | CommittableColumn::FixedBytes(_, _)
             | CommittableColumn::RangeCheckWord(_) => ColumnBounds::NoOrder,
         }
     }
@@ -258,6 +262,9 @@ impl ColumnBounds {
             (ColumnBounds::BigInt(bounds_a), ColumnBounds::BigInt(bounds_b)) => {
                 Ok(ColumnBounds::BigInt(bounds_a.union(bounds_b)))
             }
+            (ColumnBounds::UInt64(bounds_a), ColumnBounds::UInt64(bounds_b)) => {
+                Ok(ColumnBounds::UInt64(bounds_a.union(bounds_b)))
+            }
             (ColumnBounds::TimestampTZ(bounds_a), ColumnBounds::TimestampTZ(bounds_b)) => {
                 Ok(ColumnBounds::TimestampTZ(bounds_a.union(bounds_b)))
             }
@@ -290,6 +297,9 @@ impl ColumnBounds {
             (ColumnBounds::BigInt(bounds_a), ColumnBounds::BigInt(bounds_b)) => {
                 Ok(ColumnBounds::BigInt(bounds_a.difference(bounds_b)))
             }
+            (ColumnBounds::UInt64(bounds_a), ColumnBounds::UInt64(bounds_b)) => {
+                Ok(ColumnBounds::UInt64(bounds_a.difference(bounds_b)))
+            }
             (ColumnBounds::Int128(bounds_a), ColumnBounds::Int128(bounds_b)) => {
                 Ok(ColumnBounds::Int128(bounds_a.difference(bounds_b)))
             }
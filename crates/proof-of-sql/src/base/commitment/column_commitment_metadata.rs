@@ -48,11 +48,13 @@ impl ColumnCommitmentMetadata {
             | (ColumnType::SmallInt, ColumnBounds::SmallInt(_))
             | (ColumnType::Int, ColumnBounds::Int(_))
             | (ColumnType::BigInt, ColumnBounds::BigInt(_))
+            | (ColumnType::UInt64, ColumnBounds::UInt64(_))
             | (ColumnType::Int128, ColumnBounds::Int128(_))
             | (ColumnType::TimestampTZ(_, _), ColumnBounds::TimestampTZ(_))
             | (
                 ColumnType::Boolean
                 | ColumnType::VarChar
+                | ColumnType::FixedBytes(_)
                 | ColumnType::Scalar
                 | ColumnType::Decimal75(..),
                 ColumnBounds::NoOrder,
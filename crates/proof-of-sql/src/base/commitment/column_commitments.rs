@@ -1,6 +1,7 @@
 use super::{
     committable_column::CommittableColumn, ColumnCommitmentMetadata, ColumnCommitmentMetadataMap,
-    ColumnCommitmentMetadataMapExt, ColumnCommitmentsMismatch, Commitment, VecCommitmentExt,
+    ColumnCommitmentMetadataMapExt, ColumnCommitmentsMismatch, Commitment, TaggedCommitment,
+    VecCommitmentExt,
 };
 use crate::base::{
     database::{ColumnField, ColumnRef, CommitmentAccessor, TableRef},
@@ -51,21 +52,35 @@ pub struct ColumnCommitments<C> {
 
 impl<C: Commitment> ColumnCommitments<C> {
     /// Create a new [`ColumnCommitments`] for a table from a commitment accessor.
+    ///
+    /// Will error if the provided columns have duplicate identifiers.
+    /// No commitments are fetched from the accessor in that case.
     pub fn from_accessor_with_max_bounds(
         table: TableRef,
         columns: &[ColumnField],
         accessor: &impl CommitmentAccessor<C>,
-    ) -> Self {
+    ) -> Result<Self, DuplicateIdentifiers> {
+        // Check for duplicate identifiers
+        let mut unique_identifiers = IndexSet::default();
+        if let Some(duplicate) = columns
+            .iter()
+            .find(|field| !unique_identifiers.insert(field.name()))
+        {
+            return Err(DuplicateIdentifiers {
+                id: duplicate.name().to_string(),
+            });
+        }
+
         let column_metadata =
             ColumnCommitmentMetadataMap::from_column_fields_with_max_bounds(columns);
         let commitments = columns
             .iter()
             .map(|c| accessor.get_commitment(ColumnRef::new(table, c.name(), c.data_type())))
             .collect();
-        ColumnCommitments {
+        Ok(ColumnCommitments {
             commitments,
             column_metadata,
-        }
+        })
     }
 
     #[cfg(test)]
@@ -111,6 +126,19 @@ impl<C: Commitment> ColumnCommitments<C> {
         self.column_metadata.get(identifier)
     }
 
+    /// Returns the commitment with the given identifier, tagged with the type in its metadata.
+    #[must_use]
+    pub fn get_tagged_commitment(&self, identifier: &Identifier) -> Option<TaggedCommitment<C>>
+    where
+        C: Serialize,
+    {
+        self.column_metadata
+            .get_full(identifier)
+            .map(|(index, _, metadata)| {
+                TaggedCommitment::new(*metadata.column_type(), self.commitments[index].clone())
+            })
+    }
+
     /// Iterate over the metadata and commitments by reference.
     pub fn iter(&self) -> Iter<C> {
         self.into_iter()
@@ -355,8 +383,14 @@ impl<C> FromIterator<(Identifier, ColumnCommitmentMetadata, C)> for ColumnCommit
 mod tests {
     use super::*;
     use crate::base::{
-        commitment::{column_bounds::Bounds, naive_commitment::NaiveCommitment, ColumnBounds},
-        database::{owned_table_utility::*, ColumnType, OwnedColumn, OwnedTable},
+        commitment::{
+            column_bounds::Bounds, naive_commitment::NaiveCommitment,
+            test_evaluation_proof::TestEvaluationProof, ColumnBounds,
+        },
+        database::{
+            owned_table_utility::*, ColumnType, OwnedColumn, OwnedTable, OwnedTableTestAccessor,
+            TestAccessor,
+        },
         scalar::test_scalar::TestScalar,
     };
 
@@ -546,6 +580,40 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn we_cannot_construct_commitments_from_accessor_with_duplicate_identifiers() {
+        let table_ref = "sxt.table".parse().unwrap();
+        let mut accessor = OwnedTableTestAccessor::<TestEvaluationProof>::new_empty_with_setup(());
+        accessor.add_table(
+            table_ref,
+            owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])]),
+            0,
+        );
+
+        let columns = [
+            ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+            ColumnField::new("b".parse().unwrap(), ColumnType::VarChar),
+            ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+        ];
+        let err = ColumnCommitments::<NaiveCommitment>::from_accessor_with_max_bounds(
+            table_ref, &columns, &accessor,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot create commitments with duplicate identifier: a"
+        );
+
+        let column_commitments =
+            ColumnCommitments::<NaiveCommitment>::from_accessor_with_max_bounds(
+                table_ref,
+                &columns[..2],
+                &accessor,
+            )
+            .unwrap();
+        assert_eq!(column_commitments.len(), 2);
+    }
+
     #[test]
     fn we_can_iterate_over_column_commitments() {
         let bigint_id: Identifier = "bigint_column".parse().unwrap();
@@ -33,6 +33,8 @@ pub enum CommittableColumn<'a> {
     Int(&'a [i32]),
     /// Borrowed `BigInt` column, mapped to `i64`.
     BigInt(&'a [i64]),
+    /// Borrowed `UInt64` column, mapped to `u64`.
+    UInt64(&'a [u64]),
     /// Borrowed Int128 column, mapped to `i128`.
     Int128(&'a [i128]),
     /// Borrowed Decimal75(precion, scale, column), mapped to 'i256'
@@ -41,6 +43,8 @@ pub enum CommittableColumn<'a> {
     Scalar(Vec<[u64; 4]>),
     /// Column of limbs for committing to scalars, hashed from a `VarChar` column.
     VarChar(Vec<[u64; 4]>),
+    /// Column of limbs for committing to scalars, hashed from a `FixedBytes` column of the given width.
+    FixedBytes(u8, Vec<[u64; 4]>),
     /// Borrowed Timestamp column with Timezone, mapped to `i64`.
     TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, &'a [i64]),
     /// Borrowed byte column, mapped to `u8`. This is not a `PoSQL`
@@ -57,10 +61,12 @@ impl<'a> CommittableColumn<'a> {
             CommittableColumn::SmallInt(col) => col.len(),
             CommittableColumn::Int(col) => col.len(),
             CommittableColumn::BigInt(col) | CommittableColumn::TimestampTZ(_, _, col) => col.len(),
+            CommittableColumn::UInt64(col) => col.len(),
             CommittableColumn::Int128(col) => col.len(),
             CommittableColumn::Decimal75(_, _, col)
             | CommittableColumn::Scalar(col)
-            | CommittableColumn::VarChar(col) => col.len(),
+            | CommittableColumn::VarChar(col)
+            | CommittableColumn::FixedBytes(_, col) => col.len(),
             CommittableColumn::Boolean(col) => col.len(),
             CommittableColumn::RangeCheckWord(col) => col.len(),
         }
@@ -86,12 +92,14 @@ impl<'a> From<&CommittableColumn<'a>> for ColumnType {
             CommittableColumn::SmallInt(_) => ColumnType::SmallInt,
             CommittableColumn::Int(_) => ColumnType::Int,
             CommittableColumn::BigInt(_) => ColumnType::BigInt,
+            CommittableColumn::UInt64(_) => ColumnType::UInt64,
             CommittableColumn::Int128(_) => ColumnType::Int128,
             CommittableColumn::Decimal75(precision, scale, _) => {
                 ColumnType::Decimal75(*precision, *scale)
             }
             CommittableColumn::Scalar(_) => ColumnType::Scalar,
             CommittableColumn::VarChar(_) => ColumnType::VarChar,
+            CommittableColumn::FixedBytes(width, _) => ColumnType::FixedBytes(*width),
             CommittableColumn::Boolean(_) => ColumnType::Boolean,
             CommittableColumn::TimestampTZ(tu, tz, _) => ColumnType::TimestampTZ(*tu, *tz),
             CommittableColumn::RangeCheckWord(_) => {
@@ -109,6 +117,7 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for CommittableColumn<'a> {
             Column::SmallInt(ints) => CommittableColumn::SmallInt(ints),
             Column::Int(ints) => CommittableColumn::Int(ints),
             Column::BigInt(ints) => CommittableColumn::BigInt(ints),
+            Column::UInt64(ints) => CommittableColumn::UInt64(ints),
             Column::Int128(ints) => CommittableColumn::Int128(ints),
             Column::Decimal75(precision, scale, decimals) => {
                 let as_limbs: Vec<_> = decimals.iter().map(RefInto::<[u64; 4]>::ref_into).collect();
@@ -119,6 +128,10 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for CommittableColumn<'a> {
                 let as_limbs: Vec<_> = scalars.iter().map(RefInto::<[u64; 4]>::ref_into).collect();
                 CommittableColumn::VarChar(as_limbs)
             }
+            Column::FixedBytes(width, (_, scalars)) => {
+                let as_limbs: Vec<_> = scalars.iter().map(RefInto::<[u64; 4]>::ref_into).collect();
+                CommittableColumn::FixedBytes(*width, as_limbs)
+            }
             Column::TimestampTZ(tu, tz, times) => CommittableColumn::TimestampTZ(*tu, *tz, times),
         }
     }
@@ -138,6 +151,7 @@ impl<'a, S: Scalar> From<&'a OwnedColumn<S>> for CommittableColumn<'a> {
             OwnedColumn::SmallInt(ints) => (ints as &[_]).into(),
             OwnedColumn::Int(ints) => (ints as &[_]).into(),
             OwnedColumn::BigInt(ints) => (ints as &[_]).into(),
+            OwnedColumn::UInt64(ints) => (ints as &[_]).into(),
             OwnedColumn::Int128(ints) => (ints as &[_]).into(),
             OwnedColumn::Decimal75(precision, scale, decimals) => CommittableColumn::Decimal75(
                 *precision,
@@ -159,6 +173,14 @@ impl<'a, S: Scalar> From<&'a OwnedColumn<S>> for CommittableColumn<'a> {
             OwnedColumn::TimestampTZ(tu, tz, times) => {
                 CommittableColumn::TimestampTZ(*tu, *tz, times as &[_])
             }
+            OwnedColumn::FixedBytes(width, values) => CommittableColumn::FixedBytes(
+                *width,
+                values
+                    .iter()
+                    .map(|bytes| S::from(bytes.as_slice()))
+                    .map(Into::<[u64; 4]>::into)
+                    .collect(),
+            ),
         }
     }
 }
@@ -190,6 +212,12 @@ impl<'a> From<&'a [i64]> for CommittableColumn<'a> {
     }
 }
 
+impl<'a> From<&'a [u64]> for CommittableColumn<'a> {
+    fn from(value: &'a [u64]) -> Self {
+        CommittableColumn::UInt64(value)
+    }
+}
+
 impl<'a> From<&'a [i128]> for CommittableColumn<'a> {
     fn from(value: &'a [i128]) -> Self {
         CommittableColumn::Int128(value)
@@ -214,10 +242,12 @@ impl<'a, 'b> From<&'a CommittableColumn<'b>> for Sequence<'a> {
             CommittableColumn::SmallInt(ints) => Sequence::from(*ints),
             CommittableColumn::Int(ints) => Sequence::from(*ints),
             CommittableColumn::BigInt(ints) => Sequence::from(*ints),
+            CommittableColumn::UInt64(ints) => Sequence::from(*ints),
             CommittableColumn::Int128(ints) => Sequence::from(*ints),
             CommittableColumn::Decimal75(_, _, limbs)
             | CommittableColumn::Scalar(limbs)
-            | CommittableColumn::VarChar(limbs) => Sequence::from(limbs),
+            | CommittableColumn::VarChar(limbs)
+            | CommittableColumn::FixedBytes(_, limbs) => Sequence::from(limbs),
             CommittableColumn::Boolean(bools) => Sequence::from(*bools),
             CommittableColumn::TimestampTZ(_, _, times) => Sequence::from(*times),
             CommittableColumn::RangeCheckWord(words) => Sequence::from(*words),
@@ -0,0 +1,120 @@
+use super::{Commitment, CommittableColumn};
+use alloc::vec;
+
+/// The number of rows of the all-ones column that is materialized at a time
+const ONES_CHUNK_LENGTH: usize = 1 << 12;
+
+/// Compute the commitment to a column of `length` rows that are all `value`, using the given generator offset.
+///
+/// This is identical to the commitment to the materialized column, but only a bounded chunk of rows is ever materialized.
+/// The column is `value` times the all-ones column, and the commitment to the all-ones column is the sum of
+/// the generators of its rows. So the commitment is `value * sum(generators[offset..offset + length])`,
+/// where the sum is built up from the commitments to chunks of ones.
+#[must_use]
+pub fn commit_constant_column<C: Commitment>(
+    value: C::Scalar,
+    length: usize,
+    offset: usize,
+    setup: &C::PublicSetup<'_>,
+) -> C {
+    commit_constant_column_in_chunks(value, length, offset, setup, ONES_CHUNK_LENGTH)
+}
+
+fn commit_constant_column_in_chunks<C: Commitment>(
+    value: C::Scalar,
+    length: usize,
+    offset: usize,
+    setup: &C::PublicSetup<'_>,
+    chunk_length: usize,
+) -> C {
+    let ones = vec![true; length.min(chunk_length)];
+    let sum_of_generators = (0..length)
+        .step_by(chunk_length)
+        .flat_map(|start| {
+            C::compute_commitments(
+                &[CommittableColumn::Boolean(
+                    &ones[..chunk_length.min(length - start)],
+                )],
+                offset + start,
+                setup,
+            )
+        })
+        .fold(C::default(), |mut commitment, chunk_commitment| {
+            commitment += chunk_commitment;
+            commitment
+        });
+    value * sum_of_generators
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::scalar::Scalar,
+        proof_primitive::dory::{
+            test_rng, DoryCommitment, DoryProverPublicSetup, DoryScalar, ProverSetup,
+            PublicParameters,
+        },
+    };
+
+    #[test]
+    fn constant_and_materialized_columns_have_the_same_commitments() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+        for value in [0_i64, 1, -7, i64::MAX] {
+            for length in [0, 1, 5, 13] {
+                for offset in [0, 3] {
+                    let materialized = DoryCommitment::compute_commitments(
+                        &[CommittableColumn::BigInt(&vec![value; length])],
+                        offset,
+                        &dory_prover_setup,
+                    )
+                    .pop()
+                    .unwrap();
+                    let value = DoryScalar::from(value);
+                    assert_eq!(
+                        commit_constant_column::<DoryCommitment>(
+                            value,
+                            length,
+                            offset,
+                            &dory_prover_setup
+                        ),
+                        materialized
+                    );
+                    for chunk_length in [1, 4] {
+                        assert_eq!(
+                            commit_constant_column_in_chunks::<DoryCommitment>(
+                                value,
+                                length,
+                                offset,
+                                &dory_prover_setup,
+                                chunk_length,
+                            ),
+                            materialized
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn a_constant_decimal_column_has_the_commitment_of_its_scalars() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+        let value = -DoryScalar::from(u128::MAX) * DoryScalar::TWO;
+        let materialized = DoryCommitment::compute_commitments(
+            &[CommittableColumn::Scalar(vec![value.into(); 6])],
+            2,
+            &dory_prover_setup,
+        )
+        .pop()
+        .unwrap();
+        assert_eq!(
+            commit_constant_column::<DoryCommitment>(value, 6, 2, &dory_prover_setup),
+            materialized
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use super::{Commitment, CommittableColumn};
+use crate::base::{
+    database::{OwnedTable, TableRef},
+    map::IndexMap,
+    scalar::Scalar,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::ops::Range;
+use proof_of_sql_parser::Identifier;
+use snafu::Snafu;
+
+/// Errors from laying out columns in a shared generator space.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum GeneratorLayoutError {
+    /// There is not exactly one generator offset per column.
+    #[snafu(display("expected {expected} generator offsets, one per column, but got {actual}"))]
+    GeneratorOffsetCountMismatch {
+        /// The number of columns
+        expected: usize,
+        /// The number of generator offsets
+        actual: usize,
+    },
+    /// Two columns use some of the same generators.
+    #[snafu(display("the generators of columns {first} and {second} overlap"))]
+    OverlappingGenerators {
+        /// The index of the column with the lower generator offset
+        first: usize,
+        /// The index of the other column
+        second: usize,
+    },
+    /// The generators of a column extend past `usize::MAX`.
+    #[snafu(display("the generators of a column extend past usize::MAX"))]
+    GeneratorOffsetOverflow,
+    /// A column was added to a [`GeneratorLayout`] more than once.
+    #[snafu(display("column {column} of table {table_ref} is already laid out"))]
+    DuplicateColumn {
+        /// The table of the column
+        table_ref: Box<TableRef>,
+        /// The name of the column
+        column: Identifier,
+    },
+}
+
+/// The range of generators used by each column, in the order of the columns.
+fn generator_ranges(
+    lengths: impl IntoIterator<Item = usize>,
+    generator_offsets: &[usize],
+) -> Result<Vec<Range<usize>>, GeneratorLayoutError> {
+    let lengths: Vec<usize> = lengths.into_iter().collect();
+    if lengths.len() != generator_offsets.len() {
+        return Err(GeneratorLayoutError::GeneratorOffsetCountMismatch {
+            expected: lengths.len(),
+            actual: generator_offsets.len(),
+        });
+    }
+    lengths
+        .into_iter()
+        .zip(generator_offsets)
+        .map(|(length, &offset)| {
+            let end = offset
+                .checked_add(length)
+                .ok_or(GeneratorLayoutError::GeneratorOffsetOverflow)?;
+            Ok(offset..end)
+        })
+        .collect()
+}
+
+/// Check that no two of the columns with the given generator ranges use the same generator.
+///
+/// Empty columns use no generators, so they never overlap.
+fn check_generators_do_not_overlap(ranges: &[Range<usize>]) -> Result<(), GeneratorLayoutError> {
+    let mut indexes: Vec<usize> = (0..ranges.len())
+        .filter(|&i| !ranges[i].is_empty())
+        .collect();
+    indexes.sort_unstable_by_key(|&i| (ranges[i].start, i));
+    match indexes
+        .windows(2)
+        .find(|pair| ranges[pair[0]].end > ranges[pair[1]].start)
+    {
+        Some(pair) => Err(GeneratorLayoutError::OverlappingGenerators {
+            first: pair[0],
+            second: pair[1],
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Compute the commitments for the given columns, each starting at its own generator offset.
+///
+/// This is [`Commitment::compute_commitments`] with a separate offset for every column,
+/// so the commitment to each column is the one `compute_commitments` computes with its offset.
+/// The columns may have different lengths. No two columns may use the same generator,
+/// since commitments that share generators interfere when they are combined.
+/// Use a [`GeneratorLayout`] to assign offsets that do not overlap.
+///
+/// # Errors
+/// Returns an error if there is not exactly one offset per column or if the generators of two columns overlap.
+pub fn compute_commitments_with_generator_offsets<C: Commitment>(
+    committable_columns: &[CommittableColumn],
+    generator_offsets: &[usize],
+    setup: &C::PublicSetup<'_>,
+) -> Result<Vec<C>, GeneratorLayoutError> {
+    let ranges = generator_ranges(
+        committable_columns.iter().map(CommittableColumn::len),
+        generator_offsets,
+    )?;
+    check_generators_do_not_overlap(&ranges)?;
+    Ok(committable_columns
+        .iter()
+        .zip(ranges)
+        .flat_map(|(column, range)| {
+            C::compute_commitments(core::slice::from_ref(column), range.start, setup)
+        })
+        .collect())
+}
+
+/// Assigns each column of a set of tables its own range of generators in a shared generator space.
+///
+/// Columns are laid out one after another in the order they are added, starting from the offset
+/// the layout is created with, so the generators of no two columns overlap.
+/// The offsets can be passed to [`compute_commitments_with_generator_offsets`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeneratorLayout {
+    ranges: IndexMap<(TableRef, Identifier), Range<usize>>,
+    end: usize,
+}
+
+impl GeneratorLayout {
+    /// Create an empty layout whose first column starts at the given generator offset.
+    #[must_use]
+    pub fn starting_at(offset: usize) -> Self {
+        Self {
+            ranges: IndexMap::default(),
+            end: offset,
+        }
+    }
+
+    /// Lay out a column with the given number of rows after the columns laid out so far.
+    ///
+    /// Returns the generator offset of the column.
+    ///
+    /// # Errors
+    /// Returns an error if the column is already laid out or if its generators would extend past `usize::MAX`.
+    pub fn try_add_column(
+        &mut self,
+        table_ref: TableRef,
+        column: Identifier,
+        length: usize,
+    ) -> Result<usize, GeneratorLayoutError> {
+        if self.ranges.contains_key(&(table_ref, column)) {
+            return Err(GeneratorLayoutError::DuplicateColumn {
+                table_ref: Box::new(table_ref),
+                column,
+            });
+        }
+        let offset = self.end;
+        self.end = offset
+            .checked_add(length)
+            .ok_or(GeneratorLayoutError::GeneratorOffsetOverflow)?;
+        self.ranges.insert((table_ref, column), offset..self.end);
+        Ok(offset)
+    }
+
+    /// Lay out every column of a table, in order, after the columns laid out so far.
+    ///
+    /// Returns the generator offset of each column, in the order of the columns of the table.
+    ///
+    /// # Errors
+    /// Returns an error if a column of the table is already laid out or if its generators would extend past `usize::MAX`.
+    pub fn try_add_table<S: Scalar>(
+        &mut self,
+        table_ref: TableRef,
+        table: &OwnedTable<S>,
+    ) -> Result<Vec<usize>, GeneratorLayoutError> {
+        table
+            .inner_table()
+            .keys()
+            .map(|&column| self.try_add_column(table_ref, column, table.num_rows()))
+            .collect()
+    }
+
+    /// The generator offset of a column, if it is laid out.
+    #[must_use]
+    pub fn generator_offset(&self, table_ref: TableRef, column: Identifier) -> Option<usize> {
+        self.generators(table_ref, column).map(|range| range.start)
+    }
+
+    /// The range of generators used by a column, if it is laid out.
+    #[must_use]
+    pub fn generators(&self, table_ref: TableRef, column: Identifier) -> Option<Range<usize>> {
+        self.ranges.get(&(table_ref, column)).cloned()
+    }
+
+    /// The first generator after all the columns laid out so far, which is where the next column starts.
+    #[must_use]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::database::owned_table_utility::*,
+        proof_primitive::dory::{
+            test_rng, DoryCommitment, DoryProverPublicSetup, DoryScalar, ProverSetup,
+            PublicParameters,
+        },
+    };
+
+    #[test]
+    fn two_tables_laid_out_by_a_generator_layout_do_not_overlap() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+        let a: TableRef = "sxt.a".parse().unwrap();
+        let b: TableRef = "sxt.b".parse().unwrap();
+        let table_a: OwnedTable<DoryScalar> = owned_table([
+            bigint("x", [1_i64, 2, 3]),
+            boolean("y", [true, false, true]),
+        ]);
+        let table_b: OwnedTable<DoryScalar> =
+            owned_table([bigint("x", [4_i64, 5]), varchar("z", ["a", "b"])]);
+
+        let mut layout = GeneratorLayout::starting_at(2);
+        assert_eq!(layout.try_add_table(a, &table_a), Ok(vec![2, 5]));
+        assert_eq!(layout.try_add_table(b, &table_b), Ok(vec![8, 10]));
+        assert_eq!(layout.end(), 12);
+        assert_eq!(layout.generator_offset(b, "x".parse().unwrap()), Some(8));
+        assert_eq!(layout.generators(a, "y".parse().unwrap()), Some(5..8));
+        assert_eq!(layout.generator_offset(b, "y".parse().unwrap()), None);
+
+        let columns: Vec<CommittableColumn> = table_a
+            .inner_table()
+            .values()
+            .chain(table_b.inner_table().values())
+            .map(Into::into)
+            .collect();
+        let offsets = [2, 5, 8, 10];
+        let commitments = compute_commitments_with_generator_offsets::<DoryCommitment>(
+            &columns, &offsets, &setup,
+        )
+        .unwrap();
+        for ((column, offset), commitment) in columns.iter().zip(offsets).zip(&commitments) {
+            assert_eq!(
+                *commitment,
+                DoryCommitment::compute_commitments(core::slice::from_ref(column), offset, &setup)
+                    [0]
+            );
+        }
+    }
+
+    #[test]
+    fn we_cannot_compute_commitments_with_overlapping_or_missing_generator_offsets() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+        let columns = [
+            CommittableColumn::BigInt(&[1, 2, 3]),
+            CommittableColumn::BigInt(&[4, 5]),
+            CommittableColumn::BigInt(&[]),
+        ];
+        let compute = |offsets: &[usize]| {
+            compute_commitments_with_generator_offsets::<DoryCommitment>(&columns, offsets, &setup)
+        };
+
+        assert!(compute(&[0, 3, 3]).is_ok());
+        // An empty column uses no generators, so it can share an offset with anything.
+        assert!(compute(&[4, 0, 1]).is_ok());
+        assert_eq!(
+            compute(&[0, 2, 10]),
+            Err(GeneratorLayoutError::OverlappingGenerators {
+                first: 0,
+                second: 1
+            })
+        );
+        assert_eq!(
+            compute(&[5, 4, 10]),
+            Err(GeneratorLayoutError::OverlappingGenerators {
+                first: 1,
+                second: 0
+            })
+        );
+        assert_eq!(
+            compute(&[0, 0, 10]),
+            Err(GeneratorLayoutError::OverlappingGenerators {
+                first: 0,
+                second: 1
+            })
+        );
+        assert_eq!(
+            compute(&[0, 3]),
+            Err(GeneratorLayoutError::GeneratorOffsetCountMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            compute(&[usize::MAX, 0, 0]),
+            Err(GeneratorLayoutError::GeneratorOffsetOverflow)
+        );
+    }
+
+    #[test]
+    fn we_cannot_lay_out_a_column_twice() {
+        let t: TableRef = "sxt.t".parse().unwrap();
+        let mut layout = GeneratorLayout::default();
+        assert_eq!(layout.try_add_column(t, "a".parse().unwrap(), 4), Ok(0));
+        assert_eq!(layout.try_add_column(t, "b".parse().unwrap(), 0), Ok(4));
+        assert_eq!(
+            layout.try_add_column(t, "a".parse().unwrap(), 1),
+            Err(GeneratorLayoutError::DuplicateColumn {
+                table_ref: Box::new(t),
+                column: "a".parse().unwrap()
+            })
+        );
+        assert_eq!(layout.end(), 4);
+        assert_eq!(
+            GeneratorLayout::starting_at(usize::MAX).try_add_column(t, "a".parse().unwrap(), 1),
+            Err(GeneratorLayoutError::GeneratorOffsetOverflow)
+        );
+    }
+}
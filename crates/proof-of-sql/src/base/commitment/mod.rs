@@ -41,6 +41,37 @@ pub use table_commitment::{
 mod query_commitments;
 pub use query_commitments::{QueryCommitments, QueryCommitmentsExt};
 
+mod precomputed_mle;
+pub use precomputed_mle::{commit_mle, NonPowerOfTwoMleLength, PrecomputedMle};
+
+mod sparse_boolean_column;
+pub use sparse_boolean_column::{
+    commit_sparse_boolean_column, InvalidSparseBooleanColumn, SparseBooleanColumn,
+};
+
+mod constant_column;
+pub use constant_column::commit_constant_column;
+
+mod row_major_commitment;
+pub use row_major_commitment::{compute_commitments_row_major, RowMajorCommitmentError};
+
+mod generator_layout;
+pub use generator_layout::{
+    compute_commitments_with_generator_offsets, GeneratorLayout, GeneratorLayoutError,
+};
+
+mod table_snapshot;
+pub use table_snapshot::{TableSnapshot, TableSnapshotError, TABLE_SNAPSHOT_VERSION};
+
+mod sharded_table;
+pub use sharded_table::{try_combine_shard_commitments, ShardedTable, ShardedTableError};
+
+mod table_manifest;
+pub use table_manifest::{ColumnInclusionProof, ManifestEntry, TableManifest};
+
+mod tagged_commitment;
+pub use tagged_commitment::TaggedCommitment;
+
 /// Module for providing a mock commitment.
 #[cfg(test)]
 pub mod naive_commitment;
@@ -77,6 +108,52 @@ pub trait Commitment:
     /// The public setup for the commitment scheme.
     type PublicSetup<'a>;
 
+    /// Compute the group difference `self - other` of two commitments.
+    ///
+    /// Since commitments are homomorphic, this can be used to verify updates to a table incrementally.
+    /// If `old` commits to a column and `new` is the claimed commitment to that column after some rows were updated,
+    /// then `new.sub(&old)` must equal the commitment to the deltas, i.e. the column that is `new_value - old_value`
+    /// at every updated row and zero elsewhere, computed with the same offset.
+    /// The delta column only needs to span the updated rows, as long as its offset is shifted accordingly.
+    /// This lets a verifier check the new commitment while only committing to the updated rows.
+    ///
+    /// Note that in generic code the operator [`core::ops::Sub`] is also in scope,
+    /// so this method has to be called as `Commitment::sub(&new, &old)`.
+    #[must_use]
+    fn sub(&self, other: &Self) -> Self {
+        self.clone() - other.clone()
+    }
+
+    /// Check that this is the commitment to `column` with the given generator offset.
+    ///
+    /// This recommits to the raw column and compares the result with `self`. No proof is involved,
+    /// so it is only meaningful for auditing data the caller already trusts.
+    /// The serialized forms of the commitments are compared in constant time,
+    /// which assumes that equal commitments have equal serializations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either commitment cannot be serialized, which should not happen for valid commitments.
+    fn verify_against<'a>(
+        &self,
+        column: impl Into<CommittableColumn<'a>>,
+        offset: usize,
+        setup: &Self::PublicSetup<'_>,
+    ) -> bool
+    where
+        Self: serde::Serialize,
+    {
+        let recomputed = Self::compute_commitments(&[column.into()], offset, setup);
+        let expected = postcard::to_allocvec(self).expect("commitment should serialize");
+        let actual = postcard::to_allocvec(&recomputed[0]).expect("commitment should serialize");
+        expected.len() == actual.len()
+            && expected
+                .iter()
+                .zip(&actual)
+                .fold(0, |difference, (a, b)| difference | (a ^ b))
+                == 0
+    }
+
     /// Compute the commitments for the given columns.
     ///
     /// The resulting commitments are written to the slice in `commitments`, which is a buffer.
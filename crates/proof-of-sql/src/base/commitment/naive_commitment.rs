@@ -134,6 +134,9 @@ impl Commitment for NaiveCommitment {
                     CommittableColumn::BigInt(big_int_vec) => {
                         big_int_vec.iter().map(core::convert::Into::into).collect()
                     }
+                    CommittableColumn::UInt64(uint64_vec) => {
+                        uint64_vec.iter().map(core::convert::Into::into).collect()
+                    }
                     CommittableColumn::Int128(int_128_vec) => {
                         int_128_vec.iter().map(core::convert::Into::into).collect()
                     }
@@ -143,7 +146,8 @@ impl Commitment for NaiveCommitment {
                     CommittableColumn::Scalar(scalar_vec) => {
                         scalar_vec.iter().map(core::convert::Into::into).collect()
                     }
-                    CommittableColumn::VarChar(varchar_vec) => {
+                    CommittableColumn::VarChar(varchar_vec)
+                    | CommittableColumn::FixedBytes(_, varchar_vec) => {
                         varchar_vec.iter().map(core::convert::Into::into).collect()
                     }
                     CommittableColumn::TimestampTZ(_, _, i64_vec) => {
@@ -0,0 +1,171 @@
+use super::{Commitment, CommittableColumn};
+use crate::base::{database::OwnedColumn, ref_into::RefInto, scalar::Scalar};
+use alloc::vec::Vec;
+use snafu::Snafu;
+
+/// Cannot create a [`PrecomputedMle`] from evaluations whose length is not a power of two.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+#[snafu(display("MLE evaluations must have a power of two length, but found {length}"))]
+pub struct NonPowerOfTwoMleLength {
+    /// The number of evaluations given
+    pub length: usize,
+}
+
+/// The evaluations of a multilinear extension over the boolean hypercube.
+///
+/// This lets custom proof pipelines that already hold an MLE reuse the commitment and proof machinery
+/// without first building a column of some other type.
+/// Evaluation `i` is the value of the MLE at the point whose coordinates are the bits of `i`, least significant first.
+/// There must be exactly `2^num_vars` evaluations.
+///
+/// An MLE is committed to and proven over as a [`ColumnType::Scalar`](crate::base::database::ColumnType::Scalar) column.
+/// Use [`Self::into_owned_column`] to add it to a table that the prover can access,
+/// and [`commit_mle`] to compute the commitment the verifier needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrecomputedMle<S: Scalar> {
+    evaluations: Vec<S>,
+}
+
+impl<S: Scalar> PrecomputedMle<S> {
+    /// Create a new [`PrecomputedMle`] from its evaluations over the boolean hypercube.
+    ///
+    /// Will error if the number of evaluations is not a power of two.
+    pub fn try_new(evaluations: Vec<S>) -> Result<Self, NonPowerOfTwoMleLength> {
+        if evaluations.len().is_power_of_two() {
+            Ok(Self { evaluations })
+        } else {
+            Err(NonPowerOfTwoMleLength {
+                length: evaluations.len(),
+            })
+        }
+    }
+
+    /// Returns the evaluations of the MLE over the boolean hypercube.
+    #[must_use]
+    pub fn evaluations(&self) -> &[S] {
+        &self.evaluations
+    }
+
+    /// Returns the number of variables of the MLE, which is the base 2 logarithm of the number of evaluations.
+    #[must_use]
+    pub fn num_vars(&self) -> usize {
+        self.evaluations.len().trailing_zeros() as usize
+    }
+
+    /// Convert the MLE into a scalar column with one row per evaluation.
+    #[must_use]
+    pub fn into_owned_column(self) -> OwnedColumn<S> {
+        OwnedColumn::Scalar(self.evaluations)
+    }
+}
+
+/// Compute the commitment to a precomputed MLE using the given generator offset.
+///
+/// This is identical to the commitment to the scalar column [`PrecomputedMle::into_owned_column`] returns,
+/// so it can be handed to the verifier in place of a column commitment.
+///
+/// # Panics
+///
+/// Panics if the commitment scheme does not return exactly one commitment for the MLE.
+#[must_use]
+pub fn commit_mle<C: Commitment>(
+    mle: &PrecomputedMle<C::Scalar>,
+    offset: usize,
+    setup: &C::PublicSetup<'_>,
+) -> C {
+    let evaluations = mle
+        .evaluations
+        .iter()
+        .map(RefInto::<[u64; 4]>::ref_into)
+        .collect();
+    C::compute_commitments(&[CommittableColumn::Scalar(evaluations)], offset, setup)
+        .pop()
+        .expect("one commitment is computed per column")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::database::{
+            owned_table_utility::*, ColumnRef, ColumnType, CommitmentAccessor, OwnedTable,
+            OwnedTableTestAccessor, TestAccessor,
+        },
+        proof_primitive::dory::{
+            test_rng, DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar,
+            DoryVerifierPublicSetup, ProverSetup, PublicParameters, VerifierSetup,
+        },
+        sql::{
+            proof::VerifiableQueryResult, proof_exprs::test_utility::*,
+            proof_plans::test_utility::*,
+        },
+    };
+    use alloc::vec;
+
+    #[test]
+    fn we_can_only_create_an_mle_with_a_power_of_two_length() {
+        for length in [1, 2, 4, 16] {
+            let mle = PrecomputedMle::try_new(vec![DoryScalar::from(7); length]).unwrap();
+            assert_eq!(1 << mle.num_vars(), length);
+            assert_eq!(mle.evaluations().len(), length);
+        }
+        for length in [0, 3, 5, 6, 12] {
+            assert_eq!(
+                PrecomputedMle::try_new(vec![DoryScalar::from(7); length]),
+                Err(NonPowerOfTwoMleLength { length })
+            );
+        }
+    }
+
+    #[test]
+    fn proving_over_an_mle_built_from_a_column_matches_proving_over_the_column() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let verifier_setup = VerifierSetup::from(&public_parameters);
+        let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+        let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+        let a = [1_i64, 2, 3, 2, -1, 2, 0, 5];
+        let b = [10_i64, 20, 30, 40, 50, 60, 70, 80];
+        let mle =
+            PrecomputedMle::try_new(a.iter().copied().map(DoryScalar::from).collect()).unwrap();
+
+        let column_ref = "sxt.column".parse().unwrap();
+        let column_table: OwnedTable<DoryScalar> = owned_table([bigint("a", a), bigint("b", b)]);
+        let mle_ref = "sxt.mle".parse().unwrap();
+        let mle_table = owned_table([
+            ("a".parse().unwrap(), mle.clone().into_owned_column()),
+            bigint("b", b),
+        ]);
+
+        let mut accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+            column_ref,
+            column_table,
+            0,
+            dory_prover_setup,
+        );
+        accessor.add_table(mle_ref, mle_table, 0);
+
+        // The MLE commits to the same values as the column, so the commitments are equal
+        let mle_commitment = commit_mle::<DoryCommitment>(&mle, 0, &dory_prover_setup);
+        let a_ref = |table_ref| ColumnRef::new(table_ref, "a".parse().unwrap(), ColumnType::BigInt);
+        assert_eq!(mle_commitment, accessor.get_commitment(a_ref(column_ref)));
+        assert_eq!(mle_commitment, accessor.get_commitment(a_ref(mle_ref)));
+
+        let prove_and_verify = |table_ref, a_value| {
+            let expr = filter(
+                cols_expr_plan(table_ref, &["b"], &accessor),
+                tab(table_ref),
+                equal(column(table_ref, "a", &accessor), a_value),
+            );
+            VerifiableQueryResult::<DoryEvaluationProof>::new(&expr, &accessor, &dory_prover_setup)
+                .verify(&expr, &accessor, &dory_verifier_setup)
+                .unwrap()
+                .table
+        };
+        let column_result = prove_and_verify(column_ref, const_bigint(2));
+        let mle_result = prove_and_verify(mle_ref, const_scalar::<DoryScalar, _>(2));
+        assert_eq!(column_result, owned_table([bigint("b", [20, 40, 60])]));
+        assert_eq!(mle_result, column_result);
+    }
+}
@@ -1,10 +1,13 @@
-use super::{Commitment, TableCommitment};
-use crate::base::{
-    database::{
-        ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, SchemaAccessor,
-        TableRef,
+use super::{Commitment, DuplicateIdentifiers, TableCommitment};
+use crate::{
+    base::{
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor,
+            SchemaAccessor, TableRef,
+        },
+        map::IndexMap,
     },
-    map::IndexMap,
+    sql::proof::ProofPlan,
 };
 use alloc::vec::Vec;
 use proof_of_sql_parser::Identifier;
@@ -23,17 +26,35 @@ where
     C: Commitment,
 {
     /// Create a new `QueryCommitments` from a collection of columns and an accessor.
+    ///
+    /// Will error if the accessor reports duplicate column identifiers for any table.
     fn from_accessor_with_max_bounds(
         columns: impl IntoIterator<Item = ColumnRef>,
         accessor: &(impl CommitmentAccessor<C> + SchemaAccessor),
-    ) -> Self;
+    ) -> Result<Self, DuplicateIdentifiers>
+    where
+        Self: Sized;
+
+    /// Create a new `QueryCommitments` containing only the columns referenced by a proof plan.
+    ///
+    /// This is the query footprint, which is all the verifier needs to verify the plan.
+    /// Will error if the accessor reports duplicate column identifiers for any table.
+    fn from_accessor_for_plan_with_max_bounds(
+        plan: &impl ProofPlan,
+        accessor: &(impl CommitmentAccessor<C> + SchemaAccessor),
+    ) -> Result<Self, DuplicateIdentifiers>
+    where
+        Self: Sized,
+    {
+        Self::from_accessor_with_max_bounds(plan.get_column_references(), accessor)
+    }
 }
 
 impl<C: Commitment> QueryCommitmentsExt<C> for QueryCommitments<C> {
     fn from_accessor_with_max_bounds(
         columns: impl IntoIterator<Item = ColumnRef>,
         accessor: &(impl CommitmentAccessor<C> + SchemaAccessor),
-    ) -> Self {
+    ) -> Result<Self, DuplicateIdentifiers> {
         columns
             .into_iter()
             .fold(
@@ -48,7 +69,7 @@ impl<C: Commitment> QueryCommitmentsExt<C> for QueryCommitments<C> {
             )
             .into_iter()
             .map(|(table_ref, columns)| {
-                (
+                Ok((
                     table_ref,
                     TableCommitment::from_accessor_with_max_bounds(
                         table_ref,
@@ -58,8 +79,8 @@ impl<C: Commitment> QueryCommitmentsExt<C> for QueryCommitments<C> {
                             .filter_map(|c| columns.iter().find(|x| x.name() == c.0).copied())
                             .collect::<Vec<_>>(),
                         accessor,
-                    ),
-                )
+                    )?,
+                ))
             })
             .collect()
     }
@@ -377,7 +398,8 @@ mod tests {
                 ColumnRef::new(table_b_id, column_b_id, ColumnType::Int128),
             ],
             &accessor,
-        );
+        )
+        .unwrap();
         assert_eq!(query_commitments, expected_query_commitments);
     }
 }
@@ -0,0 +1,300 @@
+use super::{Commitment, CommittableColumn};
+use crate::base::{
+    database::{ColumnType, LiteralValue, OwnedColumn, TableSchema},
+    scalar::Scalar,
+};
+use alloc::vec::Vec;
+use proof_of_sql_parser::Identifier;
+use snafu::Snafu;
+
+/// Cannot commit to the given rows with [`compute_commitments_row_major`].
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum RowMajorCommitmentError {
+    /// A row does not have one value per column of the schema.
+    #[snafu(display("row {row} has {actual} values, but the schema has {expected} columns"))]
+    RowLengthMismatch {
+        /// The index of the row
+        row: usize,
+        /// The number of columns in the schema
+        expected: usize,
+        /// The number of values in the row
+        actual: usize,
+    },
+    /// A value does not have the type of its column.
+    #[snafu(display(
+        "value in row {row} of column {column} has type {actual}, expected {expected}"
+    ))]
+    ValueTypeMismatch {
+        /// The index of the row
+        row: usize,
+        /// The column of the value
+        column: Identifier,
+        /// The type of the column in the schema
+        expected: ColumnType,
+        /// The type of the value
+        actual: ColumnType,
+    },
+}
+
+/// Gather the values of one column from the rows, checking that each has the type of the column.
+fn collect_column_values<T, R: AsRef<[LiteralValue]>>(
+    rows: &[R],
+    index: usize,
+    column: Identifier,
+    expected: ColumnType,
+    extract: impl Fn(&LiteralValue) -> Option<T>,
+) -> Result<Vec<T>, RowMajorCommitmentError> {
+    rows.iter()
+        .enumerate()
+        .map(|(row, values)| {
+            let value = &values.as_ref()[index];
+            (value.column_type() == expected)
+                .then(|| extract(value))
+                .flatten()
+                .ok_or(RowMajorCommitmentError::ValueTypeMismatch {
+                    row,
+                    column,
+                    expected,
+                    actual: value.column_type(),
+                })
+        })
+        .collect()
+}
+
+/// Transpose one column of the rows into an [`OwnedColumn`].
+fn column_from_rows<S: Scalar, R: AsRef<[LiteralValue]>>(
+    rows: &[R],
+    index: usize,
+    column: Identifier,
+    column_type: ColumnType,
+) -> Result<OwnedColumn<S>, RowMajorCommitmentError> {
+    macro_rules! collect {
+        ($variant:ident) => {
+            collect!(|value: &LiteralValue| match value.clone() {
+                LiteralValue::$variant(v) => Some(v),
+                _ => None,
+            })
+        };
+        ($extract:expr) => {
+            collect_column_values(rows, index, column, column_type, $extract)?
+        };
+    }
+    Ok(match column_type {
+        ColumnType::Boolean => OwnedColumn::Boolean(collect!(Boolean)),
+        ColumnType::TinyInt => OwnedColumn::TinyInt(collect!(TinyInt)),
+        ColumnType::SmallInt => OwnedColumn::SmallInt(collect!(SmallInt)),
+        ColumnType::Int => OwnedColumn::Int(collect!(Int)),
+        ColumnType::BigInt => OwnedColumn::BigInt(collect!(BigInt)),
+        ColumnType::UInt64 => OwnedColumn::UInt64(collect!(UInt64)),
+        ColumnType::Int128 => OwnedColumn::Int128(collect!(Int128)),
+        ColumnType::VarChar => OwnedColumn::VarChar(collect!(VarChar)),
+        ColumnType::FixedBytes(width) => OwnedColumn::FixedBytes(width, collect!(FixedBytes)),
+        ColumnType::TimestampTZ(tu, tz) => OwnedColumn::TimestampTZ(
+            tu,
+            tz,
+            collect!(|value: &LiteralValue| match value {
+                LiteralValue::TimeStampTZ(_, _, v) => Some(*v),
+                _ => None,
+            }),
+        ),
+        ColumnType::Decimal75(precision, scale) => OwnedColumn::Decimal75(
+            precision,
+            scale,
+            collect!(|value: &LiteralValue| Some(value.to_scalar())),
+        ),
+        ColumnType::Scalar => {
+            OwnedColumn::Scalar(collect!(|value: &LiteralValue| Some(value.to_scalar())))
+        }
+    })
+}
+
+/// Compute the commitments to the columns of a table given as rows, using the given generator offset.
+///
+/// Each row must have one value per column of `schema`, in the order of the schema, and of the type of that column.
+/// The commitments are identical to those of the columns of the same table, in the order of the schema.
+/// Only one column is transposed at a time, so at most one column is ever held in column-major form.
+///
+/// # Errors
+/// Returns an error if a row does not have one value per column, or if a value does not have the type of its column.
+pub fn compute_commitments_row_major<C: Commitment, R: AsRef<[LiteralValue]>>(
+    rows: &[R],
+    schema: &TableSchema,
+    offset: usize,
+    setup: &C::PublicSetup<'_>,
+) -> Result<Vec<C>, RowMajorCommitmentError> {
+    let expected = schema.columns().len();
+    if let Some((row, values)) = rows
+        .iter()
+        .enumerate()
+        .find(|(_, values)| values.as_ref().len() != expected)
+    {
+        return Err(RowMajorCommitmentError::RowLengthMismatch {
+            row,
+            expected,
+            actual: values.as_ref().len(),
+        });
+    }
+    schema
+        .columns()
+        .iter()
+        .enumerate()
+        .map(|(index, (column, column_type))| {
+            let owned_column =
+                column_from_rows::<C::Scalar, R>(rows, index, *column, *column_type)?;
+            let mut commitments =
+                C::compute_commitments(&[CommittableColumn::from(&owned_column)], offset, setup);
+            Ok(commitments.remove(0))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::{
+            commitment::VecCommitmentExt,
+            database::{owned_table_utility::*, OwnedTable},
+            math::{decimal::Precision, i256::I256},
+        },
+        proof_primitive::dory::{
+            test_rng, DoryCommitment, DoryProverPublicSetup, DoryScalar, ProverSetup,
+            PublicParameters,
+        },
+    };
+    use alloc::string::ToString;
+    use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+
+    fn schema_of(table: &OwnedTable<DoryScalar>) -> TableSchema {
+        TableSchema::new(
+            table
+                .inner_table()
+                .iter()
+                .map(|(name, column)| (*name, column.column_type()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn row_major_and_column_major_commitments_of_a_table_are_identical() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+        let table: OwnedTable<DoryScalar> = owned_table([
+            boolean("a", [true, false, true]),
+            tinyint("b", [1_i8, -2, 3]),
+            smallint("c", [10_i16, -20, 30]),
+            int("d", [100, -200, 300]),
+            bigint("e", [1000_i64, -2000, 3000]),
+            int128("f", [10_000_i128, -20_000, 30_000]),
+            varchar("g", ["x", "yy", "zzz"]),
+            decimal75("h", 12, 2, [125_i64, -300, 0]),
+            timestamptz(
+                "i",
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::Utc,
+                [1_625_072_400, 1_625_076_000, 1_625_079_600],
+            ),
+            scalar("j", [7, 8, 9]),
+        ]);
+        let precision = Precision::new(12).unwrap();
+        let rows: Vec<Vec<LiteralValue>> = (0..3)
+            .map(|i| {
+                vec![
+                    LiteralValue::Boolean([true, false, true][i]),
+                    LiteralValue::TinyInt([1, -2, 3][i]),
+                    LiteralValue::SmallInt([10, -20, 30][i]),
+                    LiteralValue::Int([100, -200, 300][i]),
+                    LiteralValue::BigInt([1000, -2000, 3000][i]),
+                    LiteralValue::Int128([10_000, -20_000, 30_000][i]),
+                    LiteralValue::VarChar(["x", "yy", "zzz"][i].to_string()),
+                    LiteralValue::Decimal75(precision, 2, I256::from([125, -300, 0][i])),
+                    LiteralValue::TimeStampTZ(
+                        PoSQLTimeUnit::Second,
+                        PoSQLTimeZone::Utc,
+                        [1_625_072_400, 1_625_076_000, 1_625_079_600][i],
+                    ),
+                    LiteralValue::Scalar(DoryScalar::from([7, 8, 9][i]).into()),
+                ]
+            })
+            .collect();
+        for offset in [0, 5] {
+            assert_eq!(
+                compute_commitments_row_major::<DoryCommitment, _>(
+                    &rows,
+                    &schema_of(&table),
+                    offset,
+                    &setup
+                )
+                .unwrap(),
+                Vec::<DoryCommitment>::from_columns_with_offset(
+                    table.inner_table().values(),
+                    offset,
+                    &setup
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn we_can_commit_to_a_table_without_rows_in_row_major_order() {
+        let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 1);
+        let table: OwnedTable<DoryScalar> =
+            owned_table([bigint("a", [0_i64; 0]), varchar("b", [""; 0])]);
+        let rows: [Vec<LiteralValue>; 0] = [];
+        assert_eq!(
+            compute_commitments_row_major::<DoryCommitment, _>(
+                &rows,
+                &schema_of(&table),
+                0,
+                &setup
+            )
+            .unwrap(),
+            Vec::<DoryCommitment>::from_columns_with_offset(
+                table.inner_table().values(),
+                0,
+                &setup
+            )
+        );
+    }
+
+    #[test]
+    fn we_cannot_commit_to_rows_that_do_not_match_the_schema() {
+        let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 1);
+        let table: OwnedTable<DoryScalar> =
+            owned_table([bigint("a", [1_i64]), boolean("b", [true])]);
+        let schema = schema_of(&table);
+
+        let rows = [
+            vec![LiteralValue::BigInt(1), LiteralValue::Boolean(true)],
+            vec![LiteralValue::BigInt(2)],
+        ];
+        assert_eq!(
+            compute_commitments_row_major::<DoryCommitment, _>(&rows, &schema, 0, &setup),
+            Err(RowMajorCommitmentError::RowLengthMismatch {
+                row: 1,
+                expected: 2,
+                actual: 1
+            })
+        );
+
+        let rows = [
+            vec![LiteralValue::BigInt(1), LiteralValue::Boolean(true)],
+            vec![LiteralValue::Int(2), LiteralValue::Boolean(false)],
+        ];
+        assert_eq!(
+            compute_commitments_row_major::<DoryCommitment, _>(&rows, &schema, 0, &setup),
+            Err(RowMajorCommitmentError::ValueTypeMismatch {
+                row: 1,
+                column: "a".parse().unwrap(),
+                expected: ColumnType::BigInt,
+                actual: ColumnType::Int
+            })
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use super::{Commitment, TableCommitment, TableCommitmentArithmeticError};
+use crate::base::{
+    database::{union_util::column_union, Column, OwnedColumn, OwnedTable},
+    scalar::Scalar,
+};
+use alloc::vec::Vec;
+use bumpalo::Bump;
+use snafu::Snafu;
+
+/// Errors that can occur when creating a [`ShardedTable`] or combining the commitments to its shards.
+#[derive(Debug, Snafu)]
+pub enum ShardedTableError {
+    /// A sharded table has no shards.
+    #[snafu(display("a sharded table must have at least one shard"))]
+    NoShards,
+    /// A shard does not have the same column names and types, in the same order, as the first shard.
+    #[snafu(display("shard {index} does not have the schema of the first shard"))]
+    ShardSchemaMismatch {
+        /// The index of the shard
+        index: usize,
+    },
+    /// The commitments to the shards cannot be added together,
+    /// e.g. because they do not cover consecutive rows or their columns do not match.
+    #[snafu(transparent)]
+    ShardCommitmentMismatch {
+        /// The underlying source error
+        source: TableCommitmentArithmeticError,
+    },
+}
+
+/// A table that is stored as several shards, each with the same schema.
+///
+/// The logical table is the `UNION ALL` of the shards, in order.
+/// Shard `i` is committed to with the generators of the rows it has in the logical table,
+/// i.e. with an offset of the number of rows in the shards before it.
+/// Since commitments are additive, the sum of the shard commitments is then the commitment to the logical table,
+/// and a query over the logical table is proven and verified as a query over any other table.
+///
+/// The verifier only needs the shard commitments, which it combines with [`try_combine_shard_commitments`].
+/// Combining fails unless the shards cover consecutive rows and have matching columns,
+/// which binds the logical table to exactly the committed shards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShardedTable<S: Scalar> {
+    shards: Vec<OwnedTable<S>>,
+}
+
+impl<S: Scalar> ShardedTable<S> {
+    /// Create a sharded table from its shards, in the order of their rows in the logical table.
+    ///
+    /// Will error if there are no shards, or if the shards do not all have the same schema.
+    pub fn try_new(shards: Vec<OwnedTable<S>>) -> Result<Self, ShardedTableError> {
+        let first = shards.first().ok_or(ShardedTableError::NoShards)?;
+        let schema = |shard: &OwnedTable<S>| {
+            shard
+                .inner_table()
+                .iter()
+                .map(|(column_id, column)| (*column_id, column.column_type()))
+                .collect::<Vec<_>>()
+        };
+        let first_schema = schema(first);
+        if let Some(index) = shards
+            .iter()
+            .position(|shard| schema(shard) != first_schema)
+        {
+            return Err(ShardedTableError::ShardSchemaMismatch { index });
+        }
+        Ok(Self { shards })
+    }
+
+    /// Returns the shards, in order.
+    #[must_use]
+    pub fn shards(&self) -> &[OwnedTable<S>] {
+        &self.shards
+    }
+
+    /// Returns the number of rows of the logical table.
+    #[must_use]
+    pub fn num_rows(&self) -> usize {
+        self.shards.iter().map(OwnedTable::num_rows).sum()
+    }
+
+    /// Concatenate the shards into the logical table, which can be added to a table the prover can access.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shards do not have the same schema, which [`ShardedTable::try_new`] rules out.
+    #[must_use]
+    pub fn to_owned_table(&self) -> OwnedTable<S> {
+        let alloc = Bump::new();
+        OwnedTable::try_from_iter(
+            self.shards[0]
+                .inner_table()
+                .iter()
+                .map(|(column_id, column)| {
+                    let shard_columns: Vec<_> = self
+                        .shards
+                        .iter()
+                        .map(|shard| {
+                            Column::from_owned_column(&shard.inner_table()[column_id], &alloc)
+                        })
+                        .collect();
+                    let union = column_union(
+                        &shard_columns.iter().collect::<Vec<_>>(),
+                        &alloc,
+                        column.column_type(),
+                    )
+                    .expect("shards should have the same schema");
+                    (*column_id, OwnedColumn::from(&union))
+                }),
+        )
+        .expect("shards should have the same schema")
+    }
+
+    /// Commit to each shard, with the generators of its rows in the logical table.
+    ///
+    /// `offset` is the generator offset of the logical table.
+    #[must_use]
+    pub fn shard_commitments<C: Commitment>(
+        &self,
+        offset: usize,
+        setup: &C::PublicSetup<'_>,
+    ) -> Vec<TableCommitment<C>> {
+        self.shards
+            .iter()
+            .scan(offset, |shard_offset, shard| {
+                let commitment =
+                    TableCommitment::from_owned_table_with_offset(shard, *shard_offset, setup);
+                *shard_offset += shard.num_rows();
+                Some(commitment)
+            })
+            .collect()
+    }
+}
+
+/// Combine the commitments to the shards of a [`ShardedTable`], in order, into the commitment to the logical table.
+///
+/// Will error if there are no commitments, if they do not cover consecutive rows, or if their columns do not match.
+pub fn try_combine_shard_commitments<C: Commitment>(
+    shard_commitments: impl IntoIterator<Item = TableCommitment<C>>,
+) -> Result<TableCommitment<C>, ShardedTableError> {
+    let mut shard_commitments = shard_commitments.into_iter();
+    let first = shard_commitments
+        .next()
+        .ok_or(ShardedTableError::NoShards)?;
+    shard_commitments.try_fold(first, |combined, shard_commitment| {
+        if combined.range().end != shard_commitment.range().start {
+            return Err(TableCommitmentArithmeticError::NonContiguous.into());
+        }
+        Ok(combined.try_add(shard_commitment)?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::{
+            commitment::TableSnapshot,
+            database::{owned_table_utility::*, OwnedTableTestAccessor, TableRef, TestAccessor},
+        },
+        proof_primitive::dory::{
+            test_rng, DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar,
+            DoryVerifierPublicSetup, ProverSetup, PublicParameters, VerifierSetup,
+        },
+        sql::{parse::QueryExpr, postprocessing::apply_postprocessing_steps, proof::QueryProof},
+    };
+    use alloc::vec;
+
+    fn shards() -> Vec<OwnedTable<DoryScalar>> {
+        vec![
+            owned_table([bigint("a", [1, 5, 2]), varchar("b", ["x", "y", "x"])]),
+            owned_table([bigint("a", [7]), varchar("b", ["z"])]),
+            owned_table([
+                bigint("a", [3, 8, 4, 6]),
+                varchar("b", ["y", "x", "x", "z"]),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn we_can_concatenate_and_commit_to_the_shards_of_a_sharded_table() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+        let sharded = ShardedTable::try_new(shards()).unwrap();
+        assert_eq!(sharded.shards().len(), 3);
+        assert_eq!(sharded.num_rows(), 8);
+        let logical = owned_table([
+            bigint("a", [1, 5, 2, 7, 3, 8, 4, 6]),
+            varchar("b", ["x", "y", "x", "z", "y", "x", "x", "z"]),
+        ]);
+        assert_eq!(sharded.to_owned_table(), logical);
+
+        for offset in [0, 5] {
+            let shard_commitments = sharded.shard_commitments::<DoryCommitment>(offset, &setup);
+            assert_eq!(
+                shard_commitments
+                    .iter()
+                    .map(|commitment| commitment.range().clone())
+                    .collect::<Vec<_>>(),
+                vec![
+                    offset..offset + 3,
+                    offset + 3..offset + 4,
+                    offset + 4..offset + 8
+                ]
+            );
+            assert_eq!(
+                try_combine_shard_commitments(shard_commitments).unwrap(),
+                TableCommitment::from_owned_table_with_offset(&logical, offset, &setup)
+            );
+        }
+    }
+
+    #[test]
+    fn we_cannot_create_a_sharded_table_without_shards_or_with_mismatched_shards() {
+        assert!(matches!(
+            ShardedTable::<DoryScalar>::try_new(vec![]),
+            Err(ShardedTableError::NoShards)
+        ));
+        let mut mismatched = shards();
+        mismatched.push(owned_table([bigint("a", [1]), bigint("b", [2])]));
+        assert!(matches!(
+            ShardedTable::try_new(mismatched),
+            Err(ShardedTableError::ShardSchemaMismatch { index: 3 })
+        ));
+        let mut reordered = shards();
+        reordered[1] = owned_table([varchar("b", ["z"]), bigint("a", [7])]);
+        assert!(matches!(
+            ShardedTable::try_new(reordered),
+            Err(ShardedTableError::ShardSchemaMismatch { index: 1 })
+        ));
+    }
+
+    #[test]
+    fn we_cannot_combine_shard_commitments_that_do_not_form_one_table() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+        let shard_commitments = ShardedTable::try_new(shards())
+            .unwrap()
+            .shard_commitments::<DoryCommitment>(0, &setup);
+
+        assert!(matches!(
+            try_combine_shard_commitments::<DoryCommitment>([]),
+            Err(ShardedTableError::NoShards)
+        ));
+        // a shard is missing
+        assert!(matches!(
+            try_combine_shard_commitments([
+                shard_commitments[0].clone(),
+                shard_commitments[2].clone()
+            ]),
+            Err(ShardedTableError::ShardCommitmentMismatch { .. })
+        ));
+        // the shards are out of order
+        assert!(matches!(
+            try_combine_shard_commitments([
+                shard_commitments[1].clone(),
+                shard_commitments[0].clone(),
+                shard_commitments[2].clone()
+            ]),
+            Err(ShardedTableError::ShardCommitmentMismatch { .. })
+        ));
+        // a shard has other columns
+        let other_shard = TableCommitment::from_owned_table_with_offset(
+            &owned_table::<DoryScalar>([bigint("a", [7]), bigint("c", [0])]),
+            3,
+            &setup,
+        );
+        assert!(matches!(
+            try_combine_shard_commitments([
+                shard_commitments[0].clone(),
+                other_shard,
+                shard_commitments[2].clone()
+            ]),
+            Err(ShardedTableError::ShardCommitmentMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn a_query_over_a_sharded_table_matches_the_query_over_the_concatenated_table() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let verifier_setup = VerifierSetup::from(&public_parameters);
+        let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+        let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+        let table_ref: TableRef = "sxt.table".parse().unwrap();
+        let sharded = ShardedTable::try_new(shards()).unwrap();
+        let mut sharded_accessor =
+            OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+        sharded_accessor.add_table(table_ref, sharded.to_owned_table(), 0);
+        let mut concatenated_accessor =
+            OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+        concatenated_accessor.add_table(
+            table_ref,
+            owned_table([
+                bigint("a", [1, 5, 2, 7, 3, 8, 4, 6]),
+                varchar("b", ["x", "y", "x", "z", "y", "x", "x", "z"]),
+            ]),
+            0,
+        );
+        // The verifier only has the commitments to the shards.
+        let snapshot = TableSnapshot::new(
+            table_ref,
+            try_combine_shard_commitments(
+                sharded.shard_commitments::<DoryCommitment>(0, &dory_prover_setup),
+            )
+            .unwrap(),
+        );
+
+        for (sql, expected) in [
+            (
+                "SELECT a FROM table WHERE b = 'x'",
+                owned_table([bigint("a", [1, 2, 8, 4])]),
+            ),
+            (
+                "SELECT COUNT(*) AS n FROM table WHERE a >= 3",
+                owned_table([bigint("n", [6])]),
+            ),
+        ] {
+            let query = QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &snapshot)
+                .unwrap();
+            let prove_and_verify = |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+                let (proof, result) = QueryProof::<DoryEvaluationProof>::new(
+                    query.proof_expr(),
+                    accessor,
+                    &dory_prover_setup,
+                );
+                let table = proof
+                    .verify(query.proof_expr(), &snapshot, &result, &dory_verifier_setup)
+                    .unwrap()
+                    .table;
+                apply_postprocessing_steps(table, query.postprocessing()).unwrap()
+            };
+            let sharded_result = prove_and_verify(&sharded_accessor);
+            assert_eq!(sharded_result, expected);
+            assert_eq!(prove_and_verify(&concatenated_accessor), sharded_result);
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use super::{Commitment, CommittableColumn};
+use crate::base::{database::OwnedColumn, scalar::Scalar};
+use alloc::{vec, vec::Vec};
+use snafu::Snafu;
+
+/// Cannot create a [`SparseBooleanColumn`] from the given indices.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum InvalidSparseBooleanColumn {
+    /// An index is not less than the length of the column.
+    #[snafu(display("index {index} is out of bounds for a column of length {len}"))]
+    IndexOutOfBounds {
+        /// The out of bounds index
+        index: usize,
+        /// The length of the column
+        len: usize,
+    },
+    /// The indices are not strictly increasing, so some are repeated or out of order.
+    #[snafu(display("index {index} does not come after the index before it"))]
+    UnsortedIndex {
+        /// The first index that is not greater than the one before it
+        index: usize,
+    },
+}
+
+/// A boolean column that stores only the indices of its `true` rows.
+///
+/// This is cheaper to store and commit to than a dense column when almost every row is `false`.
+/// The logical column is the dense column that is `true` at exactly these indices.
+/// Its commitment, from [`commit_sparse_boolean_column`], is identical to the commitment to the dense column,
+/// so filters and comparisons over it are proven as over any other boolean column.
+/// Since the indices are distinct, every row of the logical column is 0 or 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseBooleanColumn {
+    len: usize,
+    true_indices: Vec<usize>,
+}
+
+impl SparseBooleanColumn {
+    /// Create a column of length `len` that is `true` at `true_indices`.
+    ///
+    /// Will error if the indices are not strictly increasing or not less than `len`.
+    pub fn try_new(
+        len: usize,
+        true_indices: Vec<usize>,
+    ) -> Result<Self, InvalidSparseBooleanColumn> {
+        if let Some(pair) = true_indices.windows(2).find(|pair| pair[0] >= pair[1]) {
+            return Err(InvalidSparseBooleanColumn::UnsortedIndex { index: pair[1] });
+        }
+        match true_indices.last() {
+            Some(&index) if index >= len => {
+                Err(InvalidSparseBooleanColumn::IndexOutOfBounds { index, len })
+            }
+            _ => Ok(Self { len, true_indices }),
+        }
+    }
+
+    /// Create the sparse form of a dense boolean column.
+    #[must_use]
+    pub fn from_dense(column: &[bool]) -> Self {
+        Self {
+            len: column.len(),
+            true_indices: column
+                .iter()
+                .enumerate()
+                .filter_map(|(index, &value)| value.then_some(index))
+                .collect(),
+        }
+    }
+
+    /// Returns the number of rows of the logical column.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the logical column has no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the indices of the `true` rows, in increasing order.
+    #[must_use]
+    pub fn true_indices(&self) -> &[usize] {
+        &self.true_indices
+    }
+
+    /// Expand the column into its dense form.
+    #[must_use]
+    pub fn to_dense(&self) -> Vec<bool> {
+        let mut column = vec![false; self.len];
+        for &index in &self.true_indices {
+            column[index] = true;
+        }
+        column
+    }
+
+    /// Expand the column into a dense boolean column that can be added to a table the prover can access.
+    #[must_use]
+    pub fn to_owned_column<S: Scalar>(&self) -> OwnedColumn<S> {
+        OwnedColumn::Boolean(self.to_dense())
+    }
+
+    /// Returns the runs of consecutive `true` rows as `(start, length)` pairs.
+    fn true_runs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut indices = self.true_indices.iter().copied().peekable();
+        core::iter::from_fn(move || {
+            let start = indices.next()?;
+            let mut length = 1;
+            while indices.next_if_eq(&(start + length)).is_some() {
+                length += 1;
+            }
+            Some((start, length))
+        })
+    }
+}
+
+/// Compute the commitment to a sparse boolean column using the given generator offset.
+///
+/// This is identical to the commitment to the dense column [`SparseBooleanColumn::to_owned_column`] returns,
+/// so it can be handed to the verifier in place of a column commitment.
+/// Only the runs of `true` rows are committed to, and the commitments to the runs are added together,
+/// so the `false` rows are never materialized.
+#[must_use]
+pub fn commit_sparse_boolean_column<C: Commitment>(
+    column: &SparseBooleanColumn,
+    offset: usize,
+    setup: &C::PublicSetup<'_>,
+) -> C {
+    let longest_run = column.true_runs().map(|(_, length)| length).max();
+    let trues = vec![true; longest_run.unwrap_or(0)];
+    column
+        .true_runs()
+        .flat_map(|(start, length)| {
+            C::compute_commitments(
+                &[CommittableColumn::Boolean(&trues[..length])],
+                offset + start,
+                setup,
+            )
+        })
+        .fold(C::default(), |mut commitment, run_commitment| {
+            commitment += run_commitment;
+            commitment
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::database::{
+            owned_table_utility::*, ColumnRef, ColumnType, CommitmentAccessor, OwnedTable,
+            OwnedTableTestAccessor, TestAccessor,
+        },
+        proof_primitive::dory::{
+            test_rng, DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar,
+            DoryVerifierPublicSetup, ProverSetup, PublicParameters, VerifierSetup,
+        },
+        sql::{
+            proof::VerifiableQueryResult, proof_exprs::test_utility::*,
+            proof_plans::test_utility::*,
+        },
+    };
+
+    #[test]
+    fn we_can_convert_between_sparse_and_dense_boolean_columns() {
+        let dense = [false, true, true, false, false, false, true, false];
+        let sparse = SparseBooleanColumn::from_dense(&dense);
+        assert_eq!(sparse.true_indices(), &[1, 2, 6]);
+        assert_eq!(sparse.len(), 8);
+        assert!(!sparse.is_empty());
+        assert_eq!(sparse.to_dense(), dense);
+        assert_eq!(sparse.true_runs().collect::<Vec<_>>(), vec![(1, 2), (6, 1)]);
+        assert_eq!(SparseBooleanColumn::try_new(8, vec![1, 2, 6]), Ok(sparse));
+        assert!(SparseBooleanColumn::try_new(0, vec![]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn we_cannot_create_a_sparse_boolean_column_with_invalid_indices() {
+        assert_eq!(
+            SparseBooleanColumn::try_new(4, vec![1, 4]),
+            Err(InvalidSparseBooleanColumn::IndexOutOfBounds { index: 4, len: 4 })
+        );
+        assert_eq!(
+            SparseBooleanColumn::try_new(4, vec![1, 1]),
+            Err(InvalidSparseBooleanColumn::UnsortedIndex { index: 1 })
+        );
+        assert_eq!(
+            SparseBooleanColumn::try_new(4, vec![2, 0, 3]),
+            Err(InvalidSparseBooleanColumn::UnsortedIndex { index: 0 })
+        );
+    }
+
+    #[test]
+    fn sparse_and_dense_boolean_columns_have_the_same_commitments() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+        for dense in [
+            vec![],
+            vec![false; 5],
+            vec![true; 5],
+            vec![false, true, true, false, true, false, false, true],
+        ] {
+            let sparse = SparseBooleanColumn::from_dense(&dense);
+            for offset in [0, 3] {
+                let dense_commitment = DoryCommitment::compute_commitments(
+                    &[CommittableColumn::Boolean(&dense)],
+                    offset,
+                    &dory_prover_setup,
+                )
+                .pop()
+                .unwrap();
+                assert_eq!(
+                    commit_sparse_boolean_column::<DoryCommitment>(
+                        &sparse,
+                        offset,
+                        &dory_prover_setup
+                    ),
+                    dense_commitment
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn proving_over_a_sparse_boolean_column_matches_proving_over_the_dense_column() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let verifier_setup = VerifierSetup::from(&public_parameters);
+        let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+        let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+        let a = [false, false, true, false, false, false, true, false];
+        let b = [10_i64, 20, 30, 40, 50, 60, 70, 80];
+        let sparse = SparseBooleanColumn::try_new(8, vec![2, 6]).unwrap();
+
+        let dense_ref = "sxt.dense".parse().unwrap();
+        let dense_table: OwnedTable<DoryScalar> = owned_table([boolean("a", a), bigint("b", b)]);
+        let sparse_ref = "sxt.sparse".parse().unwrap();
+        let sparse_table = owned_table([
+            ("a".parse().unwrap(), sparse.to_owned_column()),
+            bigint("b", b),
+        ]);
+
+        let mut accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+            dense_ref,
+            dense_table,
+            0,
+            dory_prover_setup,
+        );
+        accessor.add_table(sparse_ref, sparse_table, 0);
+
+        let sparse_commitment =
+            commit_sparse_boolean_column::<DoryCommitment>(&sparse, 0, &dory_prover_setup);
+        let a_ref =
+            |table_ref| ColumnRef::new(table_ref, "a".parse().unwrap(), ColumnType::Boolean);
+        assert_eq!(sparse_commitment, accessor.get_commitment(a_ref(dense_ref)));
+        assert_eq!(
+            sparse_commitment,
+            accessor.get_commitment(a_ref(sparse_ref))
+        );
+
+        let prove_and_verify = |table_ref| {
+            let expr = filter(
+                cols_expr_plan(table_ref, &["b"], &accessor),
+                tab(table_ref),
+                column(table_ref, "a", &accessor),
+            );
+            VerifiableQueryResult::<DoryEvaluationProof>::new(&expr, &accessor, &dory_prover_setup)
+                .verify(&expr, &accessor, &dory_verifier_setup)
+                .unwrap()
+                .table
+        };
+        let dense_result = prove_and_verify(dense_ref);
+        assert_eq!(dense_result, owned_table([bigint("b", [30, 70])]));
+        assert_eq!(prove_and_verify(sparse_ref), dense_result);
+    }
+}
@@ -92,6 +92,8 @@ where
 
 impl<C: Commitment> TableCommitment<C> {
     /// Create a new [`TableCommitment`] for a table from a commitment accessor.
+    ///
+    /// Will error if the provided columns have duplicate identifiers.
     #[allow(
         clippy::missing_panics_doc,
         reason = "The assertion ensures that from_accessor should not create columns with a negative range"
@@ -100,14 +102,14 @@ impl<C: Commitment> TableCommitment<C> {
         table_ref: TableRef,
         columns: &[ColumnField],
         accessor: &impl CommitmentAccessor<C>,
-    ) -> Self {
+    ) -> Result<Self, DuplicateIdentifiers> {
         let length = accessor.get_length(table_ref);
         let offset = accessor.get_offset(table_ref);
-        Self::try_new(
-            ColumnCommitments::from_accessor_with_max_bounds(table_ref, columns, accessor),
+        Ok(Self::try_new(
+            ColumnCommitments::from_accessor_with_max_bounds(table_ref, columns, accessor)?,
             offset..offset + length,
         )
-        .expect("from_accessor should not create columns with a negative range")
+        .expect("from_accessor should not create columns with a negative range"))
     }
 
     #[cfg(test)]
@@ -359,6 +361,22 @@ impl<C: Commitment> TableCommitment<C> {
     }
 }
 
+impl<C: Commitment> TableCommitment<C>
+where
+    Self: Serialize,
+{
+    /// Returns the length of the postcard serialization of this commitment, without serializing it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the commitment cannot be serialized, which should not happen.
+    #[must_use]
+    pub fn serialized_size(&self) -> usize {
+        postcard::serialize_with_flavor(self, postcard::ser_flavors::Size::default())
+            .expect("table commitment should serialize")
+    }
+}
+
 /// Return the number of rows for the provided columns, erroring if they have mixed length.
 fn num_rows_of_columns<'a>(
     committable_columns: impl IntoIterator<Item = &'a CommittableColumn<'a>>,
@@ -0,0 +1,332 @@
+use super::{Commitment, TableCommitment};
+use crate::base::database::ColumnType;
+use alloc::vec::Vec;
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// The domain separator hashed before every leaf of a [`TableManifest`]
+const LEAF_DOMAIN: u8 = 0;
+
+/// The domain separator hashed before every inner node of a [`TableManifest`]
+const NODE_DOMAIN: u8 = 1;
+
+/// The entry of a single column in a [`TableManifest`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry<C> {
+    /// The name of the column
+    pub name: Identifier,
+    /// The type of the column
+    pub column_type: ColumnType,
+    /// The number of rows in the column
+    pub num_rows: u64,
+    /// The commitment to the column
+    pub commitment: C,
+}
+
+impl<C: Commitment + Serialize> ManifestEntry<C> {
+    /// The leaf hash of this entry, i.e. the blake3 hash of its postcard serialization after a domain separator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entry cannot be serialized, which should not happen for valid commitments.
+    #[must_use]
+    pub fn leaf_hash(&self) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&[LEAF_DOMAIN]);
+        hasher.update(&postcard::to_allocvec(self).expect("manifest entry should serialize"));
+        hasher.finalize().into()
+    }
+}
+
+/// A manifest of a committed table: the name, type, row count, and commitment of each column,
+/// hashed into a Merkle tree whose root commits to the whole layout of the table.
+///
+/// The root is a compact value that can be signed or published in place of the full table commitment.
+/// A client that trusts the root can check the entry of a single column against it with a
+/// [`ColumnInclusionProof`], without the entries of the other columns.
+///
+/// The leaves are the [`ManifestEntry::leaf_hash`]es of the columns, in the order of the table commitment.
+/// Each level pairs adjacent nodes as `blake3(1 || left || right)` and carries an unpaired last node up unchanged.
+/// The root of a manifest without columns is the blake3 hash of no bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableManifest<C> {
+    entries: Vec<ManifestEntry<C>>,
+}
+
+impl<C: Commitment + Serialize> TableManifest<C> {
+    /// Create the manifest of a table commitment.
+    #[must_use]
+    pub fn from_table_commitment(table_commitment: &TableCommitment<C>) -> Self {
+        let num_rows = table_commitment.num_rows() as u64;
+        let column_commitments = table_commitment.column_commitments();
+        let entries = column_commitments
+            .column_metadata()
+            .iter()
+            .zip(column_commitments.commitments())
+            .map(|((name, metadata), commitment)| ManifestEntry {
+                name: *name,
+                column_type: *metadata.column_type(),
+                num_rows,
+                commitment: commitment.clone(),
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Returns the entries of the columns, in order.
+    #[must_use]
+    pub fn entries(&self) -> &[ManifestEntry<C>] {
+        &self.entries
+    }
+
+    /// Returns the entry of the column with the given name, if there is one.
+    #[must_use]
+    pub fn entry(&self, name: &Identifier) -> Option<&ManifestEntry<C>> {
+        self.entries.iter().find(|entry| entry.name == *name)
+    }
+
+    /// Returns the Merkle root of the manifest.
+    #[must_use]
+    pub fn merkle_root(&self) -> [u8; 32] {
+        let mut level = self.leaf_hashes();
+        if level.is_empty() {
+            return blake3::hash(&[]).into();
+        }
+        while level.len() > 1 {
+            level = next_level(&level);
+        }
+        level[0]
+    }
+
+    /// Returns the proof that the entry of the column with the given name is in the manifest,
+    /// or `None` if there is no such column.
+    #[must_use]
+    pub fn inclusion_proof(&self, name: &Identifier) -> Option<ColumnInclusionProof> {
+        let index = self.entries.iter().position(|entry| entry.name == *name)?;
+        let mut siblings = Vec::new();
+        let mut level = self.leaf_hashes();
+        let mut position = index;
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(position ^ 1) {
+                siblings.push(*sibling);
+            }
+            level = next_level(&level);
+            position /= 2;
+        }
+        Some(ColumnInclusionProof {
+            index,
+            num_columns: self.entries.len(),
+            siblings,
+        })
+    }
+
+    fn leaf_hashes(&self) -> Vec<[u8; 32]> {
+        self.entries.iter().map(ManifestEntry::leaf_hash).collect()
+    }
+}
+
+/// A proof that a [`ManifestEntry`] is the entry of a column in a [`TableManifest`] with a given root
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnInclusionProof {
+    /// The position of the column in the manifest
+    pub index: usize,
+    /// The number of columns in the manifest
+    pub num_columns: usize,
+    /// The hashes of the siblings of the path from the leaf to the root, from the bottom up.
+    /// Levels where the node on the path is unpaired have no sibling.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl ColumnInclusionProof {
+    /// Check that `entry` is the entry of the column at [`ColumnInclusionProof::index`]
+    /// of a manifest whose Merkle root is `root`.
+    #[must_use]
+    pub fn verify<C: Commitment + Serialize>(
+        &self,
+        entry: &ManifestEntry<C>,
+        root: &[u8; 32],
+    ) -> bool {
+        if self.index >= self.num_columns {
+            return false;
+        }
+        let mut siblings = self.siblings.iter();
+        let mut hash = entry.leaf_hash();
+        let mut position = self.index;
+        let mut level_len = self.num_columns;
+        while level_len > 1 {
+            if position ^ 1 < level_len {
+                let Some(sibling) = siblings.next() else {
+                    return false;
+                };
+                hash = if position % 2 == 0 {
+                    hash_node(&hash, sibling)
+                } else {
+                    hash_node(sibling, &hash)
+                };
+            }
+            position /= 2;
+            level_len = level_len.div_ceil(2);
+        }
+        siblings.next().is_none() && hash == *root
+    }
+}
+
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&[NODE_DOMAIN]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Pair adjacent nodes of a level, carrying an unpaired last node up unchanged.
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hash_node(left, right),
+            [node] => *node,
+            _ => unreachable!("chunks of two have one or two nodes"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{
+        commitment::naive_commitment::NaiveCommitment,
+        database::{owned_table_utility::*, OwnedTable},
+        scalar::test_scalar::TestScalar,
+    };
+
+    fn manifest(table: &OwnedTable<TestScalar>) -> TableManifest<NaiveCommitment> {
+        TableManifest::from_table_commitment(
+            &TableCommitment::<NaiveCommitment>::from_owned_table_with_offset(table, 0, &()),
+        )
+    }
+
+    fn table() -> OwnedTable<TestScalar> {
+        owned_table([
+            bigint("a", [1, 2, 3]),
+            varchar("b", ["x", "y", "z"]),
+            boolean("c", [true, false, true]),
+            decimal75("d", 10, 2, [100, -5, 0]),
+            int128("e", [4, 5, 6]),
+        ])
+    }
+
+    #[test]
+    fn we_can_create_the_manifest_of_a_table_commitment() {
+        let manifest = manifest(&table());
+        assert_eq!(manifest.entries().len(), 5);
+        let entry = manifest.entry(&"b".parse().unwrap()).unwrap();
+        assert_eq!(entry.column_type, ColumnType::VarChar);
+        assert_eq!(entry.num_rows, 3);
+        assert!(manifest.entry(&"f".parse().unwrap()).is_none());
+        assert!(manifest.inclusion_proof(&"f".parse().unwrap()).is_none());
+    }
+
+    #[test]
+    fn the_merkle_root_is_stable_and_depends_on_every_entry() {
+        let root = manifest(&table()).merkle_root();
+        assert_eq!(manifest(&table()).merkle_root(), root);
+        let serialized = postcard::to_allocvec(&manifest(&table())).unwrap();
+        let deserialized: TableManifest<NaiveCommitment> =
+            postcard::from_bytes(&serialized).unwrap();
+        assert_eq!(deserialized.merkle_root(), root);
+
+        let changed_data = owned_table([
+            bigint("a", [1, 2, 3]),
+            varchar("b", ["x", "y", "z"]),
+            boolean("c", [true, false, true]),
+            decimal75("d", 10, 2, [100, -5, 0]),
+            int128("e", [4, 5, 7]),
+        ]);
+        assert_ne!(manifest(&changed_data).merkle_root(), root);
+        let renamed = owned_table([
+            bigint("a", [1, 2, 3]),
+            varchar("b", ["x", "y", "z"]),
+            boolean("c", [true, false, true]),
+            decimal75("d", 10, 2, [100, -5, 0]),
+            int128("f", [4, 5, 6]),
+        ]);
+        assert_ne!(manifest(&renamed).merkle_root(), root);
+        let retyped = owned_table([
+            bigint("a", [1, 2, 3]),
+            varchar("b", ["x", "y", "z"]),
+            boolean("c", [true, false, true]),
+            decimal75("d", 10, 2, [100, -5, 0]),
+            bigint("e", [4, 5, 6]),
+        ]);
+        assert_ne!(manifest(&retyped).merkle_root(), root);
+
+        assert_eq!(
+            manifest(&owned_table([])).merkle_root(),
+            <[u8; 32]>::from(blake3::hash(&[]))
+        );
+    }
+
+    #[test]
+    fn valid_column_inclusion_proofs_verify_for_any_number_of_columns() {
+        let table = table();
+        for num_columns in 1..=5 {
+            let manifest = manifest(&owned_table(
+                table
+                    .inner_table()
+                    .iter()
+                    .take(num_columns)
+                    .map(|(name, column)| (*name, column.clone())),
+            ));
+            let root = manifest.merkle_root();
+            for entry in manifest.entries() {
+                let proof = manifest.inclusion_proof(&entry.name).unwrap();
+                assert!(proof.verify(entry, &root));
+                assert!(!proof.verify(entry, &[0; 32]));
+            }
+        }
+    }
+
+    #[test]
+    fn a_tampered_column_commitment_fails_its_inclusion_proof() {
+        let manifest = manifest(&table());
+        let root = manifest.merkle_root();
+        let name = "d".parse().unwrap();
+        let proof = manifest.inclusion_proof(&name).unwrap();
+        let entry = manifest.entry(&name).unwrap();
+        assert!(proof.verify(entry, &root));
+
+        let other_commitment = TableCommitment::<NaiveCommitment>::from_owned_table_with_offset(
+            &owned_table::<TestScalar>([decimal75("d", 10, 2, [100, -5, 1])]),
+            0,
+            &(),
+        )
+        .column_commitments()
+        .commitments()[0]
+            .clone();
+        let tampered = ManifestEntry {
+            commitment: other_commitment,
+            ..entry.clone()
+        };
+        assert!(!proof.verify(&tampered, &root));
+        let tampered_rows = ManifestEntry {
+            num_rows: 4,
+            ..entry.clone()
+        };
+        assert!(!proof.verify(&tampered_rows, &root));
+
+        // The proof of another column does not prove this entry.
+        let other_proof = manifest.inclusion_proof(&"e".parse().unwrap()).unwrap();
+        assert!(!other_proof.verify(entry, &root));
+        let moved_proof = ColumnInclusionProof {
+            index: 4,
+            ..proof.clone()
+        };
+        assert!(!moved_proof.verify(entry, &root));
+        let truncated_proof = ColumnInclusionProof {
+            siblings: proof.siblings[1..].to_vec(),
+            ..proof
+        };
+        assert!(!truncated_proof.verify(entry, &root));
+    }
+}
@@ -0,0 +1,365 @@
+use super::{Commitment, TableCommitment};
+use crate::base::{
+    database::{
+        ColumnField, ColumnRef, ColumnType, CommitmentAccessor, MetadataAccessor, OwnedTable,
+        SchemaAccessor, TableRef,
+    },
+    scalar::Scalar,
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+#[cfg(feature = "std")]
+use std::{
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+};
+
+/// The serialization format version written at the start of every serialized [`TableSnapshot`].
+pub const TABLE_SNAPSHOT_VERSION: u8 = 1;
+
+/// Errors that can occur when deserializing a [`TableSnapshot`].
+#[derive(Debug, Snafu)]
+pub enum TableSnapshotError {
+    /// The serialized snapshot is empty and has no version.
+    #[snafu(display("serialized table snapshot is empty"))]
+    MissingVersion,
+    /// The serialized snapshot was written with an unsupported format version.
+    #[snafu(display("unsupported table snapshot version: {version}"))]
+    UnsupportedVersion {
+        /// The version found in the serialized snapshot
+        version: u8,
+    },
+    /// The serialized snapshot could not be deserialized.
+    #[snafu(display("failed to deserialize table snapshot: {error}"))]
+    Deserialization {
+        /// The underlying deserialization error
+        error: postcard::Error,
+    },
+    /// The serialized snapshot contains an invalid table reference.
+    #[snafu(display("table snapshot contains an invalid table reference: {table_ref}"))]
+    InvalidTableRef {
+        /// The invalid table reference
+        table_ref: String,
+    },
+}
+
+/// The serialized form of a [`TableSnapshot`], following the version byte.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "C: Serialize + for<'a> Deserialize<'a>")]
+struct TableSnapshotBody<C: Commitment> {
+    table_ref: String,
+    table_commitment: TableCommitment<C>,
+}
+
+/// A committed table, bundling its schema, per-column commitments, and row count.
+///
+/// A snapshot can be persisted once and reloaded later, so that a table does not need to be recommitted
+/// while its data is unchanged. The snapshot implements the accessors needed for verification, so it can be
+/// passed directly as the commitment accessor when verifying a proof generated against the live table.
+/// Proof generation itself only reads the table data, and never recommits to the table's columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableSnapshot<C: Commitment> {
+    table_ref: TableRef,
+    table_commitment: TableCommitment<C>,
+}
+
+impl<C: Commitment> TableSnapshot<C> {
+    /// Create a new [`TableSnapshot`] from an existing table commitment.
+    #[must_use]
+    pub fn new(table_ref: TableRef, table_commitment: TableCommitment<C>) -> Self {
+        Self {
+            table_ref,
+            table_commitment,
+        }
+    }
+
+    /// Commit to an [`OwnedTable`] and bundle the result into a [`TableSnapshot`].
+    ///
+    /// The given generator offset will be used for committing to the table.
+    pub fn from_owned_table_with_offset<S>(
+        table_ref: TableRef,
+        owned_table: &OwnedTable<S>,
+        offset: usize,
+        setup: &C::PublicSetup<'_>,
+    ) -> Self
+    where
+        S: Scalar,
+    {
+        Self::new(
+            table_ref,
+            TableCommitment::from_owned_table_with_offset(owned_table, offset, setup),
+        )
+    }
+
+    /// Returns the table this snapshot is of.
+    #[must_use]
+    pub fn table_ref(&self) -> TableRef {
+        self.table_ref
+    }
+
+    /// Returns a reference to the table commitment.
+    #[must_use]
+    pub fn table_commitment(&self) -> &TableCommitment<C> {
+        &self.table_commitment
+    }
+
+    /// Returns the number of rows in the committed table.
+    #[must_use]
+    pub fn num_rows(&self) -> usize {
+        self.table_commitment.num_rows()
+    }
+
+    /// Returns the schema of the committed table.
+    #[must_use]
+    pub fn schema(&self) -> Vec<ColumnField> {
+        self.table_commitment
+            .column_commitments()
+            .column_metadata()
+            .iter()
+            .map(|(identifier, metadata)| ColumnField::new(*identifier, *metadata.column_type()))
+            .collect()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the table reference is not the one this snapshot is of.
+    fn assert_table_ref(&self, table_ref: TableRef) {
+        assert_eq!(
+            table_ref, self.table_ref,
+            "table snapshot is not a snapshot of the requested table"
+        );
+    }
+}
+
+impl<C> TableSnapshot<C>
+where
+    C: Commitment + Serialize + for<'a> Deserialize<'a>,
+{
+    /// Serialize this snapshot, prefixed with [`TABLE_SNAPSHOT_VERSION`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the snapshot cannot be serialized, which should not happen for valid commitments.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let body = TableSnapshotBody {
+            table_ref: self.table_ref.to_string(),
+            table_commitment: self.table_commitment.clone(),
+        };
+        let mut bytes = vec![TABLE_SNAPSHOT_VERSION];
+        bytes.extend(postcard::to_allocvec(&body).expect("table snapshot should serialize"));
+        bytes
+    }
+
+    /// Deserialize a snapshot that was serialized with [`TableSnapshot::to_bytes`].
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, TableSnapshotError> {
+        let (&version, body) = bytes
+            .split_first()
+            .ok_or(TableSnapshotError::MissingVersion)?;
+        if version != TABLE_SNAPSHOT_VERSION {
+            return Err(TableSnapshotError::UnsupportedVersion { version });
+        }
+        let body: TableSnapshotBody<C> = postcard::from_bytes(body)
+            .map_err(|error| TableSnapshotError::Deserialization { error })?;
+        let table_ref =
+            body.table_ref
+                .parse()
+                .map_err(|_| TableSnapshotError::InvalidTableRef {
+                    table_ref: body.table_ref.clone(),
+                })?;
+        Ok(Self::new(table_ref, body.table_commitment))
+    }
+
+    #[cfg(feature = "std")]
+    /// Function to save `TableSnapshot` to a file in binary form
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        fs::write(path, self.to_bytes())
+    }
+
+    #[cfg(feature = "std")]
+    /// Function to load `TableSnapshot` from a file in binary form
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        Self::try_from_bytes(&fs::read(path)?).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
+/// # Panics
+///
+/// Panics if the table reference is not the one this snapshot is of.
+impl<C: Commitment> MetadataAccessor for TableSnapshot<C> {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.assert_table_ref(table_ref);
+        self.table_commitment.num_rows()
+    }
+
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.assert_table_ref(table_ref);
+        self.table_commitment.range().start
+    }
+}
+
+/// # Panics
+///
+/// Panics if the table reference is not the one this snapshot is of, or if the column cannot be found.
+impl<C: Commitment> CommitmentAccessor<C> for TableSnapshot<C> {
+    fn get_commitment(&self, column: ColumnRef) -> C {
+        self.assert_table_ref(column.table_ref());
+        self.table_commitment
+            .column_commitments()
+            .get_commitment(&column.column_id())
+            .unwrap()
+    }
+}
+
+impl<C: Commitment> SchemaAccessor for TableSnapshot<C> {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
+        (table_ref == self.table_ref)
+            .then(|| {
+                self.table_commitment
+                    .column_commitments()
+                    .get_metadata(&column_id)
+                    .map(|column_metadata| *column_metadata.column_type())
+            })
+            .flatten()
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Identifier, ColumnType)> {
+        if table_ref == self.table_ref {
+            self.schema()
+                .into_iter()
+                .map(|field| (field.name(), field.data_type()))
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::database::{owned_table_utility::*, OwnedTableTestAccessor, TestAccessor},
+        proof_primitive::dory::{
+            test_rng, DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar,
+            DoryVerifierPublicSetup, ProverSetup, PublicParameters, VerifierSetup,
+        },
+        sql::{parse::QueryExpr, proof::QueryProof},
+    };
+
+    #[test]
+    fn we_can_serialize_and_deserialize_a_table_snapshot() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+        let table_ref = "sxt.table".parse().unwrap();
+        let table: OwnedTable<DoryScalar> =
+            owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]);
+        let snapshot = TableSnapshot::<DoryCommitment>::from_owned_table_with_offset(
+            table_ref, &table, 2, &setup,
+        );
+        assert_eq!(snapshot.num_rows(), 3);
+        assert_eq!(
+            snapshot.schema(),
+            vec![
+                ColumnField::new("a".parse().unwrap(), ColumnType::BigInt),
+                ColumnField::new("b".parse().unwrap(), ColumnType::VarChar),
+            ]
+        );
+
+        let bytes = snapshot.to_bytes();
+        assert_eq!(bytes[0], TABLE_SNAPSHOT_VERSION);
+        assert_eq!(
+            TableSnapshot::<DoryCommitment>::try_from_bytes(&bytes).unwrap(),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn we_cannot_deserialize_a_table_snapshot_with_an_unsupported_version() {
+        let table_ref = "sxt.table".parse().unwrap();
+        let snapshot = TableSnapshot::<DoryCommitment>::new(table_ref, TableCommitment::default());
+        let mut bytes = snapshot.to_bytes();
+        bytes[0] = TABLE_SNAPSHOT_VERSION + 1;
+        assert!(matches!(
+            TableSnapshot::<DoryCommitment>::try_from_bytes(&bytes),
+            Err(TableSnapshotError::UnsupportedVersion { version }) if version == TABLE_SNAPSHOT_VERSION + 1
+        ));
+        assert!(matches!(
+            TableSnapshot::<DoryCommitment>::try_from_bytes(&[]),
+            Err(TableSnapshotError::MissingVersion)
+        ));
+        assert!(matches!(
+            TableSnapshot::<DoryCommitment>::try_from_bytes(&[TABLE_SNAPSHOT_VERSION, 255]),
+            Err(TableSnapshotError::Deserialization { .. })
+        ));
+    }
+
+    #[test]
+    fn we_can_verify_a_proof_against_a_loaded_table_snapshot() {
+        let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let verifier_setup = VerifierSetup::from(&public_parameters);
+        let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+        let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+        let table_ref = "sxt.table".parse().unwrap();
+        let table = owned_table([bigint("a", [1, 2, 3, 4]), bigint("b", [1, 0, 1, 1])]);
+        let mut accessor =
+            OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+        accessor.add_table(table_ref, table.clone(), 0);
+
+        let path = std::env::temp_dir().join(format!(
+            "proof_of_sql_table_snapshot_{}.snapshot",
+            std::process::id()
+        ));
+        TableSnapshot::<DoryCommitment>::from_owned_table_with_offset(
+            table_ref,
+            &table,
+            0,
+            &dory_prover_setup,
+        )
+        .save(&path)
+        .unwrap();
+        let snapshot = TableSnapshot::<DoryCommitment>::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let query = QueryExpr::try_new(
+            "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+            "sxt".parse().unwrap(),
+            &snapshot,
+        )
+        .unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let snapshot_result = proof
+            .verify(
+                query.proof_expr(),
+                &snapshot,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap();
+        let live_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap();
+        assert_eq!(snapshot_result.table, live_result.table);
+        assert_eq!(snapshot_result.table, owned_table([bigint("a", [1, 3, 4])]));
+    }
+}
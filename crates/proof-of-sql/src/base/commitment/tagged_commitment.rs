@@ -0,0 +1,253 @@
+use super::{Commitment, CommittableColumn};
+use crate::base::{database::ColumnType, proof::Transcript};
+use serde::{Deserialize, Serialize};
+
+/// The domain separator hashed or appended before every [`TaggedCommitment`]
+const TAGGED_COMMITMENT_DOMAIN: &[u8] = b"proof-of-sql tagged column commitment";
+
+/// A column commitment bound to the type of the column, including the precision and scale of decimals.
+///
+/// The commitment schemes only commit to the scalars of a column, so the same integer data has the same
+/// commitment as a `BIGINT`, a `DECIMAL(10, 2)`, or a `DECIMAL(10, 4)`. A bare commitment that is passed around
+/// on its own, e.g. to check a column against it, therefore does not say at which scale the column was committed.
+/// A tagged commitment carries the type alongside the commitment, and only checks out against a column of that type.
+/// Its [`binding`](Self::binding) is a digest of both, and [`extend_transcript`](Self::extend_transcript)
+/// appends both to a transcript, so that anything derived from them depends on the declared type.
+///
+/// Query proofs do not use tagged commitments. The verifier plans a query with the column types of its
+/// [`ColumnCommitmentMetadata`](super::ColumnCommitmentMetadata), which fix the type and scale of each column
+/// when it is first committed and reject rows of any other type, and the plan is part of the transcript.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaggedCommitment<C> {
+    column_type: ColumnType,
+    commitment: C,
+}
+
+impl<C: Commitment + Serialize> TaggedCommitment<C> {
+    /// Create a tagged commitment from a column type and a commitment to a column of that type.
+    #[must_use]
+    pub fn new(column_type: ColumnType, commitment: C) -> Self {
+        Self {
+            column_type,
+            commitment,
+        }
+    }
+
+    /// Commit to `column` with the given generator offset, tagged with the type of the column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the commitment scheme does not return a commitment for the column, which should not happen.
+    #[must_use]
+    pub fn compute<'a>(
+        column: impl Into<CommittableColumn<'a>>,
+        offset: usize,
+        setup: &C::PublicSetup<'_>,
+    ) -> Self {
+        let column = column.into();
+        let column_type = ColumnType::from(&column);
+        let commitment = C::compute_commitments(&[column], offset, setup)
+            .pop()
+            .expect("one commitment should be computed per column");
+        Self::new(column_type, commitment)
+    }
+
+    /// Returns the type of the committed column.
+    #[must_use]
+    pub fn column_type(&self) -> &ColumnType {
+        &self.column_type
+    }
+
+    /// Returns the untagged commitment.
+    #[must_use]
+    pub fn commitment(&self) -> &C {
+        &self.commitment
+    }
+
+    /// Returns the blake3 digest of the type and the commitment after a domain separator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the commitment cannot be serialized, which should not happen for valid commitments.
+    #[must_use]
+    pub fn binding(&self) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(TAGGED_COMMITMENT_DOMAIN);
+        hasher.update(&postcard::to_allocvec(self).expect("tagged commitment should serialize"));
+        hasher.finalize().into()
+    }
+
+    /// Appends a domain separator, the type, and the commitment to the transcript, in that order.
+    pub fn extend_transcript(&self, transcript: &mut impl Transcript) {
+        transcript.extend_as_be(TAGGED_COMMITMENT_DOMAIN.iter().copied());
+        transcript.extend_serialize_as_le(&self.column_type);
+        transcript.extend_serialize_as_le(&self.commitment);
+    }
+
+    /// Check that this is the commitment to `column` with the given generator offset,
+    /// and that `column` has the type of the tag.
+    ///
+    /// See [`Commitment::verify_against`] for the caveats of recommitting to the raw column.
+    pub fn verify_against<'a>(
+        &self,
+        column: impl Into<CommittableColumn<'a>>,
+        offset: usize,
+        setup: &C::PublicSetup<'_>,
+    ) -> bool {
+        let column = column.into();
+        ColumnType::from(&column) == self.column_type
+            && self.commitment.verify_against(column, offset, setup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{
+        commitment::{
+            naive_commitment::NaiveCommitment, AppendColumnCommitmentsError, ColumnCommitments,
+            ColumnCommitmentsMismatch, QueryCommitments, TableCommitment,
+        },
+        database::{owned_table_utility::*, OwnedColumn, SchemaAccessor},
+        math::decimal::Precision,
+        proof::{Keccak256Transcript, Transcript},
+        ref_into::RefInto,
+        scalar::test_scalar::TestScalar,
+    };
+
+    fn decimal(scale: i8, values: &[i64]) -> CommittableColumn<'static> {
+        CommittableColumn::Decimal75(
+            Precision::new(10).unwrap(),
+            scale,
+            values
+                .iter()
+                .map(|&value| RefInto::<[u64; 4]>::ref_into(&TestScalar::from(value)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn tagged_commitments_of_the_same_data_at_different_scales_differ() {
+        let values = [100, -5, 0, 7];
+        let scale_2 = TaggedCommitment::<NaiveCommitment>::compute(decimal(2, &values), 0, &());
+        let scale_4 = TaggedCommitment::<NaiveCommitment>::compute(decimal(4, &values), 0, &());
+        let bigint = TaggedCommitment::<NaiveCommitment>::compute(&[100_i64, -5, 0, 7][..], 0, &());
+
+        // The untagged commitments cannot tell the scales apart.
+        assert_eq!(scale_2.commitment(), scale_4.commitment());
+        assert_eq!(scale_2.commitment(), bigint.commitment());
+
+        assert_eq!(
+            *scale_2.column_type(),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2)
+        );
+        assert_ne!(scale_2, scale_4);
+        assert_ne!(scale_2.binding(), scale_4.binding());
+        assert_ne!(scale_2.binding(), bigint.binding());
+        assert_eq!(
+            scale_2.binding(),
+            TaggedCommitment::<NaiveCommitment>::compute(decimal(2, &values), 0, &()).binding()
+        );
+
+        let challenge = |tagged: &TaggedCommitment<NaiveCommitment>| {
+            let mut transcript = Keccak256Transcript::new();
+            tagged.extend_transcript(&mut transcript);
+            transcript.challenge_as_le()
+        };
+        assert_ne!(challenge(&scale_2), challenge(&scale_4));
+        assert_eq!(
+            challenge(&scale_2),
+            challenge(&TaggedCommitment::new(
+                *scale_2.column_type(),
+                scale_2.commitment().clone()
+            ))
+        );
+    }
+
+    #[test]
+    fn we_cannot_verify_a_tagged_commitment_against_a_column_of_another_scale() {
+        let values = [100, -5, 0, 7];
+        let scale_2 = TaggedCommitment::<NaiveCommitment>::compute(decimal(2, &values), 0, &());
+
+        assert!(scale_2.verify_against(decimal(2, &values), 0, &()));
+        // The untagged commitment verifies against the same data at any scale.
+        assert!(scale_2
+            .commitment()
+            .verify_against(decimal(4, &values), 0, &()));
+        assert!(!scale_2.verify_against(decimal(4, &values), 0, &()));
+        assert!(!scale_2.verify_against(&[100_i64, -5, 0, 7][..], 0, &()));
+        // Relabeling the commitment with another scale does not help either.
+        let relabeled = TaggedCommitment::new(
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 4),
+            scale_2.commitment().clone(),
+        );
+        assert_ne!(relabeled.binding(), scale_2.binding());
+        assert!(!relabeled.verify_against(decimal(2, &values), 0, &()));
+        // The data and the offset are still checked.
+        assert!(!scale_2.verify_against(decimal(2, &[100, -5, 0, 8]), 0, &()));
+        assert!(!scale_2.verify_against(decimal(2, &values), 1, &()));
+    }
+
+    #[test]
+    fn we_can_get_the_tagged_commitments_of_column_commitments() {
+        let table = owned_table::<TestScalar>([
+            bigint("a", [100, -5, 0, 7]),
+            decimal75("b", 10, 2, [100, -5, 0, 7]),
+        ]);
+        let column_commitments =
+            ColumnCommitments::<NaiveCommitment>::try_from_columns_with_offset(
+                table.inner_table(),
+                0,
+                &(),
+            )
+            .unwrap();
+        let a = column_commitments
+            .get_tagged_commitment(&"a".parse().unwrap())
+            .unwrap();
+        let b = column_commitments
+            .get_tagged_commitment(&"b".parse().unwrap())
+            .unwrap();
+        assert!(column_commitments
+            .get_tagged_commitment(&"c".parse().unwrap())
+            .is_none());
+
+        assert_eq!(a.commitment(), b.commitment());
+        assert_ne!(a.binding(), b.binding());
+        let b_column: &OwnedColumn<TestScalar> = &table.inner_table()[1];
+        assert_eq!(
+            b,
+            TaggedCommitment::<NaiveCommitment>::compute(b_column, 0, &())
+        );
+        assert!(b.verify_against(b_column, 0, &()));
+        assert!(!b.verify_against(decimal(4, &[100, -5, 0, 7]), 0, &()));
+        assert!(!a.verify_against(b_column, 0, &()));
+    }
+
+    #[test]
+    fn the_column_commitment_metadata_already_fixes_the_scale_of_a_column() {
+        let table = owned_table::<TestScalar>([decimal75("d", 10, 2, [100, -5, 0, 7])]);
+        let table_commitment =
+            TableCommitment::<NaiveCommitment>::from_owned_table_with_offset(&table, 0, &());
+        let t = "sxt.t".parse().unwrap();
+        let d = "d".parse().unwrap();
+
+        // The verifier plans queries with the type in the metadata.
+        let query_commitments = QueryCommitments::from_iter([(t, table_commitment.clone())]);
+        assert_eq!(
+            query_commitments.lookup_column(t, d),
+            Some(ColumnType::Decimal75(Precision::new(10).unwrap(), 2))
+        );
+
+        // The same data cannot be appended at another scale.
+        let mut column_commitments = table_commitment.column_commitments().clone();
+        assert!(matches!(
+            column_commitments.try_append_rows_with_offset([(&d, decimal(4, &[1, 2]))], 4, &()),
+            Err(AppendColumnCommitmentsError::Mismatch {
+                source: ColumnCommitmentsMismatch::ColumnCommitmentMetadata { .. }
+            })
+        ));
+        assert!(column_commitments
+            .try_append_rows_with_offset([(&d, decimal(2, &[1, 2]))], 4, &())
+            .is_ok());
+    }
+}
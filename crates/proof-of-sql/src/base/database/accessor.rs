@@ -54,6 +54,31 @@ pub trait CommitmentAccessor<C: Commitment>: MetadataAccessor {
     fn get_commitment(&self, column: ColumnRef) -> C;
 }
 
+/// A store of database column commitments that are fetched one at a time, such as a key-value store.
+///
+/// Unlike a [`CommitmentAccessor`], a source need not hold every column, so the verifier fetches only the
+/// columns a query references and reports any that are missing.
+/// See [`QueryProof::verify_with_commitment_source`](crate::sql::proof::QueryProof::verify_with_commitment_source).
+pub trait CommitmentSource<C: Commitment> {
+    /// Return the full table column commitment, or `None` if the source does not have it
+    fn get(&self, column: ColumnRef) -> Option<C>;
+}
+
+impl<C: Commitment> CommitmentSource<C> for IndexMap<ColumnRef, C> {
+    fn get(&self, column: ColumnRef) -> Option<C> {
+        IndexMap::get(self, &column).cloned()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Commitment, H: core::hash::BuildHasher> CommitmentSource<C>
+    for std::collections::HashMap<ColumnRef, C, H>
+{
+    fn get(&self, column: ColumnRef) -> Option<C> {
+        std::collections::HashMap::get(self, &column).cloned()
+    }
+}
+
 /// Access database columns of an in-memory table span.
 ///
 /// Prover uses this information to process a query.
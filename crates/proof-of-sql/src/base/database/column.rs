@@ -36,6 +36,8 @@ pub enum Column<'a, S: Scalar> {
     Int(&'a [i32]),
     /// i64 columns
     BigInt(&'a [i64]),
+    /// u64 columns
+    UInt64(&'a [u64]),
     /// i128 columns
     Int128(&'a [i128]),
     /// Decimal columns with a max width of 252 bits
@@ -52,6 +54,10 @@ pub enum Column<'a, S: Scalar> {
     /// - the second element maps to a timezone
     /// - the third element maps to columns of timeunits since unix epoch
     TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, &'a [i64]),
+    /// Fixed-length binary columns
+    /// - the first element maps to the length in bytes of every value
+    /// - the second element maps to the byte values and their hashes (see [`crate::base::scalar::Scalar`])
+    FixedBytes(u8, (&'a [&'a [u8]], &'a [S])),
 }
 
 impl<'a, S: Scalar> Column<'a, S> {
@@ -64,7 +70,9 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::SmallInt(_) => ColumnType::SmallInt,
             Self::Int(_) => ColumnType::Int,
             Self::BigInt(_) => ColumnType::BigInt,
+            Self::UInt64(_) => ColumnType::UInt64,
             Self::VarChar(_) => ColumnType::VarChar,
+            Self::FixedBytes(width, _) => ColumnType::FixedBytes(*width),
             Self::Int128(_) => ColumnType::Int128,
             Self::Scalar(_) => ColumnType::Scalar,
             Self::Decimal75(precision, scale, _) => ColumnType::Decimal75(*precision, *scale),
@@ -84,10 +92,15 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::SmallInt(col) => col.len(),
             Self::Int(col) => col.len(),
             Self::BigInt(col) | Self::TimestampTZ(_, _, col) => col.len(),
+            Self::UInt64(col) => col.len(),
             Self::VarChar((col, scals)) => {
                 assert_eq!(col.len(), scals.len());
                 col.len()
             }
+            Self::FixedBytes(_, (col, scals)) => {
+                assert_eq!(col.len(), scals.len());
+                col.len()
+            }
             Self::Int128(col) => col.len(),
             Self::Scalar(col) | Self::Decimal75(_, _, col) => col.len(),
         }
@@ -99,6 +112,10 @@ impl<'a, S: Scalar> Column<'a, S> {
     }
 
     /// Generate a constant column from a literal value with a given length
+    ///
+    /// # Panics
+    ///
+    /// Panics if a fixed bytes literal is longer than 255 bytes.
     pub fn from_literal_with_length(
         literal: &LiteralValue,
         length: usize,
@@ -118,6 +135,9 @@ impl<'a, S: Scalar> Column<'a, S> {
             LiteralValue::BigInt(value) => {
                 Column::BigInt(alloc.alloc_slice_fill_copy(length, *value))
             }
+            LiteralValue::UInt64(value) => {
+                Column::UInt64(alloc.alloc_slice_fill_copy(length, *value))
+            }
             LiteralValue::Int128(value) => {
                 Column::Int128(alloc.alloc_slice_fill_copy(length, *value))
             }
@@ -136,6 +156,15 @@ impl<'a, S: Scalar> Column<'a, S> {
                 alloc.alloc_slice_fill_with(length, |_| alloc.alloc_str(string) as &str),
                 alloc.alloc_slice_fill_copy(length, S::from(string)),
             )),
+            LiteralValue::FixedBytes(bytes) => Column::FixedBytes(
+                u8::try_from(bytes.len()).expect("fixed bytes literals are at most 255 bytes"),
+                (
+                    alloc.alloc_slice_fill_with(length, |_| {
+                        alloc.alloc_slice_copy(bytes) as &[u8]
+                    }),
+                    alloc.alloc_slice_fill_copy(length, S::from(bytes.as_slice())),
+                ),
+            ),
         }
     }
 
@@ -147,6 +176,7 @@ impl<'a, S: Scalar> Column<'a, S> {
             OwnedColumn::SmallInt(col) => Column::SmallInt(col.as_slice()),
             OwnedColumn::Int(col) => Column::Int(col.as_slice()),
             OwnedColumn::BigInt(col) => Column::BigInt(col.as_slice()),
+            OwnedColumn::UInt64(col) => Column::UInt64(col.as_slice()),
             OwnedColumn::Int128(col) => Column::Int128(col.as_slice()),
             OwnedColumn::Decimal75(precision, scale, col) => {
                 Column::Decimal75(*precision, *scale, col.as_slice())
@@ -164,6 +194,23 @@ impl<'a, S: Scalar> Column<'a, S> {
                 ))
             }
             OwnedColumn::TimestampTZ(tu, tz, col) => Column::TimestampTZ(*tu, *tz, col.as_slice()),
+            OwnedColumn::FixedBytes(width, col) => {
+                let scalars = col
+                    .iter()
+                    .map(|bytes| S::from(bytes.as_slice()))
+                    .collect::<Vec<_>>();
+                let slices = col
+                    .iter()
+                    .map(|bytes| bytes.as_slice() as &'a [u8])
+                    .collect::<Vec<_>>();
+                Column::FixedBytes(
+                    *width,
+                    (
+                        alloc.alloc_slice_copy(slices.as_slice()),
+                        alloc.alloc_slice_copy(scalars.as_slice()),
+                    ),
+                )
+            }
         }
     }
 
@@ -207,6 +254,14 @@ impl<'a, S: Scalar> Column<'a, S> {
         }
     }
 
+    /// Returns the column as a slice of u64 if it is a uint64 column. Otherwise, returns None.
+    pub(crate) fn as_uint64(&self) -> Option<&'a [u64]> {
+        match self {
+            Self::UInt64(col) => Some(col),
+            _ => None,
+        }
+    }
+
     /// Returns the column as a slice of i128 if it is an int128 column. Otherwise, returns None.
     pub(crate) fn as_int128(&self) -> Option<&'a [i128]> {
         match self {
@@ -239,6 +294,14 @@ impl<'a, S: Scalar> Column<'a, S> {
         }
     }
 
+    /// Returns the column as a slice of byte values and a slice of scalars if it is a fixed bytes column. Otherwise, returns None.
+    pub(crate) fn as_fixed_bytes(&self) -> Option<(&'a [&'a [u8]], &'a [S])> {
+        match self {
+            Self::FixedBytes(_, (col, scals)) => Some((col, scals)),
+            _ => None,
+        }
+    }
+
     /// Returns the column as a slice of i64 if it is a timestamp column. Otherwise, returns None.
     pub(crate) fn as_timestamptz(&self) -> Option<&'a [i64]> {
         match self {
@@ -257,9 +320,10 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::SmallInt(col) => S::from(col[index]),
             Self::Int(col) => S::from(col[index]),
             Self::BigInt(col) | Self::TimestampTZ(_, _, col) => S::from(col[index]),
+            Self::UInt64(col) => S::from(col[index]),
             Self::Int128(col) => S::from(col[index]),
             Self::Scalar(col) | Self::Decimal75(_, _, col) => col[index],
-            Self::VarChar((_, scals)) => scals[index],
+            Self::VarChar((_, scals)) | Self::FixedBytes(_, (_, scals)) => scals[index],
         })
     }
 
@@ -270,11 +334,14 @@ impl<'a, S: Scalar> Column<'a, S> {
         match self {
             Self::Boolean(col) => slice_cast_with(col, |b| S::from(b) * scale_factor),
             Self::Decimal75(_, _, col) => slice_cast_with(col, |s| *s * scale_factor),
-            Self::VarChar((_, values)) => slice_cast_with(values, |s| *s * scale_factor),
+            Self::VarChar((_, values)) | Self::FixedBytes(_, (_, values)) => {
+                slice_cast_with(values, |s| *s * scale_factor)
+            }
             Self::TinyInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::SmallInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::Int(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::BigInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
+            Self::UInt64(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::Int128(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::Scalar(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::TimestampTZ(_, _, col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
@@ -304,6 +371,9 @@ pub enum ColumnType {
     /// Mapped to i64
     #[serde(alias = "BIGINT", alias = "bigint")]
     BigInt,
+    /// Mapped to u64
+    #[serde(alias = "UBIGINT", alias = "ubigint")]
+    UInt64,
     /// Mapped to i128
     #[serde(rename = "Decimal", alias = "DECIMAL", alias = "decimal")]
     Int128,
@@ -319,6 +389,9 @@ pub enum ColumnType {
     /// Mapped to `S`
     #[serde(alias = "SCALAR", alias = "scalar")]
     Scalar,
+    /// Mapped to `[u8; N]` for the given `N`
+    #[serde(alias = "BINARY", alias = "binary")]
+    FixedBytes(u8),
 }
 
 impl ColumnType {
@@ -331,6 +404,7 @@ impl ColumnType {
                 | ColumnType::SmallInt
                 | ColumnType::Int
                 | ColumnType::BigInt
+                | ColumnType::UInt64
                 | ColumnType::Int128
                 | ColumnType::Scalar
                 | ColumnType::Decimal75(_, _)
@@ -346,11 +420,12 @@ impl ColumnType {
                 | ColumnType::SmallInt
                 | ColumnType::Int
                 | ColumnType::BigInt
+                | ColumnType::UInt64
                 | ColumnType::Int128
         )
     }
 
-    /// Returns the number of bits in the integer type if it is an integer type. Otherwise, return None.
+    /// Returns the number of bits in the integer type if it is a signed integer type. Otherwise, return None.
     fn to_integer_bits(self) -> Option<usize> {
         match self {
             ColumnType::TinyInt => Some(8),
@@ -378,6 +453,9 @@ impl ColumnType {
 
     /// Returns the larger integer type of two [`ColumnType`]s if they are both integers.
     ///
+    /// Two unsigned integers give [`ColumnType::UInt64`]. An unsigned and a signed integer give
+    /// [`ColumnType::Int128`], which is the smallest integer type that holds the values of both.
+    ///
     /// If either of the columns is not an integer, return None.
     #[must_use]
     pub fn max_integer_type(&self, other: &Self) -> Option<Self> {
@@ -385,6 +463,11 @@ impl ColumnType {
         if !self.is_integer() || !other.is_integer() {
             return None;
         }
+        match (self, other) {
+            (ColumnType::UInt64, ColumnType::UInt64) => return Some(ColumnType::UInt64),
+            (ColumnType::UInt64, _) | (_, ColumnType::UInt64) => return Some(ColumnType::Int128),
+            _ => {}
+        }
         self.to_integer_bits().and_then(|self_bits| {
             other
                 .to_integer_bits()
@@ -400,12 +483,13 @@ impl ColumnType {
             Self::SmallInt => Some(5_u8),
             Self::Int => Some(10_u8),
             Self::BigInt | Self::TimestampTZ(_, _) => Some(19_u8),
+            Self::UInt64 => Some(20_u8),
             Self::Int128 => Some(39_u8),
             Self::Decimal75(precision, _) => Some(precision.value()),
             // Scalars are not in database & are only used for typeless comparisons for testing so we return 0
             // so that they do not cause errors when used in comparisons.
             Self::Scalar => Some(0_u8),
-            Self::Boolean | Self::VarChar => None,
+            Self::Boolean | Self::VarChar | Self::FixedBytes(_) => None,
         }
     }
     /// Returns scale of a [`ColumnType`] if it is convertible to a decimal wrapped in `Some()`. Otherwise return None.
@@ -417,9 +501,10 @@ impl ColumnType {
             | Self::SmallInt
             | Self::Int
             | Self::BigInt
+            | Self::UInt64
             | Self::Int128
             | Self::Scalar => Some(0),
-            Self::Boolean | Self::VarChar => None,
+            Self::Boolean | Self::VarChar | Self::FixedBytes(_) => None,
             Self::TimestampTZ(tu, _) => match tu {
                 PoSQLTimeUnit::Second => Some(0),
                 PoSQLTimeUnit::Millisecond => Some(3),
@@ -438,8 +523,11 @@ impl ColumnType {
             Self::SmallInt => size_of::<i16>(),
             Self::Int => size_of::<i32>(),
             Self::BigInt | Self::TimestampTZ(_, _) => size_of::<i64>(),
+            Self::UInt64 => size_of::<u64>(),
             Self::Int128 => size_of::<i128>(),
-            Self::Scalar | Self::Decimal75(_, _) | Self::VarChar => size_of::<[u64; 4]>(),
+            Self::Scalar | Self::Decimal75(_, _) | Self::VarChar | Self::FixedBytes(_) => {
+                size_of::<[u64; 4]>()
+            }
         }
     }
 
@@ -460,7 +548,12 @@ impl ColumnType {
             | Self::BigInt
             | Self::Int128
             | Self::TimestampTZ(_, _) => true,
-            Self::Decimal75(_, _) | Self::Scalar | Self::VarChar | Self::Boolean => false,
+            Self::UInt64
+            | Self::Decimal75(_, _)
+            | Self::Scalar
+            | Self::VarChar
+            | Self::Boolean
+            | Self::FixedBytes(_) => false,
         }
     }
 }
@@ -474,6 +567,7 @@ impl Display for ColumnType {
             ColumnType::SmallInt => write!(f, "SMALLINT"),
             ColumnType::Int => write!(f, "INT"),
             ColumnType::BigInt => write!(f, "BIGINT"),
+            ColumnType::UInt64 => write!(f, "UBIGINT"),
             ColumnType::Int128 => write!(f, "DECIMAL"),
             ColumnType::Decimal75(precision, scale) => {
                 write!(
@@ -483,6 +577,7 @@ impl Display for ColumnType {
                 )
             }
             ColumnType::VarChar => write!(f, "VARCHAR"),
+            ColumnType::FixedBytes(width) => write!(f, "BINARY({width})"),
             ColumnType::Scalar => write!(f, "SCALAR"),
             ColumnType::TimestampTZ(timeunit, timezone) => {
                 write!(f, "TIMESTAMP(TIMEUNIT: {timeunit}, TIMEZONE: {timezone})")
@@ -591,6 +686,10 @@ mod tests {
         let serialized = serde_json::to_string(&column_type).unwrap();
         assert_eq!(serialized, r#""BigInt""#);
 
+        let column_type = ColumnType::UInt64;
+        let serialized = serde_json::to_string(&column_type).unwrap();
+        assert_eq!(serialized, r#""UInt64""#);
+
         let column_type = ColumnType::Int128;
         let serialized = serde_json::to_string(&column_type).unwrap();
         assert_eq!(serialized, r#""Decimal""#);
@@ -693,6 +792,14 @@ mod tests {
             serde_json::from_str::<ColumnType>(r#""BIGINT""#).unwrap(),
             ColumnType::BigInt
         );
+        assert_eq!(
+            serde_json::from_str::<ColumnType>(r#""ubigint""#).unwrap(),
+            ColumnType::UInt64
+        );
+        assert_eq!(
+            serde_json::from_str::<ColumnType>(r#""UBIGINT""#).unwrap(),
+            ColumnType::UInt64
+        );
         assert_eq!(
             serde_json::from_str::<ColumnType>(r#""TINYINT""#).unwrap(),
             ColumnType::TinyInt
@@ -761,6 +868,16 @@ mod tests {
             serde_json::from_str::<ColumnType>(r#"{"DECIMAL75":[1,-128]}"#).unwrap(),
             ColumnType::Decimal75(Precision::new(1).unwrap(), -128)
         );
+
+        assert_eq!(
+            serde_json::from_str::<ColumnType>(r#"{"BINARY":16}"#).unwrap(),
+            ColumnType::FixedBytes(16)
+        );
+        assert_eq!(
+            serde_json::from_str::<ColumnType>(r#"{"binary":16}"#).unwrap(),
+            ColumnType::FixedBytes(16)
+        );
+        assert_eq!(ColumnType::FixedBytes(16).to_string(), "BINARY(16)");
     }
 
     #[test]
@@ -1012,6 +1129,37 @@ mod tests {
         assert_eq!(owned_col, new_owned_col);
     }
 
+    #[test]
+    fn we_can_get_the_max_integer_type_of_unsigned_integers() {
+        assert_eq!(
+            ColumnType::UInt64.max_integer_type(&ColumnType::UInt64),
+            Some(ColumnType::UInt64)
+        );
+        for signed in [
+            ColumnType::TinyInt,
+            ColumnType::SmallInt,
+            ColumnType::Int,
+            ColumnType::BigInt,
+            ColumnType::Int128,
+        ] {
+            assert_eq!(
+                ColumnType::UInt64.max_integer_type(&signed),
+                Some(ColumnType::Int128)
+            );
+            assert_eq!(
+                signed.max_integer_type(&ColumnType::UInt64),
+                Some(ColumnType::Int128)
+            );
+        }
+        assert_eq!(
+            ColumnType::UInt64.max_integer_type(&ColumnType::VarChar),
+            None
+        );
+        assert!(ColumnType::UInt64.is_integer());
+        assert!(!ColumnType::UInt64.is_signed());
+        assert_eq!(ColumnType::UInt64.to_string(), "UBIGINT");
+    }
+
     #[test]
     fn we_can_get_the_data_size_of_a_column() {
         let column = Column::<DoryScalar>::Boolean(&[true, false, true]);
@@ -1034,6 +1182,10 @@ mod tests {
         assert_eq!(column.column_type().byte_size(), 8);
         assert_eq!(column.column_type().bit_size(), 64);
 
+        let column = Column::<TestScalar>::UInt64(&[1]);
+        assert_eq!(column.column_type().byte_size(), 8);
+        assert_eq!(column.column_type().bit_size(), 64);
+
         let column = Column::<DoryScalar>::Int128(&[1, 2]);
         assert_eq!(column.column_type().byte_size(), 16);
         assert_eq!(column.column_type().bit_size(), 128);
@@ -6,8 +6,8 @@ use crate::base::{
             try_subtract_decimal_columns,
         },
         slice_operation::{
-            try_add, try_div, try_mul, try_slice_binary_op, try_slice_binary_op_left_upcast,
-            try_slice_binary_op_right_upcast, try_sub,
+            try_add, try_div, try_mul, try_slice_binary_op, try_slice_binary_op_both_upcast,
+            try_slice_binary_op_left_upcast, try_slice_binary_op_right_upcast, try_sub,
         },
         ColumnType, OwnedColumn,
     },
@@ -151,6 +151,93 @@ pub trait ArithmeticOp {
                 Ok(OwnedColumn::Decimal75(new_precision, new_scale, new_values))
             }
 
+            (OwnedColumn::UInt64(lhs), OwnedColumn::UInt64(rhs)) => Ok(OwnedColumn::UInt64(
+                try_slice_binary_op(lhs, rhs, Self::op)?,
+            )),
+            (OwnedColumn::UInt64(lhs), OwnedColumn::TinyInt(rhs)) => {
+                Ok(OwnedColumn::Int128(try_slice_binary_op_both_upcast::<
+                    _,
+                    _,
+                    i128,
+                    _,
+                    _,
+                >(lhs, rhs, Self::op)?))
+            }
+            (OwnedColumn::UInt64(lhs), OwnedColumn::SmallInt(rhs)) => {
+                Ok(OwnedColumn::Int128(try_slice_binary_op_both_upcast::<
+                    _,
+                    _,
+                    i128,
+                    _,
+                    _,
+                >(lhs, rhs, Self::op)?))
+            }
+            (OwnedColumn::UInt64(lhs), OwnedColumn::Int(rhs)) => {
+                Ok(OwnedColumn::Int128(try_slice_binary_op_both_upcast::<
+                    _,
+                    _,
+                    i128,
+                    _,
+                    _,
+                >(lhs, rhs, Self::op)?))
+            }
+            (OwnedColumn::UInt64(lhs), OwnedColumn::BigInt(rhs)) => {
+                Ok(OwnedColumn::Int128(try_slice_binary_op_both_upcast::<
+                    _,
+                    _,
+                    i128,
+                    _,
+                    _,
+                >(lhs, rhs, Self::op)?))
+            }
+            (OwnedColumn::UInt64(lhs), OwnedColumn::Int128(rhs)) => Ok(OwnedColumn::Int128(
+                try_slice_binary_op_left_upcast(lhs, rhs, Self::op)?,
+            )),
+            (OwnedColumn::UInt64(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
+                let (new_precision, new_scale, new_values) =
+                    Self::decimal_op(lhs_values, rhs_values, lhs.column_type(), rhs.column_type())?;
+                Ok(OwnedColumn::Decimal75(new_precision, new_scale, new_values))
+            }
+            (OwnedColumn::TinyInt(lhs), OwnedColumn::UInt64(rhs)) => {
+                Ok(OwnedColumn::Int128(try_slice_binary_op_both_upcast::<
+                    _,
+                    _,
+                    i128,
+                    _,
+                    _,
+                >(lhs, rhs, Self::op)?))
+            }
+            (OwnedColumn::SmallInt(lhs), OwnedColumn::UInt64(rhs)) => {
+                Ok(OwnedColumn::Int128(try_slice_binary_op_both_upcast::<
+                    _,
+                    _,
+                    i128,
+                    _,
+                    _,
+                >(lhs, rhs, Self::op)?))
+            }
+            (OwnedColumn::Int(lhs), OwnedColumn::UInt64(rhs)) => {
+                Ok(OwnedColumn::Int128(try_slice_binary_op_both_upcast::<
+                    _,
+                    _,
+                    i128,
+                    _,
+                    _,
+                >(lhs, rhs, Self::op)?))
+            }
+            (OwnedColumn::BigInt(lhs), OwnedColumn::UInt64(rhs)) => {
+                Ok(OwnedColumn::Int128(try_slice_binary_op_both_upcast::<
+                    _,
+                    _,
+                    i128,
+                    _,
+                    _,
+                >(lhs, rhs, Self::op)?))
+            }
+            (OwnedColumn::Int128(lhs), OwnedColumn::UInt64(rhs)) => Ok(OwnedColumn::Int128(
+                try_slice_binary_op_right_upcast(lhs, rhs, Self::op)?,
+            )),
+
             (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::TinyInt(rhs_values)) => {
                 let (new_precision, new_scale, new_values) =
                     Self::decimal_op(lhs_values, rhs_values, lhs.column_type(), rhs.column_type())?;
@@ -176,6 +263,11 @@ pub trait ArithmeticOp {
                     Self::decimal_op(lhs_values, rhs_values, lhs.column_type(), rhs.column_type())?;
                 Ok(OwnedColumn::Decimal75(new_precision, new_scale, new_values))
             }
+            (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::UInt64(rhs_values)) => {
+                let (new_precision, new_scale, new_values) =
+                    Self::decimal_op(lhs_values, rhs_values, lhs.column_type(), rhs.column_type())?;
+                Ok(OwnedColumn::Decimal75(new_precision, new_scale, new_values))
+            }
             (
                 OwnedColumn::Decimal75(_, _, lhs_values),
                 OwnedColumn::Decimal75(_, _, rhs_values),
@@ -3,7 +3,8 @@ use crate::base::{
     database::{
         slice_decimal_operation::{eq_decimal_columns, ge_decimal_columns, le_decimal_columns},
         slice_operation::{
-            slice_binary_op, slice_binary_op_left_upcast, slice_binary_op_right_upcast,
+            slice_binary_op, slice_binary_op_both_upcast, slice_binary_op_left_upcast,
+            slice_binary_op_right_upcast,
         },
         ColumnType, OwnedColumn,
     },
@@ -44,6 +45,13 @@ pub trait ComparisonOp {
     /// Return an error if op is not implemented for string
     fn string_op(lhs: &[String], rhs: &[String]) -> ColumnOperationResult<Vec<bool>>;
 
+    /// Return an error if op is not implemented for fixed-length binary values of the given width
+    fn fixed_bytes_op(
+        lhs: &[Vec<u8>],
+        rhs: &[Vec<u8>],
+        width: u8,
+    ) -> ColumnOperationResult<Vec<bool>>;
+
     #[allow(clippy::too_many_lines)]
     fn owned_column_element_wise_comparison<S: Scalar>(
         lhs: &OwnedColumn<S>,
@@ -55,187 +63,242 @@ pub trait ComparisonOp {
                 len_b: rhs.len(),
             });
         }
-        let result = match (&lhs, &rhs) {
-            (OwnedColumn::TinyInt(lhs), OwnedColumn::TinyInt(rhs)) => {
-                Ok(slice_binary_op(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::TinyInt(lhs), OwnedColumn::SmallInt(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::TinyInt(lhs), OwnedColumn::Int(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::TinyInt(lhs), OwnedColumn::BigInt(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::TinyInt(lhs), OwnedColumn::Int128(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::TinyInt(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
-                Ok(Self::decimal_op_left_upcast(
-                    lhs_values,
-                    rhs_values,
-                    lhs.column_type(),
-                    rhs.column_type(),
-                ))
-            }
+        let result =
+            match (&lhs, &rhs) {
+                (OwnedColumn::TinyInt(lhs), OwnedColumn::TinyInt(rhs)) => {
+                    Ok(slice_binary_op(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::TinyInt(lhs), OwnedColumn::SmallInt(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::TinyInt(lhs), OwnedColumn::Int(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::TinyInt(lhs), OwnedColumn::BigInt(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::TinyInt(lhs), OwnedColumn::Int128(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::TinyInt(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
+                    Ok(Self::decimal_op_left_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
 
-            (OwnedColumn::SmallInt(lhs), OwnedColumn::TinyInt(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::SmallInt(lhs), OwnedColumn::SmallInt(rhs)) => {
-                Ok(slice_binary_op(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::SmallInt(lhs), OwnedColumn::Int(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::SmallInt(lhs), OwnedColumn::BigInt(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::SmallInt(lhs), OwnedColumn::Int128(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::SmallInt(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
-                Ok(Self::decimal_op_left_upcast(
-                    lhs_values,
-                    rhs_values,
-                    lhs.column_type(),
-                    rhs.column_type(),
-                ))
-            }
+                (OwnedColumn::SmallInt(lhs), OwnedColumn::TinyInt(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::SmallInt(lhs), OwnedColumn::SmallInt(rhs)) => {
+                    Ok(slice_binary_op(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::SmallInt(lhs), OwnedColumn::Int(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::SmallInt(lhs), OwnedColumn::BigInt(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::SmallInt(lhs), OwnedColumn::Int128(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::SmallInt(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
+                    Ok(Self::decimal_op_left_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
 
-            (OwnedColumn::Int(lhs), OwnedColumn::TinyInt(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int(lhs), OwnedColumn::SmallInt(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int(lhs), OwnedColumn::Int(rhs)) => {
-                Ok(slice_binary_op(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int(lhs), OwnedColumn::BigInt(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int(lhs), OwnedColumn::Int128(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
-                Ok(Self::decimal_op_left_upcast(
-                    lhs_values,
-                    rhs_values,
-                    lhs.column_type(),
-                    rhs.column_type(),
-                ))
-            }
+                (OwnedColumn::Int(lhs), OwnedColumn::TinyInt(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int(lhs), OwnedColumn::SmallInt(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int(lhs), OwnedColumn::Int(rhs)) => {
+                    Ok(slice_binary_op(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int(lhs), OwnedColumn::BigInt(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int(lhs), OwnedColumn::Int128(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
+                    Ok(Self::decimal_op_left_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
 
-            (OwnedColumn::BigInt(lhs), OwnedColumn::TinyInt(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::BigInt(lhs), OwnedColumn::SmallInt(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::BigInt(lhs), OwnedColumn::Int(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::BigInt(lhs), OwnedColumn::BigInt(rhs)) => {
-                Ok(slice_binary_op(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::BigInt(lhs), OwnedColumn::Int128(rhs)) => {
-                Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::BigInt(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
-                Ok(Self::decimal_op_left_upcast(
-                    lhs_values,
-                    rhs_values,
-                    lhs.column_type(),
-                    rhs.column_type(),
-                ))
-            }
+                (OwnedColumn::BigInt(lhs), OwnedColumn::TinyInt(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::BigInt(lhs), OwnedColumn::SmallInt(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::BigInt(lhs), OwnedColumn::Int(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::BigInt(lhs), OwnedColumn::BigInt(rhs)) => {
+                    Ok(slice_binary_op(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::BigInt(lhs), OwnedColumn::Int128(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::BigInt(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
+                    Ok(Self::decimal_op_left_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
 
-            (OwnedColumn::Int128(lhs), OwnedColumn::TinyInt(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int128(lhs), OwnedColumn::SmallInt(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int128(lhs), OwnedColumn::Int(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int128(lhs), OwnedColumn::BigInt(rhs)) => {
-                Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int128(lhs), OwnedColumn::Int128(rhs)) => {
-                Ok(slice_binary_op(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::Int128(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
-                Ok(Self::decimal_op_left_upcast(
-                    lhs_values,
-                    rhs_values,
-                    lhs.column_type(),
-                    rhs.column_type(),
-                ))
-            }
+                (OwnedColumn::Int128(lhs), OwnedColumn::TinyInt(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int128(lhs), OwnedColumn::SmallInt(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int128(lhs), OwnedColumn::Int(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int128(lhs), OwnedColumn::BigInt(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int128(lhs), OwnedColumn::Int128(rhs)) => {
+                    Ok(slice_binary_op(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::Int128(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
+                    Ok(Self::decimal_op_left_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
 
-            (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::TinyInt(rhs_values)) => {
-                Ok(Self::decimal_op_right_upcast(
-                    lhs_values,
-                    rhs_values,
-                    lhs.column_type(),
-                    rhs.column_type(),
-                ))
-            }
-            (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::SmallInt(rhs_values)) => {
-                Ok(Self::decimal_op_right_upcast(
-                    lhs_values,
-                    rhs_values,
-                    lhs.column_type(),
-                    rhs.column_type(),
-                ))
-            }
-            (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::Int(rhs_values)) => {
-                Ok(Self::decimal_op_right_upcast(
-                    lhs_values,
-                    rhs_values,
-                    lhs.column_type(),
-                    rhs.column_type(),
-                ))
-            }
-            (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::BigInt(rhs_values)) => {
-                Ok(Self::decimal_op_right_upcast(
-                    lhs_values,
-                    rhs_values,
-                    lhs.column_type(),
-                    rhs.column_type(),
-                ))
-            }
-            (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::Int128(rhs_values)) => {
-                Ok(Self::decimal_op_right_upcast(
+                (OwnedColumn::UInt64(lhs), OwnedColumn::UInt64(rhs)) => {
+                    Ok(slice_binary_op(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::UInt64(lhs), OwnedColumn::TinyInt(rhs)) => Ok(
+                    slice_binary_op_both_upcast::<_, _, i128, _, _>(lhs, rhs, Self::op),
+                ),
+                (OwnedColumn::UInt64(lhs), OwnedColumn::SmallInt(rhs)) => Ok(
+                    slice_binary_op_both_upcast::<_, _, i128, _, _>(lhs, rhs, Self::op),
+                ),
+                (OwnedColumn::UInt64(lhs), OwnedColumn::Int(rhs)) => Ok(
+                    slice_binary_op_both_upcast::<_, _, i128, _, _>(lhs, rhs, Self::op),
+                ),
+                (OwnedColumn::UInt64(lhs), OwnedColumn::BigInt(rhs)) => Ok(
+                    slice_binary_op_both_upcast::<_, _, i128, _, _>(lhs, rhs, Self::op),
+                ),
+                (OwnedColumn::UInt64(lhs), OwnedColumn::Int128(rhs)) => {
+                    Ok(slice_binary_op_left_upcast(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::UInt64(lhs_values), OwnedColumn::Decimal75(_, _, rhs_values)) => {
+                    Ok(Self::decimal_op_left_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
+                (OwnedColumn::TinyInt(lhs), OwnedColumn::UInt64(rhs)) => Ok(
+                    slice_binary_op_both_upcast::<_, _, i128, _, _>(lhs, rhs, Self::op),
+                ),
+                (OwnedColumn::SmallInt(lhs), OwnedColumn::UInt64(rhs)) => Ok(
+                    slice_binary_op_both_upcast::<_, _, i128, _, _>(lhs, rhs, Self::op),
+                ),
+                (OwnedColumn::Int(lhs), OwnedColumn::UInt64(rhs)) => Ok(
+                    slice_binary_op_both_upcast::<_, _, i128, _, _>(lhs, rhs, Self::op),
+                ),
+                (OwnedColumn::BigInt(lhs), OwnedColumn::UInt64(rhs)) => Ok(
+                    slice_binary_op_both_upcast::<_, _, i128, _, _>(lhs, rhs, Self::op),
+                ),
+                (OwnedColumn::Int128(lhs), OwnedColumn::UInt64(rhs)) => {
+                    Ok(slice_binary_op_right_upcast(lhs, rhs, Self::op))
+                }
+
+                (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::TinyInt(rhs_values)) => {
+                    Ok(Self::decimal_op_right_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
+                (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::SmallInt(rhs_values)) => {
+                    Ok(Self::decimal_op_right_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
+                (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::Int(rhs_values)) => {
+                    Ok(Self::decimal_op_right_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
+                (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::BigInt(rhs_values)) => {
+                    Ok(Self::decimal_op_right_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
+                (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::Int128(rhs_values)) => {
+                    Ok(Self::decimal_op_right_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
+                (OwnedColumn::Decimal75(_, _, lhs_values), OwnedColumn::UInt64(rhs_values)) => {
+                    Ok(Self::decimal_op_right_upcast(
+                        lhs_values,
+                        rhs_values,
+                        lhs.column_type(),
+                        rhs.column_type(),
+                    ))
+                }
+                (
+                    OwnedColumn::Decimal75(_, _, lhs_values),
+                    OwnedColumn::Decimal75(_, _, rhs_values),
+                ) => Ok(Self::decimal_op_left_upcast(
                     lhs_values,
                     rhs_values,
                     lhs.column_type(),
                     rhs.column_type(),
-                ))
-            }
-            (
-                OwnedColumn::Decimal75(_, _, lhs_values),
-                OwnedColumn::Decimal75(_, _, rhs_values),
-            ) => Ok(Self::decimal_op_left_upcast(
-                lhs_values,
-                rhs_values,
-                lhs.column_type(),
-                rhs.column_type(),
-            )),
+                )),
 
-            (OwnedColumn::Boolean(lhs), OwnedColumn::Boolean(rhs)) => {
-                Ok(slice_binary_op(lhs, rhs, Self::op))
-            }
-            (OwnedColumn::VarChar(lhs), OwnedColumn::VarChar(rhs)) => Self::string_op(lhs, rhs),
-            _ => Err(ColumnOperationError::BinaryOperationInvalidColumnType {
-                operator: "ComparisonOp".to_string(),
-                left_type: lhs.column_type(),
-                right_type: rhs.column_type(),
-            }),
-        }?;
+                (OwnedColumn::Boolean(lhs), OwnedColumn::Boolean(rhs)) => {
+                    Ok(slice_binary_op(lhs, rhs, Self::op))
+                }
+                (OwnedColumn::VarChar(lhs), OwnedColumn::VarChar(rhs)) => Self::string_op(lhs, rhs),
+                (
+                    OwnedColumn::FixedBytes(left_width, lhs),
+                    OwnedColumn::FixedBytes(right_width, rhs),
+                ) if left_width == right_width => Self::fixed_bytes_op(lhs, rhs, *left_width),
+                _ => Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+                    operator: "ComparisonOp".to_string(),
+                    left_type: lhs.column_type(),
+                    right_type: rhs.column_type(),
+                }),
+            }?;
         Ok(OwnedColumn::Boolean(result))
     }
 }
@@ -278,6 +341,14 @@ impl ComparisonOp for EqualOp {
     fn string_op(lhs: &[String], rhs: &[String]) -> ColumnOperationResult<Vec<bool>> {
         Ok(lhs.iter().zip(rhs.iter()).map(|(l, r)| l == r).collect())
     }
+
+    fn fixed_bytes_op(
+        lhs: &[Vec<u8>],
+        rhs: &[Vec<u8>],
+        _width: u8,
+    ) -> ColumnOperationResult<Vec<bool>> {
+        Ok(lhs.iter().zip(rhs.iter()).map(|(l, r)| l == r).collect())
+    }
 }
 
 pub struct GreaterThanOrEqualOp {}
@@ -322,6 +393,18 @@ impl ComparisonOp for GreaterThanOrEqualOp {
             right_type: ColumnType::VarChar,
         })
     }
+
+    fn fixed_bytes_op(
+        _lhs: &[Vec<u8>],
+        _rhs: &[Vec<u8>],
+        width: u8,
+    ) -> ColumnOperationResult<Vec<bool>> {
+        Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+            operator: ">=".to_string(),
+            left_type: ColumnType::FixedBytes(width),
+            right_type: ColumnType::FixedBytes(width),
+        })
+    }
 }
 
 pub struct LessThanOrEqualOp {}
@@ -366,4 +449,16 @@ impl ComparisonOp for LessThanOrEqualOp {
             right_type: ColumnType::VarChar,
         })
     }
+
+    fn fixed_bytes_op(
+        _lhs: &[Vec<u8>],
+        _rhs: &[Vec<u8>],
+        width: u8,
+    ) -> ColumnOperationResult<Vec<bool>> {
+        Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+            operator: "<=".to_string(),
+            left_type: ColumnType::FixedBytes(width),
+            right_type: ColumnType::FixedBytes(width),
+        })
+    }
 }
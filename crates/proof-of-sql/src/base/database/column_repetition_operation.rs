@@ -11,6 +11,7 @@ pub trait RepetitionOp {
     fn op<T: Clone>(column: &[T], n: usize) -> impl Iterator<Item = T>;
 
     /// Run a column repetition operation on a `Column`.
+    #[allow(clippy::too_many_lines)]
     fn column_op<'a, S>(column: &Column<'a, S>, alloc: &'a Bump, n: usize) -> Column<'a, S>
     where
         S: Scalar,
@@ -48,6 +49,12 @@ pub trait RepetitionOp {
                     iter.next().expect("Iterator should have enough elements")
                 }) as &[_])
             }
+            ColumnType::UInt64 => {
+                let mut iter = Self::op(column.as_uint64().expect("Column types should match"), n);
+                Column::UInt64(alloc.alloc_slice_fill_with(len, |_| {
+                    iter.next().expect("Iterator should have enough elements")
+                }) as &[_])
+            }
             ColumnType::Int128 => {
                 let mut iter = Self::op(column.as_int128().expect("Column types should match"), n);
                 Column::Int128(alloc.alloc_slice_fill_with(len, |_| {
@@ -92,6 +99,30 @@ pub trait RepetitionOp {
                     }) as &[_],
                 ))
             }
+            ColumnType::FixedBytes(width) => {
+                let (raw_result, raw_scalars) =
+                    column.as_fixed_bytes().expect("Column types should match");
+
+                // Create iterators for both the result and scalars
+                let mut result_iter = Self::op(raw_result, n);
+                let mut scalar_iter = Self::op(raw_scalars, n);
+
+                Column::FixedBytes(
+                    width,
+                    (
+                        alloc.alloc_slice_fill_with(len, |_| {
+                            result_iter
+                                .next()
+                                .expect("Iterator should have enough elements")
+                        }) as &[_],
+                        alloc.alloc_slice_fill_with(len, |_| {
+                            scalar_iter
+                                .next()
+                                .expect("Iterator should have enough elements")
+                        }) as &[_],
+                    ),
+                )
+            }
             ColumnType::TimestampTZ(tu, tz) => {
                 let mut iter = Self::op(
                     column.as_timestamptz().expect("Column types should match"),
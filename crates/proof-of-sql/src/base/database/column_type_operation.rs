@@ -169,6 +169,63 @@ pub fn try_divide_column_types(
     Ok(ColumnType::Decimal75(precision, scale))
 }
 
+/// Determine the output type of a `CASE WHEN` expression selecting between the two input types
+/// if they are compatible. If the types are not compatible, return an error.
+///
+/// Identical types are always compatible. Otherwise both types must be numeric, and the result is
+/// the smallest numeric type that can hold values of both.
+///
+/// # Panics
+///
+/// - Panics if `lhs` or `rhs` does not have a precision or scale when they are expected to be numeric types.
+/// - Panics if `lhs` or `rhs` is an integer, and `lhs.max_integer_type(&rhs)` returns `None`.
+pub fn try_case_column_types(
+    lhs: ColumnType,
+    rhs: ColumnType,
+) -> ColumnOperationResult<ColumnType> {
+    if lhs == rhs {
+        return Ok(lhs);
+    }
+    if !lhs.is_numeric() || !rhs.is_numeric() {
+        return Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+            operator: "CASE".to_string(),
+            left_type: lhs,
+            right_type: rhs,
+        });
+    }
+    if lhs.is_integer() && rhs.is_integer() {
+        // We can unwrap here because we know that both types are integers
+        return Ok(lhs.max_integer_type(&rhs).unwrap());
+    }
+    if lhs == ColumnType::Scalar || rhs == ColumnType::Scalar {
+        return Ok(ColumnType::Scalar);
+    }
+    let left_precision_value =
+        i16::from(lhs.precision_value().expect("Numeric types have precision"));
+    let right_precision_value =
+        i16::from(rhs.precision_value().expect("Numeric types have precision"));
+    let left_scale = lhs.scale().expect("Numeric types have scale");
+    let right_scale = rhs.scale().expect("Numeric types have scale");
+    let scale = left_scale.max(right_scale);
+    let precision_value: i16 = i16::from(scale)
+        + (left_precision_value - i16::from(left_scale))
+            .max(right_precision_value - i16::from(right_scale));
+    let precision = u8::try_from(precision_value)
+        .map_err(|_| ColumnOperationError::DecimalConversionError {
+            source: DecimalError::InvalidPrecision {
+                error: precision_value.to_string(),
+            },
+        })
+        .and_then(|p| {
+            Precision::new(p).map_err(|_| ColumnOperationError::DecimalConversionError {
+                source: DecimalError::InvalidPrecision {
+                    error: p.to_string(),
+                },
+            })
+        })?;
+    Ok(ColumnType::Decimal75(precision, scale))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -750,4 +807,63 @@ mod test {
             })
         ));
     }
+
+    #[test]
+    fn we_can_select_between_compatible_types() {
+        // Identical types
+        for column_type in [
+            ColumnType::Boolean,
+            ColumnType::VarChar,
+            ColumnType::BigInt,
+            ColumnType::Scalar,
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+        ] {
+            assert_eq!(
+                try_case_column_types(column_type, column_type).unwrap(),
+                column_type
+            );
+        }
+
+        // Integers of different sizes
+        let actual = try_case_column_types(ColumnType::TinyInt, ColumnType::BigInt).unwrap();
+        assert_eq!(actual, ColumnType::BigInt);
+        let actual = try_case_column_types(ColumnType::Int128, ColumnType::SmallInt).unwrap();
+        assert_eq!(actual, ColumnType::Int128);
+
+        // Scalars
+        let actual = try_case_column_types(ColumnType::Int, ColumnType::Scalar).unwrap();
+        assert_eq!(actual, ColumnType::Scalar);
+
+        // Decimals keep enough integer digits and fractional digits for both types
+        let lhs = ColumnType::Decimal75(Precision::new(10).unwrap(), 2);
+        let rhs = ColumnType::Decimal75(Precision::new(8).unwrap(), 5);
+        let expected = ColumnType::Decimal75(Precision::new(13).unwrap(), 5);
+        assert_eq!(try_case_column_types(lhs, rhs).unwrap(), expected);
+        let lhs = ColumnType::Decimal75(Precision::new(10).unwrap(), 2);
+        let rhs = ColumnType::BigInt;
+        let expected = ColumnType::Decimal75(Precision::new(21).unwrap(), 2);
+        assert_eq!(try_case_column_types(lhs, rhs).unwrap(), expected);
+    }
+
+    #[test]
+    fn we_cannot_select_between_incompatible_types() {
+        assert!(matches!(
+            try_case_column_types(ColumnType::VarChar, ColumnType::BigInt),
+            Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
+        ));
+        assert!(matches!(
+            try_case_column_types(ColumnType::Boolean, ColumnType::TinyInt),
+            Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
+        ));
+
+        // Invalid precision
+        let lhs = ColumnType::Decimal75(Precision::new(75).unwrap(), 0);
+        let rhs = ColumnType::Decimal75(Precision::new(10).unwrap(), 10);
+        assert!(matches!(
+            try_case_column_types(lhs, rhs),
+            Err(ColumnOperationError::DecimalConversionError {
+                source: DecimalError::InvalidPrecision { .. }
+            })
+        ));
+    }
 }
@@ -48,6 +48,7 @@ impl<S: Scalar> OwnedTable<S> {
         match lit {
             Literal::Boolean(b) => Ok(OwnedColumn::Boolean(vec![*b; len])),
             Literal::BigInt(i) => Ok(OwnedColumn::BigInt(vec![*i; len])),
+            Literal::UInt64(i) => Ok(OwnedColumn::UInt64(vec![*i; len])),
             Literal::Int128(i) => Ok(OwnedColumn::Int128(vec![*i; len])),
             Literal::Decimal(d) => {
                 let raw_scale = d.scale();
@@ -61,6 +62,12 @@ impl<S: Scalar> OwnedTable<S> {
                 Ok(OwnedColumn::Decimal75(precision, scale, vec![scalar; len]))
             }
             Literal::VarChar(s) => Ok(OwnedColumn::VarChar(vec![s.clone(); len])),
+            Literal::FixedBytes(bytes) => Ok(OwnedColumn::FixedBytes(
+                u8::try_from(bytes.len()).map_err(|_| ExpressionEvaluationError::Unsupported {
+                    expression: "binary literals of more than 255 bytes".to_string(),
+                })?,
+                vec![bytes.clone(); len],
+            )),
             Literal::Timestamp(its) => Ok(OwnedColumn::TimestampTZ(
                 its.timeunit(),
                 its.timezone(),
@@ -77,6 +84,8 @@ impl<S: Scalar> OwnedTable<S> {
         let column = self.evaluate(expr)?;
         match op {
             UnaryOperator::Not => Ok(column.element_wise_not()?),
+            UnaryOperator::Minus => Ok(column.element_wise_neg()?),
+            UnaryOperator::PGAbs => Ok(column.element_wise_abs()?),
             // Handle unsupported unary operators
             _ => Err(ExpressionEvaluationError::Unsupported {
                 expression: format!("Unary operator '{op}' is not supported."),
@@ -102,6 +111,7 @@ impl<S: Scalar> OwnedTable<S> {
             BinaryOperator::Minus => Ok(left.element_wise_sub(&right)?),
             BinaryOperator::Multiply => Ok(left.element_wise_mul(&right)?),
             BinaryOperator::Divide => Ok(left.element_wise_div(&right)?),
+            BinaryOperator::StringConcat => Ok(left.element_wise_concat(&right)?),
             _ => Err(ExpressionEvaluationError::Unsupported {
                 expression: format!("Binary operator '{op}' is not supported."),
             }),
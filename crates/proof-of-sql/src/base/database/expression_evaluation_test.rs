@@ -140,6 +140,33 @@ fn we_can_evaluate_a_logical_expression() {
     assert_eq!(actual_column, expected_column);
 }
 
+#[test]
+fn we_can_evaluate_a_string_concatenation() {
+    let table: OwnedTable<TestScalar> = owned_table([
+        varchar("first", ["Ada", "", "Alan", ""]),
+        varchar("last", ["Lovelace", "Hopper", "", ""]),
+    ]);
+    let expr = concat(concat(col("first"), lit(" ")), col("last"));
+    let actual_column = table.evaluate(&expr).unwrap();
+    let expected_column = OwnedColumn::VarChar(
+        ["Ada Lovelace", " Hopper", "Alan ", " "]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+    );
+    assert_eq!(actual_column, expected_column);
+
+    let expr = concat(col("first"), lit(""));
+    let actual_column = table.evaluate(&expr).unwrap();
+    let expected_column = OwnedColumn::VarChar(
+        ["Ada", "", "Alan", ""]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+    );
+    assert_eq!(actual_column, expected_column);
+}
+
 #[test]
 fn we_can_evaluate_an_arithmetic_expression() {
     let table: OwnedTable<TestScalar> = owned_table([
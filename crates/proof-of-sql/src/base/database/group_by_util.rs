@@ -158,13 +158,17 @@ pub(crate) fn sum_aggregate_column_by_index_counts<'a, S: Scalar>(
         Column::SmallInt(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::BigInt(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::UInt64(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int128(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Decimal75(_, _, col) => {
             sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
         }
         Column::Scalar(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         // The following should never be reached because the `SUM` function can only be applied to numeric types.
-        Column::VarChar(_) | Column::TimestampTZ(_, _, _) | Column::Boolean(_) => {
+        Column::VarChar(_)
+        | Column::FixedBytes(_, _)
+        | Column::TimestampTZ(_, _, _)
+        | Column::Boolean(_) => {
             unreachable!("SUM can not be applied to non-numeric types")
         }
     }
@@ -187,6 +191,7 @@ pub(crate) fn max_aggregate_column_by_index_counts<'a, S: Scalar>(
         Column::SmallInt(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::BigInt(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::UInt64(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int128(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Decimal75(_, _, col) => {
             max_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
@@ -195,9 +200,9 @@ pub(crate) fn max_aggregate_column_by_index_counts<'a, S: Scalar>(
             max_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
         }
         Column::Scalar(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
-        // The following should never be reached because the `MAX` function can't be applied to varchar.
-        Column::VarChar(_) => {
-            unreachable!("MAX can not be applied to varchar")
+        // The following should never be reached because the `MAX` function can't be applied to varchar or binary.
+        Column::VarChar(_) | Column::FixedBytes(_, _) => {
+            unreachable!("MAX can not be applied to varchar or binary")
         }
     }
 }
@@ -219,6 +224,7 @@ pub(crate) fn min_aggregate_column_by_index_counts<'a, S: Scalar>(
         Column::SmallInt(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::BigInt(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::UInt64(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int128(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Decimal75(_, _, col) => {
             min_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
@@ -227,9 +233,9 @@ pub(crate) fn min_aggregate_column_by_index_counts<'a, S: Scalar>(
             min_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
         }
         Column::Scalar(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
-        // The following should never be reached because the `MIN` function can't be applied to varchar.
-        Column::VarChar(_) => {
-            unreachable!("MIN can not be applied to varchar")
+        // The following should never be reached because the `MIN` function can't be applied to varchar or binary.
+        Column::VarChar(_) | Column::FixedBytes(_, _) => {
+            unreachable!("MIN can not be applied to varchar or binary")
         }
     }
 }
@@ -3,7 +3,7 @@ use crate::base::{
     math::{decimal::Precision, i256::I256},
     scalar::Scalar,
 };
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +25,8 @@ pub enum LiteralValue {
     Int(i32),
     /// i64 literals
     BigInt(i64),
+    /// u64 literals
+    UInt64(u64),
 
     /// String literals
     ///  - the first element maps to the str value.
@@ -40,10 +42,16 @@ pub enum LiteralValue {
     /// `TimeStamp` defined over a unit (s, ms, ns, etc) and timezone with backing store
     /// mapped to i64, which is time units since unix epoch
     TimeStampTZ(PoSQLTimeUnit, PoSQLTimeZone, i64),
+    /// Fixed-length binary literals, whose length is the length of the type
+    FixedBytes(Vec<u8>),
 }
 
 impl LiteralValue {
     /// Provides the column type associated with the column
+    ///
+    /// # Panics
+    ///
+    /// Panics if a fixed bytes literal is longer than 255 bytes.
     #[must_use]
     pub fn column_type(&self) -> ColumnType {
         match self {
@@ -52,11 +60,15 @@ impl LiteralValue {
             Self::SmallInt(_) => ColumnType::SmallInt,
             Self::Int(_) => ColumnType::Int,
             Self::BigInt(_) => ColumnType::BigInt,
+            Self::UInt64(_) => ColumnType::UInt64,
             Self::VarChar(_) => ColumnType::VarChar,
             Self::Int128(_) => ColumnType::Int128,
             Self::Scalar(_) => ColumnType::Scalar,
             Self::Decimal75(precision, scale, _) => ColumnType::Decimal75(*precision, *scale),
             Self::TimeStampTZ(tu, tz, _) => ColumnType::TimestampTZ(*tu, *tz),
+            Self::FixedBytes(bytes) => ColumnType::FixedBytes(
+                u8::try_from(bytes.len()).expect("fixed bytes literals are at most 255 bytes"),
+            ),
         }
     }
 
@@ -68,11 +80,13 @@ impl LiteralValue {
             Self::SmallInt(i) => i.into(),
             Self::Int(i) => i.into(),
             Self::BigInt(i) => i.into(),
+            Self::UInt64(i) => i.into(),
             Self::VarChar(str) => str.into(),
             Self::Decimal75(_, _, i) => i.into_scalar(),
             Self::Int128(i) => i.into(),
             Self::Scalar(limbs) => (*limbs).into(),
             Self::TimeStampTZ(_, _, time) => time.into(),
+            Self::FixedBytes(bytes) => bytes.as_slice().into(),
         }
     }
 }
@@ -2,7 +2,9 @@
 //! accessor traits and the `OwnedTable` type along with some utility functions to convert
 //! between Arrow and `OwnedTable`.
 mod accessor;
-pub use accessor::{CommitmentAccessor, DataAccessor, MetadataAccessor, SchemaAccessor};
+pub use accessor::{
+    CommitmentAccessor, CommitmentSource, DataAccessor, MetadataAccessor, SchemaAccessor,
+};
 
 mod column;
 pub use column::{Column, ColumnField, ColumnRef, ColumnType};
@@ -14,7 +16,8 @@ mod slice_decimal_operation;
 
 mod column_type_operation;
 pub use column_type_operation::{
-    try_add_subtract_column_types, try_divide_column_types, try_multiply_column_types,
+    try_add_subtract_column_types, try_case_column_types, try_divide_column_types,
+    try_multiply_column_types,
 };
 
 mod column_arithmetic_operation;
@@ -67,6 +70,16 @@ pub use owned_table::OwnedTable;
 pub(crate) use owned_table::OwnedTableError;
 #[cfg(test)]
 mod owned_table_test;
+
+mod owned_table_inference;
+pub use owned_table_inference::InferError;
+#[cfg(test)]
+mod owned_table_inference_test;
+
+mod owned_table_row;
+pub use owned_table_row::{Row, RowValue};
+#[cfg(test)]
+mod owned_table_row_test;
 pub mod owned_table_utility;
 
 mod table;
@@ -77,9 +90,21 @@ pub use table::{Table, TableOptions};
 mod table_test;
 pub mod table_utility;
 
+mod tombstone;
+pub(crate) use tombstone::has_tombstone_column;
+pub use tombstone::{tombstone_column_id, tombstone_commitment_delta, TOMBSTONE_COLUMN_NAME};
+
 mod table_evaluation;
 pub use table_evaluation::TableEvaluation;
 
+mod nested_column;
+pub use nested_column::{flatten_nested_table, FieldPath, NestedColumn, NestedColumnError};
+
+mod table_schema;
+pub use table_schema::{
+    ColumnTypeChange, SchemaDiff, TableSchema, TableSchemaJsonError, TABLE_SCHEMA_JSON_VERSION,
+};
+
 /// TODO: add docs
 pub(crate) mod expression_evaluation;
 mod expression_evaluation_error;
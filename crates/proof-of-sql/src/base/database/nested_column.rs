@@ -0,0 +1,228 @@
+use super::{OwnedColumn, OwnedTable, OwnedTableError};
+use crate::base::{map::IndexMap, scalar::Scalar};
+use alloc::{boxed::Box, vec::Vec};
+use proof_of_sql_parser::Identifier;
+use snafu::Snafu;
+
+/// Errors from flattening a table with struct columns with [`flatten_nested_table`].
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum NestedColumnError {
+    /// A struct column has no fields.
+    #[snafu(display("struct column {name} has no fields"))]
+    EmptyStruct {
+        /// The name of the struct column
+        name: Identifier,
+    },
+    /// The name of the leaf column of a field is longer than 64 bytes.
+    #[snafu(display("the leaf column name of field {field} of struct column {name} is too long"))]
+    LeafNameTooLong {
+        /// The name of the struct column
+        name: Identifier,
+        /// The name of the field
+        field: Box<Identifier>,
+    },
+    /// Two columns have the same name after flattening.
+    #[snafu(display("column {name} appears more than once after flattening"))]
+    DuplicateColumn {
+        /// The name of the column
+        name: Identifier,
+    },
+    /// The flattened columns do not all have the same length.
+    #[snafu(display("columns have different lengths"))]
+    ColumnLengthMismatch,
+}
+
+/// A column of a table with nested data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NestedColumn<S: Scalar> {
+    /// A column without fields, which is committed as it is
+    Flat(OwnedColumn<S>),
+    /// A struct column, with one level of fields in order
+    ///
+    /// Fields cannot themselves be struct columns.
+    Struct(IndexMap<Identifier, OwnedColumn<S>>),
+}
+
+/// The path of a field of a struct column, which a query refers to as `parent.field`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldPath {
+    /// The name of the struct column
+    pub parent: Identifier,
+    /// The name of the field
+    pub field: Identifier,
+}
+
+impl FieldPath {
+    /// The name of the leaf column that this field is flattened to.
+    ///
+    /// See [`Identifier::try_new_nested`].
+    ///
+    /// # Errors
+    /// Returns an error if the name of the leaf column is longer than 64 bytes.
+    pub fn leaf_column_id(&self) -> Result<Identifier, NestedColumnError> {
+        Identifier::try_new_nested(self.parent, self.field).map_err(|_| {
+            NestedColumnError::LeafNameTooLong {
+                name: self.parent,
+                field: Box::new(self.field),
+            }
+        })
+    }
+}
+
+/// Flatten the struct columns of a table into their leaf columns.
+///
+/// Each field of a struct column becomes a leaf column of the flattened table, named as in [`FieldPath::leaf_column_id`],
+/// in the position of the struct column. Flat columns are kept as they are. Committing to the flattened table commits
+/// to every field separately, and queries that refer to `parent.field` are planned against its leaf column,
+/// so proofs over the flattened table need nothing specific to nested data.
+///
+/// Returns the flattened table along with the leaf column of every field.
+///
+/// # Errors
+/// Returns an error if a struct column has no fields, if the name of a leaf column is too long
+/// or is the name of another column, or if the columns do not all have the same length.
+pub fn flatten_nested_table<S: Scalar>(
+    columns: impl IntoIterator<Item = (Identifier, NestedColumn<S>)>,
+) -> Result<(OwnedTable<S>, IndexMap<FieldPath, Identifier>), NestedColumnError> {
+    let mut flattened: IndexMap<Identifier, OwnedColumn<S>> = IndexMap::default();
+    let mut leaf_columns = IndexMap::default();
+    for (name, column) in columns {
+        let leaves: Vec<(Identifier, OwnedColumn<S>)> = match column {
+            NestedColumn::Flat(column) => Vec::from([(name, column)]),
+            NestedColumn::Struct(fields) => {
+                if fields.is_empty() {
+                    return Err(NestedColumnError::EmptyStruct { name });
+                }
+                fields
+                    .into_iter()
+                    .map(|(field, column)| {
+                        let path = FieldPath {
+                            parent: name,
+                            field,
+                        };
+                        let leaf = path.leaf_column_id()?;
+                        leaf_columns.insert(path, leaf);
+                        Ok((leaf, column))
+                    })
+                    .collect::<Result<_, NestedColumnError>>()?
+            }
+        };
+        for (leaf, column) in leaves {
+            if flattened.insert(leaf, column).is_some() {
+                return Err(NestedColumnError::DuplicateColumn { name: leaf });
+            }
+        }
+    }
+    let table = OwnedTable::try_new(flattened).map_err(|error| match error {
+        OwnedTableError::ColumnLengthMismatch => NestedColumnError::ColumnLengthMismatch,
+    })?;
+    Ok((table, leaf_columns))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{
+        database::owned_table_utility::*, map::indexmap, scalar::test_scalar::TestScalar,
+    };
+
+    #[test]
+    fn we_can_flatten_a_table_with_a_struct_column() {
+        let (table, leaf_columns) = flatten_nested_table::<TestScalar>([
+            (
+                "id".parse().unwrap(),
+                NestedColumn::Flat(OwnedColumn::BigInt(vec![1, 2])),
+            ),
+            (
+                "address".parse().unwrap(),
+                NestedColumn::Struct(indexmap! {
+                    "zip".parse().unwrap() => OwnedColumn::BigInt(vec![10, 20]),
+                    "city".parse().unwrap() => OwnedColumn::VarChar(vec!["a".into(), "b".into()]),
+                }),
+            ),
+            (
+                "b".parse().unwrap(),
+                NestedColumn::Flat(OwnedColumn::Boolean(vec![true, false])),
+            ),
+        ])
+        .unwrap();
+        assert_eq!(
+            table,
+            owned_table([
+                bigint("id", [1, 2]),
+                bigint("address__zip", [10, 20]),
+                varchar("address__city", ["a", "b"]),
+                boolean("b", [true, false]),
+            ])
+        );
+        let path = |field: &str| FieldPath {
+            parent: "address".parse().unwrap(),
+            field: field.parse().unwrap(),
+        };
+        assert_eq!(
+            leaf_columns,
+            indexmap! {
+                path("zip") => "address__zip".parse::<Identifier>().unwrap(),
+                path("city") => "address__city".parse().unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn we_cannot_flatten_an_invalid_nested_table() {
+        let address: Identifier = "address".parse().unwrap();
+        assert_eq!(
+            flatten_nested_table::<TestScalar>([(
+                address,
+                NestedColumn::Struct(IndexMap::default())
+            )]),
+            Err(NestedColumnError::EmptyStruct { name: address })
+        );
+
+        let long_field: Identifier = "f".repeat(60).parse().unwrap();
+        assert_eq!(
+            flatten_nested_table::<TestScalar>([(
+                address,
+                NestedColumn::Struct(indexmap! { long_field => OwnedColumn::BigInt(vec![1]) })
+            )]),
+            Err(NestedColumnError::LeafNameTooLong {
+                name: address,
+                field: Box::new(long_field)
+            })
+        );
+
+        assert_eq!(
+            flatten_nested_table::<TestScalar>([
+                (
+                    "address__zip".parse().unwrap(),
+                    NestedColumn::Flat(OwnedColumn::BigInt(vec![1]))
+                ),
+                (
+                    address,
+                    NestedColumn::Struct(indexmap! {
+                        "zip".parse().unwrap() => OwnedColumn::BigInt(vec![2]),
+                    })
+                ),
+            ]),
+            Err(NestedColumnError::DuplicateColumn {
+                name: "address__zip".parse().unwrap()
+            })
+        );
+
+        assert_eq!(
+            flatten_nested_table::<TestScalar>([
+                (
+                    "id".parse().unwrap(),
+                    NestedColumn::Flat(OwnedColumn::BigInt(vec![1]))
+                ),
+                (
+                    address,
+                    NestedColumn::Struct(indexmap! {
+                        "zip".parse().unwrap() => OwnedColumn::BigInt(vec![2, 3]),
+                    })
+                ),
+            ]),
+            Err(NestedColumnError::ColumnLengthMismatch)
+        );
+    }
+}
@@ -2,7 +2,7 @@
 /// This is primarily used as an internal result that is used before
 /// converting to the final result in either Arrow format or JSON.
 /// This is the analog of an arrow Array.
-use super::{Column, ColumnType, OwnedColumnError, OwnedColumnResult};
+use super::{Column, ColumnType, OwnedColumnError, OwnedColumnResult, RowValue};
 use crate::base::{
     math::{
         decimal::Precision,
@@ -32,6 +32,8 @@ pub enum OwnedColumn<S: Scalar> {
     Int(Vec<i32>),
     /// i64 columns
     BigInt(Vec<i64>),
+    /// u64 columns
+    UInt64(Vec<u64>),
     /// String columns
     VarChar(Vec<String>),
     /// i128 columns
@@ -42,6 +44,8 @@ pub enum OwnedColumn<S: Scalar> {
     Scalar(Vec<S>),
     /// Timestamp columns
     TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, Vec<i64>),
+    /// Fixed-length binary columns, along with the length in bytes of every value
+    FixedBytes(u8, Vec<Vec<u8>>),
 }
 
 impl<S: Scalar> OwnedColumn<S> {
@@ -55,14 +59,43 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
                 inner_product_ref_cast(col, vec)
             }
+            OwnedColumn::UInt64(col) => inner_product_ref_cast(col, vec),
             OwnedColumn::VarChar(col) => inner_product_ref_cast(col, vec),
             OwnedColumn::Int128(col) => inner_product_ref_cast(col, vec),
             OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => {
                 inner_product_ref_cast(col, vec)
             }
+            OwnedColumn::FixedBytes(_, col) => col
+                .iter()
+                .zip(vec)
+                .map(|(bytes, s)| S::from(bytes.as_slice()) * *s)
+                .sum(),
         }
     }
 
+    /// Returns the value at `index`, or `None` if `index` is out of bounds.
+    #[must_use]
+    pub fn value_at(&self, index: usize) -> Option<RowValue<'_, S>> {
+        Some(match self {
+            OwnedColumn::Boolean(col) => RowValue::Boolean(*col.get(index)?),
+            OwnedColumn::TinyInt(col) => RowValue::TinyInt(*col.get(index)?),
+            OwnedColumn::SmallInt(col) => RowValue::SmallInt(*col.get(index)?),
+            OwnedColumn::Int(col) => RowValue::Int(*col.get(index)?),
+            OwnedColumn::BigInt(col) => RowValue::BigInt(*col.get(index)?),
+            OwnedColumn::UInt64(col) => RowValue::UInt64(*col.get(index)?),
+            OwnedColumn::VarChar(col) => RowValue::VarChar(col.get(index)?),
+            OwnedColumn::Int128(col) => RowValue::Int128(*col.get(index)?),
+            OwnedColumn::Decimal75(precision, scale, col) => {
+                RowValue::Decimal75(*precision, *scale, *col.get(index)?)
+            }
+            OwnedColumn::Scalar(col) => RowValue::Scalar(*col.get(index)?),
+            OwnedColumn::TimestampTZ(unit, timezone, col) => {
+                RowValue::TimestampTZ(*unit, *timezone, *col.get(index)?)
+            }
+            OwnedColumn::FixedBytes(_, col) => RowValue::FixedBytes(col.get(index)?),
+        })
+    }
+
     /// Returns the length of the column.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -72,9 +105,11 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(col) => col.len(),
             OwnedColumn::Int(col) => col.len(),
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.len(),
+            OwnedColumn::UInt64(col) => col.len(),
             OwnedColumn::VarChar(col) => col.len(),
             OwnedColumn::Int128(col) => col.len(),
             OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => col.len(),
+            OwnedColumn::FixedBytes(_, col) => col.len(),
         }
     }
 
@@ -86,6 +121,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(permutation.try_apply(col)?),
             OwnedColumn::Int(col) => OwnedColumn::Int(permutation.try_apply(col)?),
             OwnedColumn::BigInt(col) => OwnedColumn::BigInt(permutation.try_apply(col)?),
+            OwnedColumn::UInt64(col) => OwnedColumn::UInt64(permutation.try_apply(col)?),
             OwnedColumn::VarChar(col) => OwnedColumn::VarChar(permutation.try_apply(col)?),
             OwnedColumn::Int128(col) => OwnedColumn::Int128(permutation.try_apply(col)?),
             OwnedColumn::Decimal75(precision, scale, col) => {
@@ -95,6 +131,9 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::TimestampTZ(tu, tz, col) => {
                 OwnedColumn::TimestampTZ(*tu, *tz, permutation.try_apply(col)?)
             }
+            OwnedColumn::FixedBytes(width, col) => {
+                OwnedColumn::FixedBytes(*width, permutation.try_apply(col)?)
+            }
         })
     }
 
@@ -107,6 +146,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(col[start..end].to_vec()),
             OwnedColumn::Int(col) => OwnedColumn::Int(col[start..end].to_vec()),
             OwnedColumn::BigInt(col) => OwnedColumn::BigInt(col[start..end].to_vec()),
+            OwnedColumn::UInt64(col) => OwnedColumn::UInt64(col[start..end].to_vec()),
             OwnedColumn::VarChar(col) => OwnedColumn::VarChar(col[start..end].to_vec()),
             OwnedColumn::Int128(col) => OwnedColumn::Int128(col[start..end].to_vec()),
             OwnedColumn::Decimal75(precision, scale, col) => {
@@ -116,6 +156,9 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::TimestampTZ(tu, tz, col) => {
                 OwnedColumn::TimestampTZ(*tu, *tz, col[start..end].to_vec())
             }
+            OwnedColumn::FixedBytes(width, col) => {
+                OwnedColumn::FixedBytes(*width, col[start..end].to_vec())
+            }
         }
     }
 
@@ -128,9 +171,11 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(col) => col.is_empty(),
             OwnedColumn::Int(col) => col.is_empty(),
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.is_empty(),
+            OwnedColumn::UInt64(col) => col.is_empty(),
             OwnedColumn::VarChar(col) => col.is_empty(),
             OwnedColumn::Int128(col) => col.is_empty(),
             OwnedColumn::Scalar(col) | OwnedColumn::Decimal75(_, _, col) => col.is_empty(),
+            OwnedColumn::FixedBytes(_, col) => col.is_empty(),
         }
     }
     /// Returns the type of the column.
@@ -142,6 +187,7 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(_) => ColumnType::SmallInt,
             OwnedColumn::Int(_) => ColumnType::Int,
             OwnedColumn::BigInt(_) => ColumnType::BigInt,
+            OwnedColumn::UInt64(_) => ColumnType::UInt64,
             OwnedColumn::VarChar(_) => ColumnType::VarChar,
             OwnedColumn::Int128(_) => ColumnType::Int128,
             OwnedColumn::Scalar(_) => ColumnType::Scalar,
@@ -149,6 +195,7 @@ impl<S: Scalar> OwnedColumn<S> {
                 ColumnType::Decimal75(*precision, *scale)
             }
             OwnedColumn::TimestampTZ(tu, tz, _) => ColumnType::TimestampTZ(*tu, *tz),
+            OwnedColumn::FixedBytes(width, _) => ColumnType::FixedBytes(*width),
         }
     }
 
@@ -200,6 +247,15 @@ impl<S: Scalar> OwnedColumn<S> {
                         error: "Overflow in scalar conversions".to_string(),
                     })?,
             )),
+            ColumnType::UInt64 => Ok(OwnedColumn::UInt64(
+                scalars
+                    .iter()
+                    .map(|s| -> Result<u64, _> { TryInto::<u64>::try_into(*s) })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| OwnedColumnError::ScalarConversionError {
+                        error: "Overflow in scalar conversions".to_string(),
+                    })?,
+            )),
             ColumnType::Int128 => Ok(OwnedColumn::Int128(
                 scalars
                     .iter()
@@ -228,6 +284,11 @@ impl<S: Scalar> OwnedColumn<S> {
                 from_type: ColumnType::Scalar,
                 to_type: ColumnType::VarChar,
             }),
+            // Can not convert scalars to FixedBytes
+            ColumnType::FixedBytes(width) => Err(OwnedColumnError::TypeCastError {
+                from_type: ColumnType::Scalar,
+                to_type: ColumnType::FixedBytes(width),
+            }),
         }
     }
 
@@ -284,6 +345,15 @@ impl<S: Scalar> OwnedColumn<S> {
     }
     #[cfg(test)]
     /// Returns an iterator over the raw data of the column
+    /// assuming the underlying type is [u64], panicking if it is not.
+    pub fn u64_iter(&self) -> impl Iterator<Item = &u64> {
+        match self {
+            OwnedColumn::UInt64(col) => col.iter(),
+            _ => panic!("Expected UInt64 column"),
+        }
+    }
+    #[cfg(test)]
+    /// Returns an iterator over the raw data of the column
     /// assuming the underlying type is [i128], panicking if it is not.
     pub fn i128_iter(&self) -> impl Iterator<Item = &i128> {
         match self {
@@ -328,6 +398,7 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for OwnedColumn<S> {
             Column::SmallInt(col) => OwnedColumn::SmallInt(col.to_vec()),
             Column::Int(col) => OwnedColumn::Int(col.to_vec()),
             Column::BigInt(col) => OwnedColumn::BigInt(col.to_vec()),
+            Column::UInt64(col) => OwnedColumn::UInt64(col.to_vec()),
             Column::VarChar((col, _)) => {
                 OwnedColumn::VarChar(col.iter().map(ToString::to_string).collect())
             }
@@ -337,6 +408,9 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for OwnedColumn<S> {
             }
             Column::Scalar(col) => OwnedColumn::Scalar(col.to_vec()),
             Column::TimestampTZ(tu, tz, col) => OwnedColumn::TimestampTZ(*tu, *tz, col.to_vec()),
+            Column::FixedBytes(width, (col, _)) => {
+                OwnedColumn::FixedBytes(*width, col.iter().map(|bytes| bytes.to_vec()).collect())
+            }
         }
     }
 }
@@ -4,13 +4,22 @@ use super::{
 };
 use crate::base::{
     database::{
-        slice_operation::{slice_and, slice_not, slice_or},
+        slice_operation::{slice_and, slice_not, slice_or, try_abs, try_neg},
         OwnedColumn,
     },
     scalar::Scalar,
 };
 use alloc::string::ToString;
 
+/// The absolute value of a scalar that represents a signed value
+fn scalar_abs<S: Scalar>(value: &S) -> S {
+    if *value > S::MAX_SIGNED {
+        -*value
+    } else {
+        *value
+    }
+}
+
 impl<S: Scalar> OwnedColumn<S> {
     /// Element-wise NOT operation for a column
     pub fn element_wise_not(&self) -> ColumnOperationResult<Self> {
@@ -23,6 +32,111 @@ impl<S: Scalar> OwnedColumn<S> {
         }
     }
 
+    /// Element-wise negation for a numeric column
+    ///
+    /// Integer columns keep their type, so negating the smallest value of a signed type
+    /// or any nonzero unsigned value is an overflow.
+    pub fn element_wise_neg(&self) -> ColumnOperationResult<Self> {
+        match self {
+            Self::TinyInt(values) => Ok(Self::TinyInt(
+                values
+                    .iter()
+                    .map(try_neg)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::SmallInt(values) => Ok(Self::SmallInt(
+                values
+                    .iter()
+                    .map(try_neg)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::Int(values) => Ok(Self::Int(
+                values
+                    .iter()
+                    .map(try_neg)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::BigInt(values) => Ok(Self::BigInt(
+                values
+                    .iter()
+                    .map(try_neg)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::Int128(values) => Ok(Self::Int128(
+                values
+                    .iter()
+                    .map(try_neg)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::UInt64(values) => Ok(Self::UInt64(
+                values
+                    .iter()
+                    .map(try_neg)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::Decimal75(precision, scale, values) => Ok(Self::Decimal75(
+                *precision,
+                *scale,
+                values.iter().map(|value| -*value).collect(),
+            )),
+            Self::Scalar(values) => Ok(Self::Scalar(values.iter().map(|value| -*value).collect())),
+            _ => Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "-".to_string(),
+                operand_type: self.column_type(),
+            }),
+        }
+    }
+
+    /// Element-wise absolute value for a numeric column
+    ///
+    /// Integer columns keep their type, so the absolute value of the smallest value of a signed type
+    /// is an overflow.
+    pub fn element_wise_abs(&self) -> ColumnOperationResult<Self> {
+        match self {
+            Self::TinyInt(values) => Ok(Self::TinyInt(
+                values
+                    .iter()
+                    .map(try_abs)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::SmallInt(values) => Ok(Self::SmallInt(
+                values
+                    .iter()
+                    .map(try_abs)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::Int(values) => Ok(Self::Int(
+                values
+                    .iter()
+                    .map(try_abs)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::BigInt(values) => Ok(Self::BigInt(
+                values
+                    .iter()
+                    .map(try_abs)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::Int128(values) => Ok(Self::Int128(
+                values
+                    .iter()
+                    .map(try_abs)
+                    .collect::<ColumnOperationResult<_>>()?,
+            )),
+            Self::UInt64(values) => Ok(Self::UInt64(values.clone())),
+            Self::Decimal75(precision, scale, values) => Ok(Self::Decimal75(
+                *precision,
+                *scale,
+                values.iter().map(scalar_abs).collect(),
+            )),
+            Self::Scalar(values) => Ok(Self::Scalar(values.iter().map(scalar_abs).collect())),
+            _ => Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "ABS".to_string(),
+                operand_type: self.column_type(),
+            }),
+        }
+    }
+
     /// Element-wise AND for two columns
     pub fn element_wise_and(&self, rhs: &Self) -> ColumnOperationResult<Self> {
         if self.len() != rhs.len() {
@@ -93,13 +207,36 @@ impl<S: Scalar> OwnedColumn<S> {
     pub fn element_wise_div(&self, rhs: &OwnedColumn<S>) -> ColumnOperationResult<OwnedColumn<S>> {
         DivOp::owned_column_element_wise_arithmetic(self, rhs)
     }
+
+    /// Element-wise string concatenation for two columns
+    pub fn element_wise_concat(&self, rhs: &Self) -> ColumnOperationResult<Self> {
+        if self.len() != rhs.len() {
+            return Err(ColumnOperationError::DifferentColumnLength {
+                len_a: self.len(),
+                len_b: rhs.len(),
+            });
+        }
+        match (self, rhs) {
+            (Self::VarChar(lhs), Self::VarChar(rhs)) => Ok(Self::VarChar(
+                lhs.iter()
+                    .zip(rhs)
+                    .map(|(lhs, rhs)| [lhs.as_str(), rhs.as_str()].concat())
+                    .collect(),
+            )),
+            _ => Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+                operator: "||".to_string(),
+                left_type: self.column_type(),
+                right_type: rhs.column_type(),
+            }),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::base::{math::decimal::Precision, scalar::test_scalar::TestScalar};
-    use alloc::vec;
+    use alloc::{string::String, vec};
 
     #[test]
     fn we_cannot_do_binary_operation_on_columns_with_different_lengths() {
@@ -237,6 +374,144 @@ mod test {
         );
     }
 
+    #[test]
+    fn we_can_concat_varchar_columns() {
+        let lhs = OwnedColumn::<TestScalar>::VarChar(
+            ["Space", "", "proof", ""].map(String::from).to_vec(),
+        );
+        let rhs = OwnedColumn::<TestScalar>::VarChar(
+            [" and Time", "sql", "", ""].map(String::from).to_vec(),
+        );
+        assert_eq!(
+            lhs.element_wise_concat(&rhs),
+            Ok(OwnedColumn::<TestScalar>::VarChar(
+                ["Space and Time", "sql", "proof", ""]
+                    .map(String::from)
+                    .to_vec()
+            ))
+        );
+    }
+
+    #[test]
+    fn we_cannot_concat_nonvarchar_columns_or_columns_with_different_lengths() {
+        let lhs = OwnedColumn::<TestScalar>::VarChar(["a", "b"].map(String::from).to_vec());
+        let rhs = OwnedColumn::<TestScalar>::BigInt(vec![1, 2]);
+        assert!(matches!(
+            lhs.element_wise_concat(&rhs),
+            Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
+        ));
+
+        let rhs = OwnedColumn::<TestScalar>::VarChar(["c"].map(String::from).to_vec());
+        assert!(matches!(
+            lhs.element_wise_concat(&rhs),
+            Err(ColumnOperationError::DifferentColumnLength { .. })
+        ));
+    }
+
+    #[test]
+    fn we_can_negate_numeric_columns() {
+        let column = OwnedColumn::<TestScalar>::SmallInt(vec![5, -3, 0, i16::MAX]);
+        assert_eq!(
+            column.element_wise_neg(),
+            Ok(OwnedColumn::<TestScalar>::SmallInt(vec![
+                -5,
+                3,
+                0,
+                -i16::MAX
+            ]))
+        );
+
+        let column = OwnedColumn::<TestScalar>::Decimal75(
+            Precision::new(5).unwrap(),
+            2,
+            vec![TestScalar::from(125), TestScalar::from(-300)],
+        );
+        assert_eq!(
+            column.element_wise_neg(),
+            Ok(OwnedColumn::<TestScalar>::Decimal75(
+                Precision::new(5).unwrap(),
+                2,
+                vec![TestScalar::from(-125), TestScalar::from(300)],
+            ))
+        );
+    }
+
+    #[test]
+    fn we_cannot_negate_the_smallest_integer_or_non_numeric_columns() {
+        let column = OwnedColumn::<TestScalar>::BigInt(vec![1, i64::MIN]);
+        assert!(matches!(
+            column.element_wise_neg(),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+
+        let column = OwnedColumn::<TestScalar>::UInt64(vec![0, 1]);
+        assert!(matches!(
+            column.element_wise_neg(),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+
+        let column = OwnedColumn::<TestScalar>::Boolean(vec![true]);
+        assert!(matches!(
+            column.element_wise_neg(),
+            Err(ColumnOperationError::UnaryOperationInvalidColumnType { .. })
+        ));
+    }
+
+    #[test]
+    fn we_can_take_the_absolute_value_of_numeric_columns() {
+        let column = OwnedColumn::<TestScalar>::Int(vec![5, -3, 0, i32::MAX, -i32::MAX]);
+        assert_eq!(
+            column.element_wise_abs(),
+            Ok(OwnedColumn::<TestScalar>::Int(vec![
+                5,
+                3,
+                0,
+                i32::MAX,
+                i32::MAX
+            ]))
+        );
+
+        let column = OwnedColumn::<TestScalar>::UInt64(vec![0, 1, u64::MAX]);
+        assert_eq!(column.element_wise_abs(), Ok(column.clone()));
+
+        let column = OwnedColumn::<TestScalar>::Decimal75(
+            Precision::new(5).unwrap(),
+            2,
+            vec![
+                TestScalar::from(125),
+                TestScalar::from(-300),
+                TestScalar::ZERO,
+            ],
+        );
+        assert_eq!(
+            column.element_wise_abs(),
+            Ok(OwnedColumn::<TestScalar>::Decimal75(
+                Precision::new(5).unwrap(),
+                2,
+                vec![
+                    TestScalar::from(125),
+                    TestScalar::from(300),
+                    TestScalar::ZERO
+                ],
+            ))
+        );
+    }
+
+    #[test]
+    fn we_cannot_take_the_absolute_value_of_the_smallest_integer_or_non_numeric_columns() {
+        let column = OwnedColumn::<TestScalar>::TinyInt(vec![1, i8::MIN]);
+        assert!(matches!(
+            column.element_wise_abs(),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+
+        let column = OwnedColumn::<TestScalar>::VarChar(vec!["a".to_string()]);
+        assert!(matches!(
+            column.element_wise_abs(),
+            Err(ColumnOperationError::UnaryOperationInvalidColumnType { .. })
+        ));
+    }
+
     #[test]
     fn we_can_do_eq_operation() {
         // Integers
@@ -586,6 +861,89 @@ mod test {
         );
     }
 
+    #[test]
+    fn we_can_compare_unsigned_columns_across_the_signed_range() {
+        let above = i64::MAX as u64 + 1;
+        let lhs = OwnedColumn::<TestScalar>::UInt64(vec![i64::MAX as u64, above, above, u64::MAX]);
+        let rhs = OwnedColumn::<TestScalar>::UInt64(vec![above, i64::MAX as u64, above, 0]);
+        assert_eq!(
+            lhs.element_wise_le(&rhs),
+            Ok(OwnedColumn::<TestScalar>::Boolean(vec![
+                true, false, true, false
+            ]))
+        );
+        assert_eq!(
+            lhs.element_wise_eq(&rhs),
+            Ok(OwnedColumn::<TestScalar>::Boolean(vec![
+                false, false, true, false
+            ]))
+        );
+
+        // Unsigned and signed integers are compared by value
+        let signed = OwnedColumn::<TestScalar>::BigInt(vec![i64::MAX, -1, i64::MIN, i64::MAX]);
+        assert_eq!(
+            lhs.element_wise_ge(&signed),
+            Ok(OwnedColumn::<TestScalar>::Boolean(vec![
+                true, true, true, true
+            ]))
+        );
+        assert_eq!(
+            signed.element_wise_ge(&lhs),
+            Ok(OwnedColumn::<TestScalar>::Boolean(vec![
+                true, false, false, false
+            ]))
+        );
+    }
+
+    #[test]
+    fn we_can_do_arithmetic_on_unsigned_columns() {
+        let lhs = OwnedColumn::<TestScalar>::UInt64(vec![i64::MAX as u64, 5, u64::MAX - 1]);
+        let rhs = OwnedColumn::<TestScalar>::UInt64(vec![1, 3, 1]);
+        assert_eq!(
+            lhs.element_wise_add(&rhs),
+            Ok(OwnedColumn::<TestScalar>::UInt64(vec![
+                i64::MAX as u64 + 1,
+                8,
+                u64::MAX
+            ]))
+        );
+        assert_eq!(
+            lhs.element_wise_sub(&rhs),
+            Ok(OwnedColumn::<TestScalar>::UInt64(vec![
+                i64::MAX as u64 - 1,
+                2,
+                u64::MAX - 2
+            ]))
+        );
+        assert!(matches!(
+            rhs.element_wise_sub(&lhs),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+        assert!(matches!(
+            lhs.element_wise_mul(&lhs),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+
+        // Mixing unsigned and signed integers gives an Int128 column
+        let signed = OwnedColumn::<TestScalar>::BigInt(vec![-1, -10, i64::MIN]);
+        assert_eq!(
+            lhs.element_wise_add(&signed),
+            Ok(OwnedColumn::<TestScalar>::Int128(vec![
+                i128::from(i64::MAX) - 1,
+                -5,
+                i128::from(u64::MAX - 1) + i128::from(i64::MIN)
+            ]))
+        );
+        assert_eq!(
+            signed.element_wise_sub(&lhs),
+            Ok(OwnedColumn::<TestScalar>::Int128(vec![
+                -i128::from(i64::MAX) - 1,
+                -15,
+                i128::from(i64::MIN) - i128::from(u64::MAX - 1)
+            ]))
+        );
+    }
+
     #[test]
     fn we_can_add_decimal_columns() {
         // lhs and rhs have the same precision and scale
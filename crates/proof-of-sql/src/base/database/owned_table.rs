@@ -1,6 +1,9 @@
-use super::OwnedColumn;
-use crate::base::{map::IndexMap, polynomial::compute_evaluation_vector, scalar::Scalar};
-use alloc::{vec, vec::Vec};
+use super::{
+    owned_table_inference::{infer_column, InferError},
+    OwnedColumn, Row,
+};
+use crate::base::{map::IndexMap, polynomial::EvaluationVector, scalar::Scalar};
+use alloc::{string::ToString, vec::Vec};
 use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
 use snafu::Snafu;
@@ -40,6 +43,49 @@ impl<S: Scalar> OwnedTable<S> {
     ) -> Result<Self, OwnedTableError> {
         Self::try_new(IndexMap::from_iter(iter))
     }
+    /// Creates a new [`OwnedTable`] from rows of strings, inferring the type of each column.
+    ///
+    /// Each column becomes
+    /// - a `BigInt` column if every value is an integer that fits in an `i64`,
+    /// - a `Decimal75` column if every value is a number, with the smallest scale and precision that hold every value exactly,
+    /// - a `Boolean` column if every value is `true` or `false`, ignoring case,
+    /// - a `VarChar` column if no value is any of the above.
+    ///
+    /// A column without any rows becomes a `VarChar` column.
+    ///
+    /// # Errors
+    /// Returns an error if a header is not a valid column name or is repeated,
+    /// if a row does not have one value per header,
+    /// if a column mixes values of incompatible kinds, e.g. booleans and integers,
+    /// or if a decimal column needs more precision than is supported.
+    pub fn infer_from_rows(header: &[&str], rows: &[Vec<&str>]) -> Result<Self, InferError> {
+        if let Some((row, values)) = rows
+            .iter()
+            .enumerate()
+            .find(|(_, values)| values.len() != header.len())
+        {
+            return Err(InferError::RowLengthMismatch {
+                row,
+                expected: header.len(),
+                actual: values.len(),
+            });
+        }
+        let mut table = IndexMap::default();
+        for (index, name) in header.iter().enumerate() {
+            let identifier =
+                Identifier::try_new(name).map_err(|_| InferError::InvalidColumnName {
+                    name: (*name).to_string(),
+                })?;
+            let values: Vec<&str> = rows.iter().map(|row| row[index]).collect();
+            let column = infer_column(name, &values)?;
+            if table.insert(identifier, column).is_some() {
+                return Err(InferError::DuplicateColumnName {
+                    name: (*name).to_string(),
+                });
+            }
+        }
+        Ok(Self { table })
+    }
     /// Number of columns in the table.
     #[must_use]
     pub fn num_columns(&self) -> usize {
@@ -74,16 +120,42 @@ impl<S: Scalar> OwnedTable<S> {
         self.table.keys()
     }
 
-    pub(crate) fn mle_evaluations(&self, evaluation_point: &[S]) -> Vec<S> {
-        let mut evaluation_vector = vec![S::ZERO; self.num_rows()];
-        compute_evaluation_vector(&mut evaluation_vector, evaluation_point);
+    /// Returns an iterator over the rows of this table in order.
+    ///
+    /// The rows borrow the table, so this does not copy any data up front.
+    #[must_use]
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = Row<'_, S>> {
+        (0..self.num_rows()).map(move |index| Row { table: self, index })
+    }
+
+    /// Evaluate the MLE of every column at the point of `evaluation_vector`.
+    ///
+    /// The evaluation vector must have at least as many entries as the table has rows.
+    pub(crate) fn mle_evaluations(&self, evaluation_vector: &EvaluationVector<S>) -> Vec<S> {
+        debug_assert!(evaluation_vector.len() >= self.num_rows());
         self.table
             .values()
-            .map(|column| column.inner_product(&evaluation_vector))
+            .map(|column| column.inner_product(evaluation_vector.as_slice()))
             .collect()
     }
 }
 
+impl<S: Scalar> OwnedTable<S>
+where
+    Self: Serialize,
+{
+    /// Returns the length of the postcard serialization of the table, without serializing it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table cannot be serialized, which should not happen.
+    #[must_use]
+    pub fn serialized_size(&self) -> usize {
+        postcard::serialize_with_flavor(self, postcard::ser_flavors::Size::default())
+            .expect("table should serialize")
+    }
+}
+
 // Note: we modify the default PartialEq for IndexMap to also check for column ordering.
 // This is to align with the behaviour of a `RecordBatch`.
 impl<S: Scalar> PartialEq for OwnedTable<S> {
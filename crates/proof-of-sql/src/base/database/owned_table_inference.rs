@@ -0,0 +1,183 @@
+use super::OwnedColumn;
+#[cfg(doc)]
+use super::OwnedTable;
+use crate::base::{
+    math::{
+        decimal::{try_convert_intermediate_decimal_to_scalar, Precision, MAX_SUPPORTED_PRECISION},
+        BigDecimalExt,
+    },
+    scalar::Scalar,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bigdecimal::BigDecimal;
+use snafu::Snafu;
+
+/// Errors that can occur when inferring an [`OwnedTable`] from rows of strings.
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum InferError {
+    /// A header is not a valid column name.
+    #[snafu(display("Invalid column name: {name}"))]
+    InvalidColumnName {
+        /// The invalid header
+        name: String,
+    },
+    /// Two headers name the same column.
+    #[snafu(display("Duplicate column name: {name}"))]
+    DuplicateColumnName {
+        /// The duplicated header
+        name: String,
+    },
+    /// A row does not have one value per header.
+    #[snafu(display("Row {row} has {actual} values but there are {expected} columns"))]
+    RowLengthMismatch {
+        /// The index of the row
+        row: usize,
+        /// The number of headers
+        expected: usize,
+        /// The number of values in the row
+        actual: usize,
+    },
+    /// A column holds values that cannot share a type, e.g. a boolean and an integer.
+    #[snafu(display(
+        "Column {column} has a value in row {row} that is incompatible with the earlier values"
+    ))]
+    InconsistentColumn {
+        /// The name of the column
+        column: String,
+        /// The index of the first row whose value is incompatible with the earlier values
+        row: usize,
+    },
+    /// A decimal column needs more precision than is supported.
+    #[snafu(display("Column {column} needs a precision of {precision}, which is not supported"))]
+    DecimalOutOfRange {
+        /// The name of the column
+        column: String,
+        /// The precision the column needs
+        precision: u64,
+    },
+}
+
+/// The kinds of values that can be inferred from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InferredKind {
+    Integer,
+    Decimal,
+    Boolean,
+    VarChar,
+}
+
+impl InferredKind {
+    /// The kind of a single value.
+    fn of(value: &str) -> Self {
+        if value.parse::<i64>().is_ok() {
+            Self::Integer
+        } else if value.parse::<BigDecimal>().is_ok() {
+            Self::Decimal
+        } else if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+            Self::Boolean
+        } else {
+            Self::VarChar
+        }
+    }
+
+    /// The kind of a column that holds values of both kinds, if there is one.
+    ///
+    /// Integers are promoted to decimals. Any other pair of different kinds is incompatible.
+    fn unify(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (a, b) if a == b => Some(a),
+            (Self::Integer, Self::Decimal) | (Self::Decimal, Self::Integer) => Some(Self::Decimal),
+            _ => None,
+        }
+    }
+}
+
+/// Infer the type of a single column and parse its values.
+pub(super) fn infer_column<S: Scalar>(
+    name: &str,
+    values: &[&str],
+) -> Result<OwnedColumn<S>, InferError> {
+    let mut kind: Option<InferredKind> = None;
+    for (row, value) in values.iter().enumerate() {
+        let value_kind = InferredKind::of(value);
+        kind = Some(match kind {
+            None => value_kind,
+            Some(kind) => kind
+                .unify(value_kind)
+                .ok_or_else(|| InferError::InconsistentColumn {
+                    column: name.to_string(),
+                    row,
+                })?,
+        });
+    }
+    // The kind of every value is known to parse, so no value is dropped.
+    Ok(match kind.unwrap_or(InferredKind::VarChar) {
+        InferredKind::Integer => OwnedColumn::BigInt(
+            values
+                .iter()
+                .filter_map(|value| value.parse().ok())
+                .collect(),
+        ),
+        InferredKind::Decimal => infer_decimal_column(name, values)?,
+        InferredKind::Boolean => OwnedColumn::Boolean(
+            values
+                .iter()
+                .map(|value| value.eq_ignore_ascii_case("true"))
+                .collect(),
+        ),
+        InferredKind::VarChar => {
+            OwnedColumn::VarChar(values.iter().map(ToString::to_string).collect())
+        }
+    })
+}
+
+/// Parse a column of numbers as decimals with a common precision and scale.
+fn infer_decimal_column<S: Scalar>(
+    name: &str,
+    values: &[&str],
+) -> Result<OwnedColumn<S>, InferError> {
+    // Every value is a number, so no value is dropped.
+    let decimals: Vec<BigDecimal> = values
+        .iter()
+        .filter_map(|value| value.parse().ok())
+        .collect();
+    let scale = decimals
+        .iter()
+        .map(BigDecimalExt::scale)
+        .max()
+        .unwrap_or(0)
+        .max(0);
+    let integer_digits = decimals
+        .iter()
+        .map(|decimal| {
+            i64::try_from(decimal.precision())
+                .unwrap_or(i64::MAX)
+                .saturating_sub(BigDecimalExt::scale(decimal))
+        })
+        .max()
+        .unwrap_or(0)
+        .max(0);
+    let precision = u64::try_from(integer_digits.saturating_add(scale))
+        .unwrap_or(u64::MAX)
+        .max(1);
+    let out_of_range = || InferError::DecimalOutOfRange {
+        column: name.to_string(),
+        precision,
+    };
+    if precision > u64::from(MAX_SUPPORTED_PRECISION) {
+        return Err(out_of_range());
+    }
+    let precision_value = Precision::try_from(precision).map_err(|_| out_of_range())?;
+    let scale = i8::try_from(scale).map_err(|_| out_of_range())?;
+    let scalars = decimals
+        .iter()
+        .map(|decimal| {
+            try_convert_intermediate_decimal_to_scalar(decimal, precision_value, scale)
+                .map_err(|_| out_of_range())
+        })
+        .collect::<Result<Vec<S>, _>>()?;
+    Ok(OwnedColumn::Decimal75(precision_value, scale, scalars))
+}
@@ -0,0 +1,132 @@
+use super::{owned_table_utility::*, InferError, OwnedTable};
+use crate::base::scalar::test_scalar::TestScalar;
+
+#[test]
+fn we_can_infer_integer_boolean_and_varchar_columns() {
+    let table = OwnedTable::<TestScalar>::infer_from_rows(
+        &["id", "flag", "name"],
+        &[
+            vec!["1", "true", "alice"],
+            vec!["-20", "FALSE", "bob"],
+            vec!["300", "True", ""],
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        table,
+        owned_table([
+            bigint("id", [1_i64, -20, 300]),
+            boolean("flag", [true, false, true]),
+            varchar("name", ["alice", "bob", ""]),
+        ])
+    );
+}
+
+#[test]
+fn we_can_infer_a_decimal_column_from_mixed_integers_and_decimals() {
+    let table = OwnedTable::<TestScalar>::infer_from_rows(
+        &["price", "amount"],
+        &[vec!["1", "100"], vec!["2.5", "0.5"], vec!["-3.25", "7"]],
+    )
+    .unwrap();
+    assert_eq!(
+        table,
+        owned_table([
+            decimal75("price", 3, 2, [100_i64, 250, -325]),
+            decimal75("amount", 4, 1, [1000_i64, 5, 70]),
+        ])
+    );
+}
+
+#[test]
+fn we_can_infer_a_decimal_column_from_integers_that_do_not_fit_in_a_bigint() {
+    let table = OwnedTable::<TestScalar>::infer_from_rows(
+        &["a"],
+        &[vec!["1"], vec!["100000000000000000000"]],
+    )
+    .unwrap();
+    assert_eq!(
+        table,
+        owned_table([decimal75(
+            "a",
+            21,
+            0,
+            [
+                TestScalar::from(1),
+                TestScalar::from(100_000_000_000_000_000_000_u128)
+            ]
+        )])
+    );
+}
+
+#[test]
+fn we_can_infer_varchar_columns_from_a_header_without_rows() {
+    let table = OwnedTable::<TestScalar>::infer_from_rows(&["a", "b"], &[]).unwrap();
+    assert_eq!(
+        table,
+        owned_table([varchar("a", [""; 0]), varchar("b", [""; 0])])
+    );
+}
+
+#[test]
+fn we_cannot_infer_a_column_with_incompatible_values() {
+    let rows = [vec!["1", "x"], vec!["2.5", "y"], vec!["true", "3"]];
+    assert_eq!(
+        OwnedTable::<TestScalar>::infer_from_rows(&["a", "b"], &rows[..2]),
+        Ok(owned_table([
+            decimal75("a", 2, 1, [10_i64, 25]),
+            varchar("b", ["x", "y"]),
+        ]))
+    );
+    assert_eq!(
+        OwnedTable::<TestScalar>::infer_from_rows(&["a", "b"], &rows),
+        Err(InferError::InconsistentColumn {
+            column: "a".to_string(),
+            row: 2,
+        })
+    );
+    assert_eq!(
+        OwnedTable::<TestScalar>::infer_from_rows(&["b"], &[vec!["x"], vec!["1"]]),
+        Err(InferError::InconsistentColumn {
+            column: "b".to_string(),
+            row: 1,
+        })
+    );
+}
+
+#[test]
+fn we_cannot_infer_a_table_with_malformed_headers_or_rows() {
+    assert_eq!(
+        OwnedTable::<TestScalar>::infer_from_rows(&["a", "b"], &[vec!["1", "2"], vec!["3"]]),
+        Err(InferError::RowLengthMismatch {
+            row: 1,
+            expected: 2,
+            actual: 1,
+        })
+    );
+    assert_eq!(
+        OwnedTable::<TestScalar>::infer_from_rows(&["not a name"], &[vec!["1"]]),
+        Err(InferError::InvalidColumnName {
+            name: "not a name".to_string(),
+        })
+    );
+    assert_eq!(
+        OwnedTable::<TestScalar>::infer_from_rows(&["a", "A"], &[vec!["1", "2"]]),
+        Err(InferError::DuplicateColumnName {
+            name: "A".to_string(),
+        })
+    );
+}
+
+#[test]
+fn we_cannot_infer_a_decimal_column_that_needs_too_much_precision() {
+    let digits = "1".repeat(40);
+    let value = digits.clone() + "." + &digits;
+    assert_eq!(
+        OwnedTable::<TestScalar>::infer_from_rows(&["a"], &[vec![value.as_str()]]),
+        Err(InferError::DecimalOutOfRange {
+            column: "a".to_string(),
+            precision: 80,
+        })
+    );
+}
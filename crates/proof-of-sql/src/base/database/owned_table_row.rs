@@ -0,0 +1,92 @@
+use super::OwnedTable;
+use crate::base::{math::decimal::Precision, scalar::Scalar};
+use proof_of_sql_parser::{
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+    Identifier,
+};
+
+/// A single value of a [`Row`], borrowed from the column it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RowValue<'a, S: Scalar> {
+    /// Boolean values
+    Boolean(bool),
+    /// i8 values
+    TinyInt(i8),
+    /// i16 values
+    SmallInt(i16),
+    /// i32 values
+    Int(i32),
+    /// i64 values
+    BigInt(i64),
+    /// u64 values
+    UInt64(u64),
+    /// String values
+    VarChar(&'a str),
+    /// i128 values
+    Int128(i128),
+    /// Decimal values, along with the precision and scale of the column
+    Decimal75(Precision, i8, S),
+    /// Scalar values
+    Scalar(S),
+    /// Timestamp values, along with the time unit and timezone of the column
+    TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, i64),
+    /// Fixed-length binary values
+    FixedBytes(&'a [u8]),
+}
+
+/// A single row of an [`OwnedTable`].
+///
+/// This only borrows the table, so iterating over rows does not copy any column.
+/// Values are decoded from the columns as they are accessed.
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'a, S: Scalar> {
+    pub(super) table: &'a OwnedTable<S>,
+    pub(super) index: usize,
+}
+
+impl<'a, S: Scalar> Row<'a, S> {
+    /// The index of this row in the table
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The number of values in this row, which is the number of columns in the table
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.table.num_columns()
+    }
+
+    /// Whether the row has no values
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Returns the value of the column with the given name, or `None` if there is no such column.
+    #[must_use]
+    pub fn get(&self, column: &Identifier) -> Option<RowValue<'a, S>> {
+        self.table.inner_table().get(column)?.value_at(self.index)
+    }
+
+    /// Returns the values of this row in column order.
+    pub fn values(&self) -> impl Iterator<Item = RowValue<'a, S>> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns the column names and values of this row in column order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row index is out of bounds, which cannot happen for rows returned by [`OwnedTable::rows`].
+    pub fn iter(&self) -> impl Iterator<Item = (&'a Identifier, RowValue<'a, S>)> {
+        let index = self.index;
+        self.table.inner_table().iter().map(move |(name, column)| {
+            let value = column
+                .value_at(index)
+                .expect("row index is within the table");
+            (name, value)
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    base::{
+        database::{owned_table_utility::*, OwnedColumn, OwnedTable, RowValue, TableSchema},
+        map::IndexMap,
+        math::decimal::Precision,
+        scalar::test_scalar::TestScalar,
+    },
+    sql::proof::QueryData,
+};
+use alloc::{vec, vec::Vec};
+use proof_of_sql_parser::{
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+    Identifier,
+};
+
+#[test]
+fn we_can_iterate_over_every_row_of_a_table_in_order() {
+    let table: OwnedTable<TestScalar> = owned_table([
+        bigint("a", [1_i64, 2, 3]),
+        varchar("b", ["x", "y", "z"]),
+        boolean("c", [true, false, true]),
+        decimal75("d", 10, 2, [100_i64, -250, 0]),
+        timestamptz("e", PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, [7, 8, 9]),
+    ]);
+    let rows = table.rows();
+    assert_eq!(rows.len(), 3);
+    let rows: Vec<Vec<RowValue<'_, TestScalar>>> = rows
+        .map(|row| {
+            assert_eq!(row.len(), 5);
+            row.values().collect()
+        })
+        .collect();
+    let precision = Precision::new(10).unwrap();
+    let timestamp = |value| RowValue::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, value);
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                RowValue::BigInt(1),
+                RowValue::VarChar("x"),
+                RowValue::Boolean(true),
+                RowValue::Decimal75(precision, 2, TestScalar::from(100)),
+                timestamp(7),
+            ],
+            vec![
+                RowValue::BigInt(2),
+                RowValue::VarChar("y"),
+                RowValue::Boolean(false),
+                RowValue::Decimal75(precision, 2, TestScalar::from(-250)),
+                timestamp(8),
+            ],
+            vec![
+                RowValue::BigInt(3),
+                RowValue::VarChar("z"),
+                RowValue::Boolean(true),
+                RowValue::Decimal75(precision, 2, TestScalar::from(0)),
+                timestamp(9),
+            ],
+        ]
+    );
+}
+
+#[test]
+fn we_can_look_up_row_values_by_column_name() {
+    let table: OwnedTable<TestScalar> = owned_table([
+        tinyint("a", [1_i8, 2]),
+        smallint("b", [3_i16, 4]),
+        int("c", [5_i32, 6]),
+        int128("d", [7_i128, 8]),
+        scalar("e", [9, 10]),
+    ]);
+    let row = table.rows().nth(1).unwrap();
+    assert_eq!(row.index(), 1);
+    let get = |name: &str| row.get(&name.parse().unwrap());
+    assert_eq!(get("a"), Some(RowValue::TinyInt(2)));
+    assert_eq!(get("b"), Some(RowValue::SmallInt(4)));
+    assert_eq!(get("c"), Some(RowValue::Int(6)));
+    assert_eq!(get("d"), Some(RowValue::Int128(8)));
+    assert_eq!(get("e"), Some(RowValue::Scalar(TestScalar::from(10))));
+    assert_eq!(get("f"), None);
+    let names: Vec<&Identifier> = row.iter().map(|(name, _)| name).collect();
+    assert_eq!(names, table.column_names().collect::<Vec<_>>());
+}
+
+#[test]
+fn we_can_iterate_over_the_rows_of_a_single_column_table() {
+    let table: OwnedTable<TestScalar> = owned_table([varchar("a", ["one", "two", "three"])]);
+    let values: Vec<RowValue<'_, TestScalar>> = table
+        .rows()
+        .map(|row| {
+            assert_eq!(row.len(), 1);
+            row.values().next().unwrap()
+        })
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            RowValue::VarChar("one"),
+            RowValue::VarChar("two"),
+            RowValue::VarChar("three")
+        ]
+    );
+}
+
+#[test]
+fn an_empty_table_has_no_rows() {
+    let no_columns = OwnedTable::<TestScalar>::try_new(IndexMap::default()).unwrap();
+    assert_eq!(no_columns.rows().len(), 0);
+    let no_rows: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [0_i64; 0]), varchar("b", [""; 0])]);
+    assert_eq!(no_rows.rows().len(), 0);
+    assert!(no_rows.rows().next().is_none());
+}
+
+#[test]
+fn we_can_only_get_values_within_a_column() {
+    let column = OwnedColumn::<TestScalar>::BigInt(vec![1, 2]);
+    assert_eq!(column.value_at(1), Some(RowValue::BigInt(2)));
+    assert_eq!(column.value_at(2), None);
+}
+
+#[test]
+fn we_can_iterate_over_the_rows_of_query_data() {
+    let query_data = QueryData {
+        table: owned_table::<TestScalar>([bigint("a", [1_i64, 2]), varchar("b", ["x", "y"])]),
+        verification_hash: [0; 32],
+        result_schema: TableSchema::default(),
+    };
+    let rows: Vec<(i64, &str)> = query_data
+        .rows()
+        .map(|row| match row.values().collect::<Vec<_>>()[..] {
+            [RowValue::BigInt(a), RowValue::VarChar(b)] => (a, b),
+            _ => panic!("unexpected row"),
+        })
+        .collect();
+    assert_eq!(rows, vec![(1, "x"), (2, "y")]);
+
+    let empty = QueryData {
+        table: owned_table::<TestScalar>([bigint("a", [0_i64; 0])]),
+        verification_hash: [0; 32],
+        result_schema: TableSchema::default(),
+    };
+    assert_eq!(empty.rows().len(), 0);
+}
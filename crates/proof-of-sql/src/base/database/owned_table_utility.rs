@@ -15,7 +15,7 @@
 //! ```
 use super::{OwnedColumn, OwnedTable};
 use crate::base::scalar::Scalar;
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::ops::Deref;
 use proof_of_sql_parser::{
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
@@ -131,6 +131,26 @@ pub fn bigint<S: Scalar>(
     )
 }
 
+/// Creates a `(Identifier, OwnedColumn)` pair for a uint64 column.
+/// This is primarily intended for use in conjunction with [`owned_table`].
+/// # Example
+/// ```rust
+/// use proof_of_sql::base::{database::owned_table_utility::*, scalar::Curve25519Scalar};
+/// let result = owned_table::<Curve25519Scalar>([
+///     uint64("a", [1_u64, 2, u64::MAX]),
+/// ]);
+/// ```
+#[allow(clippy::missing_panics_doc)]
+pub fn uint64<S: Scalar>(
+    name: impl Deref<Target = str>,
+    data: impl IntoIterator<Item = impl Into<u64>>,
+) -> (Identifier, OwnedColumn<S>) {
+    (
+        name.parse().unwrap(),
+        OwnedColumn::UInt64(data.into_iter().map(Into::into).collect()),
+    )
+}
+
 /// Creates a `(Identifier, OwnedColumn)` pair for a boolean column.
 /// This is primarily intended for use in conjunction with [`owned_table`].
 /// # Example
@@ -219,6 +239,32 @@ pub fn varchar<S: Scalar>(
     )
 }
 
+/// Creates a `(Identifier, OwnedColumn)` pair for a fixed-length binary column of `N` bytes.
+/// This is primarily intended for use in conjunction with [`owned_table`].
+/// # Example
+/// ```
+/// use proof_of_sql::base::{database::owned_table_utility::*, scalar::Curve25519Scalar};
+/// let result = owned_table::<Curve25519Scalar>([
+///     fixed_bytes("a", [[0x00, 0x01], [0xab, 0xcd]]),
+/// ]);
+/// ```
+///
+/// # Panics
+/// - Panics if `name.parse()` fails to convert the name into an `Identifier`.
+/// - Panics if `N` is more than 255.
+pub fn fixed_bytes<S: Scalar, const N: usize>(
+    name: impl Deref<Target = str>,
+    data: impl IntoIterator<Item = [u8; N]>,
+) -> (Identifier, OwnedColumn<S>) {
+    (
+        name.parse().unwrap(),
+        OwnedColumn::FixedBytes(
+            N.try_into().unwrap(),
+            data.into_iter().map(Vec::from).collect(),
+        ),
+    )
+}
+
 /// Creates a `(Identifier, OwnedColumn)` pair for a decimal75 column.
 /// This is primarily intended for use in conjunction with [`owned_table`].
 /// # Example
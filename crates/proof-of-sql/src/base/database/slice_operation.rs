@@ -1,7 +1,7 @@
 use super::{ColumnOperationError, ColumnOperationResult};
 use alloc::{format, vec::Vec};
 use core::fmt::Debug;
-use num_traits::ops::checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use num_traits::ops::checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub};
 
 /// Reverse a binary operator. That is, $a *_{op} b = b * a$.
 ///
@@ -37,6 +37,34 @@ where
         })
 }
 
+/// Function for checked negation with overflow error handling
+pub(super) fn try_neg<T>(value: &T) -> ColumnOperationResult<T>
+where
+    T: CheckedNeg + Debug,
+{
+    value
+        .checked_neg()
+        .ok_or(ColumnOperationError::IntegerOverflow {
+            error: format!("Overflow in integer negation -{value:?}"),
+        })
+}
+
+/// Function for checked absolute value with overflow error handling
+pub(super) fn try_abs<T>(value: &T) -> ColumnOperationResult<T>
+where
+    T: CheckedNeg + Copy + Debug + Default + PartialOrd,
+{
+    if *value < T::default() {
+        value
+            .checked_neg()
+            .ok_or(ColumnOperationError::IntegerOverflow {
+                error: format!("Overflow in integer absolute value abs({value:?})"),
+            })
+    } else {
+        Ok(*value)
+    }
+}
+
 /// Function for checked multiplication with overflow error handling
 pub(super) fn try_mul<T>(l: &T, r: &T) -> ColumnOperationResult<T>
 where
@@ -203,6 +231,34 @@ where
     })
 }
 
+/// Apply a binary operator to two slices of the same length, upcasting both sides to `V`.
+pub(crate) fn slice_binary_op_both_upcast<S, T, V, U, F>(lhs: &[S], rhs: &[T], op: F) -> Vec<U>
+where
+    S: Copy + Into<V>,
+    T: Copy + Into<V>,
+    F: Fn(&V, &V) -> U,
+{
+    slice_binary_op(lhs, rhs, |l, r| -> U {
+        op(&Into::<V>::into(*l), &Into::<V>::into(*r))
+    })
+}
+
+/// Apply a binary operator to two slices of the same length, upcasting both sides to `V` and returning results.
+pub(crate) fn try_slice_binary_op_both_upcast<S, T, V, U, F>(
+    lhs: &[S],
+    rhs: &[T],
+    op: F,
+) -> ColumnOperationResult<Vec<U>>
+where
+    S: Copy + Into<V>,
+    T: Copy + Into<V>,
+    F: Fn(&V, &V) -> ColumnOperationResult<U>,
+{
+    try_slice_binary_op(lhs, rhs, |l, r| -> ColumnOperationResult<U> {
+        op(&Into::<V>::into(*l), &Into::<V>::into(*r))
+    })
+}
+
 // Unary operations
 
 /// Negate a slice of boolean values.
@@ -0,0 +1,435 @@
+use super::{ColumnField, ColumnType};
+use crate::base::map::IndexMap;
+use alloc::vec::Vec;
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// The version of the schema document written by [`TableSchema::to_json`], and the only one it can read
+pub const TABLE_SCHEMA_JSON_VERSION: u64 = 1;
+
+/// The schema document written by [`TableSchema::to_json`]
+#[derive(Serialize, Deserialize)]
+struct TableSchemaDocument {
+    version: u64,
+    columns: Vec<ColumnField>,
+}
+
+/// The part of a schema document that is read before the columns
+#[derive(Deserialize)]
+struct TableSchemaDocumentVersion {
+    version: u64,
+}
+
+/// Errors that can occur when reading a schema document with [`TableSchema::from_json`]
+#[derive(Debug, Snafu)]
+pub enum TableSchemaJsonError {
+    /// The document was written with a version this library cannot read.
+    #[snafu(display(
+        "unsupported table schema document version {found}, supported version is {supported}"
+    ))]
+    UnsupportedVersion {
+        /// The version found in the document
+        found: u64,
+        /// The version this library supports
+        supported: u64,
+    },
+    /// The document is malformed, e.g. because a column has an unknown type.
+    #[snafu(display("invalid table schema document: {error}"))]
+    InvalidDocument {
+        /// The underlying deserialization error
+        error: serde_json::Error,
+    },
+    /// The document has more than one column with the same name.
+    #[snafu(display("column '{name}' appears more than once in the table schema document"))]
+    DuplicateColumn {
+        /// The name of the repeated column
+        name: Identifier,
+    },
+}
+
+/// The names and types of the columns of a table
+///
+/// Two schemas are equal if they have the same columns with the same types, regardless of column order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableSchema {
+    columns: IndexMap<Identifier, ColumnType>,
+}
+
+impl TableSchema {
+    /// Create a new `TableSchema` from the columns of a table, in order
+    #[must_use]
+    pub fn new(columns: IndexMap<Identifier, ColumnType>) -> Self {
+        Self { columns }
+    }
+
+    /// Returns the columns of the schema, in order
+    #[must_use]
+    pub fn columns(&self) -> &IndexMap<Identifier, ColumnType> {
+        &self.columns
+    }
+
+    /// Returns the type of a column, if the schema has it
+    #[must_use]
+    pub fn column_type(&self, name: &Identifier) -> Option<ColumnType> {
+        self.columns.get(name).copied()
+    }
+
+    /// Compare this schema against a newer one, column by column.
+    ///
+    /// Columns are matched by name, so reordering columns is not a change.
+    /// Added columns are reported in the order of `other`,
+    /// while removed and type-changed columns are reported in the order of `self`.
+    #[must_use]
+    pub fn diff(&self, other: &TableSchema) -> SchemaDiff {
+        let added = other
+            .columns
+            .iter()
+            .filter(|(name, _)| !self.columns.contains_key(*name))
+            .map(|(name, data_type)| ColumnField::new(*name, *data_type))
+            .collect();
+        let (removed, type_changed) = self.columns.iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut removed, mut type_changed), (name, old_type)| {
+                match other.column_type(name) {
+                    None => removed.push(ColumnField::new(*name, *old_type)),
+                    Some(new_type) if new_type != *old_type => {
+                        type_changed.push(ColumnTypeChange {
+                            name: *name,
+                            old_type: *old_type,
+                            new_type,
+                        });
+                    }
+                    Some(_) => {}
+                }
+                (removed, type_changed)
+            },
+        );
+        SchemaDiff {
+            added,
+            removed,
+            type_changed,
+        }
+    }
+
+    /// Describe the schema as a versioned JSON document.
+    ///
+    /// The document has the form `{"version": 1, "columns": [{"name": "a", "data_type": "BigInt"}, ...]}`,
+    /// with the columns in order and each type written the way [`ColumnType`] serializes,
+    /// e.g. `{"Decimal75": [10, 2]}` for a decimal with precision 10 and scale 2.
+    /// Columns cannot hold nulls, so the document has no nullability.
+    /// This describes the schema only, not the data or commitments of the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema cannot be serialized, which should not happen.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(TableSchemaDocument {
+            version: TABLE_SCHEMA_JSON_VERSION,
+            columns: self
+                .columns
+                .iter()
+                .map(|(name, data_type)| ColumnField::new(*name, *data_type))
+                .collect(),
+        })
+        .expect("schema should serialize")
+    }
+
+    /// Read a schema from a document written by [`Self::to_json`].
+    ///
+    /// The version is checked before the columns are read.
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, TableSchemaJsonError> {
+        let invalid_document = |error| TableSchemaJsonError::InvalidDocument { error };
+        let TableSchemaDocumentVersion { version } =
+            TableSchemaDocumentVersion::deserialize(value).map_err(invalid_document)?;
+        if version != TABLE_SCHEMA_JSON_VERSION {
+            return Err(TableSchemaJsonError::UnsupportedVersion {
+                found: version,
+                supported: TABLE_SCHEMA_JSON_VERSION,
+            });
+        }
+        let document = TableSchemaDocument::deserialize(value).map_err(invalid_document)?;
+        let mut columns = IndexMap::default();
+        for field in document.columns {
+            if columns.insert(field.name(), field.data_type()).is_some() {
+                return Err(TableSchemaJsonError::DuplicateColumn { name: field.name() });
+            }
+        }
+        Ok(Self::new(columns))
+    }
+}
+
+impl FromIterator<ColumnField> for TableSchema {
+    fn from_iter<T: IntoIterator<Item = ColumnField>>(iter: T) -> Self {
+        Self::new(
+            iter.into_iter()
+                .map(|field| (field.name(), field.data_type()))
+                .collect(),
+        )
+    }
+}
+
+/// A column whose type differs between two schemas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnTypeChange {
+    /// The name of the column
+    pub name: Identifier,
+    /// The type of the column in the old schema
+    pub old_type: ColumnType,
+    /// The type of the column in the new schema
+    pub new_type: ColumnType,
+}
+
+/// The semantic differences between two table schemas, as computed by [`TableSchema::diff`]
+///
+/// Proofs against the old schema remain meaningful for the new one only if nothing was removed or changed type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    /// Columns that are only in the new schema
+    pub added: Vec<ColumnField>,
+    /// Columns that are only in the old schema
+    pub removed: Vec<ColumnField>,
+    /// Columns that are in both schemas with different types
+    pub type_changed: Vec<ColumnTypeChange>,
+}
+
+impl SchemaDiff {
+    /// Returns true if the schemas have the same columns with the same types
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.type_changed.is_empty()
+    }
+
+    /// Returns true if existing columns were removed or changed type.
+    ///
+    /// Adding columns alone does not invalidate commitments to or proofs about the existing columns.
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        !self.removed.is_empty() || !self.type_changed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ColumnTypeChange, SchemaDiff, TableSchema, TableSchemaJsonError, TABLE_SCHEMA_JSON_VERSION,
+    };
+    use crate::base::{
+        database::{ColumnField, ColumnType},
+        map::indexmap,
+    };
+    use alloc::vec;
+    use proof_of_sql_parser::posql_time::{PoSQLTimeUnit, PoSQLTimeZone};
+    use serde_json::json;
+
+    fn schema(columns: &[(&str, ColumnType)]) -> TableSchema {
+        columns
+            .iter()
+            .map(|(name, data_type)| ColumnField::new(name.parse().unwrap(), *data_type))
+            .collect()
+    }
+
+    #[test]
+    fn we_can_diff_identical_schemas() {
+        let old = schema(&[("a", ColumnType::BigInt), ("b", ColumnType::VarChar)]);
+        let diff = old.diff(&old.clone());
+        assert_eq!(diff, SchemaDiff::default());
+        assert!(diff.is_empty());
+        assert!(!diff.is_breaking());
+    }
+
+    #[test]
+    fn we_can_diff_schemas_with_an_added_column() {
+        let old = schema(&[("a", ColumnType::BigInt)]);
+        let new = schema(&[("a", ColumnType::BigInt), ("b", ColumnType::Boolean)]);
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.added,
+            vec![ColumnField::new("b".parse().unwrap(), ColumnType::Boolean)]
+        );
+        assert!(diff.removed.is_empty());
+        assert!(diff.type_changed.is_empty());
+        assert!(!diff.is_empty());
+        assert!(!diff.is_breaking());
+    }
+
+    #[test]
+    fn we_can_diff_schemas_with_a_removed_column() {
+        let old = schema(&[("a", ColumnType::BigInt), ("b", ColumnType::Boolean)]);
+        let new = schema(&[("b", ColumnType::Boolean)]);
+        let diff = old.diff(&new);
+        assert!(diff.added.is_empty());
+        assert_eq!(
+            diff.removed,
+            vec![ColumnField::new("a".parse().unwrap(), ColumnType::BigInt)]
+        );
+        assert!(diff.type_changed.is_empty());
+        assert!(diff.is_breaking());
+    }
+
+    #[test]
+    fn we_can_diff_schemas_with_a_type_change() {
+        let old = schema(&[("a", ColumnType::Int), ("b", ColumnType::Boolean)]);
+        let new = schema(&[("a", ColumnType::BigInt), ("b", ColumnType::Boolean)]);
+        let diff = old.diff(&new);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.type_changed,
+            vec![ColumnTypeChange {
+                name: "a".parse().unwrap(),
+                old_type: ColumnType::Int,
+                new_type: ColumnType::BigInt,
+            }]
+        );
+        assert!(diff.is_breaking());
+
+        // Changing the precision of a decimal is also a type change
+        let old = schema(&[("d", ColumnType::Decimal75(10.try_into().unwrap(), 2))]);
+        let new = schema(&[("d", ColumnType::Decimal75(12.try_into().unwrap(), 2))]);
+        assert_eq!(old.diff(&new).type_changed.len(), 1);
+    }
+
+    #[test]
+    fn we_report_no_diff_for_reordered_columns() {
+        let old = schema(&[
+            ("a", ColumnType::BigInt),
+            ("b", ColumnType::VarChar),
+            ("c", ColumnType::Boolean),
+        ]);
+        let new = schema(&[
+            ("c", ColumnType::Boolean),
+            ("a", ColumnType::BigInt),
+            ("b", ColumnType::VarChar),
+        ]);
+        assert!(old.diff(&new).is_empty());
+        assert!(new.diff(&old).is_empty());
+        assert_eq!(old, new);
+    }
+
+    #[test]
+    fn we_can_diff_schemas_with_several_kinds_of_changes() {
+        let old = TableSchema::new(indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::VarChar,
+            "c".parse().unwrap() => ColumnType::Boolean,
+        });
+        let new = TableSchema::new(indexmap! {
+            "e".parse().unwrap() => ColumnType::Int128,
+            "c".parse().unwrap() => ColumnType::SmallInt,
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "d".parse().unwrap() => ColumnType::Scalar,
+        });
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.added,
+            vec![
+                ColumnField::new("e".parse().unwrap(), ColumnType::Int128),
+                ColumnField::new("d".parse().unwrap(), ColumnType::Scalar),
+            ]
+        );
+        assert_eq!(
+            diff.removed,
+            vec![ColumnField::new("b".parse().unwrap(), ColumnType::VarChar)]
+        );
+        assert_eq!(
+            diff.type_changed,
+            vec![ColumnTypeChange {
+                name: "c".parse().unwrap(),
+                old_type: ColumnType::Boolean,
+                new_type: ColumnType::SmallInt,
+            }]
+        );
+    }
+
+    #[test]
+    fn we_can_round_trip_a_schema_with_every_column_type_through_json() {
+        let schema = TableSchema::new(indexmap! {
+            "scal".parse().unwrap() => ColumnType::Scalar,
+            "bool".parse().unwrap() => ColumnType::Boolean,
+            "tiny".parse().unwrap() => ColumnType::TinyInt,
+            "small".parse().unwrap() => ColumnType::SmallInt,
+            "int".parse().unwrap() => ColumnType::Int,
+            "big".parse().unwrap() => ColumnType::BigInt,
+            "i128".parse().unwrap() => ColumnType::Int128,
+            "str".parse().unwrap() => ColumnType::VarChar,
+            "dec".parse().unwrap() => ColumnType::Decimal75(10.try_into().unwrap(), -2),
+            "time".parse().unwrap() => ColumnType::TimestampTZ(PoSQLTimeUnit::Millisecond, PoSQLTimeZone::Utc),
+        });
+        let json = schema.to_json();
+        assert_eq!(json["version"], TABLE_SCHEMA_JSON_VERSION);
+        assert_eq!(json["columns"].as_array().unwrap().len(), 10);
+        let round_tripped = TableSchema::from_json(&json).unwrap();
+        assert_eq!(round_tripped, schema);
+        // Equality ignores order, so check the order separately
+        assert!(round_tripped.columns().iter().eq(schema.columns().iter()));
+        assert_eq!(round_tripped.to_json(), json);
+    }
+
+    #[test]
+    fn we_write_a_stable_schema_json_document() {
+        let schema = TableSchema::new(indexmap! {
+            "b".parse().unwrap() => ColumnType::BigInt,
+            "a".parse().unwrap() => ColumnType::Decimal75(75.try_into().unwrap(), 3),
+        });
+        assert_eq!(
+            schema.to_json(),
+            json!({
+                "version": 1,
+                "columns": [
+                    {"name": "b", "data_type": "BigInt"},
+                    {"name": "a", "data_type": {"Decimal75": [75, 3]}},
+                ],
+            })
+        );
+        assert_eq!(
+            TableSchema::default().to_json(),
+            json!({"version": 1, "columns": []})
+        );
+    }
+
+    #[test]
+    fn we_cannot_read_a_schema_json_document_with_an_unknown_type() {
+        let json = json!({
+            "version": 1,
+            "columns": [{"name": "a", "data_type": "Float"}],
+        });
+        let error = TableSchema::from_json(&json).unwrap_err();
+        assert!(matches!(
+            error,
+            TableSchemaJsonError::InvalidDocument { .. }
+        ));
+        assert!(error.to_string().contains("unknown variant `Float`"));
+    }
+
+    #[test]
+    fn we_cannot_read_a_malformed_or_unsupported_schema_json_document() {
+        assert!(matches!(
+            TableSchema::from_json(&json!({"version": 2, "columns": [{"name": "a"}]})),
+            Err(TableSchemaJsonError::UnsupportedVersion {
+                found: 2,
+                supported: 1
+            })
+        ));
+        assert!(matches!(
+            TableSchema::from_json(&json!({"columns": []})),
+            Err(TableSchemaJsonError::InvalidDocument { .. })
+        ));
+        assert!(matches!(
+            TableSchema::from_json(&json!({"version": 1})),
+            Err(TableSchemaJsonError::InvalidDocument { .. })
+        ));
+        let json = json!({
+            "version": 1,
+            "columns": [
+                {"name": "a", "data_type": "BigInt"},
+                {"name": "a", "data_type": "Int"},
+            ],
+        });
+        assert!(matches!(
+            TableSchema::from_json(&json),
+            Err(TableSchemaJsonError::DuplicateColumn { name }) if name == *"a"
+        ));
+    }
+}
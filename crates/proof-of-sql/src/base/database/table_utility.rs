@@ -165,6 +165,31 @@ pub fn borrowed_bigint<S: Scalar>(
     (name.parse().unwrap(), Column::BigInt(alloc_data))
 }
 
+/// Creates a `(Identifier, Column)` pair for a uint64 column.
+/// This is primarily intended for use in conjunction with [`table`].
+///
+/// # Example
+/// ```rust
+/// use bumpalo::Bump;
+/// use proof_of_sql::base::{database::table_utility::*, scalar::Curve25519Scalar};
+/// let alloc = Bump::new();
+/// let result = table::<Curve25519Scalar>([
+///     borrowed_uint64("a", [1_u64, 2, u64::MAX], &alloc),
+/// ]);
+/// ```
+///
+/// # Panics
+/// - Panics if `name.parse()` fails to convert the name into an `Identifier`.
+pub fn borrowed_uint64<S: Scalar>(
+    name: impl Deref<Target = str>,
+    data: impl IntoIterator<Item = impl Into<u64>>,
+    alloc: &Bump,
+) -> (Identifier, Column<'_, S>) {
+    let transformed_data: Vec<u64> = data.into_iter().map(Into::into).collect();
+    let alloc_data = alloc.alloc_slice_copy(&transformed_data);
+    (name.parse().unwrap(), Column::UInt64(alloc_data))
+}
+
 /// Creates a `(Identifier, Column)` pair for a boolean column.
 /// This is primarily intended for use in conjunction with [`table`].
 ///
@@ -275,6 +300,39 @@ pub fn borrowed_varchar<'a, S: Scalar>(
     )
 }
 
+/// Creates a `(Identifier, Column)` pair for a fixed-length binary column of `N` bytes.
+/// This is primarily intended for use in conjunction with [`table`].
+/// # Example
+/// ```
+/// use bumpalo::Bump;
+/// use proof_of_sql::base::{database::table_utility::*, scalar::Curve25519Scalar};
+/// let alloc = Bump::new();
+/// let result = table::<Curve25519Scalar>([
+///     borrowed_fixed_bytes("a", [[0x00, 0x01], [0xab, 0xcd]], &alloc),
+/// ]);
+/// ```
+///
+/// # Panics
+/// - Panics if `name.parse()` fails to convert the name into an `Identifier`.
+/// - Panics if `N` is more than 255.
+pub fn borrowed_fixed_bytes<'a, S: Scalar, const N: usize>(
+    name: impl Deref<Target = str>,
+    data: impl IntoIterator<Item = [u8; N]>,
+    alloc: &'a Bump,
+) -> (Identifier, Column<'a, S>) {
+    let values: Vec<&'a [u8]> = data
+        .into_iter()
+        .map(|bytes| alloc.alloc_slice_copy(&bytes) as &'a [u8])
+        .collect();
+    let alloc_values = alloc.alloc_slice_copy(&values);
+    let scalars: Vec<S> = values.iter().map(|bytes| (*bytes).into()).collect();
+    let alloc_scalars = alloc.alloc_slice_copy(&scalars);
+    (
+        name.parse().unwrap(),
+        Column::FixedBytes(N.try_into().unwrap(), (alloc_values, alloc_scalars)),
+    )
+}
+
 /// Creates a `(Identifier, Column)` pair for a decimal75 column.
 /// This is primarily intended for use in conjunction with [`table`].
 /// # Example
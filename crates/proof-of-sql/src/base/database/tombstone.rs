@@ -0,0 +1,43 @@
+use super::{ColumnType, SchemaAccessor, TableRef};
+use crate::base::commitment::{Commitment, CommittableColumn};
+use proof_of_sql_parser::Identifier;
+
+/// The name of the column that marks the deleted rows of a table.
+///
+/// A table may have a boolean column with this name, where a row is `true` exactly when it is deleted.
+/// The planner then ANDs `NOT __tombstone__` into the selection of every query on the table,
+/// so deleted rows never appear in results. The tombstone column is a column of the table like any other,
+/// so its commitment is bound into every proof over the table.
+/// It is not part of the expansion of `*`.
+pub const TOMBSTONE_COLUMN_NAME: &str = "__tombstone__";
+
+/// The identifier of the tombstone column. See [`TOMBSTONE_COLUMN_NAME`].
+#[allow(
+    clippy::missing_panics_doc,
+    reason = "the tombstone column name is a valid identifier"
+)]
+#[must_use]
+pub fn tombstone_column_id() -> Identifier {
+    TOMBSTONE_COLUMN_NAME
+        .parse()
+        .expect("tombstone column name should be a valid identifier")
+}
+
+/// Whether `table_ref` has a tombstone column, i.e. a boolean column named [`TOMBSTONE_COLUMN_NAME`].
+pub(crate) fn has_tombstone_column(
+    schema_accessor: &(impl SchemaAccessor + ?Sized),
+    table_ref: TableRef,
+) -> bool {
+    schema_accessor.lookup_column(table_ref, tombstone_column_id()) == Some(ColumnType::Boolean)
+}
+
+/// The commitment update that deletes a row of a table with a tombstone column.
+///
+/// `offset` is the offset of the table plus the index of the row. Deleting the row flips its tombstone
+/// from `false` to `true`, so adding the returned commitment to the commitment of the tombstone column
+/// gives the commitment after the deletion, without recommitting the rest of the column.
+pub fn tombstone_commitment_delta<C: Commitment>(offset: usize, setup: &C::PublicSetup<'_>) -> C {
+    C::compute_commitments(&[CommittableColumn::Boolean(&[true])], offset, setup)
+        .pop()
+        .unwrap_or_default()
+}
@@ -0,0 +1,90 @@
+use super::VarInt;
+use std::io::{self, Write};
+
+/// The maximum number of bytes a varint takes, which is the length of a 256-bit scalar varint
+const MAX_VARINT_LENGTH: usize = 37;
+
+/// A writer that encodes varints into an underlying [`Write`] and tracks what was written.
+///
+/// The number of bytes written is always tracked. If the writer was created with
+/// [`CountingVarIntWriter::with_checksum`], a BLAKE3 hash of the bytes is updated as they are written,
+/// so a serializer can append an accurate length and integrity check as a trailer without reading its output back.
+///
+/// Raw bytes can be interleaved with varints through the [`Write`] impl, and are tracked the same way.
+/// Only bytes the underlying writer accepted are counted and hashed.
+#[derive(Debug)]
+pub struct CountingVarIntWriter<W: Write> {
+    inner: W,
+    bytes_written: usize,
+    hasher: Option<blake3::Hasher>,
+}
+
+impl<W: Write> CountingVarIntWriter<W> {
+    /// Create a writer that counts the bytes written to `inner`
+    #[must_use]
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            bytes_written: 0,
+            hasher: None,
+        }
+    }
+
+    /// Also hash the bytes written from now on
+    #[must_use]
+    pub fn with_checksum(mut self) -> Self {
+        self.hasher = Some(blake3::Hasher::new());
+        self
+    }
+
+    /// Encode `value` as a varint, returning the number of bytes it took.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the underlying writer fails.
+    pub fn write_varint<V: VarInt>(&mut self, value: V) -> io::Result<usize> {
+        let mut buf = [0; MAX_VARINT_LENGTH];
+        let len = value.encode_var(&mut buf);
+        self.write_all(&buf[..len])?;
+        Ok(len)
+    }
+
+    /// The number of bytes written so far
+    #[must_use]
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    /// The BLAKE3 hash of the bytes written so far, if the writer tracks a checksum
+    #[must_use]
+    pub fn checksum(&self) -> Option<[u8; 32]> {
+        self.hasher.as_ref().map(|hasher| hasher.finalize().into())
+    }
+
+    /// The underlying writer
+    #[must_use]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Unwrap the underlying writer
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for CountingVarIntWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.bytes_written += written;
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(&buf[..written]);
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,86 @@
+use super::{CountingVarIntWriter, VarInt};
+use crate::base::scalar::Curve25519Scalar;
+use std::io::{self, Write};
+
+/// A writer that accepts at most one byte per call
+struct OneByteWriter(Vec<u8>);
+
+impl Write for OneByteWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.extend(buf.first());
+        Ok(buf.len().min(1))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn write_values(writer: &mut CountingVarIntWriter<impl Write>) {
+    assert_eq!(writer.write_varint(0_u64).unwrap(), 1);
+    assert_eq!(writer.write_varint(300_u64).unwrap(), 2);
+    assert_eq!(writer.write_varint(-2_i32).unwrap(), 1);
+    assert_eq!(writer.write_varint(u128::MAX).unwrap(), 19);
+    writer.write_all(b"payload").unwrap();
+    let scalar = -Curve25519Scalar::from(1);
+    assert_eq!(
+        writer.write_varint(scalar).unwrap(),
+        scalar.required_space()
+    );
+}
+
+#[test]
+fn the_byte_count_matches_the_output_length() {
+    let mut writer = CountingVarIntWriter::new(Vec::new());
+    assert_eq!(writer.bytes_written(), 0);
+    write_values(&mut writer);
+    assert_eq!(writer.bytes_written(), writer.get_ref().len());
+    assert_eq!(writer.checksum(), None);
+
+    let bytes = writer.into_inner();
+    let mut expected = 0_u64.encode_var_vec();
+    expected.extend(300_u64.encode_var_vec());
+    expected.extend((-2_i32).encode_var_vec());
+    expected.extend(u128::MAX.encode_var_vec());
+    expected.extend_from_slice(b"payload");
+    expected.extend((-Curve25519Scalar::from(1)).encode_var_vec());
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn the_checksum_matches_a_hash_of_the_output() {
+    let mut writer = CountingVarIntWriter::new(Vec::new()).with_checksum();
+    assert_eq!(writer.checksum(), Some(*blake3::hash(&[]).as_bytes()));
+    write_values(&mut writer);
+    let checksum = writer.checksum().unwrap();
+    let bytes = writer.into_inner();
+    assert_eq!(checksum, *blake3::hash(&bytes).as_bytes());
+}
+
+#[test]
+fn only_bytes_accepted_by_the_underlying_writer_are_tracked() {
+    let mut writer = CountingVarIntWriter::new(OneByteWriter(Vec::new())).with_checksum();
+    assert_eq!(writer.write(b"abc").unwrap(), 1);
+    assert_eq!(writer.bytes_written(), 1);
+    assert_eq!(writer.checksum(), Some(*blake3::hash(b"a").as_bytes()));
+
+    write_values(&mut writer);
+    writer.flush().unwrap();
+    let (bytes_written, checksum) = (writer.bytes_written(), writer.checksum().unwrap());
+    let bytes = writer.into_inner().0;
+    assert_eq!(bytes_written, bytes.len());
+    assert_eq!(checksum, *blake3::hash(&bytes).as_bytes());
+}
+
+#[test]
+fn a_failing_writer_is_reported_and_only_the_accepted_bytes_are_counted() {
+    let mut buf = [0_u8; 1];
+    let mut writer = CountingVarIntWriter::new(&mut buf[..]);
+    assert!(writer.write_varint(300_u64).is_err());
+    assert_eq!(writer.bytes_written(), 1);
+    assert_eq!(
+        writer.write_varint(1_u8).unwrap_err().kind(),
+        io::ErrorKind::WriteZero
+    );
+    assert_eq!(writer.bytes_written(), 1);
+}
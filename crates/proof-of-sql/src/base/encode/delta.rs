@@ -0,0 +1,60 @@
+use super::{VarInt, ZeroCopyReader};
+use alloc::vec::Vec;
+
+/// The longest varint encoding of a `u64` or `i64`
+const MAX_VARINT_LEN: usize = 10;
+
+fn push_varint(value: impl VarInt, dst: &mut Vec<u8>) {
+    let mut buf = [0; MAX_VARINT_LEN];
+    let len = value.encode_var(&mut buf);
+    dst.extend_from_slice(&buf[..len]);
+}
+
+/// Append the delta encoding of `values` to `dst`.
+///
+/// The encoding is the number of values and the first value as varints, followed by the difference
+/// between each value and the one before it as a zigzag varint. Sorted columns, such as timestamps
+/// or auto-increment ids, have small differences and so encode to few bytes per value.
+///
+/// The values do not have to be sorted: differences wrap around, so any sequence is decoded exactly
+/// by [`delta_decode_sorted`], it is just less compact.
+/// This is a storage encoding and has nothing to do with commitments.
+#[allow(clippy::cast_possible_wrap)]
+pub fn delta_encode_sorted(values: &[u64], dst: &mut Vec<u8>) {
+    push_varint(values.len(), dst);
+    let mut previous = 0;
+    for (i, &value) in values.iter().enumerate() {
+        if i == 0 {
+            push_varint(value, dst);
+        } else {
+            push_varint(value.wrapping_sub(previous) as i64, dst);
+        }
+        previous = value;
+    }
+}
+
+/// Decode values that were encoded with [`delta_encode_sorted`] from the start of `src`.
+/// Returns the values and the number of bytes read.
+///
+/// If `strictly_sorted` is `true`, a sequence that is not strictly increasing is rejected.
+/// Returns `None` if the encoding is truncated, holds an invalid varint, or is rejected.
+#[allow(clippy::cast_sign_loss)]
+#[must_use]
+pub fn delta_decode_sorted(src: &[u8], strictly_sorted: bool) -> Option<(Vec<u64>, usize)> {
+    let mut reader = ZeroCopyReader::new(src);
+    let len: usize = reader.read_varint()?;
+    // Every value takes at least one byte, so a longer length cannot be valid. This bounds the allocation.
+    let mut values = Vec::with_capacity(len.min(reader.remaining().len()));
+    if len > 0 {
+        values.push(reader.read_varint::<u64>()?);
+    }
+    for _ in 1..len {
+        let previous = *values.last()?;
+        let value = previous.wrapping_add(reader.read_varint::<i64>()? as u64);
+        if strictly_sorted && value <= previous {
+            return None;
+        }
+        values.push(value);
+    }
+    Some((values, reader.position()))
+}
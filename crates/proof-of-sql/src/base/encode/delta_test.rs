@@ -0,0 +1,89 @@
+use super::{delta_decode_sorted, delta_encode_sorted, VarInt};
+use alloc::{vec, vec::Vec};
+use rand::Rng;
+
+fn encode(values: &[u64]) -> Vec<u8> {
+    let mut dst = Vec::new();
+    delta_encode_sorted(values, &mut dst);
+    dst
+}
+
+#[test]
+fn we_can_round_trip_sorted_values() {
+    for values in [
+        vec![],
+        vec![0],
+        vec![u64::MAX],
+        vec![1_700_000_000, 1_700_000_001, 1_700_000_005, 1_700_000_100],
+        vec![0, 1, u64::MAX - 1, u64::MAX],
+        (1..=1000).collect(),
+    ] {
+        let bytes = encode(&values);
+        assert_eq!(
+            delta_decode_sorted(&bytes, true),
+            Some((values.clone(), bytes.len()))
+        );
+        assert_eq!(
+            delta_decode_sorted(&bytes, false),
+            Some((values, bytes.len()))
+        );
+    }
+}
+
+#[test]
+fn sorted_values_encode_to_the_first_value_and_small_deltas() {
+    let timestamps = [1_700_000_000_u64, 1_700_000_001, 1_700_000_003];
+    let mut expected = 3_usize.encode_var_vec();
+    expected.extend(1_700_000_000_u64.encode_var_vec());
+    expected.extend([2, 4]);
+    assert_eq!(encode(&timestamps), expected);
+
+    let ids: Vec<u64> = (1..=1000).collect();
+    // The length, the first value and one byte per delta
+    assert_eq!(encode(&ids).len(), 2 + 1 + 999);
+}
+
+#[test]
+fn we_can_round_trip_unsorted_values_unless_they_must_be_strictly_sorted() {
+    let mut rng = rand::thread_rng();
+    let random: Vec<u64> = (0..100).map(|_| rng.gen()).collect();
+    for values in [
+        vec![5, 4, 3, 2, 1],
+        vec![u64::MAX, 0],
+        vec![1, 1, 2],
+        random,
+    ] {
+        let bytes = encode(&values);
+        assert_eq!(
+            delta_decode_sorted(&bytes, false),
+            Some((values, bytes.len()))
+        );
+        assert_eq!(delta_decode_sorted(&bytes, true), None);
+    }
+}
+
+#[test]
+fn we_can_decode_values_followed_by_other_data() {
+    let mut bytes = encode(&[10, 20, 30]);
+    let len = bytes.len();
+    bytes.extend([0xFF, 0x01]);
+    assert_eq!(
+        delta_decode_sorted(&bytes, true),
+        Some((vec![10, 20, 30], len))
+    );
+}
+
+#[test]
+fn we_cannot_decode_a_truncated_or_invalid_encoding() {
+    let bytes = encode(&[10, 20, 300]);
+    for len in 0..bytes.len() {
+        assert_eq!(delta_decode_sorted(&bytes[..len], false), None);
+    }
+    // A length that is longer than the values that follow
+    assert_eq!(
+        delta_decode_sorted(&[0xFF, 0xFF, 0xFF, 0x0F, 1], false),
+        None
+    );
+    // An unterminated varint
+    assert_eq!(delta_decode_sorted(&[2, 1, 0x80], false), None);
+}
@@ -13,6 +13,30 @@ mod scalar_varint;
 mod scalar_varint_test;
 
 mod varint_trait;
-pub use varint_trait::VarInt;
+pub use varint_trait::{
+    decode_var_bounded, decode_var_small, decode_var_unchecked, decode_var_widening,
+    encode_var_small, VarInt, MAX_SMALL_VARINT,
+};
 #[cfg(test)]
 mod varint_trait_test;
+
+mod zero_copy_reader;
+pub use zero_copy_reader::ZeroCopyReader;
+#[cfg(test)]
+mod zero_copy_reader_test;
+
+#[cfg(feature = "std")]
+mod counting_varint_writer;
+#[cfg(feature = "std")]
+pub use counting_varint_writer::CountingVarIntWriter;
+#[cfg(all(test, feature = "std"))]
+mod counting_varint_writer_test;
+
+pub mod varint_be;
+#[cfg(test)]
+mod varint_be_test;
+
+mod delta;
+pub use delta::{delta_decode_sorted, delta_encode_sorted};
+#[cfg(test)]
+mod delta_test;
@@ -0,0 +1,64 @@
+//! Big-endian variable length integers.
+//!
+//! The encoding splits an integer into groups of 7 bits and writes the most-significant group first,
+//! one group per byte. Every byte except the last has its most significant bit set. This is the
+//! variable length quantity used by e.g. MIDI files and ASN.1 object identifiers.
+//!
+//! This is *not* the encoding used by the rest of this crate, which is the little-endian LEB128 encoding
+//! of [`VarInt`](super::VarInt). The two encodings agree on values below 128 and disagree on everything
+//! else, so they must never be mixed up.
+
+/// The continuation bit, which is set on every byte of an encoding except the last one.
+const CONTINUATION: u8 = 0b1000_0000;
+/// The bits of a byte that hold a group of the value.
+const GROUP: u8 = 0b0111_1111;
+/// The length of the encoding of `u64::MAX`, which is the longest encoding of a `u64`.
+const MAX_ENCODED_LEN: usize = 10;
+
+/// Returns the number of bytes that [`encode_var_be`] writes for `value`.
+#[must_use]
+pub fn required_space_be(value: u64) -> usize {
+    let bits = 64 - value.leading_zeros() as usize;
+    core::cmp::max(1, bits.div_ceil(7))
+}
+
+/// Encode `value` into the start of `dst`, most-significant group first.
+/// The number of bytes written is returned.
+///
+/// # Panics
+///
+/// Panics if `dst` is shorter than [`required_space_be`] of `value`.
+#[allow(clippy::cast_possible_truncation)]
+pub fn encode_var_be(value: u64, dst: &mut [u8]) -> usize {
+    let len = required_space_be(value);
+    assert!(dst.len() >= len, "destination is too short for the varint");
+    for (i, byte) in dst[..len].iter_mut().enumerate() {
+        let shift = 7 * (len - 1 - i);
+        *byte = (value >> shift) as u8 & GROUP;
+        if i + 1 < len {
+            *byte |= CONTINUATION;
+        }
+    }
+    len
+}
+
+/// Decode a value from the start of `src`, most-significant group first.
+/// Returns the value and the number of bytes read.
+///
+/// Returns `None` if the encoding is unterminated, longer than the longest encoding of a `u64`,
+/// or holds a value that overflows `u64`.
+#[must_use]
+pub fn decode_var_be(src: &[u8]) -> Option<(u64, usize)> {
+    let mut result: u64 = 0;
+    for (i, byte) in src.iter().take(MAX_ENCODED_LEN).enumerate() {
+        // Shifting in another group must not drop any set bits.
+        if result.leading_zeros() < 7 {
+            return None;
+        }
+        result = (result << 7) | u64::from(byte & GROUP);
+        if byte & CONTINUATION == 0 {
+            return Some((result, i + 1));
+        }
+    }
+    None
+}
@@ -0,0 +1,120 @@
+use super::{
+    varint_be::{decode_var_be, encode_var_be, required_space_be},
+    VarInt,
+};
+use alloc::{vec, vec::Vec};
+use rand::Rng;
+
+fn encode_var_be_vec(value: u64) -> Vec<u8> {
+    let mut dst = vec![0; required_space_be(value)];
+    assert_eq!(encode_var_be(value, &mut dst), dst.len());
+    dst
+}
+
+#[test]
+fn we_can_compute_the_required_space_of_big_endian_varints() {
+    assert_eq!(required_space_be(0), 1);
+    assert_eq!(required_space_be(127), 1);
+    assert_eq!(required_space_be(128), 2);
+    assert_eq!(required_space_be(16383), 2);
+    assert_eq!(required_space_be(16384), 3);
+    assert_eq!(required_space_be(u64::MAX), 10);
+}
+
+#[test]
+fn we_can_encode_big_endian_varints_most_significant_group_first() {
+    assert_eq!(encode_var_be_vec(0), vec![0x00]);
+    assert_eq!(encode_var_be_vec(0x7F), vec![0x7F]);
+    assert_eq!(encode_var_be_vec(300), vec![0x82, 0x2C]);
+    assert_eq!(
+        encode_var_be_vec(u64::MAX),
+        vec![0x81, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F]
+    );
+    // The little-endian LEB128 encoding of the same value has the groups in the opposite order
+    assert_eq!(300_u64.encode_var_vec(), vec![0xAC, 0x02]);
+}
+
+#[test]
+fn we_can_decode_known_big_endian_varints_from_an_external_format() {
+    // Variable length quantities from the MIDI file specification
+    let examples: [(&[u8], u64); 12] = [
+        (&[0x00], 0x0000_0000),
+        (&[0x40], 0x0000_0040),
+        (&[0x7F], 0x0000_007F),
+        (&[0x81, 0x00], 0x0000_0080),
+        (&[0xC0, 0x00], 0x0000_2000),
+        (&[0xFF, 0x7F], 0x0000_3FFF),
+        (&[0x81, 0x80, 0x00], 0x0000_4000),
+        (&[0xC0, 0x80, 0x00], 0x0010_0000),
+        (&[0xFF, 0xFF, 0x7F], 0x001F_FFFF),
+        (&[0x81, 0x80, 0x80, 0x00], 0x0020_0000),
+        (&[0xC0, 0x80, 0x80, 0x00], 0x0800_0000),
+        (&[0xFF, 0xFF, 0xFF, 0x7F], 0x0FFF_FFFF),
+    ];
+    for (encoded, value) in examples {
+        assert_eq!(decode_var_be(encoded), Some((value, encoded.len())));
+        assert_eq!(encode_var_be_vec(value), encoded);
+    }
+}
+
+#[test]
+fn we_can_round_trip_big_endian_varints() {
+    let mut rng = rand::thread_rng();
+    let values = (0..64)
+        .flat_map(|shift| [1_u64 << shift, (1_u64 << shift) - 1])
+        .chain([u64::MAX])
+        .chain((0..100).map(|_| rng.gen::<u64>()));
+    for value in values {
+        let encoded = encode_var_be_vec(value);
+        assert_eq!(decode_var_be(&encoded), Some((value, encoded.len())));
+    }
+}
+
+#[test]
+fn we_can_decode_consecutive_big_endian_varints() {
+    let mut buffer = [0; 32];
+    let mut written = 0;
+    for value in [300, 0, u64::MAX, 0x3FFF] {
+        written += encode_var_be(value, &mut buffer[written..]);
+    }
+    let mut read = 0;
+    let mut decoded = Vec::new();
+    while read < written {
+        let (value, len) = decode_var_be(&buffer[read..written]).unwrap();
+        decoded.push(value);
+        read += len;
+    }
+    assert_eq!(decoded, vec![300, 0, u64::MAX, 0x3FFF]);
+}
+
+#[test]
+fn we_cannot_decode_truncated_big_endian_varints() {
+    assert_eq!(decode_var_be(&[]), None);
+    assert_eq!(decode_var_be(&[0x81]), None);
+    assert_eq!(decode_var_be(&[0xFF, 0xFF, 0xFF]), None);
+}
+
+#[test]
+fn we_cannot_decode_big_endian_varints_that_overflow_u64() {
+    // u64::MAX + 1
+    assert_eq!(
+        decode_var_be(&[0x82, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00]),
+        None
+    );
+    // Eleven groups
+    assert_eq!(
+        decode_var_be(&[0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00]),
+        None
+    );
+    // Leading zero groups do not overflow, but the encoding is still too long
+    assert_eq!(
+        decode_var_be(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01]),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "destination is too short for the varint")]
+fn we_cannot_encode_a_big_endian_varint_into_a_short_buffer() {
+    encode_var_be(300, &mut [0; 1]);
+}
@@ -205,6 +205,109 @@ impl VarInt for u64 {
     }
 }
 
+/// Decode a `u64` varint from a slice that is known to hold a valid encoding.
+///
+/// This is [`u64::decode_var`] without the checks for overflowing or unterminated input,
+/// and so is only for hot paths whose input was produced by [`VarInt::encode_var`] or already validated.
+/// It must never be used on external input: an invalid encoding silently decodes to a wrong value.
+/// With `debug_assertions` enabled, an invalid encoding panics instead.
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub fn decode_var_unchecked(src: &[u8]) -> (u64, usize) {
+    let mut result: u64 = 0;
+    let mut shift = 0;
+    for b in src {
+        debug_assert!(
+            shift < 63 || (shift == 63 && *b < 2),
+            "varint overflows u64"
+        );
+        result |= u64::from(b & DROP_MSB) << shift;
+        shift += 7;
+        if b & MSB == 0 {
+            return (result, shift / 7);
+        }
+    }
+    debug_assert!(false, "varint is unterminated");
+    (result, shift / 7)
+}
+
+/// The largest value whose varint encoding fits in two bytes, == 2^14 - 1
+pub const MAX_SMALL_VARINT: u64 = 0x3FFF;
+
+/// Encode a value that is known to be at most [`MAX_SMALL_VARINT`], such as a column index or an enum tag.
+/// The number of bytes written is returned.
+///
+/// The encoding is the same as [`VarInt::encode_var`], but without the general loop.
+/// With `debug_assertions` enabled, a larger value panics. Otherwise it is silently truncated.
+///
+/// # Panics
+///
+/// Panics if `dst` is shorter than the encoding.
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+pub fn encode_var_small(value: u64, dst: &mut [u8]) -> usize {
+    debug_assert!(
+        value <= MAX_SMALL_VARINT,
+        "value does not fit in a small varint"
+    );
+    if value < u64::from(MSB) {
+        dst[0] = value as u8;
+        1
+    } else {
+        dst[0] = MSB | (value as u8);
+        dst[1] = (value >> 7) as u8 & DROP_MSB;
+        2
+    }
+}
+
+/// Decode a value that was encoded with [`encode_var_small`], or with [`VarInt::encode_var`] from a value
+/// that is at most [`MAX_SMALL_VARINT`]. Returns the value and the number of bytes read.
+///
+/// Returns `None` if `src` is empty, or if the encoding is unterminated or longer than two bytes.
+#[inline]
+#[must_use]
+pub fn decode_var_small(src: &[u8]) -> Option<(u64, usize)> {
+    let first = *src.first()?;
+    if first & MSB == 0 {
+        return Some((u64::from(first), 1));
+    }
+    let second = *src.get(1)?;
+    if second & MSB == 0 {
+        Some((u64::from(first & DROP_MSB) | (u64::from(second) << 7), 2))
+    } else {
+        None
+    }
+}
+
+/// Decode a `u64` varint whose encoding is at most `max_bytes` bytes long. Returns the value and the number of bytes read.
+///
+/// This is [`u64::decode_var`] with a stricter limit on the encoded length, e.g. 5 bytes for a wire format
+/// that only encodes 32-bit values, so that over-long encodings can be rejected.
+/// Returns `None` if the encoding is unterminated within `max_bytes` bytes or overflows `u64`.
+#[inline]
+#[must_use]
+pub fn decode_var_bounded(src: &[u8], max_bytes: usize) -> Option<(u64, usize)> {
+    u64::decode_var(&src[..src.len().min(max_bytes)])
+}
+
+/// Decode a varint of type `Small` that may have been written as the wider type `Large`, e.g. by a newer encoder.
+/// Returns the value as `Large`, the number of bytes read, and whether the value overflows `Small`.
+///
+/// [`VarInt::decode_var`] for `Small` returns `None` for such values, which loses them. This lets callers
+/// decide how to handle the skew instead. `Small` and `Large` must both be signed or both be unsigned,
+/// since signed values are zigzag encoded. Returns `None` if the value is invalid or overflows `Large`.
+#[inline]
+#[must_use]
+pub fn decode_var_widening<Small, Large>(src: &[u8]) -> Option<(Large, usize, bool)>
+where
+    Small: VarInt + TryFrom<Large>,
+    Large: VarInt,
+{
+    let (value, read) = Large::decode_var(src)?;
+    Some((value, read, Small::try_from(value).is_err()))
+}
+
 impl VarInt for i64 {
     fn required_space(self) -> usize {
         zigzag_encode(self).required_space()
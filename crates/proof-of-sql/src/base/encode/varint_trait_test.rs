@@ -1,4 +1,7 @@
-use super::VarInt;
+use super::{
+    decode_var_bounded, decode_var_small, decode_var_unchecked, decode_var_widening,
+    encode_var_small, VarInt, MAX_SMALL_VARINT,
+};
 use crate::base::scalar::{test_scalar::TestScalar, Scalar};
 use alloc::{vec, vec::Vec};
 use core::{
@@ -114,6 +117,165 @@ fn test_unterminated_varint_2() {
     assert!(u64::decode_var(&buf).is_none());
 }
 
+#[test]
+fn we_can_decode_valid_u64_varints_unchecked() {
+    let mut rng = rand::thread_rng();
+    let values = [0, 1, 127, 128, 0x12345, u64::MAX - 1, u64::MAX]
+        .into_iter()
+        .chain((0..100).map(|_| rng.gen::<u64>() >> rng.gen_range(0..64)));
+    for value in values {
+        let mut encoded = value.encode_var_vec();
+        let expected = u64::decode_var(&encoded).unwrap();
+        assert_eq!(decode_var_unchecked(&encoded), expected);
+        // Trailing bytes are not read
+        encoded.extend([0xff, 0x01]);
+        assert_eq!(decode_var_unchecked(&encoded), expected);
+    }
+}
+
+#[test]
+fn we_can_decode_a_varint_that_fits_the_smaller_type_without_the_overflow_flag() {
+    for value in [0_u32, 1, 0x7f, 0x80, u32::MAX] {
+        let encoded = value.encode_var_vec();
+        assert_eq!(
+            decode_var_widening::<u32, u64>(&encoded),
+            Some((u64::from(value), encoded.len(), false))
+        );
+    }
+    for value in [0_i32, -1, 63, -64, i32::MIN, i32::MAX] {
+        let encoded = value.encode_var_vec();
+        assert_eq!(
+            decode_var_widening::<i32, i64>(&encoded),
+            Some((i64::from(value), encoded.len(), false))
+        );
+    }
+}
+
+#[test]
+fn we_can_decode_a_varint_that_overflows_the_smaller_type_as_the_larger_type() {
+    for value in [u64::from(u32::MAX) + 1, 1 << 40, u64::MAX] {
+        let mut encoded = value.encode_var_vec();
+        let length = encoded.len();
+        assert_eq!(u32::decode_var(&encoded), None);
+        // Trailing bytes are not read
+        encoded.extend([0xff, 0x01]);
+        assert_eq!(
+            decode_var_widening::<u32, u64>(&encoded),
+            Some((value, length, true))
+        );
+    }
+    for value in [i64::from(i32::MAX) + 1, i64::from(i32::MIN) - 1, i64::MIN] {
+        let encoded = value.encode_var_vec();
+        assert_eq!(i32::decode_var(&encoded), None);
+        assert_eq!(
+            decode_var_widening::<i32, i64>(&encoded),
+            Some((value, encoded.len(), true))
+        );
+    }
+    assert_eq!(
+        decode_var_widening::<u8, u16>(&300_u16.encode_var_vec()),
+        Some((300, 2, true))
+    );
+}
+
+#[test]
+fn we_cannot_decode_a_varint_that_overflows_the_larger_type() {
+    assert_eq!(decode_var_widening::<u32, u64>(&[]), None);
+    assert_eq!(decode_var_widening::<u32, u64>(&[0x80]), None);
+    assert_eq!(
+        decode_var_widening::<u16, u32>(&u64::MAX.encode_var_vec()),
+        None
+    );
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "varint overflows u64")]
+fn we_cannot_decode_an_overflowing_u64_varint_unchecked_in_debug_mode() {
+    let buf = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02];
+    let _ = decode_var_unchecked(&buf);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "varint overflows u64")]
+fn we_cannot_decode_an_overlong_u64_varint_unchecked_in_debug_mode() {
+    let _ = decode_var_unchecked(&[0xff_u8; 12]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "varint is unterminated")]
+fn we_cannot_decode_an_unterminated_u64_varint_unchecked_in_debug_mode() {
+    let _ = decode_var_unchecked(&[0xff, 0xff]);
+}
+
+#[test]
+fn we_can_encode_and_decode_every_small_varint_like_the_general_path() {
+    let mut buf = [0; 2];
+    for value in 0..=MAX_SMALL_VARINT {
+        let length = encode_var_small(value, &mut buf);
+        let mut encoded = value.encode_var_vec();
+        assert_eq!(&buf[..length], &encoded[..]);
+        assert_eq!(decode_var_small(&encoded), u64::decode_var(&encoded));
+        // Trailing bytes are not read
+        encoded.extend([0xff, 0x01]);
+        assert_eq!(decode_var_small(&encoded), Some((value, length)));
+    }
+}
+
+#[test]
+fn we_cannot_decode_a_small_varint_that_is_unterminated_or_too_long() {
+    assert_eq!(decode_var_small(&[]), None);
+    assert_eq!(decode_var_small(&[0x80]), None);
+    assert_eq!(
+        decode_var_small(&(MAX_SMALL_VARINT + 1).encode_var_vec()),
+        None
+    );
+    assert_eq!(decode_var_small(&u64::MAX.encode_var_vec()), None);
+}
+
+#[test]
+fn we_can_decode_a_varint_within_a_maximum_length_like_the_general_path() {
+    for value in [0, 1, 0x7f, 0x80, u64::from(u32::MAX), u64::MAX] {
+        let mut encoded = value.encode_var_vec();
+        assert_eq!(decode_var_bounded(&encoded, 10), u64::decode_var(&encoded));
+        assert_eq!(
+            decode_var_bounded(&encoded, encoded.len()),
+            Some((value, encoded.len()))
+        );
+        // Trailing bytes are not read
+        encoded.extend([0xff, 0x01]);
+        assert_eq!(
+            decode_var_bounded(&encoded, 10),
+            Some((value, encoded.len() - 2))
+        );
+    }
+}
+
+#[test]
+fn we_cannot_decode_a_varint_that_is_longer_than_the_maximum_length() {
+    // 2^35 needs 6 bytes
+    let encoded = (1u64 << 35).encode_var_vec();
+    assert_eq!(encoded.len(), 6);
+    assert_eq!(u64::decode_var(&encoded), Some((1 << 35, 6)));
+    assert_eq!(decode_var_bounded(&encoded, 6), Some((1 << 35, 6)));
+    assert_eq!(decode_var_bounded(&encoded, 5), None);
+    // An over-long encoding of a small value is rejected as well
+    let overlong = [0x81, 0x80, 0x80, 0x80, 0x80, 0x00];
+    assert_eq!(u64::decode_var(&overlong), Some((1, 6)));
+    assert_eq!(decode_var_bounded(&overlong, 5), None);
+    assert_eq!(decode_var_bounded(&[], 5), None);
+    assert_eq!(decode_var_bounded(&[0x01], 0), None);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "value does not fit in a small varint")]
+fn we_cannot_encode_a_large_value_as_a_small_varint_in_debug_mode() {
+    let _ = encode_var_small(MAX_SMALL_VARINT + 1, &mut [0; 3]);
+}
+
 #[test]
 fn test_decode_extra_bytes_u64() {
     let mut encoded = 0x12345u64.encode_var_vec();
@@ -0,0 +1,66 @@
+use super::VarInt;
+
+/// A cursor over an encoded buffer that decodes values in place.
+///
+/// Length-delimited payloads are returned as sub-slices of the buffer rather than copied,
+/// so the buffer can e.g. be a memory-mapped file. A length-delimited field is a `usize` varint
+/// holding the length of the payload, followed by the payload itself. This is the framing of
+/// byte and string columns in a [`ProvableQueryResult`](crate::sql::proof::ProvableQueryResult).
+///
+/// Every read returns `None` without advancing if the buffer does not hold a complete value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroCopyReader<'a> {
+    buf: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ZeroCopyReader<'a> {
+    /// Create a reader positioned at the start of `buf`
+    #[must_use]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, position: 0 }
+    }
+
+    /// The number of bytes read so far
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The bytes that have not been read yet
+    #[must_use]
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.buf[self.position..]
+    }
+
+    /// Whether every byte has been read
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.position == self.buf.len()
+    }
+
+    /// Read a varint
+    pub fn read_varint<T: VarInt>(&mut self) -> Option<T> {
+        let (value, len) = T::decode_var(self.remaining())?;
+        self.position += len;
+        Some(value)
+    }
+
+    /// Read the next `len` bytes, borrowed from the buffer
+    pub fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.remaining().get(..len)?;
+        self.position += len;
+        Some(bytes)
+    }
+
+    /// Read a length-delimited payload, borrowed from the buffer
+    pub fn read_length_delimited(&mut self) -> Option<&'a [u8]> {
+        let (len, len_size) = usize::decode_var(self.remaining())?;
+        let payload = self
+            .remaining()
+            .get(len_size..)
+            .and_then(|rest| rest.get(..len))?;
+        self.position += len_size + len;
+        Some(payload)
+    }
+}
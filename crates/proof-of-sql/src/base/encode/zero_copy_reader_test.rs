@@ -0,0 +1,57 @@
+use super::{VarInt, ZeroCopyReader};
+use alloc::vec::Vec;
+
+fn length_delimited(payloads: &[&[u8]]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for payload in payloads {
+        buf.extend(payload.len().encode_var_vec());
+        buf.extend_from_slice(payload);
+    }
+    buf
+}
+
+#[test]
+fn a_length_delimited_payload_points_into_the_original_buffer() {
+    let buf = length_delimited(&[b"abc", b"", b"defgh"]);
+    let mut reader = ZeroCopyReader::new(&buf);
+    let first = reader.read_length_delimited().unwrap();
+    let second = reader.read_length_delimited().unwrap();
+    let third = reader.read_length_delimited().unwrap();
+    assert!(reader.is_empty());
+    assert_eq!(
+        (first, second, third),
+        (&b"abc"[..], &b""[..], &b"defgh"[..])
+    );
+    assert_eq!(first.as_ptr_range(), buf[1..4].as_ptr_range());
+    assert_eq!(third.as_ptr_range(), buf[6..11].as_ptr_range());
+}
+
+#[test]
+fn we_can_read_varints_and_raw_bytes_in_sequence() {
+    let mut buf = 300_u64.encode_var_vec();
+    buf.extend((-2_i64).encode_var_vec());
+    buf.extend_from_slice(b"xyz");
+    let mut reader = ZeroCopyReader::new(&buf);
+    assert_eq!(reader.read_varint::<u64>(), Some(300));
+    assert_eq!(reader.read_varint::<i64>(), Some(-2));
+    assert_eq!(reader.position(), 3);
+    assert_eq!(reader.remaining(), b"xyz");
+    let bytes = reader.read_bytes(2).unwrap();
+    assert_eq!(bytes.as_ptr_range(), buf[3..5].as_ptr_range());
+    assert_eq!(reader.read_bytes(2), None);
+    assert_eq!(reader.read_bytes(1), Some(&b"z"[..]));
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn we_cannot_read_a_truncated_length_delimited_payload() {
+    let buf = length_delimited(&[b"abc"]);
+    let mut reader = ZeroCopyReader::new(&buf[..3]);
+    assert_eq!(reader.read_length_delimited(), None);
+    assert_eq!(reader.position(), 0);
+    // A length that does not fit in the buffer, or in a usize
+    let mut reader =
+        ZeroCopyReader::new(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+    assert_eq!(reader.read_length_delimited(), None);
+    assert_eq!(ZeroCopyReader::new(&[]).read_varint::<u64>(), None);
+}
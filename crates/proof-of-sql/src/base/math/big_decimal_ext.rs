@@ -1,4 +1,4 @@
-use super::decimal::{IntermediateDecimalError, IntermediateDecimalError::LossyCast};
+use super::decimal::{IntermediateDecimalError, IntermediateDecimalError::OutOfRange};
 use bigdecimal::BigDecimal;
 use num_bigint::BigInt;
 
@@ -28,7 +28,8 @@ impl BigDecimalExt for BigDecimal {
     /// Returns an error if the conversion cannot be performed due to precision or scale constraints.
     ///
     /// # Errors
-    /// Returns an `IntermediateDecimalError::LossyCast` error if the number of digits in the scaled decimal exceeds the specified precision.
+    /// Returns an `IntermediateDecimalError::OutOfRange` error if the number of digits in the scaled decimal exceeds the specified precision,
+    /// e.g. when a large exponent such as the one of `1e40` is normalized into the digits.
     fn try_into_bigint_with_precision_and_scale(
         &self,
         precision: u8,
@@ -39,7 +40,7 @@ impl BigDecimalExt for BigDecimal {
         }
         let scaled_decimal = self.normalized().with_scale(scale.into());
         if scaled_decimal.digits() > precision.into() {
-            return Err(LossyCast);
+            return Err(OutOfRange);
         }
         let (d, _) = scaled_decimal.into_bigint_and_exponent();
         Ok(d)
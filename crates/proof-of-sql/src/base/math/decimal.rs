@@ -137,6 +137,46 @@ impl<'de> Deserialize<'de> for Precision {
     }
 }
 
+/// How a decimal is rounded when it is rescaled to fewer fractional digits.
+///
+/// Comparisons and arithmetic between decimals of different scales always scale up to the larger scale,
+/// which is exact, so the rounding mode only matters when a value is explicitly rescaled to a smaller scale.
+///
+/// The default is [`RoundingMode::HalfUp`], which is how Postgres rounds `numeric` values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RoundingMode {
+    /// Drop the extra digits, rounding toward zero, e.g. `1.5` and `-1.5` become `1` and `-1`
+    Truncate,
+    /// Round to the nearest value, with ties away from zero, e.g. `1.5` and `-1.5` become `2` and `-2`
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, with ties to the even neighbor, e.g. `1.5` and `2.5` both become `2`
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Divide `value` by the positive `divisor`, rounding the quotient with this mode.
+    pub(crate) fn divide(self, value: i128, divisor: i128) -> i128 {
+        debug_assert!(divisor > 0);
+        let (quotient, remainder) = (value / divisor, value % divisor);
+        // `|r| >= d - |r|` rather than `2 * |r| >= d`, which could overflow
+        let (half, more_than_half) = (
+            remainder.abs() == divisor - remainder.abs(),
+            remainder.abs() > divisor - remainder.abs(),
+        );
+        let round_away_from_zero = match self {
+            RoundingMode::Truncate => false,
+            RoundingMode::HalfUp => half || more_than_half,
+            RoundingMode::HalfEven => more_than_half || (half && quotient % 2 != 0),
+        };
+        if round_away_from_zero {
+            quotient + value.signum()
+        } else {
+            quotient
+        }
+    }
+}
+
 /// Fallibly attempts to convert an `IntermediateDecimal` into the
 /// native proof-of-sql [Scalar] backing store. This function adjusts
 /// the decimal to the specified `target_precision` and `target_scale`,
@@ -328,4 +368,63 @@ mod scale_adjust_test {
         )
         .is_ok());
     }
+
+    #[test]
+    fn we_can_divide_with_each_rounding_mode() {
+        let divide = |mode: RoundingMode, value| mode.divide(value, 10);
+        let values = [15, 25, -15, -25, 14, 16, -14, -16, 5, -5, 0];
+        let expected: [(RoundingMode, [i128; 11]); 3] = [
+            (
+                RoundingMode::Truncate,
+                [1, 2, -1, -2, 1, 1, -1, -1, 0, 0, 0],
+            ),
+            (RoundingMode::HalfUp, [2, 3, -2, -3, 1, 2, -1, -2, 1, -1, 0]),
+            (
+                RoundingMode::HalfEven,
+                [2, 2, -2, -2, 1, 2, -1, -2, 0, 0, 0],
+            ),
+        ];
+        for (mode, quotients) in expected {
+            for (value, quotient) in values.into_iter().zip(quotients) {
+                assert_eq!(divide(mode, value), quotient, "{mode:?} {value}");
+            }
+        }
+        assert_eq!(RoundingMode::default(), RoundingMode::HalfUp);
+        assert_eq!(RoundingMode::HalfUp.divide(i128::MAX, i128::MAX / 2 + 1), 2);
+    }
+
+    #[test]
+    fn we_can_convert_decimals_in_scientific_notation() {
+        let convert = |decimal: &str, precision, scale| {
+            try_convert_intermediate_decimal_to_scalar::<TestScalar>(
+                &decimal.parse().unwrap(),
+                Precision::new(precision).unwrap(),
+                scale,
+            )
+        };
+        assert_eq!(convert("1.5e3", 4, 0), Ok(TestScalar::from(1500)));
+        assert_eq!(convert("1.5e3", 6, 2), Ok(TestScalar::from(150_000)));
+        assert_eq!(convert("1.5e3", 2, -2), Ok(TestScalar::from(15)));
+        assert_eq!(convert("2E-2", 1, 2), Ok(TestScalar::from(2)));
+        assert_eq!(convert("-2e-2", 3, 4), Ok(-TestScalar::from(200)));
+        assert_eq!(
+            convert("1e40", 41, 0),
+            Ok(TestScalar::from(10_i128.pow(20)) * TestScalar::from(10_i128.pow(20)))
+        );
+
+        // 1e40 has 41 digits, which overflows a precision of 38
+        assert_eq!(
+            convert("1e40", 38, 0),
+            Err(DecimalError::IntermediateDecimalConversionError {
+                source: IntermediateDecimalError::OutOfRange
+            })
+        );
+        // 2e-2 has a fractional digit past a scale of 1
+        assert_eq!(
+            convert("2E-2", 10, 1),
+            Err(DecimalError::IntermediateDecimalConversionError {
+                source: IntermediateDecimalError::ConversionFailure
+            })
+        );
+    }
 }
@@ -7,10 +7,12 @@ pub(crate) mod bit;
 pub mod commitment;
 pub mod database;
 /// TODO: add docs
-pub(crate) mod encode;
+#[doc(hidden)]
+pub mod encode;
 pub mod math;
 /// TODO: add docs
 pub(crate) mod polynomial;
+pub use polynomial::{compute_evaluation_vector, EvaluationVector, MultilinearExtension};
 pub(crate) mod proof;
 pub(crate) mod ref_into;
 /// This module contains the `Scalar` trait as well as the main, generic, implementations of it.
@@ -40,7 +40,7 @@ pub struct CompositePolynomial<S: Scalar> {
 
 /// Stores the number of variables and max number of multiplicands of the added polynomial used by the prover.
 /// This data structures will is used as the verifier key.
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct CompositePolynomialInfo {
     /// max number of multiplicands in each product
     pub max_multiplicands: usize,
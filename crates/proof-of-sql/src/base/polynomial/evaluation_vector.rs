@@ -1,4 +1,6 @@
-use crate::base::if_rayon;
+use super::MultilinearExtension;
+use crate::base::{if_rayon, scalar::Scalar};
+use alloc::{vec, vec::Vec};
 use core::{
     cmp,
     ops::{Mul, MulAssign, Sub, SubAssign},
@@ -63,3 +65,63 @@ where
         compute_evaluation_vector_impl(left, right, *p);
     }
 }
+
+/// The evaluation vector of a point, computed once so that it can be reused to evaluate
+/// every multilinear extension at that point.
+///
+/// The entries are exactly the ones written by [`compute_evaluation_vector`], so evaluating with an
+/// `EvaluationVector` gives the same result as computing the evaluation vector afresh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvaluationVector<S: Scalar> {
+    point: Vec<S>,
+    vector: Vec<S>,
+}
+
+impl<S: Scalar> EvaluationVector<S> {
+    /// Compute the first `length` entries of the evaluation vector of `point`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` is larger than `2^point.len()`.
+    #[must_use]
+    pub fn new(point: &[S], length: usize) -> Self {
+        let mut vector = vec![S::ZERO; length];
+        compute_evaluation_vector(&mut vector, point);
+        Self {
+            point: point.to_vec(),
+            vector,
+        }
+    }
+
+    /// The point that the evaluation vector was computed for.
+    #[must_use]
+    pub fn point(&self) -> &[S] {
+        &self.point
+    }
+
+    /// The entries of the evaluation vector.
+    #[must_use]
+    pub fn as_slice(&self) -> &[S] {
+        &self.vector
+    }
+
+    /// The number of entries of the evaluation vector.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.vector.len()
+    }
+
+    /// Whether the evaluation vector has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.vector.is_empty()
+    }
+
+    /// Evaluate a multilinear extension at the point.
+    ///
+    /// Entries of `mle` beyond the length of the evaluation vector are ignored,
+    /// so `mle` should not be longer than the evaluation vector.
+    pub fn evaluate(&self, mle: &impl MultilinearExtension<S>) -> S {
+        mle.inner_product(&self.vector)
+    }
+}
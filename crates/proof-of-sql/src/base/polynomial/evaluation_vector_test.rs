@@ -1,4 +1,4 @@
-use super::compute_evaluation_vector;
+use super::{compute_evaluation_vector, EvaluationVector, MultilinearExtension};
 use crate::base::{scalar::test_scalar::TestScalar, slice_ops};
 use ark_poly::Polynomial;
 use num_traits::{One, Zero};
@@ -93,3 +93,39 @@ fn we_get_the_same_result_using_evaluation_vector_as_direct_evaluation() {
     let expected_eval = TestScalar::new(poly.evaluate(&TestScalar::unwrap_slice(&point)));
     assert_eq!(eval, expected_eval);
 }
+
+#[test]
+fn we_get_the_same_evaluations_with_a_cached_evaluation_vector() {
+    let point = [
+        TestScalar::from(81u64),
+        TestScalar::from(33u64),
+        TestScalar::from(22u64),
+    ];
+    let columns: [&[i64]; 4] = [
+        &[3, 7, 2, 9, 21, 10, 5, 92],
+        &[-1, 0, 1, -2, 2, -3, 3, i64::MIN],
+        &[i64::MAX, 4, 6],
+        &[],
+    ];
+    let evaluation_vector = EvaluationVector::new(&point, 8);
+    assert_eq!(evaluation_vector.point(), point);
+    assert_eq!(evaluation_vector.len(), 8);
+    for column in columns {
+        let mut v = vec![TestScalar::zero(); column.len()];
+        compute_evaluation_vector(&mut v, &point);
+        let uncached = (&column).inner_product(&v);
+        assert_eq!(evaluation_vector.evaluate(&column), uncached);
+        assert_eq!(&evaluation_vector.as_slice()[..column.len()], v);
+    }
+}
+
+#[test]
+fn we_can_cache_the_evaluation_vector_of_an_empty_point() {
+    let evaluation_vector = EvaluationVector::<TestScalar>::new(&[], 1);
+    assert_eq!(evaluation_vector.as_slice(), [TestScalar::one()]);
+    assert_eq!(
+        evaluation_vector.evaluate(&&[5_i64][..]),
+        TestScalar::from(5)
+    );
+    assert!(EvaluationVector::<TestScalar>::new(&[], 0).is_empty());
+}
@@ -10,7 +10,7 @@ mod interpolate_test;
 pub use interpolate::{interpolate_evaluations_to_reverse_coefficients, interpolate_uni_poly};
 
 mod evaluation_vector;
-pub use evaluation_vector::compute_evaluation_vector;
+pub use evaluation_vector::{compute_evaluation_vector, EvaluationVector};
 #[cfg(test)]
 mod evaluation_vector_test;
 
@@ -0,0 +1,114 @@
+use super::{ProofError, Transcript};
+use crate::base::scalar::Scalar;
+
+/// Appends `scalars` to the transcript and folds them with powers of a challenge drawn afterwards.
+///
+/// The result is `sum (r^i * scalars[i])` where `r` is the challenge.
+fn fold_with_challenge<S: Scalar>(transcript: &mut impl Transcript, scalars: &[S]) -> S {
+    transcript.extend_scalars_as_be(scalars);
+    let challenge: S = transcript.scalar_challenge_as_be();
+    scalars
+        .iter()
+        .rev()
+        .fold(S::ZERO, |acc, &scalar| acc * challenge + scalar)
+}
+
+/// The prover side of [`verify_all_zero`].
+///
+/// Appends `scalars` to the transcript and draws the same challenge the verifier does, so that the transcripts
+/// stay in sync. Returns the random linear combination of `scalars`, which is zero if they are all zero.
+pub fn prove_all_zero<S: Scalar>(transcript: &mut impl Transcript, scalars: &[S]) -> S {
+    fold_with_challenge(transcript, scalars)
+}
+
+/// Checks that every scalar in `scalars` is zero with a single check instead of one per scalar.
+///
+/// The scalars are appended to the transcript, and a random linear combination of them is formed
+/// with powers of a challenge drawn afterwards. If any scalar is nonzero, the combination is zero with probability
+/// at most `scalars.len() / |F|`, so this is as good as checking every scalar once the scalars are bound into the proof.
+///
+/// # Errors
+/// Errors if the random linear combination is not zero.
+pub fn verify_all_zero<S: Scalar>(
+    transcript: &mut impl Transcript,
+    scalars: &[S],
+) -> Result<(), ProofError> {
+    if fold_with_challenge(transcript, scalars) == S::ZERO {
+        Ok(())
+    } else {
+        Err(ProofError::VerificationError {
+            error: "scalars are not all zero",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prove_all_zero, verify_all_zero};
+    use crate::base::{
+        proof::{fixed_transcript::FixedTranscript, Keccak256Transcript, Transcript},
+        scalar::{test_scalar::TestScalar, Scalar},
+    };
+
+    #[test]
+    fn we_can_verify_that_all_scalars_are_zero() {
+        let scalars = [TestScalar::ZERO; 5];
+        let mut prover_transcript = Keccak256Transcript::new();
+        assert_eq!(
+            prove_all_zero(&mut prover_transcript, &scalars),
+            TestScalar::ZERO
+        );
+        let mut verifier_transcript = Keccak256Transcript::new();
+        assert!(verify_all_zero(&mut verifier_transcript, &scalars).is_ok());
+        assert_eq!(
+            prover_transcript.challenge_as_le(),
+            verifier_transcript.challenge_as_le()
+        );
+        assert!(verify_all_zero::<TestScalar>(&mut Keccak256Transcript::new(), &[]).is_ok());
+    }
+
+    #[test]
+    fn we_cannot_verify_that_all_scalars_are_zero_if_one_is_not() {
+        for index in 0..8 {
+            let mut scalars = [TestScalar::ZERO; 8];
+            scalars[index] = TestScalar::from(123);
+            assert!(verify_all_zero(&mut Keccak256Transcript::new(), &scalars).is_err());
+            assert_ne!(
+                prove_all_zero(&mut Keccak256Transcript::new(), &scalars),
+                TestScalar::ZERO
+            );
+        }
+        assert!(verify_all_zero(
+            &mut Keccak256Transcript::new(),
+            &[TestScalar::ONE, -TestScalar::ONE]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn the_random_linear_combination_uses_a_challenge_from_the_transcript() {
+        let challenge = TestScalar::from(7);
+        let scalars = [
+            TestScalar::from(2),
+            TestScalar::from(3),
+            TestScalar::from(5),
+        ];
+        assert_eq!(
+            prove_all_zero(
+                &mut FixedTranscript::from_scalar_challenges([challenge]),
+                &scalars
+            ),
+            TestScalar::from(2 + 3 * 7 + 5 * 49)
+        );
+
+        // A combination that vanishes at the challenge passes, so the challenge must not be known
+        // to the prover before the scalars are bound into the transcript.
+        let scalars = [challenge, -TestScalar::ONE];
+        assert!(verify_all_zero(
+            &mut FixedTranscript::from_scalar_challenges([challenge]),
+            &scalars
+        )
+        .is_ok());
+        assert!(verify_all_zero(&mut Keccak256Transcript::new(), &scalars).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::proof_primitive::SumcheckError;
 use snafu::Snafu;
 
 #[derive(Snafu, Debug)]
@@ -6,4 +7,10 @@ pub enum ProofError {
     #[snafu(display("Verification error: {error}"))]
     /// This error occurs when a proof failed to verify.
     VerificationError { error: &'static str },
+    /// This error occurs when a round polynomial of the sumcheck proof has a higher degree than the verifier accepts.
+    #[snafu(transparent)]
+    SumcheckError {
+        /// The underlying source error
+        source: SumcheckError,
+    },
 }
@@ -0,0 +1,123 @@
+use super::transcript_core::TranscriptCore;
+use crate::base::scalar::{Scalar, ScalarExt};
+use alloc::collections::VecDeque;
+
+/// A transcript that ignores all prover messages and returns a caller-supplied sequence of challenges.
+///
+/// This should only be used for the purpose of unit testing, where it allows proof logic to be exercised
+/// with pinned challenge values. It is not a public-coin transcript and provides no soundness.
+pub struct FixedTranscript {
+    challenges: VecDeque<[u8; 32]>,
+}
+
+impl FixedTranscript {
+    /// Create a transcript that returns the provided raw challenges, in order.
+    pub fn from_raw_challenges(challenges: impl IntoIterator<Item = [u8; 32]>) -> Self {
+        Self {
+            challenges: challenges.into_iter().collect(),
+        }
+    }
+
+    /// Create a transcript whose `scalar_challenge_as_be` calls return the provided scalars, in order.
+    pub fn from_scalar_challenges<S: Scalar>(challenges: impl IntoIterator<Item = S>) -> Self {
+        Self::from_raw_challenges(challenges.into_iter().map(|challenge| {
+            let mut bytes = challenge.to_canonical_bytes();
+            bytes.reverse();
+            bytes
+        }))
+    }
+
+    /// The number of challenges that have not yet been consumed.
+    pub fn remaining_challenges(&self) -> usize {
+        self.challenges.len()
+    }
+}
+
+impl TranscriptCore for FixedTranscript {
+    fn new() -> Self {
+        Self::from_raw_challenges([])
+    }
+    fn raw_append(&mut self, _message: &[u8]) {}
+    fn raw_challenge(&mut self) -> [u8; 32] {
+        self.challenges
+            .pop_front()
+            .expect("FixedTranscript ran out of challenges")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixedTranscript;
+    use crate::{
+        base::{
+            polynomial::CompositePolynomial,
+            proof::Transcript,
+            scalar::{test_scalar::TestScalar, Scalar},
+        },
+        proof_primitive::sumcheck::SumcheckProof,
+    };
+    use alloc::{rc::Rc, vec, vec::Vec};
+
+    #[test]
+    fn we_get_the_supplied_challenges_from_a_fixed_transcript() {
+        let mut transcript =
+            FixedTranscript::from_scalar_challenges([TestScalar::from(3), -TestScalar::ONE]);
+        transcript.extend_serialize_as_le(&123);
+        assert_eq!(
+            transcript.scalar_challenge_as_be::<TestScalar>(),
+            TestScalar::from(3)
+        );
+        transcript.extend_serialize_as_le(&456);
+        assert_eq!(
+            transcript.scalar_challenge_as_be::<TestScalar>(),
+            -TestScalar::ONE
+        );
+        assert_eq!(transcript.remaining_challenges(), 0);
+    }
+
+    #[test]
+    fn we_get_the_supplied_raw_challenges_from_a_fixed_transcript() {
+        let mut transcript = FixedTranscript::from_raw_challenges([[1; 32], [2; 32]]);
+        assert_eq!(transcript.challenge_as_le(), [1; 32]);
+        assert_eq!(transcript.challenge_as_le(), [2; 32]);
+    }
+
+    #[test]
+    #[should_panic(expected = "FixedTranscript ran out of challenges")]
+    fn we_panic_when_a_fixed_transcript_runs_out_of_challenges() {
+        let mut transcript: FixedTranscript = Transcript::new();
+        transcript.challenge_as_le();
+    }
+
+    #[test]
+    fn we_get_identical_sumcheck_proofs_from_identical_fixed_transcripts() {
+        let num_vars = 2;
+        let mut poly = CompositePolynomial::new(num_vars);
+        poly.add_product(
+            [Rc::new(
+                [1, 2, 3, 4].into_iter().map(TestScalar::from).collect(),
+            )],
+            TestScalar::ONE,
+        );
+        // The first challenge only groups the transcript messages. The rest are the round challenges.
+        let challenges = [
+            TestScalar::from(7),
+            TestScalar::from(11),
+            TestScalar::from(13),
+        ];
+
+        let prove = || {
+            let mut transcript = FixedTranscript::from_scalar_challenges(challenges);
+            let mut evaluation_point = vec![TestScalar::ZERO; num_vars];
+            let proof = SumcheckProof::create(&mut transcript, &mut evaluation_point, &poly);
+            assert_eq!(transcript.remaining_challenges(), 0);
+            (postcard::to_allocvec(&proof).unwrap(), evaluation_point)
+        };
+        let (proof_a, evaluation_point_a): (Vec<u8>, _) = prove();
+        let (proof_b, evaluation_point_b) = prove();
+
+        assert_eq!(proof_a, proof_b);
+        assert_eq!(evaluation_point_a, evaluation_point_b);
+        assert_eq!(evaluation_point_a, challenges[1..]);
+    }
+}
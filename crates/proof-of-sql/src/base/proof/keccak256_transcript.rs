@@ -15,6 +15,7 @@ use tiny_keccak::{Hasher, Keccak};
 /// ```pseudo-code
 /// challenge_(i+1) = keccak256(challenge_i, message_(i+1))
 /// ```
+#[derive(Clone)]
 pub struct Keccak256Transcript(Keccak);
 impl TranscriptCore for Keccak256Transcript {
     fn new() -> Self {
@@ -10,10 +10,28 @@ mod merlin_transcript_core;
 mod transcript;
 pub use transcript::Transcript;
 
+/// Checks that a batch of scalars is all zero with a single random linear combination.
+mod all_zero;
+pub(crate) use all_zero::{prove_all_zero, verify_all_zero};
+
 mod transcript_core;
 #[cfg(test)]
 mod transcript_core_test;
 
+/// A test-only transcript that returns caller-supplied challenges.
+#[cfg(test)]
+pub(crate) mod fixed_transcript;
+
 mod keccak256_transcript;
 #[allow(unused_imports)]
 pub use keccak256_transcript::Keccak256Transcript;
+
+/// A transcript wrapper that records every message and challenge, for auditing the Fiat-Shamir steps of a proof.
+#[cfg(feature = "transcript-log")]
+mod recording_transcript;
+#[cfg(feature = "transcript-log")]
+pub(crate) use recording_transcript::RecordingTranscript;
+#[cfg(feature = "transcript-log")]
+pub use recording_transcript::{
+    replay_transcript_log, TranscriptEntry, APPEND_LABEL, CHALLENGE_LABEL,
+};
@@ -0,0 +1,189 @@
+use super::{transcript_core::TranscriptCore, Keccak256Transcript, Transcript};
+use alloc::vec::Vec;
+
+/// The label that `merlin::Transcript` uses for every message appended through [`Transcript`].
+pub const APPEND_LABEL: &[u8] = b"TranscriptCore::raw_append";
+/// The label that `merlin::Transcript` uses for every challenge requested through [`Transcript`].
+pub const CHALLENGE_LABEL: &[u8] = b"TranscriptCore::raw_challenge";
+
+/// A single step of a transcript, as recorded by a [`RecordingTranscript`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptEntry {
+    /// A message appended to the transcript, labelled with [`APPEND_LABEL`].
+    Append {
+        /// The label the message was appended with
+        label: &'static [u8],
+        /// The bytes of the message
+        bytes: Vec<u8>,
+    },
+    /// A challenge requested from the transcript, labelled with [`CHALLENGE_LABEL`].
+    Challenge {
+        /// The label the challenge was requested with
+        label: &'static [u8],
+        /// The bytes of the challenge
+        bytes: [u8; 32],
+    },
+}
+
+/// A transcript that forwards every operation to an inner transcript and records it in an ordered log.
+///
+/// Recording has no effect on the challenges, so a `RecordingTranscript` can be used wherever the
+/// inner transcript is used. The log can then be replayed with [`replay_transcript_log`]
+/// to check the Fiat-Shamir steps of a proof.
+///
+/// The log holds every message sent to the transcript, which may include data derived from the witness.
+/// It must be treated with the same care as the witness itself.
+pub struct RecordingTranscript<T: Transcript> {
+    inner: T,
+    log: Vec<TranscriptEntry>,
+}
+
+impl<T: Transcript> RecordingTranscript<T> {
+    /// Split the recording transcript into the inner transcript and the recorded log.
+    pub fn into_parts(self) -> (T, Vec<TranscriptEntry>) {
+        (self.inner, self.log)
+    }
+}
+
+impl<T: Transcript> TranscriptCore for RecordingTranscript<T> {
+    fn new() -> Self {
+        Self {
+            inner: T::new(),
+            log: Vec::new(),
+        }
+    }
+    fn raw_append(&mut self, message: &[u8]) {
+        self.inner.extend_as_le_from_refs([message]);
+        self.log.push(TranscriptEntry::Append {
+            label: APPEND_LABEL,
+            bytes: message.to_vec(),
+        });
+    }
+    fn raw_challenge(&mut self) -> [u8; 32] {
+        let challenge = self.inner.challenge_as_le();
+        self.log.push(TranscriptEntry::Challenge {
+            label: CHALLENGE_LABEL,
+            bytes: challenge,
+        });
+        challenge
+    }
+}
+
+/// Replay a recorded log through a fresh [`Keccak256Transcript`], which is the transcript that
+/// [`QueryProof`](crate::sql::proof::QueryProof) uses.
+///
+/// Returns the index in `log` of the first challenge that the fresh transcript does not reproduce,
+/// or `None` if every recorded challenge is reproduced.
+#[must_use]
+pub fn replay_transcript_log(log: &[TranscriptEntry]) -> Option<usize> {
+    replay_transcript_log_as::<Keccak256Transcript>(log)
+}
+
+/// Replay a recorded log through a fresh transcript of type `T`.
+///
+/// See [`replay_transcript_log`].
+pub fn replay_transcript_log_as<T: Transcript>(log: &[TranscriptEntry]) -> Option<usize> {
+    let mut transcript = T::new();
+    log.iter().position(|entry| match entry {
+        TranscriptEntry::Append { bytes, .. } => {
+            transcript.extend_as_le_from_refs([bytes.as_slice()]);
+            false
+        }
+        TranscriptEntry::Challenge { bytes, .. } => transcript.challenge_as_le() != *bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "blitzar")]
+    use super::replay_transcript_log_as;
+    use super::{replay_transcript_log, RecordingTranscript, TranscriptEntry};
+    use crate::{
+        base::{
+            polynomial::CompositePolynomial,
+            proof::{Keccak256Transcript, Transcript},
+            scalar::{test_scalar::TestScalar, Scalar},
+        },
+        proof_primitive::sumcheck::SumcheckProof,
+    };
+    use alloc::{rc::Rc, vec};
+
+    fn record_a_sumcheck_proof<T: Transcript>(
+    ) -> (RecordingTranscript<T>, SumcheckProof<TestScalar>) {
+        let num_vars = 2;
+        let mut poly = CompositePolynomial::new(num_vars);
+        poly.add_product(
+            [Rc::new(
+                [1, 2, 3, 4].into_iter().map(TestScalar::from).collect(),
+            )],
+            TestScalar::ONE,
+        );
+        let mut transcript: RecordingTranscript<T> = Transcript::new();
+        transcript.extend_serialize_as_le(&"query");
+        let mut evaluation_point = vec![TestScalar::ZERO; num_vars];
+        let proof = SumcheckProof::create(&mut transcript, &mut evaluation_point, &poly);
+        (transcript, proof)
+    }
+
+    #[test]
+    fn recording_does_not_change_the_challenges() {
+        let mut recording: RecordingTranscript<Keccak256Transcript> = Transcript::new();
+        let mut plain: Keccak256Transcript = Transcript::new();
+        recording.extend_serialize_as_le(&(1, "message"));
+        plain.extend_serialize_as_le(&(1, "message"));
+        assert_eq!(
+            recording.scalar_challenge_as_be::<TestScalar>(),
+            plain.scalar_challenge_as_be::<TestScalar>()
+        );
+        assert_eq!(recording.challenge_as_le(), plain.challenge_as_le());
+        let (mut inner, log) = recording.into_parts();
+        assert_eq!(inner.challenge_as_le(), plain.challenge_as_le());
+        assert_eq!(log.len(), 3);
+    }
+
+    #[test]
+    fn a_replayed_log_reproduces_the_recorded_challenges() {
+        let (transcript, _) = record_a_sumcheck_proof::<Keccak256Transcript>();
+        let (_, log) = transcript.into_parts();
+        assert!(log
+            .iter()
+            .any(|entry| matches!(entry, TranscriptEntry::Challenge { .. })));
+        assert_eq!(replay_transcript_log(&log), None);
+    }
+
+    #[test]
+    fn a_tampered_log_does_not_reproduce_the_recorded_challenges() {
+        let (transcript, _) = record_a_sumcheck_proof::<Keccak256Transcript>();
+        let (_, mut log) = transcript.into_parts();
+        let first_challenge = log
+            .iter()
+            .position(|entry| matches!(entry, TranscriptEntry::Challenge { .. }))
+            .unwrap();
+        match &mut log[0] {
+            TranscriptEntry::Append { bytes, .. } => bytes.push(0),
+            TranscriptEntry::Challenge { .. } => panic!("the first entry is a message"),
+        }
+        assert_eq!(replay_transcript_log(&log), Some(first_challenge));
+    }
+
+    #[cfg(feature = "blitzar")]
+    #[test]
+    fn a_recorded_log_can_be_replayed_with_merlin_labels() {
+        let (transcript, _) = record_a_sumcheck_proof::<merlin::Transcript>();
+        let (_, log) = transcript.into_parts();
+        assert_eq!(replay_transcript_log_as::<merlin::Transcript>(&log), None);
+        let mut merlin_transcript = merlin::Transcript::new(b"TranscriptCore::new");
+        for entry in &log {
+            match entry {
+                TranscriptEntry::Append { label, bytes } => {
+                    merlin_transcript.append_message(label, bytes);
+                }
+                TranscriptEntry::Challenge { label, bytes } => {
+                    let mut challenge = [0; 32];
+                    merlin_transcript.challenge_bytes(label, &mut challenge);
+                    assert_eq!(&challenge, bytes);
+                }
+            }
+        }
+    }
+}
@@ -15,4 +15,7 @@ pub mod test_scalar;
 mod test_scalar_test;
 
 mod scalar_ext;
-pub use scalar_ext::ScalarExt;
+pub use scalar_ext::{all_zero, ScalarExt};
+
+mod scalar_vec;
+pub use scalar_vec::ScalarVec;
@@ -1,4 +1,4 @@
-use super::{Scalar, ScalarConversionError};
+use super::{Scalar, ScalarConversionError, ScalarExt};
 use alloc::{
     format,
     string::{String, ToString},
@@ -131,7 +131,11 @@ macro_rules! impl_from_for_mont_scalar_for_type_supported_by_from {
     };
 }
 
-/// Implement `From<&[u8]>` for `MontScalar`
+/// Hash bytes into a `MontScalar`. This is how `VarChar` values are mapped to scalars.
+///
+/// The empty slice maps to zero. Any other slice is hashed with BLAKE3, the top four bits of the hash are cleared,
+/// and the result is read as a little-endian integer modulo the field order.
+/// External systems that need to produce matching commitments to strings must hash them the same way.
 impl<T: MontConfig<4>> From<&[u8]> for MontScalar<T> {
     fn from(x: &[u8]) -> Self {
         if x.is_empty() {
@@ -186,6 +190,19 @@ where
 pub type Curve25519Scalar = MontScalar<ark_curve25519::FrConfig>;
 
 impl<T: MontConfig<4>> MontScalar<T> {
+    /// The field order as little-endian bytes, for implementing [`Scalar::MODULUS`].
+    pub(crate) const MODULUS_LE_BYTES: [u8; 32] = {
+        let limbs = T::MODULUS.0;
+        let mut bytes = [0; 32];
+        let mut i = 0;
+        while i < 32 {
+            bytes[i] = limbs[i / 8].to_le_bytes()[i % 8];
+            i += 1;
+        }
+        bytes
+    };
+    /// The number of bits of the field order, for implementing [`Scalar::ORDER_BITS`].
+    pub(crate) const MODULUS_NUM_BITS: u32 = T::MODULUS.const_num_bits();
     /// Convenience function for creating a new `MontScalar<T>` from the underlying `Fp256<MontBackend<T, 4>>`. Should only be used in tests.
     #[cfg(test)]
     pub fn new(value: Fp256<MontBackend<T, 4>>) -> Self {
@@ -341,8 +358,7 @@ impl From<&Curve25519Scalar> for curve25519_dalek::scalar::Scalar {
     ///
     /// This method will panic if the byte array is not of the expected length (32 bytes) or if it cannot be converted to a valid canonical scalar. However, under normal conditions, valid `Curve25519Scalar` values should always satisfy these requirements.
     fn from(value: &Curve25519Scalar) -> Self {
-        let bytes = ark_ff::BigInteger::to_bytes_le(&value.0.into_bigint());
-        curve25519_dalek::scalar::Scalar::from_canonical_bytes(bytes.try_into().unwrap()).unwrap()
+        curve25519_dalek::scalar::Scalar::from_canonical_bytes(value.to_canonical_bytes()).unwrap()
     }
 }
 
@@ -431,6 +447,8 @@ impl super::Scalar for Curve25519Scalar {
     const ONE: Self = Self(ark_ff::MontFp!("1"));
     const TWO: Self = Self(ark_ff::MontFp!("2"));
     const TEN: Self = Self(ark_ff::MontFp!("10"));
+    const MODULUS: [u8; 32] = Self::MODULUS_LE_BYTES;
+    const ORDER_BITS: u32 = Self::MODULUS_NUM_BITS;
 }
 
 impl<T> TryFrom<MontScalar<T>> for bool
@@ -557,6 +575,24 @@ where
     }
 }
 
+impl<T> TryFrom<MontScalar<T>> for u64
+where
+    T: MontConfig<4>,
+    MontScalar<T>: Scalar,
+{
+    type Error = ScalarConversionError;
+    fn try_from(value: MontScalar<T>) -> Result<Self, Self::Error> {
+        // Anything greater than the max signed value is negative, so it does not fit in a u64
+        let abs: [u64; 4] = value.into();
+        if value > <MontScalar<T>>::MAX_SIGNED || abs[1] != 0 || abs[2] != 0 || abs[3] != 0 {
+            return Err(ScalarConversionError::Overflow {
+                error: format!("{value} is too large to fit in a u64"),
+            });
+        }
+        Ok(abs[0])
+    }
+}
+
 impl<T> TryFrom<MontScalar<T>> for i128
 where
     T: MontConfig<4>,
@@ -244,6 +244,36 @@ fn test_curve25519_scalar_to_i64_overflow() {
     );
 }
 
+#[test]
+fn test_curve25519_scalar_to_u64() {
+    assert_eq!(u64::try_from(Curve25519Scalar::from(0)).unwrap(), 0);
+    assert_eq!(u64::try_from(Curve25519Scalar::ONE).unwrap(), 1);
+    assert_eq!(
+        u64::try_from(Curve25519Scalar::from(i64::MAX)).unwrap(),
+        i64::MAX as u64
+    );
+    assert_eq!(
+        u64::try_from(Curve25519Scalar::from(u64::MAX)).unwrap(),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_curve25519_scalar_to_u64_overflow() {
+    assert!(matches!(
+        u64::try_from(Curve25519Scalar::from(-1)),
+        Err(ScalarConversionError::Overflow { .. })
+    ));
+    assert!(matches!(
+        u64::try_from(Curve25519Scalar::from(u128::from(u64::MAX) + 1)),
+        Err(ScalarConversionError::Overflow { .. })
+    ));
+    assert!(matches!(
+        u64::try_from(-Curve25519Scalar::from(u64::MAX)),
+        Err(ScalarConversionError::Overflow { .. })
+    ));
+}
+
 #[test]
 fn test_curve25519_scalar_to_i128() {
     assert_eq!(i128::try_from(Curve25519Scalar::from(0)).unwrap(), 0);
@@ -376,6 +406,18 @@ fn two_different_strings_map_to_different_scalars() {
     assert_ne!(Curve25519Scalar::from(s), Curve25519Scalar::from("abc123"));
 }
 
+#[test]
+fn strings_are_mapped_to_the_masked_blake3_hash_of_their_bytes() {
+    for s in ["a", "abc12", "testing string to scalar"] {
+        let mut bytes: [u8; 32] = blake3::hash(s.as_bytes()).into();
+        bytes[31] &= 0b0000_1111_u8;
+        assert_eq!(
+            Curve25519Scalar::from(s),
+            Curve25519Scalar::from_le_bytes_mod_order(&bytes)
+        );
+    }
+}
+
 #[test]
 fn the_empty_buffer_will_be_mapped_to_the_zero_scalar() {
     let buf = Vec::<u8>::default();
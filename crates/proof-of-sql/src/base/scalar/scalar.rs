@@ -13,6 +13,7 @@ pub trait Scalar:
     + PartialEq
     + Default
     + for<'a> From<&'a str>
+    + for<'a> From<&'a [u8]>
     + Sync
     + Send
     + num_traits::One
@@ -29,6 +30,7 @@ pub trait Scalar:
     + for<'a> core::convert::From<&'a i16> // Required for `Column` to implement `MultilinearExtension`
     + for<'a> core::convert::From<&'a i32> // Required for `Column` to implement `MultilinearExtension`
     + for<'a> core::convert::From<&'a i64> // Required for `Column` to implement `MultilinearExtension`
+    + for<'a> core::convert::From<&'a u64> // Required for `Column` to implement `MultilinearExtension`
     + for<'a> core::convert::From<&'a i128> // Required for `Column` to implement `MultilinearExtension`
     + for<'a> core::convert::From<&'a u8> // Required for `Column` to implement `MultilinearExtension`
     + core::convert::TryInto <bool>
@@ -36,6 +38,7 @@ pub trait Scalar:
     + core::convert::TryInto <i16>
     + core::convert::TryInto <i32>
     + core::convert::TryInto <i64>
+    + core::convert::TryInto <u64>
     + core::convert::TryInto <i128>
     + core::convert::Into<[u64; 4]>
     + core::convert::From<[u64; 4]>
@@ -53,6 +56,7 @@ pub trait Scalar:
     + core::convert::From<String>
     + core::convert::From<i128>
     + core::convert::From<i64>
+    + core::convert::From<u64>
     + core::convert::From<i32>
     + core::convert::From<i16>
     + core::convert::From<i8>
@@ -71,4 +75,11 @@ pub trait Scalar:
     const TWO: Self;
     /// 2 + 2 + 2 + 2 + 2
     const TEN: Self;
+    /// The field order p, as little-endian bytes.
+    ///
+    /// This is the byte order of [`ScalarExt::to_canonical_bytes`](super::ScalarExt::to_canonical_bytes).
+    /// Every scalar is strictly less than p, so these bytes are not the encoding of any scalar.
+    const MODULUS: [u8; 32];
+    /// The number of bits of the field order p. Every scalar fits in this many bits.
+    const ORDER_BITS: u32;
 }
@@ -1,13 +1,78 @@
 use super::Scalar;
 use core::cmp::Ordering;
+use num_traits::Zero;
+
+/// The number of entries in [`POWERS_OF_TEN`]. 10^38 is the largest power of ten that fits in an `i128`.
+const NUM_CACHED_POWERS_OF_TEN: usize = 39;
+
+/// The powers 10^0 through 10^38, which covers the common decimal scales.
+const POWERS_OF_TEN: [i128; NUM_CACHED_POWERS_OF_TEN] = {
+    let mut powers = [1; NUM_CACHED_POWERS_OF_TEN];
+    let mut i = 1;
+    while i < NUM_CACHED_POWERS_OF_TEN {
+        powers[i] = powers[i - 1] * 10;
+        i += 1;
+    }
+    powers
+};
 
 /// Extention trait for blanket implementations for `Scalar` types.
 /// This trait is primarily to avoid cluttering the core `Scalar` implementation with default implemenentations
 /// and provides helper methods for `Scalar`.
 pub trait ScalarExt: Scalar {
     /// Compute 10^exponent for the Scalar. Note that we do not check for overflow.
+    ///
+    /// Exponents up to 38 are looked up from a table. Larger exponents fall back to [`ScalarExt::pow_small`].
+    #[must_use]
     fn pow10(exponent: u8) -> Self {
-        itertools::repeat_n(Self::TEN, exponent as usize).product()
+        POWERS_OF_TEN.get(usize::from(exponent)).map_or_else(
+            || Self::TEN.pow_small(exponent.into()),
+            |&power| power.into(),
+        )
+    }
+    /// Compute self^exponent using square-and-multiply. Note that we do not check for overflow.
+    #[must_use]
+    fn pow_small(self, exponent: u64) -> Self {
+        let mut result = Self::ONE;
+        let mut base = self;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base *= base;
+            }
+        }
+        result
+    }
+    /// Returns the canonical little-endian byte encoding of the scalar.
+    ///
+    /// The encoding is always of the fully reduced value, so equal scalars have equal encodings.
+    #[must_use]
+    fn to_canonical_bytes(&self) -> [u8; 32] {
+        let limbs: [u64; 4] = (*self).into();
+        let mut bytes = [0; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+    /// Decodes a scalar from its canonical little-endian byte encoding.
+    ///
+    /// Returns `None` if the bytes are not reduced, i.e. if they encode a value that is at least the field order.
+    #[must_use]
+    fn from_canonical_bytes(bytes: [u8; 32]) -> Option<Self> {
+        let limbs: [u64; 4] = core::array::from_fn(|i| {
+            u64::from_le_bytes(
+                bytes[8 * i..8 * (i + 1)]
+                    .try_into()
+                    .expect("chunk is exactly 8 bytes"),
+            )
+        });
+        let scalar = Self::from(limbs);
+        (scalar.to_canonical_bytes() == bytes).then_some(scalar)
     }
     /// Compare two `Scalar`s as signed numbers.
     fn signed_cmp(&self, other: &Self) -> Ordering {
@@ -20,10 +85,19 @@ pub trait ScalarExt: Scalar {
 }
 impl<S: Scalar> ScalarExt for S {}
 
+/// Whether every scalar in `scalars` is zero. This is `true` for an empty slice.
+#[must_use]
+pub fn all_zero<S: Scalar>(scalars: &[S]) -> bool {
+    scalars.iter().all(Zero::is_zero)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::base::scalar::{test_scalar::TestScalar, Curve25519Scalar, MontScalar};
+    use crate::{
+        base::scalar::{test_scalar::TestScalar, Curve25519Scalar, MontScalar},
+        proof_primitive::dory::DoryScalar,
+    };
     #[test]
     fn scalar_comparison_works() {
         let zero = Curve25519Scalar::ZERO;
@@ -45,6 +119,10 @@ mod tests {
                 TestScalar::from(u128::pow(10, i))
             );
         }
+        assert_eq!(
+            TestScalar::pow10(39),
+            TestScalar::from(u128::pow(10, 38)) * TestScalar::TEN
+        );
         assert_eq!(
             TestScalar::pow10(76),
             MontScalar(ark_ff::MontFp!(
@@ -52,4 +130,126 @@ mod tests {
             ))
         );
     }
+    fn we_can_round_trip_canonical_bytes<S: Scalar>() {
+        for scalar in [
+            S::ZERO,
+            S::ONE,
+            S::TEN,
+            S::from(-1),
+            S::from(i128::MAX),
+            S::MAX_SIGNED,
+            S::MAX_SIGNED + S::ONE,
+        ] {
+            let bytes = scalar.to_canonical_bytes();
+            assert_eq!(S::from_canonical_bytes(bytes), Some(scalar));
+        }
+        assert_eq!(S::ONE.to_canonical_bytes(), {
+            let mut bytes = [0; 32];
+            bytes[0] = 1;
+            bytes
+        });
+    }
+
+    fn we_cannot_decode_unreduced_canonical_bytes<S: Scalar>() {
+        // The field order is one more than the largest scalar, -1.
+        let mut order = (-S::ONE).to_canonical_bytes();
+        for byte in &mut order {
+            let (sum, carry) = byte.overflowing_add(1);
+            *byte = sum;
+            if !carry {
+                break;
+            }
+        }
+        assert_eq!(S::from_canonical_bytes(order), None);
+        assert_eq!(S::from_canonical_bytes([0xff; 32]), None);
+    }
+
+    fn we_can_decode_one_less_than_the_modulus_but_not_the_modulus<S: Scalar>() {
+        let mut modulus_minus_one = S::MODULUS;
+        for byte in &mut modulus_minus_one {
+            let (difference, borrow) = byte.overflowing_sub(1);
+            *byte = difference;
+            if !borrow {
+                break;
+            }
+        }
+        assert_eq!(S::from_canonical_bytes(modulus_minus_one), Some(-S::ONE));
+        assert_eq!(S::from_canonical_bytes(S::MODULUS), None);
+        let highest_bit = S::MODULUS
+            .iter()
+            .rposition(|&byte| byte != 0)
+            .map(|i| 8 * u32::try_from(i).unwrap() + 8 - S::MODULUS[i].leading_zeros())
+            .unwrap();
+        assert_eq!(highest_bit, S::ORDER_BITS);
+    }
+
+    #[test]
+    fn we_can_read_the_modulus_and_order_bits_of_scalars() {
+        we_can_decode_one_less_than_the_modulus_but_not_the_modulus::<TestScalar>();
+        we_can_decode_one_less_than_the_modulus_but_not_the_modulus::<Curve25519Scalar>();
+        we_can_decode_one_less_than_the_modulus_but_not_the_modulus::<DoryScalar>();
+        assert_eq!(Curve25519Scalar::ORDER_BITS, 253);
+        assert_eq!(DoryScalar::ORDER_BITS, 255);
+        // 2^252 + 27742317777372353535851937790883648493
+        assert_eq!(
+            Curve25519Scalar::MODULUS,
+            [
+                0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9,
+                0xde, 0x14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10
+            ]
+        );
+    }
+
+    #[test]
+    fn we_can_convert_scalars_to_and_from_canonical_bytes() {
+        we_can_round_trip_canonical_bytes::<TestScalar>();
+        we_can_round_trip_canonical_bytes::<Curve25519Scalar>();
+        we_can_round_trip_canonical_bytes::<DoryScalar>();
+        we_cannot_decode_unreduced_canonical_bytes::<TestScalar>();
+        we_cannot_decode_unreduced_canonical_bytes::<Curve25519Scalar>();
+        we_cannot_decode_unreduced_canonical_bytes::<DoryScalar>();
+        assert_eq!(
+            Curve25519Scalar::from(1234).to_canonical_bytes(),
+            curve25519_dalek::scalar::Scalar::from(1234_u64).to_bytes()
+        );
+    }
+
+    #[test]
+    fn we_can_check_whether_all_scalars_are_zero() {
+        assert!(all_zero::<TestScalar>(&[]));
+        assert!(all_zero(&[TestScalar::ZERO; 3]));
+        assert!(!all_zero(&[
+            TestScalar::ZERO,
+            TestScalar::ONE,
+            TestScalar::ZERO
+        ]));
+        assert!(!all_zero(&[-TestScalar::ONE]));
+    }
+
+    #[test]
+    fn we_can_compute_small_powers_of_scalars() {
+        for base in [
+            TestScalar::ZERO,
+            TestScalar::ONE,
+            TestScalar::TWO,
+            TestScalar::from(-3),
+            TestScalar::from(123_456_789),
+            TestScalar::MAX_SIGNED,
+        ] {
+            let mut naive_power = TestScalar::ONE;
+            for exponent in 0..=40 {
+                assert_eq!(base.pow_small(exponent), naive_power);
+                naive_power *= base;
+            }
+        }
+        assert_eq!(TestScalar::ZERO.pow_small(0), TestScalar::ONE);
+        assert_eq!(
+            Curve25519Scalar::from(7).pow_small(0),
+            Curve25519Scalar::ONE
+        );
+        assert_eq!(
+            Curve25519Scalar::TEN.pow_small(76),
+            Curve25519Scalar::pow10(76)
+        );
+    }
 }
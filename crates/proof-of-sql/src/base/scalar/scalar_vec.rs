@@ -0,0 +1,76 @@
+use super::{Scalar, ScalarExt};
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+/// A vector of scalars, each of which was decoded from its canonical byte encoding.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScalarVec<S: Scalar>(Vec<S>);
+
+impl<S: Scalar> ScalarVec<S> {
+    /// Decodes every element of `bytes` with [`ScalarExt::from_canonical_bytes`].
+    ///
+    /// # Errors
+    /// Returns the index of the first element that is not canonically reduced.
+    pub fn from_canonical_bytes(bytes: &[[u8; 32]]) -> Result<Self, usize> {
+        bytes
+            .iter()
+            .enumerate()
+            .map(|(index, &element)| S::from_canonical_bytes(element).ok_or(index))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    /// Returns the underlying scalars.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<S> {
+        self.0
+    }
+}
+
+impl<S: Scalar> Deref for ScalarVec<S> {
+    type Target = [S];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<S: Scalar> From<ScalarVec<S>> for Vec<S> {
+    fn from(scalars: ScalarVec<S>) -> Self {
+        scalars.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::scalar::test_scalar::TestScalar;
+
+    #[test]
+    fn we_can_decode_an_array_of_canonical_bytes() {
+        let scalars = [TestScalar::ZERO, TestScalar::ONE, TestScalar::from(-1)];
+        let bytes = scalars.map(|scalar| scalar.to_canonical_bytes());
+        let decoded = ScalarVec::<TestScalar>::from_canonical_bytes(&bytes).unwrap();
+        assert_eq!(&*decoded, &scalars);
+        assert_eq!(decoded.into_inner(), scalars.to_vec());
+        assert_eq!(
+            ScalarVec::<TestScalar>::from_canonical_bytes(&[]),
+            Ok(ScalarVec::default())
+        );
+    }
+
+    #[test]
+    fn we_get_the_index_of_the_first_non_canonical_element() {
+        let bytes = [
+            TestScalar::ONE.to_canonical_bytes(),
+            TestScalar::TWO.to_canonical_bytes(),
+            TestScalar::MODULUS,
+            TestScalar::TEN.to_canonical_bytes(),
+            [0xff; 32],
+        ];
+        assert_eq!(
+            ScalarVec::<TestScalar>::from_canonical_bytes(&bytes),
+            Err(2)
+        );
+    }
+}
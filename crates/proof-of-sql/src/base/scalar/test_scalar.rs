@@ -14,6 +14,8 @@ impl Scalar for TestScalar {
     const ONE: Self = Self(ark_ff::MontFp!("1"));
     const TWO: Self = Self(ark_ff::MontFp!("2"));
     const TEN: Self = Self(ark_ff::MontFp!("10"));
+    const MODULUS: [u8; 32] = Self::MODULUS_LE_BYTES;
+    const ORDER_BITS: u32 = Self::MODULUS_NUM_BITS;
 }
 
 pub struct TestMontConfig(pub ark_curve25519::FrConfig);
@@ -35,9 +35,11 @@ pub const fn min_as_f(column_type: ColumnType) -> F {
         ColumnType::Int => MontFp!("-2147483648"),
         ColumnType::BigInt | ColumnType::TimestampTZ(_, _) => MontFp!("-9223372036854775808"),
         ColumnType::Int128 => MontFp!("-170141183460469231731687303715884105728"),
-        ColumnType::Decimal75(_, _)
+        ColumnType::UInt64
+        | ColumnType::Decimal75(_, _)
         | ColumnType::Scalar
         | ColumnType::VarChar
+        | ColumnType::FixedBytes(_)
         | ColumnType::Boolean => MontFp!("0"),
     }
 }
@@ -111,12 +113,16 @@ fn copy_column_data_to_slice(
         CommittableColumn::BigInt(column) | CommittableColumn::TimestampTZ(_, _, column) => {
             scalar_row_slice[start..end].copy_from_slice(&column[index].offset_to_bytes());
         }
+        CommittableColumn::UInt64(column) => {
+            scalar_row_slice[start..end].copy_from_slice(&column[index].offset_to_bytes());
+        }
         CommittableColumn::Int128(column) => {
             scalar_row_slice[start..end].copy_from_slice(&column[index].offset_to_bytes());
         }
         CommittableColumn::Scalar(column)
         | CommittableColumn::Decimal75(_, _, column)
-        | CommittableColumn::VarChar(column) => {
+        | CommittableColumn::VarChar(column)
+        | CommittableColumn::FixedBytes(_, column) => {
             scalar_row_slice[start..end].copy_from_slice(&column[index].offset_to_bytes());
         }
         CommittableColumn::RangeCheckWord(_) => todo!(),
@@ -46,6 +46,8 @@ impl Scalar for DoryScalar {
     const ONE: Self = Self(ark_ff::MontFp!("1"));
     const TWO: Self = Self(ark_ff::MontFp!("2"));
     const TEN: Self = Self(ark_ff::MontFp!("10"));
+    const MODULUS: [u8; 32] = Self::MODULUS_LE_BYTES;
+    const ORDER_BITS: u32 = Self::MODULUS_NUM_BITS;
 }
 
 #[derive(
@@ -155,6 +157,62 @@ mod tests {
         assert_eq!(commitments, expected_commitments);
     }
 
+    #[test]
+    fn we_can_verify_row_updates_with_the_commitment_difference() {
+        let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 2);
+        let commit = |column: &[i64], offset: usize| {
+            DoryCommitment::compute_commitments(
+                &[CommittableColumn::BigInt(column)],
+                offset,
+                &setup,
+            )[0]
+        };
+        let offset = 3;
+
+        // rows 1 and 4 are updated
+        let old = commit(&[12, 34, 56, 78, 90], offset);
+        let new = commit(&[12, 30, 56, 78, 100], offset);
+        let deltas = commit(&[0, -4, 0, 0, 10], offset);
+        assert_eq!(new.sub(&old), deltas);
+        assert_eq!(old.sub(&new), -deltas);
+
+        // the deltas only need to span the updated rows
+        assert_eq!(new.sub(&old), commit(&[-4, 0, 0, 10], offset + 1));
+
+        // wrong deltas or a wrong offset are detected
+        assert_ne!(new.sub(&old), commit(&[-4, 0, 0, 11], offset + 1));
+        assert_ne!(new.sub(&old), commit(&[-4, 0, 0, 10], offset));
+
+        // no updates produce the identity
+        assert_eq!(old.sub(&old), DoryCommitment::default());
+    }
+
+    #[test]
+    fn we_can_verify_a_commitment_against_raw_column_data() {
+        let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 2);
+        let column = [12_i64, 34, 56, 78, 90];
+        let commitment =
+            DoryCommitment::compute_commitments(&[CommittableColumn::BigInt(&column)], 3, &setup)
+                [0];
+
+        assert!(commitment.verify_against(&column[..], 3, &setup));
+        assert!(commitment.verify_against(Column::<DoryScalar>::BigInt(&column), 3, &setup));
+        assert!(commitment.verify_against(
+            &OwnedColumn::<DoryScalar>::BigInt(column.to_vec()),
+            3,
+            &setup
+        ));
+
+        // a single changed value, a missing row or a different offset do not match
+        assert!(!commitment.verify_against(&[12_i64, 34, 56, 79, 90][..], 3, &setup));
+        assert!(!commitment.verify_against(&column[..4], 3, &setup));
+        assert!(!commitment.verify_against(&column[..], 2, &setup));
+    }
+
     #[test]
     fn we_can_append_rows() {
         let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
@@ -1,9 +1,43 @@
-use super::{pairings, DoryCommitment, DoryProverPublicSetup, DoryScalar, G1Projective};
+use super::{
+    pairings, DoryCommitment, DoryProverPublicSetup, DoryScalar, G1Affine, G1Projective, F,
+};
 use crate::base::commitment::CommittableColumn;
 use alloc::vec::Vec;
 use ark_ec::VariableBaseMSM;
 use core::iter::once;
 
+#[cfg(all(test, feature = "std"))]
+std::thread_local! {
+    /// Test hook that makes [`compute_row_commitment`] perturb the result of its MSM on this thread,
+    /// so that the debug self-check has a divergence to catch.
+    static PERTURB_ROW_MSM: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// Commit to a row of the matrix with an MSM over the given generators.
+///
+/// In debug builds, the MSM is checked against a naive sum of scalar multiplications,
+/// which shares no code with it, so a bug in the MSM path is caught during development.
+/// The check compiles out in release builds.
+fn compute_row_commitment(generators: &[G1Affine], row: &[F]) -> G1Projective {
+    let commitment = G1Projective::msm_unchecked(generators, row);
+    #[cfg(all(test, feature = "std"))]
+    let commitment = if PERTURB_ROW_MSM.with(core::cell::Cell::get) {
+        commitment + generators[0]
+    } else {
+        commitment
+    };
+    debug_assert_eq!(
+        commitment,
+        generators
+            .iter()
+            .zip(row)
+            .map(|(generator, scalar)| *generator * scalar)
+            .sum::<G1Projective>(),
+        "the MSM of a row does not match its naive sum"
+    );
+    commitment
+}
+
 #[tracing::instrument(name = "compute_dory_commitment_impl (cpu)", level = "debug", skip_all)]
 /// # Panics
 ///
@@ -34,12 +68,12 @@ where
     let remaining_rows = remaining_elements.chunks(num_columns);
 
     // Compute commitments for the rows.
-    let first_row_commit = G1Projective::msm_unchecked(
+    let first_row_commit = compute_row_commitment(
         &setup.prover_setup().Gamma_1.last().unwrap()[first_row_offset..num_columns],
         &Vec::from_iter(first_row.iter().map(|s| s.into().0)),
     );
     let remaining_row_commits = remaining_rows.map(|row| {
-        G1Projective::msm_unchecked(
+        compute_row_commitment(
             &setup.prover_setup().Gamma_1.last().unwrap()[..num_columns],
             &Vec::from_iter(row.iter().map(|s| s.into().0)),
         )
@@ -64,11 +98,14 @@ fn compute_dory_commitment(
         CommittableColumn::SmallInt(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::Int(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::BigInt(column) => compute_dory_commitment_impl(column, offset, setup),
+        CommittableColumn::UInt64(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::Int128(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::Decimal75(_, _, column) => {
             compute_dory_commitment_impl(column, offset, setup)
         }
-        CommittableColumn::VarChar(column) => compute_dory_commitment_impl(column, offset, setup),
+        CommittableColumn::VarChar(column) | CommittableColumn::FixedBytes(_, column) => {
+            compute_dory_commitment_impl(column, offset, setup)
+        }
         CommittableColumn::Boolean(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::TimestampTZ(_, _, column) => {
             compute_dory_commitment_impl(column, offset, setup)
@@ -89,3 +126,35 @@ pub(super) fn compute_dory_commitments(
         .map(|column| compute_dory_commitment(column, offset, setup))
         .collect()
 }
+
+#[cfg(all(test, feature = "std", debug_assertions))]
+mod tests {
+    use super::*;
+    use crate::proof_primitive::dory::{test_rng, ProverSetup, PublicParameters};
+
+    #[test]
+    fn the_debug_self_check_accepts_a_correct_commitment() {
+        let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 1);
+        let commitments = compute_dory_commitments(
+            &[
+                CommittableColumn::BigInt(&[1, 2, 3, 4, 5]),
+                CommittableColumn::BigInt(&[]),
+            ],
+            1,
+            &setup,
+        );
+        assert_eq!(commitments.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the MSM of a row does not match its naive sum")]
+    fn the_debug_self_check_catches_a_perturbed_msm() {
+        let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 1);
+        PERTURB_ROW_MSM.with(|perturb| perturb.set(true));
+        compute_dory_commitments(&[CommittableColumn::BigInt(&[1, 2, 3])], 0, &setup);
+    }
+}
@@ -0,0 +1,173 @@
+use super::{G1Affine, G2Affine, PublicParameters};
+use alloc::{sync::Arc, vec::Vec};
+use ark_ff::UniformRand;
+use ark_std::rand::Rng;
+use core::iter;
+use snafu::Snafu;
+
+/// Errors from assembling generators out of chunks.
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum GeneratorChunksError {
+    /// A chunk does not start where the previous chunk ends.
+    #[snafu(display(
+        "chunk starts at offset {actual}, but the next generator has offset {expected}"
+    ))]
+    OffsetMismatch {
+        /// The offset of the first generator that is not yet covered by a chunk
+        expected: usize,
+        /// The offset the chunk starts at
+        actual: usize,
+    },
+    /// A chunk has a different number of `Γ_1` and `Γ_2` generators.
+    #[snafu(display("chunk has {gamma_1_len} Γ_1 generators but {gamma_2_len} Γ_2 generators"))]
+    LengthMismatch {
+        /// The number of `Γ_1` generators
+        gamma_1_len: usize,
+        /// The number of `Γ_2` generators
+        gamma_2_len: usize,
+    },
+    /// The chunks do not add up to a power of two generators.
+    #[snafu(display("{len} generators is not a power of two"))]
+    NotPowerOfTwo {
+        /// The total number of generators
+        len: usize,
+    },
+}
+
+/// Generators `Γ_1,0` and `Γ_2,0` that are assembled from contiguous chunks.
+///
+/// When there are too many generators to derive in one batch, they can be derived one chunk
+/// at a time. Every chunk must start at the offset right after the previous one, so the chunks
+/// never leave a gap or overlap. Generator `i` of the assembled array is found in the chunk
+/// that covers offset `i`, just as if the array had been derived in one piece.
+#[derive(Debug, Clone, Default)]
+pub struct GeneratorChunks {
+    /// The offset of the first generator of each chunk
+    offsets: Vec<usize>,
+    chunks: Vec<(Vec<G1Affine>, Vec<G2Affine>)>,
+    len: usize,
+}
+
+impl GeneratorChunks {
+    /// Creates an empty set of chunks.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The total number of generators in all chunks
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no generators
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of chunks
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Appends the chunk of generators that starts at `offset`.
+    ///
+    /// `offset` must be the offset right after the last generator of the previous chunk,
+    /// which is [`Self::len`]. Empty chunks are accepted and ignored.
+    pub fn push_chunk(
+        &mut self,
+        offset: usize,
+        gamma_1: Vec<G1Affine>,
+        gamma_2: Vec<G2Affine>,
+    ) -> Result<(), GeneratorChunksError> {
+        if offset != self.len {
+            return Err(GeneratorChunksError::OffsetMismatch {
+                expected: self.len,
+                actual: offset,
+            });
+        }
+        if gamma_1.len() != gamma_2.len() {
+            return Err(GeneratorChunksError::LengthMismatch {
+                gamma_1_len: gamma_1.len(),
+                gamma_2_len: gamma_2.len(),
+            });
+        }
+        self.append(gamma_1, gamma_2);
+        Ok(())
+    }
+
+    /// Derives the next `len` generators from `rng` and appends them as a chunk.
+    ///
+    /// Each generator is drawn as a `Γ_1` element followed by a `Γ_2` element, the same way
+    /// [`PublicParameters::test_rand`] draws them, so deriving the generators of a seeded rng in
+    /// chunks gives the same generators as deriving them all at once.
+    pub fn derive_chunk<R: Rng + ?Sized>(&mut self, len: usize, rng: &mut R) {
+        let (gamma_1, gamma_2) = iter::repeat_with(|| (G1Affine::rand(rng), G2Affine::rand(rng)))
+            .take(len)
+            .unzip();
+        self.append(gamma_1, gamma_2);
+    }
+
+    /// Appends a chunk of equally many `Γ_1` and `Γ_2` generators after the last chunk.
+    fn append(&mut self, gamma_1: Vec<G1Affine>, gamma_2: Vec<G2Affine>) {
+        if gamma_1.is_empty() {
+            return;
+        }
+        self.offsets.push(self.len);
+        self.len += gamma_1.len();
+        self.chunks.push((gamma_1, gamma_2));
+    }
+
+    /// The generators at `index` of the assembled array, if it is in range.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<(G1Affine, G2Affine)> {
+        if index >= self.len {
+            return None;
+        }
+        // The chunk that covers `index` is the last one that starts at or before it.
+        let chunk = self.offsets.partition_point(|&offset| offset <= index) - 1;
+        let (gamma_1, gamma_2) = &self.chunks[chunk];
+        let position = index - self.offsets[chunk];
+        Some((gamma_1[position], gamma_2[position]))
+    }
+
+    /// Concatenates the chunks into the contiguous tables `Γ_1,0` and `Γ_2,0`.
+    #[must_use]
+    pub fn to_contiguous(&self) -> (Arc<[G1Affine]>, Arc<[G2Affine]>) {
+        (
+            self.chunks
+                .iter()
+                .flat_map(|(gamma_1, _)| gamma_1.iter().copied())
+                .collect(),
+            self.chunks
+                .iter()
+                .flat_map(|(_, gamma_2)| gamma_2.iter().copied())
+                .collect(),
+        )
+    }
+
+    /// Creates public parameters from the assembled generators.
+    ///
+    /// The total number of generators must be a power of two.
+    pub fn into_public_parameters(
+        self,
+        H_1: G1Affine,
+        H_2: G2Affine,
+        Gamma_2_fin: G2Affine,
+    ) -> Result<PublicParameters, GeneratorChunksError> {
+        if !self.len.is_power_of_two() {
+            return Err(GeneratorChunksError::NotPowerOfTwo { len: self.len });
+        }
+        let (Gamma_1, Gamma_2) = self.to_contiguous();
+        Ok(PublicParameters::from_shared_generators(
+            Gamma_1,
+            Gamma_2,
+            H_1,
+            H_2,
+            Gamma_2_fin,
+        ))
+    }
+}
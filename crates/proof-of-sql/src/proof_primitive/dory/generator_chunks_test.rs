@@ -0,0 +1,154 @@
+use super::{
+    DoryCommitment, DoryProverPublicSetup, DynamicDoryCommitment, G1Affine, G2Affine,
+    GeneratorChunks, GeneratorChunksError, ProverSetup, PublicParameters,
+};
+use crate::base::commitment::{Commitment, CommittableColumn};
+use ark_ff::UniformRand;
+use ark_std::test_rng;
+
+/// Derive the same parameters as `PublicParameters::test_rand(nu, &mut test_rng())`,
+/// with the generators derived in chunks of the given lengths.
+fn chunked_test_parameters(chunk_lens: &[usize]) -> PublicParameters {
+    let rng = &mut test_rng();
+    let (H_1, H_2) = (G1Affine::rand(rng), G2Affine::rand(rng));
+    let Gamma_2_fin = G2Affine::rand(rng);
+    let mut chunks = GeneratorChunks::new();
+    for &len in chunk_lens {
+        chunks.derive_chunk(len, rng);
+    }
+    assert_eq!(chunks.len(), chunk_lens.iter().sum::<usize>());
+    chunks
+        .into_public_parameters(H_1, H_2, Gamma_2_fin)
+        .unwrap()
+}
+
+#[test]
+fn chunked_generators_are_the_same_as_generators_derived_at_once() {
+    let expected = PublicParameters::test_rand(4, &mut test_rng());
+    for chunk_lens in [&[16][..], &[1, 15], &[5, 3, 8], &[4, 0, 4, 4, 4]] {
+        let actual = chunked_test_parameters(chunk_lens);
+        assert_eq!(actual.Gamma_1, expected.Gamma_1);
+        assert_eq!(actual.Gamma_2, expected.Gamma_2);
+        assert_eq!(actual.max_nu, expected.max_nu);
+    }
+}
+
+#[test]
+fn we_can_look_up_generators_at_chunk_boundaries() {
+    let expected = PublicParameters::test_rand(4, &mut test_rng());
+    let rng = &mut test_rng();
+    let _ = (
+        G1Affine::rand(rng),
+        G2Affine::rand(rng),
+        G2Affine::rand(rng),
+    );
+    let mut chunks = GeneratorChunks::new();
+    for len in [5, 0, 3, 8] {
+        chunks.derive_chunk(len, rng);
+    }
+    assert_eq!(chunks.chunk_count(), 3);
+    for index in 0..16 {
+        assert_eq!(
+            chunks.get(index),
+            Some((expected.Gamma_1[index], expected.Gamma_2[index]))
+        );
+    }
+    assert_eq!(chunks.get(16), None);
+    assert!(GeneratorChunks::new().get(0).is_none());
+}
+
+#[test]
+fn columns_committed_with_chunked_generators_have_the_same_commitments() {
+    let expected_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let expected_setup = ProverSetup::from(&expected_parameters);
+    let chunked_parameters = chunked_test_parameters(&[5, 3, 8]);
+    let chunked_setup = ProverSetup::from(&chunked_parameters);
+    let values: Vec<i64> = (1..=40).collect();
+    // Lengths and offsets that end just before, at, and just after the chunk boundaries at 5 and 8
+    for (len, offset) in [
+        (4, 0),
+        (5, 0),
+        (6, 0),
+        (8, 0),
+        (9, 0),
+        (3, 2),
+        (3, 5),
+        (7, 1),
+        (40, 3),
+    ] {
+        let columns = [CommittableColumn::BigInt(&values[..len])];
+        for sigma in [2, 3, 4] {
+            assert_eq!(
+                DoryCommitment::compute_commitments(
+                    &columns,
+                    offset,
+                    &DoryProverPublicSetup::new(&chunked_setup, sigma)
+                ),
+                DoryCommitment::compute_commitments(
+                    &columns,
+                    offset,
+                    &DoryProverPublicSetup::new(&expected_setup, sigma)
+                ),
+            );
+        }
+        assert_eq!(
+            DynamicDoryCommitment::compute_commitments(&columns, offset, &&chunked_setup),
+            DynamicDoryCommitment::compute_commitments(&columns, offset, &&expected_setup),
+        );
+    }
+}
+
+#[test]
+fn we_cannot_push_chunks_with_gaps_overlaps_or_mismatched_lengths() {
+    let rng = &mut test_rng();
+    let gamma_1: Vec<_> = core::iter::repeat_with(|| G1Affine::rand(rng))
+        .take(3)
+        .collect();
+    let gamma_2: Vec<_> = core::iter::repeat_with(|| G2Affine::rand(rng))
+        .take(3)
+        .collect();
+    let mut chunks = GeneratorChunks::new();
+    assert_eq!(
+        chunks.push_chunk(1, gamma_1.clone(), gamma_2.clone()),
+        Err(GeneratorChunksError::OffsetMismatch {
+            expected: 0,
+            actual: 1
+        })
+    );
+    chunks
+        .push_chunk(0, gamma_1.clone(), gamma_2.clone())
+        .unwrap();
+    for offset in [2, 4] {
+        assert_eq!(
+            chunks.push_chunk(offset, gamma_1.clone(), gamma_2.clone()),
+            Err(GeneratorChunksError::OffsetMismatch {
+                expected: 3,
+                actual: offset
+            })
+        );
+    }
+    assert_eq!(
+        chunks.push_chunk(3, gamma_1[..1].to_vec(), gamma_2.clone()),
+        Err(GeneratorChunksError::LengthMismatch {
+            gamma_1_len: 1,
+            gamma_2_len: 3
+        })
+    );
+    chunks
+        .push_chunk(3, gamma_1[..1].to_vec(), gamma_2[..1].to_vec())
+        .unwrap();
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks.get(3), Some((gamma_1[0], gamma_2[0])));
+    assert_eq!(chunks.get(2), Some((gamma_1[2], gamma_2[2])));
+    chunks
+        .push_chunk(4, gamma_1[..1].to_vec(), gamma_2[..1].to_vec())
+        .unwrap();
+    assert!(matches!(
+        chunks.into_public_parameters(
+            G1Affine::rand(rng),
+            G2Affine::rand(rng),
+            G2Affine::rand(rng)
+        ),
+        Err(GeneratorChunksError::NotPowerOfTwo { len: 5 })
+    ));
+}
@@ -88,6 +88,11 @@ mod extended_dory_inner_product_test;
 mod public_parameters;
 pub use public_parameters::PublicParameters;
 
+mod generator_chunks;
+pub use generator_chunks::{GeneratorChunks, GeneratorChunksError};
+#[cfg(test)]
+mod generator_chunks_test;
+
 mod eval_vmv_re;
 pub(crate) use eval_vmv_re::{eval_vmv_re_prove, eval_vmv_re_verify};
 
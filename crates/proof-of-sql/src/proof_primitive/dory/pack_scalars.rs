@@ -402,6 +402,17 @@ pub fn bit_table_and_scalars_for_packed_msm(
                     num_matrix_commitment_columns,
                 );
             }
+            CommittableColumn::UInt64(column) => {
+                pack_bit(
+                    column,
+                    &mut packed_scalars,
+                    cumulative_bit_sum_table[i],
+                    offset,
+                    committable_columns[i].column_type().byte_size(),
+                    bit_table_full_sum_in_bytes,
+                    num_matrix_commitment_columns,
+                );
+            }
             CommittableColumn::Int128(column) => {
                 pack_bit(
                     column,
@@ -426,7 +437,8 @@ pub fn bit_table_and_scalars_for_packed_msm(
             }
             CommittableColumn::Decimal75(_, _, column)
             | CommittableColumn::Scalar(column)
-            | CommittableColumn::VarChar(column) => {
+            | CommittableColumn::VarChar(column)
+            | CommittableColumn::FixedBytes(_, column) => {
                 pack_bit(
                     column,
                     &mut packed_scalars,
@@ -1,5 +1,5 @@
 use super::{G1Affine, G2Affine};
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 use ark_ff::UniformRand;
 use ark_serialize::{
     CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
@@ -18,11 +18,14 @@ use std::{
 /// Note: even though `H_1` and `H_2` are marked as blue, they are still needed.
 ///
 /// Note: `Gamma_1_fin` is unused, so we leave it out.
+///
+/// The generators are reference counted, so cloning the parameters does not copy them.
+#[derive(Clone)]
 pub struct PublicParameters {
     /// This is the vector of G1 elements that are used in the Dory protocol. That is, `Γ_1,0` in the Dory paper.
-    pub(super) Gamma_1: Vec<G1Affine>,
+    pub(super) Gamma_1: Arc<[G1Affine]>,
     /// This is the vector of G2 elements that are used in the Dory protocol. That is, `Γ_2,0` in the Dory paper.
-    pub(super) Gamma_2: Vec<G2Affine>,
+    pub(super) Gamma_2: Arc<[G2Affine]>,
     /// `H_1` = `H_1` in the Dory paper. This could be used for blinding, but is currently only used in the Fold-Scalars algorithm.
     pub(super) H_1: G1Affine,
     /// `H_2` = `H_2` in the Dory paper. This could be used for blinding, but is currently only used in the Fold-Scalars algorithm.
@@ -45,10 +48,39 @@ impl PublicParameters {
     fn rand_impl<R: Rng + ?Sized>(max_nu: usize, rng: &mut R) -> Self {
         let (H_1, H_2) = (G1Affine::rand(rng), G2Affine::rand(rng));
         let Gamma_2_fin = G2Affine::rand(rng);
-        let (Gamma_1, Gamma_2) = iter::repeat_with(|| (G1Affine::rand(rng), G2Affine::rand(rng)))
-            .take(1 << max_nu)
-            .unzip();
+        let (Gamma_1, Gamma_2): (Vec<_>, Vec<_>) =
+            iter::repeat_with(|| (G1Affine::rand(rng), G2Affine::rand(rng)))
+                .take(1 << max_nu)
+                .unzip();
 
+        Self {
+            Gamma_1: Gamma_1.into(),
+            Gamma_2: Gamma_2.into(),
+            H_1,
+            H_2,
+            Gamma_2_fin,
+            max_nu,
+        }
+    }
+    /// Create public parameters that share an existing table of generators, without copying it.
+    ///
+    /// `Gamma_1` and `Gamma_2` are `Γ_1,0` and `Γ_2,0` in the Dory paper.
+    /// Setups derived from parameters that share generators use the same allocation for them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Gamma_1` and `Gamma_2` have different lengths, or if their length is not a power of two.
+    #[must_use]
+    pub fn from_shared_generators(
+        Gamma_1: Arc<[G1Affine]>,
+        Gamma_2: Arc<[G2Affine]>,
+        H_1: G1Affine,
+        H_2: G2Affine,
+        Gamma_2_fin: G2Affine,
+    ) -> Self {
+        assert_eq!(Gamma_1.len(), Gamma_2.len());
+        assert!(Gamma_1.len().is_power_of_two());
+        let max_nu = Gamma_1.len().trailing_zeros() as usize;
         Self {
             Gamma_1,
             Gamma_2,
@@ -58,6 +90,13 @@ impl PublicParameters {
             max_nu,
         }
     }
+    /// The shared tables of generators `Γ_1,0` and `Γ_2,0`.
+    ///
+    /// These can be passed to [`Self::from_shared_generators`] to create other parameters without copying them.
+    #[must_use]
+    pub fn shared_generators(&self) -> (Arc<[G1Affine]>, Arc<[G2Affine]>) {
+        (Arc::clone(&self.Gamma_1), Arc::clone(&self.Gamma_2))
+    }
     #[cfg(feature = "std")]
     /// Function to save `PublicParameters` to a file in binary form
     pub fn save_to_file(&self, path: &Path) -> std::io::Result<()> {
@@ -174,12 +213,12 @@ impl CanonicalDeserialize for PublicParameters {
             .map_err(|_| SerializationError::InvalidData)?;
 
         // Deserialize Gamma_1 (Vec<G1Affine>)
-        let Gamma_1: Vec<G1Affine> = (0..(1 << max_nu))
+        let Gamma_1: Arc<[G1Affine]> = (0..(1 << max_nu))
             .map(|_| G1Affine::deserialize_with_mode(&mut reader, compress, validate))
             .collect::<Result<_, _>>()?;
 
         // Deserialize Gamma_2 (Vec<G2Affine>)
-        let Gamma_2: Vec<G2Affine> = (0..(1 << max_nu))
+        let Gamma_2: Arc<[G2Affine]> = (0..(1 << max_nu))
             .map(|_| G2Affine::deserialize_with_mode(&mut reader, compress, validate))
             .collect::<Result<_, _>>()?;
 
@@ -107,6 +107,28 @@ impl<'a> ProverSetup<'a> {
         }
     }
 
+    /// Derive the matching `VerifierSetup` from this setup.
+    ///
+    /// This is equivalent to `VerifierSetup::from` on the public parameters this setup was created from.
+    /// The two setups are distinct types, so one cannot be passed where the other is expected:
+    /// ```compile_fail
+    /// # use proof_of_sql::proof_primitive::dory::{DoryVerifierPublicSetup, ProverSetup, PublicParameters};
+    /// # let public_parameters = PublicParameters::test_rand(2, &mut ark_std::test_rng());
+    /// let prover_setup = ProverSetup::from(&public_parameters);
+    /// let verifier_setup = DoryVerifierPublicSetup::new(&prover_setup, 1);
+    /// ```
+    #[must_use]
+    pub fn to_verifier_setup(&self) -> VerifierSetup {
+        VerifierSetup::new(
+            self.Gamma_1[self.max_nu],
+            self.Gamma_2[self.max_nu],
+            self.H_1,
+            self.H_2,
+            self.Gamma_2_fin,
+            self.max_nu,
+        )
+    }
+
     /// Gets the `MSMHandle` for this setup
     #[must_use]
     #[cfg(feature = "blitzar")]
@@ -1,4 +1,9 @@
-use super::{test_rng, ProverSetup, PublicParameters, VerifierSetup};
+use super::{
+    compute_dory_commitments, test_rng, DoryProverPublicSetup, ProverSetup, PublicParameters,
+    VerifierSetup,
+};
+use crate::base::commitment::CommittableColumn;
+use alloc::sync::Arc;
 use ark_ec::pairing::Pairing;
 use std::{fs, path::Path};
 
@@ -21,6 +26,56 @@ fn we_can_create_and_manually_check_a_small_prover_setup() {
     assert_eq!(setup.Gamma_2_fin, pp.Gamma_2_fin);
 }
 
+#[test]
+fn setups_from_parameters_with_shared_generators_use_one_allocation_and_agree() {
+    let pp = PublicParameters::test_rand(3, &mut test_rng());
+    let (Gamma_1, Gamma_2) = pp.shared_generators();
+    let shared_pp =
+        PublicParameters::from_shared_generators(Gamma_1, Gamma_2, pp.H_1, pp.H_2, pp.Gamma_2_fin);
+    let cloned_pp = pp.clone();
+    assert_eq!(shared_pp.max_nu, 3);
+    assert_eq!(Arc::strong_count(&pp.Gamma_1), 3);
+    assert_eq!(Arc::strong_count(&pp.Gamma_2), 3);
+    assert!(Arc::ptr_eq(&pp.Gamma_1, &shared_pp.Gamma_1));
+    assert!(Arc::ptr_eq(&pp.Gamma_2, &cloned_pp.Gamma_2));
+
+    let prover_setup = ProverSetup::from(&pp);
+    let shared_prover_setup = ProverSetup::from(&shared_pp);
+    // The prover setups borrow the generators rather than copying them.
+    assert_eq!(Arc::strong_count(&pp.Gamma_1), 3);
+    assert!(core::ptr::eq(
+        prover_setup.Gamma_1[3],
+        shared_prover_setup.Gamma_1[3]
+    ));
+
+    let columns = [
+        CommittableColumn::BigInt(&[1, -2, 3, 4, 5]),
+        CommittableColumn::Boolean(&[true, false, true]),
+    ];
+    assert_eq!(
+        compute_dory_commitments(&columns, 2, &DoryProverPublicSetup::new(&prover_setup, 2)),
+        compute_dory_commitments(
+            &columns,
+            2,
+            &DoryProverPublicSetup::new(&shared_prover_setup, 2)
+        )
+    );
+    assert_eq!(VerifierSetup::from(&pp), VerifierSetup::from(&shared_pp));
+}
+
+#[test]
+#[should_panic(expected = "is_power_of_two")]
+fn we_cannot_share_generators_whose_length_is_not_a_power_of_two() {
+    let pp = PublicParameters::test_rand(2, &mut test_rng());
+    let _ = PublicParameters::from_shared_generators(
+        pp.Gamma_1[..3].into(),
+        pp.Gamma_2[..3].into(),
+        pp.H_1,
+        pp.H_2,
+        pp.Gamma_2_fin,
+    );
+}
+
 #[test]
 fn we_can_create_save_load_and_manually_check_a_small_verifier_setup() {
     let mut rng = test_rng();
@@ -143,6 +198,16 @@ fn we_can_create_verifier_setups_with_various_sizes() {
     }
 }
 
+#[test]
+fn we_can_derive_the_verifier_setup_from_a_prover_setup() {
+    let mut rng = test_rng();
+    for nu in 0..5 {
+        let pp = PublicParameters::test_rand(nu, &mut rng);
+        let prover_setup = ProverSetup::from(&pp);
+        assert_eq!(prover_setup.to_verifier_setup(), VerifierSetup::from(&pp));
+    }
+}
+
 // nu size 1 = 3890 bytes
 // nu size 2 = 6770 bytes
 // nu size 3 = 9650 bytes
@@ -2,3 +2,4 @@
 pub mod dory;
 /// TODO: add docs
 pub(crate) mod sumcheck;
+pub use sumcheck::SumcheckError;
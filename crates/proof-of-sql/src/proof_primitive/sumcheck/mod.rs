@@ -1,13 +1,15 @@
 mod proof;
 #[cfg(test)]
 mod proof_test;
-pub use proof::SumcheckProof;
+pub use proof::{SumcheckError, SumcheckProof};
 
 mod prover_state;
 use prover_state::ProverState;
 
 mod prover_round;
 use prover_round::prove_round;
+#[cfg(all(test, feature = "rayon"))]
+mod prover_round_test;
 
 #[cfg(test)]
 mod test_cases;
@@ -16,6 +16,22 @@ use crate::{
  */
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// Errors from verifying a [`SumcheckProof`]
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum SumcheckError {
+    /// A round polynomial has a higher degree than the verifier accepts.
+    #[snafu(display(
+        "the polynomial of sumcheck round {round} has degree {degree}, which is too high"
+    ))]
+    DegreeTooHigh {
+        /// The index of the round
+        round: usize,
+        /// The degree of the round polynomial
+        degree: usize,
+    },
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SumcheckProof<S: Scalar> {
@@ -27,11 +43,30 @@ pub struct Subclaim<S: Scalar> {
 }
 
 impl<S: Scalar> SumcheckProof<S> {
-    #[tracing::instrument(name = "SumcheckProof::create", level = "debug", skip_all)]
+    /// The number of coefficients of the round polynomials, over all rounds
+    pub fn num_coefficients(&self) -> usize {
+        self.coefficients.len()
+    }
+
     pub fn create(
         transcript: &mut impl Transcript,
         evaluation_point: &mut [S],
         polynomial: &CompositePolynomial<S>,
+    ) -> Self {
+        Self::create_with_progress(transcript, evaluation_point, polynomial, |_| {})
+    }
+
+    /// Create a sumcheck proof, calling `on_round` with the index of each round before it is proven.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `evaluation_point` is not the number of variables of `polynomial`.
+    #[tracing::instrument(name = "SumcheckProof::create", level = "debug", skip_all)]
+    pub fn create_with_progress(
+        transcript: &mut impl Transcript,
+        evaluation_point: &mut [S],
+        polynomial: &CompositePolynomial<S>,
+        mut on_round: impl FnMut(usize),
     ) -> Self {
         assert_eq!(evaluation_point.len(), polynomial.num_variables);
         transcript.extend_as_be([
@@ -43,7 +78,12 @@ impl<S: Scalar> SumcheckProof<S> {
         let mut r = None;
         let mut state = ProverState::create(polynomial);
         let mut coefficients = Vec::with_capacity(polynomial.num_variables);
-        for scalar in evaluation_point.iter_mut().take(polynomial.num_variables) {
+        for (round, scalar) in evaluation_point
+            .iter_mut()
+            .take(polynomial.num_variables)
+            .enumerate()
+        {
+            on_round(round);
             let round_evaluations = prove_round(&mut state, &r);
             let round_coefficients =
                 interpolate_evaluations_to_reverse_coefficients(&round_evaluations);
@@ -56,16 +96,45 @@ impl<S: Scalar> SumcheckProof<S> {
         SumcheckProof { coefficients }
     }
 
+    /// Verify the proof up to the evaluation of the polynomial at the evaluation point,
+    /// accepting round polynomials up to the degree of `polynomial_info`.
+    ///
+    /// The proof size already fixes every round polynomial to that degree, so the degree check
+    /// only reports an oversized proof as [`SumcheckError::DegreeTooHigh`] rather than as an invalid size.
+    /// It does not reject any proof the size check would accept.
+    /// Use [`SumcheckProof::verify_without_evaluation_with_max_degree`] with a lower bound for a stricter check.
+    pub fn verify_without_evaluation(
+        &self,
+        transcript: &mut impl Transcript,
+        polynomial_info: CompositePolynomialInfo,
+        claimed_sum: &S,
+    ) -> Result<Subclaim<S>, ProofError> {
+        self.verify_without_evaluation_with_max_degree(
+            transcript,
+            polynomial_info,
+            claimed_sum,
+            polynomial_info.max_multiplicands,
+        )
+    }
+
+    /// Verify the proof up to the evaluation of the polynomial at the evaluation point,
+    /// accepting round polynomials up to `max_degree`.
+    ///
+    /// A round polynomial of a higher degree is rejected with [`SumcheckError::DegreeTooHigh`]
+    /// before any round is evaluated. This is stricter than the size check only when `max_degree`
+    /// is below the degree of `polynomial_info`.
+    /// The degree of a round polynomial is that of its highest nonzero coefficient.
     #[tracing::instrument(
         name = "SumcheckProof::verify_without_evaluation",
         level = "debug",
         skip_all
     )]
-    pub fn verify_without_evaluation(
+    pub fn verify_without_evaluation_with_max_degree(
         &self,
         transcript: &mut impl Transcript,
         polynomial_info: CompositePolynomialInfo,
         claimed_sum: &S,
+        max_degree: usize,
     ) -> Result<Subclaim<S>, ProofError> {
         transcript.extend_as_be([
             polynomial_info.max_multiplicands as u64,
@@ -73,6 +142,7 @@ impl<S: Scalar> SumcheckProof<S> {
         ]);
         // This challenge is in order to keep transcript messages grouped. (This simplifies the Solidity implementation.)
         transcript.scalar_challenge_as_be::<S>();
+        self.check_round_degrees(polynomial_info.num_variables, max_degree)?;
         if self.coefficients.len()
             != polynomial_info.num_variables * (polynomial_info.max_multiplicands + 1)
         {
@@ -112,4 +182,36 @@ impl<S: Scalar> SumcheckProof<S> {
             expected_evaluation,
         })
     }
+
+    /// Check that no round polynomial has a degree above `max_degree`.
+    ///
+    /// Every round has the same number of coefficients, with the highest degree first.
+    /// A proof whose coefficients cannot be split evenly into the rounds is left to the size check.
+    fn check_round_degrees(
+        &self,
+        num_variables: usize,
+        max_degree: usize,
+    ) -> Result<(), SumcheckError> {
+        if num_variables == 0 || self.coefficients.len() % num_variables != 0 {
+            return Ok(());
+        }
+        let num_round_coefficients = self.coefficients.len() / num_variables;
+        if num_round_coefficients <= max_degree + 1 {
+            return Ok(());
+        }
+        for (round, round_coefficients) in
+            self.coefficients.chunks(num_round_coefficients).enumerate()
+        {
+            let degree = round_coefficients
+                .iter()
+                .position(|coefficient| !coefficient.is_zero())
+                .map_or(0, |leading_index| {
+                    num_round_coefficients - 1 - leading_index
+                });
+            if degree > max_degree {
+                return Err(SumcheckError::DegreeTooHigh { round, degree });
+            }
+        }
+        Ok(())
+    }
 }
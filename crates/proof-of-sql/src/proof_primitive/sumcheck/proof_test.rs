@@ -1,7 +1,7 @@
 use super::test_cases::sumcheck_test_cases;
 use crate::base::{
     polynomial::{CompositePolynomial, CompositePolynomialInfo},
-    proof::Transcript as _,
+    proof::{ProofError, Transcript as _},
     scalar::{test_scalar::TestScalar, Curve25519Scalar, MontScalar, Scalar},
 };
 /*
@@ -246,3 +246,82 @@ fn we_can_verify_many_random_test_cases() {
         );
     }
 }
+
+#[test]
+fn we_can_reject_round_polynomials_with_an_inflated_degree() {
+    let nv = 3;
+    let mut rng = <ark_std::rand::rngs::StdRng as ark_std::rand::SeedableRng>::from_seed([0u8; 32]);
+    let (poly, asserted_sum) = random_polynomial(nv, (2, 3), 3, &mut rng);
+    let poly_info = poly.info();
+    let mut transcript = Transcript::new(b"sumchecktest");
+    let mut evaluation_point = vec![Curve25519Scalar::zero(); nv];
+    let proof = SumcheckProof::create(&mut transcript, &mut evaluation_point, &poly);
+
+    // A well-formed proof passes under the degree of the polynomial.
+    let mut transcript = Transcript::new(b"sumchecktest");
+    assert!(proof
+        .verify_without_evaluation_with_max_degree(
+            &mut transcript,
+            poly_info,
+            &asserted_sum,
+            poly_info.max_multiplicands,
+        )
+        .is_ok());
+
+    // A configured bound below the degree of the polynomial rejects the first round.
+    let mut transcript = Transcript::new(b"sumchecktest");
+    assert!(matches!(
+        proof.verify_without_evaluation_with_max_degree(
+            &mut transcript,
+            poly_info,
+            &asserted_sum,
+            poly_info.max_multiplicands - 1,
+        ),
+        Err(ProofError::SumcheckError {
+            source: SumcheckError::DegreeTooHigh { round: 0, degree }
+        }) if degree == poly_info.max_multiplicands
+    ));
+
+    // Every round gets an extra leading coefficient, which is nonzero only in the second round.
+    let num_round_coefficients = poly_info.max_multiplicands + 1;
+    let inflated = SumcheckProof {
+        coefficients: proof
+            .coefficients
+            .chunks(num_round_coefficients)
+            .enumerate()
+            .flat_map(|(round, round_coefficients)| {
+                let leading = if round == 1 {
+                    Curve25519Scalar::one()
+                } else {
+                    Curve25519Scalar::zero()
+                };
+                core::iter::once(leading).chain(round_coefficients.iter().copied())
+            })
+            .collect(),
+    };
+    let mut transcript = Transcript::new(b"sumchecktest");
+    assert!(matches!(
+        inflated.verify_without_evaluation(&mut transcript, poly_info, &asserted_sum),
+        Err(ProofError::SumcheckError {
+            source: SumcheckError::DegreeTooHigh { round: 1, degree }
+        }) if degree == poly_info.max_multiplicands + 1
+    ));
+
+    // Leading zeros do not raise the degree, but the proof still has the wrong size.
+    let zero_padded = SumcheckProof {
+        coefficients: proof
+            .coefficients
+            .chunks(num_round_coefficients)
+            .flat_map(|round_coefficients| {
+                core::iter::once(Curve25519Scalar::zero()).chain(round_coefficients.iter().copied())
+            })
+            .collect(),
+    };
+    let mut transcript = Transcript::new(b"sumchecktest");
+    assert!(matches!(
+        zero_padded.verify_without_evaluation(&mut transcript, poly_info, &asserted_sum),
+        Err(ProofError::VerificationError {
+            error: "invalid proof size"
+        })
+    ));
+}
@@ -3,6 +3,8 @@
  *
  * See third_party/license/arkworks.LICENSE
  */
+#[cfg(feature = "rayon")]
+use crate::base::slice_ops::MIN_RAYON_LEN;
 use crate::{
     base::{if_rayon, scalar::Scalar},
     proof_primitive::sumcheck::ProverState,
@@ -11,8 +13,30 @@ use alloc::{vec, vec::Vec};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
+/// Rounds with fewer rows than this are evaluated on a single thread,
+/// since the overhead of splitting the work outweighs the gain for small instances.
+#[cfg(feature = "rayon")]
+pub const MIN_PARALLEL_ROUND_LENGTH: usize = 1 << 10;
+
 #[tracing::instrument(level = "debug", skip_all)]
 pub fn prove_round<S: Scalar>(prover_state: &mut ProverState<S>, r_maybe: &Option<S>) -> Vec<S> {
+    advance_round(prover_state, r_maybe);
+    if_rayon!(
+        if round_length(prover_state) < MIN_PARALLEL_ROUND_LENGTH {
+            sequential_round_evaluations(prover_state)
+        } else {
+            parallel_round_evaluations(prover_state)
+        },
+        sequential_round_evaluations(prover_state)
+    )
+}
+
+/// Fix the variable of the previous round to `r_maybe` and move on to the next round.
+///
+/// # Panics
+/// Panics if a verifier message is given in the first round, or missing in a later one,
+/// or if all rounds have already been proven.
+pub(super) fn advance_round<S: Scalar>(prover_state: &mut ProverState<S>, r_maybe: &Option<S>) {
     if let Some(r) = r_maybe {
         assert!(
             prover_state.round != 0,
@@ -44,64 +68,92 @@ pub fn prove_round<S: Scalar>(prover_state: &mut ProverState<S>, r_maybe: &Optio
         prover_state.round <= prover_state.num_vars,
         "Prover is not active"
     );
+}
+
+/// The number of points of the boolean hypercube summed over in the current round
+fn round_length<S: Scalar>(prover_state: &ProverState<S>) -> usize {
+    1usize << (prover_state.num_vars - prover_state.round)
+}
+
+// The pseudocode of what the round evaluations are trying to do is:
+
+// foreach t in 0..=degree compute
+//   sum over row in 0..round_length:
+//     sum over product in list_of_products:
+//       product over multiplicand in product:
+//         table = the mle of the multiplicand
+//         table[2b] * (1-t) + table[2b+1] * t
+// This gives a vector of length degree + 1
 
+// The order of these loops is changed for the purpose of efficiency.
+// The outer loop is the loop over all products in the list_of_products,
+// and the second loop is the loop over the row (b) in 0..round_length.
+
+/// Evaluate the round polynomial of the current round at `0..=degree` on a single thread.
+pub(super) fn sequential_round_evaluations<S: Scalar>(prover_state: &ProverState<S>) -> Vec<S> {
     let degree = prover_state.max_multiplicands; // the degree of univariate polynomial sent by prover at this round
-    let round_length = 1usize << (prover_state.num_vars - prover_state.round);
+    prover_state
+        .list_of_products
+        .iter()
+        .map(|(coefficient, multiplicand_indices)| {
+            (0..round_length(prover_state))
+                .map(|b| row_products(prover_state, *coefficient, multiplicand_indices, b))
+                .fold(vec![S::zero(); degree + 1], vec_elementwise_add)
+        })
+        .fold(vec![S::zero(); degree + 1], vec_elementwise_add)
+}
 
-    // The pseudocode of what this is trying to do is:
+/// Evaluate the round polynomial of the current round at `0..=degree`, splitting the work across threads.
+///
+/// Since field addition is associative and commutative, the result is identical to that of
+/// [`sequential_round_evaluations`].
+#[cfg(feature = "rayon")]
+pub(super) fn parallel_round_evaluations<S: Scalar>(prover_state: &ProverState<S>) -> Vec<S> {
+    let degree = prover_state.max_multiplicands; // the degree of univariate polynomial sent by prover at this round
+    prover_state
+        .list_of_products
+        .par_iter()
+        .map(|(coefficient, multiplicand_indices)| {
+            (0..round_length(prover_state))
+                .into_par_iter()
+                .with_min_len(MIN_RAYON_LEN)
+                .map(|b| row_products(prover_state, *coefficient, multiplicand_indices, b))
+                .reduce(|| vec![S::zero(); degree + 1], vec_elementwise_add)
+        })
+        .reduce(|| vec![S::zero(); degree + 1], vec_elementwise_add)
+}
 
-    // foreach t in 0..=degree compute
-    //   sum over row in 0..round_length:
-    //     sum over product in list_of_products:
-    //       product over multiplicand in product:
-    //         table = the mle of the multiplicand
-    //         table[2b] * (1-t) + table[2b+1] * t
-    // This gives a vector of length degree + 1
+/// The evaluations at `0..=degree` of the contribution of a single product to row `b` of the round polynomial
+fn row_products<S: Scalar>(
+    prover_state: &ProverState<S>,
+    coefficient: S,
+    multiplicand_indices: &[usize],
+    b: usize,
+) -> Vec<S> {
+    let degree = prover_state.max_multiplicands;
+    // We add a vector of products, which takes a bit of extra memory. The reason for this is for the efficient modification described below
+    let mut products = vec![coefficient; degree + 1];
 
-    // The order of these loops is changed for the purpose of efficiency.
+    // The third loop is the loop over the factors/multiplicand in the product term.
+    for &multiplicand_index in multiplicand_indices {
+        let table = &prover_state.flattened_ml_extensions[multiplicand_index];
 
-    // The outer loop is the loop over all products in the list_of_products
-    let sums_iter = if_rayon!(
-        prover_state.list_of_products.par_iter(),
-        prover_state.list_of_products.iter()
-    )
-    .map(|(coefficient, multiplicand_indices)| {
-        // The second loop is the loop over the row (b) in 0..round_length
-        let products_iter =
-            if_rayon!((0..round_length).into_par_iter(), 0..round_length).map(|b| {
-                // We add a vector of products, which takes a bit of extra memory. The reason for this is for the efficient modification described below
-                let mut products = vec![*coefficient; degree + 1];
-
-                // The third loop is the loop over the factors/multiplicand in the product term.
-                for &multiplicand_index in multiplicand_indices {
-                    let table = &prover_state.flattened_ml_extensions[multiplicand_index];
-
-                    // This third+final loop give an efficient way of computing
-                    // products[t] *= table[b << 1] * (S::one() - t_as_field) + table[(b << 1) + 1] * t_as_field;
-                    // It requires only 1 addition (plus the cumulative multiplication) to accomplish the same task.
-                    // It relies on the fact that
-                    // table[b << 1] * (S::one() - t_as_field) + table[(b << 1) + 1] * t_as_field == table[b << 1] + t * diff
-                    let mut start = table[b << 1];
-                    let step = table[(b << 1) + 1] - start;
-
-                    // The innermost loop loops over the values (t) that we are evaluating at.
-                    products.iter_mut().take(degree).for_each(|product| {
-                        *product *= start;
-                        start += step;
-                    });
-                    products[degree] *= start;
-                }
-                products
-            });
-        if_rayon!(
-            products_iter.reduce(|| vec![S::zero(); degree + 1], vec_elementwise_add),
-            products_iter.fold(vec![S::zero(); degree + 1], vec_elementwise_add)
-        )
-    });
-    if_rayon!(
-        sums_iter.reduce(|| vec![S::zero(); degree + 1], vec_elementwise_add),
-        sums_iter.fold(vec![S::zero(); degree + 1], vec_elementwise_add)
-    )
+        // This third+final loop give an efficient way of computing
+        // products[t] *= table[b << 1] * (S::one() - t_as_field) + table[(b << 1) + 1] * t_as_field;
+        // It requires only 1 addition (plus the cumulative multiplication) to accomplish the same task.
+        // It relies on the fact that
+        // table[b << 1] * (S::one() - t_as_field) + table[(b << 1) + 1] * t_as_field == table[b << 1] + t * diff
+        let mut start = table[b << 1];
+        let step = table[(b << 1) + 1] - start;
+
+        // The innermost loop loops over the values (t) that we are evaluating at.
+        products.iter_mut().take(degree).for_each(|product| {
+            *product *= start;
+            start += step;
+        });
+        products[degree] *= start;
+    }
+    products
 }
 
 /// This is equivalent to
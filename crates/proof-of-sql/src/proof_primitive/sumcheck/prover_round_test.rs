@@ -0,0 +1,70 @@
+use super::{
+    prover_round::{
+        advance_round, parallel_round_evaluations, prove_round, sequential_round_evaluations,
+        MIN_PARALLEL_ROUND_LENGTH,
+    },
+    test_cases::sumcheck_test_cases,
+    ProverState,
+};
+use crate::base::{
+    polynomial::CompositePolynomial,
+    scalar::{test_scalar::TestScalar, Scalar},
+};
+use ark_std::UniformRand;
+
+/// Run every round of sumcheck on `polynomial`, checking that both ways of evaluating a round agree.
+fn assert_parallel_and_sequential_rounds_agree(
+    polynomial: &CompositePolynomial<TestScalar>,
+    rng: &mut impl ark_std::rand::Rng,
+) {
+    let mut state = ProverState::create(polynomial);
+    let mut prover_state = ProverState::create(polynomial);
+    let mut r = None;
+    for _ in 0..polynomial.num_variables {
+        advance_round(&mut state, &r);
+        let sequential = sequential_round_evaluations(&state);
+        assert_eq!(parallel_round_evaluations(&state), sequential);
+        assert_eq!(prove_round(&mut prover_state, &r), sequential);
+        r = Some(TestScalar::rand(rng));
+    }
+}
+
+#[test]
+fn parallel_and_sequential_round_evaluations_agree_on_the_sumcheck_test_cases() {
+    let mut rng = ark_std::test_rng();
+    for test_case in sumcheck_test_cases::<TestScalar>(&mut rng) {
+        assert_parallel_and_sequential_rounds_agree(
+            &test_case.polynomial,
+            &mut ark_std::test_rng(),
+        );
+    }
+}
+
+#[test]
+fn parallel_and_sequential_round_evaluations_agree_above_the_parallel_threshold() {
+    let mut rng = ark_std::test_rng();
+    let num_vars = MIN_PARALLEL_ROUND_LENGTH.trailing_zeros() as usize + 2;
+    for (max_multiplicands, products) in [
+        (1, vec![vec![0]]),
+        (3, vec![vec![0, 1, 2], vec![3], vec![], vec![1, 3]]),
+    ] {
+        let num_multiplicands = 4;
+        let polynomial = CompositePolynomial::<TestScalar>::rand(
+            num_vars,
+            max_multiplicands,
+            core::iter::repeat(1 << num_vars).take(num_multiplicands),
+            products,
+            &mut rng,
+        );
+        assert_parallel_and_sequential_rounds_agree(&polynomial, &mut rng);
+    }
+}
+
+#[test]
+fn we_get_zero_round_evaluations_for_a_polynomial_without_products() {
+    let polynomial = CompositePolynomial::<TestScalar>::new(3);
+    let mut state = ProverState::create(&polynomial);
+    advance_round(&mut state, &None);
+    assert_eq!(sequential_round_evaluations(&state), [TestScalar::ZERO]);
+    assert_eq!(parallel_round_evaluations(&state), [TestScalar::ZERO]);
+}
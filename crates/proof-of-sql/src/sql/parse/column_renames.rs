@@ -0,0 +1,167 @@
+use crate::base::{database::TableRef, map::IndexMap};
+use alloc::boxed::Box;
+use proof_of_sql_parser::{
+    intermediate_ast::{
+        AliasedResultExpr, Expression, OrderBy, SelectResultExpr, SetExpression, TableExpression,
+    },
+    Identifier, ResourceId, SelectStatement,
+};
+
+/// Renames of committed columns, so that queries written against an older schema can still be planned.
+///
+/// Every rename maps a column name of the older schema to the name the column is committed under now.
+/// A query is planned by replacing every reference to an old name by the current name, so a query using
+/// the old names has the same plan, and therefore the same proof, as the equivalent query using the current names.
+/// The commitments are unchanged, since the underlying columns are the same.
+///
+/// A result column keeps the name the query gives it, so `SELECT old_name FROM t` still returns a column named `old_name`.
+/// A wildcard expands to the current names. Renames are not applied transitively: an old name always maps to the
+/// current name, even if some other column was once called that.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ColumnRenames {
+    renames: IndexMap<TableRef, IndexMap<Identifier, Identifier>>,
+}
+
+impl ColumnRenames {
+    /// Creates an empty set of renames, which leaves every query unchanged.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the rename of column `old_name` of `table_ref` to `current_name`.
+    ///
+    /// A later rename of the same old name replaces the earlier one.
+    #[must_use]
+    pub fn with_rename(
+        mut self,
+        table_ref: TableRef,
+        old_name: Identifier,
+        current_name: Identifier,
+    ) -> Self {
+        self.renames
+            .entry(table_ref)
+            .or_default()
+            .insert(old_name, current_name);
+        self
+    }
+
+    /// The name that column `column_id` of `table_ref` is committed under now
+    #[must_use]
+    pub fn current_name(&self, table_ref: TableRef, column_id: Identifier) -> Identifier {
+        self.renames
+            .get(&table_ref)
+            .and_then(|renames| renames.get(&column_id))
+            .copied()
+            .unwrap_or(column_id)
+    }
+
+    /// Rewrites a query that may use old column names into the equivalent query using the current names.
+    ///
+    /// The `ORDER BY` clause refers to result columns rather than table columns, so it is left unchanged.
+    #[must_use]
+    pub fn apply(&self, query: SelectStatement, default_schema: Identifier) -> SelectStatement {
+        let SetExpression::Query {
+            result_exprs,
+            from,
+            where_expr,
+            group_by,
+            having,
+        } = *query.expr;
+        let table_ref = match from.as_slice() {
+            [table] => match **table {
+                TableExpression::Named { table, schema } => Some(TableRef::new(ResourceId::new(
+                    schema.unwrap_or(default_schema),
+                    table,
+                ))),
+            },
+            _ => None,
+        };
+        let rename = |column_id: Identifier| {
+            table_ref.map_or(column_id, |table_ref| {
+                self.current_name(table_ref, column_id)
+            })
+        };
+        let result_exprs = result_exprs
+            .into_iter()
+            .map(|result_expr| match result_expr {
+                SelectResultExpr::AliasedResultExpr(AliasedResultExpr { expr, alias }) => {
+                    SelectResultExpr::AliasedResultExpr(AliasedResultExpr::new(
+                        rename_columns(*expr, &rename),
+                        alias,
+                    ))
+                }
+                SelectResultExpr::ALL | SelectResultExpr::QualifiedAll(_) => result_expr,
+            })
+            .collect();
+        let rename_boxed = |expr: Box<Expression>| Box::new(rename_columns(*expr, &rename));
+        SelectStatement {
+            expr: Box::new(SetExpression::Query {
+                result_exprs,
+                from,
+                where_expr: where_expr.map(rename_boxed),
+                group_by: group_by.into_iter().map(rename).collect(),
+                having: having.map(rename_boxed),
+            }),
+            ..query
+        }
+    }
+}
+
+/// Replace every column in `expr` by its name under `rename`
+///
+/// Subqueries are left unchanged, since they may select from other tables.
+fn rename_columns(expr: Expression, rename: &impl Fn(Identifier) -> Identifier) -> Expression {
+    let rename_boxed = |expr: Box<Expression>| Box::new(rename_columns(*expr, rename));
+    match expr {
+        Expression::Column(column_id) => Expression::Column(rename(column_id)),
+        Expression::RowNumber(OrderBy { expr, direction }) => Expression::RowNumber(OrderBy {
+            expr: rename(expr),
+            direction,
+        }),
+        Expression::Shift {
+            direction,
+            column,
+            order_by,
+        } => Expression::Shift {
+            direction,
+            column: rename(column),
+            order_by: OrderBy {
+                expr: rename(order_by.expr),
+                direction: order_by.direction,
+            },
+        },
+        Expression::Literal(_)
+        | Expression::Placeholder(_)
+        | Expression::Wildcard
+        | Expression::Exists(_)
+        | Expression::ScalarSubquery(_) => expr,
+        Expression::Unary { op, expr } => Expression::Unary {
+            op,
+            expr: rename_boxed(expr),
+        },
+        Expression::Binary { op, left, right } => Expression::Binary {
+            op,
+            left: rename_boxed(left),
+            right: rename_boxed(right),
+        },
+        Expression::Aggregation { op, expr, filter } => Expression::Aggregation {
+            op,
+            expr: rename_boxed(expr),
+            filter: filter.map(rename_boxed),
+        },
+        Expression::DateTrunc { unit, expr } => Expression::DateTrunc {
+            unit,
+            expr: rename_boxed(expr),
+        },
+        Expression::Case {
+            condition,
+            then_result,
+            else_result,
+        } => Expression::Case {
+            condition: rename_boxed(condition),
+            then_result: rename_boxed(then_result),
+            else_result: rename_boxed(else_result),
+        },
+    }
+}
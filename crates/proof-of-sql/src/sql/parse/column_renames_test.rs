@@ -0,0 +1,154 @@
+use super::{ColumnRenames, QueryExpr};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TableRef,
+            TestSchemaAccessor,
+        },
+        map::indexmap,
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, ProverSetup, PublicParameters,
+    },
+    sql::proof::QueryProof,
+};
+use proof_of_sql_parser::{sql::SelectStatementParser, Identifier, SelectStatement};
+
+fn parse(sql: &str) -> SelectStatement {
+    SelectStatementParser::new().parse(sql).unwrap()
+}
+
+fn accessor() -> TestSchemaAccessor {
+    TestSchemaAccessor::new(indexmap! {
+        "sxt.t".parse().unwrap() => indexmap! {
+            "amount".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::BigInt,
+            "c".parse().unwrap() => ColumnType::VarChar,
+        },
+        "sxt.u".parse().unwrap() => indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    })
+}
+
+/// `t.a` was renamed to `t.amount`, and `t.name` to `t.c`
+fn renames() -> ColumnRenames {
+    let t: TableRef = "sxt.t".parse().unwrap();
+    ColumnRenames::new()
+        .with_rename(t, "a".parse().unwrap(), "amount".parse().unwrap())
+        .with_rename(t, "name".parse().unwrap(), "c".parse().unwrap())
+}
+
+fn plan_with_renames(sql: &str, renames: &ColumnRenames) -> QueryExpr {
+    QueryExpr::try_new_with_column_renames(parse(sql), "sxt".parse().unwrap(), &accessor(), renames)
+        .unwrap()
+}
+
+fn plan(sql: &str) -> QueryExpr {
+    QueryExpr::try_new(parse(sql), "sxt".parse().unwrap(), &accessor()).unwrap()
+}
+
+#[test]
+fn we_can_plan_a_query_using_old_column_names_as_the_query_using_the_current_names() {
+    let renames = renames();
+    assert_eq!(
+        plan_with_renames("select a, name from t where a > 3 and b = 1", &renames),
+        plan("select amount as a, c as name from t where amount > 3 and b = 1")
+    );
+    assert_eq!(
+        plan_with_renames(
+            "select name, sum(a * 2) as total, count(*) as n from sxt.t where name = 'x' group by name order by name",
+            &renames
+        ),
+        plan(
+            "select c as name, sum(amount * 2) as total, count(*) as n from sxt.t where c = 'x' group by c order by name"
+        )
+    );
+    assert_eq!(
+        plan_with_renames("select * from t where a = 1", &renames),
+        plan("select * from t where amount = 1")
+    );
+    // Old and current names can be mixed in one query.
+    assert_eq!(
+        plan_with_renames("select amount, b from t where a = 1", &renames),
+        plan("select amount, b from t where amount = 1")
+    );
+}
+
+#[test]
+fn renames_only_apply_to_the_table_they_are_for() {
+    let renames = renames();
+    assert_eq!(
+        plan_with_renames("select a from u where a = 1", &renames),
+        plan("select a from u where a = 1")
+    );
+    assert_eq!(
+        plan_with_renames("select amount from t where b = 1", &ColumnRenames::new()),
+        plan("select amount from t where b = 1")
+    );
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let id = |name: &str| -> Identifier { name.parse().unwrap() };
+    assert_eq!(renames.current_name(t, id("a")), id("amount"));
+    assert_eq!(renames.current_name(t, id("b")), id("b"));
+    assert_eq!(
+        renames.current_name("sxt.u".parse().unwrap(), id("a")),
+        id("a")
+    );
+}
+
+#[test]
+fn a_query_using_old_column_names_has_the_same_proof_as_the_query_using_the_current_names() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("amount", [1_i64, 5, 3, 7]),
+            bigint("b", [1_i64, 1, 0, 1]),
+        ]),
+        0,
+        setup,
+    );
+    let renames =
+        ColumnRenames::new().with_rename(t, "a".parse().unwrap(), "amount".parse().unwrap());
+    let old_name_plan = QueryExpr::try_new_with_column_renames(
+        parse("select a from t where a > 2 and b = 1"),
+        "sxt".parse().unwrap(),
+        &accessor,
+        &renames,
+    )
+    .unwrap();
+    let current_name_plan = QueryExpr::try_new(
+        parse("select amount as a from t where amount > 2 and b = 1"),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (old_name_proof, old_name_result) =
+        QueryProof::<DoryEvaluationProof>::try_new(old_name_plan.proof_expr(), &accessor, &setup)
+            .unwrap();
+    let (current_proof, current_result) = QueryProof::<DoryEvaluationProof>::try_new(
+        current_name_plan.proof_expr(),
+        &accessor,
+        &setup,
+    )
+    .unwrap();
+    assert_eq!(
+        postcard::to_allocvec(&old_name_proof).unwrap(),
+        postcard::to_allocvec(&current_proof).unwrap()
+    );
+    assert_eq!(
+        postcard::to_allocvec(&old_name_result).unwrap(),
+        postcard::to_allocvec(&current_result).unwrap()
+    );
+
+    // Without the rename, the old name does not resolve.
+    assert!(QueryExpr::try_new(
+        parse("select a from t where a > 2 and b = 1"),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .is_err());
+}
@@ -1,13 +1,14 @@
 use super::ConversionError;
 use crate::{
     base::{
-        database::{ColumnRef, LiteralValue},
+        database::{ColumnRef, ColumnType, LiteralValue, Table, TableOptions},
         map::IndexMap,
         math::{
             decimal::{DecimalError, Precision},
             i256::I256,
             BigDecimalExt,
         },
+        scalar::Curve25519Scalar,
     },
     sql::{
         parse::{
@@ -18,6 +19,7 @@ use crate::{
     },
 };
 use alloc::{borrow::ToOwned, boxed::Box, format, string::ToString};
+use bumpalo::Bump;
 use proof_of_sql_parser::{
     intermediate_ast::{AggregationOperator, Expression, Literal},
     posql_time::{PoSQLTimeUnit, PoSQLTimestampError},
@@ -64,7 +66,21 @@ impl DynProofExprBuilder<'_> {
                 self.visit_binary_expr(&(*op).into(), left, right)
             }
             Expression::Unary { op, expr } => self.visit_unary_expr((*op).into(), expr),
-            Expression::Aggregation { op, expr } => self.visit_aggregate_expr(*op, expr),
+            Expression::Aggregation { op, expr, filter } => {
+                self.visit_aggregate_expr(*op, expr, filter.as_deref())
+            }
+            Expression::DateTrunc { unit, expr } => {
+                DynProofExpr::try_new_date_trunc(self.visit_expr(expr)?, *unit)
+            }
+            Expression::Case {
+                condition,
+                then_result,
+                else_result,
+            } => DynProofExpr::try_new_case(
+                self.visit_expr(condition)?,
+                self.visit_expr(then_result)?,
+                self.visit_expr(else_result)?,
+            ),
             _ => Err(ConversionError::Unprovable {
                 error: format!("Expression {expr:?} is not supported yet"),
             }),
@@ -86,6 +102,7 @@ impl DynProofExprBuilder<'_> {
         match lit {
             Literal::Boolean(b) => Ok(DynProofExpr::new_literal(LiteralValue::Boolean(*b))),
             Literal::BigInt(i) => Ok(DynProofExpr::new_literal(LiteralValue::BigInt(*i))),
+            Literal::UInt64(i) => Ok(DynProofExpr::new_literal(LiteralValue::UInt64(*i))),
             Literal::Int128(i) => Ok(DynProofExpr::new_literal(LiteralValue::Int128(*i))),
             Literal::Decimal(d) => {
                 let raw_scale = d.scale();
@@ -107,6 +124,12 @@ impl DynProofExprBuilder<'_> {
                 )))
             }
             Literal::VarChar(s) => Ok(DynProofExpr::new_literal(LiteralValue::VarChar(s.clone()))),
+            Literal::FixedBytes(bytes) if bytes.len() <= u8::MAX.into() => Ok(
+                DynProofExpr::new_literal(LiteralValue::FixedBytes(bytes.clone())),
+            ),
+            Literal::FixedBytes(_) => Err(ConversionError::InvalidExpression {
+                expression: "binary literals can be at most 255 bytes long".to_string(),
+            }),
             Literal::Timestamp(its) => {
                 let timestamp = match its.timeunit() {
                     PoSQLTimeUnit::Nanosecond => {
@@ -138,7 +161,23 @@ impl DynProofExprBuilder<'_> {
     ) -> Result<DynProofExpr, ConversionError> {
         let expr = self.visit_expr(expr);
         match op {
-            UnaryOperator::Not => DynProofExpr::try_new_not(expr?),
+            UnaryOperator::Not => {
+                let expr = expr?;
+                match boolean_literal(&expr) {
+                    Some(value) => Ok(DynProofExpr::new_literal(LiteralValue::Boolean(!value))),
+                    None => DynProofExpr::try_new_not(expr),
+                }
+            }
+            UnaryOperator::Minus => {
+                let expr = expr?;
+                let foldable = is_literal(&expr);
+                Ok(fold_if(foldable, DynProofExpr::try_new_neg(expr)?))
+            }
+            UnaryOperator::PGAbs => {
+                let expr = expr?;
+                let foldable = is_literal(&expr);
+                Ok(fold_if(foldable, DynProofExpr::try_new_abs(expr)?))
+            }
             // Handle unsupported operators
             _ => Err(ConversionError::UnsupportedOperation {
                 message: format!("{op:?}"),
@@ -156,46 +195,59 @@ impl DynProofExprBuilder<'_> {
             BinaryOperator::And => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_and(left?, right?)
+                fold_logical(left?, right?, false, DynProofExpr::try_new_and)
             }
             BinaryOperator::Or => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_or(left?, right?)
+                fold_logical(left?, right?, true, DynProofExpr::try_new_or)
             }
             BinaryOperator::Eq => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_equals(left?, right?)
+                fold_binary(left?, right?, DynProofExpr::try_new_equals)
             }
             BinaryOperator::GtEq => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_inequality(left?, right?, false)
+                fold_binary(left?, right?, |lhs, rhs| {
+                    DynProofExpr::try_new_inequality(lhs, rhs, false)
+                })
             }
             BinaryOperator::LtEq => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_inequality(left?, right?, true)
+                fold_binary(left?, right?, |lhs, rhs| {
+                    DynProofExpr::try_new_inequality(lhs, rhs, true)
+                })
             }
             BinaryOperator::Plus => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_add(left?, right?)
+                fold_binary(left?, right?, DynProofExpr::try_new_add)
             }
             BinaryOperator::Minus => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_subtract(left?, right?)
+                fold_binary(left?, right?, DynProofExpr::try_new_subtract)
             }
             BinaryOperator::Multiply => {
                 let left = self.visit_expr(left);
                 let right = self.visit_expr(right);
-                DynProofExpr::try_new_multiply(left?, right?)
+                fold_binary(left?, right?, DynProofExpr::try_new_multiply)
+            }
+            BinaryOperator::Modulo => {
+                let left = self.visit_expr(left);
+                let right = self.visit_expr(right);
+                fold_binary(left?, right?, DynProofExpr::try_new_modulo)
+            }
+            // VarChar columns are committed to by their hashes, so a concatenation cannot be
+            // proven and is instead computed from its proven operands after verification.
+            BinaryOperator::Divide | BinaryOperator::StringConcat => {
+                Err(ConversionError::Unprovable {
+                    error: format!("Binary operator {op:?} is not supported at this location"),
+                })
             }
-            BinaryOperator::Divide => Err(ConversionError::Unprovable {
-                error: format!("Binary operator {op:?} is not supported at this location"),
-            }),
             _ => {
                 // Handle unsupported binary operations
                 Err(ConversionError::UnsupportedOperation {
@@ -209,13 +261,18 @@ impl DynProofExprBuilder<'_> {
         &self,
         op: AggregationOperator,
         expr: &Expression,
+        filter: Option<&Expression>,
     ) -> Result<DynProofExpr, ConversionError> {
         if self.in_agg_scope {
             return Err(ConversionError::InvalidExpression {
                 expression: "nested aggregations are invalid".to_string(),
             });
         }
-        let expr = DynProofExprBuilder::new_agg(self.column_mapping).visit_expr(expr)?;
+        let builder = DynProofExprBuilder::new_agg(self.column_mapping);
+        if let Some(filter) = filter {
+            return builder.visit_filtered_aggregate_expr(op, expr, filter);
+        }
+        let expr = builder.visit_expr(expr)?;
         match (op, expr.data_type().is_numeric()) {
             (AggregationOperator::Count, _) | (AggregationOperator::Sum, true) => {
                 Ok(DynProofExpr::new_aggregate(op, expr))
@@ -230,4 +287,156 @@ impl DynProofExprBuilder<'_> {
             }),
         }
     }
+
+    /// Builds `op(expr) FILTER (WHERE filter)` as a sum over the rows where `filter` holds.
+    ///
+    /// The summed expression is `CASE WHEN filter THEN expr ELSE 0 END`, or `CASE WHEN filter THEN 1 ELSE 0 END`
+    /// for a count, so rows that fail the filter are dropped from this aggregation alone.
+    fn visit_filtered_aggregate_expr(
+        &self,
+        op: AggregationOperator,
+        expr: &Expression,
+        filter: &Expression,
+    ) -> Result<DynProofExpr, ConversionError> {
+        let condition = self.visit_expr(filter)?;
+        let (then_expr, else_expr) = match op {
+            AggregationOperator::Count => (
+                DynProofExpr::new_literal(LiteralValue::BigInt(1)),
+                DynProofExpr::new_literal(LiteralValue::BigInt(0)),
+            ),
+            AggregationOperator::Sum => {
+                let expr = self.visit_expr(expr)?;
+                let zero = zero_literal(expr.data_type()).ok_or_else(|| {
+                    ConversionError::InvalidExpression {
+                        expression: format!(
+                            "Aggregation operator {op:?} doesn't work with non-numeric types"
+                        ),
+                    }
+                })?;
+                (expr, DynProofExpr::new_literal(zero))
+            }
+            _ => {
+                return Err(ConversionError::Unprovable {
+                    error: format!(
+                        "Aggregation operator {op:?} is not supported with a FILTER clause"
+                    ),
+                })
+            }
+        };
+        Ok(DynProofExpr::new_aggregate(
+            AggregationOperator::Sum,
+            DynProofExpr::try_new_case(condition, then_expr, else_expr)?,
+        ))
+    }
+}
+
+/// The zero of a numeric type, or `None` if the type is not numeric.
+fn zero_literal(data_type: ColumnType) -> Option<LiteralValue> {
+    match data_type {
+        ColumnType::TinyInt => Some(LiteralValue::TinyInt(0)),
+        ColumnType::SmallInt => Some(LiteralValue::SmallInt(0)),
+        ColumnType::Int => Some(LiteralValue::Int(0)),
+        ColumnType::BigInt => Some(LiteralValue::BigInt(0)),
+        ColumnType::UInt64 => Some(LiteralValue::UInt64(0)),
+        ColumnType::Int128 => Some(LiteralValue::Int128(0)),
+        ColumnType::Decimal75(precision, scale) => {
+            Some(LiteralValue::Decimal75(precision, scale, I256::from(0)))
+        }
+        ColumnType::Scalar => Some(LiteralValue::Scalar([0; 4])),
+        _ => None,
+    }
+}
+
+/// The value of `expr` if it is a boolean literal.
+fn boolean_literal(expr: &DynProofExpr) -> Option<bool> {
+    match expr {
+        DynProofExpr::Literal(literal) => match literal.value() {
+            LiteralValue::Boolean(value) => Some(*value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Folds an `AND` or `OR` with a boolean literal operand into one of its operands.
+///
+/// `absorbing` is the literal that decides the result on its own, `false` for `AND` and `true` for `OR`.
+/// The other literal leaves the other operand unchanged. This way `WHERE TRUE AND <expr>` is proven
+/// as `WHERE <expr>`, and a predicate built only from literals becomes a single literal.
+/// Operands that are not boolean are left to `new`, which rejects them.
+fn fold_logical(
+    lhs: DynProofExpr,
+    rhs: DynProofExpr,
+    absorbing: bool,
+    new: fn(DynProofExpr, DynProofExpr) -> Result<DynProofExpr, ConversionError>,
+) -> Result<DynProofExpr, ConversionError> {
+    let is_boolean = |expr: &DynProofExpr| expr.data_type() == ColumnType::Boolean;
+    match (boolean_literal(&lhs), boolean_literal(&rhs)) {
+        (Some(value), _) if is_boolean(&rhs) => Ok(if value == absorbing { lhs } else { rhs }),
+        (_, Some(value)) if is_boolean(&lhs) => Ok(if value == absorbing { rhs } else { lhs }),
+        _ => new(lhs, rhs),
+    }
+}
+
+/// Whether `expr` is a literal.
+fn is_literal(expr: &DynProofExpr) -> bool {
+    matches!(expr, DynProofExpr::Literal(_))
+}
+
+/// Builds a binary expression with `new`, folding it into a literal if both operands are literals.
+fn fold_binary(
+    lhs: DynProofExpr,
+    rhs: DynProofExpr,
+    new: fn(DynProofExpr, DynProofExpr) -> Result<DynProofExpr, ConversionError>,
+) -> Result<DynProofExpr, ConversionError> {
+    let foldable = is_literal(&lhs) && is_literal(&rhs);
+    Ok(fold_if(foldable, new(lhs, rhs)?))
+}
+
+/// Replaces `expr` by the literal it evaluates to if `foldable` is set.
+///
+/// The expression is evaluated on a single row with the same code the prover uses, so scaling of decimals
+/// and arithmetic in the field are exactly those of the unfolded expression. Type checking bounds the precision
+/// of arithmetic results by 75 digits, so no field the proof may use wraps around and the value does not depend
+/// on the scalar used here. The expression is left unfolded if the value is not representable as
+/// a literal of its type, such as a `BIGINT` sum that overflows, or a decimal that does not fit in an `i128`.
+fn fold_if(foldable: bool, expr: DynProofExpr) -> DynProofExpr {
+    if !foldable {
+        return expr;
+    }
+    let alloc = Bump::new();
+    let Ok(table) = Table::<Curve25519Scalar>::try_new_with_options(
+        IndexMap::default(),
+        TableOptions::new(Some(1)),
+    ) else {
+        return expr;
+    };
+    expr.result_evaluate(&alloc, &table)
+        .scalar_at(0)
+        .and_then(|value| literal_from_scalar(value, expr.data_type()))
+        .map_or(expr, DynProofExpr::new_literal)
+}
+
+/// The literal of type `column_type` whose scalar is `value`, if there is one.
+fn literal_from_scalar(value: Curve25519Scalar, column_type: ColumnType) -> Option<LiteralValue> {
+    match column_type {
+        ColumnType::Boolean => value.try_into().ok().map(LiteralValue::Boolean),
+        ColumnType::TinyInt => value.try_into().ok().map(LiteralValue::TinyInt),
+        ColumnType::SmallInt => value.try_into().ok().map(LiteralValue::SmallInt),
+        ColumnType::Int => value.try_into().ok().map(LiteralValue::Int),
+        ColumnType::BigInt => value.try_into().ok().map(LiteralValue::BigInt),
+        ColumnType::UInt64 => value.try_into().ok().map(LiteralValue::UInt64),
+        ColumnType::Int128 => value.try_into().ok().map(LiteralValue::Int128),
+        ColumnType::Decimal75(precision, scale) => i128::try_from(value).ok().map(|value| {
+            LiteralValue::Decimal75(
+                precision,
+                scale,
+                I256::from_num_bigint(&num_bigint::BigInt::from(value)),
+            )
+        }),
+        ColumnType::VarChar
+        | ColumnType::FixedBytes(_)
+        | ColumnType::Scalar
+        | ColumnType::TimestampTZ(..) => None,
+    }
 }
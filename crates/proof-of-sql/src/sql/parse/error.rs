@@ -64,6 +64,20 @@ pub enum ConversionError {
         alias: String,
     },
 
+    #[snafu(display("Table '{qualifier}' of '{qualifier}.*' was not found in the FROM clause"))]
+    /// A qualified wildcard refers to a table that is not in the FROM clause
+    MissingWildcardTable {
+        /// The qualifier of the wildcard
+        qualifier: Box<Identifier>,
+    },
+
+    #[snafu(display("Column '{identifier}' selected by '*' is present in more than one table"))]
+    /// A wildcard would select several columns with the same name
+    AmbiguousWildcardColumn {
+        /// The ambiguous column identifier
+        identifier: Box<Identifier>,
+    },
+
     #[snafu(display(
         "A WHERE clause must has boolean type. It is currently of type '{datatype}'."
     ))]
@@ -140,6 +154,31 @@ pub enum ConversionError {
         error: String,
     },
 
+    #[snafu(display("Invalid view '{view}': {error}"))]
+    /// A view cannot be defined or used as written
+    InvalidView {
+        /// The name of the view
+        view: String,
+        /// The reason the view is invalid
+        error: String,
+    },
+
+    #[snafu(display("Placeholder ${index} has no value"))]
+    /// A query with a placeholder was planned without binding a value to it
+    UnboundPlaceholder {
+        /// The index of the placeholder
+        index: u64,
+    },
+
+    #[snafu(display("Expected {expected} parameters but {actual} were bound"))]
+    /// The number of bound values does not match the placeholders of a prepared query
+    ParameterCountMismatch {
+        /// The number of parameters the query takes
+        expected: usize,
+        /// The number of values that were bound
+        actual: usize,
+    },
+
     #[snafu(display("Unsupported operator: {message}"))]
     /// Unsupported operation
     UnsupportedOperation {
@@ -13,6 +13,21 @@ mod query_expr_tests;
 mod query_expr;
 pub use query_expr::QueryExpr;
 
+mod view;
+pub use view::View;
+#[cfg(test)]
+mod view_test;
+
+mod column_renames;
+pub use column_renames::ColumnRenames;
+#[cfg(test)]
+mod column_renames_test;
+
+mod prepared_plan;
+pub use prepared_plan::PreparedPlan;
+#[cfg(test)]
+mod prepared_plan_test;
+
 mod filter_exec_builder;
 pub(crate) use filter_exec_builder::FilterExecBuilder;
 
@@ -20,12 +35,23 @@ pub(crate) use filter_exec_builder::FilterExecBuilder;
 pub(crate) mod query_context;
 pub(crate) use query_context::QueryContext;
 
+mod type_check_mode;
+pub use type_check_mode::TypeCheckMode;
+#[cfg(test)]
+mod type_check_mode_test;
+
 mod query_context_builder;
 pub(crate) use query_context_builder::{type_check_binary_operation, QueryContextBuilder};
 
 mod dyn_proof_expr_builder;
 pub(crate) use dyn_proof_expr_builder::DynProofExprBuilder;
 
+mod unsupported_features;
+pub use unsupported_features::{unsupported_features, UnsupportedFeature};
+
+mod wildcard_expansion;
+pub(crate) use wildcard_expansion::{expand_qualified_wildcard, expand_wildcard, FromTable};
+
 mod where_expr_builder;
 pub(crate) use where_expr_builder::WhereExprBuilder;
 #[cfg(test)]
@@ -0,0 +1,186 @@
+use super::{ConversionError, ConversionResult, QueryExpr};
+use crate::base::database::SchemaAccessor;
+use alloc::{boxed::Box, vec::Vec};
+use proof_of_sql_parser::{
+    intermediate_ast::{AliasedResultExpr, Expression, Literal, SelectResultExpr, SetExpression},
+    Identifier, SelectStatement,
+};
+
+/// A parsed query with numbered placeholders `$1`, `$2`, ... that can be planned for many values.
+///
+/// Binding values to the placeholders produces the same [`QueryExpr`] as planning the query
+/// with those values written as literals. The plan is type-checked when it is bound, since the
+/// type of a placeholder is not known until a value is supplied for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedPlan {
+    statement: SelectStatement,
+    default_schema: Identifier,
+    parameter_count: usize,
+}
+
+impl PreparedPlan {
+    /// Prepares a parsed query for binding.
+    ///
+    /// The query takes one parameter for every placeholder index up to the largest one it uses.
+    #[must_use]
+    pub fn new(statement: SelectStatement, default_schema: Identifier) -> Self {
+        let parameter_count = set_expr_max_placeholder(&statement.expr);
+        Self {
+            statement,
+            default_schema,
+            parameter_count,
+        }
+    }
+
+    /// The number of values that must be bound
+    #[must_use]
+    pub fn parameter_count(&self) -> usize {
+        self.parameter_count
+    }
+
+    /// Substitutes `params[i]` for the placeholder `$i+1` and plans the resulting query.
+    pub fn bind(
+        &self,
+        params: &[Literal],
+        schema_accessor: &dyn SchemaAccessor,
+    ) -> ConversionResult<QueryExpr> {
+        if params.len() != self.parameter_count {
+            return Err(ConversionError::ParameterCountMismatch {
+                expected: self.parameter_count,
+                actual: params.len(),
+            });
+        }
+        let statement = SelectStatement {
+            expr: Box::new(bind_set_expr(*self.statement.expr.clone(), params)),
+            ..self.statement.clone()
+        };
+        QueryExpr::try_new(statement, self.default_schema, schema_accessor)
+    }
+}
+
+fn set_expr_max_placeholder(set_expr: &SetExpression) -> usize {
+    let SetExpression::Query {
+        result_exprs,
+        where_expr,
+        having,
+        ..
+    } = set_expr;
+    result_exprs
+        .iter()
+        .filter_map(|result_expr| match result_expr {
+            SelectResultExpr::AliasedResultExpr(AliasedResultExpr { expr, .. }) => Some(expr),
+            SelectResultExpr::ALL | SelectResultExpr::QualifiedAll(_) => None,
+        })
+        .chain(where_expr)
+        .chain(having)
+        .map(|expr| max_placeholder(expr))
+        .max()
+        .unwrap_or(0)
+}
+
+/// The largest placeholder index in `expr`, or 0 if there is none
+fn max_placeholder(expr: &Expression) -> usize {
+    match expr {
+        Expression::Placeholder(index) => usize::try_from(*index).unwrap_or(usize::MAX),
+        Expression::Literal(_)
+        | Expression::Column(_)
+        | Expression::Wildcard
+        | Expression::RowNumber(_)
+        | Expression::Shift { .. } => 0,
+        Expression::Unary { expr, .. } | Expression::DateTrunc { expr, .. } => {
+            max_placeholder(expr)
+        }
+        Expression::Aggregation { expr, filter, .. } => {
+            max_placeholder(expr).max(filter.as_deref().map_or(0, max_placeholder))
+        }
+        Expression::Binary { left, right, .. } => max_placeholder(left).max(max_placeholder(right)),
+        Expression::Case {
+            condition,
+            then_result,
+            else_result,
+        } => max_placeholder(condition)
+            .max(max_placeholder(then_result))
+            .max(max_placeholder(else_result)),
+        Expression::Exists(subquery) | Expression::ScalarSubquery(subquery) => {
+            set_expr_max_placeholder(subquery)
+        }
+    }
+}
+
+fn bind_set_expr(set_expr: SetExpression, params: &[Literal]) -> SetExpression {
+    let SetExpression::Query {
+        result_exprs,
+        from,
+        where_expr,
+        group_by,
+        having,
+    } = set_expr;
+    let bind_boxed = |expr: Box<Expression>| Box::new(bind_expr(*expr, params));
+    SetExpression::Query {
+        result_exprs: result_exprs
+            .into_iter()
+            .map(|result_expr| match result_expr {
+                SelectResultExpr::AliasedResultExpr(AliasedResultExpr { expr, alias }) => {
+                    SelectResultExpr::AliasedResultExpr(AliasedResultExpr {
+                        expr: bind_boxed(expr),
+                        alias,
+                    })
+                }
+                SelectResultExpr::ALL | SelectResultExpr::QualifiedAll(_) => result_expr,
+            })
+            .collect::<Vec<_>>(),
+        from,
+        where_expr: where_expr.map(bind_boxed),
+        group_by,
+        having: having.map(bind_boxed),
+    }
+}
+
+/// Replace every placeholder in `expr` by its value. The caller checks that every index is in range.
+fn bind_expr(expr: Expression, params: &[Literal]) -> Expression {
+    let bind_boxed = |expr: Box<Expression>| Box::new(bind_expr(*expr, params));
+    match expr {
+        Expression::Placeholder(index) => usize::try_from(index)
+            .ok()
+            .and_then(|index| params.get(index.checked_sub(1)?))
+            .map_or(expr, |literal| Expression::Literal(literal.clone())),
+        Expression::Literal(_)
+        | Expression::Column(_)
+        | Expression::Wildcard
+        | Expression::RowNumber(_)
+        | Expression::Shift { .. } => expr,
+        Expression::Unary { op, expr } => Expression::Unary {
+            op,
+            expr: bind_boxed(expr),
+        },
+        Expression::Binary { op, left, right } => Expression::Binary {
+            op,
+            left: bind_boxed(left),
+            right: bind_boxed(right),
+        },
+        Expression::Aggregation { op, expr, filter } => Expression::Aggregation {
+            op,
+            expr: bind_boxed(expr),
+            filter: filter.map(bind_boxed),
+        },
+        Expression::DateTrunc { unit, expr } => Expression::DateTrunc {
+            unit,
+            expr: bind_boxed(expr),
+        },
+        Expression::Case {
+            condition,
+            then_result,
+            else_result,
+        } => Expression::Case {
+            condition: bind_boxed(condition),
+            then_result: bind_boxed(then_result),
+            else_result: bind_boxed(else_result),
+        },
+        Expression::Exists(subquery) => {
+            Expression::Exists(Box::new(bind_set_expr(*subquery, params)))
+        }
+        Expression::ScalarSubquery(subquery) => {
+            Expression::ScalarSubquery(Box::new(bind_set_expr(*subquery, params)))
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::{ConversionError, PreparedPlan, QueryExpr};
+use crate::base::{
+    database::{ColumnType, TestSchemaAccessor},
+    map::indexmap,
+};
+use proof_of_sql_parser::{intermediate_ast::Literal, sql::SelectStatementParser};
+
+fn accessor() -> TestSchemaAccessor {
+    TestSchemaAccessor::new(indexmap! {
+        "sxt.t".parse().unwrap() => indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::VarChar,
+        },
+    })
+}
+
+fn prepare(sql: &str) -> PreparedPlan {
+    PreparedPlan::new(
+        SelectStatementParser::new().parse(sql).unwrap(),
+        "sxt".parse().unwrap(),
+    )
+}
+
+fn plan(sql: &str) -> QueryExpr {
+    QueryExpr::try_new(
+        SelectStatementParser::new().parse(sql).unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn we_can_bind_different_values_to_a_prepared_plan() {
+    let prepared = prepare("select a, b from t where a > $1");
+    assert_eq!(prepared.parameter_count(), 1);
+    assert_eq!(
+        prepared.bind(&[Literal::BigInt(5)], &accessor()).unwrap(),
+        plan("select a, b from t where a > 5")
+    );
+    assert_eq!(
+        prepared.bind(&[Literal::BigInt(-7)], &accessor()).unwrap(),
+        plan("select a, b from t where a > -7")
+    );
+}
+
+#[test]
+fn we_can_bind_placeholders_anywhere_in_a_query() {
+    let prepared = prepare(
+        "select b, sum(a + $2) as s from t where b = $1 or a = $2 group by b having count(*) > $3",
+    );
+    assert_eq!(prepared.parameter_count(), 3);
+    assert_eq!(
+        prepared
+            .bind(
+                &[
+                    Literal::VarChar("x".to_string()),
+                    Literal::BigInt(2),
+                    Literal::BigInt(10)
+                ],
+                &accessor()
+            )
+            .unwrap(),
+        plan("select b, sum(a + 2) as s from t where b = 'x' or a = 2 group by b having count(*) > 10")
+    );
+}
+
+#[test]
+fn we_cannot_bind_a_value_of_an_incompatible_type() {
+    let prepared = prepare("select a from t where a > $1");
+    assert!(matches!(
+        prepared.bind(&[Literal::VarChar("5".to_string())], &accessor()),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_bind_the_wrong_number_of_values() {
+    let prepared = prepare("select a from t where a > $2");
+    assert_eq!(
+        prepared.bind(&[Literal::BigInt(1)], &accessor()),
+        Err(ConversionError::ParameterCountMismatch {
+            expected: 2,
+            actual: 1
+        })
+    );
+    assert_eq!(
+        prepare("select a from t")
+            .bind(&[Literal::BigInt(1)], &accessor())
+            .map(|_| ()),
+        Err(ConversionError::ParameterCountMismatch {
+            expected: 0,
+            actual: 1
+        })
+    );
+}
+
+#[test]
+fn we_cannot_plan_a_query_with_unbound_placeholders() {
+    assert_eq!(
+        QueryExpr::try_new(
+            SelectStatementParser::new()
+                .parse("select a from t where a > $1")
+                .unwrap(),
+            "sxt".parse().unwrap(),
+            &accessor(),
+        ),
+        Err(ConversionError::UnboundPlaceholder { index: 1 })
+    );
+}
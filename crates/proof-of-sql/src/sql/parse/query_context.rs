@@ -5,11 +5,13 @@ use crate::{
     },
     sql::{
         parse::{ConversionError, ConversionResult, DynProofExprBuilder, WhereExprBuilder},
+        postprocessing::{GroupByPostprocessing, HyperLogLogPostprocessing, SelectPostprocessing},
         proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr},
-        proof_plans::GroupByExec,
+        proof_plans::{GroupByExec, RowNumberExec, ShiftExec},
     },
 };
-use alloc::{borrow::ToOwned, boxed::Box, string::ToString, vec::Vec};
+use alloc::{borrow::ToOwned, boxed::Box, format, string::ToString, vec, vec::Vec};
+use core::iter;
 use proof_of_sql_parser::{
     intermediate_ast::{AggregationOperator, AliasedResultExpr, Expression, OrderBy, Slice},
     Identifier,
@@ -27,10 +29,16 @@ pub struct QueryContext {
     order_by_exprs: Vec<OrderBy>,
     group_by_exprs: Vec<Identifier>,
     where_expr: Option<Box<Expression>>,
+    having_expr: Option<Box<Expression>>,
     result_column_set: IndexSet<Identifier>,
     res_aliased_exprs: Vec<AliasedResultExpr>,
     column_mapping: IndexMap<Identifier, ColumnRef>,
     first_result_col_out_agg_scope: Option<Identifier>,
+    count_distinct_counter: usize,
+    approx_count_distinct_counter: usize,
+    row_number_counter: usize,
+    shift_counter: usize,
+    agg_filter_counter: usize,
 }
 
 impl QueryContext {
@@ -55,6 +63,14 @@ impl QueryContext {
         &self.where_expr
     }
 
+    pub fn set_having_expr(&mut self, having_expr: Option<Box<Expression>>) {
+        self.having_expr = having_expr;
+    }
+
+    pub fn get_having_expr(&self) -> &Option<Box<Expression>> {
+        &self.having_expr
+    }
+
     pub fn set_slice_expr(&mut self, slice_expr: Option<Slice>) {
         self.slice_expr = slice_expr;
     }
@@ -97,7 +113,32 @@ impl QueryContext {
 
     /// TODO: add docs
     pub(crate) fn has_agg(&self) -> bool {
-        self.agg_counter > 0 || !self.group_by_exprs.is_empty()
+        self.agg_counter > 0 || !self.group_by_exprs.is_empty() || self.having_expr.is_some()
+    }
+
+    /// Record that the query contains a `COUNT(DISTINCT ...)` aggregation
+    pub(crate) fn push_count_distinct(&mut self) {
+        self.count_distinct_counter += 1;
+    }
+
+    /// Record that the query contains an `APPROX_COUNT_DISTINCT(...)` aggregation
+    pub(crate) fn push_approx_count_distinct(&mut self) {
+        self.approx_count_distinct_counter += 1;
+    }
+
+    /// Record that the query contains an aggregation with a `FILTER` clause
+    pub(crate) fn push_agg_filter(&mut self) {
+        self.agg_filter_counter += 1;
+    }
+
+    /// Record that the query contains a `ROW_NUMBER()` window function
+    pub(crate) fn push_row_number(&mut self) {
+        self.row_number_counter += 1;
+    }
+
+    /// Record that the query contains a `LAG` or `LEAD` window function
+    pub(crate) fn push_shift(&mut self) {
+        self.shift_counter += 1;
     }
 
     pub fn push_column_ref(&mut self, column: Identifier, column_ref: ColumnRef) {
@@ -156,12 +197,12 @@ impl QueryContext {
             })
     }
 
-    /// # Panics
-    ///
-    /// Will panic if:
-    /// - `self.res_aliased_exprs` is empty, triggering the assertion `assert!(!self.res_aliased_exprs.is_empty(), "empty aliased exprs")`.
     pub fn get_aliased_result_exprs(&self) -> ConversionResult<&[AliasedResultExpr]> {
-        assert!(!self.res_aliased_exprs.is_empty(), "empty aliased exprs");
+        if self.res_aliased_exprs.is_empty() {
+            return Err(ConversionError::InvalidExpression {
+                expression: "a query must select at least one column".to_string(),
+            });
+        }
 
         // We need to check that each column alias is unique
         for col in &self.res_aliased_exprs {
@@ -179,13 +220,12 @@ impl QueryContext {
         }
 
         // We cannot have column references outside aggregations when there is no group by expressions
-        if self.group_by_exprs.is_empty()
-            && self.agg_counter > 0
-            && self.first_result_col_out_agg_scope.is_some()
-        {
-            return Err(ConversionError::InvalidGroupByColumnRef {
-                column: self.first_result_col_out_agg_scope.unwrap().to_string(),
-            });
+        if let Some(column) = self.first_result_col_out_agg_scope {
+            if self.group_by_exprs.is_empty() && self.agg_counter > 0 {
+                return Err(ConversionError::InvalidGroupByColumnRef {
+                    column: column.to_string(),
+                });
+            }
         }
 
         Ok(&self.res_aliased_exprs)
@@ -220,31 +260,27 @@ impl QueryContext {
     pub fn get_column_mapping(&self) -> IndexMap<Identifier, ColumnRef> {
         self.column_mapping.clone()
     }
-}
 
-/// Converts a `QueryContext` into a `Option<GroupByExec>`.
-///
-/// We use Some if the query is provable and None if it is not
-/// We error out if the query is wrong
-impl TryFrom<&QueryContext> for Option<GroupByExec> {
-    type Error = ConversionError;
+    fn build_where_clause(&self) -> ConversionResult<DynProofExpr> {
+        Ok(WhereExprBuilder::new(&self.column_mapping)
+            .build(self.where_expr.clone())?
+            .unwrap_or_else(|| DynProofExpr::new_literal(LiteralValue::Boolean(true))))
+    }
 
-    fn try_from(value: &QueryContext) -> Result<Option<GroupByExec>, Self::Error> {
-        let where_clause = WhereExprBuilder::new(&value.column_mapping)
-            .build(value.where_expr.clone())?
-            .unwrap_or_else(|| DynProofExpr::new_literal(LiteralValue::Boolean(true)));
-        let table = value.table.map(|table_ref| TableExpr { table_ref }).ok_or(
+    fn build_table_expr(&self) -> ConversionResult<TableExpr> {
+        self.table.map(|table_ref| TableExpr { table_ref }).ok_or(
             ConversionError::InvalidExpression {
                 expression: "QueryContext has no table_ref".to_owned(),
             },
-        )?;
-        let resource_id = table.table_ref.resource_id();
-        let group_by_exprs = value
-            .group_by_exprs
+        )
+    }
+
+    fn build_group_by_columns(&self) -> ConversionResult<Vec<ColumnExpr>> {
+        let resource_id = self.build_table_expr()?.table_ref.resource_id();
+        self.group_by_exprs
             .iter()
-            .map(|expr| -> Result<ColumnExpr, ConversionError> {
-                value
-                    .column_mapping
+            .map(|expr| {
+                self.column_mapping
                     .get(expr)
                     .ok_or(ConversionError::MissingColumn {
                         identifier: Box::new(*expr),
@@ -252,7 +288,511 @@ impl TryFrom<&QueryContext> for Option<GroupByExec> {
                     })
                     .map(|column_ref| ColumnExpr::new(*column_ref))
             })
-            .collect::<Result<Vec<ColumnExpr>, ConversionError>>()?;
+            .collect()
+    }
+
+    /// Plan a query that counts the distinct values of a column,
+    /// i.e. `SELECT COUNT(DISTINCT a) AS alias FROM tab WHERE ...`.
+    ///
+    /// The distinct values are proven by a [`GroupByExec`] grouping by the column,
+    /// which proves that every selected value is in its result and that its result has no duplicates.
+    /// The verified distinct values are then counted by the returned postprocessing.
+    ///
+    /// Returns `None` if the query has no `COUNT(DISTINCT ...)` aggregation.
+    ///
+    /// # Errors
+    /// Returns an error if the query uses `COUNT(DISTINCT ...)` in any other way,
+    /// e.g. with a `GROUP BY` clause, along with other result columns or on an expression that is not a column.
+    pub(crate) fn try_count_distinct(
+        &self,
+    ) -> ConversionResult<Option<(GroupByExec, GroupByPostprocessing)>> {
+        if self.count_distinct_counter == 0 {
+            return Ok(None);
+        }
+        let unsupported = || {
+            ConversionError::Unprovable {
+            error: "count distinct is only supported as the only result of a query over a column without group by or having".to_owned(),
+        }
+        };
+        let ([result], [], None) = (
+            self.res_aliased_exprs.as_slice(),
+            self.group_by_exprs.as_slice(),
+            &self.having_expr,
+        ) else {
+            return Err(unsupported());
+        };
+        let Expression::Aggregation {
+            op: AggregationOperator::CountDistinct,
+            expr,
+            filter: None,
+        } = &*result.expr
+        else {
+            return Err(unsupported());
+        };
+        let Expression::Column(column) = **expr else {
+            return Err(unsupported());
+        };
+        let count_alias: Identifier = COUNT_DISTINCT_GROUP_COUNT_NAME
+            .parse()
+            .map_err(|_| unsupported())?;
+        if column == count_alias {
+            return Err(unsupported());
+        }
+        let column_ref =
+            self.column_mapping
+                .get(&column)
+                .ok_or_else(|| ConversionError::MissingColumn {
+                    identifier: Box::new(column),
+                    resource_id: Box::new(self.get_table_ref().resource_id()),
+                })?;
+        let group_by_exec = GroupByExec::new(
+            vec![ColumnExpr::new(*column_ref)],
+            vec![],
+            count_alias,
+            self.build_table_expr()?,
+            self.build_where_clause()?,
+        );
+        let postprocessing = GroupByPostprocessing::try_new(
+            vec![],
+            vec![AliasedResultExpr {
+                expr: Box::new(Expression::Aggregation {
+                    op: AggregationOperator::Count,
+                    expr: Box::new(Expression::Column(column)),
+                    filter: None,
+                }),
+                alias: result.alias,
+            }],
+        )?;
+        Ok(Some((group_by_exec, postprocessing)))
+    }
+
+    /// Plan a query that estimates the number of distinct values of a column,
+    /// i.e. `SELECT APPROX_COUNT_DISTINCT(a) AS alias FROM tab WHERE ...`.
+    ///
+    /// The `HyperLogLog` register update of every selected value is proven by a [`GroupByExec`] grouping by
+    /// the updates, which proves that its result is exactly the set of updates, so the sketch is verified.
+    /// The returned postprocessing then computes the estimate from the sketch, along with its standard error
+    /// in a column named `<alias>_standard_error`. The sketch has `2^APPROX_COUNT_DISTINCT_PRECISION` registers,
+    /// so the relative standard error is about 1.6%.
+    ///
+    /// Returns `None` if the query has no `APPROX_COUNT_DISTINCT(...)` aggregation.
+    ///
+    /// # Errors
+    /// Returns an error if the query uses `APPROX_COUNT_DISTINCT(...)` in any other way,
+    /// e.g. with a `GROUP BY` clause, along with other result columns or on an expression that is not a column.
+    pub(crate) fn try_approx_count_distinct(
+        &self,
+    ) -> ConversionResult<Option<(GroupByExec, HyperLogLogPostprocessing)>> {
+        if self.approx_count_distinct_counter == 0 {
+            return Ok(None);
+        }
+        let unsupported = || {
+            ConversionError::Unprovable {
+            error: "approx_count_distinct is only supported as the only result of a query over a column without group by or having".to_owned(),
+        }
+        };
+        let ([result], [], None) = (
+            self.res_aliased_exprs.as_slice(),
+            self.group_by_exprs.as_slice(),
+            &self.having_expr,
+        ) else {
+            return Err(unsupported());
+        };
+        let Expression::Aggregation {
+            op: AggregationOperator::ApproxCountDistinct,
+            expr,
+            filter: None,
+        } = &*result.expr
+        else {
+            return Err(unsupported());
+        };
+        let Expression::Column(column) = **expr else {
+            return Err(unsupported());
+        };
+        let updates_alias: Identifier = APPROX_COUNT_DISTINCT_UPDATES_NAME
+            .parse()
+            .map_err(|_| unsupported())?;
+        let count_alias: Identifier = APPROX_COUNT_DISTINCT_GROUP_COUNT_NAME
+            .parse()
+            .map_err(|_| unsupported())?;
+        let standard_error_alias: Identifier = format!("{}_standard_error", result.alias)
+            .parse()
+            .map_err(|_| unsupported())?;
+        let column_ref =
+            self.column_mapping
+                .get(&column)
+                .ok_or_else(|| ConversionError::MissingColumn {
+                    identifier: Box::new(column),
+                    resource_id: Box::new(self.get_table_ref().resource_id()),
+                })?;
+        let group_by_exec = GroupByExec::new_with_key_exprs(
+            vec![AliasedDynProofExpr {
+                expr: DynProofExpr::try_new_hyperloglog_update(
+                    DynProofExpr::new_column(*column_ref),
+                    APPROX_COUNT_DISTINCT_PRECISION,
+                )?,
+                alias: updates_alias,
+            }],
+            vec![],
+            count_alias,
+            self.build_table_expr()?,
+            self.build_where_clause()?,
+        );
+        let postprocessing = HyperLogLogPostprocessing::try_new(
+            updates_alias,
+            APPROX_COUNT_DISTINCT_PRECISION,
+            result.alias,
+            standard_error_alias,
+        )?;
+        Ok(Some((group_by_exec, postprocessing)))
+    }
+
+    /// Plan a query whose aggregations restrict their rows with `FILTER` clauses,
+    /// i.e. `SELECT a, SUM(b) FILTER (WHERE c >= 0) AS s, COUNT(*) AS n FROM tab WHERE ... GROUP BY a`.
+    ///
+    /// Every aggregation is proven by the same [`GroupByExec`], so the query needs only one proof.
+    /// A filtered aggregation is proven as a sum whose input is zero on the rows where the filter does not hold,
+    /// which ANDs the filter into the selection of that aggregation alone.
+    /// The first unfiltered `COUNT` is the count column of the [`GroupByExec`].
+    /// If there is none, a count column is added to the proven result and dropped again by the returned postprocessing,
+    /// which also restores the order of the result columns.
+    ///
+    /// Returns `None` if the query has no `FILTER` clause.
+    ///
+    /// # Errors
+    /// Returns an error if the query uses `FILTER` in any other way,
+    /// e.g. along with a `HAVING` clause or with results other than the group by columns followed by sums and counts.
+    pub(crate) fn try_filtered_aggregation(
+        &self,
+    ) -> ConversionResult<Option<(GroupByExec, Option<SelectPostprocessing>)>> {
+        if self.agg_filter_counter == 0 {
+            return Ok(None);
+        }
+        let unsupported = || {
+            ConversionError::Unprovable {
+            error: "aggregations with a filter are only supported as sums and counts following the group by columns of a query without having".to_owned(),
+        }
+        };
+        let num_group_by_columns = self.group_by_exprs.len();
+        if self.having_expr.is_some()
+            || self.res_aliased_exprs.len() <= num_group_by_columns
+            || !self.group_by_exprs.iter().zip(&self.res_aliased_exprs).all(
+                |(identifier, aliased_expr)| *aliased_expr.expr == Expression::Column(*identifier),
+            )
+        {
+            return Err(unsupported());
+        }
+        let aggregations = &self.res_aliased_exprs[num_group_by_columns..];
+        let count_alias = aggregations
+            .iter()
+            .find(|aliased_expr| {
+                matches!(
+                    *aliased_expr.expr,
+                    Expression::Aggregation {
+                        op: AggregationOperator::Count,
+                        filter: None,
+                        ..
+                    }
+                )
+            })
+            .map(|aliased_expr| aliased_expr.alias);
+        let builder = DynProofExprBuilder::new(&self.column_mapping);
+        let sum_expr = aggregations
+            .iter()
+            .filter(|aliased_expr| Some(aliased_expr.alias) != count_alias)
+            .map(|aliased_expr| match *aliased_expr.expr {
+                Expression::Aggregation {
+                    op: AggregationOperator::Sum | AggregationOperator::Count,
+                    ..
+                } => Ok(AliasedDynProofExpr {
+                    alias: aliased_expr.alias,
+                    expr: builder.build(&aliased_expr.expr)?,
+                }),
+                _ => Err(unsupported()),
+            })
+            .collect::<ConversionResult<Vec<_>>>()?;
+        let count_alias = if let Some(count_alias) = count_alias {
+            count_alias
+        } else {
+            let count_alias: Identifier = FILTERED_AGGREGATION_COUNT_NAME
+                .parse()
+                .map_err(|_| unsupported())?;
+            if self
+                .res_aliased_exprs
+                .iter()
+                .any(|aliased_expr| aliased_expr.alias == count_alias)
+            {
+                return Err(unsupported());
+            }
+            count_alias
+        };
+        let group_by_exec = GroupByExec::new(
+            self.build_group_by_columns()?,
+            sum_expr,
+            count_alias,
+            self.build_table_expr()?,
+            self.build_where_clause()?,
+        );
+        let reorder = (self
+            .res_aliased_exprs
+            .last()
+            .is_some_and(|aliased_expr| aliased_expr.alias != count_alias))
+        .then(|| {
+            SelectPostprocessing::new(
+                self.res_aliased_exprs
+                    .iter()
+                    .map(|aliased_expr| {
+                        AliasedResultExpr::new(
+                            Expression::Column(aliased_expr.alias),
+                            aliased_expr.alias,
+                        )
+                    })
+                    .collect(),
+            )
+        });
+        Ok(Some((group_by_exec, reorder)))
+    }
+
+    /// Plan a query that numbers its rows,
+    /// i.e. `SELECT <exprs>, ROW_NUMBER() OVER (ORDER BY a) AS alias FROM tab WHERE ...`.
+    ///
+    /// The rows are sorted and numbered by a [`RowNumberExec`].
+    /// If the key column `a` is not a result column, it is added to the proven result
+    /// and dropped again by the returned postprocessing, which also restores the order of the result columns.
+    ///
+    /// Returns `None` if the query has no `ROW_NUMBER()`.
+    ///
+    /// # Errors
+    /// Returns an error if the query uses `ROW_NUMBER()` in any other way,
+    /// e.g. more than once, inside another expression, along with an aggregation or along with a result that is not provable.
+    pub(crate) fn try_row_number(
+        &self,
+    ) -> ConversionResult<Option<(RowNumberExec, Option<SelectPostprocessing>)>> {
+        if self.row_number_counter == 0 {
+            return Ok(None);
+        }
+        let unsupported = || {
+            ConversionError::Unprovable {
+            error: "row number is only supported once as a result column of a query without aggregation over provable results".to_owned(),
+        }
+        };
+        if self.row_number_counter > 1 || self.has_agg() {
+            return Err(unsupported());
+        }
+        let (row_number_index, key, row_number_alias) = self
+            .res_aliased_exprs
+            .iter()
+            .enumerate()
+            .find_map(|(index, aliased_expr)| match &*aliased_expr.expr {
+                Expression::RowNumber(order_by) => {
+                    Some((index, order_by.clone(), aliased_expr.alias))
+                }
+                _ => None,
+            })
+            .ok_or_else(unsupported)?;
+        let mut results = self
+            .res_aliased_exprs
+            .iter()
+            .filter(|aliased_expr| aliased_expr.alias != row_number_alias)
+            .cloned()
+            .collect::<Vec<_>>();
+        let key_alias = column_result_alias(&mut results, key.expr).ok_or_else(unsupported)?;
+        let row_number_exec = RowNumberExec::new(
+            self.build_aliased_results(&results)?,
+            self.build_table_expr()?,
+            self.build_where_clause()?,
+            OrderBy {
+                expr: key_alias,
+                direction: key.direction,
+            },
+            row_number_alias,
+        );
+        let reorder = (row_number_index + 1 != self.res_aliased_exprs.len()
+            || results.len() == self.res_aliased_exprs.len())
+        .then(|| {
+            SelectPostprocessing::new(
+                self.res_aliased_exprs
+                    .iter()
+                    .map(|aliased_expr| {
+                        AliasedResultExpr::new(
+                            Expression::Column(aliased_expr.alias),
+                            aliased_expr.alias,
+                        )
+                    })
+                    .collect(),
+            )
+        });
+        Ok(Some((row_number_exec, reorder)))
+    }
+
+    /// Plan a query that takes the value of a column in an adjacent row,
+    /// i.e. `SELECT <exprs>, LAG(b) OVER (ORDER BY a) AS alias FROM tab WHERE ...` or the same with `LEAD`.
+    ///
+    /// The rows are sorted and shifted by a [`ShiftExec`]. Columns are not nullable, so the row without an adjacent row
+    /// holds the default value of the type, and the boolean column `<alias>_is_null` right after the shifted column
+    /// is `true` in that row.
+    /// If `a` or `b` is not a result column, it is added to the proven result
+    /// and dropped again by the returned postprocessing, which also restores the order of the result columns.
+    ///
+    /// Returns `None` if the query has no `LAG` or `LEAD`.
+    ///
+    /// # Errors
+    /// Returns an error if the query uses `LAG` or `LEAD` in any other way, e.g. more than once, along with `ROW_NUMBER()`,
+    /// inside another expression, along with an aggregation or along with a result that is not provable.
+    pub(crate) fn try_shift(
+        &self,
+    ) -> ConversionResult<Option<(ShiftExec, Option<SelectPostprocessing>)>> {
+        if self.shift_counter == 0 {
+            return Ok(None);
+        }
+        let unsupported = || {
+            ConversionError::Unprovable {
+            error: "lag and lead are only supported once as a result column of a query without aggregation or row number over provable results".to_owned(),
+        }
+        };
+        if self.shift_counter > 1 || self.row_number_counter > 0 || self.has_agg() {
+            return Err(unsupported());
+        }
+        let (shift_index, direction, column, order_by, shift_alias) = self
+            .res_aliased_exprs
+            .iter()
+            .enumerate()
+            .find_map(|(index, aliased_expr)| match &*aliased_expr.expr {
+                Expression::Shift {
+                    direction,
+                    column,
+                    order_by,
+                } => Some((
+                    index,
+                    *direction,
+                    *column,
+                    order_by.clone(),
+                    aliased_expr.alias,
+                )),
+                _ => None,
+            })
+            .ok_or_else(unsupported)?;
+        let is_null_alias: Identifier = format!("{shift_alias}_is_null")
+            .parse()
+            .map_err(|_| unsupported())?;
+        if self
+            .res_aliased_exprs
+            .iter()
+            .any(|aliased_expr| aliased_expr.alias == is_null_alias)
+        {
+            return Err(unsupported());
+        }
+        let mut results = self
+            .res_aliased_exprs
+            .iter()
+            .filter(|aliased_expr| aliased_expr.alias != shift_alias)
+            .cloned()
+            .collect::<Vec<_>>();
+        let key_alias = column_result_alias(&mut results, order_by.expr).ok_or_else(unsupported)?;
+        let source_alias = column_result_alias(&mut results, column).ok_or_else(unsupported)?;
+        let shift_exec = ShiftExec::new(
+            self.build_aliased_results(&results)?,
+            self.build_table_expr()?,
+            self.build_where_clause()?,
+            OrderBy {
+                expr: key_alias,
+                direction: order_by.direction,
+            },
+            direction,
+            source_alias,
+            shift_alias,
+            is_null_alias,
+        );
+        let reorder = (shift_index + 1 != self.res_aliased_exprs.len()
+            || results.len() + 1 != self.res_aliased_exprs.len())
+        .then(|| {
+            SelectPostprocessing::new(
+                self.res_aliased_exprs
+                    .iter()
+                    .flat_map(|aliased_expr| {
+                        iter::once(aliased_expr.alias)
+                            .chain((aliased_expr.alias == shift_alias).then_some(is_null_alias))
+                    })
+                    .map(|alias| AliasedResultExpr::new(Expression::Column(alias), alias))
+                    .collect(),
+            )
+        });
+        Ok(Some((shift_exec, reorder)))
+    }
+
+    /// Build the proof expressions of the given result columns
+    fn build_aliased_results(
+        &self,
+        results: &[AliasedResultExpr],
+    ) -> ConversionResult<Vec<AliasedDynProofExpr>> {
+        let builder = DynProofExprBuilder::new(&self.column_mapping);
+        results
+            .iter()
+            .map(|aliased_expr| {
+                Ok(AliasedDynProofExpr {
+                    alias: aliased_expr.alias,
+                    expr: builder.build(&aliased_expr.expr)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The alias of the result column that is exactly `column`, which is added to `results` if there is none
+///
+/// Returns `None` if `column` has to be added but its name is already the alias of another result column.
+fn column_result_alias(
+    results: &mut Vec<AliasedResultExpr>,
+    column: Identifier,
+) -> Option<Identifier> {
+    match results
+        .iter()
+        .find(|aliased_expr| *aliased_expr.expr == Expression::Column(column))
+    {
+        Some(aliased_expr) => Some(aliased_expr.alias),
+        None if results
+            .iter()
+            .any(|aliased_expr| aliased_expr.alias == column) =>
+        {
+            None
+        }
+        None => {
+            results.push(AliasedResultExpr::new(Expression::Column(column), column));
+            Some(column)
+        }
+    }
+}
+
+/// The name of the count column of the [`GroupByExec`] that proves the distinct values of a `COUNT(DISTINCT ...)`
+const COUNT_DISTINCT_GROUP_COUNT_NAME: &str = "__count_distinct_rows__";
+
+/// The name of the `HyperLogLog` register updates that the [`GroupByExec`] of an `APPROX_COUNT_DISTINCT(...)` groups by
+const APPROX_COUNT_DISTINCT_UPDATES_NAME: &str = "__hyperloglog_updates__";
+
+/// The name of the count column of the [`GroupByExec`] that proves the sketch of an `APPROX_COUNT_DISTINCT(...)`
+const APPROX_COUNT_DISTINCT_GROUP_COUNT_NAME: &str = "__hyperloglog_update_rows__";
+
+/// The `HyperLogLog` sketch of an `APPROX_COUNT_DISTINCT(...)` has `2^APPROX_COUNT_DISTINCT_PRECISION` registers
+const APPROX_COUNT_DISTINCT_PRECISION: u8 = 12;
+
+/// The name of the count column that is added to the [`GroupByExec`] of filtered aggregations without an unfiltered `COUNT`
+const FILTERED_AGGREGATION_COUNT_NAME: &str = "__filtered_aggregation_rows__";
+
+/// Converts a `QueryContext` into a `Option<GroupByExec>`.
+///
+/// We use Some if the query is provable and None if it is not
+/// We error out if the query is wrong
+impl TryFrom<&QueryContext> for Option<GroupByExec> {
+    type Error = ConversionError;
+
+    fn try_from(value: &QueryContext) -> Result<Option<GroupByExec>, Self::Error> {
+        // Filtering the aggregated rows is done in postprocessing
+        if value.having_expr.is_some() {
+            return Ok(None);
+        }
+        let where_clause = value.build_where_clause()?;
+        let table = value.build_table_expr()?;
+        let group_by_exprs = value.build_group_by_columns()?;
         // For a query to be provable the result columns must be of one of three kinds below:
         // 1. Group by columns (it is mandatory to have all of them in the correct order)
         // 2. Sum(expr) expressions (it is optional to have any)
@@ -307,6 +847,7 @@ impl TryFrom<&QueryContext> for Option<GroupByExec> {
             *count_column.expr,
             Expression::Aggregation {
                 op: AggregationOperator::Count,
+                filter: None,
                 ..
             }
         );
@@ -1,19 +1,23 @@
-use super::{ConversionError, ConversionResult, QueryContext};
+use super::{
+    expand_qualified_wildcard, expand_wildcard, ConversionError, ConversionResult, FromTable,
+    QueryContext, TypeCheckMode,
+};
 use crate::base::{
     database::{
-        try_add_subtract_column_types, try_multiply_column_types, ColumnRef, ColumnType,
-        SchemaAccessor, TableRef,
+        has_tombstone_column, tombstone_column_id, try_add_subtract_column_types,
+        try_case_column_types, try_multiply_column_types, ColumnOperationError, ColumnRef,
+        ColumnType, SchemaAccessor, TableRef,
     },
     math::{
         decimal::{DecimalError, Precision},
         BigDecimalExt,
     },
 };
-use alloc::{boxed::Box, format, string::ToString, vec::Vec};
+use alloc::{boxed::Box, format, string::ToString, vec, vec::Vec};
 use proof_of_sql_parser::{
     intermediate_ast::{
-        AggregationOperator, AliasedResultExpr, Expression, Literal, OrderBy, SelectResultExpr,
-        Slice, TableExpression,
+        self, AggregationOperator, AliasedResultExpr, Expression, Literal, OrderBy,
+        SelectResultExpr, ShiftDirection, Slice, TableExpression,
     },
     Identifier, ResourceId,
 };
@@ -21,6 +25,7 @@ use sqlparser::ast::{BinaryOperator, UnaryOperator};
 pub struct QueryContextBuilder<'a> {
     context: QueryContext,
     schema_accessor: &'a dyn SchemaAccessor,
+    type_check_mode: TypeCheckMode,
 }
 
 // Public interface
@@ -29,17 +34,32 @@ impl<'a> QueryContextBuilder<'a> {
         Self {
             context: QueryContext::default(),
             schema_accessor,
+            type_check_mode: TypeCheckMode::default(),
         }
     }
 
-    #[allow(clippy::vec_box, clippy::missing_panics_doc)]
+    /// Set how strictly the types of operands are checked.
+    pub fn with_type_check_mode(mut self, type_check_mode: TypeCheckMode) -> Self {
+        self.type_check_mode = type_check_mode;
+        self
+    }
+
+    /// Visit the `FROM` clause, which must name exactly one table.
+    #[allow(clippy::vec_box)]
     pub fn visit_table_expr(
         mut self,
         table_expr: &[Box<TableExpression>],
         default_schema: Identifier,
-    ) -> Self {
-        assert_eq!(table_expr.len(), 1);
-        match *table_expr[0] {
+    ) -> ConversionResult<Self> {
+        let [table_expr] = table_expr else {
+            return Err(ConversionError::Unprovable {
+                error: format!(
+                    "a query must select from exactly one table, but this one selects from {}",
+                    table_expr.len()
+                ),
+            });
+        };
+        match **table_expr {
             TableExpression::Named { table, schema } => {
                 self.context.set_table_ref(TableRef::new(ResourceId::new(
                     schema.unwrap_or(default_schema),
@@ -47,13 +67,28 @@ impl<'a> QueryContextBuilder<'a> {
                 )));
             }
         }
-        self
+        Ok(self)
     }
 
+    /// Visit the `WHERE` clause, which excludes the deleted rows of a table with a tombstone column.
     pub fn visit_where_expr(
         mut self,
         mut where_expr: Option<Box<Expression>>,
     ) -> ConversionResult<Self> {
+        if has_tombstone_column(self.schema_accessor, *self.context.get_table_ref()) {
+            let not_deleted = Box::new(Expression::Unary {
+                op: intermediate_ast::UnaryOperator::Not,
+                expr: Box::new(Expression::Column(tombstone_column_id())),
+            });
+            where_expr = Some(match where_expr {
+                Some(right) => Box::new(Expression::Binary {
+                    op: intermediate_ast::BinaryOperator::And,
+                    left: not_deleted,
+                    right,
+                }),
+                None => not_deleted,
+            });
+        }
         if let Some(expr) = where_expr.as_deref_mut() {
             self.visit_expr(expr)?;
         }
@@ -61,6 +96,23 @@ impl<'a> QueryContextBuilder<'a> {
         Ok(self)
     }
 
+    pub fn visit_having_expr(
+        mut self,
+        having_expr: Option<Box<Expression>>,
+    ) -> ConversionResult<Self> {
+        if let Some(expr) = having_expr.as_deref() {
+            let dtype = self.visit_expr(expr)?;
+            if dtype != ColumnType::Boolean {
+                return Err(ConversionError::InvalidDataType {
+                    expected: ColumnType::Boolean,
+                    actual: dtype,
+                });
+            }
+        }
+        self.context.set_having_expr(having_expr);
+        Ok(self)
+    }
+
     pub fn visit_result_exprs(
         mut self,
         result_exprs: Vec<SelectResultExpr>,
@@ -69,6 +121,9 @@ impl<'a> QueryContextBuilder<'a> {
         for column in result_exprs {
             match column {
                 SelectResultExpr::ALL => self.visit_select_all_expr()?,
+                SelectResultExpr::QualifiedAll(qualifier) => {
+                    self.visit_qualified_select_all_expr(qualifier)?;
+                }
                 SelectResultExpr::AliasedResultExpr(expr) => self.visit_aliased_expr(expr)?,
             }
         }
@@ -106,19 +161,38 @@ impl<'a> QueryContextBuilder<'a> {
 
 // Private interface
 impl<'a> QueryContextBuilder<'a> {
-    #[allow(
-        clippy::missing_panics_doc,
-        reason = "The assertion ensures there is at least one column, and this is a fundamental requirement for schema retrieval."
-    )]
-    fn lookup_schema(&self) -> Vec<(Identifier, ColumnType)> {
-        let table_ref = self.context.get_table_ref();
-        let columns = self.schema_accessor.lookup_schema(*table_ref);
-        assert!(!columns.is_empty(), "At least one column must exist");
-        columns
+    /// The tables of the `FROM` clause, qualified by their table names.
+    ///
+    /// The tombstone column is omitted, so that it is not part of the expansion of a wildcard.
+    /// A table that does not exist has no columns, so a wildcard over it selects nothing.
+    fn tables_in_from_clause(&self) -> Vec<FromTable> {
+        let tombstone_column_id = tombstone_column_id();
+        let table_ref = *self.context.get_table_ref();
+        vec![(
+            table_ref.table_id(),
+            self.schema_accessor
+                .lookup_schema(table_ref)
+                .into_iter()
+                .filter(|(column_id, _)| *column_id != tombstone_column_id)
+                .collect(),
+        )]
     }
 
     fn visit_select_all_expr(&mut self) -> ConversionResult<()> {
-        for (column_name, _) in self.lookup_schema() {
+        let columns = expand_wildcard(&self.tables_in_from_clause())?;
+        self.visit_wildcard_columns(columns)
+    }
+
+    fn visit_qualified_select_all_expr(&mut self, qualifier: Identifier) -> ConversionResult<()> {
+        let columns = expand_qualified_wildcard(qualifier, &self.tables_in_from_clause())?;
+        self.visit_wildcard_columns(columns)
+    }
+
+    fn visit_wildcard_columns(
+        &mut self,
+        columns: Vec<(Identifier, ColumnType)>,
+    ) -> ConversionResult<()> {
+        for (column_name, _) in columns {
             let col_expr = Expression::Column(column_name);
             self.visit_aliased_expr(AliasedResultExpr::new(col_expr, column_name))?;
         }
@@ -141,7 +215,30 @@ impl<'a> QueryContextBuilder<'a> {
             Expression::Binary { op, left, right } => {
                 self.visit_binary_expr(&(*op).into(), left, right)
             }
-            Expression::Aggregation { op, expr } => self.visit_agg_expr(*op, expr),
+            Expression::Aggregation { op, expr, filter } => {
+                self.visit_agg_expr(*op, expr, filter.as_deref())
+            }
+            Expression::DateTrunc { expr, .. } => self.visit_date_trunc_expr(expr),
+            Expression::Case {
+                condition,
+                then_result,
+                else_result,
+            } => self.visit_case_expr(condition, then_result, else_result),
+            Expression::RowNumber(order_by) => self.visit_row_number_expr(order_by),
+            Expression::Shift {
+                direction,
+                column,
+                order_by,
+            } => self.visit_shift_expr(*direction, *column, order_by),
+            Expression::Exists(_) => Err(ConversionError::Unprovable {
+                error: "EXISTS subqueries are not supported yet".into(),
+            }),
+            Expression::ScalarSubquery(_) => Err(ConversionError::Unprovable {
+                error: "scalar subqueries are not supported yet".into(),
+            }),
+            Expression::Placeholder(index) => {
+                Err(ConversionError::UnboundPlaceholder { index: *index })
+            }
         }
     }
 
@@ -156,6 +253,34 @@ impl<'a> QueryContextBuilder<'a> {
         self.visit_column_identifier(identifier)
     }
 
+    fn visit_row_number_expr(&mut self, order_by: &OrderBy) -> ConversionResult<ColumnType> {
+        if !self.context.is_in_result_scope() {
+            return Err(ConversionError::InvalidExpression {
+                expression: "ROW_NUMBER() is only allowed in the result columns".to_string(),
+            });
+        }
+        self.visit_column_identifier(order_by.expr)?;
+        self.context.push_row_number();
+        Ok(ColumnType::BigInt)
+    }
+
+    fn visit_shift_expr(
+        &mut self,
+        direction: ShiftDirection,
+        column: Identifier,
+        order_by: &OrderBy,
+    ) -> ConversionResult<ColumnType> {
+        if !self.context.is_in_result_scope() {
+            return Err(ConversionError::InvalidExpression {
+                expression: format!("{direction} is only allowed in the result columns"),
+            });
+        }
+        self.visit_column_identifier(order_by.expr)?;
+        let column_type = self.visit_column_identifier(column)?;
+        self.context.push_shift();
+        Ok(column_type)
+    }
+
     fn visit_binary_expr(
         &mut self,
         op: &BinaryOperator,
@@ -165,6 +290,8 @@ impl<'a> QueryContextBuilder<'a> {
         let left_dtype = self.visit_expr(left)?;
         let right_dtype = self.visit_expr(right)?;
         check_dtypes(left_dtype, right_dtype, op)?;
+        self.type_check_mode
+            .check_no_coercion(left_dtype, right_dtype)?;
         match op {
             BinaryOperator::And
             | BinaryOperator::Or
@@ -175,6 +302,8 @@ impl<'a> QueryContextBuilder<'a> {
             | BinaryOperator::Divide
             | BinaryOperator::Minus
             | BinaryOperator::Plus => Ok(left_dtype),
+            BinaryOperator::Modulo => Ok(ColumnType::BigInt),
+            BinaryOperator::StringConcat => Ok(ColumnType::VarChar),
             _ => {
                 // Handle unsupported binary operations
                 Err(ConversionError::UnsupportedOperation {
@@ -200,6 +329,22 @@ impl<'a> QueryContextBuilder<'a> {
                 }
                 Ok(ColumnType::Boolean)
             }
+            UnaryOperator::Minus | UnaryOperator::PGAbs => {
+                let dtype = self.visit_expr(expr)?;
+                if !dtype.is_numeric() {
+                    return Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                        operator: if op == UnaryOperator::Minus {
+                            "-"
+                        } else {
+                            "ABS"
+                        }
+                        .to_string(),
+                        operand_type: dtype,
+                    }
+                    .into());
+                }
+                Ok(dtype)
+            }
             // Handle unsupported operators
             _ => Err(ConversionError::UnsupportedOperation {
                 message: format!("{op:?}"),
@@ -207,17 +352,89 @@ impl<'a> QueryContextBuilder<'a> {
         }
     }
 
+    fn visit_date_trunc_expr(&mut self, expr: &Expression) -> ConversionResult<ColumnType> {
+        let dtype = self.visit_expr(expr)?;
+        if !matches!(dtype, ColumnType::TimestampTZ(_, _)) {
+            return Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "DATE_TRUNC".to_string(),
+                operand_type: dtype,
+            }
+            .into());
+        }
+        Ok(dtype)
+    }
+
+    fn visit_case_expr(
+        &mut self,
+        condition: &Expression,
+        then_result: &Expression,
+        else_result: &Expression,
+    ) -> ConversionResult<ColumnType> {
+        let condition_dtype = self.visit_expr(condition)?;
+        if condition_dtype != ColumnType::Boolean {
+            return Err(ConversionError::InvalidDataType {
+                expected: ColumnType::Boolean,
+                actual: condition_dtype,
+            });
+        }
+        let then_dtype = self.visit_expr(then_result)?;
+        let else_dtype = self.visit_expr(else_result)?;
+        self.type_check_mode
+            .check_no_coercion(then_dtype, else_dtype)?;
+        try_case_column_types(then_dtype, else_dtype).map_err(|_| {
+            ConversionError::DataTypeMismatch {
+                left_type: then_dtype.to_string(),
+                right_type: else_dtype.to_string(),
+            }
+        })
+    }
+
     fn visit_agg_expr(
         &mut self,
         op: AggregationOperator,
         expr: &Expression,
+        filter: Option<&Expression>,
     ) -> ConversionResult<ColumnType> {
         self.context.set_in_agg_scope(true)?;
 
         let expr_dtype = self.visit_expr(expr)?;
 
-        // We only support sum/max/min aggregations on numeric columns.
-        if op != AggregationOperator::Count && expr_dtype == ColumnType::VarChar {
+        // Only the rows of sums and counts can be restricted by a filter
+        if let Some(filter) = filter {
+            if !matches!(op, AggregationOperator::Sum | AggregationOperator::Count) {
+                return Err(ConversionError::Unprovable {
+                    error: format!("{op} with a FILTER clause is not supported"),
+                });
+            }
+            let filter_dtype = self.visit_expr(filter)?;
+            if filter_dtype != ColumnType::Boolean {
+                return Err(ConversionError::InvalidDataType {
+                    expected: ColumnType::Boolean,
+                    actual: filter_dtype,
+                });
+            }
+            self.context.push_agg_filter();
+        }
+
+        // We only support counting the distinct values of integer columns
+        // and sum/max/min aggregations on numeric columns.
+        if op == AggregationOperator::CountDistinct {
+            if !expr_dtype.is_integer() {
+                return Err(ConversionError::Unprovable {
+                    error: format!("count distinct of type {expr_dtype} is not supported"),
+                });
+            }
+            self.context.push_count_distinct();
+        } else if op == AggregationOperator::ApproxCountDistinct {
+            if !expr_dtype.is_integer() {
+                return Err(ConversionError::Unprovable {
+                    error: format!("approx_count_distinct of type {expr_dtype} is not supported"),
+                });
+            }
+            self.context.push_approx_count_distinct();
+        } else if op != AggregationOperator::Count
+            && matches!(expr_dtype, ColumnType::VarChar | ColumnType::FixedBytes(_))
+        {
             return Err(ConversionError::non_numeric_expr_in_agg(
                 expr_dtype.to_string(),
                 op.to_string(),
@@ -226,8 +443,13 @@ impl<'a> QueryContextBuilder<'a> {
 
         self.context.set_in_agg_scope(false)?;
 
-        // Count aggregation always results in an integer type
-        if op == AggregationOperator::Count {
+        // Count aggregations always result in an integer type
+        if matches!(
+            op,
+            AggregationOperator::Count
+                | AggregationOperator::CountDistinct
+                | AggregationOperator::ApproxCountDistinct
+        ) {
             Ok(ColumnType::BigInt)
         } else {
             Ok(expr_dtype)
@@ -239,6 +461,7 @@ impl<'a> QueryContextBuilder<'a> {
         match literal {
             Literal::Boolean(_) => Ok(ColumnType::Boolean),
             Literal::BigInt(_) => Ok(ColumnType::BigInt),
+            Literal::UInt64(_) => Ok(ColumnType::UInt64),
             Literal::Int128(_) => Ok(ColumnType::Int128),
             Literal::VarChar(_) => Ok(ColumnType::VarChar),
             Literal::Decimal(d) => {
@@ -252,6 +475,11 @@ impl<'a> QueryContextBuilder<'a> {
                 ))
             }
             Literal::Timestamp(its) => Ok(ColumnType::TimestampTZ(its.timeunit(), its.timezone())),
+            Literal::FixedBytes(bytes) => u8::try_from(bytes.len())
+                .map(ColumnType::FixedBytes)
+                .map_err(|_| ConversionError::InvalidExpression {
+                    expression: "binary literals can be at most 255 bytes long".to_string(),
+                }),
         }
     }
 
@@ -293,6 +521,10 @@ pub(crate) fn type_check_binary_operation(
                     | (ColumnType::Boolean, ColumnType::Boolean)
                     | (_, ColumnType::Scalar)
                     | (ColumnType::Scalar, _)
+            ) || matches!(
+                (left_dtype, right_dtype),
+                (ColumnType::FixedBytes(left_width), ColumnType::FixedBytes(right_width))
+                    if left_width == right_width
             ) || (left_dtype.is_numeric() && right_dtype.is_numeric())
         }
         BinaryOperator::GtEq | BinaryOperator::LtEq => {
@@ -322,6 +554,10 @@ pub(crate) fn type_check_binary_operation(
         }
         BinaryOperator::Multiply => try_multiply_column_types(*left_dtype, *right_dtype).is_ok(),
         BinaryOperator::Divide => left_dtype.is_numeric() && right_dtype.is_numeric(),
+        BinaryOperator::Modulo => left_dtype.is_integer() && right_dtype.is_integer(),
+        BinaryOperator::StringConcat => {
+            left_dtype == &ColumnType::VarChar && right_dtype == &ColumnType::VarChar
+        }
         _ => {
             // Handle unsupported binary operations
             false
@@ -1,4 +1,6 @@
-use super::{EnrichedExpr, FilterExecBuilder, QueryContextBuilder};
+use super::{
+    ColumnRenames, EnrichedExpr, FilterExecBuilder, QueryContextBuilder, TypeCheckMode, View,
+};
 use crate::{
     base::database::SchemaAccessor,
     sql::{
@@ -44,11 +46,62 @@ impl QueryExpr {
         }
     }
 
+    /// Parse an intermediate AST `SelectStatement` that may select from one of `views` into a `QueryExpr`.
+    ///
+    /// A query that selects from a view is planned as the equivalent query on the view's underlying table.
+    /// See [`View`].
+    pub fn try_new_with_views(
+        ast: SelectStatement,
+        default_schema: Identifier,
+        schema_accessor: &dyn SchemaAccessor,
+        views: &[View],
+    ) -> ConversionResult<Self> {
+        let ast = views
+            .iter()
+            .try_fold(ast, |ast, view| view.inline(ast, default_schema))?;
+        Self::try_new(ast, default_schema, schema_accessor)
+    }
+
+    /// Parse an intermediate AST `SelectStatement` that may use old names of renamed columns into a `QueryExpr`.
+    ///
+    /// The query is planned as the equivalent query using the current names. See [`ColumnRenames`].
+    pub fn try_new_with_column_renames(
+        ast: SelectStatement,
+        default_schema: Identifier,
+        schema_accessor: &dyn SchemaAccessor,
+        column_renames: &ColumnRenames,
+    ) -> ConversionResult<Self> {
+        Self::try_new(
+            column_renames.apply(ast, default_schema),
+            default_schema,
+            schema_accessor,
+        )
+    }
+
     /// Parse an intermediate AST `SelectStatement` into a `QueryExpr`.
+    ///
+    /// Operands of different types are coerced to a common type where possible, as with [`TypeCheckMode::Lenient`].
     pub fn try_new(
         ast: SelectStatement,
         default_schema: Identifier,
         schema_accessor: &dyn SchemaAccessor,
+    ) -> ConversionResult<Self> {
+        Self::try_new_with_type_check_mode(
+            ast,
+            default_schema,
+            schema_accessor,
+            TypeCheckMode::Lenient,
+        )
+    }
+
+    /// Parse an intermediate AST `SelectStatement` into a `QueryExpr`, checking the types of operands
+    /// as `type_check_mode` says.
+    #[allow(clippy::too_many_lines)]
+    pub fn try_new_with_type_check_mode(
+        ast: SelectStatement,
+        default_schema: Identifier,
+        schema_accessor: &dyn SchemaAccessor,
+        type_check_mode: TypeCheckMode,
     ) -> ConversionResult<Self> {
         let context = match *ast.expr {
             SetExpression::Query {
@@ -56,11 +109,14 @@ impl QueryExpr {
                 from,
                 where_expr,
                 group_by,
+                having,
             } => QueryContextBuilder::new(schema_accessor)
-                .visit_table_expr(&from, default_schema)
+                .with_type_check_mode(type_check_mode)
+                .visit_table_expr(&from, default_schema)?
                 .visit_group_by_exprs(group_by)?
                 .visit_result_exprs(result_exprs)?
                 .visit_where_expr(where_expr)?
+                .visit_having_expr(having)?
                 .visit_order_by_exprs(ast.order_by)
                 .visit_slice_expr(ast.slice)
                 .build()?,
@@ -81,7 +137,43 @@ impl QueryExpr {
                 SlicePostprocessing::new(Some(slice.number_rows), Some(slice.offset_value)),
             ));
         }
-        if context.has_agg() {
+        if let Some((row_number_expr, reorder)) = context.try_row_number()? {
+            if let Some(reorder) = reorder {
+                postprocessing.insert(0, OwnedTablePostprocessing::new_select(reorder));
+            }
+            Ok(Self {
+                proof_expr: DynProofPlan::RowNumber(row_number_expr),
+                postprocessing,
+            })
+        } else if let Some((shift_expr, reorder)) = context.try_shift()? {
+            if let Some(reorder) = reorder {
+                postprocessing.insert(0, OwnedTablePostprocessing::new_select(reorder));
+            }
+            Ok(Self {
+                proof_expr: DynProofPlan::Shift(shift_expr),
+                postprocessing,
+            })
+        } else if let Some((group_by_expr, count_distinct)) = context.try_count_distinct()? {
+            postprocessing.insert(0, OwnedTablePostprocessing::new_group_by(count_distinct));
+            Ok(Self {
+                proof_expr: DynProofPlan::GroupBy(group_by_expr),
+                postprocessing,
+            })
+        } else if let Some((group_by_expr, hyperloglog)) = context.try_approx_count_distinct()? {
+            postprocessing.insert(0, OwnedTablePostprocessing::new_hyperloglog(hyperloglog));
+            Ok(Self {
+                proof_expr: DynProofPlan::GroupBy(group_by_expr),
+                postprocessing,
+            })
+        } else if let Some((group_by_expr, reorder)) = context.try_filtered_aggregation()? {
+            if let Some(reorder) = reorder {
+                postprocessing.insert(0, OwnedTablePostprocessing::new_select(reorder));
+            }
+            Ok(Self {
+                proof_expr: DynProofPlan::GroupBy(group_by_expr),
+                postprocessing,
+            })
+        } else if context.has_agg() {
             if let Some(group_by_expr) = Option::<GroupByExec>::try_from(&context)? {
                 Ok(Self {
                     proof_expr: DynProofPlan::GroupBy(group_by_expr),
@@ -101,8 +193,11 @@ impl QueryExpr {
                     .add_result_columns(&raw_enriched_exprs)
                     .build();
 
-                let group_by_postprocessing =
-                    GroupByPostprocessing::try_new(group_by.to_vec(), result_aliased_exprs)?;
+                let group_by_postprocessing = GroupByPostprocessing::try_new_with_having(
+                    group_by.to_vec(),
+                    result_aliased_exprs,
+                    context.get_having_expr().as_deref().cloned(),
+                )?;
                 postprocessing.insert(
                     0,
                     OwnedTablePostprocessing::new_group_by(group_by_postprocessing.clone()),
@@ -1,7 +1,7 @@
 use super::ConversionError;
 use crate::{
     base::{
-        database::{ColumnType, TableRef, TestSchemaAccessor},
+        database::{ColumnOperationError, ColumnType, TableRef, TestSchemaAccessor},
         map::{indexmap, IndexMap, IndexSet},
     },
     sql::{
@@ -13,11 +13,11 @@ use crate::{
 };
 use itertools::Itertools;
 use proof_of_sql_parser::{
-    intermediate_ast::OrderByDirection::*,
+    intermediate_ast::{OrderByDirection::*, SetExpression, ShiftDirection},
     sql::SelectStatementParser,
     utility::{
-        add as padd, aliased_expr, col, count, count_all, lit, max, min, mul as pmul, sub as psub,
-        sum,
+        add as padd, aliased_expr, and as pand, col, count, count_all, ge as pge, le as ple, lit,
+        max, min, mul as pmul, sub as psub, sum,
     },
     Identifier,
 };
@@ -87,6 +87,31 @@ fn we_can_convert_an_ast_with_one_column_and_i128_data() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_convert_an_ast_with_one_column_and_a_filter_by_an_unsigned_literal() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::UInt64,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select a from sxt_tab where a >= ubigint 9223372036854775808",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            gte(column(t, "a", &accessor), const_uint64(i64::MAX as u64 + 1)),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
 #[test]
 fn we_can_convert_an_ast_with_one_column_and_a_filter_by_a_string_literal() {
     let t = "sxt.sxt_tab".parse().unwrap();
@@ -235,6 +260,56 @@ fn we_can_parse_all_result_columns_with_select_star() {
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_can_parse_all_result_columns_with_a_qualified_select_star() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "b".parse().unwrap() => ColumnType::BigInt,
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select a as c, sxt_tab.* from sxt_tab where a = 3",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            vec![
+                aliased_plan(column(t, "a", &accessor), "c"),
+                col_expr_plan(t, "b", &accessor),
+                col_expr_plan(t, "a", &accessor),
+            ],
+            tab(t),
+            equal(column(t, "a", &accessor), const_bigint(3)),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_convert_an_ast_with_a_qualified_select_star_of_another_table() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select other_tab.* from sxt_tab")
+        .unwrap();
+    assert_eq!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::MissingWildcardTable {
+            qualifier: Box::new("other_tab".parse().unwrap())
+        })
+    );
+}
+
 #[test]
 fn we_can_convert_an_ast_with_one_positive_cond() {
     let t = "sxt.sxt_tab".parse().unwrap();
@@ -537,6 +612,182 @@ fn we_cannot_convert_an_ast_with_a_nonexistent_column() {
     invalid_query_to_provable_ast(t, "select * from sxt_tab where a = 3", &accessor);
 }
 
+#[test]
+fn we_cannot_convert_an_ast_with_an_exists_subquery() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "k".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    for query in [
+        "select a from sxt_tab where exists (select k from sxt_tab where k = 3)",
+        "select a from sxt_tab where not exists (select k from sxt_tab)",
+    ] {
+        let intermediate_ast = SelectStatementParser::new().parse(query).unwrap();
+        assert!(matches!(
+            QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+            Err(ConversionError::Unprovable { .. })
+        ));
+    }
+}
+
+#[test]
+fn we_can_convert_an_ast_with_modulo() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::Int,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select a % 3 as r, b from sxt_tab where b % -2 = 1",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            vec![
+                aliased_plan(modulo(column(t, "a", &accessor), const_bigint(3)), "r"),
+                col_expr_plan(t, "b", &accessor),
+            ],
+            tab(t),
+            equal(
+                modulo(column(t, "b", &accessor), const_bigint(-2)),
+                const_bigint(1),
+            ),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_convert_an_ast_with_modulo_by_zero_or_by_a_column() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select a from sxt_tab where a % 0 = 1")
+        .unwrap();
+    assert!(matches!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::ColumnOperationError {
+            source: ColumnOperationError::DivisionByZero
+        })
+    ));
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select a from sxt_tab where a % b = 1")
+        .unwrap();
+    assert!(matches!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::Unprovable { .. })
+    ));
+}
+
+#[test]
+fn we_can_convert_an_ast_with_case_when() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::VarChar,
+            "c".parse().unwrap() => ColumnType::Boolean,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select case when a >= 3 then b else 'small' end as r, a from sxt_tab \
+         where case when c then a = 1 when a = 2 then true else false end",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            vec![
+                aliased_plan(
+                    case_when(
+                        gte(column(t, "a", &accessor), const_bigint(3)),
+                        column(t, "b", &accessor),
+                        const_varchar("small"),
+                    ),
+                    "r",
+                ),
+                col_expr_plan(t, "a", &accessor),
+            ],
+            tab(t),
+            case_when(
+                column(t, "c", &accessor),
+                equal(column(t, "a", &accessor), const_bigint(1)),
+                case_when(
+                    equal(column(t, "a", &accessor), const_bigint(2)),
+                    const_bool(true),
+                    const_bool(false),
+                ),
+            ),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_convert_an_ast_with_case_when_with_a_non_boolean_condition_or_mismatched_results() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::VarChar,
+        },
+    );
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select case when a then 1 else 2 end as r from sxt_tab")
+        .unwrap();
+    assert!(matches!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    let intermediate_ast = SelectStatementParser::new()
+        .parse("select case when a = 1 then b else 2 end as r from sxt_tab")
+        .unwrap();
+    assert!(matches!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_convert_an_ast_with_a_scalar_subquery() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "k".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    for query in [
+        "select a, (select max(a) from sxt_tab where k = 3) as m from sxt_tab",
+        "select a from sxt_tab where a = (select min(a) from sxt_tab)",
+    ] {
+        let intermediate_ast = SelectStatementParser::new().parse(query).unwrap();
+        assert!(matches!(
+            QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+            Err(ConversionError::Unprovable { .. })
+        ));
+    }
+}
+
 #[test]
 fn we_cannot_convert_an_ast_with_a_column_type_different_than_equal_literal() {
     let t = "sxt.sxt_tab".parse().unwrap();
@@ -746,219 +997,684 @@ fn we_cannot_parse_order_by_referencing_an_alias_name_associated_with_two_differ
         "select salary as name, name from sxt_tab order by salary desc",
         &accessor,
     );
-    // Note: This is not ambiguous with PostgreSQL either,
-    // but it is with our code for the reasons mentioned above.
-    invalid_query_to_provable_ast(
+    // Note: This is not ambiguous with PostgreSQL either,
+    // but it is with our code for the reasons mentioned above.
+    invalid_query_to_provable_ast(
+        t,
+        "select salary as s, name as s from sxt_tab order by salary desc",
+        &accessor,
+    );
+}
+
+#[test]
+fn we_can_parse_order_by_queries_with_the_same_column_name_appearing_more_than_once_and_with_different_alias_name(
+) {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "name".parse().unwrap() => ColumnType::VarChar,
+        },
+    );
+    for order_by in ["s", "d"] {
+        let ast = query_to_provable_ast(
+            t,
+            &("select salary as s, name, salary as d from sxt_tab order by ".to_owned() + order_by),
+            &accessor,
+        );
+        let expected_ast = QueryExpr::new(
+            filter(
+                vec![
+                    aliased_col_expr_plan(t, "salary", "s", &accessor),
+                    col_expr_plan(t, "name", &accessor),
+                    aliased_col_expr_plan(t, "salary", "d", &accessor),
+                ],
+                tab(t),
+                const_bool(true),
+            ),
+            vec![orders(&[order_by], &[Asc])],
+        );
+        assert_eq!(ast, expected_ast);
+    }
+}
+
+/////////////////////////
+// Slice
+/////////////////////////
+
+#[test]
+fn we_can_parse_a_query_having_a_simple_limit_clause() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(t, "select a from sxt_tab limit 3", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![slice(Some(3), Some(0))],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn slice_is_still_applied_when_limit_is_u64_max_and_offset_is_zero() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(t, "select a from sxt_tab offset 0", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![slice(Some(u64::MAX), Some(0))],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_having_a_simple_positive_offset_clause() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(t, "select a from sxt_tab offset 7", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![slice(Some(u64::MAX), Some(7))],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_having_a_negative_offset_clause() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(t, "select a from sxt_tab offset -7", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![slice(Some(u64::MAX), Some(-7))],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_parse_a_query_having_a_simple_limit_and_offset_clause() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(t, "select a from sxt_tab limit 55 offset 3", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![slice(Some(55), Some(3))],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn fetch_first_and_top_produce_the_same_plan_as_limit() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            const_bool(true),
+        ),
+        vec![orders(&["a"], &[Desc]), slice(Some(5), Some(0))],
+    );
+    for query in [
+        "select a from sxt_tab order by a desc limit 5",
+        "select a from sxt_tab order by a desc fetch first 5 rows only",
+        "select top 5 a from sxt_tab order by a desc",
+    ] {
+        assert_eq!(query_to_provable_ast(t, query, &accessor), expected_ast);
+    }
+}
+
+///////////////////////////
+// Composition Expressions
+///////////////////////////
+#[test]
+fn we_can_parse_a_query_having_a_simple_limit_and_offset_clause_preceded_by_where_expr_and_order_by(
+) {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "boolean".parse().unwrap() => ColumnType::Boolean,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select a, boolean and a >= 4 as res from sxt_tab where a = -3 order by a desc limit 55 offset 3",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            vec![
+                col_expr_plan(t, "a", &accessor),
+                aliased_plan(
+                    and(
+                        column(t, "boolean", &accessor),
+                        gte(column(t, "a", &accessor), const_bigint(4)),
+                    ),
+                    "res",
+                ),
+            ],
+            tab(t),
+            equal(column(t, "a", &accessor), const_bigint(-3)),
+        ),
+        vec![orders(&["a"], &[Desc]), slice(Some(55), Some(3))],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+///////////////////////////
+// Group By Expressions - Prover
+///////////////////////////
+#[test]
+fn we_can_do_provable_group_by() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select department, sum(salary) as total_salary, count(*) as num_employee from employees group by department",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        group_by(
+            cols_expr(t, &["department"], &accessor),
+            vec![sum_expr(column(t, "salary", &accessor), "total_salary")],
+            "num_employee",
+            tab(t),
+            const_bool(true),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_filter_aggregated_rows_with_having_in_postprocessing() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::BigInt,
+            "bonus".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    // This would be a provable group by without the HAVING clause
+    let ast = query_to_provable_ast(
+        t,
+        "select department, sum(salary) as total_salary, count(*) as num_employee from employees \
+         where salary >= 0 group by department having count(*) >= 2 and sum(bonus) <= 10",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["bonus", "department", "salary"], &accessor),
+            tab(t),
+            gte(column(t, "salary", &accessor), const_bigint(0)),
+        ),
+        vec![group_by_having_postprocessing(
+            &["department"],
+            &[
+                aliased_expr(col("department"), "department"),
+                aliased_expr(sum(col("salary")), "total_salary"),
+                aliased_expr(count_all(), "num_employee"),
+            ],
+            *pand(pge(count_all(), lit(2)), ple(sum(col("bonus")), lit(10))),
+        )],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_use_invalid_having_clauses() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let query = |query_text: &str| {
+        let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor)
+    };
+    assert!(matches!(
+        query("select department from employees group by department having salary >= 2"),
+        Err(ConversionError::PostprocessingError {
+            source: PostprocessingError::IdentifierNotInAggregationOperatorOrGroupByClause { .. }
+        })
+    ));
+    assert!(matches!(
+        query("select department from employees group by department having sum(salary)"),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    assert!(matches!(
+        query("select department from employees group by department having sum(bonus) >= 2"),
+        Err(ConversionError::MissingColumn { .. })
+    ));
+}
+
+#[test]
+fn we_can_do_provable_group_by_without_sum() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select department, count(*) as num_employee from employees group by department",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        group_by(
+            cols_expr(t, &["department"], &accessor),
+            vec![],
+            "num_employee",
+            tab(t),
+            const_bool(true),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_do_provable_count_distinct() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::Int,
+        },
+    );
+    let ast = query_to_provable_ast(
+        t,
+        "select count(distinct department) as num_departments from employees where salary >= 4",
+        &accessor,
+    );
+    let expected_ast = QueryExpr::new(
+        group_by(
+            cols_expr(t, &["department"], &accessor),
+            vec![],
+            "__count_distinct_rows__",
+            tab(t),
+            gte(column(t, "salary", &accessor), const_bigint(4)),
+        ),
+        vec![group_by_postprocessing(
+            &[],
+            &[aliased_expr(count(col("department")), "num_departments")],
+        )],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_can_do_provable_approx_count_distinct() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::Int,
+        },
+    );
+    let ast = query_to_provable_ast(
         t,
-        "select salary as s, name as s from sxt_tab order by salary desc",
+        "select approx_count_distinct(department) as num_departments from employees where salary >= 4",
         &accessor,
     );
+    let expected_ast = QueryExpr::new(
+        group_by_key_exprs(
+            vec![aliased_plan(
+                hyperloglog_update_expr(column(t, "department", &accessor), 12),
+                "__hyperloglog_updates__",
+            )],
+            vec![],
+            "__hyperloglog_update_rows__",
+            tab(t),
+            gte(column(t, "salary", &accessor), const_bigint(4)),
+        ),
+        vec![hyperloglog(
+            "__hyperloglog_updates__",
+            12,
+            "num_departments",
+            "num_departments_standard_error",
+        )],
+    );
+    assert_eq!(ast, expected_ast);
 }
 
 #[test]
-fn we_can_parse_order_by_queries_with_the_same_column_name_appearing_more_than_once_and_with_different_alias_name(
-) {
-    let t = "sxt.sxt_tab".parse().unwrap();
+fn we_cannot_do_unsupported_approx_count_distinct() {
+    let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
             "salary".parse().unwrap() => ColumnType::BigInt,
             "name".parse().unwrap() => ColumnType::VarChar,
+            "bonus".parse().unwrap() => ColumnType::Int128,
         },
     );
-    for order_by in ["s", "d"] {
-        let ast = query_to_provable_ast(
-            t,
-            &("select salary as s, name, salary as d from sxt_tab order by ".to_owned() + order_by),
-            &accessor,
-        );
-        let expected_ast = QueryExpr::new(
-            filter(
-                vec![
-                    aliased_col_expr_plan(t, "salary", "s", &accessor),
-                    col_expr_plan(t, "name", &accessor),
-                    aliased_col_expr_plan(t, "salary", "d", &accessor),
-                ],
-                tab(t),
-                const_bool(true),
+    for query in [
+        "select approx_count_distinct(name) as n from employees",
+        "select approx_count_distinct(bonus) as n from employees",
+        "select approx_count_distinct(salary + 1) as n from employees",
+        "select approx_count_distinct(salary) as n, count(*) as c from employees",
+        "select name, approx_count_distinct(salary) as n from employees group by name",
+    ] {
+        let intermediate_ast = SelectStatementParser::new().parse(query).unwrap();
+        assert!(
+            matches!(
+                QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+                Err(ConversionError::Unprovable { .. })
             ),
-            vec![orders(&[order_by], &[Asc])],
+            "{query}"
         );
-        assert_eq!(ast, expected_ast);
     }
 }
 
-/////////////////////////
-// Slice
-/////////////////////////
-
 #[test]
-fn we_can_parse_a_query_having_a_simple_limit_clause() {
-    let t = "sxt.sxt_tab".parse().unwrap();
+fn we_can_do_provable_row_number() {
+    let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
-            "a".parse().unwrap() => ColumnType::BigInt,
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "name".parse().unwrap() => ColumnType::VarChar,
         },
     );
-    let ast = query_to_provable_ast(t, "select a from sxt_tab limit 3", &accessor);
+    let ast = query_to_provable_ast(
+        t,
+        "select name, salary as pay, row_number() over (order by salary desc) as rank from employees where salary >= 4 order by name limit 2",
+        &accessor,
+    );
     let expected_ast = QueryExpr::new(
-        filter(
-            cols_expr_plan(t, &["a"], &accessor),
+        row_number(
+            vec![
+                col_expr_plan(t, "name", &accessor),
+                aliased_col_expr_plan(t, "salary", "pay", &accessor),
+            ],
             tab(t),
-            const_bool(true),
+            gte(column(t, "salary", &accessor), const_bigint(4)),
+            "pay",
+            Desc,
+            "rank",
         ),
-        vec![slice(Some(3), Some(0))],
+        vec![orders(&["name"], &[Asc]), slice(Some(2), Some(0))],
     );
     assert_eq!(ast, expected_ast);
 }
 
 #[test]
-fn slice_is_still_applied_when_limit_is_u64_max_and_offset_is_zero() {
-    let t = "sxt.sxt_tab".parse().unwrap();
+fn we_can_do_provable_row_number_by_a_column_that_is_not_selected() {
+    let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
-            "a".parse().unwrap() => ColumnType::BigInt,
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "name".parse().unwrap() => ColumnType::VarChar,
         },
     );
-    let ast = query_to_provable_ast(t, "select a from sxt_tab offset 0", &accessor);
+    let ast = query_to_provable_ast(
+        t,
+        "select row_number() over (order by salary) as rank, name from employees",
+        &accessor,
+    );
     let expected_ast = QueryExpr::new(
-        filter(
-            cols_expr_plan(t, &["a"], &accessor),
+        row_number(
+            vec![
+                col_expr_plan(t, "name", &accessor),
+                col_expr_plan(t, "salary", &accessor),
+            ],
             tab(t),
             const_bool(true),
+            "salary",
+            Asc,
+            "rank",
         ),
-        vec![slice(Some(u64::MAX), Some(0))],
+        vec![select_expr(&[
+            aliased_expr(col("rank"), "rank"),
+            aliased_expr(col("name"), "name"),
+        ])],
     );
     assert_eq!(ast, expected_ast);
 }
 
 #[test]
-fn we_can_parse_a_query_having_a_simple_positive_offset_clause() {
-    let t = "sxt.sxt_tab".parse().unwrap();
+fn we_cannot_do_unsupported_row_numbers() {
+    let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
-            "a".parse().unwrap() => ColumnType::BigInt,
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "name".parse().unwrap() => ColumnType::VarChar,
         },
     );
-    let ast = query_to_provable_ast(t, "select a from sxt_tab offset 7", &accessor);
-    let expected_ast = QueryExpr::new(
-        filter(
-            cols_expr_plan(t, &["a"], &accessor),
-            tab(t),
-            const_bool(true),
-        ),
-        vec![slice(Some(u64::MAX), Some(7))],
-    );
-    assert_eq!(ast, expected_ast);
+    for query in [
+        // twice
+        "select row_number() over (order by salary) as a, row_number() over (order by name) as b from employees",
+        // inside another expression
+        "select row_number() over (order by salary) + 1 as rank from employees",
+        // with an aggregation
+        "select name, count(*) as c, row_number() over (order by name) as rank from employees group by name",
+        // with a result that is not provable
+        "select salary / 2 as half, row_number() over (order by salary) as rank from employees",
+        // a result has the name of the key column, but a different value
+        "select name as salary, row_number() over (order by salary) as rank from employees",
+        // outside of the result
+        "select name from employees where row_number() over (order by salary) = 1",
+        // by a column that does not exist
+        "select name, row_number() over (order by age) as rank from employees",
+    ] {
+        invalid_query_to_provable_ast(t, query, &accessor);
+    }
 }
 
 #[test]
-fn we_can_parse_a_query_having_a_negative_offset_clause() {
-    let t = "sxt.sxt_tab".parse().unwrap();
+fn we_can_do_provable_lag() {
+    let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
-            "a".parse().unwrap() => ColumnType::BigInt,
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "name".parse().unwrap() => ColumnType::VarChar,
         },
     );
-    let ast = query_to_provable_ast(t, "select a from sxt_tab offset -7", &accessor);
+    let ast = query_to_provable_ast(
+        t,
+        "select name, salary, lag(salary) over (order by salary) as prev from employees where salary >= 4",
+        &accessor,
+    );
     let expected_ast = QueryExpr::new(
-        filter(
-            cols_expr_plan(t, &["a"], &accessor),
+        shift(
+            vec![
+                col_expr_plan(t, "name", &accessor),
+                col_expr_plan(t, "salary", &accessor),
+            ],
             tab(t),
-            const_bool(true),
+            gte(column(t, "salary", &accessor), const_bigint(4)),
+            "salary",
+            Asc,
+            ShiftDirection::Lag,
+            "salary",
+            "prev",
+            "prev_is_null",
         ),
-        vec![slice(Some(u64::MAX), Some(-7))],
+        vec![],
     );
     assert_eq!(ast, expected_ast);
 }
 
 #[test]
-fn we_can_parse_a_query_having_a_simple_limit_and_offset_clause() {
-    let t = "sxt.sxt_tab".parse().unwrap();
+fn we_can_do_provable_lead_of_columns_that_are_not_selected() {
+    let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
-            "a".parse().unwrap() => ColumnType::BigInt,
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "name".parse().unwrap() => ColumnType::VarChar,
         },
     );
-    let ast = query_to_provable_ast(t, "select a from sxt_tab limit 55 offset 3", &accessor);
+    let ast = query_to_provable_ast(
+        t,
+        "select lead(name) over (order by salary desc) as next, salary as pay from employees",
+        &accessor,
+    );
     let expected_ast = QueryExpr::new(
-        filter(
-            cols_expr_plan(t, &["a"], &accessor),
+        shift(
+            vec![
+                aliased_col_expr_plan(t, "salary", "pay", &accessor),
+                col_expr_plan(t, "name", &accessor),
+            ],
             tab(t),
             const_bool(true),
+            "pay",
+            Desc,
+            ShiftDirection::Lead,
+            "name",
+            "next",
+            "next_is_null",
         ),
-        vec![slice(Some(55), Some(3))],
+        vec![select_expr(&[
+            aliased_expr(col("next"), "next"),
+            aliased_expr(col("next_is_null"), "next_is_null"),
+            aliased_expr(col("pay"), "pay"),
+        ])],
     );
     assert_eq!(ast, expected_ast);
 }
 
-///////////////////////////
-// Composition Expressions
-///////////////////////////
 #[test]
-fn we_can_parse_a_query_having_a_simple_limit_and_offset_clause_preceded_by_where_expr_and_order_by(
-) {
-    let t = "sxt.sxt_tab".parse().unwrap();
+fn we_cannot_do_unsupported_lags_or_leads() {
+    let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
-            "a".parse().unwrap() => ColumnType::BigInt,
-            "boolean".parse().unwrap() => ColumnType::Boolean,
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "name".parse().unwrap() => ColumnType::VarChar,
         },
     );
-    let ast = query_to_provable_ast(
-        t,
-        "select a, boolean and a >= 4 as res from sxt_tab where a = -3 order by a desc limit 55 offset 3",
-        &accessor,
-    );
-    let expected_ast = QueryExpr::new(
-        filter(
-            vec![
-                col_expr_plan(t, "a", &accessor),
-                aliased_plan(
-                    and(
-                        column(t, "boolean", &accessor),
-                        gte(column(t, "a", &accessor), const_bigint(4)),
-                    ),
-                    "res",
-                ),
-            ],
-            tab(t),
-            equal(column(t, "a", &accessor), const_bigint(-3)),
-        ),
-        vec![orders(&["a"], &[Desc]), slice(Some(55), Some(3))],
-    );
-    assert_eq!(ast, expected_ast);
+    for query in [
+        // twice
+        "select lag(salary) over (order by salary) as a, lead(salary) over (order by salary) as b from employees",
+        // with a row number
+        "select lag(salary) over (order by salary) as a, row_number() over (order by salary) as b from employees",
+        // inside another expression
+        "select lag(salary) over (order by salary) + 1 as prev from employees",
+        // with an aggregation
+        "select name, count(*) as c, lag(name) over (order by name) as prev from employees group by name",
+        // the null flag collides with a result
+        "select name as prev_is_null, lag(salary) over (order by salary) as prev from employees",
+        // a result has the name of the shifted column, but a different value
+        "select name as salary, lag(salary) over (order by name) as prev from employees",
+        // outside of the result
+        "select name from employees where lag(salary) over (order by salary) = 1",
+        // of a column that does not exist
+        "select name, lead(age) over (order by salary) as next from employees",
+    ] {
+        invalid_query_to_provable_ast(t, query, &accessor);
+    }
 }
 
-///////////////////////////
-// Group By Expressions - Prover
-///////////////////////////
 #[test]
-fn we_can_do_provable_group_by() {
+fn we_can_do_provable_filtered_aggregations() {
     let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
             "salary".parse().unwrap() => ColumnType::BigInt,
-            "department".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::Int,
         },
     );
     let ast = query_to_provable_ast(
         t,
-        "select department, sum(salary) as total_salary, count(*) as num_employee from employees group by department",
+        "select department, sum(salary) filter (where salary >= 4) as s, count(*) filter (where salary >= 4) as n, count(*) as c from employees group by department",
         &accessor,
     );
     let expected_ast = QueryExpr::new(
         group_by(
             cols_expr(t, &["department"], &accessor),
-            vec![sum_expr(column(t, "salary", &accessor), "total_salary")],
-            "num_employee",
+            vec![
+                sum_expr(
+                    case_when(
+                        gte(column(t, "salary", &accessor), const_bigint(4)),
+                        column(t, "salary", &accessor),
+                        const_bigint(0),
+                    ),
+                    "s",
+                ),
+                sum_expr(
+                    case_when(
+                        gte(column(t, "salary", &accessor), const_bigint(4)),
+                        const_bigint(1),
+                        const_bigint(0),
+                    ),
+                    "n",
+                ),
+            ],
+            "c",
             tab(t),
             const_bool(true),
         ),
@@ -968,33 +1684,75 @@ fn we_can_do_provable_group_by() {
 }
 
 #[test]
-fn we_can_do_provable_group_by_without_sum() {
+fn we_can_do_provable_filtered_aggregations_without_an_unfiltered_count() {
     let t = "sxt.employees".parse().unwrap();
     let accessor = schema_accessor_from_table_ref_with_schema(
         t,
         indexmap! {
             "salary".parse().unwrap() => ColumnType::BigInt,
-            "department".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::Int,
         },
     );
     let ast = query_to_provable_ast(
         t,
-        "select department, count(*) as num_employee from employees group by department",
+        "select department, count(*) filter (where salary >= 4) as n from employees where department >= 1 group by department",
         &accessor,
     );
     let expected_ast = QueryExpr::new(
         group_by(
             cols_expr(t, &["department"], &accessor),
-            vec![],
-            "num_employee",
+            vec![sum_expr(
+                case_when(
+                    gte(column(t, "salary", &accessor), const_bigint(4)),
+                    const_bigint(1),
+                    const_bigint(0),
+                ),
+                "n",
+            )],
+            "__filtered_aggregation_rows__",
             tab(t),
-            const_bool(true),
+            gte(column(t, "department", &accessor), const_bigint(1)),
         ),
-        vec![],
+        vec![select_expr(&[
+            aliased_expr(col("department"), "department"),
+            aliased_expr(col("n"), "n"),
+        ])],
     );
     assert_eq!(ast, expected_ast);
 }
 
+#[test]
+fn we_cannot_do_unsupported_filtered_aggregations() {
+    let t = "sxt.employees".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "salary".parse().unwrap() => ColumnType::BigInt,
+            "department".parse().unwrap() => ColumnType::Int,
+            "name".parse().unwrap() => ColumnType::VarChar,
+        },
+    );
+    for query in [
+        // on an aggregation other than a sum or a count
+        "select max(salary) filter (where salary >= 4) as m from employees",
+        "select count(distinct department) filter (where salary >= 4) as n from employees",
+        // inside another expression
+        "select sum(salary) filter (where salary >= 4) + 1 as s from employees",
+        // with a having clause
+        "select department, count(*) filter (where salary >= 4) as n from employees group by department having count(*) >= 2",
+        // before the group by columns
+        "select count(*) filter (where salary >= 4) as n, department from employees group by department",
+        // a filter that is not a boolean
+        "select sum(salary) filter (where salary) as s from employees",
+        // a nested aggregation in the filter
+        "select sum(salary) filter (where count(*) >= 2) as s from employees",
+        // a sum of a non-numeric column
+        "select sum(name) filter (where salary >= 4) as s from employees",
+    ] {
+        invalid_query_to_provable_ast(t, query, &accessor);
+    }
+}
+
 #[test]
 fn we_can_do_provable_group_by_with_two_group_by_columns() {
     let t = "sxt.employees".parse().unwrap();
@@ -1618,11 +2376,13 @@ fn varchar_column_is_not_compatible_with_integer_column() {
     let bigint_to_varchar_queries = vec![
         "select -123 * name from sxt.employees",
         "select salary - name from sxt.employees",
+        "select salary || name from sxt.employees",
     ];
 
     let varchar_to_bigint_queries = vec![
         "select name from sxt.employees where 'abc' = salary",
         "select name from sxt.employees where 'abc' != salary",
+        "select name || salary from sxt.employees",
     ];
 
     for query_text in &bigint_to_varchar_queries {
@@ -2148,3 +2908,90 @@ fn we_can_serialize_list_of_filters_from_query_expr() {
     assert_eq!(filter_execs.len(), deserialized_as_ref.len());
     assert_eq!(filter_execs[0], deserialized_as_ref[0]);
 }
+
+#[test]
+fn we_exclude_tombstoned_rows_from_every_query_on_a_table_with_a_tombstone_column() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "__tombstone__".parse().unwrap() => ColumnType::Boolean,
+        },
+    );
+    let ast = query_to_provable_ast(t, "select * from sxt_tab where a = 3", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            and(
+                not(column(t, "__tombstone__", &accessor)),
+                equal(column(t, "a", &accessor), const_bigint(3)),
+            ),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+    let ast = query_to_provable_ast(t, "select a from sxt_tab", &accessor);
+    let expected_ast = QueryExpr::new(
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            not(column(t, "__tombstone__", &accessor)),
+        ),
+        vec![],
+    );
+    assert_eq!(ast, expected_ast);
+}
+
+#[test]
+fn we_cannot_convert_an_ast_that_does_not_select_from_exactly_one_table() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    for num_tables in [0, 2] {
+        let mut intermediate_ast = SelectStatementParser::new()
+            .parse("select a from sxt_tab")
+            .unwrap();
+        let SetExpression::Query { from, .. } = intermediate_ast.expr.as_mut();
+        *from = vec![from[0].clone(); num_tables];
+        assert!(matches!(
+            QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+            Err(ConversionError::Unprovable { .. })
+        ));
+    }
+}
+
+#[test]
+fn we_cannot_convert_an_ast_without_result_columns() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        t,
+        indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+        },
+    );
+    let mut intermediate_ast = SelectStatementParser::new()
+        .parse("select a from sxt_tab")
+        .unwrap();
+    let SetExpression::Query { result_exprs, .. } = intermediate_ast.expr.as_mut();
+    result_exprs.clear();
+    assert!(matches!(
+        QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+        Err(ConversionError::InvalidExpression { .. })
+    ));
+
+    // A wildcard over a table without columns, or over a table that does not exist, selects nothing either.
+    for query in ["select * from sxt_tab", "select * from missing_tab"] {
+        let accessor = schema_accessor_from_table_ref_with_schema(t, indexmap! {});
+        let intermediate_ast = SelectStatementParser::new().parse(query).unwrap();
+        assert!(matches!(
+            QueryExpr::try_new(intermediate_ast, t.schema_id(), &accessor),
+            Err(ConversionError::InvalidExpression { .. })
+        ));
+    }
+}
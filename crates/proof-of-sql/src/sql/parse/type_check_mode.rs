@@ -0,0 +1,36 @@
+use super::{ConversionError, ConversionResult};
+use crate::base::database::ColumnType;
+use alloc::string::ToString;
+
+/// How strictly the planner checks the types of the operands of an expression
+///
+/// There is no `CAST` yet, so under [`TypeCheckMode::Strict`] the operands must already have the same type.
+/// Note that integer literals are `BIGINT`, so they can only be compared with `BIGINT` columns.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TypeCheckMode {
+    /// Operands of different types are coerced to a common type where possible,
+    /// e.g. an `INT` column can be compared with a `DECIMAL` literal.
+    #[default]
+    Lenient,
+    /// Operands of different types are rejected, to catch data-modeling mistakes.
+    Strict,
+}
+
+impl TypeCheckMode {
+    /// Check that combining operands of types `left` and `right` does not need an implicit coercion,
+    /// if the mode is strict.
+    pub(crate) fn check_no_coercion(
+        self,
+        left: ColumnType,
+        right: ColumnType,
+    ) -> ConversionResult<()> {
+        if self == TypeCheckMode::Strict && left != right {
+            Err(ConversionError::DataTypeMismatch {
+                left_type: left.to_string(),
+                right_type: right.to_string(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use super::{ConversionError, QueryExpr, TypeCheckMode};
+use crate::base::{
+    database::{ColumnType, TestSchemaAccessor},
+    map::indexmap,
+    math::decimal::Precision,
+};
+use alloc::string::ToString;
+use proof_of_sql_parser::{sql::SelectStatementParser, SelectStatement};
+
+fn parse(sql: &str) -> SelectStatement {
+    SelectStatementParser::new().parse(sql).unwrap()
+}
+
+fn accessor() -> TestSchemaAccessor {
+    TestSchemaAccessor::new(indexmap! {
+        "sxt.t".parse().unwrap() => indexmap! {
+            "a".parse().unwrap() => ColumnType::Int,
+            "b".parse().unwrap() => ColumnType::BigInt,
+            "d".parse().unwrap() => ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+            "c".parse().unwrap() => ColumnType::VarChar,
+        },
+    })
+}
+
+fn plan(sql: &str, type_check_mode: TypeCheckMode) -> Result<QueryExpr, ConversionError> {
+    QueryExpr::try_new_with_type_check_mode(
+        parse(sql),
+        "sxt".parse().unwrap(),
+        &accessor(),
+        type_check_mode,
+    )
+}
+
+#[test]
+fn we_can_plan_a_mixed_type_comparison_in_lenient_mode() {
+    let sql = "select a from t where a = 1.5";
+    // Lenient mode is the behavior of `QueryExpr::try_new`.
+    assert_eq!(
+        plan(sql, TypeCheckMode::Lenient).unwrap(),
+        QueryExpr::try_new(parse(sql), "sxt".parse().unwrap(), &accessor()).unwrap()
+    );
+    assert_eq!(TypeCheckMode::default(), TypeCheckMode::Lenient);
+    for sql in [
+        "select a + b as s from t",
+        "select case when b = 1 then a else b end as e from t",
+    ] {
+        assert!(plan(sql, TypeCheckMode::Lenient).is_ok());
+    }
+}
+
+#[test]
+fn we_cannot_plan_a_mixed_type_comparison_in_strict_mode() {
+    assert!(matches!(
+        plan("select a from t where a = 1.5", TypeCheckMode::Strict),
+        Err(ConversionError::DataTypeMismatch { left_type, right_type })
+            if left_type == ColumnType::Int.to_string()
+                && right_type == ColumnType::Decimal75(Precision::new(2).unwrap(), 1).to_string()
+    ));
+    for sql in [
+        "select a from t where b >= a",
+        "select a + b as s from t",
+        "select d * b as p from t",
+        "select case when b = 1 then a else b end as e from t",
+    ] {
+        assert!(matches!(
+            plan(sql, TypeCheckMode::Strict),
+            Err(ConversionError::DataTypeMismatch { .. })
+        ));
+    }
+}
+
+#[test]
+fn we_can_plan_operands_of_the_same_type_in_strict_mode() {
+    for sql in [
+        "select a from t where b = 2",
+        "select a, b * b as p from t where c = 'x' and d >= d",
+        "select case when b = 1 then b else b + 1 end as e from t",
+        "select sum(b) as s, count(*) as n from t group by a",
+    ] {
+        assert_eq!(
+            plan(sql, TypeCheckMode::Strict).unwrap(),
+            plan(sql, TypeCheckMode::Lenient).unwrap()
+        );
+    }
+}
@@ -0,0 +1,429 @@
+use crate::base::map::IndexSet;
+use alloc::{string::ToString, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+use sqlparser::ast::{
+    Expr, FunctionArg, FunctionArgExpr, JoinOperator, Query, Select, SelectItem, SetExpr,
+    TableFactor, TableWithJoins,
+};
+
+/// A SQL construct that can be parsed but not yet proven
+///
+/// Queries containing any of these can still be executed, but only without verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnsupportedFeature {
+    /// A join that is not an outer join, including a `FROM` clause with several tables
+    Join,
+    /// A `LEFT`, `RIGHT` or `FULL OUTER JOIN`
+    OuterJoin,
+    /// A function call with an `OVER` clause
+    WindowFunction,
+    /// A subquery, either in the `FROM` clause or in an expression
+    Subquery,
+    /// A `UNION`, `INTERSECT` or `EXCEPT`
+    SetOperation,
+    /// A `WITH` clause
+    CommonTableExpression,
+    /// A `SELECT DISTINCT`
+    Distinct,
+    /// An expression that handles NULL, such as `IS NULL` or `COALESCE`
+    ///
+    /// Columns cannot hold NULL yet, so these have nothing to prove.
+    Null,
+    /// Extracting a value from JSON, either with `json_extract` or with an operator such as `->`
+    ///
+    /// There is no JSON column type, and parsing JSON cannot yet be proven.
+    Json,
+}
+
+impl Display for UnsupportedFeature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            UnsupportedFeature::Join => "JOIN",
+            UnsupportedFeature::OuterJoin => "OUTER JOIN",
+            UnsupportedFeature::WindowFunction => "window function",
+            UnsupportedFeature::Subquery => "subquery",
+            UnsupportedFeature::SetOperation => "set operation",
+            UnsupportedFeature::CommonTableExpression => "WITH",
+            UnsupportedFeature::Distinct => "DISTINCT",
+            UnsupportedFeature::Null => "NULL handling",
+            UnsupportedFeature::Json => "JSON extraction",
+        })
+    }
+}
+
+/// Report the constructs in a parsed query that cannot be proven yet.
+///
+/// Each feature is reported once, in the order it is first found.
+/// An empty result does not guarantee that the query can be proven,
+/// since it may still fail to type check or reference unknown columns.
+#[must_use]
+pub fn unsupported_features(query: &Query) -> Vec<UnsupportedFeature> {
+    let mut features = IndexSet::default();
+    visit_query(query, &mut features);
+    features.into_iter().collect()
+}
+
+fn visit_query(query: &Query, features: &mut IndexSet<UnsupportedFeature>) {
+    if query.with.is_some() {
+        features.insert(UnsupportedFeature::CommonTableExpression);
+    }
+    visit_set_expr(&query.body, features);
+    for order_by in &query.order_by {
+        visit_expr(&order_by.expr, features);
+    }
+}
+
+fn visit_set_expr(set_expr: &SetExpr, features: &mut IndexSet<UnsupportedFeature>) {
+    match set_expr {
+        SetExpr::Select(select) => visit_select(select, features),
+        SetExpr::Query(query) => {
+            features.insert(UnsupportedFeature::Subquery);
+            visit_query(query, features);
+        }
+        SetExpr::SetOperation { left, right, .. } => {
+            features.insert(UnsupportedFeature::SetOperation);
+            visit_set_expr(left, features);
+            visit_set_expr(right, features);
+        }
+        _ => {}
+    }
+}
+
+fn visit_select(select: &Select, features: &mut IndexSet<UnsupportedFeature>) {
+    if select.distinct.is_some() {
+        features.insert(UnsupportedFeature::Distinct);
+    }
+    for item in &select.projection {
+        match item {
+            SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                visit_expr(expr, features);
+            }
+            SelectItem::QualifiedWildcard(..) | SelectItem::Wildcard(_) => {}
+        }
+    }
+    if select.from.len() > 1 {
+        features.insert(UnsupportedFeature::Join);
+    }
+    for table in &select.from {
+        visit_table_with_joins(table, features);
+    }
+    if let Some(selection) = &select.selection {
+        visit_expr(selection, features);
+    }
+    if let Some(having) = &select.having {
+        visit_expr(having, features);
+    }
+}
+
+fn visit_table_with_joins(table: &TableWithJoins, features: &mut IndexSet<UnsupportedFeature>) {
+    visit_table_factor(&table.relation, features);
+    for join in &table.joins {
+        features.insert(match join.join_operator {
+            JoinOperator::LeftOuter(_)
+            | JoinOperator::RightOuter(_)
+            | JoinOperator::FullOuter(_)
+            | JoinOperator::OuterApply => UnsupportedFeature::OuterJoin,
+            _ => UnsupportedFeature::Join,
+        });
+        visit_table_factor(&join.relation, features);
+    }
+}
+
+fn visit_table_factor(table: &TableFactor, features: &mut IndexSet<UnsupportedFeature>) {
+    match table {
+        TableFactor::Derived { subquery, .. } => {
+            features.insert(UnsupportedFeature::Subquery);
+            visit_query(subquery, features);
+        }
+        TableFactor::NestedJoin {
+            table_with_joins, ..
+        } => visit_table_with_joins(table_with_joins, features),
+        _ => {}
+    }
+}
+
+fn visit_expr(expr: &Expr, features: &mut IndexSet<UnsupportedFeature>) {
+    match expr {
+        Expr::BinaryOp { left, right, .. }
+        | Expr::IsDistinctFrom(left, right)
+        | Expr::IsNotDistinctFrom(left, right) => {
+            visit_expr(left, features);
+            visit_expr(right, features);
+        }
+        Expr::JsonAccess { left, right, .. } => {
+            features.insert(UnsupportedFeature::Json);
+            visit_expr(left, features);
+            visit_expr(right, features);
+        }
+        Expr::IsNull(expr) | Expr::IsNotNull(expr) => {
+            features.insert(UnsupportedFeature::Null);
+            visit_expr(expr, features);
+        }
+        Expr::UnaryOp { expr, .. }
+        | Expr::Nested(expr)
+        | Expr::Cast { expr, .. }
+        | Expr::IsTrue(expr)
+        | Expr::IsNotTrue(expr)
+        | Expr::IsFalse(expr)
+        | Expr::IsNotFalse(expr)
+        | Expr::IsUnknown(expr)
+        | Expr::IsNotUnknown(expr) => visit_expr(expr, features),
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            visit_expr(expr, features);
+            visit_expr(low, features);
+            visit_expr(high, features);
+        }
+        Expr::InList { expr, list, .. } => {
+            visit_expr(expr, features);
+            for item in list {
+                visit_expr(item, features);
+            }
+        }
+        Expr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            for expr in operand
+                .iter()
+                .chain(else_result)
+                .map(AsRef::as_ref)
+                .chain(conditions)
+                .chain(results)
+            {
+                visit_expr(expr, features);
+            }
+        }
+        Expr::Function(function) => {
+            if function.over.is_some() {
+                features.insert(UnsupportedFeature::WindowFunction);
+            }
+            let name = function.name.to_string();
+            if name.eq_ignore_ascii_case("coalesce") {
+                features.insert(UnsupportedFeature::Null);
+            }
+            if name.eq_ignore_ascii_case("json_extract") {
+                features.insert(UnsupportedFeature::Json);
+            }
+            for arg in &function.args {
+                if let FunctionArg::Named {
+                    arg: FunctionArgExpr::Expr(expr),
+                    ..
+                }
+                | FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) = arg
+                {
+                    visit_expr(expr, features);
+                }
+            }
+        }
+        Expr::InSubquery { expr, subquery, .. } => {
+            features.insert(UnsupportedFeature::Subquery);
+            visit_expr(expr, features);
+            visit_query(subquery, features);
+        }
+        Expr::Exists { subquery, .. }
+        | Expr::Subquery(subquery)
+        | Expr::ArraySubquery(subquery) => {
+            features.insert(UnsupportedFeature::Subquery);
+            visit_query(subquery, features);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unsupported_features, UnsupportedFeature};
+    use alloc::{string::ToString, vec, vec::Vec};
+    use proof_of_sql_parser::SelectStatement;
+    use sqlparser::{
+        ast::{Query, Statement},
+        dialect::GenericDialect,
+        parser::Parser,
+    };
+
+    fn parse(sql: &str) -> Query {
+        match Parser::parse_sql(&GenericDialect {}, sql)
+            .unwrap()
+            .remove(0)
+        {
+            Statement::Query(query) => *query,
+            _ => panic!("expected a query"),
+        }
+    }
+
+    fn features(sql: &str) -> Vec<UnsupportedFeature> {
+        unsupported_features(&parse(sql))
+    }
+
+    #[test]
+    fn we_report_no_unsupported_features_for_a_supported_query() {
+        let sql = "SELECT a, SUM(b) AS s, COUNT(*) FROM sxt.t \
+                   WHERE a > 3 AND (c = 'x' OR NOT d) GROUP BY a ORDER BY a LIMIT 5";
+        assert_eq!(features(sql), vec![]);
+    }
+
+    #[test]
+    fn we_report_no_unsupported_features_for_a_query_converted_from_the_posql_parser() {
+        let statement: SelectStatement = "SELECT a, b FROM sxt.t WHERE a = 3 ORDER BY b OFFSET 1"
+            .parse()
+            .unwrap();
+        assert_eq!(unsupported_features(&Query::from(statement)), vec![]);
+    }
+
+    #[test]
+    fn we_report_an_outer_join() {
+        assert_eq!(
+            features("SELECT * FROM sxt.t LEFT OUTER JOIN sxt.u ON t.a = u.a"),
+            vec![UnsupportedFeature::OuterJoin]
+        );
+        assert_eq!(
+            features("SELECT * FROM sxt.t FULL JOIN sxt.u ON t.a = u.a"),
+            vec![UnsupportedFeature::OuterJoin]
+        );
+    }
+
+    #[test]
+    fn we_report_inner_joins_and_multiple_tables_as_joins() {
+        assert_eq!(
+            features("SELECT * FROM sxt.t JOIN sxt.u ON t.a = u.a"),
+            vec![UnsupportedFeature::Join]
+        );
+        assert_eq!(
+            features("SELECT * FROM sxt.t, sxt.u"),
+            vec![UnsupportedFeature::Join]
+        );
+    }
+
+    #[test]
+    fn we_report_self_joins_under_different_aliases_as_joins() {
+        assert_eq!(
+            features(
+                "SELECT e.name, m.name AS manager FROM sxt.employees e \
+                 JOIN sxt.employees m ON e.mgr = m.id"
+            ),
+            vec![UnsupportedFeature::Join]
+        );
+        assert_eq!(
+            features(
+                "SELECT e.name FROM sxt.employees AS e, sxt.employees AS m WHERE e.mgr = m.id"
+            ),
+            vec![UnsupportedFeature::Join]
+        );
+    }
+
+    #[test]
+    fn we_report_anti_joins_as_subqueries() {
+        assert_eq!(
+            features("SELECT a FROM sxt.t WHERE a NOT IN (SELECT b FROM sxt.u)"),
+            vec![UnsupportedFeature::Subquery]
+        );
+        assert_eq!(
+            features("SELECT a FROM sxt.t WHERE NOT EXISTS (SELECT b FROM sxt.u WHERE u.b = t.a)"),
+            vec![UnsupportedFeature::Subquery]
+        );
+        assert_eq!(
+            features(
+                "SELECT a FROM sxt.t WHERE a NOT IN (SELECT b FROM sxt.u WHERE b IS NOT NULL)"
+            ),
+            vec![UnsupportedFeature::Subquery, UnsupportedFeature::Null]
+        );
+    }
+
+    #[test]
+    fn we_report_intersect_and_except_as_set_operations() {
+        assert_eq!(
+            features("SELECT a FROM sxt.t INTERSECT SELECT a FROM sxt.u"),
+            vec![UnsupportedFeature::SetOperation]
+        );
+        assert_eq!(
+            features("SELECT a FROM sxt.t EXCEPT SELECT a FROM sxt.t WHERE a > 3"),
+            vec![UnsupportedFeature::SetOperation]
+        );
+        assert_eq!(
+            features("SELECT DISTINCT a FROM sxt.t INTERSECT ALL SELECT a FROM sxt.u"),
+            vec![
+                UnsupportedFeature::SetOperation,
+                UnsupportedFeature::Distinct
+            ]
+        );
+    }
+
+    #[test]
+    fn we_report_window_functions_but_not_having() {
+        assert_eq!(
+            features("SELECT a, SUM(b) OVER (PARTITION BY a) FROM sxt.t GROUP BY a HAVING a > 1"),
+            vec![UnsupportedFeature::WindowFunction]
+        );
+        assert_eq!(
+            features("SELECT a, COUNT(*) FROM sxt.t GROUP BY a HAVING COUNT(*) >= 2"),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn we_report_null_handling() {
+        assert_eq!(
+            features("SELECT COALESCE(a, 0) AS a FROM sxt.t"),
+            vec![UnsupportedFeature::Null]
+        );
+        assert_eq!(
+            features("SELECT COALESCE(a, b, c, 0) AS a FROM sxt.t"),
+            vec![UnsupportedFeature::Null]
+        );
+        assert_eq!(
+            features("SELECT a FROM sxt.t WHERE b IS NULL OR coalesce(c, d) IS NOT NULL"),
+            vec![UnsupportedFeature::Null]
+        );
+        assert_eq!(features("SELECT a FROM sxt.t WHERE b IS TRUE"), vec![]);
+    }
+
+    #[test]
+    fn we_report_json_extraction() {
+        assert_eq!(
+            features("SELECT json_extract(doc, '$.name') AS name FROM sxt.t"),
+            vec![UnsupportedFeature::Json]
+        );
+        assert_eq!(
+            features("SELECT a FROM sxt.t WHERE doc ->> 'name' = 'x'"),
+            vec![UnsupportedFeature::Json]
+        );
+        assert_eq!(
+            features("SELECT a FROM sxt.t WHERE JSON_EXTRACT(doc, '$.age') IS NULL"),
+            vec![UnsupportedFeature::Null, UnsupportedFeature::Json]
+        );
+    }
+
+    #[test]
+    fn we_report_each_feature_once_including_those_in_nested_queries() {
+        assert_eq!(
+            features(
+                "WITH w AS (SELECT a FROM sxt.t) \
+                 SELECT DISTINCT a FROM (SELECT a FROM sxt.t LEFT JOIN sxt.u ON t.a = u.a) \
+                 WHERE a IN (SELECT a FROM sxt.u RIGHT JOIN sxt.v ON u.a = v.a) \
+                 UNION SELECT a FROM sxt.v"
+            ),
+            vec![
+                UnsupportedFeature::CommonTableExpression,
+                UnsupportedFeature::SetOperation,
+                UnsupportedFeature::Distinct,
+                UnsupportedFeature::Subquery,
+                UnsupportedFeature::OuterJoin,
+            ]
+        );
+    }
+
+    #[test]
+    fn we_can_display_unsupported_features() {
+        assert_eq!(UnsupportedFeature::OuterJoin.to_string(), "OUTER JOIN");
+        assert_eq!(UnsupportedFeature::Null.to_string(), "NULL handling");
+        assert_eq!(UnsupportedFeature::Json.to_string(), "JSON extraction");
+        assert_eq!(
+            UnsupportedFeature::WindowFunction.to_string(),
+            "window function"
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use super::{ConversionError, ConversionResult, QueryExpr};
+use crate::{
+    base::{
+        database::{tombstone_column_id, SchemaAccessor, TableRef},
+        map::IndexMap,
+    },
+    sql::proof_plans::DynProofPlan,
+};
+use alloc::{boxed::Box, format, string::ToString, vec::Vec};
+use proof_of_sql_parser::{
+    intermediate_ast::{
+        AliasedResultExpr, BinaryOperator, Expression, OrderBy, SelectResultExpr, SetExpression,
+        TableExpression,
+    },
+    Identifier, ResourceId, SelectStatement,
+};
+
+/// A named query that other queries can select from as if it were a table.
+///
+/// A view is defined by a query of the form `SELECT <exprs> FROM <table> [WHERE <filter>]`.
+/// A query that selects from the view is planned by inlining the view: every reference to
+/// a column of the view is replaced by the expression that defines it, and the view's filter is
+/// combined with the query's own filter. So a query on a view has exactly the same plan, and
+/// therefore the same proof, as the equivalent query on the underlying table.
+///
+/// Within a query on the view, a column name always refers to the view's output column
+/// of that name, even if the underlying table has a different column with the same name.
+#[derive(Debug, PartialEq)]
+pub struct View {
+    table_ref: TableRef,
+    source: TableRef,
+    columns: IndexMap<Identifier, Expression>,
+    where_expr: Option<Box<Expression>>,
+    query_expr: QueryExpr,
+}
+
+impl View {
+    /// Creates a view named `table_ref` from its defining query.
+    ///
+    /// The definition is planned against `schema_accessor`, so it must be a provable query.
+    /// It may not aggregate, group, order, or slice its rows.
+    pub fn try_new(
+        table_ref: TableRef,
+        definition: SelectStatement,
+        default_schema: Identifier,
+        schema_accessor: &dyn SchemaAccessor,
+    ) -> ConversionResult<Self> {
+        let invalid = |error: &str| ConversionError::InvalidView {
+            view: table_ref.to_string(),
+            error: error.to_string(),
+        };
+        if !definition.order_by.is_empty() || definition.slice.is_some() {
+            return Err(invalid("a view cannot be ordered or sliced"));
+        }
+        let SetExpression::Query {
+            result_exprs,
+            from,
+            where_expr,
+            group_by,
+            having,
+        } = &*definition.expr;
+        if !group_by.is_empty() || having.is_some() {
+            return Err(invalid("a view cannot group its rows"));
+        }
+        let source = match from.as_slice() {
+            [table] => match **table {
+                TableExpression::Named { table, schema } => {
+                    TableRef::new(ResourceId::new(schema.unwrap_or(default_schema), table))
+                }
+            },
+            _ => return Err(invalid("a view must select from exactly one table")),
+        };
+        let mut columns = IndexMap::default();
+        for result_expr in result_exprs.iter().cloned() {
+            match result_expr {
+                SelectResultExpr::ALL => columns.extend(
+                    schema_accessor
+                        .lookup_schema(source)
+                        .into_iter()
+                        .filter(|(column_id, _)| *column_id != tombstone_column_id())
+                        .map(|(column_id, _)| (column_id, Expression::Column(column_id))),
+                ),
+                SelectResultExpr::QualifiedAll(_) => {
+                    return Err(invalid("a view cannot select a qualified wildcard"));
+                }
+                SelectResultExpr::AliasedResultExpr(AliasedResultExpr { expr, alias }) => {
+                    if contains_aggregation(&expr) {
+                        return Err(invalid("a view cannot aggregate its rows"));
+                    }
+                    columns.insert(alias, *expr);
+                }
+            }
+        }
+        let where_expr = where_expr.clone();
+        let query_expr = QueryExpr::try_new(definition, default_schema, schema_accessor)?;
+        Ok(Self {
+            table_ref,
+            source,
+            columns,
+            where_expr,
+            query_expr,
+        })
+    }
+
+    /// The name of the view
+    #[must_use]
+    pub fn table_ref(&self) -> TableRef {
+        self.table_ref
+    }
+
+    /// The plan of the view's defining query
+    #[must_use]
+    pub fn proof_plan(&self) -> &DynProofPlan {
+        self.query_expr.proof_expr()
+    }
+
+    /// Rewrites a query that selects from this view into the equivalent query on the underlying table.
+    ///
+    /// A query that does not select from this view is returned unchanged.
+    pub fn inline(
+        &self,
+        query: SelectStatement,
+        default_schema: Identifier,
+    ) -> ConversionResult<SelectStatement> {
+        let SetExpression::Query {
+            result_exprs,
+            from,
+            where_expr,
+            group_by,
+            having,
+        } = *query.expr;
+        let selects_from_view = matches!(
+            from.as_slice(),
+            [table] if matches!(
+                **table,
+                TableExpression::Named { table, schema }
+                    if TableRef::new(ResourceId::new(schema.unwrap_or(default_schema), table))
+                        == self.table_ref
+            )
+        );
+        if !selects_from_view {
+            return Ok(SelectStatement {
+                expr: Box::new(SetExpression::Query {
+                    result_exprs,
+                    from,
+                    where_expr,
+                    group_by,
+                    having,
+                }),
+                ..query
+            });
+        }
+        let result_exprs = result_exprs
+            .into_iter()
+            .map(|result_expr| match result_expr {
+                SelectResultExpr::ALL => Ok(self.output_exprs()),
+                SelectResultExpr::QualifiedAll(qualifier)
+                    if qualifier == self.table_ref.table_id() =>
+                {
+                    Ok(self.output_exprs())
+                }
+                SelectResultExpr::QualifiedAll(qualifier) => {
+                    Err(ConversionError::MissingWildcardTable {
+                        qualifier: Box::new(qualifier),
+                    })
+                }
+                SelectResultExpr::AliasedResultExpr(AliasedResultExpr { expr, alias }) => {
+                    Ok(Vec::from([SelectResultExpr::AliasedResultExpr(
+                        AliasedResultExpr::new(self.substitute(*expr)?, alias),
+                    )]))
+                }
+            })
+            .collect::<ConversionResult<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        let where_expr = match (
+            self.where_expr.clone(),
+            where_expr
+                .map(|expr| self.substitute(*expr).map(Box::new))
+                .transpose()?,
+        ) {
+            (Some(left), Some(right)) => Some(Box::new(Expression::Binary {
+                op: BinaryOperator::And,
+                left,
+                right,
+            })),
+            (left, right) => left.or(right),
+        };
+        let group_by = group_by
+            .into_iter()
+            .map(|column_id| match self.lookup(column_id)? {
+                Expression::Column(source_column_id) => Ok(*source_column_id),
+                _ => Err(ConversionError::InvalidView {
+                    view: self.table_ref.to_string(),
+                    error: format!("cannot group by the computed column '{column_id}'"),
+                }),
+            })
+            .collect::<ConversionResult<_>>()?;
+        let having = having
+            .map(|expr| self.substitute(*expr).map(Box::new))
+            .transpose()?;
+        Ok(SelectStatement {
+            expr: Box::new(SetExpression::Query {
+                result_exprs,
+                from: Vec::from([Box::new(TableExpression::Named {
+                    table: self.source.table_id(),
+                    schema: Some(self.source.schema_id()),
+                })]),
+                where_expr,
+                group_by,
+                having,
+            }),
+            ..query
+        })
+    }
+
+    /// The output columns of the view as result expressions over the underlying table
+    fn output_exprs(&self) -> Vec<SelectResultExpr> {
+        self.columns
+            .iter()
+            .map(|(alias, expr)| {
+                SelectResultExpr::AliasedResultExpr(AliasedResultExpr::new(expr.clone(), *alias))
+            })
+            .collect()
+    }
+
+    /// The expression that defines an output column of the view
+    fn lookup(&self, column_id: Identifier) -> ConversionResult<&Expression> {
+        self.columns
+            .get(&column_id)
+            .ok_or_else(|| ConversionError::MissingColumn {
+                identifier: Box::new(column_id),
+                resource_id: Box::new(self.table_ref.resource_id()),
+            })
+    }
+
+    /// Replace every column of the view in `expr` by the expression that defines it
+    fn substitute(&self, expr: Expression) -> ConversionResult<Expression> {
+        let substitute_boxed = |expr: Box<Expression>| self.substitute(*expr).map(Box::new);
+        Ok(match expr {
+            Expression::Column(column_id) => self.lookup(column_id)?.clone(),
+            Expression::Literal(_) | Expression::Placeholder(_) | Expression::Wildcard => expr,
+            Expression::Unary { op, expr } => Expression::Unary {
+                op,
+                expr: substitute_boxed(expr)?,
+            },
+            Expression::Binary { op, left, right } => Expression::Binary {
+                op,
+                left: substitute_boxed(left)?,
+                right: substitute_boxed(right)?,
+            },
+            Expression::Aggregation { op, expr, filter } => Expression::Aggregation {
+                op,
+                expr: substitute_boxed(expr)?,
+                filter: filter.map(substitute_boxed).transpose()?,
+            },
+            Expression::DateTrunc { unit, expr } => Expression::DateTrunc {
+                unit,
+                expr: substitute_boxed(expr)?,
+            },
+            Expression::Case {
+                condition,
+                then_result,
+                else_result,
+            } => Expression::Case {
+                condition: substitute_boxed(condition)?,
+                then_result: substitute_boxed(then_result)?,
+                else_result: substitute_boxed(else_result)?,
+            },
+            Expression::RowNumber(OrderBy { expr, direction }) => match self.lookup(expr)? {
+                Expression::Column(expr) => Expression::RowNumber(OrderBy {
+                    expr: *expr,
+                    direction,
+                }),
+                _ => Err(ConversionError::InvalidView {
+                    view: self.table_ref.to_string(),
+                    error: format!("a row number cannot be ordered by the computed column {expr}"),
+                })?,
+            },
+            Expression::Shift {
+                direction,
+                column,
+                order_by:
+                    OrderBy {
+                        expr,
+                        direction: order_by_direction,
+                    },
+            } => match (self.lookup(column)?, self.lookup(expr)?) {
+                (Expression::Column(column), Expression::Column(expr)) => Expression::Shift {
+                    direction,
+                    column: *column,
+                    order_by: OrderBy {
+                        expr: *expr,
+                        direction: order_by_direction,
+                    },
+                },
+                _ => Err(ConversionError::InvalidView {
+                    view: self.table_ref.to_string(),
+                    error: format!("{direction} cannot take or be ordered by a computed column"),
+                })?,
+            },
+            Expression::Exists(_) | Expression::ScalarSubquery(_) => {
+                Err(ConversionError::InvalidView {
+                    view: self.table_ref.to_string(),
+                    error: "a query on a view cannot use a subquery".to_string(),
+                })?
+            }
+        })
+    }
+}
+
+/// Whether an expression aggregates rows
+fn contains_aggregation(expr: &Expression) -> bool {
+    match expr {
+        Expression::Aggregation { .. } => true,
+        Expression::Literal(_)
+        | Expression::Placeholder(_)
+        | Expression::Column(_)
+        | Expression::Wildcard
+        | Expression::RowNumber(_)
+        | Expression::Shift { .. }
+        | Expression::Exists(_)
+        | Expression::ScalarSubquery(_) => false,
+        Expression::Unary { expr, .. } | Expression::DateTrunc { expr, .. } => {
+            contains_aggregation(expr)
+        }
+        Expression::Binary { left, right, .. } => {
+            contains_aggregation(left) || contains_aggregation(right)
+        }
+        Expression::Case {
+            condition,
+            then_result,
+            else_result,
+        } => {
+            contains_aggregation(condition)
+                || contains_aggregation(then_result)
+                || contains_aggregation(else_result)
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use super::{ConversionError, QueryExpr, View};
+use crate::base::{
+    database::{ColumnType, TableRef, TestSchemaAccessor},
+    map::indexmap,
+};
+use proof_of_sql_parser::{sql::SelectStatementParser, SelectStatement};
+
+fn parse(sql: &str) -> SelectStatement {
+    SelectStatementParser::new().parse(sql).unwrap()
+}
+
+fn accessor() -> TestSchemaAccessor {
+    TestSchemaAccessor::new(indexmap! {
+        "sxt.t".parse().unwrap() => indexmap! {
+            "a".parse().unwrap() => ColumnType::BigInt,
+            "b".parse().unwrap() => ColumnType::BigInt,
+            "c".parse().unwrap() => ColumnType::VarChar,
+        },
+    })
+}
+
+fn view(name: &str, definition: &str) -> View {
+    let table_ref: TableRef = name.parse().unwrap();
+    View::try_new(
+        table_ref,
+        parse(definition),
+        table_ref.schema_id(),
+        &accessor(),
+    )
+    .unwrap()
+}
+
+fn plan_with_views(sql: &str, views: &[View]) -> QueryExpr {
+    QueryExpr::try_new_with_views(parse(sql), "sxt".parse().unwrap(), &accessor(), views).unwrap()
+}
+
+fn plan(sql: &str) -> QueryExpr {
+    plan_with_views(sql, &[])
+}
+
+#[test]
+fn we_can_plan_a_query_on_a_view_as_the_inlined_query() {
+    let v = view("sxt.v", "select a + b as s, c from t where a > 0");
+    assert_eq!(
+        plan_with_views("select s, c from v where c = 'x'", &[v]),
+        plan("select a + b as s, c from t where a > 0 and c = 'x'")
+    );
+}
+
+#[test]
+fn we_can_plan_wildcards_and_aggregations_on_a_view_as_the_inlined_query() {
+    let v = view("sxt.v", "select * from t where b = 1");
+    assert_eq!(
+        plan_with_views("select * from v", core::slice::from_ref(&v)),
+        plan("select a, b, c from t where b = 1")
+    );
+    assert_eq!(
+        plan_with_views(
+            "select c, sum(a) as total, count(*) as n from v group by c order by c",
+            core::slice::from_ref(&v)
+        ),
+        plan("select c, sum(a) as total, count(*) as n from t where b = 1 group by c order by c")
+    );
+    assert_eq!(
+        v.proof_plan(),
+        plan("select * from t where b = 1").proof_expr()
+    );
+}
+
+#[test]
+fn view_columns_shadow_columns_of_the_underlying_table() {
+    let v = view("sxt.v", "select b as a, a as b from t");
+    assert_eq!(
+        plan_with_views("select a as first, b as second from v where a = 1", &[v]),
+        plan("select b as first, a as second from t where b = 1")
+    );
+}
+
+#[test]
+fn queries_that_do_not_select_from_a_view_are_unchanged() {
+    let v = view("sxt.v", "select a from t");
+    assert_eq!(
+        plan_with_views("select a, c from t where b = 2", &[v]),
+        plan("select a, c from t where b = 2")
+    );
+}
+
+#[test]
+fn we_cannot_select_a_column_that_is_not_in_the_view() {
+    let v = view("sxt.v", "select a from t");
+    assert_eq!(
+        QueryExpr::try_new_with_views(
+            parse("select b from v"),
+            "sxt".parse().unwrap(),
+            &accessor(),
+            &[v]
+        ),
+        Err(ConversionError::MissingColumn {
+            identifier: Box::new("b".parse().unwrap()),
+            resource_id: Box::new("sxt.v".parse().unwrap()),
+        })
+    );
+}
+
+#[test]
+fn we_cannot_define_a_view_that_aggregates_or_orders_its_rows() {
+    for definition in [
+        "select sum(a) as s from t",
+        "select a, count(*) as n from t group by a",
+        "select a from t order by a",
+        "select a from t limit 1",
+    ] {
+        assert!(matches!(
+            View::try_new(
+                "sxt.v".parse().unwrap(),
+                parse(definition),
+                "sxt".parse().unwrap(),
+                &accessor()
+            ),
+            Err(ConversionError::InvalidView { .. })
+        ));
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     base::{
         database::{ColumnRef, ColumnType, LiteralValue, TestSchemaAccessor},
         map::{indexmap, IndexMap},
-        math::decimal::Precision,
+        math::{decimal::Precision, i256::I256},
     },
     sql::{
         parse::{ConversionError, QueryExpr, WhereExprBuilder},
@@ -368,3 +368,182 @@ fn we_do_not_expect_an_error_while_trying_to_check_bigint_column_eq_decimal_with
     )
     .is_ok());
 }
+
+#[test]
+fn we_can_compare_columns_with_decimals_in_scientific_notation() {
+    let t = "sxt.sxt_tab".parse().unwrap();
+    let accessor = TestSchemaAccessor::new(indexmap! {
+        t => indexmap! {
+            "b".parse().unwrap() => ColumnType::BigInt,
+            "d".parse().unwrap() => ColumnType::Decimal75(Precision::new(10).unwrap(), 3),
+        },
+    });
+    for query in [
+        "select * from sxt_tab where b = 1.5e3",
+        "select * from sxt_tab where b = 1e3",
+        "select * from sxt_tab where d = 2E-2",
+        "select * from sxt_tab where d > -1.25e+2",
+    ] {
+        assert!(
+            QueryExpr::try_new(
+                SelectStatement::from_str(query).unwrap(),
+                t.schema_id(),
+                &accessor,
+            )
+            .is_ok(),
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn we_can_fold_boolean_literals_in_where_clauses() {
+    let column_mapping = get_column_mappings_for_testing();
+    let boolean_column = DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+        "sxt.sxt_tab".parse().unwrap(),
+        ident("boolean_column"),
+        ColumnType::Boolean,
+    )));
+    let build = |expr| {
+        WhereExprBuilder::new(&column_mapping)
+            .build(Some(expr))
+            .unwrap()
+            .unwrap()
+    };
+    assert_eq!(
+        build(lit(true)),
+        DynProofExpr::new_literal(LiteralValue::Boolean(true))
+    );
+    assert_eq!(
+        build(not(lit(true))),
+        DynProofExpr::new_literal(LiteralValue::Boolean(false))
+    );
+    assert_eq!(build(and(lit(true), col("boolean_column"))), boolean_column);
+    assert_eq!(build(or(col("boolean_column"), lit(false))), boolean_column);
+    assert_eq!(
+        build(and(col("boolean_column"), not(lit(true)))),
+        DynProofExpr::new_literal(LiteralValue::Boolean(false))
+    );
+    assert_eq!(
+        build(or(lit(true), col("boolean_column"))),
+        DynProofExpr::new_literal(LiteralValue::Boolean(true))
+    );
+    assert_eq!(
+        build(or(and(lit(false), lit(true)), not(lit(false)))),
+        DynProofExpr::new_literal(LiteralValue::Boolean(true))
+    );
+}
+
+#[test]
+fn we_cannot_fold_boolean_literals_with_non_boolean_operands() {
+    let column_mapping = get_column_mappings_for_testing();
+    let builder = WhereExprBuilder::new(&column_mapping);
+    assert!(matches!(
+        builder.build(Some(and(lit(false), col("bigint_column")))),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    let builder = WhereExprBuilder::new(&column_mapping);
+    assert!(matches!(
+        builder.build(Some(or(col("varchar_column"), lit(true)))),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+}
+
+#[test]
+fn we_can_fold_literal_only_subexpressions_in_where_clauses() {
+    let column_mapping = get_column_mappings_for_testing();
+    let build = |expr| {
+        WhereExprBuilder::new(&column_mapping)
+            .build(Some(expr))
+            .unwrap()
+            .unwrap()
+    };
+    let bigint_column = DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+        "sxt.sxt_tab".parse().unwrap(),
+        ident("bigint_column"),
+        ColumnType::BigInt,
+    )));
+    let decimal_column = DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+        "sxt.sxt_tab".parse().unwrap(),
+        ident("decimal_column"),
+        ColumnType::Decimal75(Precision::new(7).unwrap(), 2),
+    )));
+
+    let folded = build(ge(col("bigint_column"), add(lit(2), lit(3))));
+    let unfolded = DynProofExpr::try_new_inequality(
+        bigint_column.clone(),
+        DynProofExpr::try_new_add(
+            DynProofExpr::new_literal(LiteralValue::BigInt(2)),
+            DynProofExpr::new_literal(LiteralValue::BigInt(3)),
+        )
+        .unwrap(),
+        false,
+    )
+    .unwrap();
+    assert_eq!(folded, build(ge(col("bigint_column"), lit(5))));
+    assert!(
+        postcard::to_allocvec(&folded).unwrap().len()
+            < postcard::to_allocvec(&unfolded).unwrap().len()
+    );
+
+    // 1 + 0.1 has scale 1, and the product has the scale of the column plus 1.
+    let folded = build(ge(
+        mul(
+            col("decimal_column"),
+            add(lit(1), lit(BigDecimal::from_str("0.1").unwrap())),
+        ),
+        lit(0),
+    ));
+    let expected = DynProofExpr::try_new_inequality(
+        DynProofExpr::try_new_multiply(
+            decimal_column,
+            DynProofExpr::new_literal(LiteralValue::Decimal75(
+                Precision::new(21).unwrap(),
+                1,
+                I256::from(11),
+            )),
+        )
+        .unwrap(),
+        DynProofExpr::new_literal(LiteralValue::BigInt(0)),
+        false,
+    )
+    .unwrap();
+    assert_eq!(folded, expected);
+
+    assert_eq!(
+        build(equal(neg(sub(lit(2), lit(7))), modulo(lit(17), lit(6)))),
+        DynProofExpr::new_literal(LiteralValue::Boolean(true))
+    );
+    assert_eq!(
+        build(le(mul(lit(3), lit(4)), lit(11))),
+        DynProofExpr::new_literal(LiteralValue::Boolean(false))
+    );
+    assert_eq!(
+        build(equal(lit("abc"), lit("abc"))),
+        DynProofExpr::new_literal(LiteralValue::Boolean(true))
+    );
+}
+
+#[test]
+fn we_do_not_fold_literal_only_subexpressions_that_overflow_their_type() {
+    let column_mapping = get_column_mappings_for_testing();
+    let expr = WhereExprBuilder::new(&column_mapping)
+        .build(Some(ge(col("bigint_column"), add(lit(i64::MAX), lit(1)))))
+        .unwrap()
+        .unwrap();
+    let unfolded = DynProofExpr::try_new_inequality(
+        DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+            "sxt.sxt_tab".parse().unwrap(),
+            ident("bigint_column"),
+            ColumnType::BigInt,
+        ))),
+        DynProofExpr::try_new_add(
+            DynProofExpr::new_literal(LiteralValue::BigInt(i64::MAX)),
+            DynProofExpr::new_literal(LiteralValue::BigInt(1)),
+        )
+        .unwrap(),
+        false,
+    )
+    .unwrap();
+    assert_eq!(expr, unfolded);
+}
@@ -0,0 +1,120 @@
+use super::{ConversionError, ConversionResult};
+use crate::base::{database::ColumnType, map::IndexMap};
+use alloc::{boxed::Box, vec::Vec};
+use proof_of_sql_parser::Identifier;
+
+/// The qualifier of a table in the `FROM` clause along with its ordered columns
+pub(crate) type FromTable = (Identifier, Vec<(Identifier, ColumnType)>);
+
+/// Expand `qualifier.*` to the columns of the `FROM` table with that qualifier, in schema order.
+pub(crate) fn expand_qualified_wildcard(
+    qualifier: Identifier,
+    from_tables: &[FromTable],
+) -> ConversionResult<Vec<(Identifier, ColumnType)>> {
+    from_tables
+        .iter()
+        .find(|(table_qualifier, _)| *table_qualifier == qualifier)
+        .map(|(_, columns)| columns.clone())
+        .ok_or(ConversionError::MissingWildcardTable {
+            qualifier: Box::new(qualifier),
+        })
+}
+
+/// Expand `*` to the columns of every `FROM` table, in the order the tables appear.
+///
+/// Errors if a column name appears in more than one table, since the result would be ambiguous.
+pub(crate) fn expand_wildcard(
+    from_tables: &[FromTable],
+) -> ConversionResult<Vec<(Identifier, ColumnType)>> {
+    let mut columns = IndexMap::default();
+    for (column_id, column_type) in from_tables.iter().flat_map(|(_, columns)| columns) {
+        if columns.insert(*column_id, *column_type).is_some() {
+            return Err(ConversionError::AmbiguousWildcardColumn {
+                identifier: Box::new(*column_id),
+            });
+        }
+    }
+    Ok(columns.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn id(name: &str) -> Identifier {
+        name.parse().unwrap()
+    }
+
+    fn from_tables() -> Vec<FromTable> {
+        vec![
+            (
+                id("orders"),
+                vec![
+                    (id("order_id"), ColumnType::BigInt),
+                    (id("customer_id"), ColumnType::BigInt),
+                    (
+                        id("total"),
+                        ColumnType::Decimal75(10.try_into().unwrap(), 2),
+                    ),
+                ],
+            ),
+            (
+                id("customers"),
+                vec![
+                    (id("name"), ColumnType::VarChar),
+                    (id("region"), ColumnType::VarChar),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn we_can_expand_a_qualified_wildcard_to_the_columns_of_its_table() {
+        let tables = from_tables();
+        assert_eq!(
+            expand_qualified_wildcard(id("orders"), &tables).unwrap(),
+            tables[0].1
+        );
+        assert_eq!(
+            expand_qualified_wildcard(id("customers"), &tables).unwrap(),
+            tables[1].1
+        );
+    }
+
+    #[test]
+    fn we_cannot_expand_a_qualified_wildcard_of_a_table_not_in_the_from_clause() {
+        assert_eq!(
+            expand_qualified_wildcard(id("products"), &from_tables()),
+            Err(ConversionError::MissingWildcardTable {
+                qualifier: Box::new(id("products"))
+            })
+        );
+    }
+
+    #[test]
+    fn we_can_expand_a_wildcard_to_the_columns_of_all_tables_without_collisions() {
+        let tables = from_tables();
+        let expected: Vec<_> = tables[0].1.iter().chain(&tables[1].1).copied().collect();
+        assert_eq!(expand_wildcard(&tables).unwrap(), expected);
+        assert_eq!(expand_wildcard(&tables[..1]).unwrap(), tables[0].1);
+        assert_eq!(expand_wildcard(&[]).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn we_cannot_expand_a_wildcard_when_a_column_name_collides_across_tables() {
+        let mut tables = from_tables();
+        tables[1].1.push((id("customer_id"), ColumnType::BigInt));
+        assert_eq!(
+            expand_wildcard(&tables),
+            Err(ConversionError::AmbiguousWildcardColumn {
+                identifier: Box::new(id("customer_id"))
+            })
+        );
+        // A qualified wildcard is still unambiguous.
+        assert_eq!(
+            expand_qualified_wildcard(id("customers"), &tables).unwrap(),
+            tables[1].1
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::base::database::ColumnType;
 use alloc::string::String;
 use proof_of_sql_parser::Identifier;
 use snafu::Snafu;
@@ -47,12 +48,46 @@ pub enum PostprocessingError {
         /// The underlying source error
         source: crate::base::database::OwnedColumnError,
     },
+    /// `HAVING` clause that does not evaluate to a boolean
+    #[snafu(display("HAVING clause must be a boolean expression, but it has type {data_type}"))]
+    NonBooleanHavingClause {
+        /// The data type of the `HAVING` clause
+        data_type: ColumnType,
+    },
     /// Nested aggregation in `GROUP BY` clause
     #[snafu(display("Nested aggregation in `GROUP BY` clause: {error}"))]
     NestedAggregationInGroupByClause {
         /// The nested aggregation error
         error: String,
     },
+    /// Aggregation with a `FILTER` clause in `GROUP BY` clause
+    #[snafu(display(
+        "Aggregations with a FILTER clause are not supported in postprocessing: {error}"
+    ))]
+    AggregationFilterInGroupByClause {
+        /// The filtered aggregation error
+        error: String,
+    },
+    /// `HyperLogLog` sketch with an unsupported number of registers
+    #[snafu(display("HyperLogLog precision must be between 4 and 16, but it is {precision}"))]
+    InvalidHyperLogLogPrecision {
+        /// The precision of the sketch
+        precision: u8,
+    },
+    /// `HyperLogLog` register updates that are not a `BIGINT` column
+    #[snafu(display(
+        "HyperLogLog register updates must be a BIGINT column, but they have type {data_type}"
+    ))]
+    InvalidHyperLogLogUpdates {
+        /// The data type of the updates
+        data_type: ColumnType,
+    },
+    /// `HyperLogLog` register update with a register or rank out of range
+    #[snafu(display("Invalid HyperLogLog register update: {update}"))]
+    InvalidHyperLogLogUpdate {
+        /// The invalid update
+        update: i64,
+    },
 }
 
 /// Result type for postprocessing
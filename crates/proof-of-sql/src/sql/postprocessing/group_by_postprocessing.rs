@@ -1,6 +1,9 @@
 use super::{PostprocessingError, PostprocessingResult, PostprocessingStep};
 use crate::base::{
-    database::{group_by_util::aggregate_columns, Column, OwnedColumn, OwnedTable},
+    database::{
+        filter_util::filter_columns, group_by_util::aggregate_columns, Column, OwnedColumn,
+        OwnedTable,
+    },
     map::{indexmap, IndexMap, IndexSet},
     scalar::Scalar,
 };
@@ -8,7 +11,7 @@ use alloc::{boxed::Box, format, string::ToString, vec, vec::Vec};
 use bumpalo::Bump;
 use itertools::{izip, Itertools};
 use proof_of_sql_parser::{
-    intermediate_ast::{AggregationOperator, AliasedResultExpr, Expression},
+    intermediate_ast::{AggregationOperator, AliasedResultExpr, Expression, OrderBy},
     Identifier,
 };
 use serde::{Deserialize, Serialize};
@@ -24,6 +27,9 @@ pub struct GroupByPostprocessing {
 
     /// A list of aggregation expressions
     aggregation_exprs: Vec<(AggregationOperator, Expression, Identifier)>,
+
+    /// A predicate on the aggregated rows from the `HAVING` clause, using the same aggregation columns as `remainder_exprs`
+    having_expr: Option<Expression>,
 }
 
 /// Check whether multiple layers of aggregation exist within the same GROUP BY clause
@@ -33,29 +39,98 @@ pub struct GroupByPostprocessing {
 /// Otherwise we need two layers of aggregation functions to be nested.
 fn contains_nested_aggregation(expr: &Expression, is_agg: bool) -> bool {
     match expr {
-        Expression::Column(_) | Expression::Literal(_) | Expression::Wildcard => false,
+        Expression::Column(_)
+        | Expression::Literal(_)
+        | Expression::Placeholder(_)
+        | Expression::Wildcard
+        | Expression::RowNumber(_)
+        | Expression::Shift { .. }
+        | Expression::Exists(_)
+        | Expression::ScalarSubquery(_) => false,
         Expression::Aggregation { expr, .. } => is_agg || contains_nested_aggregation(expr, true),
         Expression::Binary { left, right, .. } => {
             contains_nested_aggregation(left, is_agg) || contains_nested_aggregation(right, is_agg)
         }
-        Expression::Unary { expr, .. } => contains_nested_aggregation(expr, is_agg),
+        Expression::Unary { expr, .. } | Expression::DateTrunc { expr, .. } => {
+            contains_nested_aggregation(expr, is_agg)
+        }
+        Expression::Case {
+            condition,
+            then_result,
+            else_result,
+        } => {
+            contains_nested_aggregation(condition, is_agg)
+                || contains_nested_aggregation(then_result, is_agg)
+                || contains_nested_aggregation(else_result, is_agg)
+        }
+    }
+}
+
+/// Check whether any aggregation has a `FILTER` clause, which postprocessing cannot evaluate
+fn contains_aggregation_filter(expr: &Expression) -> bool {
+    match expr {
+        Expression::Column(_)
+        | Expression::Literal(_)
+        | Expression::Placeholder(_)
+        | Expression::Wildcard
+        | Expression::RowNumber(_)
+        | Expression::Shift { .. }
+        | Expression::Exists(_)
+        | Expression::ScalarSubquery(_) => false,
+        Expression::Aggregation { filter, .. } => filter.is_some(),
+        Expression::Binary { left, right, .. } => {
+            contains_aggregation_filter(left) || contains_aggregation_filter(right)
+        }
+        Expression::Unary { expr, .. } | Expression::DateTrunc { expr, .. } => {
+            contains_aggregation_filter(expr)
+        }
+        Expression::Case {
+            condition,
+            then_result,
+            else_result,
+        } => {
+            contains_aggregation_filter(condition)
+                || contains_aggregation_filter(then_result)
+                || contains_aggregation_filter(else_result)
+        }
     }
 }
 
 /// Get identifiers NOT in aggregate functions
 fn get_free_identifiers_from_expr(expr: &Expression) -> IndexSet<Identifier> {
     match expr {
-        Expression::Column(identifier) => IndexSet::from_iter([*identifier]),
-        Expression::Literal(_) | Expression::Aggregation { .. } | Expression::Wildcard => {
-            IndexSet::default()
-        }
+        Expression::Column(identifier)
+        | Expression::RowNumber(OrderBy {
+            expr: identifier, ..
+        }) => IndexSet::from_iter([*identifier]),
+        Expression::Shift {
+            column, order_by, ..
+        } => IndexSet::from_iter([*column, order_by.expr]),
+        Expression::Literal(_)
+        | Expression::Placeholder(_)
+        | Expression::Aggregation { .. }
+        | Expression::Wildcard
+        | Expression::Exists(_)
+        | Expression::ScalarSubquery(_) => IndexSet::default(),
         Expression::Binary { left, right, .. } => {
             let mut left_identifiers = get_free_identifiers_from_expr(left);
             let right_identifiers = get_free_identifiers_from_expr(right);
             left_identifiers.extend(right_identifiers);
             left_identifiers
         }
-        Expression::Unary { expr, .. } => get_free_identifiers_from_expr(expr),
+        Expression::Unary { expr, .. } | Expression::DateTrunc { expr, .. } => {
+            get_free_identifiers_from_expr(expr)
+        }
+        Expression::Case {
+            condition,
+            then_result,
+            else_result,
+        } => {
+            let mut identifiers = get_free_identifiers_from_expr(condition);
+            identifiers.extend(get_free_identifiers_from_expr(then_result));
+            identifiers.extend(get_free_identifiers_from_expr(else_result));
+            identifiers
+        }
     }
 }
 
@@ -73,8 +148,15 @@ fn get_aggregate_and_remainder_expressions(
     aggregation_expr_map: &mut IndexMap<(AggregationOperator, Expression), Identifier>,
 ) -> Expression {
     match expr {
-        Expression::Column(_) | Expression::Literal(_) | Expression::Wildcard => expr,
-        Expression::Aggregation { op, expr } => {
+        Expression::Column(_)
+        | Expression::Literal(_)
+        | Expression::Placeholder(_)
+        | Expression::Wildcard
+        | Expression::RowNumber(_)
+        | Expression::Shift { .. }
+        | Expression::Exists(_)
+        | Expression::ScalarSubquery(_) => expr,
+        Expression::Aggregation { op, expr, .. } => {
             let key = (op, (*expr));
             if aggregation_expr_map.contains_key(&key) {
                 Expression::Column(*aggregation_expr_map.get(&key).unwrap())
@@ -104,34 +186,63 @@ fn get_aggregate_and_remainder_expressions(
                 expr: Box::new(remainder),
             }
         }
+        Expression::DateTrunc { unit, expr } => {
+            let remainder = get_aggregate_and_remainder_expressions(*expr, aggregation_expr_map);
+            Expression::DateTrunc {
+                unit,
+                expr: Box::new(remainder),
+            }
+        }
+        Expression::Case {
+            condition,
+            then_result,
+            else_result,
+        } => Expression::Case {
+            condition: Box::new(get_aggregate_and_remainder_expressions(
+                *condition,
+                aggregation_expr_map,
+            )),
+            then_result: Box::new(get_aggregate_and_remainder_expressions(
+                *then_result,
+                aggregation_expr_map,
+            )),
+            else_result: Box::new(get_aggregate_and_remainder_expressions(
+                *else_result,
+                aggregation_expr_map,
+            )),
+        },
     }
 }
 
-/// Given an `AliasedResultExpr`, check if it is legitimate and if so grab the relevant aggregation expression
+/// Given an `Expression`, check if it is legitimate and if so grab the relevant aggregation expression
 /// # Panics
 ///
 /// Will panic if there is an issue retrieving the first element from the difference of free identifiers and group-by identifiers, indicating a logical inconsistency in the identifiers.
 fn check_and_get_aggregation_and_remainder(
-    expr: AliasedResultExpr,
+    expr: Expression,
     group_by_identifiers: &[Identifier],
     aggregation_expr_map: &mut IndexMap<(AggregationOperator, Expression), Identifier>,
-) -> PostprocessingResult<AliasedResultExpr> {
-    let free_identifiers = get_free_identifiers_from_expr(&expr.expr);
+) -> PostprocessingResult<Expression> {
+    let free_identifiers = get_free_identifiers_from_expr(&expr);
     let group_by_identifier_set = group_by_identifiers
         .iter()
         .copied()
         .collect::<IndexSet<_>>();
-    if contains_nested_aggregation(&expr.expr, false) {
+    if contains_nested_aggregation(&expr, false) {
         return Err(PostprocessingError::NestedAggregationInGroupByClause {
-            error: format!("Nested aggregations found {:?}", expr.expr),
+            error: format!("Nested aggregations found {expr:?}"),
+        });
+    }
+    if contains_aggregation_filter(&expr) {
+        return Err(PostprocessingError::AggregationFilterInGroupByClause {
+            error: format!("Filtered aggregations found {expr:?}"),
         });
     }
     if free_identifiers.is_subset(&group_by_identifier_set) {
-        let remainder = get_aggregate_and_remainder_expressions(*expr.expr, aggregation_expr_map);
-        Ok(AliasedResultExpr {
-            alias: expr.alias,
-            expr: Box::new(remainder),
-        })
+        Ok(get_aggregate_and_remainder_expressions(
+            expr,
+            aggregation_expr_map,
+        ))
     } else {
         let diff = free_identifiers
             .difference(&group_by_identifier_set)
@@ -150,6 +261,18 @@ impl GroupByPostprocessing {
     pub fn try_new(
         by_ids: Vec<Identifier>,
         aliased_exprs: Vec<AliasedResultExpr>,
+    ) -> PostprocessingResult<Self> {
+        Self::try_new_with_having(by_ids, aliased_exprs, None)
+    }
+
+    /// Create a new group by expression whose aggregated rows are filtered by a `HAVING` predicate
+    ///
+    /// The predicate follows the same rules as the result expressions:
+    /// outside aggregate functions it may only reference columns in the group by clause.
+    pub fn try_new_with_having(
+        by_ids: Vec<Identifier>,
+        aliased_exprs: Vec<AliasedResultExpr>,
+        having_expr: Option<Expression>,
     ) -> PostprocessingResult<Self> {
         let mut aggregation_expr_map: IndexMap<(AggregationOperator, Expression), Identifier> =
             IndexMap::default();
@@ -157,13 +280,21 @@ impl GroupByPostprocessing {
         let remainder_exprs: Vec<AliasedResultExpr> = aliased_exprs
             .into_iter()
             .map(|aliased_expr| -> PostprocessingResult<_> {
-                check_and_get_aggregation_and_remainder(
-                    aliased_expr,
-                    &by_ids,
-                    &mut aggregation_expr_map,
-                )
+                Ok(AliasedResultExpr {
+                    alias: aliased_expr.alias,
+                    expr: Box::new(check_and_get_aggregation_and_remainder(
+                        *aliased_expr.expr,
+                        &by_ids,
+                        &mut aggregation_expr_map,
+                    )?),
+                })
             })
             .collect::<PostprocessingResult<Vec<AliasedResultExpr>>>()?;
+        let having_expr = having_expr
+            .map(|expr| {
+                check_and_get_aggregation_and_remainder(expr, &by_ids, &mut aggregation_expr_map)
+            })
+            .transpose()?;
         let group_by_identifiers = Vec::from_iter(IndexSet::from_iter(by_ids));
         Ok(Self {
             remainder_exprs,
@@ -172,6 +303,7 @@ impl GroupByPostprocessing {
                 .into_iter()
                 .map(|((op, expr), id)| (op, expr, id))
                 .collect(),
+            having_expr,
         })
     }
 
@@ -192,6 +324,12 @@ impl GroupByPostprocessing {
     pub fn aggregation_exprs(&self) -> &[(AggregationOperator, Expression, Identifier)] {
         &self.aggregation_exprs
     }
+
+    /// Get the `HAVING` predicate, written in terms of the group by identifiers and aggregation columns
+    #[must_use]
+    pub fn having_expr(&self) -> Option<&Expression> {
+        self.having_expr.as_ref()
+    }
 }
 
 impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
@@ -204,7 +342,11 @@ impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
             .aggregation_exprs
             .iter()
             .map(|(agg_op, expr, id)| -> PostprocessingResult<_> {
-                let evaluated_owned_column = owned_table.evaluate(expr)?;
+                // COUNT(*) = COUNT(1)
+                let evaluated_owned_column = match expr {
+                    Expression::Wildcard => owned_table.evaluate(&Expression::Literal(1.into()))?,
+                    _ => owned_table.evaluate(expr)?,
+                };
                 Ok((*agg_op, (*id, evaluated_owned_column)))
             })
             .process_results(|iter| {
@@ -324,6 +466,10 @@ impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
         } else {
             new_owned_table
         };
+        let target_table = match &self.having_expr {
+            Some(having_expr) => filter_owned_table(&alloc, &target_table, having_expr)?,
+            None => target_table,
+        };
         let result = self
             .remainder_exprs
             .iter()
@@ -336,6 +482,33 @@ impl<S: Scalar> PostprocessingStep<S> for GroupByPostprocessing {
     }
 }
 
+/// Keep only the rows of `owned_table` where `predicate` is true.
+fn filter_owned_table<S: Scalar>(
+    alloc: &Bump,
+    owned_table: &OwnedTable<S>,
+    predicate: &Expression,
+) -> PostprocessingResult<OwnedTable<S>> {
+    let selection = match owned_table.evaluate(predicate)? {
+        OwnedColumn::Boolean(selection) => selection,
+        column => {
+            return Err(PostprocessingError::NonBooleanHavingClause {
+                data_type: column.column_type(),
+            })
+        }
+    };
+    let (identifiers, columns): (Vec<_>, Vec<_>) = owned_table
+        .inner_table()
+        .iter()
+        .map(|(id, column)| (*id, Column::<S>::from_owned_column(column, alloc)))
+        .unzip();
+    let (filtered_columns, _) = filter_columns(alloc, &columns, &selection);
+    Ok(OwnedTable::try_from_iter(
+        identifiers
+            .into_iter()
+            .zip(filtered_columns.iter().map(OwnedColumn::from)),
+    )?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,7 +9,10 @@ use crate::{
     },
 };
 use bigdecimal::BigDecimal;
-use proof_of_sql_parser::{intermediate_ast::AggregationOperator, utility::*};
+use proof_of_sql_parser::{
+    intermediate_ast::{AggregationOperator, Expression},
+    utility::*,
+};
 
 #[test]
 fn we_cannot_have_invalid_group_bys() {
@@ -30,6 +33,152 @@ fn we_cannot_have_invalid_group_bys() {
     ));
 }
 
+#[test]
+fn we_cannot_have_invalid_having_clauses() {
+    // Column in HAVING but not in group by or aggregation
+    let res = GroupByPostprocessing::try_new_with_having(
+        vec![ident("a")],
+        vec![aliased_expr(count_all(), "c")],
+        Some(*ge(col("b"), lit(1))),
+    );
+    assert!(matches!(
+        res,
+        Err(PostprocessingError::IdentifierNotInAggregationOperatorOrGroupByClause { .. })
+    ));
+
+    // Nested aggregation in HAVING
+    let res = GroupByPostprocessing::try_new_with_having(
+        vec![ident("a")],
+        vec![aliased_expr(count_all(), "c")],
+        Some(*ge(sum(max(col("b"))), lit(1))),
+    );
+    assert!(matches!(
+        res,
+        Err(PostprocessingError::NestedAggregationInGroupByClause { .. })
+    ));
+}
+
+#[test]
+fn we_can_make_group_by_postprocessing_with_having() {
+    // SELECT a, SUM(b) as s FROM tab GROUP BY a HAVING SUM(b) >= 2 AND COUNT(*) <= 5
+    let res = GroupByPostprocessing::try_new_with_having(
+        vec![ident("a")],
+        vec![
+            aliased_expr(col("a"), "a"),
+            aliased_expr(sum(col("b")), "s"),
+        ],
+        Some(*and(ge(sum(col("b")), lit(2)), le(count_all(), lit(5)))),
+    )
+    .unwrap();
+    assert_eq!(
+        res.having_expr(),
+        Some(&*and(
+            ge(col("__col_agg_0"), lit(2)),
+            le(col("__col_agg_1"), lit(5))
+        ))
+    );
+    assert_eq!(
+        res.aggregation_exprs(),
+        &[
+            (AggregationOperator::Sum, *col("b"), ident("__col_agg_0")),
+            (
+                AggregationOperator::Count,
+                Expression::Wildcard,
+                ident("__col_agg_1")
+            ),
+        ]
+    );
+    assert_eq!(
+        GroupByPostprocessing::try_new(vec![ident("a")], vec![aliased_expr(col("a"), "a")])
+            .unwrap()
+            .having_expr(),
+        None
+    );
+}
+
+#[test]
+fn we_can_filter_groups_by_their_aggregates() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1_i64, 1, 2, 2, 2, 3]),
+        bigint("b", [5_i64, 6, 1, 1, 1, 10]),
+    ]);
+
+    // SELECT a, COUNT(*) as c FROM tab GROUP BY a HAVING COUNT(*) >= 2
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_having_postprocessing(
+        &["a"],
+        &[aliased_expr(col("a"), "a"), aliased_expr(count_all(), "c")],
+        *ge(count_all(), lit(2)),
+    )];
+    let expected_table = owned_table([bigint("a", [1_i64, 2]), bigint("c", [2_i64, 3])]);
+    let actual_table = apply_postprocessing_steps(table.clone(), &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+
+    // SELECT a FROM tab GROUP BY a HAVING SUM(b) >= 10, where the aggregate is not selected
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_having_postprocessing(
+        &["a"],
+        &[aliased_expr(col("a"), "a")],
+        *ge(sum(col("b")), lit(10)),
+    )];
+    let expected_table = owned_table([bigint("a", [1_i64, 3])]);
+    let actual_table = apply_postprocessing_steps(table.clone(), &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+
+    // SELECT a, SUM(b) as s FROM tab GROUP BY a HAVING NOT a = 1 AND SUM(b) <= 5
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_having_postprocessing(
+        &["a"],
+        &[
+            aliased_expr(col("a"), "a"),
+            aliased_expr(sum(col("b")), "s"),
+        ],
+        *and(not(equal(col("a"), lit(1))), le(sum(col("b")), lit(5))),
+    )];
+    let expected_table = owned_table([bigint("a", [2_i64]), bigint("s", [3_i64])]);
+    let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+}
+
+#[test]
+fn we_can_filter_out_all_groups_with_having() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1_i64, 1, 2]),
+        varchar("d", ["Space", "and", "Time"]),
+    ]);
+
+    // SELECT a, COUNT(*) as c FROM tab GROUP BY a HAVING COUNT(*) >= 3
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_having_postprocessing(
+        &["a"],
+        &[aliased_expr(col("a"), "a"), aliased_expr(count_all(), "c")],
+        *ge(count_all(), lit(3)),
+    )];
+    let expected_table = owned_table([bigint("a", [0_i64; 0]), bigint("c", [0_i64; 0])]);
+    let actual_table = apply_postprocessing_steps(table.clone(), &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+
+    // SELECT COUNT(*) as c FROM tab HAVING COUNT(*) >= 4
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_having_postprocessing(
+        &[],
+        &[aliased_expr(count_all(), "c")],
+        *ge(count_all(), lit(4)),
+    )];
+    let expected_table = owned_table([bigint("c", [0_i64; 0])]);
+    let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
+    assert_eq!(actual_table, expected_table);
+}
+
+#[test]
+fn we_cannot_filter_groups_with_a_non_boolean_having_clause() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [1_i64, 1, 2])]);
+    let postprocessing: [OwnedTablePostprocessing; 1] = [group_by_having_postprocessing(
+        &["a"],
+        &[aliased_expr(col("a"), "a")],
+        *count_all(),
+    )];
+    assert!(matches!(
+        apply_postprocessing_steps(table, &postprocessing),
+        Err(PostprocessingError::NonBooleanHavingClause { .. })
+    ));
+}
+
 #[test]
 fn we_can_make_group_by_postprocessing() {
     // SELECT SUM(a) + 2 as c0, SUM(b + a) as c1 FROM tab GROUP BY a, b
@@ -0,0 +1,179 @@
+use super::{PostprocessingError, PostprocessingResult, PostprocessingStep};
+use crate::{
+    base::{
+        database::{OwnedColumn, OwnedTable},
+        scalar::Scalar,
+    },
+    sql::proof_exprs::{
+        HYPERLOGLOG_MAX_PRECISION, HYPERLOGLOG_MIN_PRECISION, HYPERLOGLOG_RANK_RADIX,
+    },
+};
+use alloc::{string::ToString, vec, vec::Vec};
+use core::f64::consts::{FRAC_1_SQRT_2, LN_2};
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// The relative standard error of a `HyperLogLog` estimate is `HYPERLOGLOG_ERROR_FACTOR / sqrt(2^precision)`
+const HYPERLOGLOG_ERROR_FACTOR: f64 = 1.04;
+
+/// Estimates the number of distinct values from the verified register updates of a `HyperLogLog` sketch.
+///
+/// The updates are the distinct values of [`hyperloglog_update`](crate::sql::proof_exprs::hyperloglog_update)
+/// over a column, e.g. the keys of a verified `GroupByExec`. The sketch keeps the maximum rank of each of the
+/// `m = 2^precision` registers, and the estimate is `alpha_m * m^2 / sum_j 2^(-M_j)`, falling back to
+/// linear counting `m * ln(m / V)` when the estimate is at most `2.5 * m` and `V` registers are empty.
+/// The hash has 64 bits, so no large range correction is needed.
+///
+/// The result is a single row with the estimate and its standard error, both rounded to a `BIGINT`.
+/// The standard error is `1.04 / sqrt(m)` of the estimate, e.g. about 1.6% for a precision of 12,
+/// so the estimate is within two standard errors of the number of distinct values with probability about 95%.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HyperLogLogPostprocessing {
+    /// The column of register updates
+    updates: Identifier,
+    /// The sketch has `2^precision` registers
+    precision: u8,
+    /// The alias of the estimate
+    estimate_alias: Identifier,
+    /// The alias of the standard error of the estimate
+    standard_error_alias: Identifier,
+}
+
+impl HyperLogLogPostprocessing {
+    /// Create a new `HyperLogLogPostprocessing`
+    ///
+    /// # Errors
+    /// Returns an error if the precision is not between
+    /// [`HYPERLOGLOG_MIN_PRECISION`] and [`HYPERLOGLOG_MAX_PRECISION`].
+    pub fn try_new(
+        updates: Identifier,
+        precision: u8,
+        estimate_alias: Identifier,
+        standard_error_alias: Identifier,
+    ) -> PostprocessingResult<Self> {
+        if !(HYPERLOGLOG_MIN_PRECISION..=HYPERLOGLOG_MAX_PRECISION).contains(&precision) {
+            return Err(PostprocessingError::InvalidHyperLogLogPrecision { precision });
+        }
+        Ok(Self {
+            updates,
+            precision,
+            estimate_alias,
+            standard_error_alias,
+        })
+    }
+
+    /// The maximum rank of each register
+    fn registers(&self, updates: &[i64]) -> PostprocessingResult<Vec<u32>> {
+        let mut registers = vec![0; 1 << self.precision];
+        let max_rank = 65 - u32::from(self.precision);
+        for &update in updates {
+            let (register, rank) = (
+                update.div_euclid(HYPERLOGLOG_RANK_RADIX),
+                update.rem_euclid(HYPERLOGLOG_RANK_RADIX),
+            );
+            let (Ok(register), Ok(rank)) = (usize::try_from(register), u32::try_from(rank)) else {
+                return Err(PostprocessingError::InvalidHyperLogLogUpdate { update });
+            };
+            if register >= registers.len() || rank == 0 || rank > max_rank {
+                return Err(PostprocessingError::InvalidHyperLogLogUpdate { update });
+            }
+            registers[register] = registers[register].max(rank);
+        }
+        Ok(registers)
+    }
+
+    /// The estimate of the number of distinct values and its standard error
+    #[allow(clippy::cast_precision_loss)]
+    fn estimate(&self, registers: &[u32]) -> (f64, f64) {
+        let num_registers = registers.len() as f64;
+        let alpha = match registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / num_registers),
+        };
+        // sum_j 2^(-M_j) = sum_j 2^(max_rank - M_j) / 2^max_rank, which is exact before the division
+        let max_rank = 65 - u32::from(self.precision);
+        let scaled_sum: u128 = registers
+            .iter()
+            .map(|&rank| 1u128 << (max_rank - rank))
+            .sum();
+        let raw_estimate =
+            alpha * num_registers * num_registers * (1u128 << max_rank) as f64 / scaled_sum as f64;
+        let empty_registers = registers.iter().filter(|&&rank| rank == 0).count();
+        let estimate = if raw_estimate <= 2.5 * num_registers && empty_registers > 0 {
+            num_registers * (f64::from(self.precision) * LN_2 - ln(empty_registers as f64))
+        } else {
+            raw_estimate
+        };
+        // 1 / sqrt(2^precision)
+        let inverse_sqrt_registers = (1u64 << (self.precision / 2)) as f64;
+        let inverse_sqrt_registers = if self.precision % 2 == 0 {
+            1.0 / inverse_sqrt_registers
+        } else {
+            FRAC_1_SQRT_2 / inverse_sqrt_registers
+        };
+        (
+            estimate,
+            HYPERLOGLOG_ERROR_FACTOR * inverse_sqrt_registers * estimate,
+        )
+    }
+}
+
+/// The natural logarithm of a positive number, which `core` does not provide
+fn ln(x: f64) -> f64 {
+    // x = 2^exponent * mantissa with mantissa in [1, 2)
+    let (mut mantissa, mut exponent) = (x, 0i32);
+    while mantissa >= 2.0 {
+        mantissa /= 2.0;
+        exponent += 1;
+    }
+    while mantissa < 1.0 {
+        mantissa *= 2.0;
+        exponent -= 1;
+    }
+    // ln(mantissa) = 2 * atanh(y) = 2 * (y + y^3 / 3 + y^5 / 5 + ...) with y = (mantissa - 1) / (mantissa + 1) < 1/3
+    let y = (mantissa - 1.0) / (mantissa + 1.0);
+    let (mut term, mut series) = (y, 0.0);
+    for denominator in (1..40).step_by(2) {
+        series += term / f64::from(denominator);
+        term *= y * y;
+    }
+    f64::from(exponent) * LN_2 + 2.0 * series
+}
+
+/// Round a nonnegative number to the nearest integer, which `core` does not provide
+#[allow(clippy::cast_possible_truncation)]
+fn round(x: f64) -> i64 {
+    (x + 0.5) as i64
+}
+
+impl<S: Scalar> PostprocessingStep<S> for HyperLogLogPostprocessing {
+    /// Apply the `HyperLogLog` estimate to the given `OwnedTable`.
+    fn apply(&self, owned_table: OwnedTable<S>) -> PostprocessingResult<OwnedTable<S>> {
+        let updates = match owned_table.inner_table().get(&self.updates) {
+            Some(OwnedColumn::BigInt(updates)) => updates,
+            Some(column) => {
+                return Err(PostprocessingError::InvalidHyperLogLogUpdates {
+                    data_type: column.column_type(),
+                })
+            }
+            None => {
+                return Err(PostprocessingError::ColumnNotFound {
+                    column: self.updates.to_string(),
+                })
+            }
+        };
+        let (estimate, standard_error) = self.estimate(&self.registers(updates)?);
+        Ok(OwnedTable::try_from_iter([
+            (
+                self.estimate_alias,
+                OwnedColumn::BigInt(vec![round(estimate)]),
+            ),
+            (
+                self.standard_error_alias,
+                OwnedColumn::BigInt(vec![round(standard_error)]),
+            ),
+        ])?)
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    base::{
+        database::{owned_table_utility::*, ColumnType, OwnedTable},
+        scalar::Curve25519Scalar,
+    },
+    sql::{
+        postprocessing::{
+            apply_postprocessing_steps, test_utility::*, HyperLogLogPostprocessing,
+            PostprocessingError,
+        },
+        proof_exprs::hyperloglog_update,
+    },
+};
+use itertools::Itertools;
+use proof_of_sql_parser::utility::ident;
+
+/// Estimate the number of distinct values, returning the estimate and its standard error
+fn estimate(values: impl IntoIterator<Item = i64>, precision: u8) -> (i64, i64) {
+    let updates = values
+        .into_iter()
+        .map(|value| hyperloglog_update(value, precision))
+        .unique()
+        .sorted()
+        .collect_vec();
+    let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("updates", updates)]);
+    let result = apply_postprocessing_steps(
+        table,
+        &[hyperloglog("updates", precision, "n", "n_standard_error")],
+    )
+    .unwrap();
+    let column = |name: &str| {
+        result.inner_table()[&ident(name)]
+            .i64_iter()
+            .copied()
+            .collect_vec()
+    };
+    let (estimate, standard_error) = (column("n"), column("n_standard_error"));
+    assert_eq!((estimate.len(), standard_error.len()), (1, 1));
+    (estimate[0], standard_error[0])
+}
+
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_wrap)]
+#[test]
+fn we_can_estimate_the_number_of_distinct_values_within_the_error_bound() {
+    for precision in [4, 8, 12, 14] {
+        let relative_error = 1.04 / f64::from(1u32 << precision).sqrt();
+        for num_distinct in [1_i64, 10, 1_000, 100_000] {
+            for values in [
+                (0..num_distinct).collect_vec(),
+                (0..num_distinct)
+                    .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15_u64 as i64))
+                    .collect_vec(),
+                (0..num_distinct).map(|i| -1000 * i).collect_vec(),
+            ] {
+                let (estimate, standard_error) = estimate(values, precision);
+                let bound = 3.0 * relative_error * num_distinct as f64;
+                assert!(
+                    ((estimate - num_distinct) as f64).abs() <= bound.max(1.0),
+                    "precision {precision}: estimated {estimate} for {num_distinct}"
+                );
+                assert!(
+                    (standard_error as f64 - relative_error * estimate as f64).abs() <= 1.0,
+                    "precision {precision}: standard error {standard_error} of {estimate}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn duplicate_values_do_not_change_the_estimate() {
+    let values = (0..500).collect_vec();
+    let duplicated = values.iter().flat_map(|&value| [value; 3]).collect_vec();
+    assert_eq!(estimate(values, 10), estimate(duplicated, 10));
+    assert_eq!(estimate([7; 20], 10), (1, 0));
+}
+
+#[test]
+fn we_can_estimate_an_empty_sketch() {
+    assert_eq!(estimate([], 4), (0, 0));
+    assert_eq!(estimate([], 16), (0, 0));
+}
+
+#[test]
+fn we_cannot_create_a_sketch_with_an_unsupported_precision() {
+    for precision in [0, 3, 17, 64] {
+        assert_eq!(
+            HyperLogLogPostprocessing::try_new(ident("a"), precision, ident("n"), ident("e")),
+            Err(PostprocessingError::InvalidHyperLogLogPrecision { precision })
+        );
+    }
+}
+
+#[test]
+fn we_cannot_estimate_from_invalid_updates() {
+    // Precision 4 has registers 0..16 and ranks 1..=61
+    for update in [-1, 0, 64, 16 * 64 + 1, 62, 5 * 64 + 63, i64::MAX] {
+        let table: OwnedTable<Curve25519Scalar> = owned_table([bigint("a", [65, update])]);
+        assert_eq!(
+            apply_postprocessing_steps(table, &[hyperloglog("a", 4, "n", "e")]),
+            Err(PostprocessingError::InvalidHyperLogLogUpdate { update })
+        );
+    }
+}
+
+#[test]
+fn we_cannot_estimate_from_a_missing_or_non_bigint_column() {
+    let table: OwnedTable<Curve25519Scalar> = owned_table([int("a", [65])]);
+    assert_eq!(
+        apply_postprocessing_steps(table.clone(), &[hyperloglog("a", 4, "n", "e")]),
+        Err(PostprocessingError::InvalidHyperLogLogUpdates {
+            data_type: ColumnType::Int
+        })
+    );
+    assert_eq!(
+        apply_postprocessing_steps(table, &[hyperloglog("b", 4, "n", "e")]),
+        Err(PostprocessingError::ColumnNotFound {
+            column: "b".to_string()
+        })
+    );
+}
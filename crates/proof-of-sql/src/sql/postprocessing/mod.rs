@@ -29,3 +29,8 @@ mod slice_postprocessing;
 pub use slice_postprocessing::SlicePostprocessing;
 #[cfg(test)]
 mod slice_postprocessing_test;
+
+mod hyperloglog_postprocessing;
+pub use hyperloglog_postprocessing::HyperLogLogPostprocessing;
+#[cfg(test)]
+mod hyperloglog_postprocessing_test;
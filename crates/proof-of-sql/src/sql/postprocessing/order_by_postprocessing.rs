@@ -14,6 +14,8 @@ use rayon::prelude::ParallelSliceMut;
 use serde::{Deserialize, Serialize};
 
 /// A node representing a list of `OrderBy` expressions.
+///
+/// Rows whose keys are all equal are kept in their original order, i.e. the sort is stable.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrderByPostprocessing {
     by_exprs: Vec<OrderBy>,
@@ -52,12 +54,15 @@ impl<S: Scalar> PostprocessingStep<S> for OrderByPostprocessing {
             )
             .collect::<PostprocessingResult<Vec<(OwnedColumn<S>, OrderByDirection)>>>()?;
         // Define the ordering
+        // Rows with equal keys stay in the order of the verified result, so the ordering is fully determined.
         if_rayon!(
             indexes.par_sort_unstable_by(|&a, &b| {
                 compare_indexes_by_owned_columns_with_direction(&order_by_pairs, a, b)
+                    .then(a.cmp(&b))
             }),
             indexes.sort_unstable_by(|&a, &b| {
                 compare_indexes_by_owned_columns_with_direction(&order_by_pairs, a, b)
+                    .then(a.cmp(&b))
             })
         );
         let permutation = Permutation::unchecked_new(indexes);
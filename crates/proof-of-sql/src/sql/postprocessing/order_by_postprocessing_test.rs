@@ -159,3 +159,62 @@ fn we_can_do_order_by_with_random_i128_data() {
     let actual_table = apply_postprocessing_steps(table, &postprocessing).unwrap();
     assert_eq!(actual_table, expected_table);
 }
+
+#[test]
+fn we_can_order_varchar_columns_lexicographically_by_bytes() {
+    let strings = [
+        "b", "", "ab", "a", "abc", "B", "é", "a\u{0}", "zz", "", "e", "aa",
+    ];
+    let mut sorted_strings = strings;
+    sorted_strings.sort_unstable();
+    assert_eq!(
+        sorted_strings,
+        ["", "", "B", "a", "a\u{0}", "aa", "ab", "abc", "b", "e", "zz", "é"]
+    );
+    let mut reverse_sorted_strings = sorted_strings;
+    reverse_sorted_strings.reverse();
+
+    let table: OwnedTable<Curve25519Scalar> = owned_table([varchar("s", strings)]);
+    let ascending: [OwnedTablePostprocessing; 1] = [orders(&["s"], &[Asc])];
+    let actual_table = apply_postprocessing_steps(table.clone(), &ascending).unwrap();
+    assert_eq!(actual_table, owned_table([varchar("s", sorted_strings)]));
+    let descending: [OwnedTablePostprocessing; 1] = [orders(&["s"], &[Desc])];
+    let actual_table = apply_postprocessing_steps(table, &descending).unwrap();
+    assert_eq!(
+        actual_table,
+        owned_table([varchar("s", reverse_sorted_strings)])
+    );
+}
+
+#[test]
+fn rows_with_equal_keys_keep_their_original_order() {
+    let mut rng = rand::thread_rng();
+    let keys: Vec<i64> = (0..200).map(|_| rng.gen_range(-3..3)).collect();
+    let rows: Vec<(i64, i64)> = keys.iter().copied().zip(0..).collect();
+    let table: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("k", keys.clone()),
+        bigint("i", rows.iter().map(|(_, i)| *i)),
+    ]);
+
+    let mut ascending_rows = rows.clone();
+    ascending_rows.sort_by_key(|(k, _)| *k);
+    let ascending: [OwnedTablePostprocessing; 1] = [orders(&["k"], &[Asc])];
+    assert_eq!(
+        apply_postprocessing_steps(table.clone(), &ascending).unwrap(),
+        owned_table([
+            bigint("k", ascending_rows.iter().map(|(k, _)| *k)),
+            bigint("i", ascending_rows.iter().map(|(_, i)| *i)),
+        ])
+    );
+
+    let mut descending_rows = rows;
+    descending_rows.sort_by_key(|(k, _)| core::cmp::Reverse(*k));
+    let descending: [OwnedTablePostprocessing; 1] = [orders(&["k"], &[Desc])];
+    assert_eq!(
+        apply_postprocessing_steps(table, &descending).unwrap(),
+        owned_table([
+            bigint("k", descending_rows.iter().map(|(k, _)| *k)),
+            bigint("i", descending_rows.iter().map(|(_, i)| *i)),
+        ])
+    );
+}
@@ -1,6 +1,6 @@
 use super::{
-    GroupByPostprocessing, OrderByPostprocessing, PostprocessingResult, PostprocessingStep,
-    SelectPostprocessing, SlicePostprocessing,
+    GroupByPostprocessing, HyperLogLogPostprocessing, OrderByPostprocessing, PostprocessingResult,
+    PostprocessingStep, SelectPostprocessing, SlicePostprocessing,
 };
 use crate::base::{database::OwnedTable, scalar::Scalar};
 use serde::{Deserialize, Serialize};
@@ -16,6 +16,8 @@ pub enum OwnedTablePostprocessing {
     Select(SelectPostprocessing),
     /// Aggregate the `OwnedTable` with the given `GroupByPostprocessing`.
     GroupBy(GroupByPostprocessing),
+    /// Estimate the number of distinct values of the `OwnedTable` with the given `HyperLogLogPostprocessing`.
+    HyperLogLog(HyperLogLogPostprocessing),
 }
 
 impl<S: Scalar> PostprocessingStep<S> for OwnedTablePostprocessing {
@@ -26,6 +28,7 @@ impl<S: Scalar> PostprocessingStep<S> for OwnedTablePostprocessing {
             OwnedTablePostprocessing::OrderBy(order_by_expr) => order_by_expr.apply(owned_table),
             OwnedTablePostprocessing::Select(select_expr) => select_expr.apply(owned_table),
             OwnedTablePostprocessing::GroupBy(group_by_expr) => group_by_expr.apply(owned_table),
+            OwnedTablePostprocessing::HyperLogLog(hyperloglog) => hyperloglog.apply(owned_table),
         }
     }
 }
@@ -51,6 +54,11 @@ impl OwnedTablePostprocessing {
     pub fn new_group_by(group_by_postprocessing: GroupByPostprocessing) -> Self {
         Self::GroupBy(group_by_postprocessing)
     }
+    /// Create a new `OwnedTablePostprocessing` with the given `HyperLogLogPostprocessing`.
+    #[must_use]
+    pub fn new_hyperloglog(hyperloglog_postprocessing: HyperLogLogPostprocessing) -> Self {
+        Self::HyperLogLog(hyperloglog_postprocessing)
+    }
 }
 
 /// Apply a list of postprocessing steps to an `OwnedTable`.
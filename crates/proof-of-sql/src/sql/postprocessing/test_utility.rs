@@ -1,6 +1,6 @@
 use super::*;
 use proof_of_sql_parser::{
-    intermediate_ast::{AliasedResultExpr, OrderBy, OrderByDirection},
+    intermediate_ast::{AliasedResultExpr, Expression, OrderBy, OrderByDirection},
     utility::ident,
     Identifier,
 };
@@ -16,6 +16,19 @@ pub fn group_by_postprocessing(
     )
 }
 
+#[must_use]
+pub fn group_by_having_postprocessing(
+    cols: &[&str],
+    result_exprs: &[AliasedResultExpr],
+    having_expr: Expression,
+) -> OwnedTablePostprocessing {
+    let ids: Vec<Identifier> = cols.iter().map(|col| ident(col)).collect();
+    OwnedTablePostprocessing::new_group_by(
+        GroupByPostprocessing::try_new_with_having(ids, result_exprs.to_vec(), Some(having_expr))
+            .unwrap(),
+    )
+}
+
 ///
 /// # Panics
 ///
@@ -25,6 +38,28 @@ pub fn select_expr(result_exprs: &[AliasedResultExpr]) -> OwnedTablePostprocessi
     OwnedTablePostprocessing::new_select(SelectPostprocessing::new(result_exprs.to_vec()))
 }
 
+///
+/// # Panics
+///
+/// This function will panic if any of the identifiers cannot be parsed or the precision is out of range.
+#[must_use]
+pub fn hyperloglog(
+    updates: &str,
+    precision: u8,
+    estimate: &str,
+    standard_error: &str,
+) -> OwnedTablePostprocessing {
+    OwnedTablePostprocessing::new_hyperloglog(
+        HyperLogLogPostprocessing::try_new(
+            ident(updates),
+            precision,
+            ident(estimate),
+            ident(standard_error),
+        )
+        .unwrap(),
+    )
+}
+
 #[must_use]
 pub fn slice(limit: Option<u64>, offset: Option<i64>) -> OwnedTablePostprocessing {
     OwnedTablePostprocessing::new_slice(SlicePostprocessing::new(limit, offset))
@@ -0,0 +1,243 @@
+use super::{
+    CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProvableQueryResult, ProveError,
+    ProverEvaluate, QueryError, QueryProof, SumcheckSubpolynomialType, VerificationBuilder,
+};
+use crate::base::{
+    commitment::CommitmentEvaluationProof,
+    database::{
+        ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, LiteralValue,
+        OwnedTable, Table, TableEvaluation, TableOptions, TableRef,
+    },
+    map::{indexset, IndexMap, IndexSet},
+    proof::ProofError,
+    scalar::Scalar,
+    slice_ops,
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// Errors from proving or verifying that a value does not occur in a column.
+#[derive(Snafu, Debug)]
+pub enum AbsenceError {
+    /// The value does not have the type of the column.
+    #[snafu(display(
+        "a value of type {value_type} cannot occur in a column of type {column_type}"
+    ))]
+    TypeMismatch {
+        /// The type of the column
+        column_type: ColumnType,
+        /// The type of the value
+        value_type: ColumnType,
+    },
+    /// The value occurs in the column, so its absence cannot be proven.
+    #[snafu(display("the value occurs in row {row} of the column"))]
+    ValuePresent {
+        /// The first row that holds the value
+        row: usize,
+    },
+    /// The absence proof could not be created.
+    #[snafu(transparent)]
+    Prove {
+        /// The underlying source error
+        source: ProveError,
+    },
+    /// The absence proof failed to verify.
+    #[snafu(transparent)]
+    Verification {
+        /// The underlying source error
+        source: QueryError,
+    },
+}
+
+/// A proof that a value does not occur in a committed column.
+///
+/// Every value of the column minus the absent value has an inverse, and the proof commits to these inverses
+/// and shows with a single sumcheck constraint that each one is the inverse of its difference.
+/// A difference of zero has no inverse, so no such proof exists if the value occurs.
+/// This is leaner than proving a `COUNT` query, which also has to prove the count of the matching rows.
+///
+/// Note: Because the struct is deserialized from untrusted data, it
+/// cannot maintain any invariant on its data members; hence, they are
+/// all public so as to allow for easy manipulation for testing.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AbsenceProof<CP: CommitmentEvaluationProof> {
+    /// The proof of the absence plan, which has no result columns
+    pub query_proof: QueryProof<CP>,
+}
+
+/// [`ProofPlan`] proving that `value` does not occur in `column`. Its result has no rows or columns.
+#[derive(Debug, Serialize)]
+struct AbsenceExec {
+    column: ColumnRef,
+    value: LiteralValue,
+}
+
+impl ProofPlan for AbsenceExec {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        builder.count_intermediate_mles(1);
+        builder.count_subpolynomials(1);
+        builder.count_degree(3);
+        Ok(())
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        _result: Option<&OwnedTable<S>>,
+        one_eval_map: &IndexMap<TableRef, S>,
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        let column_eval = *accessor.get(&self.column).expect("Column does not exist");
+        let one_eval = *one_eval_map
+            .get(&self.column.table_ref())
+            .expect("One eval not found");
+        let value = self.value.to_scalar::<S>();
+
+        // inverse of column - value
+        let inverse_eval = builder.consume_intermediate_mle();
+
+        // subpolynomial: (column - value) * inverse - 1
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            (column_eval - value * one_eval) * inverse_eval - one_eval,
+        );
+        Ok(TableEvaluation::new(Vec::new(), S::zero()))
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        Vec::new()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        indexset! {self.column}
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        indexset! {self.column.table_ref()}
+    }
+}
+
+impl ProverEvaluate for AbsenceExec {
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        _alloc: &'a Bump,
+        _table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> (Table<'a, S>, Vec<usize>) {
+        (
+            Table::<'a, S>::try_new_with_options(IndexMap::default(), TableOptions::new(Some(0)))
+                .unwrap(),
+            vec![],
+        )
+    }
+
+    fn first_round_evaluate(&self, _builder: &mut FirstRoundBuilder) {}
+
+    #[tracing::instrument(name = "AbsenceExec::final_round_evaluate", level = "debug", skip_all)]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> Table<'a, S> {
+        let table = table_map
+            .get(&self.column.table_ref())
+            .expect("Table not found");
+        let column = table
+            .inner_table()
+            .get(&self.column.column_id())
+            .expect("Column not found");
+        let values: &[_] = alloc.alloc_slice_copy(&column.to_scalar_with_scaling(0));
+        let value = self.value.to_scalar::<S>();
+        let ones: &[_] = alloc.alloc_slice_fill_copy(values.len(), true);
+
+        // inverse of column - value
+        let inverse = alloc.alloc_slice_fill_with(values.len(), |i| values[i] - value);
+        slice_ops::batch_inversion(inverse);
+        builder.metrics_mut().record_field_inversions(values.len());
+        builder.produce_intermediate_mle(inverse as &[_]);
+
+        // subpolynomial: (column - value) * inverse - 1
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::one(), vec![Box::new(values), Box::new(inverse as &[_])]),
+                (-value, vec![Box::new(ones), Box::new(inverse as &[_])]),
+                (-S::one(), vec![Box::new(ones)]),
+            ],
+        );
+        Table::<'a, S>::try_new_with_options(IndexMap::default(), TableOptions::new(Some(0)))
+            .unwrap()
+    }
+}
+
+/// Check that `value` has the type of `column`, so that the two are compared without any conversion.
+fn check_types(column: ColumnRef, value: &LiteralValue) -> Result<(), AbsenceError> {
+    if *column.column_type() == value.column_type() {
+        Ok(())
+    } else {
+        Err(AbsenceError::TypeMismatch {
+            column_type: *column.column_type(),
+            value_type: value.column_type(),
+        })
+    }
+}
+
+/// Prove that `value` does not occur in `column`.
+///
+/// The proof is checked with [`verify_absence`] against the commitment of the column.
+/// A column without rows trivially does not hold the value.
+///
+/// # Errors
+/// Fails fast if the value does not have the type of the column, or occurs in the column.
+/// Also fails if the column does not have the row count of its table.
+pub fn prove_absence<CP: CommitmentEvaluationProof>(
+    column: ColumnRef,
+    value: &LiteralValue,
+    accessor: &impl DataAccessor<CP::Scalar>,
+    setup: &CP::ProverPublicSetup<'_>,
+) -> Result<AbsenceProof<CP>, AbsenceError> {
+    check_types(column, value)?;
+    let scalar = value.to_scalar::<CP::Scalar>();
+    if let Some(row) = accessor
+        .get_column(column)
+        .to_scalar_with_scaling(0)
+        .iter()
+        .position(|&element| element == scalar)
+    {
+        return Err(AbsenceError::ValuePresent { row });
+    }
+    let expr = AbsenceExec {
+        column,
+        value: value.clone(),
+    };
+    let (query_proof, _) = QueryProof::try_new(&expr, accessor, setup)?;
+    Ok(AbsenceProof { query_proof })
+}
+
+/// Verify a proof from [`prove_absence`] that `value` does not occur in `column`.
+///
+/// # Errors
+/// Fails fast if the value does not have the type of the column.
+/// Otherwise fails if the proof does not show that the value is absent from the column committed to in `accessor`.
+pub fn verify_absence<CP: CommitmentEvaluationProof>(
+    proof: &AbsenceProof<CP>,
+    column: ColumnRef,
+    value: &LiteralValue,
+    accessor: &impl CommitmentAccessor<CP::Commitment>,
+    setup: &CP::VerifierPublicSetup<'_>,
+) -> Result<(), AbsenceError> {
+    check_types(column, value)?;
+    let expr = AbsenceExec {
+        column,
+        value: value.clone(),
+    };
+    proof.query_proof.verify(
+        &expr,
+        accessor,
+        &ProvableQueryResult::new::<CP::Scalar>(0, &[]),
+        setup,
+    )?;
+    Ok(())
+}
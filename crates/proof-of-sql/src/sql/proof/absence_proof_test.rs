@@ -0,0 +1,147 @@
+use super::{prove_absence, verify_absence, AbsenceError};
+use crate::{
+    base::database::{
+        owned_table_utility::*, ColumnRef, ColumnType, LiteralValue, OwnedTableTestAccessor,
+        TableRef,
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup, ProverSetup,
+        PublicParameters, VerifierSetup,
+    },
+};
+
+fn column_ref(t: TableRef, name: &str, column_type: ColumnType) -> ColumnRef {
+    ColumnRef::new(t, name.parse().unwrap(), column_type)
+}
+
+#[test]
+fn we_can_prove_that_a_value_is_absent_from_a_column() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1_i64, 2, 3, 2, -5]),
+            varchar("b", ["x", "yy", "zzz", "", "w"]),
+            bigint("c", [1_i64, 2, 3, 2, 4]),
+        ]),
+        0,
+        setup,
+    );
+    let a = column_ref(t, "a", ColumnType::BigInt);
+    let b = column_ref(t, "b", ColumnType::VarChar);
+
+    let proof =
+        prove_absence::<DoryEvaluationProof>(a, &LiteralValue::BigInt(4), &accessor, &setup)
+            .unwrap();
+    verify_absence(
+        &proof,
+        a,
+        &LiteralValue::BigInt(4),
+        &accessor,
+        &verifier_setup,
+    )
+    .unwrap();
+
+    let banned = LiteralValue::VarChar("banned".into());
+    let varchar_proof =
+        prove_absence::<DoryEvaluationProof>(b, &banned, &accessor, &setup).unwrap();
+    verify_absence(&varchar_proof, b, &banned, &accessor, &verifier_setup).unwrap();
+
+    // The proof only attests the absence of the value and column it was created for.
+    assert!(matches!(
+        verify_absence(
+            &proof,
+            a,
+            &LiteralValue::BigInt(2),
+            &accessor,
+            &verifier_setup
+        ),
+        Err(AbsenceError::Verification { .. })
+    ));
+    assert!(matches!(
+        verify_absence(
+            &proof,
+            column_ref(t, "c", ColumnType::BigInt),
+            &LiteralValue::BigInt(4),
+            &accessor,
+            &verifier_setup
+        ),
+        Err(AbsenceError::Verification { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_prove_that_a_present_value_is_absent() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1_i64, 2, 3, 2, -5]),
+            varchar("b", ["x", "yy", "zzz", "", "w"]),
+        ]),
+        0,
+        setup,
+    );
+    let a = column_ref(t, "a", ColumnType::BigInt);
+
+    let Err(err) =
+        prove_absence::<DoryEvaluationProof>(a, &LiteralValue::BigInt(2), &accessor, &setup)
+    else {
+        panic!("the absence of a present value should not be provable");
+    };
+    assert!(matches!(err, AbsenceError::ValuePresent { row: 1 }));
+    assert_eq!(err.to_string(), "the value occurs in row 1 of the column");
+    assert!(matches!(
+        prove_absence::<DoryEvaluationProof>(
+            column_ref(t, "b", ColumnType::VarChar),
+            &LiteralValue::VarChar(String::new()),
+            &accessor,
+            &setup
+        ),
+        Err(AbsenceError::ValuePresent { row: 3 })
+    ));
+    assert!(matches!(
+        prove_absence::<DoryEvaluationProof>(a, &LiteralValue::Int(4), &accessor, &setup),
+        Err(AbsenceError::TypeMismatch {
+            column_type: ColumnType::BigInt,
+            value_type: ColumnType::Int
+        })
+    ));
+}
+
+#[test]
+fn we_can_prove_that_a_value_is_absent_from_an_empty_column() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [0_i64; 0])]),
+        0,
+        setup,
+    );
+    let a = column_ref(t, "a", ColumnType::BigInt);
+
+    let proof =
+        prove_absence::<DoryEvaluationProof>(a, &LiteralValue::BigInt(0), &accessor, &setup)
+            .unwrap();
+    verify_absence(
+        &proof,
+        a,
+        &LiteralValue::BigInt(0),
+        &accessor,
+        &verifier_setup,
+    )
+    .unwrap();
+}
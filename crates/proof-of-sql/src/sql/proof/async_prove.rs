@@ -0,0 +1,76 @@
+use super::{ProofPlan, ProvableQueryResult, ProveError, ProveOptions, QueryProof};
+use crate::base::{commitment::CommitmentEvaluationProof, database::DataAccessor};
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll},
+};
+use serde::Serialize;
+use std::{panic, sync::Arc};
+use tokio::task::JoinHandle;
+
+/// The future returned by [`QueryProof::try_new_async`].
+///
+/// Dropping the future cancels the proof: the blocking task stops at the start of its next [`ProofStage`](super::ProofStage).
+pub struct ProveFuture<CP: CommitmentEvaluationProof> {
+    handle: JoinHandle<Result<(QueryProof<CP>, ProvableQueryResult), ProveError>>,
+    dropped: Arc<AtomicBool>,
+}
+
+impl<CP: CommitmentEvaluationProof> Future for ProveFuture<CP> {
+    type Output = Result<(QueryProof<CP>, ProvableQueryResult), ProveError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.handle).poll(cx).map(|joined| {
+            joined.unwrap_or_else(|error| match error.try_into_panic() {
+                Ok(payload) => panic::resume_unwind(payload),
+                // The runtime is shutting down, so the task was never run to completion.
+                Err(_) => Err(ProveError::Cancelled),
+            })
+        })
+    }
+}
+
+impl<CP: CommitmentEvaluationProof> Drop for ProveFuture<CP> {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::Relaxed);
+    }
+}
+
+impl<CP: CommitmentEvaluationProof + Send + 'static> QueryProof<CP> {
+    /// Create a new `QueryProof` on the blocking thread pool of the current tokio runtime,
+    /// so that awaiting it does not block the runtime's other tasks.
+    ///
+    /// Proof generation is abandoned if `should_cancel` returns `true` or if the returned future is dropped,
+    /// as with [`ProveOptions::should_cancel`].
+    /// Otherwise the proof is identical to the one [`QueryProof::try_new`] creates.
+    ///
+    /// Will error if the columns referenced by the query do not have the row counts of their tables,
+    /// or with [`ProveError::Cancelled`] if the proof is cancelled.
+    ///
+    /// # Panics
+    /// Panics if called outside of a tokio runtime. A panic while proving is propagated when the future is polled.
+    pub fn try_new_async(
+        expr: impl ProofPlan + Serialize + 'static,
+        accessor: impl DataAccessor<CP::Scalar> + Send + 'static,
+        setup: CP::ProverPublicSetup<'static>,
+        should_cancel: impl Fn() -> bool + Send + 'static,
+    ) -> ProveFuture<CP>
+    where
+        CP::ProverPublicSetup<'static>: Send,
+    {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let is_dropped = Arc::clone(&dropped);
+        let handle = tokio::task::spawn_blocking(move || {
+            let cancelled = || is_dropped.load(Ordering::Relaxed) || should_cancel();
+            let options = ProveOptions {
+                should_cancel: Some(&cancelled),
+                ..ProveOptions::default()
+            };
+            Self::try_new_with_options(&expr, &accessor, &setup, options)
+                .map(|(proof, result)| (proof, result.disclosed))
+        });
+        ProveFuture { handle, dropped }
+    }
+}
@@ -0,0 +1,121 @@
+use super::{ProveError, QueryProof};
+use crate::{
+    base::database::{owned_table_utility::*, OwnedTableTestAccessor},
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, ProverSetup, PublicParameters,
+    },
+    sql::{
+        proof_exprs::test_utility::*,
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    mpsc, Arc,
+};
+use tokio::runtime::{Builder, Runtime};
+
+/// A setup that lives as long as the blocking tasks that prove with it
+fn static_setup() -> DoryProverPublicSetup<'static> {
+    let public_parameters = Box::leak(Box::new(PublicParameters::test_rand(3, &mut test_rng())));
+    let prover_setup = Box::leak(Box::new(ProverSetup::from(&*public_parameters)));
+    DoryProverPublicSetup::new(prover_setup, 2)
+}
+
+fn accessor_and_plan(
+    setup: DoryProverPublicSetup<'static>,
+) -> (
+    OwnedTableTestAccessor<'static, DoryEvaluationProof>,
+    DynProofPlan,
+) {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1_i64, 2, 3, 2]), bigint("b", [5_i64, 6, 7, 8])]),
+        0,
+        setup,
+    );
+    let plan = filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(2)),
+    );
+    (accessor, plan)
+}
+
+fn runtime() -> Runtime {
+    Builder::new_current_thread().build().unwrap()
+}
+
+#[test]
+fn an_async_proof_is_the_same_as_a_sync_proof() {
+    let setup = static_setup();
+    let (accessor, plan) = accessor_and_plan(setup);
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    let (async_accessor, async_plan) = accessor_and_plan(setup);
+    let (async_proof, async_result) = runtime()
+        .block_on(async {
+            QueryProof::<DoryEvaluationProof>::try_new_async(
+                async_plan,
+                async_accessor,
+                setup,
+                || false,
+            )
+            .await
+        })
+        .unwrap();
+    assert_eq!(
+        postcard::to_allocvec(&proof).unwrap(),
+        postcard::to_allocvec(&async_proof).unwrap()
+    );
+    assert_eq!(
+        postcard::to_allocvec(&result).unwrap(),
+        postcard::to_allocvec(&async_result).unwrap()
+    );
+}
+
+#[test]
+fn we_can_cancel_an_async_proof_with_the_cancellation_callback() {
+    let setup = static_setup();
+    let (accessor, plan) = accessor_and_plan(setup);
+    assert_eq!(
+        runtime()
+            .block_on(async {
+                QueryProof::<DoryEvaluationProof>::try_new_async(plan, accessor, setup, || true)
+                    .await
+            })
+            .err()
+            .unwrap(),
+        ProveError::Cancelled
+    );
+}
+
+#[test]
+fn dropping_an_async_proof_cancels_it() {
+    let setup = static_setup();
+    let (accessor, plan) = accessor_and_plan(setup);
+    let checks = Arc::new(AtomicUsize::new(0));
+    let (started_sender, started) = mpsc::channel();
+    let (release, released) = mpsc::channel::<()>();
+    let runtime = runtime();
+    let future = {
+        let _guard = runtime.enter();
+        let checks = Arc::clone(&checks);
+        QueryProof::<DoryEvaluationProof>::try_new_async(plan, accessor, setup, move || {
+            if checks.fetch_add(1, Ordering::Relaxed) == 0 {
+                started_sender.send(()).unwrap();
+                released.recv().unwrap();
+            }
+            false
+        })
+    };
+    // Drop the future while proof generation is paused at the start of its first stage.
+    started.recv().unwrap();
+    drop(future);
+    release.send(()).unwrap();
+    // Dropping the runtime waits for the blocking task to finish.
+    drop(runtime);
+    // The task stopped at the start of the next stage, without asking the callback again.
+    assert_eq!(checks.load(Ordering::Relaxed), 1);
+}
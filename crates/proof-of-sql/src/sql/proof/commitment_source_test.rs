@@ -0,0 +1,86 @@
+use super::{QueryProof, VerificationError};
+use crate::{
+    base::database::{
+        owned_table_utility::*, ColumnRef, ColumnType, CommitmentAccessor, OwnedTableTestAccessor,
+        TableRef,
+    },
+    proof_primitive::dory::{
+        test_rng, DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup,
+        DoryVerifierPublicSetup, ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use std::collections::HashMap;
+
+fn column_ref(t: TableRef, name: &str) -> ColumnRef {
+    ColumnRef::new(t, name.parse().unwrap(), ColumnType::BigInt)
+}
+
+#[test]
+fn we_can_verify_a_proof_with_commitments_fetched_from_a_commitment_source() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1_i64, 2, 3, 2]),
+            bigint("b", [5_i64, 6, 7, 8]),
+            bigint("c", [0_i64, 0, 0, 0]),
+        ]),
+        0,
+        setup,
+    );
+    // select b from sxt.t where a = 2
+    let plan = filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(2)),
+    );
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    let expected = proof
+        .verify(&plan, &accessor, &result, &verifier_setup)
+        .unwrap();
+
+    // The source only needs the columns the query references, so `c` can be left out.
+    let mut source: HashMap<ColumnRef, DoryCommitment> = ["a", "b"]
+        .into_iter()
+        .map(|name| {
+            let column = column_ref(t, name);
+            (column, accessor.get_commitment(column))
+        })
+        .collect();
+    let verified = proof
+        .verify_with_commitment_source(&plan, &accessor, &source, &result, &verifier_setup)
+        .unwrap();
+    assert_eq!(verified.table, expected.table);
+    assert_eq!(verified.verification_hash, expected.verification_hash);
+
+    // A wrong commitment fails verification.
+    source.insert(
+        column_ref(t, "a"),
+        accessor.get_commitment(column_ref(t, "c")),
+    );
+    assert!(matches!(
+        proof.verify_with_commitment_source(&plan, &accessor, &source, &result, &verifier_setup),
+        Err(VerificationError::Query { .. })
+    ));
+
+    // A missing commitment is reported.
+    source.remove(&column_ref(t, "a"));
+    let Err(err) =
+        proof.verify_with_commitment_source(&plan, &accessor, &source, &result, &verifier_setup)
+    else {
+        panic!("verification should fail without the commitment of a");
+    };
+    assert!(matches!(
+        &err,
+        VerificationError::MissingCommitment { table_ref, column_id }
+            if **table_ref == t && *column_id == column_ref(t, "a").column_id()
+    ));
+    assert_eq!(err.to_string(), "no commitment for column a of table sxt.t");
+}
@@ -1,5 +1,5 @@
 use super::{
-    CompositePolynomialBuilder, SumcheckRandomScalars, SumcheckSubpolynomial,
+    CompositePolynomialBuilder, ProveMetrics, SumcheckRandomScalars, SumcheckSubpolynomial,
     SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
 };
 use crate::base::{
@@ -25,6 +25,7 @@ pub struct FinalRoundBuilder<'a, S: Scalar> {
     /// Note: this vector is treated as a stack and the first
     /// challenge is the last entry in the vector.
     post_result_challenges: Vec<S>,
+    metrics: ProveMetrics,
 }
 
 impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
@@ -36,6 +37,7 @@ impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
             pcs_proof_mles: Vec::new(),
             sumcheck_subpolynomials: Vec::new(),
             post_result_challenges,
+            metrics: ProveMetrics::default(),
         }
     }
 
@@ -51,6 +53,17 @@ impl<'a, S: Scalar> FinalRoundBuilder<'a, S> {
         &self.pcs_proof_mles
     }
 
+    pub fn commitment_descriptor(&self) -> &[CommittableColumn<'a>] {
+        &self.commitment_descriptor
+    }
+
+    /// The counters of the operations performed while proving.
+    ///
+    /// Recording is a no-op unless the `prove-metrics` feature is enabled.
+    pub fn metrics_mut(&mut self) -> &mut ProveMetrics {
+        &mut self.metrics
+    }
+
     /// Produce a bit distribution that describes which bits are constant
     /// and which bits varying in a column of data
     pub fn produce_bit_distribution(&mut self, dist: BitDistribution) {
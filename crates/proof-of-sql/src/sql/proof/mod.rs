@@ -26,6 +26,11 @@ pub(crate) use provable_result_column::ProvableResultColumn;
 mod provable_query_result;
 pub use provable_query_result::ProvableQueryResult;
 
+mod partially_disclosed_result;
+pub use partially_disclosed_result::PartiallyDisclosedResult;
+#[cfg(test)]
+mod partially_disclosed_result_test;
+
 mod sumcheck_mle_evaluations;
 pub(crate) use sumcheck_mle_evaluations::SumcheckMleEvaluations;
 #[cfg(test)]
@@ -35,13 +40,78 @@ mod sumcheck_random_scalars;
 pub(crate) use sumcheck_random_scalars::SumcheckRandomScalars;
 
 mod proof_plan;
-pub use proof_plan::ProofPlan;
+pub use proof_plan::{ProofPlan, ValidationError};
+#[cfg(test)]
+mod proof_plan_test;
 pub(crate) use proof_plan::{HonestProver, ProverEvaluate, ProverHonestyMarker};
 
 mod query_proof;
-pub use query_proof::QueryProof;
+#[cfg(feature = "transcript-log")]
+pub use crate::base::proof::{
+    replay_transcript_log, TranscriptEntry, APPEND_LABEL, CHALLENGE_LABEL,
+};
+use query_proof::check_row_counts;
+pub use query_proof::{MalformedError, ProofStage, ProveError, QueryProof, VerificationError};
+#[cfg(all(test, feature = "std"))]
+mod commitment_source_test;
 #[cfg(all(test, feature = "blitzar"))]
 mod query_proof_test;
+#[cfg(test)]
+mod replay_challenges_test;
+#[cfg(test)]
+mod well_formed_test;
+
+mod proof_batch;
+pub use proof_batch::ProofBatch;
+#[cfg(test)]
+mod proof_batch_test;
+
+#[cfg(feature = "proof-stages")]
+mod proof_stages;
+#[cfg(feature = "proof-stages")]
+pub use proof_stages::{CommittedStage, ProofStages, SumcheckStage};
+#[cfg(all(test, feature = "proof-stages"))]
+mod proof_stages_test;
+
+#[cfg(feature = "tokio")]
+mod async_prove;
+#[cfg(feature = "tokio")]
+pub use async_prove::ProveFuture;
+#[cfg(all(test, feature = "tokio"))]
+mod async_prove_test;
+
+mod verify_cost;
+pub use verify_cost::VerifyCost;
+#[cfg(test)]
+mod verify_cost_test;
+
+mod table_equality_proof;
+pub use table_equality_proof::{
+    prove_table_equality, verify_table_equality, TableEqualityError, TableEqualityProof,
+};
+#[cfg(test)]
+mod table_equality_proof_test;
+
+mod absence_proof;
+pub use absence_proof::{prove_absence, verify_absence, AbsenceError, AbsenceProof};
+#[cfg(test)]
+mod absence_proof_test;
+
+mod percentile_proof;
+pub use percentile_proof::{
+    prove_percentile, prove_percentile_of_value, verify_percentile, Fraction, PercentileError,
+    PercentileProof,
+};
+#[cfg(test)]
+mod percentile_proof_test;
+
+mod prover_scratch;
+pub use prover_scratch::ProverScratch;
+#[cfg(test)]
+mod prover_scratch_test;
+
+mod prove_options;
+pub use prove_options::ProveOptions;
 
 mod query_result;
 pub use query_result::{QueryData, QueryError, QueryResult};
@@ -51,6 +121,20 @@ pub(crate) use sumcheck_subpolynomial::{
     SumcheckSubpolynomial, SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
 };
 
+mod proof_envelope;
+pub use proof_envelope::{
+    envelope_size, serialized_size, to_envelope_bytes, try_from_envelope_bytes, DeserializeError,
+    EnvelopePayload, OLDEST_SUPPORTED_PROOF_FORMAT_VERSION, PROOF_ENVELOPE_MAGIC,
+    PROOF_FORMAT_VERSION,
+};
+#[cfg(test)]
+mod proof_envelope_test;
+
+mod proof_archive;
+pub use proof_archive::{ProofArchive, ProofArchiveError, PROOF_ARCHIVE_MAGIC};
+#[cfg(test)]
+mod proof_archive_test;
+
 mod verifiable_query_result;
 pub use verifiable_query_result::VerifiableQueryResult;
 #[cfg(all(test, feature = "blitzar"))]
@@ -61,11 +145,22 @@ mod verifiable_query_result_test_utility;
 #[cfg(all(test, feature = "blitzar"))]
 pub(crate) use verifiable_query_result_test_utility::exercise_verification;
 
+mod streaming_verifier;
+pub use streaming_verifier::{StreamingVerificationError, StreamingVerifier};
+#[cfg(test)]
+mod streaming_verifier_test;
+
 mod result_element_serialization;
 pub(crate) use result_element_serialization::{
     decode_and_convert, decode_multiple_elements, ProvableResultElement,
 };
 
+mod prove_metrics;
+#[cfg(feature = "prove-metrics")]
+pub use prove_metrics::ProveMetrics;
+#[cfg(not(feature = "prove-metrics"))]
+pub(crate) use prove_metrics::ProveMetrics;
+
 mod first_round_builder;
 pub(crate) use first_round_builder::FirstRoundBuilder;
 
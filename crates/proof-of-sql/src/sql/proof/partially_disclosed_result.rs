@@ -0,0 +1,34 @@
+use super::ProvableQueryResult;
+use crate::base::commitment::Commitment;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// A query result where some of the columns are disclosed and the rest are only committed to.
+///
+/// This is created by [`QueryProof::try_new_with_options`](super::QueryProof::try_new_with_options)
+/// and verified with [`QueryProof::verify_partially_disclosed`](super::QueryProof::verify_partially_disclosed).
+/// The proof binds every column of the result, so verifying it confirms the disclosed columns
+/// and that each hidden column is the column of the true result that its commitment commits to.
+///
+/// The hidden columns are withheld, not hidden in the cryptographic sense. Their commitments are deterministic
+/// and not hiding, and the proof opens the evaluations of their MLEs at a random point, so both leak information
+/// about the columns. Anyone holding the public setup can recompute the commitment of a guessed column, so columns
+/// with few possible values, such as flags, small integers or short strings, can be recovered by brute force.
+/// Only withhold columns when that is acceptable.
+///
+/// Note: Because the struct is deserialized from untrusted data, it
+/// cannot maintain any invariant on its data members; hence, they are
+/// all public so as to allow for easy manipulation for testing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartiallyDisclosedResult<C: Commitment> {
+    /// The disclosed columns, in the order they appear in the result
+    ///
+    /// Its length is the length of the whole result, even when every column is hidden.
+    pub disclosed: ProvableQueryResult,
+    /// The positions of the hidden columns in the result, in increasing order
+    pub hidden_column_indexes: Vec<usize>,
+    /// The commitments to the hidden columns, in the order of `hidden_column_indexes`
+    ///
+    /// Each column is committed to starting at the smallest offset of the tables the query reads.
+    pub hidden_commitments: Vec<C>,
+}
@@ -0,0 +1,263 @@
+use super::{ProvableQueryResult, ProveError, ProveOptions, QueryProof, VerifiableQueryResult};
+use crate::{
+    base::{
+        commitment::{Commitment, CommittableColumn},
+        database::{
+            owned_table_utility::*, Column, OwnedColumn, OwnedTable, OwnedTableTestAccessor,
+            TableRef,
+        },
+    },
+    proof_primitive::dory::{
+        test_rng, DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar,
+        DoryVerifierPublicSetup, ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        proof_exprs::test_utility::*,
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+use proof_of_sql_parser::Identifier;
+
+/// Options that hide the result columns `names`
+fn hidden(names: &[&str]) -> ProveOptions<'static, DoryScalar> {
+    ProveOptions {
+        hidden_columns: names.iter().map(|name| name.parse().unwrap()).collect(),
+        ..ProveOptions::default()
+    }
+}
+
+fn accessor(
+    setup: DoryProverPublicSetup<'_>,
+) -> (TableRef, OwnedTableTestAccessor<'_, DoryEvaluationProof>) {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1_i64, 2, 3, 2, 2]),
+            bigint("b", [5_i64, 6, 7, 8, 9]),
+            varchar("c", ["x", "y", "z", "w", "v"]),
+        ]),
+        0,
+        setup,
+    );
+    (t, accessor)
+}
+
+// select b, c, a from sxt.t where a = 2
+fn filter_plan(
+    t: TableRef,
+    accessor: &OwnedTableTestAccessor<DoryEvaluationProof>,
+) -> DynProofPlan {
+    filter(
+        cols_expr_plan(t, &["b", "c", "a"], accessor),
+        tab(t),
+        equal(column(t, "a", accessor), const_bigint(2)),
+    )
+}
+
+fn commitment_to(
+    column: &OwnedColumn<DoryScalar>,
+    setup: &DoryProverPublicSetup,
+) -> DoryCommitment {
+    DoryCommitment::compute_commitments(&[CommittableColumn::from(column)], 0, setup)[0]
+}
+
+#[test]
+fn we_can_verify_the_disclosed_columns_of_a_partially_disclosed_result() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = filter_plan(t, &accessor);
+
+    let (proof, result) = QueryProof::<DoryEvaluationProof>::try_new_with_options(
+        &plan,
+        &accessor,
+        &setup,
+        hidden(&["c"]),
+    )
+    .unwrap();
+    assert_eq!(result.hidden_column_indexes, vec![1]);
+    assert_eq!(result.disclosed.num_columns(), 2);
+
+    let verified = proof
+        .verify_partially_disclosed(&plan, &accessor, &result, &verifier_setup)
+        .unwrap();
+    let expected: OwnedTable<DoryScalar> =
+        owned_table([bigint("b", [6_i64, 8, 9]), bigint("a", [2_i64, 2, 2])]);
+    assert_eq!(verified.table, expected);
+
+    // The hidden column is never sent, but its commitment is the commitment to the true column.
+    let hidden_column: OwnedColumn<DoryScalar> =
+        OwnedColumn::VarChar(["y", "w", "v"].map(String::from).to_vec());
+    assert_eq!(
+        result.hidden_commitments,
+        vec![commitment_to(&hidden_column, &setup)]
+    );
+}
+
+#[test]
+fn we_can_hide_every_column_or_no_column_of_a_result() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = filter_plan(t, &accessor);
+
+    let (proof, result) = QueryProof::<DoryEvaluationProof>::try_new_with_options(
+        &plan,
+        &accessor,
+        &setup,
+        hidden(&["a", "b", "c"]),
+    )
+    .unwrap();
+    assert_eq!(result.hidden_column_indexes, vec![0, 1, 2]);
+    assert_eq!(result.disclosed.table_length(), 3);
+    let verified = proof
+        .verify_partially_disclosed(&plan, &accessor, &result, &verifier_setup)
+        .unwrap();
+    assert_eq!(verified.table.num_columns(), 0);
+
+    // Hiding nothing is the same as proving the result in full.
+    let (proof, result) = QueryProof::<DoryEvaluationProof>::try_new_with_options(
+        &plan,
+        &accessor,
+        &setup,
+        ProveOptions::default(),
+    )
+    .unwrap();
+    let (full_proof, full_result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    assert_eq!(
+        postcard::to_allocvec(&proof).unwrap(),
+        postcard::to_allocvec(&full_proof).unwrap()
+    );
+    assert_eq!(
+        postcard::to_allocvec(&result.disclosed).unwrap(),
+        postcard::to_allocvec(&full_result).unwrap()
+    );
+    assert!(result.hidden_commitments.is_empty());
+}
+
+#[test]
+fn hidden_columns_are_bound_by_the_proof() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = filter_plan(t, &accessor);
+    let (proof, result) = QueryProof::<DoryEvaluationProof>::try_new_with_options(
+        &plan,
+        &accessor,
+        &setup,
+        hidden(&["b"]),
+    )
+    .unwrap();
+    assert!(proof
+        .verify_partially_disclosed(&plan, &accessor, &result, &verifier_setup)
+        .is_ok());
+
+    // A commitment to a column other than the true hidden column is rejected.
+    let mut tampered = result.clone();
+    tampered.hidden_commitments[0] = commitment_to(&OwnedColumn::BigInt(vec![6, 8, 10]), &setup);
+    assert!(proof
+        .verify_partially_disclosed(&plan, &accessor, &tampered, &verifier_setup)
+        .is_err());
+
+    // So is a commitment to the true hidden column in a different position.
+    let mut tampered = result.clone();
+    tampered.hidden_column_indexes = vec![2];
+    assert!(proof
+        .verify_partially_disclosed(&plan, &accessor, &tampered, &verifier_setup)
+        .is_err());
+
+    // A result that hides the column is not a result that discloses it.
+    assert!(proof
+        .verify(&plan, &accessor, &result.disclosed, &verifier_setup)
+        .is_err());
+
+    // Malformed hidden columns are rejected.
+    for (indexes, num_commitments) in [(vec![], 1), (vec![0, 0], 2), (vec![3], 1)] {
+        let mut tampered = result.clone();
+        tampered.hidden_column_indexes = indexes;
+        tampered.hidden_commitments = vec![result.hidden_commitments[0]; num_commitments];
+        assert!(proof
+            .verify_partially_disclosed(&plan, &accessor, &tampered, &verifier_setup)
+            .is_err());
+    }
+}
+
+#[test]
+fn the_disclosed_columns_of_a_partially_disclosed_result_are_bound_by_the_proof() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = filter_plan(t, &accessor);
+    let (proof, mut result) = QueryProof::<DoryEvaluationProof>::try_new_with_options(
+        &plan,
+        &accessor,
+        &setup,
+        hidden(&["c"]),
+    )
+    .unwrap();
+    // b = 8 becomes b = 7
+    result.disclosed = ProvableQueryResult::new(
+        3,
+        &[
+            Column::BigInt(&[6, 7, 9]),
+            Column::<DoryScalar>::BigInt(&[2, 2, 2]),
+        ],
+    );
+    assert!(proof
+        .verify_partially_disclosed(&plan, &accessor, &result, &verifier_setup)
+        .is_err());
+}
+
+#[test]
+fn we_cannot_hide_a_column_that_is_not_in_the_result() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(2)),
+    );
+    assert!(matches!(
+        QueryProof::<DoryEvaluationProof>::try_new_with_options(
+            &plan,
+            &accessor,
+            &setup,
+            hidden(&["b", "a"]),
+        ),
+        Err(ProveError::UnknownResultColumn { column }) if column == "a".parse::<Identifier>().unwrap()
+    ));
+}
+
+#[test]
+fn we_cannot_hide_a_column_of_a_verifiable_query_result() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = filter_plan(t, &accessor);
+    assert!(matches!(
+        VerifiableQueryResult::<DoryEvaluationProof>::try_new_with_options(
+            &plan,
+            &accessor,
+            &setup,
+            hidden(&["c"]),
+        ),
+        Err(ProveError::HiddenColumnNotSupported { column }) if column == "c".parse::<Identifier>().unwrap()
+    ));
+}
@@ -0,0 +1,382 @@
+use super::{ProvableQueryResult, ProveError, QueryError, QueryProof};
+use crate::{
+    base::{
+        commitment::CommitmentEvaluationProof,
+        database::{
+            order_by_util::compare_indexes_by_columns, Column, ColumnRef, ColumnType,
+            CommitmentAccessor, DataAccessor, LiteralValue, OwnedColumn,
+        },
+        math::i256::I256,
+        scalar::Scalar,
+    },
+    sql::{
+        proof_exprs::{AliasedDynProofExpr, DynProofExpr, TableExpr},
+        proof_plans::{DynProofPlan, GroupByExec},
+    },
+};
+use alloc::{vec, vec::Vec};
+use proof_of_sql_parser::Identifier;
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// The alias of the number of rows less than the percentile
+const BELOW_ALIAS: &str = "below";
+/// The alias of the number of rows at most the percentile
+const AT_MOST_ALIAS: &str = "at_most";
+/// The alias of the number of rows of the column
+const COUNT_ALIAS: &str = "total";
+
+/// Errors from proving or verifying a percentile of a column.
+#[derive(Snafu, Debug)]
+pub enum PercentileError {
+    /// A quantile or error bound is not a fraction between zero and one.
+    #[snafu(display("{numerator}/{denominator} is not a fraction between 0 and 1"))]
+    InvalidFraction {
+        /// The numerator of the fraction
+        numerator: u32,
+        /// The denominator of the fraction
+        denominator: u32,
+    },
+    /// The column does not have an ordered type that can be compared in a proof.
+    #[snafu(display("percentiles of columns of type {column_type} are not supported"))]
+    UnsupportedType {
+        /// The type of the column
+        column_type: ColumnType,
+    },
+    /// The value does not have the type of the column.
+    #[snafu(display(
+        "a value of type {value_type} cannot be a percentile of a column of type {column_type}"
+    ))]
+    TypeMismatch {
+        /// The type of the column
+        column_type: ColumnType,
+        /// The type of the value
+        value_type: ColumnType,
+    },
+    /// The column has no rows, so it has no percentiles.
+    #[snafu(display("a column without rows has no percentiles"))]
+    EmptyColumn,
+    /// The value is not within the error bound of the requested quantile.
+    #[snafu(display(
+        "{below} of {count} rows are less than the value and {at_most} are at most the value, \
+        which is not within the error bound of the quantile"
+    ))]
+    OutOfBounds {
+        /// The number of rows less than the value
+        below: u64,
+        /// The number of rows at most the value
+        at_most: u64,
+        /// The number of rows of the column
+        count: u64,
+    },
+    /// The percentile proof could not be created.
+    #[snafu(transparent)]
+    Prove {
+        /// The underlying source error
+        source: ProveError,
+    },
+    /// The percentile proof failed to verify.
+    #[snafu(transparent)]
+    Verification {
+        /// The underlying source error
+        source: QueryError,
+    },
+}
+
+/// A fraction between zero and one, such as the quantile `0.95 = 19/20` or the error bound `0.01 = 1/100`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fraction {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Fraction {
+    /// The fraction `0`, e.g. the error bound of an exact percentile
+    pub const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// Create the fraction `numerator / denominator`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the denominator is zero or the fraction is greater than one.
+    pub fn try_new(numerator: u32, denominator: u32) -> Result<Self, PercentileError> {
+        if denominator == 0 || numerator > denominator {
+            return Err(PercentileError::InvalidFraction {
+                numerator,
+                denominator,
+            });
+        }
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+}
+
+/// A proof that a value is a percentile of a committed column, up to an error bound.
+///
+/// The proof shows how many rows of the column are less than the value, and how many are at most the value,
+/// using the comparison expressions and the `SUM` and `COUNT` aggregations of [`GroupByExec`].
+/// It does not sort the column. See [`verify_percentile`] for the guarantee these counts give.
+///
+/// Note: Because the struct is deserialized from untrusted data, it
+/// cannot maintain any invariant on its data members; hence, they are
+/// all public so as to allow for easy manipulation for testing.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PercentileProof<CP: CommitmentEvaluationProof> {
+    /// The claimed percentile
+    pub value: LiteralValue,
+    /// The proof of the counts of the rows less than and at most the value
+    pub query_proof: QueryProof<CP>,
+    /// The counts of the rows less than and at most the value, and of all rows
+    pub result: ProvableQueryResult,
+}
+
+/// Check that `column_type` can be compared in a proof.
+///
+/// Scalars are excluded because the order of field elements is not the order of the values they encode.
+fn check_column_type(column_type: ColumnType) -> Result<(), PercentileError> {
+    if column_type.is_integer()
+        || matches!(
+            column_type,
+            ColumnType::Decimal75(..) | ColumnType::TimestampTZ(..)
+        )
+    {
+        Ok(())
+    } else {
+        Err(PercentileError::UnsupportedType { column_type })
+    }
+}
+
+/// Check that `value` has the type of `column`, so that the two are compared without any conversion.
+fn check_value_type(column: ColumnRef, value: &LiteralValue) -> Result<(), PercentileError> {
+    if *column.column_type() == value.column_type() {
+        Ok(())
+    } else {
+        Err(PercentileError::TypeMismatch {
+            column_type: *column.column_type(),
+            value_type: value.column_type(),
+        })
+    }
+}
+
+/// The plan proving the counts of the rows of `column` less than and at most `value`. It is equivalent to
+/// ```ignore
+///     SELECT SUM(CASE WHEN NOT column >= value THEN 1 ELSE 0 END) AS below,
+///         SUM(CASE WHEN column <= value THEN 1 ELSE 0 END) AS at_most,
+///         COUNT(*) AS total
+///     FROM <table>
+/// ```
+///
+/// # Panics
+///
+/// Panics if an alias is not a valid identifier, which cannot happen.
+fn percentile_plan(
+    column: ColumnRef,
+    value: &LiteralValue,
+) -> Result<DynProofPlan, PercentileError> {
+    check_column_type(*column.column_type())?;
+    check_value_type(column, value)?;
+    let unsupported = |_| PercentileError::UnsupportedType {
+        column_type: *column.column_type(),
+    };
+    let alias = |name: &str| -> Identifier { name.parse().expect("alias is a valid identifier") };
+    let indicator = |condition| {
+        DynProofExpr::try_new_case(
+            condition,
+            DynProofExpr::new_literal(LiteralValue::BigInt(1)),
+            DynProofExpr::new_literal(LiteralValue::BigInt(0)),
+        )
+    };
+    let column_expr = || DynProofExpr::new_column(column);
+    let value_expr = || DynProofExpr::new_literal(value.clone());
+    let below = DynProofExpr::try_new_inequality(column_expr(), value_expr(), false)
+        .and_then(DynProofExpr::try_new_not)
+        .and_then(indicator)
+        .map_err(unsupported)?;
+    let at_most = DynProofExpr::try_new_inequality(column_expr(), value_expr(), true)
+        .and_then(indicator)
+        .map_err(unsupported)?;
+    Ok(DynProofPlan::GroupBy(GroupByExec::new(
+        Vec::new(),
+        vec![
+            AliasedDynProofExpr {
+                expr: below,
+                alias: alias(BELOW_ALIAS),
+            },
+            AliasedDynProofExpr {
+                expr: at_most,
+                alias: alias(AT_MOST_ALIAS),
+            },
+        ],
+        alias(COUNT_ALIAS),
+        TableExpr {
+            table_ref: column.table_ref(),
+        },
+        DynProofExpr::new_literal(LiteralValue::Boolean(true)),
+    )))
+}
+
+/// The value in row `index` of `column`, which has a type supported by [`check_column_type`].
+fn literal_at<S: Scalar>(column: &Column<S>, index: usize) -> LiteralValue {
+    match column {
+        Column::TinyInt(col) => LiteralValue::TinyInt(col[index]),
+        Column::SmallInt(col) => LiteralValue::SmallInt(col[index]),
+        Column::Int(col) => LiteralValue::Int(col[index]),
+        Column::BigInt(col) => LiteralValue::BigInt(col[index]),
+        Column::UInt64(col) => LiteralValue::UInt64(col[index]),
+        Column::Int128(col) => LiteralValue::Int128(col[index]),
+        Column::Decimal75(precision, scale, col) => LiteralValue::Decimal75(
+            *precision,
+            *scale,
+            I256::from_num_bigint(&col[index].into()),
+        ),
+        Column::TimestampTZ(unit, zone, col) => LiteralValue::TimeStampTZ(*unit, *zone, col[index]),
+        Column::Boolean(_) | Column::Scalar(_) | Column::VarChar(_) | Column::FixedBytes(..) => {
+            unreachable!("column type is checked to be ordered")
+        }
+    }
+}
+
+/// Prove that `value` is a percentile of `column`.
+///
+/// `value` need not be an exact percentile, e.g. it may come from a sketch of the column.
+/// The proof only attests how many rows are less than and at most `value`,
+/// and [`verify_percentile`] decides whether that is close enough to the quantile it is checked against.
+///
+/// # Errors
+/// Fails fast if the column does not have an ordered type, or the value does not have the type of the column.
+/// Also fails if the column does not have the row count of its table.
+pub fn prove_percentile_of_value<CP: CommitmentEvaluationProof>(
+    column: ColumnRef,
+    value: LiteralValue,
+    accessor: &impl DataAccessor<CP::Scalar>,
+    setup: &CP::ProverPublicSetup<'_>,
+) -> Result<PercentileProof<CP>, PercentileError> {
+    let plan = percentile_plan(column, &value)?;
+    let (query_proof, result) = QueryProof::try_new(&plan, accessor, setup)?;
+    Ok(PercentileProof {
+        value,
+        query_proof,
+        result,
+    })
+}
+
+/// Prove the exact `quantile` of `column`.
+///
+/// The exact percentile is the `k`-th smallest value of the `n` rows for `k = max(1, ceil(quantile * n))`,
+/// which is known as the nearest-rank method. Its proof verifies with any error bound, including [`Fraction::ZERO`].
+///
+/// # Errors
+/// Fails fast if the column does not have an ordered type, or has no rows.
+/// Also fails if the column does not have the row count of its table.
+///
+/// # Panics
+///
+/// Panics if the rank of the percentile is not a row of the column, which cannot happen.
+pub fn prove_percentile<CP: CommitmentEvaluationProof>(
+    column: ColumnRef,
+    quantile: Fraction,
+    accessor: &impl DataAccessor<CP::Scalar>,
+    setup: &CP::ProverPublicSetup<'_>,
+) -> Result<PercentileProof<CP>, PercentileError> {
+    check_column_type(*column.column_type())?;
+    let data = accessor.get_column(column);
+    let count = data.len();
+    if count == 0 {
+        return Err(PercentileError::EmptyColumn);
+    }
+    // ceil(quantile * count) in 1..=count, so the index fits in usize
+    let rank = (count as u128 * u128::from(quantile.numerator))
+        .div_ceil(u128::from(quantile.denominator))
+        .max(1);
+    let index = usize::try_from(rank - 1).expect("rank is at most the row count");
+    let mut indexes: Vec<usize> = (0..count).collect();
+    let columns = [data];
+    let (_, &mut nth, _) =
+        indexes.select_nth_unstable_by(index, |&i, &j| compare_indexes_by_columns(&columns, i, j));
+    prove_percentile_of_value(column, literal_at(&columns[0], nth), accessor, setup)
+}
+
+/// Whether a value that `below` of `count` rows are less than and `at_most` rows are at most
+/// is within `max_error` of the `quantile`.
+fn is_within_bound(
+    below: u64,
+    at_most: u64,
+    count: u64,
+    quantile: Fraction,
+    max_error: Fraction,
+) -> bool {
+    // Everything is scaled by the product of the denominators, so it is compared as integers.
+    let scale = u128::from(quantile.denominator) * u128::from(max_error.denominator);
+    let target = u128::from(quantile.numerator) * u128::from(max_error.denominator);
+    let error = u128::from(max_error.numerator) * u128::from(quantile.denominator);
+    let count = u128::from(count);
+    // below <= (quantile + max_error) * count
+    u128::from(below) * scale <= (target + error).saturating_mul(count)
+        // at_most >= (quantile - max_error) * count
+        && u128::from(at_most) * scale >= target.saturating_sub(error) * count
+}
+
+/// Verify a proof from [`prove_percentile`] or [`prove_percentile_of_value`] that its value is the `quantile`
+/// of `column`, up to `max_error`. Returns the verified value.
+///
+/// Let `n` be the number of rows of the column. The value is accepted if
+/// - at most `(quantile + max_error) * n` rows are less than it, and
+/// - at least `(quantile - max_error) * n` rows are at most it.
+///
+/// In other words, if the column were sorted, the value would be in a position within `max_error * n`
+/// of the position `quantile * n`. This is the guarantee an accepted value has: it is a true percentile
+/// for some quantile within `max_error` of the requested one. It says nothing about how far the value is from
+/// the exact percentile, which depends on how the values of the column are distributed.
+///
+/// # Errors
+/// Fails fast if the column does not have an ordered type, or the value does not have the type of the column.
+/// Otherwise fails if the proof does not verify against the column committed to in `accessor`,
+/// or the value is not within the error bound.
+///
+/// # Panics
+///
+/// Panics if a verified result does not hold the counts of the plan, which cannot happen.
+pub fn verify_percentile<CP: CommitmentEvaluationProof>(
+    proof: &PercentileProof<CP>,
+    column: ColumnRef,
+    quantile: Fraction,
+    max_error: Fraction,
+    accessor: &impl CommitmentAccessor<CP::Commitment>,
+    setup: &CP::VerifierPublicSetup<'_>,
+) -> Result<LiteralValue, PercentileError> {
+    let plan = percentile_plan(column, &proof.value)?;
+    let table = proof
+        .query_proof
+        .verify(&plan, accessor, &proof.result, setup)?
+        .table;
+    let read_count = |name: &str| {
+        let name: Identifier = name.parse().expect("alias is a valid identifier");
+        match table.inner_table().get(&name) {
+            Some(OwnedColumn::BigInt(col)) => col.first().map_or(0, |&value| {
+                u64::try_from(value).expect("a verified count is not negative")
+            }),
+            _ => panic!("verified result has the counts of the plan"),
+        }
+    };
+    let (below, at_most, count) = (
+        read_count(BELOW_ALIAS),
+        read_count(AT_MOST_ALIAS),
+        read_count(COUNT_ALIAS),
+    );
+    if count == 0 {
+        return Err(PercentileError::EmptyColumn);
+    }
+    if !is_within_bound(below, at_most, count, quantile, max_error) {
+        return Err(PercentileError::OutOfBounds {
+            below,
+            at_most,
+            count,
+        });
+    }
+    Ok(proof.value.clone())
+}
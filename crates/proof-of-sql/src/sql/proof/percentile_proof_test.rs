@@ -0,0 +1,277 @@
+use super::{
+    prove_percentile, prove_percentile_of_value, verify_percentile, Fraction, PercentileError,
+};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::*, ColumnRef, ColumnType, LiteralValue, OwnedTableTestAccessor,
+            TableRef,
+        },
+        math::{decimal::Precision, i256::I256},
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup, ProverSetup,
+        PublicParameters, VerifierSetup,
+    },
+};
+
+/// The multiples of 5 from 5 to 100, in no particular order
+const VALUES: [i64; 20] = [
+    35, 90, 5, 60, 100, 15, 75, 40, 95, 20, 55, 10, 85, 30, 65, 80, 25, 50, 70, 45,
+];
+
+fn column_ref(t: TableRef, name: &str, column_type: ColumnType) -> ColumnRef {
+    ColumnRef::new(t, name.parse().unwrap(), column_type)
+}
+
+fn fraction(numerator: u32, denominator: u32) -> Fraction {
+    Fraction::try_new(numerator, denominator).unwrap()
+}
+
+#[test]
+fn we_can_prove_the_exact_percentiles_of_a_column() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", VALUES), decimal75("d", 10, 2, VALUES)]),
+        0,
+        setup,
+    );
+    let a = column_ref(t, "a", ColumnType::BigInt);
+
+    // The nearest-rank percentile of 20 values is the ceil(20 * quantile)-th smallest one.
+    for (quantile, expected) in [
+        (fraction(0, 1), 5),
+        (fraction(1, 2), 50),
+        (fraction(19, 20), 95),
+        (fraction(96, 100), 100),
+        (fraction(1, 1), 100),
+    ] {
+        let proof =
+            prove_percentile::<DoryEvaluationProof>(a, quantile, &accessor, &setup).unwrap();
+        assert_eq!(proof.value, LiteralValue::BigInt(expected));
+        for max_error in [Fraction::ZERO, fraction(1, 100)] {
+            assert_eq!(
+                verify_percentile(&proof, a, quantile, max_error, &accessor, &verifier_setup)
+                    .unwrap(),
+                LiteralValue::BigInt(expected)
+            );
+        }
+    }
+
+    let d = column_ref(
+        t,
+        "d",
+        ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+    );
+    let median = fraction(1, 2);
+    let proof = prove_percentile::<DoryEvaluationProof>(d, median, &accessor, &setup).unwrap();
+    let expected = LiteralValue::Decimal75(Precision::new(10).unwrap(), 2, I256::from(50));
+    assert_eq!(proof.value, expected);
+    assert_eq!(
+        verify_percentile(
+            &proof,
+            d,
+            median,
+            Fraction::ZERO,
+            &accessor,
+            &verifier_setup
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn we_can_only_verify_an_approximate_percentile_within_its_error_bound() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", VALUES)]),
+        0,
+        setup,
+    );
+    let a = column_ref(t, "a", ColumnType::BigInt);
+    let p95 = fraction(19, 20);
+
+    // 85 is the 17th of 20 values, so it is the 95th percentile up to an error of 2 rows, or 10%.
+    let proof = prove_percentile_of_value::<DoryEvaluationProof>(
+        a,
+        LiteralValue::BigInt(85),
+        &accessor,
+        &setup,
+    )
+    .unwrap();
+    assert_eq!(
+        verify_percentile(&proof, a, p95, fraction(1, 10), &accessor, &verifier_setup).unwrap(),
+        LiteralValue::BigInt(85)
+    );
+    for max_error in [Fraction::ZERO, fraction(1, 20)] {
+        assert!(matches!(
+            verify_percentile(&proof, a, p95, max_error, &accessor, &verifier_setup),
+            Err(PercentileError::OutOfBounds {
+                below: 16,
+                at_most: 17,
+                count: 20
+            })
+        ));
+    }
+
+    // A value that is not in the column is a percentile as well.
+    let proof = prove_percentile_of_value::<DoryEvaluationProof>(
+        a,
+        LiteralValue::BigInt(97),
+        &accessor,
+        &setup,
+    )
+    .unwrap();
+    assert!(verify_percentile(&proof, a, p95, Fraction::ZERO, &accessor, &verifier_setup).is_ok());
+
+    // A wildly wrong claim is rejected.
+    let proof = prove_percentile_of_value::<DoryEvaluationProof>(
+        a,
+        LiteralValue::BigInt(10),
+        &accessor,
+        &setup,
+    )
+    .unwrap();
+    let err =
+        verify_percentile(&proof, a, p95, fraction(1, 10), &accessor, &verifier_setup).unwrap_err();
+    assert!(matches!(
+        err,
+        PercentileError::OutOfBounds {
+            below: 1,
+            at_most: 2,
+            count: 20
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "1 of 20 rows are less than the value and 2 are at most the value, \
+        which is not within the error bound of the quantile"
+    );
+}
+
+#[test]
+fn we_cannot_verify_a_percentile_proof_for_another_value_or_column() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", VALUES),
+            bigint("b", VALUES.map(|value| value - 50)),
+        ]),
+        0,
+        setup,
+    );
+    let a = column_ref(t, "a", ColumnType::BigInt);
+    let p95 = fraction(19, 20);
+    let mut proof = prove_percentile::<DoryEvaluationProof>(a, p95, &accessor, &setup).unwrap();
+
+    // The counts are only proven for the value the proof was created for.
+    proof.value = LiteralValue::BigInt(10);
+    assert!(matches!(
+        verify_percentile(&proof, a, p95, fraction(1, 1), &accessor, &verifier_setup),
+        Err(PercentileError::Verification { .. })
+    ));
+    proof.value = LiteralValue::BigInt(95);
+    assert!(matches!(
+        verify_percentile(
+            &proof,
+            column_ref(t, "b", ColumnType::BigInt),
+            p95,
+            fraction(1, 1),
+            &accessor,
+            &verifier_setup
+        ),
+        Err(PercentileError::Verification { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_prove_a_percentile_of_an_unordered_or_empty_column() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1_i64, 2]), varchar("b", ["x", "y"])]),
+        0,
+        setup,
+    );
+    let median = fraction(1, 2);
+
+    assert!(matches!(
+        prove_percentile::<DoryEvaluationProof>(
+            column_ref(t, "b", ColumnType::VarChar),
+            median,
+            &accessor,
+            &setup
+        ),
+        Err(PercentileError::UnsupportedType {
+            column_type: ColumnType::VarChar
+        })
+    ));
+    assert!(matches!(
+        prove_percentile_of_value::<DoryEvaluationProof>(
+            column_ref(t, "a", ColumnType::BigInt),
+            LiteralValue::Int(1),
+            &accessor,
+            &setup
+        ),
+        Err(PercentileError::TypeMismatch {
+            column_type: ColumnType::BigInt,
+            value_type: ColumnType::Int
+        })
+    ));
+
+    let u: TableRef = "sxt.u".parse().unwrap();
+    let empty_accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        u,
+        owned_table([bigint("a", [0_i64; 0])]),
+        0,
+        setup,
+    );
+    assert!(matches!(
+        prove_percentile::<DoryEvaluationProof>(
+            column_ref(u, "a", ColumnType::BigInt),
+            median,
+            &empty_accessor,
+            &setup
+        ),
+        Err(PercentileError::EmptyColumn)
+    ));
+}
+
+#[test]
+fn we_cannot_create_a_fraction_greater_than_one_or_with_a_zero_denominator() {
+    assert_eq!(Fraction::try_new(0, 1).unwrap(), Fraction::ZERO);
+    for (numerator, denominator) in [(3, 2), (0, 0), (1, 0)] {
+        let err = Fraction::try_new(numerator, denominator).unwrap_err();
+        assert!(matches!(
+            err,
+            PercentileError::InvalidFraction { numerator: n, denominator: d }
+                if n == numerator && d == denominator
+        ));
+    }
+    assert_eq!(
+        Fraction::try_new(3, 2).unwrap_err().to_string(),
+        "3/2 is not a fraction between 0 and 1"
+    );
+}
@@ -0,0 +1,220 @@
+use super::{
+    serialized_size, EnvelopePayload, OLDEST_SUPPORTED_PROOF_FORMAT_VERSION, PROOF_FORMAT_VERSION,
+};
+use crate::base::encode::{VarInt, ZeroCopyReader};
+use alloc::vec::Vec;
+use serde::Serialize;
+use snafu::Snafu;
+
+/// The magic number at the start of every proof archive
+pub const PROOF_ARCHIVE_MAGIC: [u8; 4] = *b"PoSA";
+
+/// The length of the archive header: the magic number and the version (`u16`)
+const HEADER_LENGTH: usize = PROOF_ARCHIVE_MAGIC.len() + 2;
+
+/// The length of the archive trailer: the position of the index (`u64`)
+const TRAILER_LENGTH: usize = 8;
+
+/// Append `value` to `bytes` as a varint
+fn extend_varint(bytes: &mut Vec<u8>, value: usize) {
+    let start = bytes.len();
+    bytes.resize(start + value.required_space(), 0);
+    value.encode_var(&mut bytes[start..]);
+}
+
+/// Errors that can occur when reading a proof archive
+#[derive(Debug, Snafu)]
+pub enum ProofArchiveError {
+    /// The bytes are too short to contain an archive header and trailer.
+    #[snafu(display(
+        "proof archive is truncated: expected at least {} bytes, found {length}",
+        HEADER_LENGTH + TRAILER_LENGTH
+    ))]
+    Truncated {
+        /// The number of bytes found
+        length: usize,
+    },
+    /// The bytes do not start with [`PROOF_ARCHIVE_MAGIC`], so they are not a proof archive.
+    #[snafu(display("invalid proof archive magic number: {found:?}"))]
+    InvalidMagic {
+        /// The first bytes found
+        found: [u8; 4],
+    },
+    /// The archive was written with a format version this library cannot read.
+    #[snafu(display(
+        "unsupported proof format version {found}, supported versions are {oldest_supported} to {supported}"
+    ))]
+    UnsupportedVersion {
+        /// The version found in the archive
+        found: u16,
+        /// The oldest version this library supports
+        oldest_supported: u16,
+        /// The newest version this library supports, which is the version it writes
+        supported: u16,
+    },
+    /// The index at the end of the archive does not point to complete proofs.
+    #[snafu(display("proof archive index is corrupt"))]
+    InvalidIndex,
+    /// The archive does not hold a proof with the requested id.
+    #[snafu(display("proof archive has {len} proofs, so there is no proof {id}"))]
+    ProofNotFound {
+        /// The requested proof id
+        id: usize,
+        /// The number of proofs in the archive
+        len: usize,
+    },
+    /// A proof could not be deserialized.
+    #[snafu(display("failed to deserialize proof {id} of the archive: {error}"))]
+    Payload {
+        /// The id of the proof
+        id: usize,
+        /// The underlying deserialization error
+        error: postcard::Error,
+    },
+}
+
+/// Many proofs stored in one buffer, each of which can be read without reading the ones before it.
+///
+/// An archive consists of
+/// 1. a header of [`PROOF_ARCHIVE_MAGIC`] and [`PROOF_FORMAT_VERSION`] as a little-endian `u16`,
+/// 2. every proof as a length-delimited field, as read by [`ZeroCopyReader::read_length_delimited`],
+///    whose payload is the postcard serialization of the proof,
+/// 3. an index of the number of proofs followed by the position of every proof in the archive, all as `usize` varints,
+/// 4. a trailer of the position of the index as a little-endian `u64`.
+///
+/// The id of a proof is its position in the slice passed to [`ProofArchive::to_bytes`].
+/// Reading an archive parses its header and index and checks the length of every proof,
+/// but a proof is only deserialized when it is requested. The proofs are borrowed from the archive bytes.
+#[derive(Debug, Clone)]
+pub struct ProofArchive<'a> {
+    bytes: &'a [u8],
+    version: u16,
+    positions: Vec<usize>,
+}
+
+impl<'a> ProofArchive<'a> {
+    /// Serialize `proofs` into a proof archive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a proof cannot be serialized, which should not happen for valid proofs.
+    #[must_use]
+    pub fn to_bytes(proofs: &[impl Serialize]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&PROOF_ARCHIVE_MAGIC);
+        bytes.extend_from_slice(&PROOF_FORMAT_VERSION.to_le_bytes());
+        let mut positions = Vec::with_capacity(proofs.len());
+        for proof in proofs {
+            positions.push(bytes.len());
+            extend_varint(&mut bytes, serialized_size(proof));
+            bytes = postcard::to_extend(proof, bytes).expect("proof should serialize");
+        }
+        let index_position = bytes.len();
+        extend_varint(&mut bytes, positions.len());
+        for position in positions {
+            extend_varint(&mut bytes, position);
+        }
+        bytes.extend_from_slice(&(index_position as u64).to_le_bytes());
+        bytes
+    }
+
+    /// Read the header and index of a proof archive that was serialized with [`ProofArchive::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the header is invalid, or the index does not point to complete proofs.
+    pub fn try_new(bytes: &'a [u8]) -> Result<Self, ProofArchiveError> {
+        let truncated = || ProofArchiveError::Truncated {
+            length: bytes.len(),
+        };
+        let (&magic, rest) = bytes.split_first_chunk().ok_or_else(truncated)?;
+        let (&version, rest) = rest.split_first_chunk().ok_or_else(truncated)?;
+        let (_, &index_position) = rest.split_last_chunk().ok_or_else(truncated)?;
+
+        if magic != PROOF_ARCHIVE_MAGIC {
+            return Err(ProofArchiveError::InvalidMagic { found: magic });
+        }
+        let version = u16::from_le_bytes(version);
+        if !(OLDEST_SUPPORTED_PROOF_FORMAT_VERSION..=PROOF_FORMAT_VERSION).contains(&version) {
+            return Err(ProofArchiveError::UnsupportedVersion {
+                found: version,
+                oldest_supported: OLDEST_SUPPORTED_PROOF_FORMAT_VERSION,
+                supported: PROOF_FORMAT_VERSION,
+            });
+        }
+
+        // the index lies between the proofs and the trailer
+        let proofs_end = usize::try_from(u64::from_le_bytes(index_position))
+            .ok()
+            .filter(|position| (HEADER_LENGTH..=bytes.len() - TRAILER_LENGTH).contains(position))
+            .ok_or(ProofArchiveError::InvalidIndex)?;
+        let mut index = ZeroCopyReader::new(&bytes[proofs_end..bytes.len() - TRAILER_LENGTH]);
+        let len: usize = index.read_varint().ok_or(ProofArchiveError::InvalidIndex)?;
+        let positions = (0..len)
+            .map(|_| index.read_varint())
+            .collect::<Option<Vec<usize>>>()
+            .ok_or(ProofArchiveError::InvalidIndex)?;
+        if !index.is_empty() {
+            return Err(ProofArchiveError::InvalidIndex);
+        }
+
+        // every proof must be complete and lie between the header and the index
+        for &position in &positions {
+            let mut reader = ZeroCopyReader::new(
+                bytes
+                    .get(position..proofs_end)
+                    .filter(|_| position >= HEADER_LENGTH)
+                    .ok_or(ProofArchiveError::InvalidIndex)?,
+            );
+            reader
+                .read_length_delimited()
+                .ok_or(ProofArchiveError::InvalidIndex)?;
+        }
+        Ok(Self {
+            bytes,
+            version,
+            positions,
+        })
+    }
+
+    /// The number of proofs in the archive
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Whether the archive holds no proofs
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// The proof format version the archive was written with
+    #[must_use]
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// The serialized proof with the given id, borrowed from the archive bytes
+    #[must_use]
+    pub fn get_bytes(&self, id: usize) -> Option<&'a [u8]> {
+        let &position = self.positions.get(id)?;
+        ZeroCopyReader::new(&self.bytes[position..]).read_length_delimited()
+    }
+
+    /// Deserialize the proof with the given id, without reading the proofs before it.
+    ///
+    /// Proofs of older supported versions are migrated by [`EnvelopePayload::from_payload`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if there is no proof with the given id, or it cannot be deserialized.
+    pub fn get<T: EnvelopePayload>(&self, id: usize) -> Result<T, ProofArchiveError> {
+        let payload = self.get_bytes(id).ok_or(ProofArchiveError::ProofNotFound {
+            id,
+            len: self.len(),
+        })?;
+        T::from_payload(self.version, payload)
+            .map_err(|error| ProofArchiveError::Payload { id, error })
+    }
+}
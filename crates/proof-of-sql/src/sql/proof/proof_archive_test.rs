@@ -0,0 +1,178 @@
+use super::{
+    ProofArchive, ProofArchiveError, VerifiableQueryResult, OLDEST_SUPPORTED_PROOF_FORMAT_VERSION,
+    PROOF_ARCHIVE_MAGIC, PROOF_FORMAT_VERSION,
+};
+use crate::{
+    base::database::{owned_table_utility::*, OwnedTableTestAccessor},
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup, ProverSetup,
+        PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use alloc::vec::Vec;
+
+#[test]
+fn we_can_read_back_every_proof_of_an_archive() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3, 4]), bigint("b", [1, 0, 1, 1])]),
+        0,
+        dory_prover_setup,
+    );
+    let exprs: Vec<_> = [1, 0, 7]
+        .into_iter()
+        .map(|b| {
+            filter(
+                cols_expr_plan(t, &["a"], &accessor),
+                tab(t),
+                equal(column(t, "b", &accessor), const_bigint(b)),
+            )
+        })
+        .collect();
+    let results: Vec<_> = exprs
+        .iter()
+        .map(|expr| {
+            VerifiableQueryResult::<DoryEvaluationProof>::new(expr, &accessor, &dory_prover_setup)
+        })
+        .collect();
+
+    let bytes = ProofArchive::to_bytes(&results);
+    assert_eq!(bytes[..4], PROOF_ARCHIVE_MAGIC);
+    assert_eq!(bytes[4..6], PROOF_FORMAT_VERSION.to_le_bytes());
+    let archive = ProofArchive::try_new(&bytes).unwrap();
+    assert_eq!(archive.len(), 3);
+    assert_eq!(archive.version(), PROOF_FORMAT_VERSION);
+
+    let expected_tables = [
+        owned_table([bigint("a", [1, 3, 4])]),
+        owned_table([bigint("a", [2])]),
+        owned_table([bigint("a", [0; 0])]),
+    ];
+    for (id, (expr, expected)) in exprs.iter().zip(expected_tables).enumerate() {
+        assert_eq!(
+            archive.get_bytes(id).unwrap(),
+            postcard::to_allocvec(&results[id]).unwrap()
+        );
+        let result: VerifiableQueryResult<DoryEvaluationProof> = archive.get(id).unwrap();
+        let table = result
+            .verify(expr, &accessor, &dory_verifier_setup)
+            .unwrap()
+            .table;
+        assert_eq!(table, expected);
+    }
+}
+
+#[test]
+fn we_can_read_the_third_proof_of_a_five_proof_archive() {
+    let proofs = [10_u64, 2000, 300_000, 4, u64::MAX];
+    let bytes = ProofArchive::to_bytes(&proofs);
+    let archive = ProofArchive::try_new(&bytes).unwrap();
+    assert_eq!(archive.len(), 5);
+    assert_eq!(archive.get::<u64>(2).unwrap(), 300_000);
+
+    // The proofs before the third one are not deserialized, so corrupting them does not affect it.
+    // The first proof starts after the 6 byte header and its 1 byte length.
+    let mut corrupted = bytes.clone();
+    corrupted[7] = 0xff;
+    let archive = ProofArchive::try_new(&corrupted).unwrap();
+    assert_eq!(archive.get::<u64>(2).unwrap(), 300_000);
+    assert!(matches!(
+        archive.get::<u64>(0),
+        Err(ProofArchiveError::Payload { id: 0, .. })
+    ));
+
+    for (id, proof) in proofs.into_iter().enumerate() {
+        assert_eq!(
+            ProofArchive::try_new(&bytes)
+                .unwrap()
+                .get::<u64>(id)
+                .unwrap(),
+            proof
+        );
+    }
+    assert!(matches!(
+        ProofArchive::try_new(&bytes).unwrap().get::<u64>(5),
+        Err(ProofArchiveError::ProofNotFound { id: 5, len: 5 })
+    ));
+}
+
+#[test]
+fn we_can_write_and_read_an_empty_archive() {
+    let bytes = ProofArchive::to_bytes(&[] as &[u64]);
+    let archive = ProofArchive::try_new(&bytes).unwrap();
+    assert!(archive.is_empty());
+    assert!(archive.get_bytes(0).is_none());
+}
+
+#[test]
+fn we_cannot_read_an_archive_with_an_invalid_header() {
+    let bytes = ProofArchive::to_bytes(&[1_u64, 2]);
+    assert!(matches!(
+        ProofArchive::try_new(&bytes[..13]),
+        Err(ProofArchiveError::Truncated { length: 13 })
+    ));
+
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] ^= 1;
+    assert!(matches!(
+        ProofArchive::try_new(&bad_magic),
+        Err(ProofArchiveError::InvalidMagic { found }) if found[1..] == PROOF_ARCHIVE_MAGIC[1..]
+    ));
+
+    for version in [
+        OLDEST_SUPPORTED_PROOF_FORMAT_VERSION - 1,
+        PROOF_FORMAT_VERSION + 1,
+    ] {
+        let mut bad_version = bytes.clone();
+        bad_version[4..6].copy_from_slice(&version.to_le_bytes());
+        assert!(matches!(
+            ProofArchive::try_new(&bad_version),
+            Err(ProofArchiveError::UnsupportedVersion { found, .. }) if found == version
+        ));
+    }
+}
+
+#[test]
+fn we_cannot_read_an_archive_with_a_corrupt_index() {
+    let bytes = ProofArchive::to_bytes(&[1_u64, 2]);
+    let len = bytes.len();
+
+    // The index position is past the end of the archive.
+    let mut corrupted = bytes.clone();
+    corrupted[len - 8..].copy_from_slice(&(len as u64).to_le_bytes());
+    assert!(matches!(
+        ProofArchive::try_new(&corrupted),
+        Err(ProofArchiveError::InvalidIndex)
+    ));
+
+    // A proof position points into the header.
+    let index_position = len - 8 - 3;
+    let mut corrupted = bytes.clone();
+    corrupted[index_position + 1] = 0;
+    assert!(matches!(
+        ProofArchive::try_new(&corrupted),
+        Err(ProofArchiveError::InvalidIndex)
+    ));
+
+    // The index claims more proofs than it has positions for.
+    let mut corrupted = bytes.clone();
+    corrupted[index_position] = 3;
+    assert!(matches!(
+        ProofArchive::try_new(&corrupted),
+        Err(ProofArchiveError::InvalidIndex)
+    ));
+
+    // A truncated archive loses its trailer.
+    assert!(matches!(
+        ProofArchive::try_new(&bytes[..len - 1]),
+        Err(ProofArchiveError::InvalidIndex)
+    ));
+}
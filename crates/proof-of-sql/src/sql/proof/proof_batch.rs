@@ -0,0 +1,119 @@
+use super::{ProofPlan, ProvableQueryResult, ProveError, QueryData, QueryProof, VerificationError};
+use crate::base::{
+    commitment::{Commitment, CommitmentEvaluationProof, CommittableColumn},
+    database::{ColumnRef, CommitmentSource, DataAccessor, MetadataAccessor, TableRef},
+    map::{IndexMap, IndexSet},
+};
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// Proofs of several queries, along with the commitments to the columns they read.
+///
+/// Every column read by any of the queries is committed to once, however many queries read it,
+/// rather than once per query. A query that fails to be proven does not fail the batch:
+/// its error is kept in place of its proof. The proofs are verified with [`ProofBatch::verify`].
+pub struct ProofBatch<CP: CommitmentEvaluationProof> {
+    /// The commitment to every column read by the queries
+    pub commitments: IndexMap<ColumnRef, CP::Commitment>,
+    /// The proof and result of each query, or the error that kept it from being proven, in the order of the queries
+    pub proofs: Vec<Result<(QueryProof<CP>, ProvableQueryResult), ProveError>>,
+}
+
+impl<CP: CommitmentEvaluationProof> ProofBatch<CP> {
+    /// Prove every query in `exprs` and commit to the columns they read.
+    ///
+    /// The columns are committed to once up front, and then each query is proven on its own.
+    /// Each proof is identical to the one [`QueryProof::try_new`] creates, and so is the error of a query that
+    /// fails to be proven, e.g. because the columns it references do not have the row counts of their tables.
+    #[must_use]
+    pub fn new(
+        exprs: &[impl ProofPlan + Serialize],
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> Self {
+        let columns: IndexSet<ColumnRef> = exprs
+            .iter()
+            .flat_map(ProofPlan::get_column_references)
+            .collect();
+        let tables: IndexSet<TableRef> = columns.iter().map(ColumnRef::table_ref).collect();
+        let mut commitments = IndexMap::default();
+        for table_ref in tables {
+            let table_columns: Vec<ColumnRef> = columns
+                .iter()
+                .filter(|column| column.table_ref() == table_ref)
+                .copied()
+                .collect();
+            let data: Vec<_> = table_columns
+                .iter()
+                .map(|&column| accessor.get_column(column))
+                .collect();
+            let table_commitments = CP::Commitment::compute_commitments(
+                &data.iter().map(CommittableColumn::from).collect::<Vec<_>>(),
+                accessor.get_offset(table_ref),
+                setup,
+            );
+            commitments.extend(table_columns.into_iter().zip(table_commitments));
+        }
+        let proofs = exprs
+            .iter()
+            .map(|expr| QueryProof::try_new(expr, accessor, setup))
+            .collect();
+        Self {
+            commitments,
+            proofs,
+        }
+    }
+
+    /// The queries that failed to be proven, as their positions in the batch along with their errors
+    pub fn errors(&self) -> impl Iterator<Item = (usize, &ProveError)> {
+        self.proofs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, proof)| proof.as_ref().err().map(|err| (index, err)))
+    }
+
+    /// Verify the proof of every query in `exprs` against the commitments in `commitment_source`.
+    ///
+    /// `exprs` must be the queries the batch was created from, in the same order.
+    /// Returns the verified result of each query in that order, or why it did not verify.
+    /// A query that failed to be proven does not verify, with [`VerificationError::QueryNotProven`].
+    /// The proofs are only as trustworthy as the commitments they are verified against, so the
+    /// [`ProofBatch::commitments`] created by the prover should only be used once they have been published to,
+    /// or checked against, a source the verifier trusts.
+    ///
+    /// # Errors
+    ///
+    /// Fails as a whole only if the number of queries is not the number of proofs.
+    #[allow(clippy::type_complexity)]
+    pub fn verify(
+        &self,
+        exprs: &[impl ProofPlan + Serialize],
+        accessor: &impl MetadataAccessor,
+        commitment_source: &impl CommitmentSource<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<Vec<Result<QueryData<CP::Scalar>, VerificationError>>, VerificationError> {
+        if exprs.len() != self.proofs.len() {
+            return Err(VerificationError::ProofBatchSizeMismatch {
+                num_queries: exprs.len(),
+                num_proofs: self.proofs.len(),
+            });
+        }
+        Ok(exprs
+            .iter()
+            .zip(&self.proofs)
+            .enumerate()
+            .map(|(index, (expr, proof))| {
+                let (proof, result) = proof
+                    .as_ref()
+                    .map_err(|_| VerificationError::QueryNotProven { index })?;
+                proof.verify_with_commitment_source(
+                    expr,
+                    accessor,
+                    commitment_source,
+                    result,
+                    setup,
+                )
+            })
+            .collect())
+    }
+}
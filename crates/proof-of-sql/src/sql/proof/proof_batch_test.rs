@@ -0,0 +1,247 @@
+use super::{ProofBatch, ProveError, QueryProof, VerificationError};
+use crate::{
+    base::database::{
+        owned_table_utility::*, ColumnRef, ColumnType, CommitmentAccessor, OwnedTable,
+        OwnedTableTestAccessor, TableRef, TestAccessor,
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        proof_exprs::test_utility::*,
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+
+#[test]
+fn we_can_prove_a_batch_of_queries_that_share_column_commitments() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let u: TableRef = "sxt.u".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1_i64, 2, 3, 2]),
+            bigint("b", [5_i64, 6, 7, 8]),
+            bigint("c", [0_i64, 1, 0, 1]),
+            bigint("d", [9_i64, 9, 9, 9]),
+        ]),
+        0,
+        setup,
+    );
+    accessor.add_table(u, owned_table([bigint("e", [4_i64, 5])]), 0);
+    let queries: Vec<DynProofPlan> = vec![
+        // select b from sxt.t where a = 2
+        filter(
+            cols_expr_plan(t, &["b"], &accessor),
+            tab(t),
+            equal(column(t, "a", &accessor), const_bigint(2)),
+        ),
+        // select a, c from sxt.t where a >= 2
+        filter(
+            cols_expr_plan(t, &["a", "c"], &accessor),
+            tab(t),
+            gte(column(t, "a", &accessor), const_bigint(2)),
+        ),
+        // select e from sxt.u where e = 5
+        filter(
+            cols_expr_plan(u, &["e"], &accessor),
+            tab(u),
+            equal(column(u, "e", &accessor), const_bigint(5)),
+        ),
+    ];
+    let batch = ProofBatch::<DoryEvaluationProof>::new(&queries, &accessor, &setup);
+
+    // a is read by two queries but only committed to once, and d is not read at all.
+    let columns: Vec<ColumnRef> = [(t, "a"), (t, "b"), (t, "c"), (u, "e")]
+        .into_iter()
+        .map(|(table, name)| ColumnRef::new(table, name.parse().unwrap(), ColumnType::BigInt))
+        .collect();
+    assert_eq!(batch.commitments.len(), columns.len());
+    for column in columns {
+        assert_eq!(batch.commitments[&column], accessor.get_commitment(column));
+    }
+
+    // Each proof is the proof of its query on its own.
+    assert_eq!(batch.proofs.len(), queries.len());
+    for (query, proof) in queries.iter().zip(&batch.proofs) {
+        let (proof, result) = proof.as_ref().unwrap();
+        let (expected_proof, expected_result) =
+            QueryProof::<DoryEvaluationProof>::try_new(query, &accessor, &setup).unwrap();
+        assert_eq!(
+            postcard::to_allocvec(proof).unwrap(),
+            postcard::to_allocvec(&expected_proof).unwrap()
+        );
+        assert_eq!(
+            postcard::to_allocvec(result).unwrap(),
+            postcard::to_allocvec(&expected_result).unwrap()
+        );
+    }
+
+    let verified = batch
+        .verify(&queries, &accessor, &batch.commitments, &verifier_setup)
+        .unwrap();
+    let expected: [OwnedTable<DoryScalar>; 3] = [
+        owned_table([bigint("b", [6_i64, 8])]),
+        owned_table([bigint("a", [2_i64, 3, 2]), bigint("c", [1_i64, 0, 1])]),
+        owned_table([bigint("e", [5_i64])]),
+    ];
+    assert_eq!(verified.len(), expected.len());
+    for (verified, expected) in verified.into_iter().zip(expected) {
+        assert_eq!(verified.unwrap().table, expected);
+    }
+}
+
+#[test]
+fn we_cannot_verify_a_batch_against_other_queries_or_commitments() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1_i64, 2, 3]), bigint("b", [4_i64, 5, 6])]),
+        0,
+        setup,
+    );
+    let queries: Vec<DynProofPlan> = ["a", "b"]
+        .into_iter()
+        .map(|name| {
+            filter(
+                cols_expr_plan(t, &[name], &accessor),
+                tab(t),
+                gte(column(t, name, &accessor), const_bigint(2)),
+            )
+        })
+        .collect();
+    let batch = ProofBatch::<DoryEvaluationProof>::new(&queries, &accessor, &setup);
+    assert!(batch
+        .verify(&queries, &accessor, &batch.commitments, &verifier_setup)
+        .unwrap()
+        .iter()
+        .all(Result::is_ok));
+
+    let Err(err) = batch.verify(
+        &queries[..1],
+        &accessor,
+        &batch.commitments,
+        &verifier_setup,
+    ) else {
+        panic!("a batch of two proofs should not verify one query");
+    };
+    assert!(matches!(
+        err,
+        VerificationError::ProofBatchSizeMismatch {
+            num_queries: 1,
+            num_proofs: 2
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "a batch of 2 proofs cannot verify 1 queries"
+    );
+
+    let mut swapped = queries;
+    swapped.swap(0, 1);
+    assert!(batch
+        .verify(&swapped, &accessor, &batch.commitments, &verifier_setup)
+        .unwrap()
+        .iter()
+        .all(Result::is_err));
+    swapped.swap(0, 1);
+    let queries = swapped;
+
+    // A commitment that is not the commitment to the column is rejected.
+    let mut commitments = batch.commitments.clone();
+    let a = *commitments.get_index(0).unwrap().0;
+    let b = *commitments.get_index(1).unwrap().0;
+    commitments.insert(a, batch.commitments[&b]);
+    let verified = batch
+        .verify(&queries, &accessor, &commitments, &verifier_setup)
+        .unwrap();
+    assert!(verified[0].is_err());
+    assert!(verified[1].is_ok());
+}
+
+#[test]
+fn a_query_that_fails_to_be_proven_does_not_fail_the_rest_of_the_batch() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1_i64, i64::MIN, 3]),
+            bigint("b", [4_i64, 5, 6]),
+        ]),
+        0,
+        setup,
+    );
+    let queries: Vec<DynProofPlan> = vec![
+        // select b from sxt.t where a = 3
+        filter(
+            cols_expr_plan(t, &["b"], &accessor),
+            tab(t),
+            equal(column(t, "a", &accessor), const_bigint(3)),
+        ),
+        // select -a as neg_a from sxt.t where b >= 5, which overflows in its first row
+        filter(
+            vec![aliased_plan(neg(column(t, "a", &accessor)), "neg_a")],
+            tab(t),
+            gte(column(t, "b", &accessor), const_bigint(5)),
+        ),
+        // select a from sxt.t where b = 4
+        filter(
+            cols_expr_plan(t, &["a"], &accessor),
+            tab(t),
+            equal(column(t, "b", &accessor), const_bigint(4)),
+        ),
+    ];
+    let batch = ProofBatch::<DoryEvaluationProof>::new(&queries, &accessor, &setup);
+
+    // The commitments are still created for every column, including those of the failed query.
+    assert_eq!(batch.commitments.len(), 2);
+    assert!(batch.proofs[0].is_ok());
+    assert!(batch.proofs[2].is_ok());
+    let expected_error = ProveError::ResultOverflow {
+        column: "neg_a".parse().unwrap(),
+        row: 0,
+        column_type: ColumnType::BigInt,
+    };
+    assert_eq!(batch.proofs[1].as_ref().err(), Some(&expected_error));
+    assert_eq!(
+        batch.errors().collect::<Vec<_>>(),
+        vec![(1, &expected_error)]
+    );
+
+    let verified = batch
+        .verify(&queries, &accessor, &batch.commitments, &verifier_setup)
+        .unwrap();
+    assert_eq!(verified.len(), 3);
+    assert_eq!(
+        verified[0].as_ref().unwrap().table,
+        owned_table::<DoryScalar>([bigint("b", [6_i64])])
+    );
+    assert!(matches!(
+        verified[1],
+        Err(VerificationError::QueryNotProven { index: 1 })
+    ));
+    assert_eq!(
+        verified[1].as_ref().err().unwrap().to_string(),
+        "query 1 of the batch has no proof because proving it failed"
+    );
+    assert_eq!(
+        verified[2].as_ref().unwrap().table,
+        owned_table::<DoryScalar>([bigint("a", [1_i64])])
+    );
+}
@@ -0,0 +1,164 @@
+use alloc::vec::Vec;
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::Snafu;
+
+/// The magic number at the start of every proof envelope
+pub const PROOF_ENVELOPE_MAGIC: [u8; 4] = *b"PoSQ";
+
+/// The proof format version written by this library
+pub const PROOF_FORMAT_VERSION: u16 = 1;
+
+/// The oldest proof format version this library can read
+///
+/// When the layout of a payload changes in a backward-compatible way, [`PROOF_FORMAT_VERSION`] is increased
+/// and the payload type migrates payloads of older versions in [`EnvelopePayload::from_payload`].
+/// This is raised when such a migration is no longer possible.
+pub const OLDEST_SUPPORTED_PROOF_FORMAT_VERSION: u16 = 1;
+
+/// The length of the envelope header: the magic number, the version (`u16`) and the payload length (`u64`)
+pub(super) const HEADER_LENGTH: usize = PROOF_ENVELOPE_MAGIC.len() + 2 + 8;
+
+/// Errors that can occur when reading a proof envelope
+#[derive(Debug, Snafu)]
+pub enum DeserializeError {
+    /// The bytes are too short to contain an envelope header.
+    #[snafu(display(
+        "proof envelope is truncated: expected at least {HEADER_LENGTH} bytes, found {length}"
+    ))]
+    TruncatedHeader {
+        /// The number of bytes found
+        length: usize,
+    },
+    /// The bytes do not start with [`PROOF_ENVELOPE_MAGIC`], so they are not a proof envelope.
+    #[snafu(display("invalid proof envelope magic number: {found:?}"))]
+    InvalidMagic {
+        /// The first bytes found
+        found: [u8; 4],
+    },
+    /// The envelope was written with a format version this library cannot read.
+    #[snafu(display(
+        "unsupported proof format version {found}, supported versions are {oldest_supported} to {supported}"
+    ))]
+    UnsupportedVersion {
+        /// The version found in the envelope
+        found: u16,
+        /// The oldest version this library supports
+        oldest_supported: u16,
+        /// The newest version this library supports, which is the version it writes
+        supported: u16,
+    },
+    /// The payload is not as long as the envelope header says it is.
+    #[snafu(display("proof envelope payload has {actual} bytes, but its header says {expected}"))]
+    PayloadLengthMismatch {
+        /// The payload length in the header
+        expected: u64,
+        /// The number of bytes following the header
+        actual: usize,
+    },
+    /// The payload could not be deserialized.
+    #[snafu(display("failed to deserialize proof envelope payload: {error}"))]
+    Payload {
+        /// The underlying deserialization error
+        error: postcard::Error,
+    },
+}
+
+/// A value that can be read from a proof envelope.
+///
+/// Envelopes of every version from [`OLDEST_SUPPORTED_PROOF_FORMAT_VERSION`] to [`PROOF_FORMAT_VERSION`] can be read.
+/// Types whose layout changed within that range override [`EnvelopePayload::from_payload`]
+/// to migrate payloads written with an older version.
+pub trait EnvelopePayload: DeserializeOwned {
+    /// Deserialize a payload written with the proof format `version`.
+    ///
+    /// The default reads payloads of every supported version with the current layout.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the payload cannot be deserialized.
+    fn from_payload(_version: u16, payload: &[u8]) -> postcard::Result<Self> {
+        postcard::from_bytes(payload)
+    }
+}
+
+/// Returns the length of the postcard serialization of `value`, which is the payload of its proof envelope.
+///
+/// The length is computed without serializing `value` into a buffer,
+/// so a buffer of exactly this length can be allocated before serializing.
+///
+/// # Panics
+///
+/// Panics if `value` cannot be serialized, which should not happen for valid proofs.
+#[must_use]
+pub fn serialized_size(value: &impl Serialize) -> usize {
+    postcard::serialize_with_flavor(value, postcard::ser_flavors::Size::default())
+        .expect("proof should serialize")
+}
+
+/// Returns the length of the proof envelope that [`to_envelope_bytes`] writes for `value`.
+///
+/// # Panics
+///
+/// Panics if `value` cannot be serialized, which should not happen for valid proofs.
+#[must_use]
+pub fn envelope_size(value: &impl Serialize) -> usize {
+    HEADER_LENGTH + serialized_size(value)
+}
+
+/// Serialize `value` into a proof envelope.
+///
+/// The envelope consists of [`PROOF_ENVELOPE_MAGIC`], [`PROOF_FORMAT_VERSION`] as a little-endian `u16`,
+/// the length of the payload as a little-endian `u64` and the payload itself.
+///
+/// # Panics
+///
+/// Panics if `value` cannot be serialized, which should not happen for valid proofs.
+#[must_use]
+pub fn to_envelope_bytes(value: &impl Serialize) -> Vec<u8> {
+    let payload_length = serialized_size(value);
+    let mut bytes = Vec::with_capacity(HEADER_LENGTH + payload_length);
+    bytes.extend_from_slice(&PROOF_ENVELOPE_MAGIC);
+    bytes.extend_from_slice(&PROOF_FORMAT_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(payload_length as u64).to_le_bytes());
+    postcard::to_extend(value, bytes).expect("proof should serialize")
+}
+
+/// Deserialize a value that was serialized with [`to_envelope_bytes`].
+///
+/// The magic number, version and payload length are all validated before the payload is read.
+/// Payloads of older supported versions are migrated by [`EnvelopePayload::from_payload`].
+pub fn try_from_envelope_bytes<T: EnvelopePayload>(bytes: &[u8]) -> Result<T, DeserializeError> {
+    let (version, payload_length, payload) = read_envelope_header(bytes)?;
+    if payload_length != payload.len() as u64 {
+        return Err(DeserializeError::PayloadLengthMismatch {
+            expected: payload_length,
+            actual: payload.len(),
+        });
+    }
+    T::from_payload(version, payload).map_err(|error| DeserializeError::Payload { error })
+}
+
+/// Read the header of a proof envelope, returning the format version, the payload length and the bytes after the header.
+///
+/// The magic number and version are validated, but the payload length is not checked against the bytes that follow.
+pub(super) fn read_envelope_header(bytes: &[u8]) -> Result<(u16, u64, &[u8]), DeserializeError> {
+    let truncated = || DeserializeError::TruncatedHeader {
+        length: bytes.len(),
+    };
+    let (&magic, rest) = bytes.split_first_chunk().ok_or_else(truncated)?;
+    let (&version, rest) = rest.split_first_chunk().ok_or_else(truncated)?;
+    let (&payload_length, payload) = rest.split_first_chunk().ok_or_else(truncated)?;
+
+    if magic != PROOF_ENVELOPE_MAGIC {
+        return Err(DeserializeError::InvalidMagic { found: magic });
+    }
+    let version = u16::from_le_bytes(version);
+    if !(OLDEST_SUPPORTED_PROOF_FORMAT_VERSION..=PROOF_FORMAT_VERSION).contains(&version) {
+        return Err(DeserializeError::UnsupportedVersion {
+            found: version,
+            oldest_supported: OLDEST_SUPPORTED_PROOF_FORMAT_VERSION,
+            supported: PROOF_FORMAT_VERSION,
+        });
+    }
+    Ok((version, u64::from_le_bytes(payload_length), payload))
+}
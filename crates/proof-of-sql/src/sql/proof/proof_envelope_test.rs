@@ -0,0 +1,311 @@
+use super::{
+    envelope_size, to_envelope_bytes, try_from_envelope_bytes, DeserializeError, EnvelopePayload,
+    ProofPlan, QueryProof, VerifiableQueryResult, OLDEST_SUPPORTED_PROOF_FORMAT_VERSION,
+    PROOF_ENVELOPE_MAGIC, PROOF_FORMAT_VERSION,
+};
+use crate::{
+    base::{
+        commitment::TableCommitment,
+        database::{owned_table_utility::*, OwnedTableTestAccessor, TableRef},
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup, ProverSetup,
+        PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use alloc::{vec, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+impl EnvelopePayload for u64 {}
+impl EnvelopePayload for () {}
+
+#[test]
+fn we_can_round_trip_a_verifiable_query_result_through_an_envelope() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3, 4]), bigint("b", [1, 0, 1, 1])]),
+        0,
+        dory_prover_setup,
+    );
+    let expr = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(1)),
+    );
+    let verifiable_result =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&expr, &accessor, &dory_prover_setup);
+
+    let bytes = verifiable_result.to_envelope_bytes();
+    assert_eq!(bytes[..4], PROOF_ENVELOPE_MAGIC);
+    assert_eq!(bytes[4..6], PROOF_FORMAT_VERSION.to_le_bytes());
+    let payload = postcard::to_allocvec(&verifiable_result).unwrap();
+    assert_eq!(bytes[6..14], (payload.len() as u64).to_le_bytes());
+    assert_eq!(bytes[14..], payload);
+
+    let deserialized =
+        VerifiableQueryResult::<DoryEvaluationProof>::try_from_envelope_bytes(&bytes).unwrap();
+    let table = deserialized
+        .verify(&expr, &accessor, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    assert_eq!(table, owned_table([bigint("a", [1, 3, 4])]));
+}
+
+#[test]
+fn we_cannot_read_an_envelope_with_a_bad_magic_number() {
+    let mut bytes = to_envelope_bytes(&123_u64);
+    bytes[0] ^= 1;
+    assert!(matches!(
+        try_from_envelope_bytes::<u64>(&bytes),
+        Err(DeserializeError::InvalidMagic { found }) if found[1..] == PROOF_ENVELOPE_MAGIC[1..]
+    ));
+
+    // Raw postcard bytes without an envelope are rejected rather than misread
+    let result = VerifiableQueryResult::<DoryEvaluationProof>::default();
+    let mut bytes = postcard::to_allocvec(&result).unwrap();
+    bytes.resize(32, 0);
+    assert!(matches!(
+        VerifiableQueryResult::<DoryEvaluationProof>::try_from_envelope_bytes(&bytes),
+        Err(DeserializeError::InvalidMagic { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_read_an_envelope_with_an_unsupported_version() {
+    for version in [0, PROOF_FORMAT_VERSION + 1, u16::MAX] {
+        let mut bytes = to_envelope_bytes(&123_u64);
+        bytes[4..6].copy_from_slice(&version.to_le_bytes());
+        assert!(matches!(
+            try_from_envelope_bytes::<u64>(&bytes),
+            Err(DeserializeError::UnsupportedVersion { found, oldest_supported, supported })
+                if found == version
+                    && oldest_supported == OLDEST_SUPPORTED_PROOF_FORMAT_VERSION
+                    && supported == PROOF_FORMAT_VERSION
+        ));
+    }
+    for version in OLDEST_SUPPORTED_PROOF_FORMAT_VERSION..=PROOF_FORMAT_VERSION {
+        let mut bytes = to_envelope_bytes(&123_u64);
+        bytes[4..6].copy_from_slice(&version.to_le_bytes());
+        assert_eq!(try_from_envelope_bytes::<u64>(&bytes).unwrap(), 123);
+    }
+}
+
+/// A payload whose layout gained the field `extra` after an older proof format version
+#[derive(Debug, PartialEq, Deserialize)]
+struct MigratedPayload {
+    value: u64,
+    extra: Vec<u64>,
+}
+
+/// The layout of [`MigratedPayload`] before `extra` was added
+#[derive(Deserialize)]
+struct MigratedPayloadV0 {
+    value: u64,
+}
+
+impl EnvelopePayload for MigratedPayload {
+    fn from_payload(version: u16, payload: &[u8]) -> postcard::Result<Self> {
+        if version >= PROOF_FORMAT_VERSION {
+            return postcard::from_bytes(payload);
+        }
+        let MigratedPayloadV0 { value } = postcard::from_bytes(payload)?;
+        Ok(Self {
+            value,
+            extra: Vec::new(),
+        })
+    }
+}
+
+/// A [`MigratedPayload`] with a `value` of 300 written with the layout of [`MigratedPayloadV0`]
+const MIGRATED_PAYLOAD_V0: &[u8] = &[0xac, 0x02];
+
+#[test]
+fn we_can_migrate_a_payload_written_with_an_older_layout() {
+    assert_eq!(
+        MigratedPayload::from_payload(PROOF_FORMAT_VERSION - 1, MIGRATED_PAYLOAD_V0).unwrap(),
+        MigratedPayload {
+            value: 300,
+            extra: vec![]
+        }
+    );
+    // The same bytes are not a payload of the current layout
+    assert!(MigratedPayload::from_payload(PROOF_FORMAT_VERSION, MIGRATED_PAYLOAD_V0).is_err());
+
+    // Payloads of the current version are read with the current layout
+    let bytes = [0xac, 0x02, 0x02, 0x01, 0x02];
+    let mut envelope = to_envelope_bytes(&());
+    envelope[6..14].copy_from_slice(&(bytes.len() as u64).to_le_bytes());
+    envelope.extend_from_slice(&bytes);
+    assert_eq!(
+        try_from_envelope_bytes::<MigratedPayload>(&envelope).unwrap(),
+        MigratedPayload {
+            value: 300,
+            extra: vec![1, 2]
+        }
+    );
+
+    // Envelopes older than the oldest supported version are rejected before the payload is read
+    let mut envelope = to_envelope_bytes(&());
+    envelope[4..6].copy_from_slice(&(OLDEST_SUPPORTED_PROOF_FORMAT_VERSION - 1).to_le_bytes());
+    envelope[6..14].copy_from_slice(&(MIGRATED_PAYLOAD_V0.len() as u64).to_le_bytes());
+    envelope.extend_from_slice(MIGRATED_PAYLOAD_V0);
+    assert!(matches!(
+        try_from_envelope_bytes::<MigratedPayload>(&envelope),
+        Err(DeserializeError::UnsupportedVersion { found, .. })
+            if found == OLDEST_SUPPORTED_PROOF_FORMAT_VERSION - 1
+    ));
+}
+
+#[test]
+fn we_cannot_read_a_truncated_or_malformed_envelope() {
+    let bytes = to_envelope_bytes(&123_u64);
+    assert_eq!(try_from_envelope_bytes::<u64>(&bytes).unwrap(), 123);
+    assert!(matches!(
+        try_from_envelope_bytes::<u64>(&bytes[..13]),
+        Err(DeserializeError::TruncatedHeader { length: 13 })
+    ));
+    assert!(matches!(
+        try_from_envelope_bytes::<u64>(&[]),
+        Err(DeserializeError::TruncatedHeader { length: 0 })
+    ));
+    assert!(matches!(
+        try_from_envelope_bytes::<u64>(&bytes[..bytes.len() - 1]),
+        Err(DeserializeError::PayloadLengthMismatch { .. })
+    ));
+    let mut extended: Vec<u8> = bytes.clone();
+    extended.push(0);
+    assert!(matches!(
+        try_from_envelope_bytes::<u64>(&extended),
+        Err(DeserializeError::PayloadLengthMismatch { expected, actual: _ }) if expected == bytes.len() as u64 - 14
+    ));
+
+    let mut bad_payload = to_envelope_bytes(&());
+    bad_payload[6..14].copy_from_slice(&1_u64.to_le_bytes());
+    bad_payload.push(0xff);
+    assert!(matches!(
+        try_from_envelope_bytes::<u64>(&bad_payload),
+        Err(DeserializeError::Payload { .. })
+    ));
+}
+
+/// Check that the sizes of the proof of `expr` match the lengths of their serializations
+fn check_serialized_sizes<T: ProofPlan + Serialize>(
+    expr: &T,
+    accessor: &OwnedTableTestAccessor<DoryEvaluationProof>,
+    setup: &DoryProverPublicSetup,
+) {
+    let (proof, result) = QueryProof::<DoryEvaluationProof>::new(expr, accessor, setup);
+    assert_eq!(
+        proof.serialized_size(),
+        postcard::to_allocvec(&proof).unwrap().len()
+    );
+    assert_eq!(
+        result.serialized_size(),
+        postcard::to_allocvec(&result).unwrap().len()
+    );
+    let verifiable_result =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(expr, accessor, setup);
+    assert_eq!(
+        verifiable_result.serialized_size(),
+        postcard::to_allocvec(&verifiable_result).unwrap().len()
+    );
+    // The envelope is allocated exactly once, with exactly the right capacity
+    let bytes = verifiable_result.to_envelope_bytes();
+    assert_eq!(envelope_size(&verifiable_result), bytes.len());
+    assert_eq!(bytes.capacity(), bytes.len());
+}
+
+#[test]
+fn the_serialized_size_is_the_length_of_the_serialization_for_several_proof_shapes() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1, 2, 2, 1, 2, 3, 300, 2]),
+            bigint("b", [99, 99, 0, 99, 0, 99, 99, 99]),
+            varchar("c", ["x", "y", "zz", "", "x", "y", "long string", "zz"]),
+        ]),
+        2,
+        dory_prover_setup,
+    );
+    // A filter selecting some rows
+    check_serialized_sizes(
+        &filter(
+            cols_expr_plan(t, &["a", "c"], &accessor),
+            tab(t),
+            equal(column(t, "b", &accessor), const_bigint(99)),
+        ),
+        &accessor,
+        &dory_prover_setup,
+    );
+    // A filter selecting no rows
+    check_serialized_sizes(
+        &filter(
+            cols_expr_plan(t, &["c"], &accessor),
+            tab(t),
+            equal(column(t, "a", &accessor), const_bigint(-1)),
+        ),
+        &accessor,
+        &dory_prover_setup,
+    );
+    // A group by
+    check_serialized_sizes(
+        &group_by(
+            cols_expr(t, &["a"], &accessor),
+            vec![sum_expr(column(t, "b", &accessor), "sum_b")],
+            "__count__",
+            tab(t),
+            equal(column(t, "b", &accessor), const_bigint(99)),
+        ),
+        &accessor,
+        &dory_prover_setup,
+    );
+    // A projection
+    check_serialized_sizes(
+        &projection(cols_expr_plan(t, &["a", "b", "c"], &accessor), tab(t)),
+        &accessor,
+        &dory_prover_setup,
+    );
+}
+
+#[test]
+fn the_serialized_size_of_tables_and_table_commitments_is_the_length_of_their_serialization() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let table = owned_table([
+        bigint("a", [1, 2, 3, 1000]),
+        varchar("b", ["x", "", "yz", "w"]),
+        boolean("c", [true, false, true, true]),
+    ]);
+    assert_eq!(
+        table.serialized_size(),
+        postcard::to_allocvec(&table).unwrap().len()
+    );
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        table,
+        0,
+        dory_prover_setup,
+    );
+    let columns = projection(cols_expr_plan(t, &["a", "b", "c"], &accessor), tab(t))
+        .get_column_result_fields();
+    let commitment =
+        TableCommitment::from_accessor_with_max_bounds(t, &columns, &accessor).unwrap();
+    assert_eq!(
+        commitment.serialized_size(),
+        postcard::to_allocvec(&commitment).unwrap().len()
+    );
+}
@@ -1,6 +1,9 @@
 use super::{CountBuilder, FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder};
 use crate::base::{
-    database::{ColumnField, ColumnRef, OwnedTable, Table, TableEvaluation, TableRef},
+    database::{
+        ColumnField, ColumnRef, ColumnType, OwnedTable, Table, TableEvaluation, TableRef,
+        TableSchema,
+    },
     map::{IndexMap, IndexSet},
     proof::ProofError,
     scalar::Scalar,
@@ -8,6 +11,31 @@ use crate::base::{
 use alloc::vec::Vec;
 use bumpalo::Bump;
 use core::fmt::Debug;
+use proof_of_sql_parser::Identifier;
+use snafu::Snafu;
+
+/// Errors that can occur when validating a [`ProofPlan`] against a [`TableSchema`]
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The plan references a column that the schema does not have.
+    #[snafu(display("column '{column}' is not in the schema"))]
+    UnknownColumn {
+        /// The name of the column
+        column: Identifier,
+    },
+    /// The plan was built with a column type that differs from the type in the schema.
+    #[snafu(display(
+        "column '{column}' has type {expected} in the schema, but the plan expects {actual}"
+    ))]
+    ColumnTypeMismatch {
+        /// The name of the column
+        column: Identifier,
+        /// The type of the column in the schema
+        expected: ColumnType,
+        /// The type of the column in the plan
+        actual: ColumnType,
+    },
+}
 
 /// Provable nodes in the provable AST.
 #[enum_dispatch::enum_dispatch(DynProofPlan)]
@@ -32,6 +60,32 @@ pub trait ProofPlan: Debug + Send + Sync + ProverEvaluate {
 
     /// Return all the tables referenced in the Query
     fn get_table_references(&self) -> IndexSet<TableRef>;
+
+    /// Check that the plan is well-typed against the schema of the table it queries,
+    /// without any data or commitments.
+    ///
+    /// Comparisons and aggregations are type-checked when a plan is built, using the types of the
+    /// columns it references. So a plan is well-typed against a schema exactly when every column
+    /// it references is in the schema with the type the plan was built with.
+    fn validate_against_schema(&self, schema: &TableSchema) -> Result<(), ValidationError> {
+        self.get_column_references()
+            .into_iter()
+            .try_for_each(|column_ref| {
+                let column = column_ref.column_id();
+                let actual = *column_ref.column_type();
+                match schema.column_type(&column) {
+                    None => Err(ValidationError::UnknownColumn { column }),
+                    Some(expected) if expected != actual => {
+                        Err(ValidationError::ColumnTypeMismatch {
+                            column,
+                            expected,
+                            actual,
+                        })
+                    }
+                    Some(_) => Ok(()),
+                }
+            })
+    }
 }
 
 #[enum_dispatch::enum_dispatch(DynProofPlan)]
@@ -0,0 +1,84 @@
+use super::{ProofPlan, ValidationError};
+use crate::{
+    base::{
+        database::{ColumnType, TableRef, TableSchema, TestSchemaAccessor},
+        map::{indexmap, IndexMap},
+    },
+    sql::parse::QueryExpr,
+};
+use proof_of_sql_parser::{sql::SelectStatementParser, Identifier};
+
+fn schema(columns: &[(&str, ColumnType)]) -> IndexMap<Identifier, ColumnType> {
+    columns
+        .iter()
+        .map(|(name, column_type)| (name.parse().unwrap(), *column_type))
+        .collect()
+}
+
+/// Plan a query against a table with columns `a: BIGINT`, `b: BIGINT` and `c: VARCHAR`
+fn plan(sql: &str) -> QueryExpr {
+    let table: TableRef = "sxt.t".parse().unwrap();
+    let accessor = TestSchemaAccessor::new(indexmap! {
+        table => schema(&[
+            ("a", ColumnType::BigInt),
+            ("b", ColumnType::BigInt),
+            ("c", ColumnType::VarChar),
+        ]),
+    });
+    let ast = SelectStatementParser::new().parse(sql).unwrap();
+    QueryExpr::try_new(ast, table.schema_id(), &accessor).unwrap()
+}
+
+#[test]
+fn we_can_validate_a_well_typed_plan_against_a_schema() {
+    let schema = TableSchema::new(schema(&[
+        ("a", ColumnType::BigInt),
+        ("b", ColumnType::BigInt),
+        ("c", ColumnType::VarChar),
+        ("d", ColumnType::Boolean),
+    ]));
+    for sql in [
+        "select a, c from t where b = 3",
+        "select a, sum(b) as s, count(*) as n from t where c = 'x' group by a",
+    ] {
+        assert_eq!(
+            plan(sql).proof_expr().validate_against_schema(&schema),
+            Ok(())
+        );
+    }
+}
+
+#[test]
+fn we_cannot_validate_a_plan_whose_column_types_differ_from_the_schema() {
+    let schema = TableSchema::new(schema(&[
+        ("a", ColumnType::BigInt),
+        ("b", ColumnType::VarChar),
+        ("c", ColumnType::VarChar),
+    ]));
+    assert_eq!(
+        plan("select a, sum(b) as s, count(*) as n from t group by a")
+            .proof_expr()
+            .validate_against_schema(&schema),
+        Err(ValidationError::ColumnTypeMismatch {
+            column: "b".parse().unwrap(),
+            expected: ColumnType::VarChar,
+            actual: ColumnType::BigInt,
+        })
+    );
+}
+
+#[test]
+fn we_cannot_validate_a_plan_that_references_a_column_missing_from_the_schema() {
+    let schema = TableSchema::new(schema(&[
+        ("a", ColumnType::BigInt),
+        ("b", ColumnType::BigInt),
+    ]));
+    assert_eq!(
+        plan("select a from t where c = 'x'")
+            .proof_expr()
+            .validate_against_schema(&schema),
+        Err(ValidationError::UnknownColumn {
+            column: "c".parse().unwrap(),
+        })
+    );
+}
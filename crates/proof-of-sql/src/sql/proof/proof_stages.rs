@@ -0,0 +1,123 @@
+use super::{query_proof::EvaluatedQuery, ProofPlan, ProvableQueryResult, ProveError, QueryProof};
+use crate::{
+    base::{
+        commitment::CommitmentEvaluationProof, database::DataAccessor, map::IndexSet,
+        proof::Keccak256Transcript,
+    },
+    proof_primitive::sumcheck::SumcheckProof,
+};
+use alloc::{vec, vec::Vec};
+use bumpalo::Bump;
+use num_traits::Zero;
+use serde::Serialize;
+
+/// The stages of proving a query, run one at a time.
+///
+/// [`QueryProof::try_new`] runs every stage in one go. This type runs them separately, so that the cost of
+/// each stage can be measured on its own, for instance by benchmarking [`ProofStages::prove_sumcheck`]
+/// against a fixed [`CommittedStage`]. The stages are:
+/// 1. [`ProofStages::try_new`] evaluates the query result and the intermediate MLEs of the proof,
+/// 2. [`ProofStages::commit`] commits to the intermediate MLEs,
+/// 3. [`ProofStages::prove_sumcheck`] proves the sumcheck,
+/// 4. [`ProofStages::prove_evaluations`] proves the evaluations of the committed MLEs and assembles the proof.
+///
+/// Every stage only reads the output of the stages before it, so a stage can be run any number of times.
+/// Running the stages in order creates the same proof as [`QueryProof::try_new`].
+/// The outputs must come from the same `ProofStages`, or the proof will not verify.
+pub struct ProofStages<'a, CP: CommitmentEvaluationProof> {
+    query: EvaluatedQuery<'a, CP>,
+    transcript: Keccak256Transcript,
+}
+
+/// The output of [`ProofStages::commit`]
+#[derive(Clone)]
+pub struct CommittedStage<CP: CommitmentEvaluationProof> {
+    commitments: Vec<CP::Commitment>,
+    transcript: Keccak256Transcript,
+}
+
+/// The output of [`ProofStages::prove_sumcheck`]
+#[derive(Clone)]
+pub struct SumcheckStage<CP: CommitmentEvaluationProof> {
+    sumcheck_proof: SumcheckProof<CP::Scalar>,
+    evaluation_point: Vec<CP::Scalar>,
+    transcript: Keccak256Transcript,
+}
+
+impl<'a, CP: CommitmentEvaluationProof> ProofStages<'a, CP>
+where
+    CP::Scalar: 'a,
+{
+    /// Evaluate the query result and the intermediate MLEs of the proof, which are allocated in `alloc`.
+    ///
+    /// Will error if the columns referenced by the query do not have the row counts of their tables,
+    /// or with [`ProveError::ResultOverflow`] if a value of the result does not fit the type of its column.
+    pub fn try_new(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &'a impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        alloc: &'a Bump,
+    ) -> Result<Self, ProveError> {
+        let (query, transcript) =
+            EvaluatedQuery::try_new(expr, accessor, setup, alloc, &IndexSet::default(), true)?;
+        Ok(Self { query, transcript })
+    }
+
+    /// The result of the query
+    #[must_use]
+    pub fn result(&self) -> &ProvableQueryResult {
+        &self.query.result.disclosed
+    }
+
+    /// Commit to the intermediate MLEs.
+    #[must_use]
+    pub fn commit(&self, setup: &CP::ProverPublicSetup<'_>) -> CommittedStage<CP> {
+        let mut transcript = self.transcript.clone();
+        let commitments = self.query.commit_intermediate_mles(&mut transcript, setup);
+        CommittedStage {
+            commitments,
+            transcript,
+        }
+    }
+
+    /// Prove the sumcheck.
+    #[must_use]
+    pub fn prove_sumcheck(&self, committed: &CommittedStage<CP>) -> SumcheckStage<CP> {
+        let mut transcript = committed.transcript.clone();
+        let mut evaluation_point =
+            vec![CP::Scalar::zero(); self.query.builder.num_sumcheck_variables()];
+        let sumcheck_proof =
+            self.query
+                .prove_sumcheck(&mut transcript, &mut evaluation_point, |_| {});
+        SumcheckStage {
+            sumcheck_proof,
+            evaluation_point,
+            transcript,
+        }
+    }
+
+    /// Prove the evaluations of the committed MLEs and assemble the proof.
+    #[must_use]
+    pub fn prove_evaluations(
+        &self,
+        committed: &CommittedStage<CP>,
+        sumcheck: &SumcheckStage<CP>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> (QueryProof<CP>, ProvableQueryResult) {
+        let mut transcript = sumcheck.transcript.clone();
+        let (pcs_proof_evaluations, evaluation_proof) = self.query.prove_evaluations(
+            &mut transcript,
+            &sumcheck.evaluation_point,
+            setup,
+            &mut Vec::new(),
+            &mut Vec::new(),
+        );
+        let proof = self.query.proof(
+            committed.commitments.clone(),
+            sumcheck.sumcheck_proof.clone(),
+            pcs_proof_evaluations,
+            evaluation_proof,
+        );
+        (proof, self.query.result.disclosed.clone())
+    }
+}
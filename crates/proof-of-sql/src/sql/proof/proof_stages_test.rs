@@ -0,0 +1,63 @@
+use super::{ProofStages, QueryProof};
+use crate::{
+    base::database::{owned_table_utility::*, OwnedTableTestAccessor, TableRef},
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup, ProverSetup,
+        PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use bumpalo::Bump;
+
+#[test]
+fn running_the_proof_stages_in_order_creates_the_same_proof_as_proving_in_one_go() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1_i64, 2, 3, 2, 5]),
+            bigint("b", [5_i64, 6, 7, 8, 9]),
+        ]),
+        0,
+        setup,
+    );
+    // select b from sxt.t where a >= 2
+    let plan = filter(
+        cols_expr_plan(t, &["b"], &accessor),
+        tab(t),
+        gte(column(t, "a", &accessor), const_bigint(2)),
+    );
+    let (expected_proof, expected_result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+
+    let alloc = Bump::new();
+    let stages =
+        ProofStages::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup, &alloc).unwrap();
+    let committed = stages.commit(&setup);
+    let sumcheck = stages.prove_sumcheck(&committed);
+    let (proof, result) = stages.prove_evaluations(&committed, &sumcheck, &setup);
+    assert_eq!(
+        postcard::to_allocvec(&proof).unwrap(),
+        postcard::to_allocvec(&expected_proof).unwrap()
+    );
+    assert_eq!(
+        postcard::to_allocvec(&result).unwrap(),
+        postcard::to_allocvec(&expected_result).unwrap()
+    );
+    assert!(proof
+        .verify(&plan, &accessor, &result, &verifier_setup)
+        .is_ok());
+
+    // Stages only read their inputs, so running one again gives the same output.
+    let (proof_again, _) =
+        stages.prove_evaluations(&committed, &stages.prove_sumcheck(&committed), &setup);
+    assert_eq!(
+        postcard::to_allocvec(&proof_again).unwrap(),
+        postcard::to_allocvec(&proof).unwrap()
+    );
+}
@@ -1,4 +1,7 @@
-use super::{decode_and_convert, decode_multiple_elements, ProvableResultColumn, QueryError};
+use super::{
+    decode_and_convert, decode_multiple_elements, proof_envelope, EnvelopePayload,
+    ProvableResultColumn, QueryError,
+};
 use crate::base::{
     database::{Column, ColumnField, ColumnType, OwnedColumn, OwnedTable, Table},
     polynomial::compute_evaluation_vector,
@@ -8,6 +11,16 @@ use alloc::{vec, vec::Vec};
 use num_traits::Zero;
 use serde::{Deserialize, Serialize};
 
+/// Decode a fixed-length binary value, checking that it has the length of its column
+fn decode_fixed_bytes(data: &[u8], width: u8) -> Result<(&[u8], usize), QueryError> {
+    let (bytes, num_read) = decode_and_convert::<&[u8], &[u8]>(data)?;
+    if bytes.len() == usize::from(width) {
+        Ok((bytes, num_read))
+    } else {
+        Err(QueryError::MiscellaneousDecodingError)
+    }
+}
+
 /// An intermediate form of a query result that can be transformed
 /// to either the finalized query result form or a query error
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -17,6 +30,8 @@ pub struct ProvableQueryResult {
     data: Vec<u8>,
 }
 
+impl EnvelopePayload for ProvableQueryResult {}
+
 // TODO: Handle truncation properly. The `allow(clippy::cast_possible_truncation)` is a temporary fix and should be replaced with proper logic to manage possible truncation scenarios.
 impl ProvableQueryResult {
     #[allow(clippy::cast_possible_truncation)]
@@ -55,6 +70,21 @@ impl ProvableQueryResult {
         }
     }
 
+    /// Returns the length of the postcard serialization of this result, without serializing it.
+    ///
+    /// See [`proof_envelope::serialized_size`].
+    #[must_use]
+    pub fn serialized_size(&self) -> usize {
+        proof_envelope::serialized_size(self)
+    }
+
+    /// Serialize this result into a versioned proof envelope, to be verified with
+    /// [`QueryProof::verify_and_decode`](super::QueryProof::verify_and_decode).
+    #[must_use]
+    pub fn to_envelope_bytes(&self) -> Vec<u8> {
+        proof_envelope::to_envelope_bytes(self)
+    }
+
     /// Form intermediate query result from index rows and result columns
     /// # Panics
     ///
@@ -116,11 +146,15 @@ impl ProvableQueryResult {
                     ColumnType::Int => decode_and_convert::<i32, S>(&self.data[offset..]),
                     ColumnType::BigInt => decode_and_convert::<i64, S>(&self.data[offset..]),
                     ColumnType::Int128 => decode_and_convert::<i128, S>(&self.data[offset..]),
-                    ColumnType::Decimal75(_, _) | ColumnType::Scalar => {
+                    ColumnType::UInt64 | ColumnType::Decimal75(_, _) | ColumnType::Scalar => {
                         decode_and_convert::<S, S>(&self.data[offset..])
                     }
 
                     ColumnType::VarChar => decode_and_convert::<&str, S>(&self.data[offset..]),
+                    ColumnType::FixedBytes(width) => {
+                        decode_fixed_bytes(&self.data[offset..], width)
+                            .map(|(bytes, sz)| (S::from(bytes), sz))
+                    }
                     ColumnType::TimestampTZ(_, _) => {
                         decode_and_convert::<i64, S>(&self.data[offset..])
                     }
@@ -184,6 +218,16 @@ impl ProvableQueryResult {
                         offset += num_read;
                         Ok((field.name(), OwnedColumn::BigInt(col)))
                     }
+                    ColumnType::UInt64 => {
+                        let (col, num_read) =
+                            decode_multiple_elements::<S>(&self.data[offset..], n)?;
+                        offset += num_read;
+                        let col = col
+                            .into_iter()
+                            .map(|v| v.try_into().map_err(|_| QueryError::Overflow))
+                            .collect::<Result<_, _>>()?;
+                        Ok((field.name(), OwnedColumn::UInt64(col)))
+                    }
                     ColumnType::Int128 => {
                         let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
                         offset += num_read;
@@ -194,6 +238,17 @@ impl ProvableQueryResult {
                         offset += num_read;
                         Ok((field.name(), OwnedColumn::VarChar(col)))
                     }
+                    ColumnType::FixedBytes(width) => {
+                        let col = (0..n)
+                            .map(|_| {
+                                let (bytes, num_read) =
+                                    decode_fixed_bytes(&self.data[offset..], width)?;
+                                offset += num_read;
+                                Ok(bytes.to_vec())
+                            })
+                            .collect::<Result<_, QueryError>>()?;
+                        Ok((field.name(), OwnedColumn::FixedBytes(width, col)))
+                    }
                     ColumnType::Scalar => {
                         let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
                         offset += num_read;
@@ -1,6 +1,6 @@
 use super::{ProvableQueryResult, QueryError};
 use crate::base::{
-    database::{Column, ColumnField, ColumnType},
+    database::{owned_table_utility::*, Column, ColumnField, ColumnType},
     math::decimal::Precision,
     polynomial::compute_evaluation_vector,
     scalar::{Curve25519Scalar, Scalar},
@@ -234,6 +234,52 @@ fn evaluation_fails_if_integer_overflow_happens() {
     ));
 }
 
+#[test]
+fn we_can_convert_unsigned_result_columns_to_a_final_result() {
+    let values = [0, i64::MAX as u64, i64::MAX as u64 + 1, u64::MAX];
+    let scalars = values.map(Curve25519Scalar::from);
+    let cols: [Column<Curve25519Scalar>; 1] = [Column::UInt64(&values)];
+    let mut res = ProvableQueryResult::new(4, &cols);
+    // A computed unsigned result is a scalar column and must be serialized identically
+    let mut computed = ProvableQueryResult::new(4, &[Column::Scalar(&scalars)]);
+    assert_eq!(res.data_mut(), computed.data_mut());
+    let column_fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::UInt64)];
+    assert_eq!(
+        res.to_owned_table::<Curve25519Scalar>(&column_fields)
+            .unwrap(),
+        owned_table([uint64("a", values)])
+    );
+
+    let evaluation_point = [
+        Curve25519Scalar::from(10u64),
+        Curve25519Scalar::from(100u64),
+    ];
+    let mut evaluation_vec = [Curve25519Scalar::ZERO; 4];
+    compute_evaluation_vector(&mut evaluation_vec, &evaluation_point);
+    let expected_eval = evaluation_vec
+        .iter()
+        .zip(scalars)
+        .map(|(e, s)| *e * s)
+        .sum::<Curve25519Scalar>();
+    assert_eq!(
+        res.evaluate(&evaluation_point, 4, &column_fields[..])
+            .unwrap(),
+        [expected_eval]
+    );
+}
+
+#[test]
+fn we_cannot_convert_negative_values_to_an_unsigned_final_result() {
+    let binding = [Curve25519Scalar::from(-1)];
+    let cols: [Column<Curve25519Scalar>; 1] = [Column::Scalar(&binding)];
+    let res = ProvableQueryResult::new(1, &cols);
+    let column_fields = vec![ColumnField::new("a".parse().unwrap(), ColumnType::UInt64)];
+    assert!(matches!(
+        res.to_owned_table::<Curve25519Scalar>(&column_fields),
+        Err(QueryError::Overflow)
+    ));
+}
+
 #[test]
 fn evaluation_fails_if_data_is_missing() {
     let cols: [Column<Curve25519Scalar>; 1] = [Column::BigInt(&[10, 12])];
@@ -28,6 +28,8 @@ impl<'a, T: ProvableResultElement<'a>> ProvableResultColumn for &[T] {
     }
 }
 
+/// `UInt64` values are written with the encoding of their scalars, which is the same
+/// encoding that computed `UInt64` results such as sums are written with.
 impl<S: Scalar> ProvableResultColumn for Column<'_, S> {
     fn num_bytes(&self, length: u64) -> usize {
         match self {
@@ -36,9 +38,14 @@ impl<S: Scalar> ProvableResultColumn for Column<'_, S> {
             Column::SmallInt(col) => col.num_bytes(length),
             Column::Int(col) => col.num_bytes(length),
             Column::BigInt(col) | Column::TimestampTZ(_, _, col) => col.num_bytes(length),
+            Column::UInt64(col) => {
+                assert_eq!(col.len() as u64, length);
+                col.iter().map(|v| S::from(v).required_bytes()).sum()
+            }
             Column::Int128(col) => col.num_bytes(length),
             Column::Decimal75(_, _, col) | Column::Scalar(col) => col.num_bytes(length),
             Column::VarChar((col, _)) => col.num_bytes(length),
+            Column::FixedBytes(_, (col, _)) => col.num_bytes(length),
         }
     }
 
@@ -49,9 +56,20 @@ impl<S: Scalar> ProvableResultColumn for Column<'_, S> {
             Column::SmallInt(col) => col.write(out, length),
             Column::Int(col) => col.write(out, length),
             Column::BigInt(col) | Column::TimestampTZ(_, _, col) => col.write(out, length),
+            Column::UInt64(col) => {
+                let mut res = 0;
+                for v in col
+                    .iter()
+                    .take(usize::try_from(length).expect("Index out of bounds"))
+                {
+                    res += S::from(v).encode(&mut out[res..]);
+                }
+                res
+            }
             Column::Int128(col) => col.write(out, length),
             Column::Decimal75(_, _, col) | Column::Scalar(col) => col.write(out, length),
             Column::VarChar((col, _)) => col.write(out, length),
+            Column::FixedBytes(_, (col, _)) => col.write(out, length),
         }
     }
 }
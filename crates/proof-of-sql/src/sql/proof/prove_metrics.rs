@@ -0,0 +1,73 @@
+use crate::base::commitment::CommittableColumn;
+
+/// Counts of the expensive operations performed while generating a [`QueryProof`](super::QueryProof).
+///
+/// Filled in through [`ProveOptions::metrics`](super::ProveOptions::metrics),
+/// which is only available with the `prove-metrics` feature.
+/// Without the feature the counters are compiled out entirely.
+///
+/// The counts cover the query proof itself. The operations performed inside the evaluation
+/// proof of the commitment scheme are not counted.
+#[cfg(feature = "prove-metrics")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProveMetrics {
+    /// The number of scalar multiplications in the multi-scalar multiplications that commit to
+    /// the intermediate MLEs, which is the total length of the committed MLEs
+    pub scalar_muls: usize,
+    /// The number of field inversions, where every element of a batch inversion counts as one inversion
+    pub field_inversions: usize,
+    /// The number of multi-scalar multiplications, one per commitment to an intermediate MLE
+    pub msm_calls: usize,
+    /// The number of rounds of the sumcheck proof
+    pub sumcheck_rounds: usize,
+    /// The number of MLEs evaluated at the sumcheck evaluation point
+    pub mle_evaluations: usize,
+}
+
+/// Stand-in for the counters when the `prove-metrics` feature is disabled. Recording is a no-op.
+#[cfg(not(feature = "prove-metrics"))]
+#[derive(Debug, Clone, Default)]
+pub struct ProveMetrics {}
+
+#[cfg(feature = "prove-metrics")]
+impl ProveMetrics {
+    /// Record field inversions
+    pub(crate) fn record_field_inversions(&mut self, count: usize) {
+        self.field_inversions += count;
+    }
+
+    /// Record the multi-scalar multiplications that commit to `columns`
+    pub(crate) fn record_commitments(&mut self, columns: &[CommittableColumn]) {
+        self.msm_calls += columns.len();
+        self.scalar_muls += columns.iter().map(CommittableColumn::len).sum::<usize>();
+    }
+
+    /// Record the rounds of a sumcheck proof
+    pub(crate) fn record_sumcheck_rounds(&mut self, count: usize) {
+        self.sumcheck_rounds += count;
+    }
+
+    /// Record evaluations of MLEs
+    pub(crate) fn record_mle_evaluations(&mut self, count: usize) {
+        self.mle_evaluations += count;
+    }
+}
+
+#[cfg(not(feature = "prove-metrics"))]
+#[allow(
+    clippy::unused_self,
+    reason = "the methods mirror the recording methods of the feature-gated counters"
+)]
+impl ProveMetrics {
+    #[inline(always)]
+    pub(crate) fn record_field_inversions(&mut self, _count: usize) {}
+
+    #[inline(always)]
+    pub(crate) fn record_commitments(&mut self, _columns: &[CommittableColumn]) {}
+
+    #[inline(always)]
+    pub(crate) fn record_sumcheck_rounds(&mut self, _count: usize) {}
+
+    #[inline(always)]
+    pub(crate) fn record_mle_evaluations(&mut self, _count: usize) {}
+}
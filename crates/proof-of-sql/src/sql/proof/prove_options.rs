@@ -0,0 +1,80 @@
+#[cfg(feature = "prove-metrics")]
+use super::ProveMetrics;
+use super::{ProofStage, ProverScratch};
+#[cfg(feature = "transcript-log")]
+use crate::base::proof::TranscriptEntry;
+use crate::base::{map::IndexSet, scalar::Scalar};
+#[cfg(feature = "transcript-log")]
+use alloc::vec::Vec;
+use proof_of_sql_parser::Identifier;
+
+/// Options for generating a proof with [`QueryProof::try_new_with_options`](super::QueryProof::try_new_with_options)
+/// or [`VerifiableQueryResult::try_new_with_options`](super::VerifiableQueryResult::try_new_with_options).
+///
+/// The default options create the same proof as [`QueryProof::try_new`](super::QueryProof::try_new).
+/// Only `hidden_columns` changes the proof and the result. The other options only affect
+/// how the proof is generated, whether it is abandoned, or what is reported about it.
+///
+/// ```ignore
+/// let mut scratch = ProverScratch::new();
+/// let (proof, result) = QueryProof::try_new_with_options(
+///     &expr,
+///     &accessor,
+///     &setup,
+///     ProveOptions {
+///         scratch: Some(&mut scratch),
+///         ..ProveOptions::default()
+///     },
+/// )?;
+/// ```
+pub struct ProveOptions<'a, S: Scalar> {
+    /// Buffers to reuse between proofs. See [`ProverScratch`] for why this is useful.
+    ///
+    /// If this is `None`, the proof allocates its own buffers.
+    pub scratch: Option<&'a mut ProverScratch<S>>,
+    /// Called with the current stage and the rough fraction of the work that is complete.
+    ///
+    /// The fractions never decrease, and the last call reports `1.0` once the proof is complete.
+    pub progress: Option<&'a mut dyn FnMut(ProofStage, f32)>,
+    /// Checked at the start of every [`ProofStage`]. Once it returns `true`, the proof is abandoned
+    /// with [`ProveError::Cancelled`](super::ProveError::Cancelled) and leaves no state behind.
+    pub should_cancel: Option<&'a dyn Fn() -> bool>,
+    /// The result columns that are only committed to instead of disclosed.
+    ///
+    /// The proof binds them just as it binds the disclosed columns. They are withheld but not hidden:
+    /// their commitments and MLE evaluations leak information about them.
+    /// See [`PartiallyDisclosedResult`](super::PartiallyDisclosedResult).
+    pub hidden_columns: IndexSet<Identifier>,
+    /// Whether to report a result value that does not fit the type of its column
+    /// with [`ProveError::ResultOverflow`](super::ProveError::ResultOverflow) instead of proving it.
+    ///
+    /// A result that overflows is otherwise proven anyway, and the verifier rejects it with
+    /// [`QueryError::Overflow`](super::QueryError::Overflow). This is `true` by default.
+    pub check_overflow: bool,
+    /// Filled with counts of the expensive operations performed to create the proof.
+    #[cfg(feature = "prove-metrics")]
+    pub metrics: Option<&'a mut ProveMetrics>,
+    /// Filled with every message and challenge of the transcript of the proof.
+    ///
+    /// The log can be checked with [`replay_transcript_log`](super::replay_transcript_log).
+    /// It holds every message sent to the transcript, which may include data derived from the witness,
+    /// so it must be treated with the same care as the witness itself.
+    #[cfg(feature = "transcript-log")]
+    pub transcript_log: Option<&'a mut Vec<TranscriptEntry>>,
+}
+
+impl<S: Scalar> Default for ProveOptions<'_, S> {
+    fn default() -> Self {
+        Self {
+            scratch: None,
+            progress: None,
+            should_cancel: None,
+            hidden_columns: IndexSet::default(),
+            check_overflow: true,
+            #[cfg(feature = "prove-metrics")]
+            metrics: None,
+            #[cfg(feature = "transcript-log")]
+            transcript_log: None,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::base::scalar::Scalar;
+use alloc::vec::Vec;
+use bumpalo::Bump;
+use num_traits::Zero;
+
+/// Reusable buffers for generating proofs.
+///
+/// Generating a proof allocates large scratch buffers, most notably the arena holding the intermediate MLEs
+/// and the vectors used to evaluate and fold the MLEs. Passing the same `ProverScratch` in the
+/// [`ProveOptions`](super::ProveOptions) of several proofs keeps these allocations alive between proofs,
+/// so that repeated proofs do not have to allocate them again.
+///
+/// Reusing scratch has no effect on the proofs that are generated.
+#[derive(Debug, Default)]
+pub struct ProverScratch<S: Scalar> {
+    pub(super) alloc: Bump,
+    pub(super) evaluation_point: Vec<S>,
+    pub(super) evaluation_vec: Vec<S>,
+    pub(super) folded_mle: Vec<S>,
+}
+
+impl<S: Scalar> ProverScratch<S> {
+    /// Create empty scratch. Buffers are allocated by the first proof that uses it.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Zero `buffer` and resize it to `len`, reusing its capacity.
+pub(super) fn zeroed<S: Scalar>(buffer: &mut Vec<S>, len: usize) -> &mut [S] {
+    buffer.clear();
+    buffer.resize(len, Zero::zero());
+    buffer
+}
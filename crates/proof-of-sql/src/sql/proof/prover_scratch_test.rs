@@ -0,0 +1,73 @@
+use super::{ProveOptions, ProverScratch, VerifiableQueryResult};
+use crate::{
+    base::database::{owned_table_utility::*, OwnedTableTestAccessor, TestAccessor},
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup, ProverSetup,
+        PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+
+#[test]
+fn reusing_scratch_produces_identical_proofs() {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let large = "sxt.large".parse().unwrap();
+    let small = "sxt.small".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        large,
+        owned_table([
+            bigint("a", 0..20),
+            bigint("b", (0..20).map(|i| i % 3)),
+            varchar("c", (0..20).map(|i| format!("row {i}"))),
+        ]),
+        0,
+        dory_prover_setup,
+    );
+    accessor.add_table(
+        small,
+        owned_table([
+            bigint("a", [5, 6, 7]),
+            bigint("b", [1, 0, 1]),
+            varchar("c", ["x", "y", "z"]),
+        ]),
+        0,
+    );
+    let query = |t| {
+        filter(
+            cols_expr_plan(t, &["a", "c"], &accessor),
+            tab(t),
+            equal(column(t, "b", &accessor), const_bigint(1)),
+        )
+    };
+
+    // Prove the large query first so that the reused buffers hold stale data that is longer than needed
+    let mut scratch = ProverScratch::new();
+    for t in [large, small, large, small] {
+        let expr = query(t);
+        let fresh =
+            VerifiableQueryResult::<DoryEvaluationProof>::new(&expr, &accessor, &dory_prover_setup);
+        let options = ProveOptions {
+            scratch: Some(&mut scratch),
+            ..ProveOptions::default()
+        };
+        let reused = VerifiableQueryResult::<DoryEvaluationProof>::try_new_with_options(
+            &expr,
+            &accessor,
+            &dory_prover_setup,
+            options,
+        )
+        .unwrap();
+        assert_eq!(
+            postcard::to_allocvec(&reused).unwrap(),
+            postcard::to_allocvec(&fresh).unwrap()
+        );
+        reused
+            .verify(&expr, &accessor, &dory_verifier_setup)
+            .unwrap();
+    }
+}
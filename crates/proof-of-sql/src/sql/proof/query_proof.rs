@@ -1,27 +1,35 @@
 use super::{
-    CountBuilder, FinalRoundBuilder, ProofCounts, ProofPlan, ProvableQueryResult, QueryResult,
-    SumcheckMleEvaluations, SumcheckRandomScalars, VerificationBuilder,
+    proof_envelope, prover_scratch::zeroed, try_from_envelope_bytes, CountBuilder,
+    DeserializeError, FinalRoundBuilder, ProofCounts, ProofPlan, ProvableQueryResult, ProveOptions,
+    ProverScratch, QueryError, QueryResult, SumcheckMleEvaluations, SumcheckRandomScalars,
+    VerificationBuilder, VerifyCost,
 };
+#[cfg(feature = "transcript-log")]
+use crate::base::proof::RecordingTranscript;
 use crate::{
     base::{
         bit::BitDistribution,
-        commitment::CommitmentEvaluationProof,
+        commitment::{Commitment, CommitmentEvaluationProof, CommittableColumn},
         database::{
-            ColumnRef, CommitmentAccessor, DataAccessor, MetadataAccessor, Table, TableRef,
+            Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, CommitmentSource,
+            DataAccessor, MetadataAccessor, OwnedTable, Table, TableRef,
         },
         map::{IndexMap, IndexSet},
         math::log2_up,
-        polynomial::{compute_evaluation_vector, CompositePolynomialInfo},
+        polynomial::{compute_evaluation_vector, CompositePolynomialInfo, EvaluationVector},
         proof::{Keccak256Transcript, ProofError, Transcript},
+        scalar::Scalar,
     },
     proof_primitive::sumcheck::SumcheckProof,
-    sql::proof::{FirstRoundBuilder, QueryData},
+    sql::proof::{FirstRoundBuilder, PartiallyDisclosedResult, QueryData},
 };
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use bumpalo::Bump;
 use core::cmp;
 use num_traits::Zero;
+use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
+use snafu::Snafu;
 
 /// Return the row number range of tables referenced in the Query
 ///
@@ -43,40 +51,284 @@ fn get_index_range<'a>(
         .unwrap_or((0, 1))
 }
 
-/// The proof for a query.
+/// Return the total number of rows of the tables referenced in the Query
+fn get_input_row_count<'a>(
+    accessor: &dyn MetadataAccessor,
+    table_refs: impl IntoIterator<Item = &'a TableRef>,
+) -> usize {
+    table_refs
+        .into_iter()
+        .map(|table_ref| accessor.get_length(*table_ref))
+        .sum()
+}
+
+/// The number of sumcheck variables for a range of `range_length` rows.
 ///
-/// Note: Because the class is deserialized from untrusted data, it
-/// cannot maintain any invariant on its data members; hence, they are
-/// all public so as to allow for easy manipulation for testing.
-#[derive(Clone, Serialize, Deserialize)]
-pub struct QueryProof<CP: CommitmentEvaluationProof> {
-    /// Bit distributions
-    pub bit_distributions: Vec<BitDistribution>,
-    /// One evaluation lengths
-    pub one_evaluation_lengths: Vec<usize>,
-    /// Commitments
-    pub commitments: Vec<CP::Commitment>,
-    /// Sumcheck Proof
-    pub sumcheck_proof: SumcheckProof<CP::Scalar>,
-    /// MLEs used in sumcheck except for the result columns
-    pub pcs_proof_evaluations: Vec<CP::Scalar>,
-    /// Inner product proof of the MLEs' evaluations
-    pub evaluation_proof: CP,
-    /// Length of the range of generators we use
-    pub range_length: usize,
+/// There is always at least one variable, even when the range is empty because every table the query reads is empty.
+fn num_sumcheck_variables(range_length: usize) -> usize {
+    cmp::max(log2_up(range_length.max(1)), 1)
 }
 
-impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
-    /// Create a new `QueryProof`.
-    #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
-    pub fn new(
+/// Errors that can occur when generating a [`QueryProof`].
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum ProveError {
+    /// A column participating in the proof has a different row count than its table.
+    #[snafu(display("column {column} has {actual} rows, but its table has {expected} rows"))]
+    RowCountMismatch {
+        /// The column with the wrong row count
+        column: Identifier,
+        /// The row count of the column's table
+        expected: usize,
+        /// The row count of the column
+        actual: usize,
+    },
+    /// Proof generation was cancelled through [`ProveOptions::should_cancel`].
+    #[snafu(display("proof generation was cancelled"))]
+    Cancelled,
+    /// A column asked to be hidden is not a column of the result.
+    #[snafu(display("column {column} is not a column of the result"))]
+    UnknownResultColumn {
+        /// The column that is not in the result
+        column: Identifier,
+    },
+    /// A column was asked to be hidden from a result that discloses every column,
+    /// such as a [`VerifiableQueryResult`](super::VerifiableQueryResult).
+    #[snafu(display("column {column} cannot be hidden from this result"))]
+    HiddenColumnNotSupported {
+        /// The column asked to be hidden
+        column: Identifier,
+    },
+    /// A value of the result does not fit the type of its column, e.g. because an arithmetic expression overflowed.
+    #[snafu(display(
+        "row {row} of result column {column} does not fit in its type {column_type}"
+    ))]
+    ResultOverflow {
+        /// The result column holding the value
+        column: Identifier,
+        /// The row of the result holding the value
+        row: usize,
+        /// The type of the result column
+        column_type: ColumnType,
+    },
+}
+
+/// Errors that can occur when verifying a serialized result with [`QueryProof::verify_and_decode`].
+#[derive(Snafu, Debug)]
+pub enum VerificationError {
+    /// The serialized result could not be deserialized.
+    #[snafu(transparent)]
+    Deserialize {
+        /// The underlying source error
+        source: DeserializeError,
+    },
+    /// The result failed to verify or could not be decoded.
+    #[snafu(transparent)]
+    Query {
+        /// The underlying source error
+        source: QueryError,
+    },
+    /// The commitment source does not have the commitment of a column the query references.
+    #[snafu(display("no commitment for column {column_id} of table {table_ref}"))]
+    MissingCommitment {
+        /// The table of the column
+        table_ref: Box<TableRef>,
+        /// The name of the column
+        column_id: Identifier,
+    },
+    /// A batch of proofs is verified against a different number of queries.
+    #[snafu(display("a batch of {num_proofs} proofs cannot verify {num_queries} queries"))]
+    ProofBatchSizeMismatch {
+        /// The number of queries
+        num_queries: usize,
+        /// The number of proofs in the batch
+        num_proofs: usize,
+    },
+    /// A query of a batch has no proof to verify, because proving it failed.
+    #[snafu(display("query {index} of the batch has no proof because proving it failed"))]
+    QueryNotProven {
+        /// The position of the query in the batch
+        index: usize,
+    },
+}
+
+/// Errors from checking the structure of a [`QueryProof`] with [`QueryProof::check_well_formed`].
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum MalformedError {
+    /// A bit distribution has a bit that varies but is never set.
+    #[snafu(display("bit distribution {index} is invalid"))]
+    InvalidBitDistribution {
+        /// The position of the bit distribution in the proof
+        index: usize,
+    },
+    /// A one evaluation length is longer than the range of generators of the proof.
+    #[snafu(display(
+        "one evaluation length {length} is longer than the range length {range_length}"
+    ))]
+    OneEvaluationLengthOutOfRange {
+        /// The one evaluation length
+        length: usize,
+        /// The range length of the proof
+        range_length: usize,
+    },
+    /// The sumcheck proof does not have the same number of coefficients, at least three, in each of its rounds.
+    #[snafu(display(
+        "sumcheck proof has {num_coefficients} coefficients, which cannot be split evenly into {num_rounds} rounds"
+    ))]
+    InvalidSumcheckProofSize {
+        /// The number of coefficients of the sumcheck proof
+        num_coefficients: usize,
+        /// The number of rounds implied by the range length
+        num_rounds: usize,
+    },
+    /// There are fewer MLE evaluations than commitments to intermediate MLEs, each of which needs an evaluation.
+    #[snafu(display(
+        "proof has {num_evaluations} MLE evaluations, but {num_commitments} intermediate commitments"
+    ))]
+    MissingEvaluations {
+        /// The number of MLE evaluations
+        num_evaluations: usize,
+        /// The number of commitments to intermediate MLEs
+        num_commitments: usize,
+    },
+}
+
+/// A stage of proof generation, reported to the progress callback of [`ProveOptions::progress`].
+///
+/// Stages are ordered in the order they occur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ProofStage {
+    /// Evaluating the query result and the intermediate columns of the proof
+    Evaluating,
+    /// Committing to the intermediate columns
+    Committing,
+    /// Proving the sumcheck rounds
+    Sumcheck,
+    /// Evaluating the committed columns and proving their evaluations
+    Finalizing,
+}
+
+/// The overall fraction of proof generation that is complete when the given sumcheck round starts.
+#[allow(clippy::cast_precision_loss)]
+fn sumcheck_progress(round: usize, num_rounds: usize) -> f32 {
+    // The number of rounds is the log of the table length, so these casts are exact.
+    0.4 + 0.4 * round as f32 / num_rounds as f32
+}
+
+/// Check that every column referenced by the query has the row count of its table.
+pub(super) fn check_row_counts<S: Scalar>(
+    expr: &impl ProofPlan,
+    accessor: &impl DataAccessor<S>,
+) -> Result<(), ProveError> {
+    expr.get_column_references()
+        .into_iter()
+        .try_for_each(|col_ref| {
+            let expected = accessor.get_length(col_ref.table_ref());
+            let actual = accessor.get_column(col_ref).len();
+            if expected == actual {
+                Ok(())
+            } else {
+                Err(ProveError::RowCountMismatch {
+                    column: col_ref.column_id(),
+                    expected,
+                    actual,
+                })
+            }
+        })
+}
+
+/// Whether `value` fits in a column of type `column_type`
+fn fits_column_type<S: Scalar>(value: S, column_type: ColumnType) -> bool {
+    match column_type {
+        ColumnType::Boolean => TryInto::<bool>::try_into(value).is_ok(),
+        ColumnType::TinyInt => TryInto::<i8>::try_into(value).is_ok(),
+        ColumnType::SmallInt => TryInto::<i16>::try_into(value).is_ok(),
+        ColumnType::Int => TryInto::<i32>::try_into(value).is_ok(),
+        ColumnType::BigInt | ColumnType::TimestampTZ(_, _) => {
+            TryInto::<i64>::try_into(value).is_ok()
+        }
+        ColumnType::UInt64 => TryInto::<u64>::try_into(value).is_ok(),
+        ColumnType::Int128 => TryInto::<i128>::try_into(value).is_ok(),
+        ColumnType::Decimal75(_, _)
+        | ColumnType::Scalar
+        | ColumnType::VarChar
+        | ColumnType::FixedBytes(_) => true,
+    }
+}
+
+/// Check that every value of the result fits the type of its column.
+///
+/// Expressions are evaluated exactly in the scalar field, so a result value can leave the range of its type.
+/// The verifier cannot decode such a result, so the prover reports the first such value instead of proving it.
+fn check_result_overflow<S: Scalar>(
+    result_schema: &[ColumnField],
+    result: &Table<'_, S>,
+) -> Result<(), ProveError> {
+    result_schema
+        .iter()
+        .zip(result.inner_table().values())
+        .try_for_each(|(field, column)| {
+            // Columns of any other variant hold values of their type already
+            let Column::Scalar(values) = column else {
+                return Ok(());
+            };
+            match values
+                .iter()
+                .position(|&value| !fits_column_type(value, field.data_type()))
+            {
+                Some(row) => Err(ProveError::ResultOverflow {
+                    column: field.name(),
+                    row,
+                    column_type: field.data_type(),
+                }),
+                None => Ok(()),
+            }
+        })
+}
+
+/// A query that has been evaluated for proving, along with the intermediate MLEs of its proof.
+///
+/// Proving is split into stages: evaluating the query, committing to the intermediate MLEs,
+/// proving the sumcheck, and proving the evaluations of the committed MLEs.
+/// Each stage is a method that continues the transcript left by the previous stage,
+/// so running them in order creates the same proof as [`QueryProof::try_new`].
+pub(super) struct EvaluatedQuery<'a, CP: CommitmentEvaluationProof> {
+    pub(super) builder: FinalRoundBuilder<'a, CP::Scalar>,
+    pub(super) result: PartiallyDisclosedResult<CP::Commitment>,
+    one_evaluation_lengths: Vec<usize>,
+    range_length: usize,
+    min_row_num: usize,
+    input_row_count: usize,
+}
+
+impl<'a, CP: CommitmentEvaluationProof> EvaluatedQuery<'a, CP>
+where
+    CP::Scalar: 'a,
+{
+    /// Evaluate the query result and the intermediate MLEs of the proof.
+    ///
+    /// Returns the transcript as it is once the result has been sent and the post-result challenges drawn.
+    /// The result discloses every column except `hidden_columns`.
+    /// If `check_overflow` is set, a result value that does not fit the type of its column is reported
+    /// with [`ProveError::ResultOverflow`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query evaluates to no sumcheck variables, which cannot happen.
+    pub(super) fn try_new<T: Transcript>(
         expr: &(impl ProofPlan + Serialize),
-        accessor: &impl DataAccessor<CP::Scalar>,
+        accessor: &'a impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
-    ) -> (Self, ProvableQueryResult) {
+        alloc: &'a Bump,
+        hidden_columns: &IndexSet<Identifier>,
+        check_overflow: bool,
+    ) -> Result<(Self, T), ProveError> {
+        check_row_counts(expr, accessor)?;
+        let result_schema = expr.get_column_result_fields();
+        let hidden_column_indexes = hidden_column_indexes(&result_schema, hidden_columns)?;
+
         let (min_row_num, max_row_num) = get_index_range(accessor, &expr.get_table_references());
         let initial_range_length = max_row_num - min_row_num;
-        let alloc = Bump::new();
+        let input_row_count = get_input_row_count(accessor, &expr.get_table_references());
 
         let total_col_refs = expr.get_column_references();
         let table_map: IndexMap<TableRef, Table<CP::Scalar>> = expr
@@ -93,8 +345,34 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             .collect();
 
         // Evaluate query result
-        let (query_result, one_evaluation_lengths) = expr.result_evaluate(&alloc, &table_map);
-        let provable_result = query_result.into();
+        let (query_result, one_evaluation_lengths) = expr.result_evaluate(alloc, &table_map);
+        let result_length = query_result.num_rows();
+        if check_overflow {
+            check_result_overflow(&result_schema, &query_result)?;
+        }
+        let (hidden_result_columns, disclosed_result_columns): (Vec<_>, Vec<_>) = query_result
+            .into_inner()
+            .into_values()
+            .enumerate()
+            .partition(|(index, _)| hidden_column_indexes.contains(index));
+        let hidden_result_columns: Vec<_> = hidden_result_columns
+            .into_iter()
+            .map(|(_, column)| column)
+            .collect();
+        let disclosed_result_columns: Vec<_> = disclosed_result_columns
+            .into_iter()
+            .map(|(_, column)| column)
+            .collect();
+        let provable_result =
+            ProvableQueryResult::new(result_length as u64, &disclosed_result_columns);
+        let hidden_commitments = CP::Commitment::compute_commitments(
+            &hidden_result_columns
+                .iter()
+                .map(CommittableColumn::from)
+                .collect::<Vec<_>>(),
+            min_row_num,
+            setup,
+        );
 
         // Prover First Round
         let mut first_round_builder = FirstRoundBuilder::new();
@@ -106,16 +384,22 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             .max()
             .expect("Will always have at least one element"); // safe to unwrap because we have at least one element
 
-        let num_sumcheck_variables = cmp::max(log2_up(range_length), 1);
+        let num_sumcheck_variables = num_sumcheck_variables(range_length);
         assert!(num_sumcheck_variables > 0);
 
         // construct a transcript for the proof
-        let mut transcript: Keccak256Transcript = make_transcript(
+        let mut transcript: T = make_transcript(
             expr,
             &provable_result,
             range_length,
             min_row_num,
             &one_evaluation_lengths,
+            input_row_count,
+        );
+        extend_transcript_with_hidden_columns(
+            &mut transcript,
+            &hidden_column_indexes,
+            &hidden_commitments,
         );
 
         // These are the challenges that will be consumed by the proof
@@ -134,36 +418,90 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             builder.produce_anchored_mle(accessor.get_column(col_ref));
         }
 
-        expr.final_round_evaluate(&mut builder, &alloc, &table_map);
+        expr.final_round_evaluate(&mut builder, alloc, &table_map);
 
-        let num_sumcheck_variables = builder.num_sumcheck_variables();
+        // the hidden result columns are opened along with the other MLEs,
+        // against the commitments sent with the result
+        for column in hidden_result_columns {
+            builder.produce_anchored_mle(column);
+        }
 
-        // commit to any intermediate MLEs
-        let commitments = builder.commit_intermediate_mles(min_row_num, setup);
+        let query = Self {
+            builder,
+            result: PartiallyDisclosedResult {
+                disclosed: provable_result,
+                hidden_column_indexes,
+                hidden_commitments,
+            },
+            one_evaluation_lengths,
+            range_length,
+            min_row_num,
+            input_row_count,
+        };
+        Ok((query, transcript))
+    }
 
-        // add the commitments, bit distributions and one evaluation lengths to the proof
-        extend_transcript(&mut transcript, &commitments, builder.bit_distributions());
+    /// Commit to the intermediate MLEs and add the commitments and bit distributions to the transcript.
+    pub(super) fn commit_intermediate_mles(
+        &self,
+        transcript: &mut impl Transcript,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> Vec<CP::Commitment> {
+        let commitments = self
+            .builder
+            .commit_intermediate_mles(self.min_row_num, setup);
+        extend_transcript(transcript, &commitments, self.builder.bit_distributions());
+        commitments
+    }
 
+    /// Create the sumcheck proof, writing the point it reduces to into `evaluation_point`.
+    ///
+    /// `evaluation_point` must have one entry per sumcheck variable.
+    /// `on_round` is called with the index of each round before it is proven.
+    pub(super) fn prove_sumcheck(
+        &self,
+        transcript: &mut impl Transcript,
+        evaluation_point: &mut [CP::Scalar],
+        on_round: impl FnMut(usize),
+    ) -> SumcheckProof<CP::Scalar> {
         // construct the sumcheck polynomial
-        let num_random_scalars = num_sumcheck_variables + builder.num_sumcheck_subpolynomials();
+        let num_sumcheck_variables = self.builder.num_sumcheck_variables();
+        let num_random_scalars =
+            num_sumcheck_variables + self.builder.num_sumcheck_subpolynomials();
         let random_scalars: Vec<_> =
             core::iter::repeat_with(|| transcript.scalar_challenge_as_be())
                 .take(num_random_scalars)
                 .collect();
-        let poly = builder.make_sumcheck_polynomial(&SumcheckRandomScalars::new(
-            &random_scalars,
-            range_length,
-            num_sumcheck_variables,
-        ));
-
-        // create the sumcheck proof -- this is the main part of proving a query
-        let mut evaluation_point = vec![Zero::zero(); poly.num_variables];
-        let sumcheck_proof = SumcheckProof::create(&mut transcript, &mut evaluation_point, &poly);
+        let poly = self
+            .builder
+            .make_sumcheck_polynomial(&SumcheckRandomScalars::new(
+                &random_scalars,
+                self.range_length,
+                num_sumcheck_variables,
+            ));
+        SumcheckProof::create_with_progress(transcript, evaluation_point, &poly, on_round)
+    }
 
+    /// Evaluate the MLEs used in sumcheck, except for the result columns, at `evaluation_point`
+    /// and prove the evaluations.
+    ///
+    /// `evaluation_vec` and `folded_mle` are scratch buffers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of MLEs and evaluations differ, which cannot happen.
+    pub(super) fn prove_evaluations(
+        &self,
+        transcript: &mut impl Transcript,
+        evaluation_point: &[CP::Scalar],
+        setup: &CP::ProverPublicSetup<'_>,
+        evaluation_vec: &mut Vec<CP::Scalar>,
+        folded_mle: &mut Vec<CP::Scalar>,
+    ) -> (Vec<CP::Scalar>, CP) {
         // evaluate the MLEs used in sumcheck except for the result columns
-        let mut evaluation_vec = vec![Zero::zero(); range_length];
-        compute_evaluation_vector(&mut evaluation_vec, &evaluation_point);
-        let pcs_proof_evaluations = builder.evaluate_pcs_proof_mles(&evaluation_vec);
+        let evaluation_vec = zeroed(evaluation_vec, self.range_length);
+        compute_evaluation_vector(evaluation_vec, evaluation_point);
+        let pcs_proof_evaluations = self.builder.evaluate_pcs_proof_mles(evaluation_vec);
 
         // commit to the MLE evaluations
         transcript.extend_canonical_serialize_as_le(&pcs_proof_evaluations);
@@ -175,31 +513,237 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
                 .take(pcs_proof_evaluations.len())
                 .collect();
 
-        assert_eq!(random_scalars.len(), builder.pcs_proof_mles().len());
-        let mut folded_mle = vec![Zero::zero(); range_length];
-        for (multiplier, evaluator) in random_scalars.iter().zip(builder.pcs_proof_mles().iter()) {
-            evaluator.mul_add(&mut folded_mle, multiplier);
+        assert_eq!(random_scalars.len(), self.builder.pcs_proof_mles().len());
+        let folded_mle = zeroed(folded_mle, self.range_length);
+        for (multiplier, evaluator) in random_scalars
+            .iter()
+            .zip(self.builder.pcs_proof_mles().iter())
+        {
+            evaluator.mul_add(folded_mle, multiplier);
         }
 
         // finally, form the inner product proof of the MLEs' evaluations
         let evaluation_proof = CP::new(
-            &mut transcript,
-            &folded_mle,
-            &evaluation_point,
-            min_row_num as u64,
+            transcript,
+            folded_mle,
+            evaluation_point,
+            self.min_row_num as u64,
             setup,
         );
+        (pcs_proof_evaluations, evaluation_proof)
+    }
 
-        let proof = Self {
-            bit_distributions: builder.bit_distributions().to_vec(),
-            one_evaluation_lengths,
+    /// Assemble the proof from the outputs of the other stages.
+    pub(super) fn proof(
+        &self,
+        commitments: Vec<CP::Commitment>,
+        sumcheck_proof: SumcheckProof<CP::Scalar>,
+        pcs_proof_evaluations: Vec<CP::Scalar>,
+        evaluation_proof: CP,
+    ) -> QueryProof<CP> {
+        QueryProof {
+            bit_distributions: self.builder.bit_distributions().to_vec(),
+            one_evaluation_lengths: self.one_evaluation_lengths.clone(),
             commitments,
             sumcheck_proof,
             pcs_proof_evaluations,
             evaluation_proof,
-            range_length,
+            range_length: self.range_length,
+            input_row_count: self.input_row_count,
+        }
+    }
+}
+
+/// The proof for a query.
+///
+/// Note: Because the class is deserialized from untrusted data, it
+/// cannot maintain any invariant on its data members; hence, they are
+/// all public so as to allow for easy manipulation for testing.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct QueryProof<CP: CommitmentEvaluationProof> {
+    /// Bit distributions
+    pub bit_distributions: Vec<BitDistribution>,
+    /// One evaluation lengths
+    pub one_evaluation_lengths: Vec<usize>,
+    /// Commitments to the intermediate MLEs, in the order the proof plan produces them
+    ///
+    /// The order depends only on the plan, not on the order of the columns in the accessor's tables,
+    /// so the same query over the same data always serializes to the same bytes.
+    pub commitments: Vec<CP::Commitment>,
+    /// Sumcheck Proof
+    pub sumcheck_proof: SumcheckProof<CP::Scalar>,
+    /// MLEs used in sumcheck except for the result columns
+    pub pcs_proof_evaluations: Vec<CP::Scalar>,
+    /// Inner product proof of the MLEs' evaluations
+    pub evaluation_proof: CP,
+    /// Length of the range of generators we use
+    pub range_length: usize,
+    /// The total number of rows of the tables the query reads
+    pub input_row_count: usize,
+}
+
+impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
+    /// Create a new `QueryProof`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns referenced by the query do not have the row counts of their tables.
+    /// See [`QueryProof::try_new`] for a non-panicking version.
+    ///
+    /// Unlike [`QueryProof::try_new`], this does not check that the values of the result fit the types of their columns.
+    /// A result that overflows is proven anyway, and the verifier rejects it with [`QueryError::Overflow`].
+    pub fn new(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> (Self, ProvableQueryResult) {
+        let options = ProveOptions {
+            check_overflow: false,
+            ..ProveOptions::default()
+        };
+        let (proof, result) = Self::try_new_with_options(expr, accessor, setup, options)
+            .expect("Failed to create query proof");
+        (proof, result.disclosed)
+    }
+
+    /// Create a new `QueryProof`.
+    ///
+    /// Will error if the columns referenced by the query do not have the row counts of their tables,
+    /// or with [`ProveError::ResultOverflow`] if a value of the result does not fit the type of its column.
+    pub fn try_new(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> Result<(Self, ProvableQueryResult), ProveError> {
+        let (proof, result) =
+            Self::try_new_with_options(expr, accessor, setup, ProveOptions::default())?;
+        Ok((proof, result.disclosed))
+    }
+
+    /// Create a new `QueryProof` with the given [`ProveOptions`].
+    ///
+    /// The result discloses every column except the hidden columns of `options`,
+    /// and is verified with [`QueryProof::verify_partially_disclosed`].
+    /// If no column is hidden, its disclosed part is the result [`QueryProof::try_new`] creates.
+    ///
+    /// Will error if the columns referenced by the query do not have the row counts of their tables,
+    /// if a hidden column is not a column of the result, with [`ProveError::Cancelled`] if the proof is cancelled,
+    /// or with [`ProveError::ResultOverflow`] if the overflow check is enabled and a value of the result
+    /// does not fit the type of its column.
+    pub fn try_new_with_options(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        options: ProveOptions<'_, CP::Scalar>,
+    ) -> Result<(Self, PartiallyDisclosedResult<CP::Commitment>), ProveError> {
+        #[cfg(feature = "transcript-log")]
+        let mut options = options;
+        #[cfg(feature = "transcript-log")]
+        if let Some(log) = options.transcript_log.take() {
+            let (proof, result, transcript) = Self::try_new_with_transcript::<
+                RecordingTranscript<Keccak256Transcript>,
+            >(expr, accessor, setup, options)?;
+            (_, *log) = transcript.into_parts();
+            return Ok((proof, result));
+        }
+        let (proof, result, _) =
+            Self::try_new_with_transcript::<Keccak256Transcript>(expr, accessor, setup, options)?;
+        Ok((proof, result))
+    }
+
+    /// Create a new `QueryProof` using a transcript of type `T`, which is returned along with the proof.
+    ///
+    /// `T` must produce the same challenges as [`Keccak256Transcript`], or the proof will not verify.
+    #[tracing::instrument(name = "QueryProof::try_new", level = "debug", skip_all)]
+    fn try_new_with_transcript<T: Transcript>(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        options: ProveOptions<'_, CP::Scalar>,
+    ) -> Result<(Self, PartiallyDisclosedResult<CP::Commitment>, T), ProveError> {
+        let ProveOptions {
+            scratch,
+            progress: mut report_progress,
+            should_cancel,
+            hidden_columns,
+            check_overflow,
+            ..
+        } = options;
+        let mut progress = |stage: ProofStage, fraction: f32| {
+            if let Some(report_progress) = report_progress.as_mut() {
+                report_progress(stage, fraction);
+            }
+        };
+        let check_cancelled = || {
+            if should_cancel.is_some_and(|should_cancel| should_cancel()) {
+                Err(ProveError::Cancelled)
+            } else {
+                Ok(())
+            }
         };
-        (proof, provable_result)
+        check_cancelled()?;
+        progress(ProofStage::Evaluating, 0.0);
+
+        let mut fresh_scratch = ProverScratch::new();
+        let ProverScratch {
+            alloc,
+            evaluation_point,
+            evaluation_vec,
+            folded_mle,
+        } = scratch.unwrap_or(&mut fresh_scratch);
+        alloc.reset();
+        let (mut query, mut transcript) = EvaluatedQuery::<CP>::try_new(
+            expr,
+            accessor,
+            setup,
+            alloc,
+            &hidden_columns,
+            check_overflow,
+        )?;
+
+        // commit to any intermediate MLEs
+        check_cancelled()?;
+        progress(ProofStage::Committing, 0.2);
+        let commitments = query.commit_intermediate_mles(&mut transcript, setup);
+        let mut metrics = core::mem::take(query.builder.metrics_mut());
+        metrics.record_commitments(query.builder.commitment_descriptor());
+
+        // create the sumcheck proof -- this is the main part of proving a query
+        check_cancelled()?;
+        let num_sumcheck_variables = query.builder.num_sumcheck_variables();
+        let evaluation_point = zeroed(evaluation_point, num_sumcheck_variables);
+        let sumcheck_proof = query.prove_sumcheck(&mut transcript, evaluation_point, |round| {
+            progress(
+                ProofStage::Sumcheck,
+                sumcheck_progress(round, num_sumcheck_variables),
+            );
+        });
+
+        metrics.record_sumcheck_rounds(num_sumcheck_variables);
+        check_cancelled()?;
+        progress(ProofStage::Finalizing, 0.8);
+
+        let (pcs_proof_evaluations, evaluation_proof) = query.prove_evaluations(
+            &mut transcript,
+            evaluation_point,
+            setup,
+            evaluation_vec,
+            folded_mle,
+        );
+        metrics.record_mle_evaluations(pcs_proof_evaluations.len());
+
+        let proof = query.proof(
+            commitments,
+            sumcheck_proof,
+            pcs_proof_evaluations,
+            evaluation_proof,
+        );
+        progress(ProofStage::Finalizing, 1.0);
+        #[cfg(feature = "prove-metrics")]
+        if let Some(metrics_out) = options.metrics {
+            *metrics_out = metrics;
+        }
+        Ok((proof, query.result, transcript))
     }
 
     #[tracing::instrument(name = "QueryProof::verify", level = "debug", skip_all, err)]
@@ -211,10 +755,326 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         result: &ProvableQueryResult,
         setup: &CP::VerifierPublicSetup<'_>,
     ) -> QueryResult<CP::Scalar> {
-        let owned_table_result = result.to_owned_table(&expr.get_column_result_fields())?;
+        self.verify_with_commitments(
+            expr,
+            accessor,
+            result,
+            (&[], &[]),
+            setup,
+            |column_references, inner_product_multipliers| {
+                self.referenced_commitments(accessor, column_references, inner_product_multipliers)
+            },
+        )
+    }
+
+    #[tracing::instrument(
+        name = "QueryProof::verify_partially_disclosed",
+        level = "debug",
+        skip_all,
+        err
+    )]
+    /// Verify a `QueryProof` of a [`PartiallyDisclosedResult`].
+    ///
+    /// The verified table holds only the disclosed columns, in the order they appear in the result.
+    /// Once this succeeds, each hidden column is attested to be the column of the true result
+    /// that its commitment in `result` commits to, without the column itself being sent.
+    /// The hidden columns are withheld but not hidden: their commitments are deterministic and not hiding,
+    /// and the proof opens their MLE evaluations, so a low-entropy column can be recovered by brute force.
+    /// See [`PartiallyDisclosedResult`].
+    /// Note: This does NOT transform the result!
+    pub fn verify_partially_disclosed(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &PartiallyDisclosedResult<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        self.verify_with_commitments(
+            expr,
+            accessor,
+            &result.disclosed,
+            (&result.hidden_column_indexes, &result.hidden_commitments),
+            setup,
+            |column_references, inner_product_multipliers| {
+                self.referenced_commitments(accessor, column_references, inner_product_multipliers)
+            },
+        )
+    }
+
+    #[tracing::instrument(name = "QueryProof::verify_and_decode", level = "debug", skip_all, err)]
+    /// Deserialize a result that was serialized with [`ProvableQueryResult::to_envelope_bytes`],
+    /// verify it against this proof and return the verified result table.
+    ///
+    /// The table is decoded from the same bytes that were verified, so there is no separate
+    /// step where unverified bytes could be decoded by mistake.
+    /// Note: This does NOT transform the result!
+    pub fn verify_and_decode(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        serialized_result: &[u8],
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<OwnedTable<CP::Scalar>, VerificationError> {
+        let result: ProvableQueryResult = try_from_envelope_bytes(serialized_result)?;
+        Ok(self.verify(expr, accessor, &result, setup)?.table)
+    }
+
+    #[tracing::instrument(name = "QueryProof::verify_streaming", level = "debug", skip_all, err)]
+    /// Verify a `QueryProof`, pulling the commitment of each referenced column from `commitment_source`.
+    ///
+    /// `commitment_source` is called exactly once per column, in the order of `expr.get_column_references()`,
+    /// with the index of the column in that order and its [`ColumnRef`].
+    /// Each commitment is folded into a single commitment as soon as it is received,
+    /// so only one column commitment needs to be held in memory at a time.
+    ///
+    /// Given the same commitments, the result is identical to that of [`Self::verify`].
+    /// Note: This does NOT transform the result!
+    pub fn verify_streaming(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl MetadataAccessor,
+        mut commitment_source: impl FnMut(usize, ColumnRef) -> CP::Commitment,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        self.verify_with_commitments(
+            expr,
+            accessor,
+            result,
+            (&[], &[]),
+            setup,
+            |column_references, inner_product_multipliers| {
+                let (column_multipliers, intermediate_multipliers) =
+                    inner_product_multipliers.split_at(column_references.len());
+                let mut folded_commitment = CP::Commitment::default();
+                for ((index, col), multiplier) in
+                    column_references.iter().enumerate().zip(column_multipliers)
+                {
+                    add_scaled_commitment(
+                        &mut folded_commitment,
+                        *multiplier,
+                        &commitment_source(index, *col),
+                    );
+                }
+                for (commitment, multiplier) in
+                    self.commitments.iter().zip(intermediate_multipliers)
+                {
+                    add_scaled_commitment(&mut folded_commitment, *multiplier, commitment);
+                }
+                (vec![folded_commitment], vec![CP::Scalar::ONE])
+            },
+        )
+    }
+
+    #[tracing::instrument(
+        name = "QueryProof::verify_with_commitment_source",
+        level = "debug",
+        skip_all,
+        err
+    )]
+    /// Verify a `QueryProof`, fetching the commitment of each referenced column from `commitment_source`.
+    ///
+    /// Only the columns in `expr.get_column_references()` are fetched, each exactly once,
+    /// so the source can be a store that holds the commitments of many more columns.
+    /// Given the same commitments, the result is identical to that of [`Self::verify`].
+    /// Note: This does NOT transform the result!
+    ///
+    /// # Errors
+    /// Returns [`VerificationError::MissingCommitment`] if the source does not have the commitment
+    /// of a referenced column, or [`VerificationError::Query`] if the proof does not verify.
+    pub fn verify_with_commitment_source(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl MetadataAccessor,
+        commitment_source: &impl CommitmentSource<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<QueryData<CP::Scalar>, VerificationError> {
+        let column_commitments = expr
+            .get_column_references()
+            .iter()
+            .map(|&column| {
+                commitment_source
+                    .get(column)
+                    .ok_or(VerificationError::MissingCommitment {
+                        table_ref: Box::new(column.table_ref()),
+                        column_id: column.column_id(),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(self.verify_with_commitments(
+            expr,
+            accessor,
+            result,
+            (&[], &[]),
+            setup,
+            |_, inner_product_multipliers| {
+                let commitments = column_commitments
+                    .into_iter()
+                    .chain(self.commitments.iter().cloned())
+                    .collect();
+                (commitments, inner_product_multipliers.to_vec())
+            },
+        )?)
+    }
+
+    #[tracing::instrument(
+        name = "QueryProof::verify_query_logic",
+        level = "debug",
+        skip_all,
+        err
+    )]
+    /// Verify only the query logic of a `QueryProof`, without checking the evaluation proof.
+    ///
+    /// This checks that the result and the claimed evaluations of the committed columns satisfy
+    /// the constraints of `expr`. It does not check that the claimed evaluations match any
+    /// commitments, so it only establishes the result in combination with
+    /// [`Self::verify_commitment_binding`]. Running both stages is equivalent to [`Self::verify`].
+    /// Note: This does NOT transform the result!
+    pub fn verify_query_logic(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl MetadataAccessor,
+        result: &ProvableQueryResult,
+    ) -> Result<OwnedTable<CP::Scalar>, VerificationError> {
+        let replay = self.replay_transcript(expr, accessor, result, (&[], &[]))?;
+        self.check_query_logic(expr, &replay)?;
+        Ok(replay.owned_table_result)
+    }
+
+    #[tracing::instrument(
+        name = "QueryProof::verify_commitment_binding",
+        level = "debug",
+        skip_all,
+        err
+    )]
+    /// Verify only that the claimed evaluations of a `QueryProof` match the commitments.
+    ///
+    /// This checks the evaluation proof against the commitments of the columns referenced by
+    /// `expr` and the intermediate commitments of the proof, without checking the query logic.
+    /// Running both this and [`Self::verify_query_logic`] is equivalent to [`Self::verify`].
+    ///
+    /// Both stages replay the transcript, including the rounds of the sumcheck proof,
+    /// so a proof whose sumcheck rounds are inconsistent is rejected by either stage.
+    pub fn verify_commitment_binding(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: &ProvableQueryResult,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<(), VerificationError> {
+        let mut replay = self.replay_transcript(expr, accessor, result, (&[], &[]))?;
+        self.check_commitment_binding(
+            &mut replay,
+            &[],
+            setup,
+            |column_references, multipliers| {
+                self.referenced_commitments(accessor, column_references, multipliers)
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Recompute the Fiat-Shamir challenges that verification derives, in the order they are drawn.
+    ///
+    /// Only the public inputs of the transcript are needed: the query, the result, the lengths and offsets
+    /// of the tables, and the messages of the proof. Neither the data of the tables nor their commitments are read.
+    /// The challenges are
+    /// - the challenges drawn after the result is appended, which the proof plan consumes,
+    /// - the random scalars of the sumcheck, i.e. the entrywise multipliers followed by the subpolynomial multipliers,
+    /// - the challenge of each sumcheck round, which together are the point the MLEs are evaluated at,
+    /// - the multipliers that batch the MLE evaluations for the evaluation proof.
+    ///
+    /// The challenges drawn inside the evaluation proof itself depend on the commitment scheme and are not included.
+    /// This is the reference that verifiers in other languages must match.
+    ///
+    /// Will error if the proof does not have the shape that `expr` requires or its sumcheck rounds are inconsistent,
+    /// since verification stops before drawing the remaining challenges.
+    pub fn replay_challenges(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl MetadataAccessor,
+        result: &ProvableQueryResult,
+    ) -> Result<Vec<CP::Scalar>, VerificationError> {
+        let replay = self.replay_transcript(expr, accessor, result, (&[], &[]))?;
+        Ok(replay
+            .post_result_challenges
+            .into_iter()
+            .chain(replay.random_scalars)
+            .chain(replay.evaluation_point)
+            .chain(replay.evaluation_random_scalars)
+            .collect())
+    }
+
+    /// The commitments of the referenced columns followed by the intermediate commitments,
+    /// batched by the inner product multipliers.
+    fn referenced_commitments(
+        &self,
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        column_references: &IndexSet<ColumnRef>,
+        inner_product_multipliers: &[CP::Scalar],
+    ) -> (Vec<CP::Commitment>, Vec<CP::Scalar>) {
+        let commitments = column_references
+            .iter()
+            .map(|col| accessor.get_commitment(*col))
+            .chain(self.commitments.iter().cloned())
+            .collect();
+        (commitments, inner_product_multipliers.to_vec())
+    }
+
+    /// Verify a `QueryProof`, batching the commitments returned by `pcs_proof_commitments`.
+    ///
+    /// `pcs_proof_commitments` receives the referenced columns and the inner product multipliers,
+    /// and returns the commitments and batching factors to check the evaluation proof against.
+    /// `hidden` holds the positions of the hidden result columns and their commitments.
+    fn verify_with_commitments(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl MetadataAccessor,
+        result: &ProvableQueryResult,
+        hidden: (&[usize], &[CP::Commitment]),
+        setup: &CP::VerifierPublicSetup<'_>,
+        pcs_proof_commitments: impl FnOnce(
+            &IndexSet<ColumnRef>,
+            &[CP::Scalar],
+        ) -> (Vec<CP::Commitment>, Vec<CP::Scalar>),
+    ) -> QueryResult<CP::Scalar> {
+        let mut replay = self.replay_transcript(expr, accessor, result, hidden)?;
+        self.check_query_logic(expr, &replay)?;
+        self.check_commitment_binding(&mut replay, hidden.1, setup, pcs_proof_commitments)?;
+        let verification_hash = replay.transcript.challenge_as_le();
+        Ok(QueryData {
+            table: replay.owned_table_result,
+            verification_hash,
+            result_schema: expr.get_column_result_fields().into_iter().collect(),
+        })
+    }
+
+    /// Check the shape of the proof and replay its transcript up to the evaluation proof.
+    ///
+    /// This is shared by both stages of verification.
+    /// `result` holds the disclosed result columns, and `hidden` the positions and commitments of the rest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of sumcheck variables is zero, which cannot happen for a proof over a non-empty range.
+    fn replay_transcript(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl MetadataAccessor,
+        result: &ProvableQueryResult,
+        (hidden_column_indexes, hidden_commitments): (&[usize], &[CP::Commitment]),
+    ) -> Result<TranscriptReplay<CP::Scalar>, QueryError> {
+        let query_result_schema = expr.get_column_result_fields();
+        let disclosed_result_schema = disclosed_result_schema(
+            &query_result_schema,
+            hidden_column_indexes,
+            hidden_commitments.len(),
+        )?;
+        let owned_table_result = result.to_owned_table(&disclosed_result_schema)?;
         let table_refs = expr.get_table_references();
         let (min_row_num, _) = get_index_range(accessor, &table_refs);
-        let num_sumcheck_variables = cmp::max(log2_up(self.range_length), 1);
+        let num_sumcheck_variables = self.num_variables();
         assert!(num_sumcheck_variables > 0);
 
         // validate bit decompositions
@@ -235,12 +1095,18 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         let counts = builder.counts()?;
 
         // verify sizes
-        if !self.validate_sizes(&counts) {
+        if !self.validate_sizes(&counts, hidden_column_indexes.len()) {
             Err(ProofError::VerificationError {
                 error: "invalid proof size",
             })?;
         }
 
+        if self.input_row_count != get_input_row_count(accessor, &table_refs) {
+            Err(ProofError::VerificationError {
+                error: "input row count does not match the tables",
+            })?;
+        }
+
         // construct a transcript for the proof
         let mut transcript: Keccak256Transcript = make_transcript(
             expr,
@@ -248,6 +1114,12 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             self.range_length,
             min_row_num,
             &self.one_evaluation_lengths,
+            self.input_row_count,
+        );
+        extend_transcript_with_hidden_columns(
+            &mut transcript,
+            hidden_column_indexes,
+            hidden_commitments,
         );
 
         // These are the challenges that will be consumed by the proof
@@ -269,8 +1141,6 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             core::iter::repeat_with(|| transcript.scalar_challenge_as_be())
                 .take(num_random_scalars)
                 .collect();
-        let sumcheck_random_scalars =
-            SumcheckRandomScalars::new(&random_scalars, self.range_length, num_sumcheck_variables);
 
         // verify sumcheck up to the evaluation check
         let poly_info = CompositePolynomialInfo {
@@ -298,10 +1168,42 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         // Always prepend input lengths to the one evaluation lengths
         let table_length_map = table_refs
             .iter()
-            .map(|table_ref| (table_ref, accessor.get_length(*table_ref)))
-            .collect::<IndexMap<_, _>>();
+            .map(|table_ref| (*table_ref, accessor.get_length(*table_ref)))
+            .collect();
 
-        let one_evaluation_lengths = table_length_map
+        Ok(TranscriptReplay {
+            owned_table_result,
+            result_length: result.table_length(),
+            hidden_column_indexes: hidden_column_indexes.to_vec(),
+            column_references,
+            table_length_map,
+            min_row_num,
+            transcript,
+            post_result_challenges,
+            random_scalars,
+            evaluation_point: subclaim.evaluation_point,
+            expected_evaluation: subclaim.expected_evaluation,
+            evaluation_random_scalars,
+        })
+    }
+
+    /// Check that the result and the claimed evaluations satisfy the constraints of `expr`.
+    fn check_query_logic(
+        &self,
+        expr: &impl ProofPlan,
+        replay: &TranscriptReplay<CP::Scalar>,
+    ) -> Result<(), QueryError> {
+        // the evaluations of the hidden result columns follow the evaluations the query plan consumes
+        let (pcs_proof_evaluations, hidden_evaluations) = self
+            .pcs_proof_evaluations
+            .split_at(self.pcs_proof_evaluations.len() - replay.hidden_column_indexes.len());
+        let sumcheck_random_scalars = SumcheckRandomScalars::new(
+            &replay.random_scalars,
+            self.range_length,
+            self.num_variables(),
+        );
+        let one_evaluation_lengths = replay
+            .table_length_map
             .values()
             .chain(self.one_evaluation_lengths.iter())
             .copied();
@@ -310,42 +1212,64 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         let sumcheck_evaluations = SumcheckMleEvaluations::new(
             self.range_length,
             one_evaluation_lengths,
-            &subclaim.evaluation_point,
+            &replay.evaluation_point,
             &sumcheck_random_scalars,
-            &self.pcs_proof_evaluations,
+            pcs_proof_evaluations,
         );
-        let one_eval_map: IndexMap<TableRef, CP::Scalar> = table_length_map
+        let one_eval_map: IndexMap<TableRef, CP::Scalar> = replay
+            .table_length_map
             .iter()
-            .map(|(table_ref, length)| (**table_ref, sumcheck_evaluations.one_evaluations[length]))
+            .map(|(table_ref, length)| (*table_ref, sumcheck_evaluations.one_evaluations[length]))
             .collect();
         let mut builder = VerificationBuilder::new(
-            min_row_num,
+            replay.min_row_num,
             sumcheck_evaluations,
             &self.bit_distributions,
             sumcheck_random_scalars.subpolynomial_multipliers,
-            &evaluation_random_scalars,
-            post_result_challenges,
+            &replay.evaluation_random_scalars[..pcs_proof_evaluations.len()],
+            replay.post_result_challenges.clone(),
             self.one_evaluation_lengths.clone(),
         );
 
-        let pcs_proof_commitments: Vec<_> = column_references
+        let evaluation_accessor: IndexMap<_, _> = replay
+            .column_references
             .iter()
-            .map(|col| accessor.get_commitment(*col))
-            .chain(self.commitments.iter().cloned())
-            .collect();
-        let evaluation_accessor: IndexMap<_, _> = column_references
-            .into_iter()
-            .map(|col| (col, builder.consume_anchored_mle()))
+            .map(|col| (*col, builder.consume_anchored_mle()))
             .collect();
 
         let verifier_evaluations = expr.verifier_evaluate(
             &mut builder,
             &evaluation_accessor,
-            Some(&owned_table_result),
+            Some(&replay.owned_table_result),
             &one_eval_map,
         )?;
-        // compute the evaluation of the result MLEs
-        let result_evaluations = owned_table_result.mle_evaluations(&subclaim.evaluation_point);
+        // a result can never have more rows than the sumcheck variables can index,
+        // which notably rules out nonempty results over empty tables
+        if replay.result_length > 1 << replay.evaluation_point.len() {
+            Err(ProofError::VerificationError {
+                error: "result has more rows than the proof can attest to",
+            })?;
+        }
+        // compute the evaluation of the disclosed result MLEs,
+        // and take the evaluations of the hidden ones from the proof
+        let result_evaluation_vector =
+            EvaluationVector::new(&replay.evaluation_point, replay.result_length);
+        let mut disclosed_evaluations = replay
+            .owned_table_result
+            .mle_evaluations(&result_evaluation_vector)
+            .into_iter();
+        let mut hidden_evaluations = hidden_evaluations.iter().copied();
+        let num_result_columns =
+            replay.owned_table_result.num_columns() + replay.hidden_column_indexes.len();
+        let result_evaluations: Vec<_> = (0..num_result_columns)
+            .filter_map(|index| {
+                if replay.hidden_column_indexes.contains(&index) {
+                    hidden_evaluations.next()
+                } else {
+                    disclosed_evaluations.next()
+                }
+            })
+            .collect();
         // check the evaluation of the result MLEs
         if verifier_evaluations.column_evals() != result_evaluations {
             Err(ProofError::VerificationError {
@@ -354,42 +1278,201 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         }
 
         // perform the evaluation check of the sumcheck polynomial
-        if builder.sumcheck_evaluation() != subclaim.expected_evaluation {
+        if builder.sumcheck_evaluation() != replay.expected_evaluation {
             Err(ProofError::VerificationError {
                 error: "sumcheck evaluation check failed",
             })?;
         }
+        Ok(())
+    }
 
-        // finally, check the MLE evaluations with the inner product proof
-        let product = builder.folded_pcs_proof_evaluation();
+    /// Check the claimed evaluations against the commitments returned by `pcs_proof_commitments`
+    /// with the evaluation proof.
+    ///
+    /// The claimed evaluations are folded with the same multipliers the verification builder
+    /// uses, so this stage does not depend on the query logic.
+    fn check_commitment_binding(
+        &self,
+        replay: &mut TranscriptReplay<CP::Scalar>,
+        hidden_commitments: &[CP::Commitment],
+        setup: &CP::VerifierPublicSetup<'_>,
+        pcs_proof_commitments: impl FnOnce(
+            &IndexSet<ColumnRef>,
+            &[CP::Scalar],
+        ) -> (Vec<CP::Commitment>, Vec<CP::Scalar>),
+    ) -> Result<(), QueryError> {
+        let product = replay
+            .evaluation_random_scalars
+            .iter()
+            .zip(&self.pcs_proof_evaluations)
+            .map(|(multiplier, evaluation)| *multiplier * *evaluation)
+            .fold(CP::Scalar::ZERO, |acc, term| acc + term);
+        // the hidden result columns are opened last, against the commitments sent with the result
+        let (multipliers, hidden_multipliers) = replay
+            .evaluation_random_scalars
+            .split_at(replay.evaluation_random_scalars.len() - hidden_commitments.len());
+        let (mut pcs_proof_commitments, mut batching_factors) =
+            pcs_proof_commitments(&replay.column_references, multipliers);
+        pcs_proof_commitments.extend_from_slice(hidden_commitments);
+        batching_factors.extend_from_slice(hidden_multipliers);
         self.evaluation_proof
             .verify_batched_proof(
-                &mut transcript,
+                &mut replay.transcript,
                 &pcs_proof_commitments,
-                builder.inner_product_multipliers(),
+                &batching_factors,
                 &product,
-                &subclaim.evaluation_point,
-                min_row_num as u64,
+                &replay.evaluation_point,
+                replay.min_row_num as u64,
                 self.range_length,
                 setup,
             )
             .map_err(|_e| ProofError::VerificationError {
                 error: "Inner product proof of MLE evaluations failed",
             })?;
+        Ok(())
+    }
 
-        let verification_hash = transcript.challenge_as_le();
-        Ok(QueryData {
-            table: owned_table_result,
-            verification_hash,
-        })
+    /// The number of variables of the sumcheck polynomial, which is determined by the range length
+    #[must_use]
+    pub fn num_variables(&self) -> usize {
+        num_sumcheck_variables(self.range_length)
+    }
+
+    /// The total number of rows of the tables the query reads.
+    ///
+    /// The count is bound into the transcript, and the verifier checks it against the lengths of the tables
+    /// in its accessor, which the table commitments attest. So once the proof verifies, this is the attested
+    /// size of the input rather than a value the prover chose.
+    #[must_use]
+    pub fn input_row_count(&self) -> usize {
+        self.input_row_count
     }
 
-    fn validate_sizes(&self, counts: &ProofCounts) -> bool {
+    /// The number of rounds in the sumcheck proof. Each round binds one variable.
+    #[must_use]
+    pub fn num_sumcheck_rounds(&self) -> usize {
+        self.num_variables()
+    }
+
+    /// The number of commitments to intermediate MLEs in the proof
+    ///
+    /// This does not include the commitments to the columns referenced by the query,
+    /// since the verifier gets those from its accessor.
+    #[must_use]
+    pub fn num_commitments(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Estimate the cost of verifying this proof from its shape: the number of sumcheck rounds,
+    /// the size of the sumcheck proof and the number of MLE evaluations.
+    ///
+    /// This is cheap, and does not need the query, the accessor or the setup, so a router can use it
+    /// to compare proofs before verifying any of them. The estimate grows with the actual cost of verification,
+    /// but is not an accurate prediction of its time. See [`VerifyCost`].
+    #[must_use]
+    pub fn estimate_verify_cost(&self) -> VerifyCost {
+        VerifyCost::new(
+            self.num_sumcheck_rounds(),
+            self.sumcheck_proof.num_coefficients(),
+            self.pcs_proof_evaluations.len(),
+        )
+    }
+
+    /// Check that the proof is structurally valid, without the query, the accessor or the setup.
+    ///
+    /// Canonical scalar encodings and length framing are already enforced when a proof is deserialized.
+    /// This checks that the parts of the proof are consistent with each other: the bit distributions are valid,
+    /// the one evaluation lengths fit in the range, the sumcheck proof has the same number of coefficients
+    /// in each round and there is an MLE evaluation for every intermediate commitment.
+    ///
+    /// This is cheap, so it can be used to reject malformed proofs before verifying them.
+    /// A well-formed proof can still fail verification, since none of the cryptographic checks are done.
+    ///
+    /// # Errors
+    /// Returns the first structural problem found.
+    pub fn check_well_formed(&self) -> Result<(), MalformedError> {
+        if let Some(index) = self
+            .bit_distributions
+            .iter()
+            .position(|dist| !dist.is_valid())
+        {
+            return Err(MalformedError::InvalidBitDistribution { index });
+        }
+        if let Some(&length) = self
+            .one_evaluation_lengths
+            .iter()
+            .find(|&&length| length > self.range_length)
+        {
+            return Err(MalformedError::OneEvaluationLengthOutOfRange {
+                length,
+                range_length: self.range_length,
+            });
+        }
+        let num_coefficients = self.sumcheck_proof.num_coefficients();
+        let num_rounds = self.num_sumcheck_rounds();
+        // Every round polynomial has degree at least 2, so at least three coefficients.
+        if num_coefficients % num_rounds != 0 || num_coefficients / num_rounds < 3 {
+            return Err(MalformedError::InvalidSumcheckProofSize {
+                num_coefficients,
+                num_rounds,
+            });
+        }
+        if self.pcs_proof_evaluations.len() < self.commitments.len() {
+            return Err(MalformedError::MissingEvaluations {
+                num_evaluations: self.pcs_proof_evaluations.len(),
+                num_commitments: self.commitments.len(),
+            });
+        }
+        Ok(())
+    }
+
+    fn validate_sizes(&self, counts: &ProofCounts, num_hidden_result_columns: usize) -> bool {
         self.commitments.len() == counts.intermediate_mles
-            && self.pcs_proof_evaluations.len() == counts.intermediate_mles + counts.anchored_mles
+            && self.pcs_proof_evaluations.len()
+                == counts.intermediate_mles + counts.anchored_mles + num_hidden_result_columns
+    }
+}
+
+impl<CP: CommitmentEvaluationProof> QueryProof<CP>
+where
+    Self: Serialize,
+{
+    /// Returns the length of the postcard serialization of this proof, without serializing it.
+    ///
+    /// See [`proof_envelope::serialized_size`].
+    #[must_use]
+    pub fn serialized_size(&self) -> usize {
+        proof_envelope::serialized_size(self)
     }
 }
 
+/// The state of the verifier after replaying the transcript of a proof up to the evaluation proof
+struct TranscriptReplay<S: Scalar> {
+    /// The disclosed result columns
+    owned_table_result: OwnedTable<S>,
+    /// The number of rows of the result, which is known even when every column is hidden
+    result_length: usize,
+    hidden_column_indexes: Vec<usize>,
+    column_references: IndexSet<ColumnRef>,
+    table_length_map: IndexMap<TableRef, usize>,
+    min_row_num: usize,
+    transcript: Keccak256Transcript,
+    post_result_challenges: Vec<S>,
+    random_scalars: Vec<S>,
+    evaluation_point: Vec<S>,
+    expected_evaluation: S,
+    evaluation_random_scalars: Vec<S>,
+}
+
+/// Adds `multiplier * commitment` to `accumulator`.
+fn add_scaled_commitment<C: Commitment>(
+    accumulator: &mut C,
+    multiplier: C::Scalar,
+    commitment: &C,
+) {
+    *accumulator += multiplier * commitment;
+}
+
 /// Creates a transcript using the Merlin library.
 ///
 /// This function is used to produce a transcript for a proof expression
@@ -411,6 +1494,8 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 ///
 /// * `one_evaluation_lengths` - A slice of `usize` values that represent unexpected intermediate table lengths
 ///
+/// * `input_row_count` - The total number of rows of the tables the query reads, as a `usize`.
+///
 /// # Returns
 /// This function returns a `merlin::Transcript`. The transcript is a record
 /// of all the operations and data involved in creating a proof.
@@ -421,6 +1506,7 @@ fn make_transcript<T: Transcript>(
     range_length: usize,
     min_row_num: usize,
     one_evaluation_lengths: &[usize],
+    input_row_count: usize,
 ) -> T {
     let mut transcript = T::new();
     transcript.extend_serialize_as_le(result);
@@ -428,9 +1514,69 @@ fn make_transcript<T: Transcript>(
     transcript.extend_serialize_as_le(&range_length);
     transcript.extend_serialize_as_le(&min_row_num);
     transcript.extend_serialize_as_le(one_evaluation_lengths);
+    transcript.extend_serialize_as_le(&input_row_count);
     transcript
 }
 
+/// Binds the positions of the hidden result columns and their commitments, which stand in for the columns.
+///
+/// Nothing is added when no column is hidden, so such proofs are the same as proofs of fully disclosed results.
+fn extend_transcript_with_hidden_columns<C: serde::Serialize>(
+    transcript: &mut impl Transcript,
+    hidden_column_indexes: &[usize],
+    hidden_commitments: &[C],
+) {
+    if !hidden_column_indexes.is_empty() {
+        transcript.extend_serialize_as_le(hidden_column_indexes);
+        transcript.extend_serialize_as_le(hidden_commitments);
+    }
+}
+
+/// The schema of the disclosed result columns, given the positions of the hidden columns and the number of their commitments.
+fn disclosed_result_schema(
+    result_schema: &[ColumnField],
+    hidden_column_indexes: &[usize],
+    num_hidden_commitments: usize,
+) -> Result<Vec<ColumnField>, ProofError> {
+    if hidden_column_indexes.len() != num_hidden_commitments
+        || hidden_column_indexes
+            .windows(2)
+            .any(|pair| pair[0] >= pair[1])
+        || hidden_column_indexes
+            .last()
+            .is_some_and(|&index| index >= result_schema.len())
+    {
+        return Err(ProofError::VerificationError {
+            error: "invalid hidden result columns",
+        });
+    }
+    Ok(result_schema
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !hidden_column_indexes.contains(index))
+        .map(|(_, field)| *field)
+        .collect())
+}
+
+/// The positions in the result of the columns to hide, in increasing order.
+fn hidden_column_indexes(
+    result_schema: &[ColumnField],
+    hidden_columns: &IndexSet<Identifier>,
+) -> Result<Vec<usize>, ProveError> {
+    if let Some(&column) = hidden_columns
+        .iter()
+        .find(|column| !result_schema.iter().any(|field| field.name() == **column))
+    {
+        return Err(ProveError::UnknownResultColumn { column });
+    }
+    Ok(result_schema
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| hidden_columns.contains(&field.name()))
+        .map(|(index, _)| index)
+        .collect())
+}
+
 fn extend_transcript<C: serde::Serialize>(
     transcript: &mut impl Transcript,
     commitments: &C,
@@ -1,5 +1,7 @@
 use super::{
-    CountBuilder, FinalRoundBuilder, ProofPlan, ProverEvaluate, QueryProof, VerificationBuilder,
+    CountBuilder, DeserializeError, FinalRoundBuilder, ProofPlan, ProofStage, ProveError,
+    ProveOptions, ProverEvaluate, QueryError, QueryProof, VerifiableQueryResult,
+    VerificationBuilder, VerificationError,
 };
 use crate::{
     base::{
@@ -7,16 +9,24 @@ use crate::{
         database::{
             owned_table_utility::{bigint, owned_table},
             table_utility::*,
-            ColumnField, ColumnRef, ColumnType, OwnedTable, OwnedTableTestAccessor, Table,
-            TableEvaluation, TableRef,
+            Column, ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor,
+            MetadataAccessor, OwnedTable, OwnedTableTestAccessor, Table, TableEvaluation, TableRef,
         },
         map::{indexset, IndexMap, IndexSet},
         proof::ProofError,
         scalar::{Curve25519Scalar, Scalar},
     },
-    sql::proof::{FirstRoundBuilder, QueryData, SumcheckSubpolynomialType},
+    sql::{
+        proof::{FirstRoundBuilder, QueryData, SumcheckSubpolynomialType},
+        proof_exprs::test_utility::*,
+        proof_plans::test_utility::*,
+    },
 };
 use bumpalo::Bump;
+use core::{
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+};
 use proof_of_sql_parser::Identifier;
 use serde::Serialize;
 
@@ -132,6 +142,7 @@ fn verify_a_trivial_query_proof_with_given_offset(n: usize, offset_generators: u
     let QueryData {
         verification_hash,
         table,
+        ..
     } = proof.verify(&expr, &accessor, &result, &()).unwrap();
     assert_ne!(verification_hash, [0; 32]);
     let expected_result = owned_table([bigint("a1", column)]);
@@ -320,6 +331,7 @@ fn verify_a_proof_with_an_anchored_commitment_and_given_offset(offset_generators
     let QueryData {
         verification_hash,
         table,
+        ..
     } = proof.verify(&expr, &accessor, &result, &()).unwrap();
     assert_ne!(verification_hash, [0; 32]);
     let expected_result = owned_table([bigint("a1", [9, 25])]);
@@ -523,6 +535,7 @@ fn verify_a_proof_with_an_intermediate_commitment_and_given_offset(offset_genera
     let QueryData {
         verification_hash,
         table,
+        ..
     } = proof.verify(&expr, &accessor, &result, &()).unwrap();
     assert_ne!(verification_hash, [0; 32]);
     let expected_result = owned_table([bigint("a1", [81, 625])]);
@@ -611,6 +624,154 @@ fn verify_fails_the_result_doesnt_satisfy_an_intermediate_equation() {
     assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
 }
 
+#[test]
+fn we_can_verify_a_proof_with_streamed_commitments() {
+    // prove and verify an artificial query where
+    //     z_i = x_i * x_i
+    //     res_i = z_i * z_i
+    // where the commitment for x is streamed in
+    let expr = DoubleSquareTestProofPlan {
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let mut requested_columns = Vec::new();
+    let streamed_res = proof
+        .verify_streaming(
+            &expr,
+            &accessor,
+            |index, column| {
+                requested_columns.push((index, column));
+                accessor.get_commitment(column)
+            },
+            &result,
+            &(),
+        )
+        .unwrap();
+    assert_eq!(
+        requested_columns,
+        expr.get_column_references()
+            .into_iter()
+            .enumerate()
+            .collect::<Vec<_>>()
+    );
+    let res = proof.verify(&expr, &accessor, &result, &()).unwrap();
+    assert_eq!(streamed_res.table, res.table);
+    assert_eq!(streamed_res.verification_hash, res.verification_hash);
+}
+
+#[test]
+fn verify_streaming_fails_for_the_same_corruptions_as_verify() {
+    let table = owned_table([bigint("x", [3, 5])]);
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        table.clone(),
+        0,
+        (),
+    );
+    let verify_both = |expr: &DoubleSquareTestProofPlan,
+                       proof: &QueryProof<InnerProductProof>,
+                       result,
+                       accessor: &OwnedTableTestAccessor<InnerProductProof>| {
+        let res = proof.verify(expr, accessor, result, &());
+        let streamed_res = proof.verify_streaming(
+            expr,
+            accessor,
+            |_, column| accessor.get_commitment(column),
+            result,
+            &(),
+        );
+        assert_eq!(res.is_ok(), streamed_res.is_ok());
+        streamed_res
+    };
+
+    // a corrupted intermediate commitment
+    let expr = DoubleSquareTestProofPlan {
+        ..Default::default()
+    };
+    let (mut proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(verify_both(&expr, &proof, &result, &accessor).is_ok());
+    proof.commitments[0] = proof.commitments[0] * Curve25519Scalar::from(2u64);
+    assert!(verify_both(&expr, &proof, &result, &accessor).is_err());
+
+    // a result that doesn't satisfy an intermediate equation
+    let expr = DoubleSquareTestProofPlan {
+        res: [81, 624],
+        ..Default::default()
+    };
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(verify_both(&expr, &proof, &result, &accessor).is_err());
+
+    // commitments with the wrong offset
+    let expr = DoubleSquareTestProofPlan {
+        ..Default::default()
+    };
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let offset_accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        table,
+        1,
+        (),
+    );
+    assert!(verify_both(&expr, &proof, &result, &offset_accessor).is_err());
+
+    // a column commitment that doesn't match the data
+    let streamed_res = proof.verify_streaming(
+        &expr,
+        &accessor,
+        |_, column| accessor.get_commitment(column) * Curve25519Scalar::from(2u64),
+        &result,
+        &(),
+    );
+    assert!(streamed_res.is_err());
+}
+
+#[test]
+fn we_can_verify_a_verifiable_query_result_with_streamed_commitments() {
+    let t = "sxt.test".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1, 2, 3, 4]),
+            bigint("b", [5, 6, 7, 8]),
+            bigint("c", [9, 10, 11, 12]),
+        ]),
+        0,
+        (),
+    );
+    let expr = filter(
+        cols_expr_plan(t, &["a", "c"], &accessor),
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(6)),
+    );
+    let verifiable_res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+    let mut num_requested_columns = 0;
+    let streamed_res = verifiable_res
+        .verify_streaming(
+            &expr,
+            &accessor,
+            |_, column| {
+                num_requested_columns += 1;
+                accessor.get_commitment(column)
+            },
+            &(),
+        )
+        .unwrap();
+    assert_eq!(num_requested_columns, 3);
+    assert_eq!(
+        streamed_res.table,
+        owned_table([bigint("a", [2]), bigint("c", [10])])
+    );
+    let res = verifiable_res.verify(&expr, &accessor, &()).unwrap();
+    assert_eq!(streamed_res.table, res.table);
+    assert_eq!(streamed_res.verification_hash, res.verification_hash);
+}
+
 #[derive(Debug, Serialize)]
 struct ChallengeTestProofPlan {}
 impl ProverEvaluate for ChallengeTestProofPlan {
@@ -717,6 +878,7 @@ fn verify_a_proof_with_a_post_result_challenge_and_given_offset(offset_generator
     let QueryData {
         verification_hash,
         table,
+        ..
     } = proof.verify(&expr, &accessor, &result, &()).unwrap();
     assert_ne!(verification_hash, [0; 32]);
     let expected_result = owned_table([bigint("a1", [9, 25])]);
@@ -741,3 +903,439 @@ fn we_can_verify_a_proof_with_a_post_result_challenge_and_with_a_zero_offset() {
 fn we_can_verify_a_proof_with_a_post_result_challenge_and_with_a_non_zero_offset() {
     verify_a_proof_with_a_post_result_challenge_and_given_offset(123);
 }
+
+/// Accessor that serves a truncated version of one column, to simulate corrupt data.
+struct TruncatedColumnAccessor {
+    inner: OwnedTableTestAccessor<InnerProductProof>,
+    truncated_column: Identifier,
+}
+impl MetadataAccessor for TruncatedColumnAccessor {
+    fn get_length(&self, table_ref: TableRef) -> usize {
+        self.inner.get_length(table_ref)
+    }
+    fn get_offset(&self, table_ref: TableRef) -> usize {
+        self.inner.get_offset(table_ref)
+    }
+}
+impl DataAccessor<Curve25519Scalar> for TruncatedColumnAccessor {
+    fn get_column(&self, column: ColumnRef) -> Column<Curve25519Scalar> {
+        match self.inner.get_column(column) {
+            Column::BigInt(values) if column.column_id() == self.truncated_column => {
+                Column::BigInt(&values[1..])
+            }
+            other => other,
+        }
+    }
+}
+
+#[test]
+fn we_cannot_create_a_query_proof_over_columns_with_mismatched_row_counts() {
+    let t = "sxt.test".parse().unwrap();
+    let inner = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [4, 5, 6])]),
+        0,
+        (),
+    );
+    let expr = filter(
+        cols_expr_plan(t, &["a", "b"], &inner),
+        tab(t),
+        const_bool(true),
+    );
+    let accessor = TruncatedColumnAccessor {
+        inner,
+        truncated_column: "b".parse().unwrap(),
+    };
+    let expected_err = ProveError::RowCountMismatch {
+        column: "b".parse().unwrap(),
+        expected: 3,
+        actual: 2,
+    };
+    assert_eq!(
+        QueryProof::<InnerProductProof>::try_new(&expr, &accessor, &())
+            .err()
+            .unwrap(),
+        expected_err
+    );
+    assert_eq!(
+        VerifiableQueryResult::<InnerProductProof>::try_new(&expr, &accessor, &())
+            .err()
+            .unwrap(),
+        expected_err
+    );
+    assert_eq!(
+        expected_err.to_string(),
+        "column b has 2 rows, but its table has 3 rows"
+    );
+}
+
+fn progress_test_accessor_and_plan() -> (
+    OwnedTableTestAccessor<'static, InnerProductProof>,
+    impl ProofPlan + Serialize,
+) {
+    let t = "sxt.test".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5, 6, 7]),
+            bigint("b", [4, 5, 6, 7, 8, 9, 10]),
+        ]),
+        0,
+        (),
+    );
+    let expr = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(6)),
+    );
+    (accessor, expr)
+}
+
+#[test]
+fn we_report_proof_progress_in_non_decreasing_order() {
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let mut reports = Vec::new();
+    let mut progress = |stage: ProofStage, fraction: f32| reports.push((stage, fraction));
+    let options = ProveOptions {
+        progress: Some(&mut progress),
+        ..ProveOptions::default()
+    };
+    QueryProof::<InnerProductProof>::try_new_with_options(&expr, &accessor, &(), options).unwrap();
+    assert!(reports
+        .windows(2)
+        .all(|pair| pair[0].0 <= pair[1].0 && pair[0].1 <= pair[1].1));
+    assert_eq!(reports.first(), Some(&(ProofStage::Evaluating, 0.0)));
+    assert_eq!(reports.last(), Some(&(ProofStage::Finalizing, 1.0)));
+    for stage in [ProofStage::Committing, ProofStage::Sumcheck] {
+        assert!(reports.iter().any(|(reported, _)| *reported == stage));
+    }
+}
+
+#[test]
+fn a_no_op_progress_callback_produces_the_same_proof_as_omitting_it() {
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let (proof, result) = QueryProof::<InnerProductProof>::try_new(&expr, &accessor, &()).unwrap();
+    let mut progress = |_: ProofStage, _: f32| {};
+    let options = ProveOptions {
+        progress: Some(&mut progress),
+        ..ProveOptions::default()
+    };
+    let (proof_with_progress, result_with_progress) =
+        QueryProof::<InnerProductProof>::try_new_with_options(&expr, &accessor, &(), options)
+            .unwrap();
+    assert_eq!(
+        postcard::to_allocvec(&proof).unwrap(),
+        postcard::to_allocvec(&proof_with_progress).unwrap()
+    );
+    assert_eq!(
+        postcard::to_allocvec(&result).unwrap(),
+        postcard::to_allocvec(&result_with_progress.disclosed).unwrap()
+    );
+
+    let verifiable_result =
+        VerifiableQueryResult::<InnerProductProof>::try_new(&expr, &accessor, &()).unwrap();
+    let mut progress = |_: ProofStage, _: f32| {};
+    let options = ProveOptions {
+        progress: Some(&mut progress),
+        ..ProveOptions::default()
+    };
+    let verifiable_result_with_progress =
+        VerifiableQueryResult::<InnerProductProof>::try_new_with_options(
+            &expr,
+            &accessor,
+            &(),
+            options,
+        )
+        .unwrap();
+    assert_eq!(
+        postcard::to_allocvec(&verifiable_result).unwrap(),
+        postcard::to_allocvec(&verifiable_result_with_progress).unwrap()
+    );
+}
+
+#[test]
+fn we_can_cancel_proof_generation() {
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let should_cancel = || true;
+    let options = ProveOptions {
+        should_cancel: Some(&should_cancel),
+        ..ProveOptions::default()
+    };
+    assert_eq!(
+        QueryProof::<InnerProductProof>::try_new_with_options(&expr, &accessor, &(), options)
+            .err()
+            .unwrap(),
+        ProveError::Cancelled
+    );
+
+    // Cancelling once the sumcheck stage is reached abandons the proof partway through.
+    let checks = Cell::new(0);
+    let should_cancel = || {
+        checks.set(checks.get() + 1);
+        checks.get() > 2
+    };
+    let options = ProveOptions {
+        should_cancel: Some(&should_cancel),
+        ..ProveOptions::default()
+    };
+    assert_eq!(
+        QueryProof::<InnerProductProof>::try_new_with_options(&expr, &accessor, &(), options)
+            .err()
+            .unwrap(),
+        ProveError::Cancelled
+    );
+    assert_eq!(checks.get(), 3);
+    assert_eq!(
+        ProveError::Cancelled.to_string(),
+        "proof generation was cancelled"
+    );
+}
+
+#[test]
+fn a_proof_that_is_never_cancelled_is_the_same_as_one_without_cancellation() {
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let cancel = AtomicBool::new(false);
+    let (proof, result) = QueryProof::<InnerProductProof>::try_new(&expr, &accessor, &()).unwrap();
+    let should_cancel = || cancel.load(Ordering::Relaxed);
+    let options = ProveOptions {
+        should_cancel: Some(&should_cancel),
+        ..ProveOptions::default()
+    };
+    let (uncancelled_proof, uncancelled_result) =
+        QueryProof::<InnerProductProof>::try_new_with_options(&expr, &accessor, &(), options)
+            .unwrap();
+    let uncancelled_result = uncancelled_result.disclosed;
+    assert_eq!(
+        postcard::to_allocvec(&proof).unwrap(),
+        postcard::to_allocvec(&uncancelled_proof).unwrap()
+    );
+    assert_eq!(
+        postcard::to_allocvec(&result).unwrap(),
+        postcard::to_allocvec(&uncancelled_result).unwrap()
+    );
+    let table = uncancelled_proof
+        .verify(&expr, &accessor, &uncancelled_result, &())
+        .unwrap()
+        .table;
+    assert_eq!(table, owned_table([bigint("a", [3])]));
+}
+
+#[test]
+fn we_can_verify_and_decode_a_serialized_result() {
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let (proof, result) = QueryProof::<InnerProductProof>::try_new(&expr, &accessor, &()).unwrap();
+    let table = proof
+        .verify_and_decode(&expr, &accessor, &result.to_envelope_bytes(), &())
+        .unwrap();
+    assert_eq!(table, owned_table([bigint("a", [3])]));
+}
+
+#[test]
+fn we_cannot_verify_and_decode_a_tampered_or_malformed_serialized_result() {
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let (proof, result) = QueryProof::<InnerProductProof>::try_new(&expr, &accessor, &()).unwrap();
+
+    let mut tampered_result = result.clone();
+    tampered_result.data_mut()[0] += 1;
+    assert!(matches!(
+        proof.verify_and_decode(&expr, &accessor, &tampered_result.to_envelope_bytes(), &()),
+        Err(VerificationError::Query {
+            source: QueryError::ProofError { .. }
+        })
+    ));
+
+    let mut bytes = result.to_envelope_bytes();
+    bytes[0] ^= 1;
+    assert!(matches!(
+        proof.verify_and_decode(&expr, &accessor, &bytes, &()),
+        Err(VerificationError::Deserialize {
+            source: DeserializeError::InvalidMagic { .. }
+        })
+    ));
+}
+
+#[test]
+fn we_can_read_the_shape_of_a_query_proof() {
+    let trivial_proof = |n: usize| {
+        let expr = TrivialTestProofPlan {
+            length: n,
+            ..Default::default()
+        };
+        let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+            "sxt.test".parse().unwrap(),
+            owned_table([bigint("a1", vec![0_i64; n])]),
+            0,
+            (),
+        );
+        QueryProof::<InnerProductProof>::new(&expr, &accessor, &()).0
+    };
+    for (n, num_variables) in [(1, 1), (2, 1), (4, 2), (5, 3)] {
+        let proof = trivial_proof(n);
+        assert_eq!(proof.num_variables(), num_variables);
+        assert_eq!(proof.num_sumcheck_rounds(), num_variables);
+        assert_eq!(proof.num_commitments(), 1);
+    }
+
+    // The commitment to the anchored column is not part of the proof, but the one to the result is
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("x", [3, 5])]),
+        0,
+        (),
+    );
+    let (proof, _) =
+        QueryProof::<InnerProductProof>::new(&SquareTestProofPlan::default(), &accessor, &());
+    assert_eq!(proof.num_variables(), 1);
+    assert_eq!(proof.num_commitments(), 1);
+
+    // So is the commitment to an intermediate column
+    let (proof, _) =
+        QueryProof::<InnerProductProof>::new(&DoubleSquareTestProofPlan::default(), &accessor, &());
+    assert_eq!(proof.num_sumcheck_rounds(), 1);
+    assert_eq!(proof.num_commitments(), 2);
+
+    // A filter over seven rows
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let (proof, _) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert_eq!(proof.num_variables(), 3);
+    assert_eq!(proof.num_sumcheck_rounds(), 3);
+    assert_eq!(proof.num_commitments(), proof.commitments.len());
+    assert!(proof.num_commitments() > 0);
+}
+
+#[cfg(feature = "transcript-log")]
+#[test]
+fn a_transcript_log_replays_and_does_not_change_the_proof() {
+    use super::{replay_transcript_log, TranscriptEntry};
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let (proof, result) = QueryProof::<InnerProductProof>::try_new(&expr, &accessor, &()).unwrap();
+    let mut log = Vec::new();
+    let options = ProveOptions {
+        transcript_log: Some(&mut log),
+        ..ProveOptions::default()
+    };
+    let (proof_with_log, result_with_log) =
+        QueryProof::<InnerProductProof>::try_new_with_options(&expr, &accessor, &(), options)
+            .unwrap();
+    let result_with_log = result_with_log.disclosed;
+    assert_eq!(
+        postcard::to_allocvec(&proof).unwrap(),
+        postcard::to_allocvec(&proof_with_log).unwrap()
+    );
+    assert_eq!(
+        postcard::to_allocvec(&result).unwrap(),
+        postcard::to_allocvec(&result_with_log).unwrap()
+    );
+    assert!(log
+        .iter()
+        .any(|entry| matches!(entry, TranscriptEntry::Challenge { .. })));
+    assert_eq!(replay_transcript_log(&log), None);
+    proof_with_log
+        .verify(&expr, &accessor, &result_with_log, &())
+        .unwrap();
+}
+
+#[cfg(feature = "prove-metrics")]
+#[test]
+fn prove_metrics_are_consistent_with_the_proof() {
+    use super::ProveMetrics;
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let (proof, result) = QueryProof::<InnerProductProof>::try_new(&expr, &accessor, &()).unwrap();
+    let mut metrics = ProveMetrics::default();
+    let options = ProveOptions {
+        metrics: Some(&mut metrics),
+        ..ProveOptions::default()
+    };
+    let (proof_with_metrics, result_with_metrics) =
+        QueryProof::<InnerProductProof>::try_new_with_options(&expr, &accessor, &(), options)
+            .unwrap();
+    assert_eq!(
+        postcard::to_allocvec(&proof).unwrap(),
+        postcard::to_allocvec(&proof_with_metrics).unwrap()
+    );
+    assert_eq!(
+        postcard::to_allocvec(&result).unwrap(),
+        postcard::to_allocvec(&result_with_metrics.disclosed).unwrap()
+    );
+    assert_eq!(metrics.sumcheck_rounds, proof.num_variables());
+    assert_eq!(metrics.msm_calls, proof.num_commitments());
+    assert_eq!(metrics.mle_evaluations, proof.pcs_proof_evaluations.len());
+    // Every commitment is to a column no longer than the seven rows of the table
+    assert!(metrics.scalar_muls >= metrics.msm_calls);
+    assert!(metrics.scalar_muls <= 7 * metrics.msm_calls);
+    // `b = 6` inverts all seven rows, and the filter inverts all seven rows and the one selected row
+    assert_eq!(metrics.field_inversions, 15);
+}
+
+#[test]
+fn verifying_the_query_logic_and_the_commitment_binding_is_equivalent_to_verify() {
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let res = proof.verify(&expr, &accessor, &result, &()).unwrap();
+    assert_eq!(
+        proof.verify_query_logic(&expr, &accessor, &result).unwrap(),
+        res.table
+    );
+    proof
+        .verify_commitment_binding(&expr, &accessor, &result, &())
+        .unwrap();
+}
+
+#[test]
+fn only_the_commitment_binding_stage_rejects_commitments_that_do_not_match_the_data() {
+    let (accessor, expr) = progress_test_accessor_and_plan();
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    let other_accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 30, 4, 5, 6, 7]),
+            bigint("b", [4, 5, 6, 7, 8, 9, 10]),
+        ]),
+        0,
+        (),
+    );
+    assert!(proof.verify(&expr, &other_accessor, &result, &()).is_err());
+    assert!(matches!(
+        proof.verify_commitment_binding(&expr, &other_accessor, &result, &()),
+        Err(VerificationError::Query {
+            source: QueryError::ProofError {
+                source: ProofError::VerificationError { .. }
+            }
+        })
+    ));
+    assert_eq!(
+        proof
+            .verify_query_logic(&expr, &other_accessor, &result)
+            .unwrap(),
+        proof.verify(&expr, &accessor, &result, &()).unwrap().table
+    );
+}
+
+#[test]
+fn only_the_query_logic_stage_rejects_a_result_that_does_not_satisfy_the_constraints() {
+    // the verifier expects an evaluation that doesn't match the evaluation from sumcheck,
+    // while the evaluation proof is honest
+    let expr = TrivialTestProofPlan {
+        evaluation: 123,
+        ..Default::default()
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        "sxt.test".parse().unwrap(),
+        owned_table([bigint("a1", [123_i64; 2])]),
+        0,
+        (),
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(proof.verify(&expr, &accessor, &result, &()).is_err());
+    assert!(matches!(
+        proof.verify_query_logic(&expr, &accessor, &result),
+        Err(VerificationError::Query {
+            source: QueryError::ProofError {
+                source: ProofError::VerificationError { .. }
+            }
+        })
+    ));
+    proof
+        .verify_commitment_binding(&expr, &accessor, &result, &())
+        .unwrap();
+}
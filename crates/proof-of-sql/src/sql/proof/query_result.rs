@@ -1,5 +1,5 @@
 use crate::base::{
-    database::{OwnedTable, OwnedTableError},
+    database::{OwnedTable, OwnedTableError, Row, TableSchema},
     proof::ProofError,
     scalar::Scalar,
 };
@@ -50,6 +50,22 @@ pub struct QueryData<S: Scalar> {
     /// Additionally, there is a 32-byte verification hash that is included with this table.
     /// This hash provides evidence that the verification has been run.
     pub verification_hash: [u8; 32],
+    /// The names and types of the result columns, in order.
+    ///
+    /// This is derived from the plan the proof was verified against, which is bound into the transcript,
+    /// so it is the schema of the verified result rather than a value the prover chose.
+    pub result_schema: TableSchema,
+}
+
+impl<S: Scalar> QueryData<S> {
+    /// Returns an iterator over the rows of the result table in order.
+    ///
+    /// Rows are decoded lazily from the result columns, so large results can be processed
+    /// one row at a time without copying the table.
+    #[must_use]
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = Row<'_, S>> {
+        self.table.rows()
+    }
 }
 
 /// The result of a query -- either an error or a table.
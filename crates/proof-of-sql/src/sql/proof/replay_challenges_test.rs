@@ -0,0 +1,121 @@
+use super::{ProvableQueryResult, QueryProof};
+use crate::{
+    base::{
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor, TableRef},
+        polynomial::compute_evaluation_vector,
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        proof::ProofPlan,
+        proof_exprs::test_utility::*,
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+
+const A: [i64; 5] = [1, 2, 3, 4, 5];
+
+fn accessor(
+    setup: DoryProverPublicSetup<'_>,
+) -> (TableRef, OwnedTableTestAccessor<'_, DoryEvaluationProof>) {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", A), bigint("b", [5_i64, 6, 7, 8, 9])]),
+        0,
+        setup,
+    );
+    (t, accessor)
+}
+
+// select b from sxt.t where a >= 3
+fn plan(t: TableRef, accessor: &OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan {
+    filter(
+        cols_expr_plan(t, &["b"], accessor),
+        tab(t),
+        gte(column(t, "a", accessor), const_bigint(3)),
+    )
+}
+
+#[test]
+fn replayed_challenges_are_the_challenges_the_proof_was_created_and_verified_with() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = plan(t, &accessor);
+    let (proof, result) = QueryProof::<DoryEvaluationProof>::new(&plan, &accessor, &setup);
+    proof
+        .verify(&plan, &accessor, &result, &verifier_setup)
+        .unwrap();
+
+    let challenges = proof.replay_challenges(&plan, &accessor, &result).unwrap();
+    assert_eq!(
+        challenges,
+        proof.replay_challenges(&plan, &accessor, &result).unwrap()
+    );
+    let num_evaluations = proof.pcs_proof_evaluations.len();
+    let num_variables = proof.num_variables();
+    assert!(challenges.len() > num_variables + num_evaluations);
+
+    // The challenges of the sumcheck rounds are the point the prover evaluated the referenced columns at.
+    let evaluation_point = &challenges
+        [challenges.len() - num_evaluations - num_variables..challenges.len() - num_evaluations];
+    let mut evaluation_vector = vec![DoryScalar::default(); A.len()];
+    compute_evaluation_vector(&mut evaluation_vector, evaluation_point);
+    let a_evaluation: DoryScalar = A
+        .iter()
+        .zip(&evaluation_vector)
+        .map(|(&a, &weight)| DoryScalar::from(a) * weight)
+        .sum();
+    let a_index = plan
+        .get_column_references()
+        .get_index_of(&col_ref(t, "a", &accessor))
+        .unwrap();
+    assert_eq!(proof.pcs_proof_evaluations[a_index], a_evaluation);
+}
+
+#[test]
+fn replayed_challenges_depend_on_the_public_inputs() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = plan(t, &accessor);
+    let (proof, result) = QueryProof::<DoryEvaluationProof>::new(&plan, &accessor, &setup);
+    let challenges = proof.replay_challenges(&plan, &accessor, &result).unwrap();
+
+    // The sumcheck rounds were created with the challenges of the honest result,
+    // so with those of another result they are inconsistent and the replay stops.
+    let other_result = ProvableQueryResult::new(3, &[Column::<DoryScalar>::BigInt(&[7, 8, 10])]);
+    assert!(proof
+        .replay_challenges(&plan, &accessor, &other_result)
+        .is_err());
+
+    let mut other_proof = proof.clone();
+    other_proof.pcs_proof_evaluations[0] += DoryScalar::from(1);
+    let other_challenges = other_proof
+        .replay_challenges(&plan, &accessor, &result)
+        .unwrap();
+    // Only the batching multipliers are drawn after the evaluations are appended.
+    let num_unchanged = challenges.len() - proof.pcs_proof_evaluations.len();
+    assert_eq!(
+        challenges[..num_unchanged],
+        other_challenges[..num_unchanged]
+    );
+    assert_ne!(
+        challenges[num_unchanged..],
+        other_challenges[num_unchanged..]
+    );
+
+    // A proof without the shape the query requires is rejected.
+    let mut malformed_proof = proof;
+    malformed_proof.pcs_proof_evaluations.pop();
+    assert!(malformed_proof
+        .replay_challenges(&plan, &accessor, &result)
+        .is_err());
+}
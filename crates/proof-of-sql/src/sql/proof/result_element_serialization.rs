@@ -1,5 +1,5 @@
 use super::QueryError;
-use crate::base::encode::VarInt;
+use crate::base::encode::{VarInt, ZeroCopyReader};
 use alloc::{string::String, vec::Vec};
 use core::str;
 
@@ -43,16 +43,11 @@ impl<'a> ProvableResultElement<'a> for &'a [u8] {
         bytes_written
     }
     fn decode(data: &'a [u8]) -> Result<(Self, usize), QueryError> {
-        let (len_buf, sizeof_usize) =
-            <usize>::decode_var(data).ok_or(QueryError::MiscellaneousDecodingError)?;
-
-        let bytes_read = len_buf + sizeof_usize;
-
-        if data.len() < bytes_read {
-            return Err(QueryError::MiscellaneousDecodingError);
-        }
-
-        Ok((&data[sizeof_usize..bytes_read], bytes_read))
+        let mut reader = ZeroCopyReader::new(data);
+        let payload = reader
+            .read_length_delimited()
+            .ok_or(QueryError::MiscellaneousDecodingError)?;
+        Ok((payload, reader.position()))
     }
 }
 
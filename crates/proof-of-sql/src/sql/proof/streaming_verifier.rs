@@ -0,0 +1,178 @@
+use super::{
+    proof_envelope::{read_envelope_header, HEADER_LENGTH},
+    DeserializeError, EnvelopePayload, ProofPlan, ProvableQueryResult, QueryData, QueryError,
+    VerifiableQueryResult,
+};
+use crate::base::{
+    commitment::CommitmentEvaluationProof, database::CommitmentAccessor, encode::ZeroCopyReader,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::Snafu;
+
+/// Errors that can occur when verifying a proof with a [`StreamingVerifier`]
+#[derive(Debug, Snafu)]
+pub enum StreamingVerificationError {
+    /// The envelope or its payload is malformed.
+    #[snafu(transparent)]
+    Envelope {
+        /// The underlying source error
+        source: DeserializeError,
+    },
+    /// The query result does not have a column for each result column of the query.
+    #[snafu(display("query result has {actual} columns, but the query has {expected}"))]
+    ResultColumnCountMismatch {
+        /// The number of result columns of the query
+        expected: usize,
+        /// The number of columns in the query result
+        actual: usize,
+    },
+    /// Verification was finalized before every byte of the envelope was received.
+    #[snafu(display("proof envelope is incomplete: received {received} bytes"))]
+    Incomplete {
+        /// The number of bytes received
+        received: usize,
+        /// The length of the envelope, if its header was received
+        expected: Option<usize>,
+    },
+    /// The proof does not verify.
+    #[snafu(transparent)]
+    Query {
+        /// The underlying source error
+        source: QueryError,
+    },
+}
+
+/// A verifier for a [`VerifiableQueryResult`] whose envelope bytes arrive in chunks, e.g. over a network.
+///
+/// Each call to [`StreamingVerifier::push`] validates as much of the envelope as has arrived:
+/// the header is validated as soon as it is complete, a payload longer than the header says is rejected
+/// as soon as the extra bytes arrive, and the query result is decoded and checked against the query
+/// as soon as it is complete, all before the proof itself has arrived.
+/// The cryptographic verdict is reached by [`StreamingVerifier::finish`] once every byte has been received,
+/// and it is the verdict of [`VerifiableQueryResult::verify`] on the whole envelope.
+pub struct StreamingVerifier<'a, CP: CommitmentEvaluationProof, P> {
+    expr: &'a P,
+    bytes: Vec<u8>,
+    /// The format version and payload length, once the header has been received and validated
+    header: Option<(u16, u64)>,
+    /// Whether the query result has been received and checked
+    result_checked: bool,
+    _phantom: PhantomData<CP>,
+}
+
+impl<'a, CP: CommitmentEvaluationProof, P: ProofPlan + Serialize> StreamingVerifier<'a, CP, P> {
+    /// Create a verifier of a proof of `expr` that has not received any bytes yet.
+    #[must_use]
+    pub fn new(expr: &'a P) -> Self {
+        Self {
+            expr,
+            bytes: Vec::new(),
+            header: None,
+            result_checked: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The number of bytes received so far
+    #[must_use]
+    pub fn bytes_received(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Whether every byte of the envelope has been received
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.expected_length() == Some(self.bytes.len())
+    }
+
+    /// The length of the envelope, once its header has been received
+    fn expected_length(&self) -> Option<usize> {
+        self.header
+            .and_then(|(_, payload_length)| usize::try_from(payload_length).ok())
+            .map(|payload_length| HEADER_LENGTH + payload_length)
+    }
+
+    /// Receive the next chunk of the envelope and validate everything that is newly available.
+    ///
+    /// Will error as soon as the envelope is known to be malformed or its query result does not match the query.
+    /// The verifier should be discarded after an error.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<(), StreamingVerificationError> {
+        self.bytes.extend_from_slice(chunk);
+        if self.header.is_none() && self.bytes.len() >= HEADER_LENGTH {
+            let (version, payload_length, _) = read_envelope_header(&self.bytes)?;
+            self.header = Some((version, payload_length));
+        }
+        let Some((_, payload_length)) = self.header else {
+            return Ok(());
+        };
+        let payload = &self.bytes[HEADER_LENGTH..];
+        if payload.len() as u64 > payload_length {
+            return Err(DeserializeError::PayloadLengthMismatch {
+                expected: payload_length,
+                actual: payload.len(),
+            })?;
+        }
+        if !self.result_checked {
+            if let Some(length) = provable_result_length(payload) {
+                let provable_result: Option<ProvableQueryResult> =
+                    postcard::from_bytes(&payload[..length])
+                        .map_err(|error| DeserializeError::Payload { error })?;
+                if let Some(result) = &provable_result {
+                    let expected = self.expr.get_column_result_fields().len();
+                    if result.num_columns() != expected {
+                        return Err(StreamingVerificationError::ResultColumnCountMismatch {
+                            expected,
+                            actual: result.num_columns(),
+                        });
+                    }
+                }
+                self.result_checked = true;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<CP: CommitmentEvaluationProof + DeserializeOwned, P: ProofPlan + Serialize>
+    StreamingVerifier<'_, CP, P>
+{
+    /// Reach the verdict once every byte of the envelope has been received.
+    ///
+    /// Will error with [`StreamingVerificationError::Incomplete`] if the envelope has not been fully received,
+    /// and otherwise reaches the verdict of [`VerifiableQueryResult::verify`].
+    ///
+    /// Note: This does NOT transform the result!
+    pub fn finish(
+        self,
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<QueryData<CP::Scalar>, StreamingVerificationError> {
+        let (Some((version, _)), true) = (self.header, self.is_complete()) else {
+            return Err(StreamingVerificationError::Incomplete {
+                received: self.bytes.len(),
+                expected: self.expected_length(),
+            });
+        };
+        let verifiable_result =
+            VerifiableQueryResult::<CP>::from_payload(version, &self.bytes[HEADER_LENGTH..])
+                .map_err(|error| DeserializeError::Payload { error })?;
+        Ok(verifiable_result.verify(self.expr, accessor, setup)?)
+    }
+}
+
+/// Returns the length of the serialized query result at the start of `payload`,
+/// or `None` if it has not been fully received.
+///
+/// The query result is an optional [`ProvableQueryResult`], which is serialized as a tag byte
+/// followed, if present, by its column count and row count as varints and its data as a length-delimited field.
+fn provable_result_length(payload: &[u8]) -> Option<usize> {
+    let mut reader = ZeroCopyReader::new(payload);
+    if reader.read_bytes(1)? == [1] {
+        reader.read_varint::<u64>()?;
+        reader.read_varint::<u64>()?;
+        reader.read_length_delimited()?;
+    }
+    Some(reader.position())
+}
@@ -0,0 +1,177 @@
+use super::{
+    proof_envelope::HEADER_LENGTH, serialized_size, DeserializeError, StreamingVerificationError,
+    StreamingVerifier, VerifiableQueryResult, PROOF_ENVELOPE_MAGIC,
+};
+use crate::{
+    base::database::{owned_table_utility::*, OwnedTableTestAccessor, TableRef},
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup, ProverSetup,
+        PublicParameters, VerifierSetup,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+
+#[test]
+fn we_can_verify_a_proof_that_arrives_in_chunks_of_any_size() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3, 4]), bigint("b", [1, 0, 1, 1])]),
+        0,
+        dory_prover_setup,
+    );
+    let expr = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(1)),
+    );
+    let bytes =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&expr, &accessor, &dory_prover_setup)
+            .to_envelope_bytes();
+    let expected = VerifiableQueryResult::<DoryEvaluationProof>::try_from_envelope_bytes(&bytes)
+        .unwrap()
+        .verify(&expr, &accessor, &dory_verifier_setup)
+        .unwrap();
+    assert_eq!(expected.table, owned_table([bigint("a", [1, 3, 4])]));
+
+    for chunk_size in [1, 3, 14, 100, bytes.len()] {
+        let mut verifier = StreamingVerifier::<DoryEvaluationProof, _>::new(&expr);
+        for chunk in bytes.chunks(chunk_size) {
+            assert!(!verifier.is_complete());
+            verifier.push(chunk).unwrap();
+        }
+        assert!(verifier.is_complete());
+        assert_eq!(verifier.bytes_received(), bytes.len());
+        let data = verifier.finish(&accessor, &dory_verifier_setup).unwrap();
+        assert_eq!(data.table, expected.table);
+        assert_eq!(data.verification_hash, expected.verification_hash);
+    }
+
+    // A proof of other data is rejected by both verifiers.
+    let other_accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3, 5]), bigint("b", [1, 0, 1, 1])]),
+        0,
+        dory_prover_setup,
+    );
+    assert!(
+        VerifiableQueryResult::<DoryEvaluationProof>::try_from_envelope_bytes(&bytes)
+            .unwrap()
+            .verify(&expr, &other_accessor, &dory_verifier_setup)
+            .is_err()
+    );
+    let mut verifier = StreamingVerifier::<DoryEvaluationProof, _>::new(&expr);
+    for chunk in bytes.chunks(7) {
+        verifier.push(chunk).unwrap();
+    }
+    assert!(matches!(
+        verifier.finish(&other_accessor, &dory_verifier_setup),
+        Err(StreamingVerificationError::Query { .. })
+    ));
+}
+
+#[test]
+fn a_truncated_proof_is_incomplete() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3])]),
+        0,
+        dory_prover_setup,
+    );
+    let expr = projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let bytes =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&expr, &accessor, &dory_prover_setup)
+            .to_envelope_bytes();
+
+    for received in [0, 5, HEADER_LENGTH, bytes.len() / 2, bytes.len() - 1] {
+        let mut verifier = StreamingVerifier::<DoryEvaluationProof, _>::new(&expr);
+        verifier.push(&bytes[..received]).unwrap();
+        assert!(!verifier.is_complete());
+        let Err(StreamingVerificationError::Incomplete {
+            received: reported,
+            expected,
+        }) = verifier.finish(&accessor, &dory_verifier_setup)
+        else {
+            panic!("a truncated proof should be incomplete");
+        };
+        assert_eq!(reported, received);
+        assert_eq!(expected, (received >= HEADER_LENGTH).then_some(bytes.len()));
+    }
+}
+
+#[test]
+fn malformed_envelopes_are_rejected_before_the_proof_arrives() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [4, 5, 6])]),
+        0,
+        dory_prover_setup,
+    );
+    let expr = projection(cols_expr_plan(t, &["a"], &accessor), tab(t));
+    let bytes =
+        VerifiableQueryResult::<DoryEvaluationProof>::new(&expr, &accessor, &dory_prover_setup)
+            .to_envelope_bytes();
+
+    // The header is validated as soon as it arrives.
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] ^= 1;
+    let mut verifier = StreamingVerifier::<DoryEvaluationProof, _>::new(&expr);
+    verifier.push(&bad_magic[..HEADER_LENGTH - 1]).unwrap();
+    assert!(matches!(
+        verifier.push(&bad_magic[HEADER_LENGTH - 1..HEADER_LENGTH]),
+        Err(StreamingVerificationError::Envelope {
+            source: DeserializeError::InvalidMagic { .. }
+        })
+    ));
+    assert_ne!(bad_magic[..4], PROOF_ENVELOPE_MAGIC);
+
+    // Bytes beyond the payload length are rejected as soon as they arrive.
+    let mut verifier = StreamingVerifier::<DoryEvaluationProof, _>::new(&expr);
+    verifier.push(&bytes).unwrap();
+    assert!(matches!(
+        verifier.push(&[0]),
+        Err(StreamingVerificationError::Envelope {
+            source: DeserializeError::PayloadLengthMismatch { .. }
+        })
+    ));
+
+    // A result that does not match the query is rejected as soon as the result arrives.
+    let two_column_expr = projection(cols_expr_plan(t, &["a", "b"], &accessor), tab(t));
+    let two_column_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        &two_column_expr,
+        &accessor,
+        &dory_prover_setup,
+    );
+    let result_length = HEADER_LENGTH + serialized_size(&two_column_result.provable_result);
+    let two_column_bytes = two_column_result.to_envelope_bytes();
+    assert!(result_length < two_column_bytes.len());
+    let mut verifier = StreamingVerifier::<DoryEvaluationProof, _>::new(&expr);
+    verifier
+        .push(&two_column_bytes[..result_length - 1])
+        .unwrap();
+    assert!(matches!(
+        verifier.push(&two_column_bytes[result_length - 1..result_length]),
+        Err(StreamingVerificationError::ResultColumnCountMismatch {
+            expected: 1,
+            actual: 2
+        })
+    ));
+}
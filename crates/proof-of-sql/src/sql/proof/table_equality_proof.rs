@@ -0,0 +1,254 @@
+use crate::base::{
+    commitment::{Commitment, CommitmentEvaluationProof, TableCommitment},
+    database::{Column, ColumnField, OwnedTable},
+    math::log2_up,
+    polynomial::{compute_evaluation_vector, MultilinearExtension},
+    proof::{prove_all_zero, verify_all_zero, Keccak256Transcript, Transcript},
+    scalar::Scalar,
+};
+use alloc::{vec, vec::Vec};
+use bumpalo::Bump;
+use core::ops::Range;
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// Errors from proving or verifying that two tables are equal.
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum TableEqualityError {
+    /// The tables do not have the same column names and types in the same order.
+    #[snafu(display("the tables do not have the same schema"))]
+    SchemaMismatch,
+    /// The tables do not have the same rows, or are not committed at the same generator offset.
+    #[snafu(display("the tables have row ranges {left:?} and {right:?}"))]
+    RangeMismatch {
+        /// The range of the first table
+        left: Range<usize>,
+        /// The range of the second table
+        right: Range<usize>,
+    },
+    /// The proof does not have one difference evaluation per column.
+    #[snafu(display("the proof has {actual} difference evaluations, expected {expected}"))]
+    InvalidProofSize {
+        /// The number of columns of the tables
+        expected: usize,
+        /// The number of difference evaluations in the proof
+        actual: usize,
+    },
+    /// The differences of the columns do not all evaluate to zero.
+    #[snafu(display("the tables are not equal"))]
+    TablesDiffer,
+    /// The evaluation proof of the differences of the columns failed.
+    #[snafu(display("the evaluation proof of the column differences failed"))]
+    EvaluationProofFailed,
+}
+
+/// A proof that two committed tables have the same schema and the same column values.
+///
+/// The columns of the two tables are subtracted position by position, and every difference is shown
+/// to vanish at a random point. This costs one evaluation proof for the whole table,
+/// rather than the sumcheck a general query proof needs.
+///
+/// Note: Because the struct is deserialized from untrusted data, it
+/// cannot maintain any invariant on its data members; hence, they are
+/// all public so as to allow for easy manipulation for testing.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TableEqualityProof<CP: CommitmentEvaluationProof> {
+    /// The evaluations of the differences of the columns at the random point, in the order of the columns
+    pub difference_evaluations: Vec<CP::Scalar>,
+    /// Inner product proof of the differences' evaluations
+    pub evaluation_proof: CP,
+}
+
+/// The names and types of the columns of a table, in order
+fn table_schema<S: Scalar>(table: &OwnedTable<S>) -> Vec<ColumnField> {
+    table
+        .inner_table()
+        .iter()
+        .map(|(name, column)| ColumnField::new(*name, column.column_type()))
+        .collect()
+}
+
+/// The names and types of the columns of a table commitment, in order
+fn commitment_schema<C: Commitment>(commitment: &TableCommitment<C>) -> Vec<ColumnField> {
+    commitment
+        .column_commitments()
+        .column_metadata()
+        .iter()
+        .map(|(name, metadata)| ColumnField::new(*name, *metadata.column_type()))
+        .collect()
+}
+
+/// The number of variables of the evaluation point for a table with `table_length` rows
+fn num_variables(table_length: usize) -> usize {
+    log2_up(table_length.max(1)).max(1)
+}
+
+/// Creates the transcript of a table equality proof, binding the schema, the rows and the column commitments of the tables.
+///
+/// The commitments must be bound so that the evaluation point cannot be known before both tables are fixed.
+/// Otherwise, whoever controls one table could choose a nonzero difference that vanishes at that point.
+fn make_transcript<C: Commitment + Serialize>(
+    schema: &[ColumnField],
+    range: &Range<usize>,
+    a: &TableCommitment<C>,
+    b: &TableCommitment<C>,
+) -> Keccak256Transcript {
+    let mut transcript = Keccak256Transcript::new();
+    transcript.extend_serialize_as_le(schema);
+    transcript.extend_serialize_as_le(&(range.start, range.end));
+    transcript.extend_serialize_as_le(a.column_commitments().commitments());
+    transcript.extend_serialize_as_le(b.column_commitments().commitments());
+    transcript
+}
+
+fn draw_scalars<S: Scalar>(transcript: &mut impl Transcript, count: usize) -> Vec<S> {
+    core::iter::repeat_with(|| transcript.scalar_challenge_as_be())
+        .take(count)
+        .collect()
+}
+
+/// Prove that the tables `a` and `b` have the same schema and the same column values.
+///
+/// Both tables are committed starting at the generator `offset`,
+/// as with [`TableCommitment::from_owned_table_with_offset`].
+/// The proof is checked with [`verify_table_equality`] against the commitments of the two tables.
+/// Those commitments are computed here as well, since the proof is bound to them.
+///
+/// # Errors
+/// Fails fast if the tables do not have the same column names and types in the same order,
+/// or do not have the same number of rows.
+/// Tables that differ only in their values produce a proof that fails verification.
+pub fn prove_table_equality<CP: CommitmentEvaluationProof>(
+    a: &OwnedTable<CP::Scalar>,
+    b: &OwnedTable<CP::Scalar>,
+    offset: usize,
+    setup: &CP::ProverPublicSetup<'_>,
+) -> Result<TableEqualityProof<CP>, TableEqualityError> {
+    let schema = table_schema(a);
+    if schema != table_schema(b) {
+        return Err(TableEqualityError::SchemaMismatch);
+    }
+    let (left, right) = (offset..offset + a.num_rows(), offset..offset + b.num_rows());
+    if left != right {
+        return Err(TableEqualityError::RangeMismatch { left, right });
+    }
+    let table_length = a.num_rows();
+    let mut transcript = make_transcript(
+        &schema,
+        &left,
+        &TableCommitment::<CP::Commitment>::from_owned_table_with_offset(a, offset, setup),
+        &TableCommitment::<CP::Commitment>::from_owned_table_with_offset(b, offset, setup),
+    );
+
+    let evaluation_point: Vec<CP::Scalar> =
+        draw_scalars(&mut transcript, num_variables(table_length));
+    let mut evaluation_vec = vec![CP::Scalar::ZERO; table_length];
+    compute_evaluation_vector(&mut evaluation_vec, &evaluation_point);
+
+    let alloc = Bump::new();
+    let column_pairs: Vec<_> = a
+        .inner_table()
+        .values()
+        .zip(b.inner_table().values())
+        .map(|(a, b)| {
+            (
+                Column::from_owned_column(a, &alloc),
+                Column::from_owned_column(b, &alloc),
+            )
+        })
+        .collect();
+    let difference_evaluations: Vec<_> = column_pairs
+        .iter()
+        .map(|(a, b)| a.inner_product(&evaluation_vec) - b.inner_product(&evaluation_vec))
+        .collect();
+    prove_all_zero(&mut transcript, &difference_evaluations);
+
+    // fold the differences together with random multipliers to form the input to a single inner product proof
+    let random_scalars: Vec<CP::Scalar> = draw_scalars(&mut transcript, column_pairs.len());
+    let mut folded_mle = vec![CP::Scalar::ZERO; table_length];
+    for (multiplier, (a, b)) in random_scalars.iter().zip(&column_pairs) {
+        a.mul_add(&mut folded_mle, multiplier);
+        b.mul_add(&mut folded_mle, &-*multiplier);
+    }
+    let evaluation_proof = CP::new(
+        &mut transcript,
+        &folded_mle,
+        &evaluation_point,
+        offset as u64,
+        setup,
+    );
+    Ok(TableEqualityProof {
+        difference_evaluations,
+        evaluation_proof,
+    })
+}
+
+/// Verify a proof from [`prove_table_equality`] that the tables committed to by `a` and `b` are equal.
+///
+/// # Errors
+/// Fails fast if the commitments do not have the same column names and types in the same order,
+/// or do not cover the same rows. Otherwise fails if the proof does not show that every column of `a`
+/// equals the column of `b` in the same position.
+pub fn verify_table_equality<CP: CommitmentEvaluationProof>(
+    proof: &TableEqualityProof<CP>,
+    a: &TableCommitment<CP::Commitment>,
+    b: &TableCommitment<CP::Commitment>,
+    setup: &CP::VerifierPublicSetup<'_>,
+) -> Result<(), TableEqualityError> {
+    let schema = commitment_schema(a);
+    if schema != commitment_schema(b) {
+        return Err(TableEqualityError::SchemaMismatch);
+    }
+    if a.range() != b.range() {
+        return Err(TableEqualityError::RangeMismatch {
+            left: a.range().clone(),
+            right: b.range().clone(),
+        });
+    }
+    if proof.difference_evaluations.len() != schema.len() {
+        return Err(TableEqualityError::InvalidProofSize {
+            expected: schema.len(),
+            actual: proof.difference_evaluations.len(),
+        });
+    }
+    let range = a.range();
+    let mut transcript = make_transcript(&schema, range, a, b);
+
+    let evaluation_point: Vec<CP::Scalar> =
+        draw_scalars(&mut transcript, num_variables(range.len()));
+    verify_all_zero(&mut transcript, &proof.difference_evaluations)
+        .map_err(|_| TableEqualityError::TablesDiffer)?;
+
+    let random_scalars: Vec<CP::Scalar> = draw_scalars(&mut transcript, schema.len());
+    let product = random_scalars
+        .iter()
+        .zip(&proof.difference_evaluations)
+        .fold(CP::Scalar::ZERO, |acc, (multiplier, evaluation)| {
+            acc + *multiplier * *evaluation
+        });
+    let commit_batch: Vec<_> = a
+        .column_commitments()
+        .commitments()
+        .iter()
+        .chain(b.column_commitments().commitments())
+        .cloned()
+        .collect();
+    let batching_factors: Vec<_> = random_scalars
+        .iter()
+        .copied()
+        .chain(random_scalars.iter().map(|multiplier| -*multiplier))
+        .collect();
+    proof
+        .evaluation_proof
+        .verify_batched_proof(
+            &mut transcript,
+            &commit_batch,
+            &batching_factors,
+            &product,
+            &evaluation_point,
+            range.start as u64,
+            range.len(),
+            setup,
+        )
+        .map_err(|_| TableEqualityError::EvaluationProofFailed)
+}
@@ -0,0 +1,247 @@
+use super::{prove_table_equality, verify_table_equality, TableEqualityError};
+use crate::{
+    base::{
+        commitment::TableCommitment,
+        database::{owned_table_utility::*, ColumnField, ColumnType, OwnedTable},
+        polynomial::compute_evaluation_vector,
+        proof::{Keccak256Transcript, Transcript},
+        scalar::Scalar,
+    },
+    proof_primitive::dory::{
+        test_rng, DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar,
+        DoryVerifierPublicSetup, ProverSetup, PublicParameters, VerifierSetup,
+    },
+};
+
+fn sample_table() -> OwnedTable<DoryScalar> {
+    owned_table([
+        bigint("a", [1_i64, -2, 3, 4, 5]),
+        varchar("b", ["x", "yy", "zzz", "", "w"]),
+        boolean("c", [true, false, true, true, false]),
+        decimal75("d", 12, 2, [125_i64, -300, 0, 7, 8]),
+    ])
+}
+
+fn prove_and_verify(
+    a: &OwnedTable<DoryScalar>,
+    b: &OwnedTable<DoryScalar>,
+) -> Result<(), TableEqualityError> {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let prover_public_setup = DoryProverPublicSetup::new(&prover_setup, 2);
+    let verifier_public_setup = DoryVerifierPublicSetup::new(&verifier_setup, 2);
+
+    let proof = prove_table_equality::<DoryEvaluationProof>(a, b, 0, &prover_public_setup)?;
+    verify_table_equality(
+        &proof,
+        &TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+            a,
+            0,
+            &prover_public_setup,
+        ),
+        &TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+            b,
+            0,
+            &prover_public_setup,
+        ),
+        &verifier_public_setup,
+    )
+}
+
+#[test]
+fn we_can_prove_that_identical_tables_are_equal() {
+    let table = sample_table();
+    assert_eq!(prove_and_verify(&table, &table.clone()), Ok(()));
+}
+
+#[test]
+fn we_can_prove_that_tables_without_rows_or_columns_are_equal() {
+    let table: OwnedTable<DoryScalar> =
+        owned_table([bigint("a", [0_i64; 0]), varchar("b", [""; 0])]);
+    assert_eq!(prove_and_verify(&table, &table), Ok(()));
+    let table: OwnedTable<DoryScalar> = owned_table([]);
+    assert_eq!(prove_and_verify(&table, &table), Ok(()));
+}
+
+#[test]
+fn we_cannot_prove_that_tables_differing_in_one_cell_are_equal() {
+    let table = sample_table();
+    let differing = [
+        owned_table([
+            bigint("a", [1_i64, -2, 3, 4, 6]),
+            varchar("b", ["x", "yy", "zzz", "", "w"]),
+            boolean("c", [true, false, true, true, false]),
+            decimal75("d", 12, 2, [125_i64, -300, 0, 7, 8]),
+        ]),
+        owned_table([
+            bigint("a", [1_i64, -2, 3, 4, 5]),
+            varchar("b", ["x", "yy", "zz", "", "w"]),
+            boolean("c", [true, false, true, true, false]),
+            decimal75("d", 12, 2, [125_i64, -300, 0, 7, 8]),
+        ]),
+        owned_table([
+            bigint("a", [1_i64, -2, 3, 4, 5]),
+            varchar("b", ["x", "yy", "zzz", "", "w"]),
+            boolean("c", [false, false, true, true, false]),
+            decimal75("d", 12, 2, [125_i64, -300, 0, 7, 8]),
+        ]),
+    ];
+    for other in &differing {
+        assert_eq!(
+            prove_and_verify(&table, other),
+            Err(TableEqualityError::TablesDiffer)
+        );
+    }
+}
+
+#[test]
+fn we_cannot_prove_that_tables_agreeing_at_a_point_known_before_commitment_are_equal() {
+    // The point that a transcript binding only the schema and the rows would draw
+    let mut transcript = Keccak256Transcript::new();
+    transcript
+        .extend_serialize_as_le(&[ColumnField::new("a".parse().unwrap(), ColumnType::Scalar)][..]);
+    transcript.extend_serialize_as_le(&(0_usize, 4_usize));
+    let point: Vec<DoryScalar> = (0..2)
+        .map(|_| transcript.scalar_challenge_as_be())
+        .collect();
+    let mut evaluation_vec = vec![DoryScalar::ZERO; 4];
+    compute_evaluation_vector(&mut evaluation_vec, &point);
+
+    // Shift the first two rows so that the shift is orthogonal to the evaluation vector
+    let a_values = [DoryScalar::from(1), 2.into(), 3.into(), 4.into()];
+    let mut b_values = a_values;
+    b_values[0] += evaluation_vec[1];
+    b_values[1] -= evaluation_vec[0];
+    let inner_product = |values: &[DoryScalar]| {
+        values
+            .iter()
+            .zip(&evaluation_vec)
+            .fold(DoryScalar::ZERO, |acc, (value, eq)| acc + *value * *eq)
+    };
+    assert_ne!(a_values, b_values);
+    assert_eq!(inner_product(&a_values), inner_product(&b_values));
+
+    let a = owned_table([scalar("a", a_values)]);
+    let b = owned_table([scalar("a", b_values)]);
+    assert_eq!(
+        prove_and_verify(&a, &b),
+        Err(TableEqualityError::TablesDiffer)
+    );
+}
+
+#[test]
+fn we_cannot_verify_a_proof_of_equality_against_a_different_table() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let prover_public_setup = DoryProverPublicSetup::new(&prover_setup, 2);
+    let verifier_public_setup = DoryVerifierPublicSetup::new(&verifier_setup, 2);
+
+    let table = sample_table();
+    let other: OwnedTable<DoryScalar> = owned_table([
+        bigint("a", [1_i64, -2, 3, 4, 5]),
+        varchar("b", ["x", "yy", "zzz", "", "w"]),
+        boolean("c", [true, false, true, true, false]),
+        decimal75("d", 12, 2, [125_i64, -300, 0, 7, 9]),
+    ]);
+    // A proof that the table equals itself claims that every difference is zero,
+    // so it passes the zero check but not the evaluation proof against the other table.
+    let proof =
+        prove_table_equality::<DoryEvaluationProof>(&table, &table, 0, &prover_public_setup)
+            .unwrap();
+    assert_eq!(
+        verify_table_equality(
+            &proof,
+            &TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+                &table,
+                0,
+                &prover_public_setup
+            ),
+            &TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+                &other,
+                0,
+                &prover_public_setup
+            ),
+            &verifier_public_setup,
+        ),
+        Err(TableEqualityError::EvaluationProofFailed)
+    );
+
+    let mut proof = proof;
+    proof.difference_evaluations.pop();
+    assert_eq!(
+        verify_table_equality(
+            &proof,
+            &TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+                &table,
+                0,
+                &prover_public_setup
+            ),
+            &TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+                &table,
+                0,
+                &prover_public_setup
+            ),
+            &verifier_public_setup,
+        ),
+        Err(TableEqualityError::InvalidProofSize {
+            expected: 4,
+            actual: 3
+        })
+    );
+}
+
+#[test]
+fn we_cannot_prove_or_verify_that_tables_with_different_schemas_are_equal() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let prover_public_setup = DoryProverPublicSetup::new(&prover_setup, 2);
+    let verifier_public_setup = DoryVerifierPublicSetup::new(&verifier_setup, 2);
+
+    let table = owned_table([bigint("a", [1_i64, 2]), int("b", [3, 4])]);
+    let mismatched: [OwnedTable<DoryScalar>; 4] = [
+        owned_table([bigint("a", [1_i64, 2]), bigint("b", [3_i64, 4])]),
+        owned_table([bigint("a", [1_i64, 2]), int("c", [3, 4])]),
+        owned_table([int("b", [3, 4]), bigint("a", [1_i64, 2])]),
+        owned_table([bigint("a", [1_i64, 2])]),
+    ];
+    for other in &mismatched {
+        assert_eq!(
+            prove_table_equality::<DoryEvaluationProof>(&table, other, 0, &prover_public_setup)
+                .err(),
+            Some(TableEqualityError::SchemaMismatch)
+        );
+        let proof =
+            prove_table_equality::<DoryEvaluationProof>(&table, &table, 0, &prover_public_setup)
+                .unwrap();
+        assert_eq!(
+            verify_table_equality(
+                &proof,
+                &TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+                    &table,
+                    0,
+                    &prover_public_setup
+                ),
+                &TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+                    other,
+                    0,
+                    &prover_public_setup
+                ),
+                &verifier_public_setup,
+            ),
+            Err(TableEqualityError::SchemaMismatch)
+        );
+    }
+
+    let shorter = owned_table([bigint("a", [1_i64]), int("b", [3])]);
+    assert_eq!(
+        prove_table_equality::<DoryEvaluationProof>(&table, &shorter, 0, &prover_public_setup)
+            .err(),
+        Some(TableEqualityError::RangeMismatch {
+            left: 0..2,
+            right: 0..1
+        })
+    );
+}
@@ -1,14 +1,18 @@
-use super::{ProofPlan, ProvableQueryResult, QueryData, QueryProof, QueryResult};
+use super::{
+    check_row_counts, proof_envelope, DeserializeError, EnvelopePayload, ProofPlan, ProofStage,
+    ProvableQueryResult, ProveError, ProveOptions, QueryData, QueryProof, QueryResult,
+};
 use crate::base::{
     commitment::CommitmentEvaluationProof,
     database::{
-        ColumnField, ColumnType, CommitmentAccessor, DataAccessor, OwnedColumn, OwnedTable,
+        ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor,
+        OwnedColumn, OwnedTable,
     },
     proof::ProofError,
     scalar::Scalar,
 };
-use alloc::vec;
-use serde::{Deserialize, Serialize};
+use alloc::{vec, vec::Vec};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 /// The result of an sql query along with a proof that the query is valid. The
 /// result and proof can be verified using commitments to database columns.
@@ -79,11 +83,58 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
     ///
     /// This function both computes the result of a query and constructs a proof of the results
     /// validity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns referenced by the query do not have the row counts of their tables.
+    /// See [`VerifiableQueryResult::try_new`] for a non-panicking version.
+    ///
+    /// Unlike [`VerifiableQueryResult::try_new`], this does not check that the values of the result fit the types of their columns.
+    /// A result that overflows is proven anyway, and the verifier rejects it with [`QueryError::Overflow`](super::QueryError::Overflow).
     pub fn new(
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
     ) -> Self {
+        let options = ProveOptions {
+            check_overflow: false,
+            ..ProveOptions::default()
+        };
+        Self::try_new_with_options(expr, accessor, setup, options)
+            .expect("Failed to create verifiable query result")
+    }
+
+    /// Form a `VerifiableQueryResult` from a query expression.
+    ///
+    /// Will error if the columns referenced by the query do not have the row counts of their tables,
+    /// or with [`ProveError::ResultOverflow`] if a value of the result does not fit the type of its column.
+    pub fn try_new(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> Result<Self, ProveError> {
+        Self::try_new_with_options(expr, accessor, setup, ProveOptions::default())
+    }
+
+    /// Form a `VerifiableQueryResult` from a query expression with the given [`ProveOptions`].
+    ///
+    /// See [`QueryProof::try_new_with_options`] for how the options are used.
+    /// A `VerifiableQueryResult` discloses every column of the result, so the options must not hide any column.
+    /// If every table the query reads is empty, no proof is created, so only the completion of the proof is reported.
+    ///
+    /// Will error as [`QueryProof::try_new_with_options`] does, or with [`ProveError::HiddenColumnNotSupported`]
+    /// if a column is asked to be hidden.
+    pub fn try_new_with_options(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        mut options: ProveOptions<'_, CP::Scalar>,
+    ) -> Result<Self, ProveError> {
+        if let Some(&column) = options.hidden_columns.first() {
+            return Err(ProveError::HiddenColumnNotSupported { column });
+        }
+        check_row_counts(expr, accessor)?;
+
         // a query must have at least one result column; if not, it should
         // have been rejected at the parsing stage.
 
@@ -93,17 +144,20 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
             .into_iter()
             .all(|table_ref| accessor.get_length(table_ref) == 0)
         {
-            return VerifiableQueryResult {
+            if let Some(progress) = options.progress.as_mut() {
+                progress(ProofStage::Finalizing, 1.0);
+            }
+            return Ok(VerifiableQueryResult {
                 provable_result: None,
                 proof: None,
-            };
+            });
         }
 
-        let (proof, res) = QueryProof::new(expr, accessor, setup);
-        Self {
-            provable_result: Some(res),
+        let (proof, res) = QueryProof::try_new_with_options(expr, accessor, setup, options)?;
+        Ok(Self {
+            provable_result: Some(res.disclosed),
             proof: Some(proof),
-        }
+        })
     }
 
     /// Verify a `VerifiableQueryResult`. Upon success, this function returns the finalized form of
@@ -123,6 +177,42 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl CommitmentAccessor<CP::Commitment>,
         setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        self.verify_with(expr, accessor, |proof, provable_result| {
+            proof.verify(expr, accessor, provable_result, setup)
+        })
+    }
+
+    /// Verify a `VerifiableQueryResult`, pulling the commitment of each referenced column from `commitment_source`
+    /// only when it is needed. See [`QueryProof::verify_streaming`].
+    ///
+    /// Given the same commitments, the result is identical to that of [`Self::verify`].
+    ///
+    /// Note: This does NOT transform the result!
+    /// # Panics
+    /// Panics under the same conditions as [`Self::verify`].
+    pub fn verify_streaming(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl MetadataAccessor,
+        commitment_source: impl FnMut(usize, ColumnRef) -> CP::Commitment,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> QueryResult<CP::Scalar> {
+        self.verify_with(expr, accessor, |proof, provable_result| {
+            proof.verify_streaming(expr, accessor, commitment_source, provable_result, setup)
+        })
+    }
+
+    /// Handle the empty case, then verify the proof with `verify_proof`.
+    ///
+    /// # Panics
+    /// Panics if `self.proof` is `None` or `self.provable_result` is `None` after the empty-case checks,
+    /// which cannot happen since they are checked to both be `Some`.
+    fn verify_with(
+        &self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl MetadataAccessor,
+        verify_proof: impl FnOnce(&QueryProof<CP>, &ProvableQueryResult) -> QueryResult<CP::Scalar>,
     ) -> QueryResult<CP::Scalar> {
         // a query must have at least one result column; if not, it should
         // have been rejected at the parsing stage.
@@ -149,15 +239,46 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
                 error: "non-zero sumcheck variables but empty result",
             })?;
         }
-        self.proof.as_ref().unwrap().verify(
-            expr,
-            accessor,
+        verify_proof(
+            self.proof.as_ref().unwrap(),
             self.provable_result.as_ref().unwrap(),
-            setup,
         )
     }
 }
 
+impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP>
+where
+    Self: Serialize + EnvelopePayload,
+{
+    /// Returns the length of the postcard serialization of this result, without serializing it.
+    ///
+    /// See [`proof_envelope::serialized_size`].
+    #[must_use]
+    pub fn serialized_size(&self) -> usize {
+        proof_envelope::serialized_size(self)
+    }
+
+    /// Serialize this result into a versioned proof envelope.
+    ///
+    /// See [`proof_envelope::to_envelope_bytes`] for the layout of the envelope.
+    #[must_use]
+    pub fn to_envelope_bytes(&self) -> Vec<u8> {
+        proof_envelope::to_envelope_bytes(self)
+    }
+
+    /// Deserialize a result that was serialized with [`Self::to_envelope_bytes`].
+    ///
+    /// Will error without reading the payload if the envelope has the wrong magic number or an unsupported version.
+    pub fn try_from_envelope_bytes(bytes: &[u8]) -> Result<Self, DeserializeError> {
+        proof_envelope::try_from_envelope_bytes(bytes)
+    }
+}
+
+impl<CP: CommitmentEvaluationProof + DeserializeOwned> EnvelopePayload
+    for VerifiableQueryResult<CP>
+{
+}
+
 fn make_empty_query_result<S: Scalar>(result_fields: &[ColumnField]) -> QueryResult<S> {
     let table = OwnedTable::try_new(
         result_fields
@@ -171,12 +292,14 @@ fn make_empty_query_result<S: Scalar>(result_fields: &[ColumnField]) -> QueryRes
                         ColumnType::SmallInt => OwnedColumn::SmallInt(vec![]),
                         ColumnType::Int => OwnedColumn::Int(vec![]),
                         ColumnType::BigInt => OwnedColumn::BigInt(vec![]),
+                        ColumnType::UInt64 => OwnedColumn::UInt64(vec![]),
                         ColumnType::Int128 => OwnedColumn::Int128(vec![]),
                         ColumnType::Decimal75(precision, scale) => {
                             OwnedColumn::Decimal75(precision, scale, vec![])
                         }
                         ColumnType::Scalar => OwnedColumn::Scalar(vec![]),
                         ColumnType::VarChar => OwnedColumn::VarChar(vec![]),
+                        ColumnType::FixedBytes(width) => OwnedColumn::FixedBytes(width, vec![]),
                         ColumnType::TimestampTZ(tu, tz) => OwnedColumn::TimestampTZ(tu, tz, vec![]),
                     },
                 )
@@ -186,5 +309,6 @@ fn make_empty_query_result<S: Scalar>(result_fields: &[ColumnField]) -> QueryRes
     Ok(QueryData {
         table,
         verification_hash: Default::default(),
+        result_schema: result_fields.iter().copied().collect(),
     })
 }
@@ -110,10 +110,7 @@ fn we_can_verify_queries_on_an_empty_table() {
         (),
     );
     let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
-    let QueryData {
-        verification_hash: _,
-        table,
-    } = res.verify(&expr, &accessor, &()).unwrap();
+    let QueryData { table, .. } = res.verify(&expr, &accessor, &()).unwrap();
     let expected_res = owned_table([bigint("a1", [0; 0])]);
     assert_eq!(table, expected_res);
 }
@@ -0,0 +1,59 @@
+use core::time::Duration;
+
+/// Rough time of a field multiplication
+const FIELD_OPERATION_NANOS: u64 = 50;
+/// Rough time per term of a multi-scalar multiplication of up to a few hundred terms
+const MSM_TERM_NANOS: u64 = 50_000;
+/// Rough time of a pairing, or of a target group operation of comparable cost
+const PAIRING_NANOS: u64 = 1_000_000;
+/// Pairing-equivalent operations of the evaluation proof per variable of the evaluation point
+const PAIRINGS_PER_VARIABLE: usize = 2;
+/// Pairings of the final check of the evaluation proof
+const FINAL_PAIRINGS: usize = 4;
+
+/// An estimate of the cost of verifying a [`QueryProof`](super::QueryProof), from the shape of the proof.
+///
+/// See [`QueryProof::estimate_verify_cost`](super::QueryProof::estimate_verify_cost).
+/// The counts and time grow with the actual cost of verification,
+/// but are not an accurate prediction of how long verification takes on any machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyCost {
+    /// The number of rounds of the sumcheck proof
+    pub sumcheck_rounds: usize,
+    /// The number of field operations of checking the sumcheck rounds and batching the MLE evaluations
+    pub field_operations: usize,
+    /// The number of terms of the multi-scalar multiplication that folds the commitments of the MLEs,
+    /// both the intermediate ones in the proof and the column commitments from the accessor
+    pub msm_size: usize,
+    /// The number of pairings, or of target group operations of comparable cost, of verifying the evaluation proof
+    ///
+    /// This is costed as for a pairing-based scheme such as Dory,
+    /// whose verifier does a constant number of such operations per variable of the evaluation point.
+    pub pairings: usize,
+    /// A rough estimate of the time verification takes
+    pub estimated_time: Duration,
+}
+
+impl VerifyCost {
+    /// Estimate the cost of verifying a proof with `sumcheck_rounds` rounds, whose round polynomials
+    /// have `sumcheck_coefficients` coefficients in total, and which evaluates `mle_evaluations` committed MLEs.
+    pub(super) fn new(
+        sumcheck_rounds: usize,
+        sumcheck_coefficients: usize,
+        mle_evaluations: usize,
+    ) -> Self {
+        let field_operations = 2 * sumcheck_coefficients + 2 * mle_evaluations;
+        let msm_size = mle_evaluations;
+        let pairings = PAIRINGS_PER_VARIABLE * sumcheck_rounds + FINAL_PAIRINGS;
+        let estimated_nanos = FIELD_OPERATION_NANOS * field_operations as u64
+            + MSM_TERM_NANOS * msm_size as u64
+            + PAIRING_NANOS * pairings as u64;
+        Self {
+            sumcheck_rounds,
+            field_operations,
+            msm_size,
+            pairings,
+            estimated_time: Duration::from_nanos(estimated_nanos),
+        }
+    }
+}
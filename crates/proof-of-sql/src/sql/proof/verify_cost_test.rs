@@ -0,0 +1,67 @@
+use super::{QueryProof, VerifyCost};
+use crate::{
+    base::database::{owned_table_utility::*, OwnedTableTestAccessor},
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, ProverSetup, PublicParameters,
+    },
+    sql::{proof_exprs::test_utility::*, proof_plans::test_utility::*},
+};
+use core::time::Duration;
+
+/// Prove `SELECT a FROM sxt.t WHERE b = 1` over a table with `num_rows` rows
+fn filter_proof_over_rows(num_rows: i64) -> QueryProof<DoryEvaluationProof> {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", 0..num_rows),
+            bigint("b", (0..num_rows).map(|i| i % 2)),
+        ]),
+        0,
+        dory_prover_setup,
+    );
+    let expr = filter(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "b", &accessor), const_bigint(1)),
+    );
+    QueryProof::new(&expr, &accessor, &dory_prover_setup).0
+}
+
+#[test]
+fn a_proof_with_more_sumcheck_rounds_has_a_higher_estimated_verify_cost() {
+    let costs: Vec<VerifyCost> = [2, 8, 64]
+        .into_iter()
+        .map(|num_rows| filter_proof_over_rows(num_rows).estimate_verify_cost())
+        .collect();
+    assert_eq!(
+        costs
+            .iter()
+            .map(|cost| cost.sumcheck_rounds)
+            .collect::<Vec<_>>(),
+        [1, 3, 6]
+    );
+    for pair in costs.windows(2) {
+        assert!(pair[0].field_operations < pair[1].field_operations);
+        assert!(pair[0].pairings < pair[1].pairings);
+        assert!(pair[0].estimated_time < pair[1].estimated_time);
+        // The same query folds the same commitments, whatever the size of the table
+        assert_eq!(pair[0].msm_size, pair[1].msm_size);
+    }
+}
+
+#[test]
+fn the_estimated_verify_cost_is_derived_from_the_shape_of_the_proof() {
+    let proof = filter_proof_over_rows(8);
+    let cost = proof.estimate_verify_cost();
+    assert_eq!(cost.sumcheck_rounds, proof.num_sumcheck_rounds());
+    assert_eq!(cost.msm_size, proof.pcs_proof_evaluations.len());
+    assert_eq!(
+        cost.field_operations,
+        2 * proof.sumcheck_proof.num_coefficients() + 2 * proof.pcs_proof_evaluations.len()
+    );
+    assert!(cost.estimated_time > Duration::ZERO);
+}
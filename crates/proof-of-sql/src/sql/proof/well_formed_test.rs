@@ -0,0 +1,126 @@
+use super::{MalformedError, ProvableQueryResult, QueryProof};
+use crate::{
+    base::{
+        bit::BitDistribution,
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor, TableRef},
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        proof_exprs::test_utility::*,
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+
+fn accessor(
+    setup: DoryProverPublicSetup<'_>,
+) -> (TableRef, OwnedTableTestAccessor<'_, DoryEvaluationProof>) {
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1_i64, 2, 3, 4, 5]),
+            bigint("b", [5_i64, 6, 7, 8, 9]),
+        ]),
+        0,
+        setup,
+    );
+    (t, accessor)
+}
+
+// select b from sxt.t where a >= 3
+fn plan(t: TableRef, accessor: &OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan {
+    filter(
+        cols_expr_plan(t, &["b"], accessor),
+        tab(t),
+        gte(column(t, "a", accessor), const_bigint(3)),
+    )
+}
+
+#[test]
+fn a_proof_with_an_incorrect_result_is_still_well_formed() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = plan(t, &accessor);
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    assert_eq!(proof.check_well_formed(), Ok(()));
+    assert!(proof
+        .verify(&plan, &accessor, &result, &verifier_setup)
+        .is_ok());
+
+    // b = 8 becomes b = 10
+    let tampered_result = ProvableQueryResult::new(3, &[Column::<DoryScalar>::BigInt(&[7, 10, 9])]);
+    let mut tampered_proof = proof.clone();
+    tampered_proof.pcs_proof_evaluations[0] += DoryScalar::from(1);
+    for (proof, result) in [(&proof, &tampered_result), (&tampered_proof, &result)] {
+        assert_eq!(proof.check_well_formed(), Ok(()));
+        assert!(proof
+            .verify(&plan, &accessor, result, &verifier_setup)
+            .is_err());
+    }
+}
+
+#[test]
+fn we_cannot_pass_a_malformed_proof_as_well_formed() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let (t, accessor) = accessor(setup);
+    let plan = plan(t, &accessor);
+    let (proof, _) = QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    assert!(!proof.bit_distributions.is_empty());
+
+    let mut malformed = proof.clone();
+    malformed.bit_distributions.push(BitDistribution {
+        or_all: [0; 4],
+        vary_mask: [1, 0, 0, 0],
+    });
+    assert_eq!(
+        malformed.check_well_formed(),
+        Err(MalformedError::InvalidBitDistribution {
+            index: proof.bit_distributions.len()
+        })
+    );
+
+    let mut malformed = proof.clone();
+    malformed
+        .one_evaluation_lengths
+        .push(proof.range_length + 1);
+    assert_eq!(
+        malformed.check_well_formed(),
+        Err(MalformedError::OneEvaluationLengthOutOfRange {
+            length: proof.range_length + 1,
+            range_length: proof.range_length
+        })
+    );
+
+    // A range of 2^40 rows needs 40 sumcheck rounds, more than the proof has coefficients.
+    let mut malformed = proof.clone();
+    malformed.range_length = 1 << 40;
+    assert_eq!(
+        malformed.check_well_formed(),
+        Err(MalformedError::InvalidSumcheckProofSize {
+            num_coefficients: proof.sumcheck_proof.num_coefficients(),
+            num_rounds: 40
+        })
+    );
+
+    let mut malformed = proof.clone();
+    malformed
+        .pcs_proof_evaluations
+        .truncate(proof.commitments.len() - 1);
+    assert_eq!(
+        malformed.check_well_formed(),
+        Err(MalformedError::MissingEvaluations {
+            num_evaluations: proof.commitments.len() - 1,
+            num_commitments: proof.commitments.len()
+        })
+    );
+}
@@ -0,0 +1,135 @@
+use super::{
+    count_sign, prover_evaluate_sign, result_evaluate_sign, verifier_evaluate_sign, DynProofExpr,
+    ProofExpr,
+};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+};
+use alloc::{boxed::Box, vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+
+/// Provable numerical `ABS` expression
+///
+/// The result has the same type as the operand. Like unary `-`, it is computed exactly,
+/// so the absolute value of the smallest value of a signed integer type does not wrap around.
+/// Such results only fail, with an overflow error, if they are selected.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AbsExpr {
+    expr: Box<DynProofExpr>,
+}
+
+impl AbsExpr {
+    /// Create numerical `ABS` expression
+    pub fn new(expr: Box<DynProofExpr>) -> Self {
+        Self { expr }
+    }
+}
+
+/// The absolute value of every value of `values`, given whether each value is negative.
+fn abs_values<'a, S: Scalar>(alloc: &'a Bump, values: &[S], sign: &[bool]) -> &'a [S] {
+    alloc.alloc_slice_fill_with(
+        values.len(),
+        |i| {
+            if sign[i] {
+                -values[i]
+            } else {
+                values[i]
+            }
+        },
+    )
+}
+
+impl ProofExpr for AbsExpr {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)?;
+        count_sign(builder)?;
+        builder.count_subpolynomials(1);
+        builder.count_intermediate_mles(1);
+        builder.count_degree(3);
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        self.expr.data_type()
+    }
+
+    #[tracing::instrument(name = "AbsExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let expr_column: Column<'a, S> = self.expr.result_evaluate(alloc, table);
+        let values = alloc.alloc_slice_copy(&expr_column.to_scalar_with_scaling(0));
+        let sign = result_evaluate_sign(table.num_rows(), alloc, values);
+        Column::Scalar(abs_values(alloc, values, sign))
+    }
+
+    #[tracing::instrument(name = "AbsExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let expr_column: Column<'a, S> = self.expr.prover_evaluate(builder, alloc, table);
+        let values: &[_] = alloc.alloc_slice_copy(&expr_column.to_scalar_with_scaling(0));
+
+        // sign(expr) == -1
+        let sign = prover_evaluate_sign(
+            builder,
+            alloc,
+            values,
+            #[cfg(test)]
+            false,
+        );
+
+        // abs
+        let abs = abs_values(alloc, values, sign);
+        builder.produce_intermediate_mle(abs);
+
+        // subpolynomial: abs - expr + 2 * sign * expr
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::one(), vec![Box::new(abs)]),
+                (-S::one(), vec![Box::new(values)]),
+                (S::TWO, vec![Box::new(sign), Box::new(values)]),
+            ],
+        );
+        Column::Scalar(abs)
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let eval = self.expr.verifier_evaluate(builder, accessor, one_eval)?;
+
+        // sign(expr) == -1
+        let sign = verifier_evaluate_sign(builder, eval, one_eval)?;
+
+        // abs
+        let abs = builder.consume_intermediate_mle();
+
+        // subpolynomial: abs - expr + 2 * sign * expr
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            abs - eval + S::TWO * sign * eval,
+        );
+        Ok(abs)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{
+            owned_table_utility::*, table_utility::*, Column, ColumnOperationError, ColumnType,
+            OwnedTableTestAccessor, TableTestAccessor, TestAccessor,
+        },
+        scalar::test_scalar::TestScalar,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, ProveError, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use bumpalo::Bump;
+
+// select abs(a) as abs_a, b from sxt.t where abs(a) <= 5
+#[test]
+fn we_can_prove_a_query_with_the_absolute_value_of_positive_negative_and_zero_values() {
+    let data = owned_table([
+        bigint("a", [5_i64, -3, 0, -6, i64::MAX, -i64::MAX]),
+        bigint("b", [1_i64, 2, 3, 4, 5, 6]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(abs(column(t, "a", &accessor)), "abs_a"),
+            col_expr_plan(t, "b", &accessor),
+        ],
+        tab(t),
+        lte(abs(column(t, "a", &accessor)), const_bigint(5)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("abs_a", [5_i64, 3, 0]), bigint("b", [1_i64, 2, 3])]);
+    assert_eq!(res, expected_res);
+}
+
+// select abs(a) as abs_a from sxt.t
+#[test]
+fn we_can_prove_a_query_with_the_absolute_value_of_decimals() {
+    let data = owned_table([decimal75("a", 10, 2, [125_i64, -300, 0])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![aliased_plan(abs(column(t, "a", &accessor)), "abs_a")],
+        tab(t),
+        const_bool(true),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([decimal75("abs_a", 10, 2, [125_i64, 300, 0])]);
+    assert_eq!(res, expected_res);
+}
+
+// select abs(a) as abs_a from sxt.t where b = 1
+#[test]
+fn the_absolute_value_of_the_smallest_value_overflows_only_if_it_is_selected() {
+    let data = owned_table([
+        smallint("a", [i16::MIN, i16::MIN]),
+        smallint("b", [1_i16, 0]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast_with_filter = |filter_val| {
+        filter(
+            vec![aliased_plan(abs(column(t, "a", &accessor)), "abs_a")],
+            tab(t),
+            equal(column(t, "b", &accessor), const_bigint(filter_val)),
+        )
+    };
+    let ast = ast_with_filter(1);
+    assert_eq!(
+        VerifiableQueryResult::<InnerProductProof>::try_new(&ast, &accessor, &()).err(),
+        Some(ProveError::ResultOverflow {
+            column: "abs_a".parse().unwrap(),
+            row: 0,
+            column_type: ColumnType::SmallInt,
+        })
+    );
+
+    let ast = ast_with_filter(2);
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &())
+        .verify(&ast, &accessor, &())
+        .unwrap()
+        .table;
+    assert_eq!(res, owned_table([smallint("abs_a", [0_i16; 0])]));
+}
+
+#[test]
+fn we_cannot_take_the_absolute_value_of_non_numeric_expressions() {
+    let data = owned_table([varchar("a", ["x"]), boolean("b", [true])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for name in ["a", "b"] {
+        assert!(matches!(
+            DynProofExpr::try_new_abs(column(t, name, &accessor)),
+            Err(ConversionError::ColumnOperationError {
+                source: ColumnOperationError::UnaryOperationInvalidColumnType { .. }
+            })
+        ));
+    }
+}
+
+#[test]
+fn we_can_compute_the_correct_output_of_an_abs_expr_using_result_evaluate() {
+    let alloc = Bump::new();
+    let data = table([borrowed_int("a", [7, -2, 0], &alloc)]);
+    let mut accessor = TableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let t = "sxt.t".parse().unwrap();
+    accessor.add_table(t, data.clone(), 0);
+    let abs_expr: DynProofExpr = abs(column(t, "a", &accessor));
+    let res = abs_expr.result_evaluate(&alloc, &data);
+    let expected_res_scalar = [7, 2, 0]
+        .iter()
+        .map(|&v| TestScalar::from(v))
+        .collect::<Vec<_>>();
+    let expected_res = Column::Scalar(&expected_res_scalar);
+    assert_eq!(res, expected_res);
+}
@@ -2,14 +2,14 @@ use crate::{
     base::{
         commitment::InnerProductProof,
         database::{
-            owned_table_utility::*, table_utility::*, Column, OwnedTableTestAccessor,
+            owned_table_utility::*, table_utility::*, Column, ColumnType, OwnedTableTestAccessor,
             TableTestAccessor,
         },
         scalar::{test_scalar::TestScalar, Curve25519Scalar},
     },
     sql::{
         parse::ConversionError,
-        proof::{exercise_verification, QueryError, VerifiableQueryResult},
+        proof::{exercise_verification, ProveError, VerifiableQueryResult},
         proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
         proof_plans::{test_utility::*, DynProofPlan},
     },
@@ -58,6 +58,50 @@ fn we_can_prove_a_typical_add_subtract_query() {
     assert_eq!(res, expected_res);
 }
 
+// select a + b as total, a - 1 as diff, a + c as mixed from sxt.t where a >= ubigint 9223372036854775807
+#[test]
+fn we_can_prove_an_add_subtract_query_on_unsigned_values_around_the_maximum_signed_value() {
+    let signed_max = i64::MAX as u64;
+    let data = owned_table([
+        uint64("a", [signed_max, signed_max + 1, 1, u64::MAX - 1]),
+        uint64("b", [1_u64, signed_max, 0, 1]),
+        bigint("c", [-1_i64, i64::MIN, 0, i64::MAX]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(
+                add(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "total",
+            ),
+            aliased_plan(subtract(column(t, "a", &accessor), const_uint64(1)), "diff"),
+            aliased_plan(
+                add(column(t, "a", &accessor), column(t, "c", &accessor)),
+                "mixed",
+            ),
+        ],
+        tab(t),
+        gte(column(t, "a", &accessor), const_uint64(signed_max)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        uint64("total", [signed_max + 1, u64::MAX, u64::MAX]),
+        uint64("diff", [signed_max - 1, signed_max, u64::MAX - 2]),
+        int128(
+            "mixed",
+            [
+                i128::from(i64::MAX) - 1,
+                0,
+                i128::from(u64::MAX - 1) + i128::from(i64::MAX),
+            ],
+        ),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
 // select a, a + b + c + 0.4 as c, d from sxt.t where a - b = 0.5
 #[test]
 fn we_can_prove_a_typical_add_subtract_query_with_decimals() {
@@ -131,12 +175,14 @@ fn result_expr_can_overflow() {
         tab(t),
         equal(column(t, "b", &accessor), const_bigint(1)),
     );
-    let verifiable_res: VerifiableQueryResult<InnerProductProof> =
-        VerifiableQueryResult::new(&ast, &accessor, &());
-    assert!(matches!(
-        verifiable_res.verify(&ast, &accessor, &()),
-        Err(QueryError::Overflow)
-    ));
+    assert_eq!(
+        VerifiableQueryResult::<InnerProductProof>::try_new(&ast, &accessor, &()).err(),
+        Some(ProveError::ResultOverflow {
+            column: "c".parse().unwrap(),
+            row: 0,
+            column_type: ColumnType::SmallInt,
+        })
+    );
 }
 
 // select a + b as c from sxt.t where b == 0
@@ -209,12 +255,68 @@ fn result_expr_can_overflow_more() {
         tab(t),
         const_bool(true),
     );
+    assert_eq!(
+        VerifiableQueryResult::<InnerProductProof>::try_new(&ast, &accessor, &()).err(),
+        Some(ProveError::ResultOverflow {
+            column: "c".parse().unwrap(),
+            row: 0,
+            column_type: ColumnType::BigInt,
+        })
+    );
+}
+
+// Integer columns are signed, so a subtraction that goes below zero is a negative value
+// rather than a wrapped-around field element.
+// select a - b as c from sxt.t where a - b <= -1
+#[test]
+fn subtracting_a_larger_value_gives_a_negative_result() {
+    let data = owned_table([
+        smallint("a", [1_i16, 5, 0, i16::MIN + 1]),
+        smallint("b", [3_i16, 2, i16::MAX, 1]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan = filter(
+        vec![aliased_plan(
+            subtract(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "c",
+        )],
+        tab(t),
+        lte(
+            subtract(column(t, "a", &accessor), column(t, "b", &accessor)),
+            const_bigint(-1),
+        ),
+    );
     let verifiable_res: VerifiableQueryResult<InnerProductProof> =
         VerifiableQueryResult::new(&ast, &accessor, &());
-    assert!(matches!(
-        verifiable_res.verify(&ast, &accessor, &()),
-        Err(QueryError::Overflow)
-    ));
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([smallint("c", [-2_i16, -i16::MAX, i16::MIN])]);
+    assert_eq!(res, expected_res);
+}
+
+// select a - b as c from sxt.t
+#[test]
+fn subtracting_a_larger_value_can_underflow() {
+    let data = owned_table([smallint("a", [0_i16, i16::MIN]), smallint("b", [1_i16, 1])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast: DynProofPlan = filter(
+        vec![aliased_plan(
+            subtract(column(t, "a", &accessor), column(t, "b", &accessor)),
+            "c",
+        )],
+        tab(t),
+        const_bool(true),
+    );
+    assert_eq!(
+        VerifiableQueryResult::<InnerProductProof>::try_new(&ast, &accessor, &()).err(),
+        Some(ProveError::ResultOverflow {
+            column: "c".parse().unwrap(),
+            row: 1,
+            column_type: ColumnType::SmallInt,
+        })
+    );
 }
 
 fn test_random_tables_with_given_offset(offset: usize) {
@@ -2,7 +2,7 @@ use crate::{
     base::{
         commitment::InnerProductProof,
         database::{
-            owned_table_utility::*, table_utility::*, Column, OwnedTableTestAccessor,
+            owned_table_utility::*, table_utility::*, Column, OwnedTableTestAccessor, Table,
             TableTestAccessor,
         },
         scalar::test_scalar::TestScalar,
@@ -173,3 +173,97 @@ fn we_can_compute_the_correct_output_of_an_and_expr_using_result_evaluate() {
     let expected_res = Column::Boolean(&[false, true, false, false]);
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn we_can_prove_and_evaluate_the_full_truth_table_of_an_and_query() {
+    // Every combination of lhs and rhs values
+    let lhs = [false, false, true, true];
+    let rhs = [false, true, false, true];
+    let data = owned_table([
+        boolean("l", lhs),
+        boolean("r", rhs),
+        bigint("i", [0, 1, 2, 3]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["i"], &accessor),
+        tab(t),
+        and(column(t, "l", &accessor), column(t, "r", &accessor)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("i", [3])]);
+    assert_eq!(res, expected_res);
+
+    let alloc = Bump::new();
+    let data: Table<TestScalar> = table([
+        borrowed_boolean("l", lhs, &alloc),
+        borrowed_boolean("r", rhs, &alloc),
+    ]);
+    let and_expr: DynProofExpr = and(column(t, "l", &accessor), column(t, "r", &accessor));
+    let res = and_expr.result_evaluate(&alloc, &data);
+    let expected_res = Column::Boolean(&[false, false, false, true]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_deeply_nested_and_or_query() {
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    let row_numbers: Vec<i64> = (0..32).collect();
+    let n = row_numbers.len();
+    let values: Vec<Vec<bool>> = (0..3)
+        .map(|_| {
+            (0..n)
+                .map(|_| Uniform::new(0, 2).sample(&mut rng) == 1)
+                .collect()
+        })
+        .collect();
+    let names = ["a", "b", "c"];
+    let data = owned_table([
+        boolean("a", values[0].clone()),
+        boolean("b", values[1].clone()),
+        boolean("c", values[2].clone()),
+        bigint("i", row_numbers.clone()),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+
+    // Build an expression that alternates between AND and OR, and between nesting on the left and
+    // on the right, tracking the expected value of each row alongside it.
+    let mut expr = column(t, "a", &accessor);
+    let mut expected: Vec<bool> = values[0].clone();
+    for depth in 0..24 {
+        let column_index = (depth + 1) % 3;
+        let next = column(t, names[column_index], &accessor);
+        let next_values = &values[column_index];
+        let is_and = depth % 2 == 0;
+        expr = match (is_and, depth % 4 < 2) {
+            (true, true) => and(expr, next),
+            (true, false) => and(next, expr),
+            (false, true) => or(expr, next),
+            (false, false) => or(next, expr),
+        };
+        for (value, next_value) in expected.iter_mut().zip(next_values) {
+            *value = if is_and {
+                *value && *next_value
+            } else {
+                *value || *next_value
+            };
+        }
+    }
+
+    let ast = filter(cols_expr_plan(t, &["i"], &accessor), tab(t), expr);
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint(
+        "i",
+        row_numbers
+            .into_iter()
+            .zip(expected)
+            .filter_map(|(i, is_selected)| is_selected.then_some(i)),
+    )]);
+    assert_eq!(res, expected_res);
+}
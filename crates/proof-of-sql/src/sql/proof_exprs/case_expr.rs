@@ -0,0 +1,241 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{try_case_column_types, Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::{Scalar, ScalarExt},
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+};
+use alloc::{boxed::Box, vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+
+/// Provable `CASE WHEN condition THEN then_expr ELSE else_expr END` expression
+///
+/// The result is proven with the select gadget `out = c * a + (1 - c) * b`, where `c` is the condition,
+/// `a` is the `THEN` result and `b` is the `ELSE` result, together with a check that `c` is boolean.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseExpr {
+    condition: Box<DynProofExpr>,
+    then_expr: Box<DynProofExpr>,
+    else_expr: Box<DynProofExpr>,
+}
+
+impl CaseExpr {
+    /// Create a new `CASE WHEN` expression
+    pub fn new(
+        condition: Box<DynProofExpr>,
+        then_expr: Box<DynProofExpr>,
+        else_expr: Box<DynProofExpr>,
+    ) -> Self {
+        Self {
+            condition,
+            then_expr,
+            else_expr,
+        }
+    }
+
+    /// The factors that rescale the `THEN` and `ELSE` results to the scale of the output.
+    fn scaling_factors<S: Scalar>(&self) -> (S, S) {
+        let scale = self.data_type().scale().unwrap_or(0);
+        let factor =
+            |expr: &DynProofExpr| S::pow10(scale.abs_diff(expr.data_type().scale().unwrap_or(0)));
+        (factor(&self.then_expr), factor(&self.else_expr))
+    }
+
+    /// Select the value of `then_column` where `condition` holds and the value of
+    /// `else_column` elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `condition` is not a boolean column, which cannot happen for expressions built
+    /// with [`DynProofExpr::try_new_case`].
+    fn select<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        condition: Column<'a, S>,
+        then_column: Column<'a, S>,
+        else_column: Column<'a, S>,
+    ) -> Column<'a, S> {
+        let condition = condition
+            .as_boolean()
+            .expect("the condition of a CASE expression is boolean");
+        match (self.data_type(), then_column, else_column) {
+            (ColumnType::Boolean, Column::Boolean(then_values), Column::Boolean(else_values)) => {
+                Column::Boolean(select_slices(alloc, condition, then_values, else_values))
+            }
+            (
+                ColumnType::VarChar,
+                Column::VarChar((then_strings, then_scalars)),
+                Column::VarChar((else_strings, else_scalars)),
+            ) => Column::VarChar((
+                select_slices(alloc, condition, then_strings, else_strings),
+                select_slices(alloc, condition, then_scalars, else_scalars),
+            )),
+            (
+                ColumnType::FixedBytes(width),
+                Column::FixedBytes(_, (then_values, then_scalars)),
+                Column::FixedBytes(_, (else_values, else_scalars)),
+            ) => Column::FixedBytes(
+                width,
+                (
+                    select_slices(alloc, condition, then_values, else_values),
+                    select_slices(alloc, condition, then_scalars, else_scalars),
+                ),
+            ),
+            (
+                ColumnType::TimestampTZ(time_unit, timezone),
+                Column::TimestampTZ(_, _, then_values),
+                Column::TimestampTZ(_, _, else_values),
+            ) => Column::TimestampTZ(
+                time_unit,
+                timezone,
+                select_slices(alloc, condition, then_values, else_values),
+            ),
+            (output_type, then_column, else_column) => {
+                let scale = output_type.scale().unwrap_or(0);
+                let then_scalars = then_column.to_scalar_with_scaling(
+                    scale - self.then_expr.data_type().scale().unwrap_or(0),
+                );
+                let else_scalars = else_column.to_scalar_with_scaling(
+                    scale - self.else_expr.data_type().scale().unwrap_or(0),
+                );
+                Column::Scalar(select_slices(
+                    alloc,
+                    condition,
+                    &then_scalars,
+                    &else_scalars,
+                ))
+            }
+        }
+    }
+}
+
+impl ProofExpr for CaseExpr {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.condition.count(builder)?;
+        self.then_expr.count(builder)?;
+        self.else_expr.count(builder)?;
+        builder.count_subpolynomials(2);
+        builder.count_intermediate_mles(1);
+        builder.count_degree(3);
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        try_case_column_types(self.then_expr.data_type(), self.else_expr.data_type())
+            .expect("Failed to select between column types")
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let condition = self.condition.result_evaluate(alloc, table);
+        let then_column = self.then_expr.result_evaluate(alloc, table);
+        let else_column = self.else_expr.result_evaluate(alloc, table);
+        self.select(alloc, condition, then_column, else_column)
+    }
+
+    #[tracing::instrument(name = "CaseExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let condition = self.condition.prover_evaluate(builder, alloc, table);
+        let then_column = self.then_expr.prover_evaluate(builder, alloc, table);
+        let else_column = self.else_expr.prover_evaluate(builder, alloc, table);
+        let selected = self.select(alloc, condition, then_column, else_column);
+        builder.produce_intermediate_mle(selected);
+
+        // subpolynomial: out - k_a * c * a - k_b * b + k_b * c * b
+        let (then_factor, else_factor): (S, S) = self.scaling_factors();
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::one(), vec![Box::new(selected)]),
+                (
+                    -then_factor,
+                    vec![Box::new(condition), Box::new(then_column)],
+                ),
+                (-else_factor, vec![Box::new(else_column)]),
+                (
+                    else_factor,
+                    vec![Box::new(condition), Box::new(else_column)],
+                ),
+            ],
+        );
+
+        // subpolynomial: c * c - c
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::one(), vec![Box::new(condition), Box::new(condition)]),
+                (-S::one(), vec![Box::new(condition)]),
+            ],
+        );
+
+        selected
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let condition = self
+            .condition
+            .verifier_evaluate(builder, accessor, one_eval)?;
+        let then_eval = self
+            .then_expr
+            .verifier_evaluate(builder, accessor, one_eval)?;
+        let else_eval = self
+            .else_expr
+            .verifier_evaluate(builder, accessor, one_eval)?;
+        let selected = builder.consume_intermediate_mle();
+
+        // subpolynomial: out - k_a * c * a - k_b * b + k_b * c * b
+        let (then_factor, else_factor): (S, S) = self.scaling_factors();
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            selected - then_factor * condition * then_eval - else_factor * else_eval
+                + else_factor * condition * else_eval,
+        );
+
+        // subpolynomial: c * c - c
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            condition * condition - condition,
+        );
+
+        Ok(selected)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.condition.get_column_references(columns);
+        self.then_expr.get_column_references(columns);
+        self.else_expr.get_column_references(columns);
+    }
+}
+
+/// Select `then_values[i]` where `condition[i]` holds and `else_values[i]` elsewhere.
+fn select_slices<'a, T: Copy>(
+    alloc: &'a Bump,
+    condition: &[bool],
+    then_values: &[T],
+    else_values: &[T],
+) -> &'a [T] {
+    alloc.alloc_slice_fill_with(condition.len(), |i| {
+        if condition[i] {
+            then_values[i]
+        } else {
+            else_values[i]
+        }
+    })
+}
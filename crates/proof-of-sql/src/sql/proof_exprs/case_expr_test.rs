@@ -0,0 +1,181 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor, TableRef, TestAccessor},
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+
+fn test_accessor() -> (TableRef, OwnedTableTestAccessor<InnerProductProof>) {
+    let data = owned_table([
+        bigint("a", [1_i64, 2, 3, 4, 5]),
+        bigint("b", [10_i64, 20, 30, 40, 50]),
+        boolean("all_true", [true; 5]),
+        boolean("all_false", [false; 5]),
+        boolean("mixed", [true, false, false, true, false]),
+        varchar("s", ["one", "two", "three", "four", "five"]),
+        int("i", [-1_i32, -2, -3, -4, -5]),
+        decimal75("d", 10, 2, [125_i64, 250, 375, 500, 625]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    (t, accessor)
+}
+
+// select case when c then a else b end as r from sxt.t
+#[test]
+fn we_can_prove_a_case_when_with_all_true_all_false_and_mixed_conditions() {
+    let (t, accessor) = test_accessor();
+    let select_a_or_b = |condition| {
+        aliased_plan(
+            case_when(
+                column(t, condition, &accessor),
+                column(t, "a", &accessor),
+                column(t, "b", &accessor),
+            ),
+            condition,
+        )
+    };
+    let ast = projection(
+        vec![
+            select_a_or_b("all_true"),
+            select_a_or_b("all_false"),
+            select_a_or_b("mixed"),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("all_true", [1_i64, 2, 3, 4, 5]),
+        bigint("all_false", [10_i64, 20, 30, 40, 50]),
+        bigint("mixed", [1_i64, 20, 30, 4, 50]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select case when a >= 3 then s else 'small' end as r, case when mixed then 0 else a end as z from sxt.t
+#[test]
+fn we_can_prove_a_case_when_with_computed_conditions_and_literal_results() {
+    let (t, accessor) = test_accessor();
+    let ast = projection(
+        vec![
+            aliased_plan(
+                case_when(
+                    gte(column(t, "a", &accessor), const_bigint(3)),
+                    column(t, "s", &accessor),
+                    const_varchar("small"),
+                ),
+                "r",
+            ),
+            aliased_plan(
+                case_when(
+                    column(t, "mixed", &accessor),
+                    const_bigint(0),
+                    column(t, "a", &accessor),
+                ),
+                "z",
+            ),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        varchar("r", ["small", "small", "three", "four", "five"]),
+        bigint("z", [0_i64, 2, 3, 0, 5]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select case when mixed then i else d end as r, case when all_false then 1 else i end as w from sxt.t
+#[test]
+fn we_can_prove_a_case_when_whose_results_have_different_numeric_types() {
+    let (t, accessor) = test_accessor();
+    let ast = projection(
+        vec![
+            aliased_plan(
+                case_when(
+                    column(t, "mixed", &accessor),
+                    column(t, "i", &accessor),
+                    column(t, "d", &accessor),
+                ),
+                "r",
+            ),
+            aliased_plan(
+                case_when(
+                    column(t, "all_false", &accessor),
+                    const_bigint(1),
+                    column(t, "i", &accessor),
+                ),
+                "w",
+            ),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        decimal75("r", 12, 2, [-100_i64, 250, 375, -400, 625]),
+        bigint("w", [-1_i64, -2, -3, -4, -5]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select s from sxt.t where case when mixed then a >= 4 else b = 30 end
+#[test]
+fn we_can_prove_a_filter_on_a_case_when_with_boolean_results() {
+    let (t, accessor) = test_accessor();
+    let ast = filter(
+        cols_expr_plan(t, &["s"], &accessor),
+        tab(t),
+        case_when(
+            column(t, "mixed", &accessor),
+            gte(column(t, "a", &accessor), const_bigint(4)),
+            equal(column(t, "b", &accessor), const_bigint(30)),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([varchar("s", ["three", "four"])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_build_a_case_when_with_a_non_boolean_condition_or_incompatible_results() {
+    let (t, accessor) = test_accessor();
+    assert!(matches!(
+        DynProofExpr::try_new_case(
+            column(t, "a", &accessor),
+            column(t, "a", &accessor),
+            column(t, "b", &accessor),
+        ),
+        Err(ConversionError::InvalidDataType { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_case(
+            column(t, "mixed", &accessor),
+            column(t, "s", &accessor),
+            column(t, "a", &accessor),
+        ),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_case(
+            column(t, "mixed", &accessor),
+            column(t, "all_true", &accessor),
+            const_bigint(1),
+        ),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+}
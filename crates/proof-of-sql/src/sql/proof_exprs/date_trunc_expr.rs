@@ -0,0 +1,196 @@
+use super::{
+    count_euclidean_division, divide_euclid, prover_evaluate_euclidean_division,
+    verifier_evaluate_euclidean_division, DynProofExpr, ProofExpr,
+};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, VerificationBuilder},
+};
+use alloc::boxed::Box;
+use bumpalo::Bump;
+use proof_of_sql_parser::posql_time::{DateTruncUnit, PoSQLTimeUnit, PoSQLTimeZone};
+use serde::{Deserialize, Serialize};
+
+/// Provable `DATE_TRUNC` expression, which rounds a timestamp down to the start of its second, minute, hour or day
+///
+/// The result has the type of the timestamp. Units start at the local midnight of the timezone of the timestamp,
+/// so with an offset of `o` ticks and a unit of `m` ticks the result is `ts - r`, where `r` is the remainder of `ts + o`
+/// divided by `m`. The remainder is proven with the truncating-division gadget of `%`,
+/// i.e. `ts + o = q * m + r` with `0 <= r < m`. The local timestamp `ts + o` is an `i64` shifted by less than a day,
+/// so the quotient is range checked to `|q| < 2^64`, which keeps the equation from wrapping around the scalar field.
+/// Truncating a timestamp whose result is before the earliest representable timestamp cannot be proven.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DateTruncExpr {
+    timestamp: Box<DynProofExpr>,
+    unit: DateTruncUnit,
+    // Not serialized, so that plans and their proofs are the same in tests as outside of them
+    #[cfg(test)]
+    #[serde(skip)]
+    pub(crate) forged_remainder_offset: i64,
+}
+
+impl DateTruncExpr {
+    /// Create `DATE_TRUNC` expression
+    pub fn new(timestamp: Box<DynProofExpr>, unit: DateTruncUnit) -> Self {
+        Self {
+            timestamp,
+            unit,
+            #[cfg(test)]
+            forged_remainder_offset: 0,
+        }
+    }
+
+    /// The time unit and timezone of the timestamp
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expression is not a timestamp, which [`DynProofExpr::try_new_date_trunc`] rules out.
+    fn time_unit_and_zone(&self) -> (PoSQLTimeUnit, PoSQLTimeZone) {
+        let ColumnType::TimestampTZ(time_unit, timezone) = self.timestamp.data_type() else {
+            panic!("DATE_TRUNC should only be applied to timestamps");
+        };
+        (time_unit, timezone)
+    }
+
+    /// The length of the unit in ticks of the timestamp, i.e. the divisor
+    fn unit_ticks(&self) -> i64 {
+        self.unit.ticks(self.time_unit_and_zone().0)
+    }
+
+    /// The offset of the timezone of the timestamp from UTC in ticks of the timestamp
+    fn offset_ticks(&self) -> i64 {
+        let (time_unit, timezone) = self.time_unit_and_zone();
+        let offset_seconds = match timezone {
+            PoSQLTimeZone::Utc => 0,
+            PoSQLTimeZone::FixedOffset(offset) => i64::from(offset),
+        };
+        offset_seconds * DateTruncUnit::Second.ticks(time_unit)
+    }
+
+    /// The local timestamps, i.e. the timestamps shifted by the offset of their timezone, which are the dividends
+    fn local_timestamps<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        timestamps: Column<'a, S>,
+    ) -> Column<'a, S> {
+        let offset = self.offset_ticks();
+        if offset == 0 {
+            return timestamps;
+        }
+        let offset = S::from(offset);
+        Column::Scalar(
+            alloc.alloc_slice_fill_iter(
+                timestamps
+                    .to_scalar_with_scaling(0)
+                    .into_iter()
+                    .map(|timestamp| timestamp + offset),
+            ),
+        )
+    }
+}
+
+impl ProofExpr for DateTruncExpr {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.timestamp.count(builder)?;
+        count_euclidean_division(builder)
+    }
+
+    fn data_type(&self) -> ColumnType {
+        self.timestamp.data_type()
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let timestamps: Column<'a, S> = self.timestamp.result_evaluate(alloc, table);
+        let (_, remainders) = divide_euclid(
+            alloc,
+            self.local_timestamps(alloc, timestamps),
+            self.unit_ticks(),
+            #[cfg(test)]
+            self.forged_remainder_offset,
+        );
+        truncate(alloc, timestamps, remainders)
+    }
+
+    #[tracing::instrument(name = "DateTruncExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let timestamps: Column<'a, S> = self.timestamp.prover_evaluate(builder, alloc, table);
+        let remainders = prover_evaluate_euclidean_division(
+            builder,
+            alloc,
+            self.local_timestamps(alloc, timestamps),
+            self.unit_ticks(),
+            #[cfg(test)]
+            self.forged_remainder_offset,
+        );
+        truncate(alloc, timestamps, remainders)
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        if !matches!(self.timestamp.data_type(), ColumnType::TimestampTZ(_, _)) {
+            return Err(ProofError::VerificationError {
+                error: "DATE_TRUNC can only be applied to timestamps",
+            });
+        }
+        let timestamp = self
+            .timestamp
+            .verifier_evaluate(builder, accessor, one_eval)?;
+        let local_timestamp = timestamp + S::from(self.offset_ticks()) * one_eval;
+        // |ts + o| < 2^64, so the quotient by a unit of at least one tick has fewer than 64 bits
+        let remainder = verifier_evaluate_euclidean_division(
+            builder,
+            local_timestamp,
+            self.unit_ticks(),
+            64,
+            one_eval,
+        )?;
+        Ok(timestamp - remainder)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.timestamp.get_column_references(columns);
+    }
+}
+
+/// The truncated timestamps, given the timestamps and the remainders of their local timestamps
+///
+/// # Panics
+///
+/// Panics if the timestamps are not a timestamp column, which [`DynProofExpr::try_new_date_trunc`] rules out.
+fn truncate<'a, S: Scalar>(
+    alloc: &'a Bump,
+    timestamps: Column<'a, S>,
+    remainders: &[i64],
+) -> Column<'a, S> {
+    let Column::TimestampTZ(time_unit, timezone, timestamps) = timestamps else {
+        panic!("DATE_TRUNC should only be applied to timestamps");
+    };
+    Column::TimestampTZ(
+        time_unit,
+        timezone,
+        alloc.alloc_slice_fill_iter(
+            timestamps
+                .iter()
+                .zip(remainders)
+                .map(|(timestamp, remainder)| timestamp - remainder),
+        ),
+    )
+}
@@ -0,0 +1,328 @@
+use crate::{
+    base::database::{
+        owned_table_utility::*, ColumnOperationError, OwnedTable, OwnedTableTestAccessor,
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        parse::{ConversionError, QueryExpr},
+        proof::{QueryError, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+use proof_of_sql_parser::{
+    posql_time::{DateTruncUnit, PoSQLTimeUnit, PoSQLTimeZone},
+    SelectStatement,
+};
+
+/// Prove `plan` over `data` as `sxt.t`, verifying it against `verified_plan`
+fn prove_and_verify(
+    data: OwnedTable<DoryScalar>,
+    plan: impl Fn(&OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan,
+    verified_plan: impl Fn(&OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan,
+) -> Result<OwnedTable<DoryScalar>, QueryError> {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        data,
+        0,
+        dory_prover_setup,
+    );
+    let verifiable_res = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        &plan(&accessor),
+        &accessor,
+        &dory_prover_setup,
+    );
+    verifiable_res
+        .verify(&verified_plan(&accessor), &accessor, &dory_verifier_setup)
+        .map(|result| result.table)
+}
+
+// select date_trunc(<unit>, ts) as r from sxt.t
+#[test]
+fn we_can_truncate_timestamps_to_each_unit() {
+    let t = "sxt.t".parse().unwrap();
+    let timestamps = [0_i64, 59, 60, 3_599, 3_600, 86_399, 86_400, 90_061, -1];
+    let data = || {
+        owned_table([timestamptz(
+            "ts",
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::Utc,
+            timestamps,
+        )])
+    };
+    let cases = [
+        (DateTruncUnit::Second, timestamps),
+        (
+            DateTruncUnit::Minute,
+            [0, 0, 60, 3_540, 3_600, 86_340, 86_400, 90_060, -60],
+        ),
+        (
+            DateTruncUnit::Hour,
+            [0, 0, 0, 0, 3_600, 82_800, 86_400, 90_000, -3_600],
+        ),
+        (
+            DateTruncUnit::Day,
+            [0, 0, 0, 0, 0, 0, 86_400, 86_400, -86_400],
+        ),
+    ];
+    for (unit, truncated) in cases {
+        let plan = |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            projection(
+                vec![aliased_plan(
+                    date_trunc(column(t, "ts", accessor), unit),
+                    "r",
+                )],
+                tab(t),
+            )
+        };
+        let res = prove_and_verify(data(), plan, plan).unwrap();
+        let expected_res = owned_table([timestamptz(
+            "r",
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::Utc,
+            truncated,
+        )]);
+        assert_eq!(res, expected_res, "{unit}");
+    }
+}
+
+// select date_trunc('second', ts) as s, date_trunc('day', ts) as d from sxt.t
+#[test]
+fn we_can_truncate_timestamps_with_a_finer_precision_or_a_fixed_offset() {
+    let t = "sxt.t".parse().unwrap();
+    let plan = |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+        projection(
+            vec![
+                aliased_plan(
+                    date_trunc(column(t, "ts", accessor), DateTruncUnit::Second),
+                    "s",
+                ),
+                aliased_plan(
+                    date_trunc(column(t, "ts", accessor), DateTruncUnit::Day),
+                    "d",
+                ),
+            ],
+            tab(t),
+        )
+    };
+
+    let millis = PoSQLTimeUnit::Millisecond;
+    let res = prove_and_verify(
+        owned_table([timestamptz(
+            "ts",
+            millis,
+            PoSQLTimeZone::Utc,
+            [1_500, -1, 86_400_999],
+        )]),
+        plan,
+        plan,
+    )
+    .unwrap();
+    let expected_res = owned_table([
+        timestamptz("s", millis, PoSQLTimeZone::Utc, [1_000, -1_000, 86_400_000]),
+        timestamptz(
+            "d",
+            millis,
+            PoSQLTimeZone::Utc,
+            [0, -86_400_000, 86_400_000],
+        ),
+    ]);
+    assert_eq!(res, expected_res);
+
+    // Days start at the local midnight, which is 23:00 UTC at an offset of +01:00.
+    let plus_one_hour = PoSQLTimeZone::FixedOffset(3_600);
+    let res = prove_and_verify(
+        owned_table([timestamptz(
+            "ts",
+            PoSQLTimeUnit::Second,
+            plus_one_hour,
+            [0, 82_799, 82_800, -3_601],
+        )]),
+        plan,
+        plan,
+    )
+    .unwrap();
+    let expected_res = owned_table([
+        timestamptz(
+            "s",
+            PoSQLTimeUnit::Second,
+            plus_one_hour,
+            [0, 82_799, 82_800, -3_601],
+        ),
+        timestamptz(
+            "d",
+            PoSQLTimeUnit::Second,
+            plus_one_hour,
+            [-3_600, -3_600, 82_800, -90_000],
+        ),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select date_trunc('hour', ts) as h, count(*) as c from sxt.t where v >= 0 group by h
+#[test]
+fn we_can_count_rows_per_time_bucket() {
+    let t = "sxt.t".parse().unwrap();
+    let data = || {
+        owned_table([
+            timestamptz(
+                "ts",
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::Utc,
+                [10_i64, 3_700, 20, 7_300, 3_599, 3_601, 7_200, 5],
+            ),
+            bigint("v", [1_i64, 2, 3, 4, -5, 6, 7, 8]),
+        ])
+    };
+    let plan = |unit| {
+        move |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            group_by_key_exprs(
+                vec![aliased_plan(
+                    date_trunc(column(t, "ts", accessor), unit),
+                    "h",
+                )],
+                vec![sum_expr(column(t, "v", accessor), "sum_v")],
+                "c",
+                tab(t),
+                gte(column(t, "v", accessor), const_bigint(0)),
+            )
+        }
+    };
+    let res =
+        prove_and_verify(data(), plan(DateTruncUnit::Hour), plan(DateTruncUnit::Hour)).unwrap();
+    let expected_res = owned_table([
+        timestamptz(
+            "h",
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::Utc,
+            [0, 3_600, 7_200],
+        ),
+        bigint("sum_v", [12, 8, 11]),
+        bigint("c", [3, 2, 2]),
+    ]);
+    assert_eq!(res, expected_res);
+
+    // Rows bucketed by hour cannot be verified as bucketed by minute.
+    assert!(prove_and_verify(
+        data(),
+        plan(DateTruncUnit::Hour),
+        plan(DateTruncUnit::Minute)
+    )
+    .is_err());
+}
+
+#[test]
+fn we_can_plan_date_trunc_in_select_and_where_clauses() {
+    let data = owned_table([
+        timestamptz(
+            "ts",
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::Utc,
+            [59_i64, 3_661, 86_401],
+        ),
+        bigint("a", [1_i64, 2, 3]),
+    ]);
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        data,
+        0,
+        dory_prover_setup,
+    );
+    let ast: SelectStatement = "select a, date_trunc('minute', ts) as m from t where date_trunc('day', ts) = timestamp '1970-01-01T00:00:00Z'"
+        .parse()
+        .unwrap();
+    let query = QueryExpr::try_new(ast, "sxt".parse().unwrap(), &accessor).unwrap();
+    let res = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    )
+    .verify(query.proof_expr(), &accessor, &dory_verifier_setup)
+    .unwrap()
+    .table;
+    let expected_res = owned_table([
+        bigint("a", [1_i64, 2]),
+        timestamptz("m", PoSQLTimeUnit::Second, PoSQLTimeZone::Utc, [0, 3_660]),
+    ]);
+    assert_eq!(res, expected_res);
+
+    let ast: SelectStatement = "select date_trunc('hour', a) as h from t".parse().unwrap();
+    assert!(matches!(
+        QueryExpr::try_new(ast, "sxt".parse().unwrap(), &accessor),
+        Err(ConversionError::ColumnOperationError {
+            source: ColumnOperationError::UnaryOperationInvalidColumnType { .. }
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_truncate_a_non_timestamp_expression() {
+    let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1_i64]), varchar("v", ["a"])]),
+        0,
+        DoryProverPublicSetup::new(&prover_setup, 1),
+    );
+    for name in ["a", "v"] {
+        assert!(matches!(
+            DynProofExpr::try_new_date_trunc(column(t, name, &accessor), DateTruncUnit::Hour),
+            Err(ConversionError::ColumnOperationError {
+                source: ColumnOperationError::UnaryOperationInvalidColumnType { .. }
+            })
+        ));
+    }
+}
+
+// select date_trunc('minute', ts) as m from sxt.t, with remainders tampered with by a dishonest prover
+#[test]
+fn we_cannot_prove_date_trunc_with_a_tampered_remainder() {
+    let t = "sxt.t".parse().unwrap();
+    let plan = |forged_remainder_offset| {
+        move |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            let mut expr = date_trunc(column(t, "ts", accessor), DateTruncUnit::Minute);
+            if let DynProofExpr::DateTrunc(date_trunc) = &mut expr {
+                date_trunc.forged_remainder_offset = forged_remainder_offset;
+            }
+            projection(vec![aliased_plan(expr, "m")], tab(t))
+        }
+    };
+    let data = || {
+        owned_table([timestamptz(
+            "ts",
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::FixedOffset(3_600),
+            [0_i64, 59, 61, -1, i64::MAX],
+        )])
+    };
+
+    let res = prove_and_verify(data(), plan(0), plan(0)).unwrap();
+    let expected_res = owned_table([timestamptz(
+        "m",
+        PoSQLTimeUnit::Second,
+        PoSQLTimeZone::FixedOffset(3_600),
+        [0_i64, 0, 60, -60, i64::MAX - 7],
+    )]);
+    assert_eq!(res, expected_res);
+
+    for forged_remainder_offset in [1, 59] {
+        assert!(prove_and_verify(data(), plan(forged_remainder_offset), plan(0)).is_err());
+    }
+}
@@ -1,11 +1,16 @@
 use super::{
-    AddSubtractExpr, AggregateExpr, AndExpr, ColumnExpr, EqualsExpr, InequalityExpr, LiteralExpr,
-    MultiplyExpr, NotExpr, OrExpr, ProofExpr,
+    AbsExpr, AddSubtractExpr, AggregateExpr, AndExpr, CaseExpr, ColumnExpr, DateTruncExpr,
+    EqualsExpr, HyperLogLogUpdateExpr, InequalityExpr, LiteralExpr, ModExpr, MultiplyExpr, NegExpr,
+    NotExpr, OrExpr, ProofExpr, RescaleExpr, HYPERLOGLOG_MAX_PRECISION, HYPERLOGLOG_MIN_PRECISION,
 };
 use crate::{
     base::{
-        database::{Column, ColumnRef, ColumnType, LiteralValue, Table},
+        database::{
+            try_case_column_types, Column, ColumnOperationError, ColumnRef, ColumnType,
+            LiteralValue, Table,
+        },
         map::{IndexMap, IndexSet},
+        math::decimal::{DecimalError, Precision, RoundingMode},
         proof::ProofError,
         scalar::Scalar,
     },
@@ -14,10 +19,10 @@ use crate::{
         proof::{CountBuilder, FinalRoundBuilder, VerificationBuilder},
     },
 };
-use alloc::{boxed::Box, string::ToString};
+use alloc::{boxed::Box, format, string::ToString};
 use bumpalo::Bump;
 use core::fmt::Debug;
-use proof_of_sql_parser::intermediate_ast::AggregationOperator;
+use proof_of_sql_parser::{intermediate_ast::AggregationOperator, posql_time::DateTruncUnit};
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::BinaryOperator;
 
@@ -43,8 +48,22 @@ pub enum DynProofExpr {
     AddSubtract(AddSubtractExpr),
     /// Provable numeric `*` expression
     Multiply(MultiplyExpr),
+    /// Provable numeric unary `-` expression
+    Neg(NegExpr),
+    /// Provable numeric `ABS` expression
+    Abs(AbsExpr),
+    /// Provable numeric `%` expression
+    Mod(ModExpr),
+    /// Provable `DATE_TRUNC` expression
+    DateTrunc(DateTruncExpr),
+    /// Provable rescaling of a numeric expression to a decimal
+    Rescale(RescaleExpr),
+    /// Provable `CASE WHEN` expression
+    Case(CaseExpr),
     /// Provable aggregate expression
     Aggregate(AggregateExpr),
+    /// Provable `HyperLogLog` register update of each value of an integer expression
+    HyperLogLogUpdate(HyperLogLogUpdateExpr),
 }
 impl DynProofExpr {
     /// Create column expression
@@ -143,6 +162,34 @@ impl DynProofExpr {
         }
     }
 
+    /// Create a new unary minus expression
+    pub fn try_new_neg(expr: DynProofExpr) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if datatype.is_numeric() {
+            Ok(Self::Neg(NegExpr::new(Box::new(expr))))
+        } else {
+            Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "-".to_string(),
+                operand_type: datatype,
+            }
+            .into())
+        }
+    }
+
+    /// Create a new absolute value expression
+    pub fn try_new_abs(expr: DynProofExpr) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if datatype.is_numeric() {
+            Ok(Self::Abs(AbsExpr::new(Box::new(expr))))
+        } else {
+            Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "ABS".to_string(),
+                operand_type: datatype,
+            }
+            .into())
+        }
+    }
+
     /// Create a new multiply expression
     pub fn try_new_multiply(lhs: DynProofExpr, rhs: DynProofExpr) -> ConversionResult<Self> {
         let lhs_datatype = lhs.data_type();
@@ -160,6 +207,164 @@ impl DynProofExpr {
         }
     }
 
+    /// Create a new modulo expression
+    ///
+    /// The dividend must be an integer column or literal and the modulus a non-zero integer literal
+    /// that fits in a `BIGINT`. Integer expressions are rejected, since their values can leave the range
+    /// of their type, and the quotient is only range checked to the bounds of the dividend's type.
+    pub fn try_new_modulo(lhs: DynProofExpr, rhs: DynProofExpr) -> ConversionResult<Self> {
+        let lhs_datatype = lhs.data_type();
+        let rhs_datatype = rhs.data_type();
+        if !lhs_datatype.is_integer() || !rhs_datatype.is_integer() {
+            return Err(ConversionError::DataTypeMismatch {
+                left_type: lhs_datatype.to_string(),
+                right_type: rhs_datatype.to_string(),
+            });
+        }
+        if !matches!(lhs, Self::Column(_) | Self::Literal(_)) {
+            return Err(ConversionError::Unprovable {
+                error: "the dividend of % must be a column or a literal".to_string(),
+            });
+        }
+        let modulus = match rhs {
+            Self::Literal(literal) => match *literal.value() {
+                LiteralValue::TinyInt(value) => Some(i64::from(value)),
+                LiteralValue::SmallInt(value) => Some(i64::from(value)),
+                LiteralValue::Int(value) => Some(i64::from(value)),
+                LiteralValue::BigInt(value) => Some(value),
+                LiteralValue::Int128(value) => i64::try_from(value).ok(),
+                _ => None,
+            },
+            _ => None,
+        }
+        .ok_or_else(|| ConversionError::Unprovable {
+            error: "the modulus must be an integer literal that fits in a BIGINT".to_string(),
+        })?;
+        if modulus == 0 {
+            return Err(ColumnOperationError::DivisionByZero.into());
+        }
+        Ok(Self::Mod(ModExpr::new(Box::new(lhs), modulus)))
+    }
+
+    /// Create a new `HyperLogLog` register update expression for a sketch with `2^precision` registers
+    ///
+    /// Only integer columns are supported, since other integer expressions may evaluate to scalars.
+    pub fn try_new_hyperloglog_update(expr: DynProofExpr, precision: u8) -> ConversionResult<Self> {
+        if !matches!(expr, DynProofExpr::Column(_)) {
+            return Err(ConversionError::Unprovable {
+                error: "approximate distinct counts are only supported for columns".to_string(),
+            });
+        }
+        let datatype = expr.data_type();
+        if !matches!(
+            datatype,
+            ColumnType::TinyInt | ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt
+        ) {
+            return Err(ConversionError::Unprovable {
+                error: format!(
+                    "approximate distinct counts of {datatype} values are not supported"
+                ),
+            });
+        }
+        if !(HYPERLOGLOG_MIN_PRECISION..=HYPERLOGLOG_MAX_PRECISION).contains(&precision) {
+            return Err(ConversionError::Unprovable {
+                error: format!(
+                    "the HyperLogLog precision must be between {HYPERLOGLOG_MIN_PRECISION} and {HYPERLOGLOG_MAX_PRECISION}"
+                ),
+            });
+        }
+        Ok(Self::HyperLogLogUpdate(HyperLogLogUpdateExpr::new(
+            Box::new(expr),
+            precision,
+        )))
+    }
+
+    /// Create a new `DATE_TRUNC` expression, which truncates a timestamp to the start of its `unit`
+    pub fn try_new_date_trunc(expr: DynProofExpr, unit: DateTruncUnit) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        if matches!(datatype, ColumnType::TimestampTZ(_, _)) {
+            Ok(Self::DateTrunc(DateTruncExpr::new(Box::new(expr), unit)))
+        } else {
+            Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "DATE_TRUNC".to_string(),
+                operand_type: datatype,
+            }
+            .into())
+        }
+    }
+
+    /// Create a new expression that rescales a numeric expression to a decimal with the given precision and scale,
+    /// rounding with `mode` when it is scaled down.
+    ///
+    /// The precision must fit every rescaled value, counting the extra digit that rounding away from zero can add.
+    /// Scaling down is limited to inputs with a precision of at most 38, by at most 38 digits.
+    pub fn try_new_decimal_rescale(
+        expr: DynProofExpr,
+        precision: Precision,
+        scale: i8,
+        mode: RoundingMode,
+    ) -> ConversionResult<Self> {
+        let datatype = expr.data_type();
+        let Some((input_precision, input_scale)) = datatype
+            .precision_value()
+            .zip(datatype.scale())
+            .filter(|_| datatype.is_numeric())
+        else {
+            return Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "CAST".to_string(),
+                operand_type: datatype,
+            }
+            .into());
+        };
+        let difference = i16::from(scale) - i16::from(input_scale);
+        let required_precision = if difference >= 0 {
+            i16::from(input_precision) + difference
+        } else {
+            if input_precision > 38 || difference < -38 {
+                return Err(ConversionError::Unprovable {
+                    error: "decimals can only be scaled down from a precision of at most 38, by at most 38 digits"
+                        .to_string(),
+                });
+            }
+            (i16::from(input_precision) + difference + i16::from(mode != RoundingMode::Truncate))
+                .max(1)
+        };
+        if required_precision > i16::from(precision.value()) {
+            return Err(DecimalError::InvalidPrecision {
+                error: precision.value().to_string(),
+            }
+            .into());
+        }
+        Ok(Self::Rescale(RescaleExpr::new(
+            Box::new(expr),
+            precision,
+            scale,
+            mode,
+        )))
+    }
+
+    /// Create a new `CASE WHEN` expression
+    pub fn try_new_case(
+        condition: DynProofExpr,
+        then_expr: DynProofExpr,
+        else_expr: DynProofExpr,
+    ) -> ConversionResult<Self> {
+        condition.check_data_type(ColumnType::Boolean)?;
+        let then_datatype = then_expr.data_type();
+        let else_datatype = else_expr.data_type();
+        try_case_column_types(then_datatype, else_datatype).map_err(|_| {
+            ConversionError::DataTypeMismatch {
+                left_type: then_datatype.to_string(),
+                right_type: else_datatype.to_string(),
+            }
+        })?;
+        Ok(Self::Case(CaseExpr::new(
+            Box::new(condition),
+            Box::new(then_expr),
+            Box::new(else_expr),
+        )))
+    }
+
     /// Create a new aggregate expression
     pub fn new_aggregate(op: AggregationOperator, expr: DynProofExpr) -> Self {
         Self::Aggregate(AggregateExpr::new(op, Box::new(expr)))
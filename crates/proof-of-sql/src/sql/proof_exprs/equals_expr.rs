@@ -117,6 +117,7 @@ pub fn prover_evaluate_equals_zero<'a, S: Scalar>(
     // lhs_pseudo_inv
     let lhs_pseudo_inv = alloc.alloc_slice_copy(lhs);
     slice_ops::batch_inversion(lhs_pseudo_inv);
+    builder.metrics_mut().record_field_inversions(table_length);
 
     builder.produce_intermediate_mle(lhs_pseudo_inv as &[_]);
 
@@ -316,6 +316,84 @@ fn we_can_prove_an_equality_query_with_a_string_comparison() {
     assert_eq!(res, expected_res);
 }
 
+#[test]
+fn we_can_prove_an_equality_query_between_columns_that_fully_match() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 3, 4]),
+        bigint("b", [1, 2, 3, 4]),
+        varchar("d", ["ab", "t", "efg", "g"]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["a", "d"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), column(t, "b", &accessor)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("a", [1, 2, 3, 4]),
+        varchar("d", ["ab", "t", "efg", "g"]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_an_equality_query_between_columns_that_partially_match() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        varchar("a", ["x", "y", "z", "w", "v"]),
+        varchar("b", ["x", "q", "z", "r", "v"]),
+        bigint("c", [1, 2, 3, 4, 5]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["a", "c"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), column(t, "b", &accessor)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([varchar("a", ["x", "z", "v"]), bigint("c", [1, 3, 5])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_an_equality_query_between_columns_that_differ_in_exactly_one_position() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        decimal75("a", 12, 2, [100, 250, -300, 400]),
+        decimal75("b", 12, 2, [100, 250, 300, 400]),
+        bigint("c", [1, 2, 3, 4]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["c"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), column(t, "b", &accessor)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("c", [1, 2, 4])]);
+    assert_eq!(res, expected_res);
+
+    // The complement contains only the one differing row.
+    let ast = filter(
+        cols_expr_plan(t, &["c"], &accessor),
+        tab(t),
+        not(equal(column(t, "a", &accessor), column(t, "b", &accessor))),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("c", [3])]);
+    assert_eq!(res, expected_res);
+}
+
 fn test_random_tables_with_given_offset(offset: usize) {
     let dist = Uniform::new(-3, 4);
     let mut rng = StdRng::from_seed([0u8; 32]);
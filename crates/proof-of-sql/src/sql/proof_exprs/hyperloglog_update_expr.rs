@@ -0,0 +1,408 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{
+        CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
+        VerificationBuilder,
+    },
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+
+/// The smallest supported precision of a `HyperLogLog` sketch, i.e. 16 registers
+pub const HYPERLOGLOG_MIN_PRECISION: u8 = 4;
+/// The largest supported precision of a `HyperLogLog` sketch, i.e. 65536 registers
+pub const HYPERLOGLOG_MAX_PRECISION: u8 = 16;
+/// A register update is `register * HYPERLOGLOG_RANK_RADIX + rank`.
+///
+/// Ranks are at most `64 - HYPERLOGLOG_MIN_PRECISION + 1`, so they fit below the radix.
+pub const HYPERLOGLOG_RANK_RADIX: i64 = 64;
+
+/// The coefficients `a`, `b` and `c` of the hash polynomial `a * u^2 + b * u + c`.
+///
+/// They are odd and below `2^63`, so the polynomial is below `2^192` for any 64 bit `u`.
+pub(super) const HASH_COEFFICIENTS: [u64; 3] = [
+    0x4f1b_bcdc_bfa5_3e0b,
+    0x503b_0eb2_3c5e_b217,
+    0x7381_bf68_d05a_146d,
+];
+/// The number of bits of the hash polynomial that are committed to
+const HASH_POLYNOMIAL_BITS: usize = 192;
+
+/// The value of `value + 2^63`, which is in `[0, 2^64)` for any `i64`
+#[allow(clippy::cast_sign_loss)]
+fn offset_value(value: i64) -> u64 {
+    (value as u64) ^ (1 << 63)
+}
+
+/// The 64 bit words, least significant first, of the hash polynomial `a * u^2 + b * u + c` at `u = value + 2^63`
+pub(super) fn hash_polynomial_words(value: i64) -> [u64; 3] {
+    let [a, b, c] = HASH_COEFFICIENTS.map(u128::from);
+    let u = u128::from(offset_value(value));
+    let square = u * u;
+    let square_low = a * (square & u128::from(u64::MAX));
+    let square_high = a * (square >> 64);
+    let (low, carry) = square_low.overflowing_add(b * u + c);
+    let high = (low >> 64) + (u128::from(carry) << 64) + square_high;
+    #[allow(clippy::cast_possible_truncation)]
+    [low as u64, high as u64, (high >> 64) as u64]
+}
+
+/// The 64 bit hash of a value, i.e. the XOR of the words of [`hash_polynomial_words`]
+fn hash_value(value: i64) -> u64 {
+    let [w0, w1, w2] = hash_polynomial_words(value);
+    w0 ^ w1 ^ w2
+}
+
+/// The register and rank that a value updates in a `HyperLogLog` sketch with `2^precision` registers.
+///
+/// The register is given by the top `precision` bits of the hash of the value,
+/// and the rank is one more than the number of leading zeros of the other `64 - precision` bits.
+#[allow(clippy::cast_possible_wrap)]
+pub(super) fn register_and_rank(value: i64, precision: u8) -> (i64, u32) {
+    let hash = hash_value(value);
+    let window_bits = 64 - u32::from(precision);
+    // The register has at most 16 bits
+    let register = (hash >> window_bits) as i64;
+    let rank = (hash << precision).leading_zeros().min(window_bits) + 1;
+    (register, rank)
+}
+
+/// The update of a `HyperLogLog` sketch with `2^precision` registers by a value.
+///
+/// This is `register * HYPERLOGLOG_RANK_RADIX + rank`, where `register` and `rank` are described in
+/// [`HyperLogLogUpdateExpr`]. The sketch is the maximum rank of each register over all updates.
+///
+/// # Panics
+///
+/// Panics if the precision is not between [`HYPERLOGLOG_MIN_PRECISION`] and [`HYPERLOGLOG_MAX_PRECISION`].
+#[must_use]
+pub fn hyperloglog_update(value: i64, precision: u8) -> i64 {
+    assert!(
+        (HYPERLOGLOG_MIN_PRECISION..=HYPERLOGLOG_MAX_PRECISION).contains(&precision),
+        "precision is out of range"
+    );
+    let (register, rank) = register_and_rank(value, precision);
+    register * HYPERLOGLOG_RANK_RADIX + i64::from(rank)
+}
+
+/// `2^index` as a scalar
+fn power_of_two<S: Scalar>(index: usize) -> S {
+    let mut limbs = [0u64; 4];
+    limbs[index / 64] = 1 << (index % 64);
+    S::from(limbs)
+}
+
+/// Provable `HyperLogLog` register update of each value of an integer expression
+///
+/// Each value `x` is hashed to 64 bits by evaluating `a * u^2 + b * u + c` at `u = x + 2^63`,
+/// where `a`, `b` and `c` are fixed 63 bit constants, and taking the XOR of the three 64 bit words of the result.
+/// The top `precision` bits of the hash select one of the `2^precision` registers of a sketch,
+/// and the rank of the value is one more than the number of leading zeros of the remaining `64 - precision` bits.
+/// The result is `register * 64 + rank`, see [`hyperloglog_update`].
+///
+/// The prover commits to the 192 bits of `a * u^2 + b * u + c`, to the registers, and to the indicators `z_k`
+/// of the first `k + 1` bits of the remaining window being zero. The verifier checks that the bits are binary
+/// and add up to the polynomial, which is below `2^192` and so cannot wrap around, that each register is made of
+/// the top bits of the XOR of the words, and that `z_k = z_{k-1} * (1 - r_k)`, where `r_k` is the `k`-th bit of the window.
+/// The rank is then `1 + sum_k z_k`, so the updates follow from committed values linearly.
+///
+/// The values must fit in a `BIGINT`, so only `TINYINT`, `SMALLINT`, `INT`, and `BIGINT` columns are supported.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HyperLogLogUpdateExpr {
+    expr: Box<DynProofExpr>,
+    precision: u8,
+}
+
+impl HyperLogLogUpdateExpr {
+    /// Create a new `HyperLogLog` register update expression
+    pub fn new(expr: Box<DynProofExpr>, precision: u8) -> Self {
+        Self { expr, precision }
+    }
+
+    /// The number of bits of the hash after the register bits
+    fn window_bits(&self) -> usize {
+        64 - usize::from(self.precision)
+    }
+
+    fn check_precision(&self) -> Result<(), ProofError> {
+        if (HYPERLOGLOG_MIN_PRECISION..=HYPERLOGLOG_MAX_PRECISION).contains(&self.precision) {
+            Ok(())
+        } else {
+            Err(ProofError::VerificationError {
+                error: "HyperLogLog precision is out of range",
+            })
+        }
+    }
+
+    /// The values of an integer column
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is not a `TINYINT`, `SMALLINT`, `INT`, or `BIGINT` column,
+    /// which [`DynProofExpr::try_new_hyperloglog_update`] rules out.
+    fn values<S: Scalar>(column: &Column<'_, S>) -> Vec<i64> {
+        match column {
+            Column::TinyInt(values) => values.iter().copied().map(i64::from).collect(),
+            Column::SmallInt(values) => values.iter().copied().map(i64::from).collect(),
+            Column::Int(values) => values.iter().copied().map(i64::from).collect(),
+            Column::BigInt(values) => values.to_vec(),
+            _ => panic!("HyperLogLog updates are only supported for integers that fit in a BIGINT"),
+        }
+    }
+
+    fn updates<'a, S: Scalar>(&self, alloc: &'a Bump, column: &Column<'a, S>) -> &'a [i64] {
+        alloc.alloc_slice_fill_iter(
+            Self::values(column)
+                .into_iter()
+                .map(|value| hyperloglog_update(value, self.precision)),
+        )
+    }
+}
+
+/// The terms of `coefficient * factor * h_j`, where `h_j` is the `j`-th bit of the hash, i.e. the XOR of
+/// `b_j`, `b_{j+64}` and `b_{j+128}`, which is `b + b' + b'' - 2 (b b' + b b'' + b' b'') + 4 b b' b''`.
+fn hash_bit_terms<'a, S: Scalar>(
+    bits: &[&'a [bool]],
+    j: usize,
+    coefficient: S,
+    factor: Option<&'a [bool]>,
+) -> Vec<SumcheckSubpolynomialTerm<'a, S>> {
+    let [b0, b1, b2] = [bits[j], bits[j + 64], bits[j + 128]];
+    let products: [(i64, Vec<&'a [bool]>); 7] = [
+        (1, vec![b0]),
+        (1, vec![b1]),
+        (1, vec![b2]),
+        (-2, vec![b0, b1]),
+        (-2, vec![b0, b2]),
+        (-2, vec![b1, b2]),
+        (4, vec![b0, b1, b2]),
+    ];
+    products
+        .into_iter()
+        .map(|(multiplier, product)| {
+            let mles = factor
+                .into_iter()
+                .chain(product)
+                .map(|mle| Box::new(mle) as Box<_>)
+                .collect();
+            (coefficient * S::from(multiplier), mles)
+        })
+        .collect()
+}
+
+/// The evaluation of the `j`-th bit of the hash, see [`hash_bit_terms`]
+fn hash_bit_eval<S: Scalar>(bit_evals: &[S], j: usize) -> S {
+    let [b0, b1, b2] = [bit_evals[j], bit_evals[j + 64], bit_evals[j + 128]];
+    b0 + b1 + b2 - S::TWO * (b0 * b1 + b0 * b2 + b1 * b2) + S::from(4) * b0 * b1 * b2
+}
+
+impl ProofExpr for HyperLogLogUpdateExpr {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.check_precision()?;
+        self.expr.count(builder)?;
+        // The bits of the hash polynomial, the registers, and the leading zero indicators
+        builder.count_intermediate_mles(HASH_POLYNOMIAL_BITS + 1 + self.window_bits());
+        // Binary bits, the decomposition, the registers, and the leading zero indicators
+        builder.count_subpolynomials(HASH_POLYNOMIAL_BITS + 2 + self.window_bits());
+        builder.count_degree(5);
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::BigInt
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let column = self.expr.result_evaluate(alloc, table);
+        Column::BigInt(self.updates(alloc, &column))
+    }
+
+    #[tracing::instrument(
+        name = "HyperLogLogUpdateExpr::prover_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let column = self.expr.prover_evaluate(builder, alloc, table);
+        let values = Self::values(&column);
+        let num_rows = values.len();
+        let words: Vec<[u64; 3]> = values.iter().copied().map(hash_polynomial_words).collect();
+        let registers_and_ranks: Vec<(i64, u32)> = values
+            .iter()
+            .map(|&value| register_and_rank(value, self.precision))
+            .collect();
+        let ones: &[bool] = alloc.alloc_slice_fill_copy(num_rows, true);
+        let offset_values: &[S] =
+            alloc.alloc_slice_fill_iter(values.iter().map(|&value| S::from(offset_value(value))));
+
+        // 1. The bits of the hash polynomial are binary
+        let bits: Vec<&'a [bool]> = (0..HASH_POLYNOMIAL_BITS)
+            .map(|index| {
+                &*alloc.alloc_slice_fill_iter(
+                    words
+                        .iter()
+                        .map(|word| (word[index / 64] >> (index % 64)) & 1 == 1),
+                )
+            })
+            .collect();
+        for &bit in &bits {
+            builder.produce_intermediate_mle(bit);
+            builder.produce_sumcheck_subpolynomial(
+                SumcheckSubpolynomialType::Identity,
+                vec![
+                    (S::one(), vec![Box::new(bit)]),
+                    (-S::one(), vec![Box::new(bit), Box::new(bit)]),
+                ],
+            );
+        }
+
+        // 2. The bits add up to `a * u^2 + b * u + c`
+        let [a, b, c] = HASH_COEFFICIENTS.map(S::from);
+        let mut terms: Vec<SumcheckSubpolynomialTerm<'a, S>> = bits
+            .iter()
+            .enumerate()
+            .map(|(index, &bit)| (power_of_two(index), vec![Box::new(bit) as Box<_>]))
+            .collect();
+        terms.push((-a, vec![Box::new(offset_values), Box::new(offset_values)]));
+        terms.push((-b, vec![Box::new(offset_values)]));
+        terms.push((-c, vec![Box::new(ones)]));
+        builder.produce_sumcheck_subpolynomial(SumcheckSubpolynomialType::Identity, terms);
+
+        // 3. The registers are the top bits of the hash
+        let window_bits = self.window_bits();
+        let registers: &[i64] =
+            alloc.alloc_slice_fill_iter(registers_and_ranks.iter().map(|&(register, _)| register));
+        builder.produce_intermediate_mle(registers);
+        let mut terms: Vec<SumcheckSubpolynomialTerm<'a, S>> =
+            vec![(S::one(), vec![Box::new(registers)])];
+        for register_bit in 0..usize::from(self.precision) {
+            terms.extend(hash_bit_terms(
+                &bits,
+                window_bits + register_bit,
+                -power_of_two::<S>(register_bit),
+                None,
+            ));
+        }
+        builder.produce_sumcheck_subpolynomial(SumcheckSubpolynomialType::Identity, terms);
+
+        // 4. `z_k` indicates that the first `k + 1` bits of the window are zero
+        let mut previous: &'a [bool] = ones;
+        for k in 0..window_bits {
+            let leading_zeros = u32::try_from(k).expect("window has at most 60 bits");
+            let indicators: &'a [bool] = alloc.alloc_slice_fill_iter(
+                registers_and_ranks
+                    .iter()
+                    .map(|&(_, rank)| rank > leading_zeros + 1),
+            );
+            builder.produce_intermediate_mle(indicators);
+            // z_k - z_{k-1} + z_{k-1} * r_k, with z_{-1} = 1
+            let mut terms: Vec<SumcheckSubpolynomialTerm<'a, S>> = vec![
+                (S::one(), vec![Box::new(indicators)]),
+                (-S::one(), vec![Box::new(previous)]),
+            ];
+            terms.extend(hash_bit_terms(
+                &bits,
+                window_bits - 1 - k,
+                S::one(),
+                (k > 0).then_some(previous),
+            ));
+            builder.produce_sumcheck_subpolynomial(SumcheckSubpolynomialType::Identity, terms);
+            previous = indicators;
+        }
+
+        Column::BigInt(self.updates(alloc, &column))
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        self.check_precision()?;
+        let value_eval = self.expr.verifier_evaluate(builder, accessor, one_eval)?;
+        let offset_value_eval = value_eval + S::from(offset_value(0)) * one_eval;
+
+        // 1. The bits of the hash polynomial are binary
+        let bit_evals: Vec<S> = (0..HASH_POLYNOMIAL_BITS)
+            .map(|_| {
+                let bit_eval = builder.consume_intermediate_mle();
+                builder.produce_sumcheck_subpolynomial_evaluation(
+                    &SumcheckSubpolynomialType::Identity,
+                    bit_eval - bit_eval * bit_eval,
+                );
+                bit_eval
+            })
+            .collect();
+
+        // 2. The bits add up to `a * u^2 + b * u + c`
+        let [a, b, c] = HASH_COEFFICIENTS.map(S::from);
+        let decomposition_eval = bit_evals
+            .iter()
+            .enumerate()
+            .fold(S::zero(), |acc, (index, &bit_eval)| {
+                acc + power_of_two::<S>(index) * bit_eval
+            })
+            - a * offset_value_eval * offset_value_eval
+            - b * offset_value_eval
+            - c * one_eval;
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            decomposition_eval,
+        );
+
+        // 3. The registers are the top bits of the hash
+        let window_bits = self.window_bits();
+        let register_eval = builder.consume_intermediate_mle();
+        let register_bits_eval = (0..usize::from(self.precision)).fold(S::zero(), |acc, bit| {
+            acc + power_of_two::<S>(bit) * hash_bit_eval(&bit_evals, window_bits + bit)
+        });
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            register_eval - register_bits_eval,
+        );
+
+        // 4. `z_k` indicates that the first `k + 1` bits of the window are zero
+        let mut previous_eval = one_eval;
+        let mut leading_zeros_eval = S::zero();
+        for k in 0..window_bits {
+            let indicator_eval = builder.consume_intermediate_mle();
+            // The product with z_{-1} = 1 is the window bit itself rather than a product with the ones column
+            let window_bit_eval = hash_bit_eval(&bit_evals, window_bits - 1 - k);
+            let product_eval = if k == 0 {
+                window_bit_eval
+            } else {
+                previous_eval * window_bit_eval
+            };
+            builder.produce_sumcheck_subpolynomial_evaluation(
+                &SumcheckSubpolynomialType::Identity,
+                indicator_eval - previous_eval + product_eval,
+            );
+            leading_zeros_eval += indicator_eval;
+            previous_eval = indicator_eval;
+        }
+
+        // The rank is `1 + sum_k z_k`
+        Ok(S::from(HYPERLOGLOG_RANK_RADIX) * register_eval + one_eval + leading_zeros_eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
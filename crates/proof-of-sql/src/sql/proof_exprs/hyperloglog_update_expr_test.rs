@@ -0,0 +1,227 @@
+use super::hyperloglog_update_expr::{hash_polynomial_words, register_and_rank, HASH_COEFFICIENTS};
+use crate::{
+    base::database::{owned_table_utility::*, Column, OwnedTable, OwnedTableTestAccessor},
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{ProvableQueryResult, QueryError, VerifiableQueryResult},
+        proof_exprs::{hyperloglog_update, test_utility::*, DynProofExpr},
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+
+/// Prove `plan` over `data` as `sxt.t`, verifying it against `verified_plan`,
+/// optionally after replacing the result with `tampered_result`
+fn prove_and_verify(
+    data: OwnedTable<DoryScalar>,
+    plan: impl Fn(&OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan,
+    verified_plan: impl Fn(&OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan,
+    tampered_result: Option<ProvableQueryResult>,
+) -> Result<OwnedTable<DoryScalar>, QueryError> {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        data,
+        0,
+        dory_prover_setup,
+    );
+    let mut verifiable_res = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        &plan(&accessor),
+        &accessor,
+        &dory_prover_setup,
+    );
+    if let Some(tampered_result) = tampered_result {
+        verifiable_res.provable_result = Some(tampered_result);
+    }
+    verifiable_res
+        .verify(&verified_plan(&accessor), &accessor, &dory_verifier_setup)
+        .map(|result| result.table)
+}
+
+#[test]
+fn the_hash_polynomial_words_are_the_value_of_the_hash_polynomial() {
+    let [a, b, c] = HASH_COEFFICIENTS.map(DoryScalar::from);
+    let word_radix = DoryScalar::from([0, 1, 0, 0]);
+    for value in [i64::MIN, -1, 0, 1, 12345, i64::MAX] {
+        let u = DoryScalar::from(value) + DoryScalar::from(1u64 << 63);
+        let [w0, w1, w2] = hash_polynomial_words(value).map(DoryScalar::from);
+        assert_eq!(
+            w0 + word_radix * (w1 + word_radix * w2),
+            a * u * u + b * u + c,
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn registers_and_ranks_are_in_range() {
+    for precision in [4, 10, 16] {
+        let max_rank = 65 - u32::from(precision);
+        let mut max_seen_rank = 0;
+        for value in (-5000..5000).chain([i64::MIN, i64::MAX]) {
+            let (register, rank) = register_and_rank(value, precision);
+            assert!((0..1 << precision).contains(&register));
+            assert!((1..=max_rank).contains(&rank));
+            assert_eq!(
+                hyperloglog_update(value, precision),
+                register * 64 + i64::from(rank)
+            );
+            max_seen_rank = max_seen_rank.max(rank);
+        }
+        // With 10000 values, some ranks exceed 10
+        assert!(max_seen_rank > 10);
+    }
+}
+
+// select hyperloglog_update(a, p) as u, hyperloglog_update(b, p) as v from sxt.t
+#[test]
+fn we_can_prove_hyperloglog_updates_of_integer_columns() {
+    let a = [i64::MIN, -1, 0, 1, 7, 7, 123_456_789, i64::MAX];
+    let b = [i32::MIN, -3, 0, 3, 7, 7, 5, i32::MAX];
+    let data = || owned_table([bigint("a", a), int("b", b)]);
+    for precision in [4, 9, 16] {
+        let plan = move |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            let t = "sxt.t".parse().unwrap();
+            projection(
+                vec![
+                    aliased_plan(
+                        hyperloglog_update_expr(column(t, "a", accessor), precision),
+                        "u",
+                    ),
+                    aliased_plan(
+                        hyperloglog_update_expr(column(t, "b", accessor), precision),
+                        "v",
+                    ),
+                ],
+                tab(t),
+            )
+        };
+        let res = prove_and_verify(data(), plan, plan, None).unwrap();
+        let expected_res = owned_table([
+            bigint("u", a.map(|value| hyperloglog_update(value, precision))),
+            bigint(
+                "v",
+                b.map(|value| hyperloglog_update(i64::from(value), precision)),
+            ),
+        ]);
+        assert_eq!(res, expected_res);
+    }
+}
+
+#[test]
+fn we_cannot_verify_tampered_hyperloglog_updates() {
+    let a = [-2_i64, 0, 5, 1_000_000];
+    let plan = |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+        let t = "sxt.t".parse().unwrap();
+        projection(
+            vec![aliased_plan(
+                hyperloglog_update_expr(column(t, "a", accessor), 8),
+                "u",
+            )],
+            tab(t),
+        )
+    };
+    let updates = a.map(|value| hyperloglog_update(value, 8));
+    // A higher rank, a lower rank, and a different register
+    for (row, offset) in [(0, 1), (1, -1), (3, 64)] {
+        let mut tampered_updates = updates;
+        tampered_updates[row] += offset;
+        let tampered_result =
+            ProvableQueryResult::new(4, &[Column::<DoryScalar>::BigInt(&tampered_updates)]);
+        assert!(
+            prove_and_verify(
+                owned_table([bigint("a", a)]),
+                plan,
+                plan,
+                Some(tampered_result)
+            )
+            .is_err(),
+            "{row} {offset}"
+        );
+    }
+    // The updates are also bound to the precision of the sketch
+    let plan_with_precision = |precision| {
+        move |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            let t = "sxt.t".parse().unwrap();
+            projection(
+                vec![aliased_plan(
+                    hyperloglog_update_expr(column(t, "a", accessor), precision),
+                    "u",
+                )],
+                tab(t),
+            )
+        }
+    };
+    assert!(prove_and_verify(
+        owned_table([bigint("a", a)]),
+        plan_with_precision(8),
+        plan_with_precision(9),
+        None
+    )
+    .is_err());
+}
+
+#[test]
+fn we_cannot_create_hyperloglog_updates_of_non_bigint_expressions_or_with_an_invalid_precision() {
+    let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [1]),
+            int128("i", [1]),
+            varchar("v", ["a"]),
+            boolean("b", [true]),
+        ]),
+        0,
+        DoryProverPublicSetup::new(&prover_setup, 1),
+    );
+    let try_update = |name, precision| {
+        DynProofExpr::try_new_hyperloglog_update(column(t, name, &accessor), precision)
+    };
+    assert!(try_update("a", 4).is_ok());
+    assert!(try_update("a", 16).is_ok());
+    for (name, precision) in [("i", 12), ("v", 12), ("b", 12), ("a", 3), ("a", 17)] {
+        assert!(
+            matches!(
+                try_update(name, precision),
+                Err(ConversionError::Unprovable { .. })
+            ),
+            "{name} {precision}"
+        );
+    }
+}
+
+#[test]
+fn we_cannot_create_hyperloglog_updates_of_integer_expressions_that_are_not_columns() {
+    let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([bigint("a", [1]), bigint("b", [2])]),
+        0,
+        DoryProverPublicSetup::new(&prover_setup, 1),
+    );
+    // These are typed BIGINT but evaluate to scalar columns
+    let exprs = [
+        add(column(t, "a", &accessor), column(t, "b", &accessor)),
+        multiply(column(t, "a", &accessor), column(t, "b", &accessor)),
+        abs(column(t, "a", &accessor)),
+    ];
+    for expr in exprs {
+        assert!(matches!(
+            DynProofExpr::try_new_hyperloglog_update(expr, 12),
+            Err(ConversionError::Unprovable { .. })
+        ));
+    }
+}
@@ -23,7 +23,9 @@ pub struct InequalityExpr {
     lhs: Box<DynProofExpr>,
     rhs: Box<DynProofExpr>,
     is_lte: bool,
+    // Not serialized, so that plans and their proofs are the same in tests as outside of them
     #[cfg(test)]
+    #[serde(skip)]
     pub(crate) treat_column_of_zeros_as_negative: bool,
 }
 
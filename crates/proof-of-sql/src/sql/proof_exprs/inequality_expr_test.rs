@@ -160,6 +160,50 @@ fn we_can_compare_columns_with_extreme_values() {
     assert_eq!(res, expected_res);
 }
 
+#[test]
+fn we_can_compare_unsigned_columns_with_values_around_the_maximum_signed_value() {
+    let signed_max = i64::MAX as u64;
+    let data = owned_table([
+        uint64(
+            "a",
+            [signed_max, signed_max + 1, signed_max + 1, u64::MAX, 0],
+        ),
+        uint64(
+            "b",
+            [signed_max + 1, signed_max, signed_max + 1, 0, u64::MAX],
+        ),
+        bigint("c", [i64::MAX, -1, i64::MIN, i64::MAX, -1]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![
+            aliased_plan(
+                lte(column(t, "a", &accessor), column(t, "b", &accessor)),
+                "a_lte_b",
+            ),
+            aliased_plan(
+                gte(column(t, "a", &accessor), const_uint64(signed_max + 1)),
+                "a_gte_const",
+            ),
+            aliased_plan(
+                lte(column(t, "c", &accessor), column(t, "a", &accessor)),
+                "c_lte_a",
+            ),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        boolean("a_lte_b", [true, false, true, false, true]),
+        boolean("a_gte_const", [false, true, true, true, false]),
+        boolean("c_lte_a", [true, true, true, true, true]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
 #[test]
 fn we_can_compare_columns_with_small_decimal_values_without_scale() {
     let scalar_pos = Curve25519Scalar::pow10(38) - Curve25519Scalar::ONE;
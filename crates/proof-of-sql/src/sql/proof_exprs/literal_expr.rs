@@ -32,6 +32,11 @@ impl LiteralExpr {
     pub fn new(value: LiteralValue) -> Self {
         Self { value }
     }
+
+    /// Returns the value of the literal
+    pub fn value(&self) -> &LiteralValue {
+        &self.value
+    }
 }
 
 impl ProofExpr for LiteralExpr {
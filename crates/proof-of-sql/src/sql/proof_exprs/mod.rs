@@ -20,6 +20,29 @@ use multiply_expr::MultiplyExpr;
 #[cfg(all(test, feature = "blitzar"))]
 mod multiply_expr_test;
 
+mod mod_expr;
+use mod_expr::{
+    count_euclidean_division, divide_euclid, prover_evaluate_euclidean_division,
+    verifier_evaluate_euclidean_division, ModExpr,
+};
+#[cfg(all(test, feature = "blitzar"))]
+mod mod_expr_test;
+
+mod date_trunc_expr;
+use date_trunc_expr::DateTruncExpr;
+#[cfg(test)]
+mod date_trunc_expr_test;
+
+mod rescale_expr;
+use rescale_expr::RescaleExpr;
+#[cfg(test)]
+mod rescale_expr_test;
+
+mod case_expr;
+use case_expr::CaseExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod case_expr_test;
+
 mod bitwise_verification;
 use bitwise_verification::{
     is_within_acceptable_range, verify_constant_abs_decomposition,
@@ -56,12 +79,22 @@ use not_expr::NotExpr;
 #[cfg(all(test, feature = "blitzar"))]
 mod not_expr_test;
 
+mod neg_expr;
+use neg_expr::NegExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod neg_expr_test;
+
+mod abs_expr;
+use abs_expr::AbsExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod abs_expr_test;
+
 mod comparison_util;
 pub(crate) use comparison_util::scale_and_subtract;
 
 mod numerical_util;
 pub(crate) use numerical_util::{
-    add_subtract_columns, multiply_columns, scale_and_add_subtract_eval,
+    add_subtract_columns, multiply_columns, scale_and_add_subtract_eval, scale_column,
 };
 
 mod equals_expr;
@@ -73,10 +106,22 @@ use equals_expr::{
 mod equals_expr_test;
 
 mod sign_expr;
-use sign_expr::{count_sign, prover_evaluate_sign, result_evaluate_sign, verifier_evaluate_sign};
+use sign_expr::{
+    count_sign, prover_evaluate_sign, result_evaluate_sign, verifier_evaluate_bounded_sign,
+    verifier_evaluate_sign,
+};
 #[cfg(all(test, feature = "blitzar"))]
 mod sign_expr_test;
 
+mod hyperloglog_update_expr;
+pub(crate) use hyperloglog_update_expr::HyperLogLogUpdateExpr;
+pub use hyperloglog_update_expr::{
+    hyperloglog_update, HYPERLOGLOG_MAX_PRECISION, HYPERLOGLOG_MIN_PRECISION,
+    HYPERLOGLOG_RANK_RADIX,
+};
+#[cfg(test)]
+mod hyperloglog_update_expr_test;
+
 mod table_expr;
 pub(crate) use table_expr::TableExpr;
 
@@ -0,0 +1,289 @@
+use super::{
+    count_sign, prover_evaluate_sign, verifier_evaluate_bounded_sign, verifier_evaluate_sign,
+    DynProofExpr, ProofExpr,
+};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+
+/// Provable numerical `%` expression with a constant, non-zero modulus
+///
+/// The remainder is always non-negative, i.e. `lhs % m` is `r` with `lhs = q * m + r` and `0 <= r < |m|`.
+/// So for negative dividends it differs from truncating remainders such as Postgres' `%`,
+/// e.g. `-7 % 3` is `2` rather than `-1`. The sign of the modulus does not matter.
+///
+/// The prover commits to the quotient `q` and remainder `r`. The verifier checks `lhs = q * m + r`,
+/// that `r` and `|m| - 1 - r` are non-negative, and range checks `q` to `|q| < 2^64`, or `|q| < 2^128` for an
+/// `INT128` dividend. Together these bound `q * m + r` far below the field modulus, so the equation also holds
+/// over the integers and `r` is the unique remainder.
+///
+/// The range check relies on the dividend being within the bounds of its type, so the dividend must be a column
+/// or a literal. See [`DynProofExpr::try_new_modulo`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModExpr {
+    lhs: Box<DynProofExpr>,
+    modulus: i64,
+    // Not serialized, so that plans and their proofs are the same in tests as outside of them
+    #[cfg(test)]
+    #[serde(skip)]
+    pub(crate) forged_remainder_offset: i64,
+}
+
+impl ModExpr {
+    /// Create numerical `%` expression
+    pub fn new(lhs: Box<DynProofExpr>, modulus: i64) -> Self {
+        Self {
+            lhs,
+            modulus,
+            #[cfg(test)]
+            forged_remainder_offset: 0,
+        }
+    }
+}
+
+/// The number of bits of the absolute value of a quotient of a column of type `dividend_type` by an `i64`
+pub(super) fn quotient_bits(dividend_type: ColumnType) -> usize {
+    match dividend_type {
+        ColumnType::Int128 => 128,
+        _ => 64,
+    }
+}
+
+/// The largest possible remainder of a division by `divisor`, `|m| - 1`
+fn max_remainder(divisor: i64) -> i128 {
+    i128::from(divisor).abs() - 1
+}
+
+/// Compute the quotients and non-negative remainders of an integer column divided by `modulus`.
+///
+/// In tests, a non-zero `forged_remainder_offset` shifts every remainder by the offset, wrapped to stay in range,
+/// and forges the quotients so that `dividend = q * m + r` still holds in the scalar field, as a dishonest prover would.
+///
+/// # Panics
+///
+/// Panics if the column is not an integer column.
+pub(super) fn divide_euclid<'a, S: Scalar>(
+    alloc: &'a Bump,
+    dividend: Column<'a, S>,
+    modulus: i64,
+    #[cfg(test)] forged_remainder_offset: i64,
+) -> (&'a [S], &'a [i64]) {
+    #[cfg(test)]
+    let modulus_inverse = S::from(modulus).inv().expect("modulus is non-zero");
+    let modulus = i128::from(modulus);
+    let (quotients, remainders): (Vec<S>, Vec<i64>) = dividend
+        .to_scalar_with_scaling(0)
+        .into_iter()
+        .map(|value| {
+            let Ok(value) = TryInto::<i128>::try_into(value) else {
+                panic!("dividend should be an integer");
+            };
+            let remainder = i64::try_from(value.rem_euclid(modulus))
+                .expect("remainder is smaller than the modulus");
+            #[cfg(test)]
+            if forged_remainder_offset != 0 {
+                let forged_remainder = i64::try_from(
+                    (i128::from(remainder) + i128::from(forged_remainder_offset))
+                        .rem_euclid(modulus),
+                )
+                .expect("remainder is smaller than the modulus");
+                return (
+                    (S::from(value) - S::from(forged_remainder)) * modulus_inverse,
+                    forged_remainder,
+                );
+            }
+            (S::from(value.div_euclid(modulus)), remainder)
+        })
+        .unzip();
+    (
+        alloc.alloc_slice_copy(&quotients),
+        alloc.alloc_slice_copy(&remainders),
+    )
+}
+
+/// Count the proof of a Euclidean division by a constant divisor. See [`prover_evaluate_euclidean_division`].
+pub(super) fn count_euclidean_division(builder: &mut CountBuilder) -> Result<(), ProofError> {
+    builder.count_intermediate_mles(2);
+    builder.count_subpolynomials(1);
+    builder.count_degree(2);
+    // The quotient, the remainder and `|m| - 1 - r`
+    count_sign(builder)?;
+    count_sign(builder)?;
+    count_sign(builder)?;
+    Ok(())
+}
+
+/// Prove the Euclidean division of an integer column by a constant, non-zero `divisor`, returning the remainders.
+///
+/// The prover commits to the quotient `q` and remainder `r` of each row, and proves `dividend = q * m + r`,
+/// that `r` and `|m| - 1 - r` are non-negative, and the sign decomposition of `q`, which the verifier range checks.
+/// This is the truncating-division gadget of both `%` and `DATE_TRUNC`.
+pub(super) fn prover_evaluate_euclidean_division<'a, S: Scalar>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    dividend: Column<'a, S>,
+    divisor: i64,
+    #[cfg(test)] forged_remainder_offset: i64,
+) -> &'a [i64] {
+    let (quotients, remainders) = divide_euclid(
+        alloc,
+        dividend,
+        divisor,
+        #[cfg(test)]
+        forged_remainder_offset,
+    );
+    builder.produce_intermediate_mle(quotients);
+    builder.produce_intermediate_mle(remainders);
+
+    // subpolynomial: dividend - m * q - r
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(dividend)]),
+            (-S::from(divisor), vec![Box::new(quotients)]),
+            (-S::one(), vec![Box::new(remainders)]),
+        ],
+    );
+
+    // The quotient is bounded, so `dividend = m * q + r` also holds over the integers
+    prover_evaluate_sign(
+        builder,
+        alloc,
+        quotients,
+        #[cfg(test)]
+        false,
+    );
+    // 0 <= r
+    let remainders_as_scalars = alloc.alloc_slice_fill_iter(remainders.iter().map(S::from));
+    prover_evaluate_sign(
+        builder,
+        alloc,
+        remainders_as_scalars,
+        #[cfg(test)]
+        false,
+    );
+    // r <= |m| - 1
+    let max_remainder = S::from(max_remainder(divisor));
+    let remainder_gaps =
+        alloc.alloc_slice_fill_iter(remainders.iter().map(|r| max_remainder - S::from(r)));
+    prover_evaluate_sign(
+        builder,
+        alloc,
+        remainder_gaps,
+        #[cfg(test)]
+        false,
+    );
+
+    remainders
+}
+
+/// Verify the Euclidean division of an expression by a constant, non-zero `divisor`, returning the evaluation of the remainders.
+///
+/// The quotients are range checked to `|q| < 2^quotient_bits`, which must hold for every honest quotient
+/// and keep `q * m + r` from wrapping around the scalar field. See [`prover_evaluate_euclidean_division`].
+pub(super) fn verifier_evaluate_euclidean_division<S: Scalar>(
+    builder: &mut VerificationBuilder<S>,
+    dividend: S,
+    divisor: i64,
+    quotient_bits: usize,
+    one_eval: S,
+) -> Result<S, ProofError> {
+    let quotient = builder.consume_intermediate_mle();
+    let remainder = builder.consume_intermediate_mle();
+
+    // subpolynomial: dividend - m * q - r
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &SumcheckSubpolynomialType::Identity,
+        dividend - S::from(divisor) * quotient - remainder,
+    );
+
+    verifier_evaluate_bounded_sign(builder, quotient, one_eval, quotient_bits)?;
+    let remainder_sign = verifier_evaluate_sign(builder, remainder, one_eval)?;
+    let remainder_gap = S::from(max_remainder(divisor)) * one_eval - remainder;
+    let remainder_gap_sign = verifier_evaluate_sign(builder, remainder_gap, one_eval)?;
+    if remainder_sign != S::zero() || remainder_gap_sign != S::zero() {
+        return Err(ProofError::VerificationError {
+            error: "remainder is out of range",
+        });
+    }
+    Ok(remainder)
+}
+
+impl ProofExpr for ModExpr {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.lhs.count(builder)?;
+        count_euclidean_division(builder)
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::BigInt
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let lhs_column: Column<'a, S> = self.lhs.result_evaluate(alloc, table);
+        let (_, remainders) = divide_euclid(
+            alloc,
+            lhs_column,
+            self.modulus,
+            #[cfg(test)]
+            self.forged_remainder_offset,
+        );
+        Column::BigInt(remainders)
+    }
+
+    #[tracing::instrument(name = "ModExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let lhs_column: Column<'a, S> = self.lhs.prover_evaluate(builder, alloc, table);
+        Column::BigInt(prover_evaluate_euclidean_division(
+            builder,
+            alloc,
+            lhs_column,
+            self.modulus,
+            #[cfg(test)]
+            self.forged_remainder_offset,
+        ))
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        if self.modulus == 0 {
+            return Err(ProofError::VerificationError {
+                error: "modulus must be non-zero",
+            });
+        }
+        let lhs = self.lhs.verifier_evaluate(builder, accessor, one_eval)?;
+        verifier_evaluate_euclidean_division(
+            builder,
+            lhs,
+            self.modulus,
+            quotient_bits(self.lhs.data_type()),
+            one_eval,
+        )
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.lhs.get_column_references(columns);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{
+            owned_table_utility::*, table_utility::*, Column, ColumnOperationError,
+            OwnedTableTestAccessor, TableTestAccessor,
+        },
+        scalar::Curve25519Scalar,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
+        proof_plans::test_utility::*,
+    },
+};
+use bumpalo::Bump;
+
+// select a % 3 as r, b from sxt.t where c % 2 = 1
+#[test]
+fn we_can_prove_a_typical_modulo_query() {
+    let data = owned_table([
+        bigint("a", [7_i64, 8, 9, 10, 100, 0]),
+        varchar("b", ["a", "b", "c", "d", "e", "f"]),
+        int("c", [1_i32, 3, 4, 5, 6, 7]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(modulo(column(t, "a", &accessor), const_bigint(3)), "r"),
+            col_expr_plan(t, "b", &accessor),
+        ],
+        tab(t),
+        equal(
+            modulo(column(t, "c", &accessor), const_int(2)),
+            const_bigint(1),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("r", [1_i64, 2, 1, 0]),
+        varchar("b", ["a", "b", "d", "f"]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+// select a % 4 as r, a % -4 as s from sxt.t
+#[test]
+fn we_can_prove_modulo_with_negative_operands_and_the_remainder_is_never_negative() {
+    let data = owned_table([bigint("a", [-7_i64, -8, -1, 0, 5, i64::MIN, i64::MAX])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = projection(
+        vec![
+            aliased_plan(modulo(column(t, "a", &accessor), const_bigint(4)), "r"),
+            aliased_plan(modulo(column(t, "a", &accessor), const_bigint(-4)), "s"),
+        ],
+        tab(t),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("r", [1_i64, 0, 3, 0, 1, 0, 3]),
+        bigint("s", [1_i64, 0, 3, 0, 1, 0, 3]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_compute_the_result_of_modulo_by_one_and_by_extreme_moduli() {
+    let alloc = Bump::new();
+    let data = table([borrowed_bigint("a", [-3_i64, 0, 3, i64::MAX], &alloc)]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = TableTestAccessor::<InnerProductProof>::new_from_table(t, data.clone(), 0, ());
+    let evaluate = |modulus| {
+        modulo(column(t, "a", &accessor), const_bigint(modulus)).result_evaluate(&alloc, &data)
+    };
+    let expected: Column<'_, Curve25519Scalar> = Column::BigInt(&[0, 0, 0, 0]);
+    assert_eq!(evaluate(1), expected);
+    let expected: Column<'_, Curve25519Scalar> = Column::BigInt(&[i64::MAX - 3, 0, 3, 0]);
+    assert_eq!(evaluate(i64::MAX), expected);
+    let expected: Column<'_, Curve25519Scalar> = Column::BigInt(&[i64::MAX - 2, 0, 3, i64::MAX]);
+    assert_eq!(evaluate(i64::MIN), expected);
+}
+
+#[test]
+fn we_cannot_take_the_modulo_by_zero_or_by_a_non_literal() {
+    let data = owned_table([
+        bigint("a", [1_i64, 2]),
+        bigint("b", [1_i64, 2]),
+        decimal75("d", 10, 2, [1_i64, 2]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_modulo(column(t, "a", &accessor), const_bigint(0)),
+        Err(ConversionError::ColumnOperationError {
+            source: ColumnOperationError::DivisionByZero
+        })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_modulo(column(t, "a", &accessor), const_int128(0)),
+        Err(ConversionError::ColumnOperationError {
+            source: ColumnOperationError::DivisionByZero
+        })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_modulo(column(t, "a", &accessor), column(t, "b", &accessor)),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_modulo(
+            column(t, "a", &accessor),
+            const_int128(i128::from(i64::MAX) + 1)
+        ),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_modulo(column(t, "d", &accessor), const_bigint(2)),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_modulo(column(t, "a", &accessor), const_decimal75(2, 1, 20)),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+}
+
+// select (a + b) % 7 from sxt.t, where a + b is out of the range of INT128
+#[test]
+fn we_cannot_take_the_modulo_of_an_int128_sum() {
+    let data = owned_table([int128("a", [i128::MAX, 1]), int128("b", [i128::MAX, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_modulo(
+            add(column(t, "a", &accessor), column(t, "b", &accessor)),
+            const_bigint(7)
+        ),
+        Err(ConversionError::Unprovable { .. })
+    ));
+}
+
+// select (a * b) % 3 from sxt.t, where the quotient of a * b by 3 does not fit in 64 bits
+#[test]
+fn we_cannot_take_the_modulo_of_a_bigint_product() {
+    let data = owned_table([bigint("a", [i64::MAX, 1]), bigint("b", [i64::MAX, 2])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_modulo(
+            multiply(column(t, "a", &accessor), column(t, "b", &accessor)),
+            const_bigint(3)
+        ),
+        Err(ConversionError::Unprovable { .. })
+    ));
+}
+
+// select a % 3 as r from sxt.t, with remainders forged by a dishonest prover
+#[test]
+fn we_cannot_prove_modulo_with_a_forged_remainder() {
+    let data = owned_table([
+        bigint("a", [7_i64, -8, 9, i64::MIN]),
+        int128("b", [7_i128, -8, 9, i128::MIN]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    for column_name in ["a", "b"] {
+        let mut expr = modulo(column(t, column_name, &accessor), const_bigint(3));
+        if let DynProofExpr::Mod(modulo) = &mut expr {
+            modulo.forged_remainder_offset = 1;
+        }
+        let ast = projection(vec![aliased_plan(expr, "r")], tab(t));
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+        assert!(verifiable_res.verify(&ast, &accessor, &()).is_err());
+    }
+}
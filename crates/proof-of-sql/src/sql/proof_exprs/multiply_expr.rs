@@ -1,14 +1,14 @@
 use super::{DynProofExpr, ProofExpr};
 use crate::{
     base::{
-        database::{try_multiply_column_types, Column, ColumnRef, ColumnType, Table},
+        database::{try_multiply_column_types, Column, ColumnRef, ColumnType, LiteralValue, Table},
         map::{IndexMap, IndexSet},
         proof::ProofError,
         scalar::Scalar,
     },
     sql::{
         proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
-        proof_exprs::multiply_columns,
+        proof_exprs::{multiply_columns, scale_column},
     },
 };
 use alloc::{boxed::Box, vec};
@@ -16,6 +16,10 @@ use bumpalo::Bump;
 use serde::{Deserialize, Serialize};
 
 /// Provable numerical * expression
+///
+/// If either operand is a literal, the product is a constant multiple of the other operand,
+/// so its evaluation follows from the evaluation of that operand and it is not committed to.
+/// Otherwise the product is committed to as an intermediate MLE and checked by a sumcheck subpolynomial.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MultiplyExpr {
     lhs: Box<DynProofExpr>,
@@ -27,12 +31,25 @@ impl MultiplyExpr {
     pub fn new(lhs: Box<DynProofExpr>, rhs: Box<DynProofExpr>) -> Self {
         Self { lhs, rhs }
     }
+
+    /// The non-literal operand and the literal factor, if either operand is a literal
+    fn constant_factor(&self) -> Option<(&DynProofExpr, &LiteralValue)> {
+        match (&*self.lhs, &*self.rhs) {
+            (expr, DynProofExpr::Literal(literal)) | (DynProofExpr::Literal(literal), expr) => {
+                Some((expr, literal.value()))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl ProofExpr for MultiplyExpr {
     fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
         self.lhs.count(builder)?;
         self.rhs.count(builder)?;
+        if self.constant_factor().is_some() {
+            return Ok(());
+        }
         builder.count_subpolynomials(1);
         builder.count_intermediate_mles(1);
         builder.count_degree(3);
@@ -49,6 +66,10 @@ impl ProofExpr for MultiplyExpr {
         alloc: &'a Bump,
         table: &Table<'a, S>,
     ) -> Column<'a, S> {
+        if let Some((expr, factor)) = self.constant_factor() {
+            let column: Column<'a, S> = expr.result_evaluate(alloc, table);
+            return Column::Scalar(scale_column(&column, factor.to_scalar(), alloc));
+        }
         let lhs_column: Column<'a, S> = self.lhs.result_evaluate(alloc, table);
         let rhs_column: Column<'a, S> = self.rhs.result_evaluate(alloc, table);
         let scalars = multiply_columns(&lhs_column, &rhs_column, alloc);
@@ -66,6 +87,10 @@ impl ProofExpr for MultiplyExpr {
         alloc: &'a Bump,
         table: &Table<'a, S>,
     ) -> Column<'a, S> {
+        if let Some((expr, factor)) = self.constant_factor() {
+            let column: Column<'a, S> = expr.prover_evaluate(builder, alloc, table);
+            return Column::Scalar(scale_column(&column, factor.to_scalar(), alloc));
+        }
         let lhs_column: Column<'a, S> = self.lhs.prover_evaluate(builder, alloc, table);
         let rhs_column: Column<'a, S> = self.rhs.prover_evaluate(builder, alloc, table);
 
@@ -90,6 +115,10 @@ impl ProofExpr for MultiplyExpr {
         accessor: &IndexMap<ColumnRef, S>,
         one_eval: S,
     ) -> Result<S, ProofError> {
+        if let Some((expr, factor)) = self.constant_factor() {
+            let eval = expr.verifier_evaluate(builder, accessor, one_eval)?;
+            return Ok(eval * factor.to_scalar());
+        }
         let lhs = self.lhs.verifier_evaluate(builder, accessor, one_eval)?;
         let rhs = self.rhs.verifier_evaluate(builder, accessor, one_eval)?;
 
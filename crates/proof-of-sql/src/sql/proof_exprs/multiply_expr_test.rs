@@ -2,14 +2,14 @@ use crate::{
     base::{
         commitment::InnerProductProof,
         database::{
-            owned_table_utility::*, table_utility::*, Column, OwnedTableTestAccessor,
+            owned_table_utility::*, table_utility::*, Column, ColumnType, OwnedTableTestAccessor,
             TableTestAccessor,
         },
         scalar::{test_scalar::TestScalar, Curve25519Scalar},
     },
     sql::{
         parse::ConversionError,
-        proof::{exercise_verification, QueryError, VerifiableQueryResult},
+        proof::{exercise_verification, CountBuilder, ProveError, VerifiableQueryResult},
         proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
         proof_plans::{test_utility::*, DynProofPlan},
     },
@@ -70,6 +70,71 @@ fn we_can_prove_a_typical_multiply_query() {
     assert_eq!(res, expected_res);
 }
 
+// select a * -3 as b, 1.5 * d as e, a * d as f from sxt.t where 2 * a >= 2
+#[test]
+fn we_can_prove_a_multiply_query_with_constant_operands() {
+    let data = owned_table([
+        bigint("a", [1_i64, 0, 3, -4, 5]),
+        decimal75("d", 2, 1, [21_i64, 4, 21, -7, 0]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(multiply(column(t, "a", &accessor), const_bigint(-3)), "b"),
+            aliased_plan(
+                multiply(const_decimal75(2, 1, 15), column(t, "d", &accessor)),
+                "e",
+            ),
+            aliased_plan(
+                multiply(column(t, "a", &accessor), column(t, "d", &accessor)),
+                "f",
+            ),
+        ],
+        tab(t),
+        gte(
+            multiply(const_bigint(2), column(t, "a", &accessor)),
+            const_bigint(2),
+        ),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([
+        bigint("b", [-3_i64, -9, -15]),
+        decimal75("e", 5, 2, [315_i64, 315, 0]),
+        decimal75("f", 22, 1, [21_i64, 63, 0]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn a_product_with_a_constant_operand_is_not_committed_to() {
+    let data = owned_table::<Curve25519Scalar>([bigint("a", [1_i64, 2]), bigint("b", [3_i64, 4])]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let count_intermediate_mles = |expr: DynProofExpr| {
+        let mut builder = CountBuilder::new(&[]);
+        expr.count(&mut builder).unwrap();
+        builder.counts().unwrap().intermediate_mles
+    };
+    assert_eq!(
+        count_intermediate_mles(multiply(column(t, "a", &accessor), const_bigint(3))),
+        0
+    );
+    assert_eq!(
+        count_intermediate_mles(multiply(const_int(3), column(t, "a", &accessor))),
+        0
+    );
+    assert_eq!(
+        count_intermediate_mles(multiply(
+            column(t, "a", &accessor),
+            column(t, "b", &accessor)
+        )),
+        1
+    );
+}
+
 // Column type issue tests
 #[test]
 fn decimal_column_type_issues_error_out_when_producing_provable_ast() {
@@ -100,12 +165,14 @@ fn result_expr_can_overflow() {
         tab(t),
         equal(column(t, "b", &accessor), const_bigint(2)),
     );
-    let verifiable_res: VerifiableQueryResult<InnerProductProof> =
-        VerifiableQueryResult::new(&ast, &accessor, &());
-    assert!(matches!(
-        verifiable_res.verify(&ast, &accessor, &()),
-        Err(QueryError::Overflow)
-    ));
+    assert_eq!(
+        VerifiableQueryResult::<InnerProductProof>::try_new(&ast, &accessor, &()).err(),
+        Some(ProveError::ResultOverflow {
+            column: "c".parse().unwrap(),
+            row: 0,
+            column_type: ColumnType::SmallInt,
+        })
+    );
 }
 
 // select a * b as c from sxt.t where b == 0
@@ -175,12 +242,14 @@ fn result_expr_can_overflow_more() {
         tab(t),
         const_bool(true),
     );
-    let verifiable_res: VerifiableQueryResult<InnerProductProof> =
-        VerifiableQueryResult::new(&ast, &accessor, &());
-    assert!(matches!(
-        verifiable_res.verify(&ast, &accessor, &()),
-        Err(QueryError::Overflow)
-    ));
+    assert_eq!(
+        VerifiableQueryResult::<InnerProductProof>::try_new(&ast, &accessor, &()).err(),
+        Some(ProveError::ResultOverflow {
+            column: "c".parse().unwrap(),
+            row: 0,
+            column_type: ColumnType::BigInt,
+        })
+    );
 }
 
 // select * from sxt.t where a * b * c * d * e = res
@@ -0,0 +1,81 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, VerificationBuilder},
+};
+use alloc::boxed::Box;
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+
+/// Provable numerical unary `-` expression
+///
+/// The result has the same type as the operand. Like `+` and `-`, it is computed exactly,
+/// so negating the smallest value of a signed integer type or any nonzero unsigned value
+/// does not wrap around. Such results only fail, with an overflow error, if they are selected.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NegExpr {
+    expr: Box<DynProofExpr>,
+}
+
+impl NegExpr {
+    /// Create numerical unary `-` expression
+    pub fn new(expr: Box<DynProofExpr>) -> Self {
+        Self { expr }
+    }
+}
+
+/// Negate every value of a numeric column.
+fn negate_column<'a, S: Scalar>(alloc: &'a Bump, column: &Column<'a, S>) -> &'a [S] {
+    let values = column.to_scalar_with_scaling(0);
+    alloc.alloc_slice_fill_with(values.len(), |i| -values[i])
+}
+
+impl ProofExpr for NegExpr {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)
+    }
+
+    fn data_type(&self) -> ColumnType {
+        self.expr.data_type()
+    }
+
+    #[tracing::instrument(name = "NegExpr::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let expr_column: Column<'a, S> = self.expr.result_evaluate(alloc, table);
+        Column::Scalar(negate_column(alloc, &expr_column))
+    }
+
+    #[tracing::instrument(name = "NegExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let expr_column: Column<'a, S> = self.expr.prover_evaluate(builder, alloc, table);
+        Column::Scalar(negate_column(alloc, &expr_column))
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let eval = self.expr.verifier_evaluate(builder, accessor, one_eval)?;
+        Ok(-eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
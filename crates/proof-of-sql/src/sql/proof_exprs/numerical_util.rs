@@ -132,6 +132,17 @@ pub(crate) fn multiply_columns<'a, S: Scalar>(
     })
 }
 
+/// Multiply a column by a constant, without materializing the constant as a column.
+/// # Panics
+/// Panics if a row of `column` is out of bounds, which cannot happen since only its rows are read.
+pub(crate) fn scale_column<'a, S: Scalar>(
+    column: &Column<'a, S>,
+    factor: S,
+    alloc: &'a Bump,
+) -> &'a [S] {
+    alloc.alloc_slice_fill_with(column.len(), |i| column.scalar_at(i).unwrap() * factor)
+}
+
 #[allow(dead_code)]
 /// Multiply two [`ColumnarValues`] together.
 /// # Panics
@@ -2,9 +2,10 @@ use crate::{
     base::{
         commitment::InnerProductProof,
         database::{
-            owned_table_utility::*, table_utility::*, Column, OwnedTableTestAccessor,
+            owned_table_utility::*, table_utility::*, Column, OwnedTableTestAccessor, Table,
             TableTestAccessor, TestAccessor,
         },
+        scalar::test_scalar::TestScalar,
     },
     sql::{
         proof::{exercise_verification, VerifiableQueryResult},
@@ -190,3 +191,37 @@ fn we_can_compute_the_correct_output_of_an_or_expr_using_result_evaluate() {
     let expected_res = Column::Boolean(&[false, true, true, true]);
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn we_can_prove_and_evaluate_the_full_truth_table_of_an_or_query() {
+    // Every combination of lhs and rhs values
+    let lhs = [false, false, true, true];
+    let rhs = [false, true, false, true];
+    let data = owned_table([
+        boolean("l", lhs),
+        boolean("r", rhs),
+        bigint("i", [0, 1, 2, 3]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t, data, 0, ());
+    let ast = filter(
+        cols_expr_plan(t, &["i"], &accessor),
+        tab(t),
+        or(column(t, "l", &accessor), column(t, "r", &accessor)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &());
+    exercise_verification(&verifiable_res, &ast, &accessor, t);
+    let res = verifiable_res.verify(&ast, &accessor, &()).unwrap().table;
+    let expected_res = owned_table([bigint("i", [1, 2, 3])]);
+    assert_eq!(res, expected_res);
+
+    let alloc = Bump::new();
+    let data: Table<TestScalar> = table([
+        borrowed_boolean("l", lhs, &alloc),
+        borrowed_boolean("r", rhs, &alloc),
+    ]);
+    let or_expr: DynProofExpr = or(column(t, "l", &accessor), column(t, "r", &accessor));
+    let res = or_expr.result_evaluate(&alloc, &data);
+    let expected_res = Column::Boolean(&[false, true, true, true]);
+    assert_eq!(res, expected_res);
+}
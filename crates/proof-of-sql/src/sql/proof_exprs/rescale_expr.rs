@@ -0,0 +1,456 @@
+use super::{
+    count_sign, prover_evaluate_sign, verifier_evaluate_bounded_sign, verifier_evaluate_sign,
+    DynProofExpr, ProofExpr,
+};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, Table},
+        map::{IndexMap, IndexSet},
+        math::decimal::{Precision, RoundingMode},
+        proof::ProofError,
+        scalar::{Scalar, ScalarExt},
+    },
+    sql::proof::{CountBuilder, FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+
+/// Provable rescaling of a numeric expression to a decimal with a given precision and scale
+///
+/// Scaling up multiplies by a power of ten, which is exact. Scaling down by `k` digits divides by `d = 10^k`
+/// and rounds the quotient `q` with the [`RoundingMode`]. The prover commits to `q`, and the verifier
+/// attests the mode through the range of the remainder `r = v - d * q`:
+/// - [`RoundingMode::Truncate`]: `0 <= s * r <= d - 1`, where `s` is the sign of the value `v`.
+/// - [`RoundingMode::HalfUp`]: `-d / 2 <= s * r <= d / 2 - 1`.
+/// - [`RoundingMode::HalfEven`]: the prover splits `q = 2 * h + p` with a committed bit `p`, and
+///   `-d / 2 + p <= r <= d / 2 - p`, so that a remainder of exactly half is only allowed for even quotients.
+///
+/// In every case exactly one quotient satisfies the range over the integers. Since `|v| < 2^127`,
+/// the verifier range checks the quotient, or the halved quotient `h`, to `|q| < 2^(128 - b)` with `2^b <= d`,
+/// so that `v = d * q + r` cannot wrap around the scalar field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RescaleExpr {
+    expr: Box<DynProofExpr>,
+    precision: Precision,
+    scale: i8,
+    mode: RoundingMode,
+    // Not serialized, so that plans and their proofs are the same in tests as outside of them
+    #[cfg(test)]
+    #[serde(skip)]
+    pub(crate) forged_remainder_offset: i64,
+}
+
+impl RescaleExpr {
+    /// Create a rescaling expression
+    ///
+    /// The caller is responsible for checking that the result fits in `precision`
+    /// and that the input fits in an `i128` when scaling down.
+    pub fn new(
+        expr: Box<DynProofExpr>,
+        precision: Precision,
+        scale: i8,
+        mode: RoundingMode,
+    ) -> Self {
+        Self {
+            expr,
+            precision,
+            scale,
+            mode,
+            #[cfg(test)]
+            forged_remainder_offset: 0,
+        }
+    }
+
+    /// The number of digits the input is scaled up by, which is negative when it is scaled down
+    fn scale_difference(&self) -> i16 {
+        let input_scale = self.expr.data_type().scale().unwrap_or(0);
+        i16::from(self.scale) - i16::from(input_scale)
+    }
+
+    /// The divisor `10^k` when scaling down by `k` digits, or `None` when scaling up
+    fn divisor(&self) -> Option<i128> {
+        let difference = self.scale_difference();
+        (difference < 0).then(|| 10_i128.pow(u32::from(difference.unsigned_abs())))
+    }
+
+    /// The factor `10^k` when scaling up by `k` digits
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input is scaled down.
+    fn multiplier<S: Scalar>(&self) -> S {
+        S::pow10(u8::try_from(self.scale_difference()).expect("scaling up is non-negative"))
+    }
+
+    fn result_column<'a, S: Scalar>(&self, values: &'a [S]) -> Column<'a, S> {
+        Column::Decimal75(self.precision, self.scale, values)
+    }
+}
+
+/// The number of bits of the absolute value of any rounded quotient, or halved quotient, of an `i128` by `divisor`
+///
+/// With `2^b <= d`, both are at most `2^127 / d + 1 < 2^(128 - b)`.
+fn quotient_bits(divisor: i128) -> usize {
+    (128 - divisor.ilog2()) as usize
+}
+
+/// Shift the quotients by `-offset / divisor`, so that the remainders are shifted by `offset`, as a dishonest prover would
+#[cfg(test)]
+fn forge_quotients<'a, S: Scalar>(
+    alloc: &'a Bump,
+    quotients: &'a [S],
+    divisor: S,
+    offset: i64,
+) -> &'a [S] {
+    if offset == 0 {
+        return quotients;
+    }
+    let shift = S::from(offset) * divisor.inv().expect("divisor is non-zero");
+    alloc.alloc_slice_fill_iter(quotients.iter().map(|quotient| *quotient - shift))
+}
+
+/// Compute the rounded quotients of a numeric column divided by `divisor`, along with the values as scalars.
+///
+/// # Panics
+///
+/// Panics if a value of the column does not fit in an `i128`.
+fn divide_rounded<'a, S: Scalar>(
+    alloc: &'a Bump,
+    column: Column<'a, S>,
+    divisor: i128,
+    mode: RoundingMode,
+) -> (&'a [S], Vec<i128>) {
+    let values = column.to_scalar_with_scaling(0);
+    let quotients = values
+        .iter()
+        .map(|&value| {
+            let Ok(value) = TryInto::<i128>::try_into(value) else {
+                panic!("value should fit in an i128");
+            };
+            mode.divide(value, divisor)
+        })
+        .collect();
+    (alloc.alloc_slice_copy(&values), quotients)
+}
+
+impl ProofExpr for RescaleExpr {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.expr.count(builder)?;
+        if self.divisor().is_some() {
+            builder.count_intermediate_mles(2);
+            builder.count_subpolynomials(1);
+            builder.count_degree(3);
+            // The sign of the value, if the mode needs it
+            if self.mode != RoundingMode::HalfEven {
+                count_sign(builder)?;
+            }
+            // The quotient and the two bounds of the remainder
+            count_sign(builder)?;
+            count_sign(builder)?;
+            count_sign(builder)?;
+        }
+        Ok(())
+    }
+
+    fn data_type(&self) -> ColumnType {
+        ColumnType::Decimal75(self.precision, self.scale)
+    }
+
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let column: Column<'a, S> = self.expr.result_evaluate(alloc, table);
+        let values = if let Some(divisor) = self.divisor() {
+            let (_, quotients) = divide_rounded(alloc, column, divisor, self.mode);
+            let quotients = alloc.alloc_slice_fill_iter(quotients.into_iter().map(S::from));
+            #[cfg(test)]
+            let quotients = forge_quotients(
+                alloc,
+                quotients,
+                S::from(divisor),
+                self.forged_remainder_offset,
+            );
+            quotients
+        } else {
+            let multiplier = self.multiplier::<S>();
+            alloc.alloc_slice_fill_iter(
+                column
+                    .to_scalar_with_scaling(0)
+                    .into_iter()
+                    .map(|value| value * multiplier),
+            )
+        };
+        self.result_column(values)
+    }
+
+    #[tracing::instrument(name = "RescaleExpr::prover_evaluate", level = "debug", skip_all)]
+    fn prover_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+    ) -> Column<'a, S> {
+        let column: Column<'a, S> = self.expr.prover_evaluate(builder, alloc, table);
+        let Some(divisor) = self.divisor() else {
+            let multiplier = self.multiplier::<S>();
+            let values = alloc.alloc_slice_fill_iter(
+                column
+                    .to_scalar_with_scaling(0)
+                    .into_iter()
+                    .map(|value| value * multiplier),
+            );
+            return self.result_column(values);
+        };
+        let (values, quotients) = divide_rounded(alloc, column, divisor, self.mode);
+        let quotients_as_scalars: &[S] =
+            alloc.alloc_slice_fill_iter(quotients.iter().copied().map(S::from));
+        #[cfg(test)]
+        let quotients_as_scalars = forge_quotients(
+            alloc,
+            quotients_as_scalars,
+            S::from(divisor),
+            self.forged_remainder_offset,
+        );
+
+        let (lower_gaps, upper_gaps) = if self.mode == RoundingMode::HalfEven {
+            prove_half_even_remainders(
+                builder,
+                alloc,
+                values,
+                &quotients,
+                divisor,
+                #[cfg(test)]
+                self.forged_remainder_offset,
+            )
+        } else {
+            prove_signed_remainders(
+                builder,
+                alloc,
+                values,
+                quotients_as_scalars,
+                divisor,
+                self.mode,
+            )
+        };
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            lower_gaps,
+            #[cfg(test)]
+            false,
+        );
+        prover_evaluate_sign(
+            builder,
+            alloc,
+            upper_gaps,
+            #[cfg(test)]
+            false,
+        );
+
+        self.result_column(quotients_as_scalars)
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        one_eval: S,
+    ) -> Result<S, ProofError> {
+        let value = self.expr.verifier_evaluate(builder, accessor, one_eval)?;
+        let Some(divisor) = self.divisor() else {
+            return Ok(value * self.multiplier::<S>());
+        };
+        let d = S::from(divisor);
+        let half = S::from(divisor / 2) * one_eval;
+        let quotient_bits = quotient_bits(divisor);
+
+        let (quotient, lower_gap, upper_gap) = if self.mode == RoundingMode::HalfEven {
+            let halved_quotient = builder.consume_intermediate_mle();
+            let parity = builder.consume_intermediate_mle();
+
+            // subpolynomial: p * p - p
+            builder.produce_sumcheck_subpolynomial_evaluation(
+                &SumcheckSubpolynomialType::Identity,
+                parity * parity - parity,
+            );
+
+            verifier_evaluate_bounded_sign(builder, halved_quotient, one_eval, quotient_bits)?;
+            let quotient = S::TWO * halved_quotient + parity;
+            let remainder = value - d * quotient;
+            (
+                quotient,
+                remainder + half - parity,
+                half - parity - remainder,
+            )
+        } else {
+            let sign = verifier_evaluate_sign(builder, value, one_eval)?;
+            let quotient = builder.consume_intermediate_mle();
+            let signed_remainder = builder.consume_intermediate_mle();
+
+            // subpolynomial: t - (1 - 2 * a) * (v - d * q), where a is the sign bit of v
+            builder.produce_sumcheck_subpolynomial_evaluation(
+                &SumcheckSubpolynomialType::Identity,
+                signed_remainder - value + d * quotient + S::TWO * sign * value
+                    - S::TWO * d * sign * quotient,
+            );
+
+            verifier_evaluate_bounded_sign(builder, quotient, one_eval, quotient_bits)?;
+            let (lower, upper) = remainder_bounds(self.mode, d, S::from(divisor / 2));
+            (
+                quotient,
+                signed_remainder - lower * one_eval,
+                upper * one_eval - signed_remainder,
+            )
+        };
+        let lower_gap_sign = verifier_evaluate_sign(builder, lower_gap, one_eval)?;
+        let upper_gap_sign = verifier_evaluate_sign(builder, upper_gap, one_eval)?;
+        if lower_gap_sign != S::zero() || upper_gap_sign != S::zero() {
+            return Err(ProofError::VerificationError {
+                error: "remainder is out of range for the rounding mode",
+            });
+        }
+
+        Ok(quotient)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.expr.get_column_references(columns);
+    }
+}
+
+/// The bounds of the signed remainder `s * r` for the modes that round symmetrically around zero
+fn remainder_bounds<S: Scalar>(mode: RoundingMode, d: S, half: S) -> (S, S) {
+    match mode {
+        RoundingMode::Truncate => (S::ZERO, d - S::ONE),
+        RoundingMode::HalfUp | RoundingMode::HalfEven => (-half, half - S::ONE),
+    }
+}
+
+/// Prove the remainders of rounding half to even, with the quotients split as `q = 2 * h + p`,
+/// returning `r + d / 2 - p` and `d / 2 - p - r`, which must be non-negative.
+fn prove_half_even_remainders<'a, S: Scalar>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    values: &'a [S],
+    quotients: &[i128],
+    divisor: i128,
+    #[cfg(test)] forged_remainder_offset: i64,
+) -> (&'a [S], &'a [S]) {
+    let d = S::from(divisor);
+    let half = S::from(divisor / 2);
+    // q = 2 * h + p
+    let halves: &[S] =
+        alloc.alloc_slice_fill_iter(quotients.iter().map(|q| S::from(q.div_euclid(2))));
+    #[cfg(test)]
+    let halves = forge_quotients(alloc, halves, S::TWO * d, forged_remainder_offset);
+    let parities: &[bool] =
+        alloc.alloc_slice_fill_iter(quotients.iter().map(|q| q.rem_euclid(2) == 1));
+    builder.produce_intermediate_mle(halves);
+    builder.produce_intermediate_mle(parities);
+
+    // subpolynomial: p * p - p
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(parities), Box::new(parities)]),
+            (-S::one(), vec![Box::new(parities)]),
+        ],
+    );
+
+    // The halved quotient is bounded, so `v = d * q + r` also holds over the integers
+    prover_evaluate_sign(
+        builder,
+        alloc,
+        halves,
+        #[cfg(test)]
+        false,
+    );
+    let remainders: Vec<S> = values
+        .iter()
+        .zip(halves)
+        .zip(parities)
+        .map(|((v, h), &p)| *v - d * (S::TWO * *h + S::from(p)))
+        .collect();
+    let parities_as_scalars = parities.iter().map(|&p| S::from(p));
+    // r + d / 2 - p and d / 2 - p - r
+    (
+        alloc.alloc_slice_fill_iter(
+            remainders
+                .iter()
+                .zip(parities_as_scalars.clone())
+                .map(|(r, p)| *r + half - p),
+        ),
+        alloc.alloc_slice_fill_iter(
+            remainders
+                .iter()
+                .zip(parities_as_scalars)
+                .map(|(r, p)| half - p - *r),
+        ),
+    )
+}
+
+/// Prove the signed remainders `t = s * r` of rounding symmetrically around zero, where `s` is the sign of the value,
+/// returning the gaps of `t` to its bounds for the mode, which must be non-negative.
+fn prove_signed_remainders<'a, S: Scalar>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    values: &'a [S],
+    quotients_as_scalars: &'a [S],
+    divisor: i128,
+    mode: RoundingMode,
+) -> (&'a [S], &'a [S]) {
+    let d = S::from(divisor);
+    // t = s * r, where s is the sign of the value
+    let signs = prover_evaluate_sign(
+        builder,
+        alloc,
+        values,
+        #[cfg(test)]
+        false,
+    );
+    let signed_remainders: &[S] =
+        alloc.alloc_slice_fill_iter(values.iter().zip(quotients_as_scalars).zip(signs).map(
+            |((v, q), &negative)| {
+                let remainder = *v - d * *q;
+                if negative {
+                    -remainder
+                } else {
+                    remainder
+                }
+            },
+        ));
+    builder.produce_intermediate_mle(quotients_as_scalars);
+    builder.produce_intermediate_mle(signed_remainders);
+
+    // subpolynomial: t - (1 - 2 * a) * (v - d * q), where a is the sign bit of v
+    builder.produce_sumcheck_subpolynomial(
+        SumcheckSubpolynomialType::Identity,
+        vec![
+            (S::one(), vec![Box::new(signed_remainders)]),
+            (-S::one(), vec![Box::new(values)]),
+            (d, vec![Box::new(quotients_as_scalars)]),
+            (S::TWO, vec![Box::new(signs), Box::new(values)]),
+            (
+                -(S::TWO * d),
+                vec![Box::new(signs), Box::new(quotients_as_scalars)],
+            ),
+        ],
+    );
+
+    // The quotient is bounded, so `v = d * q + r` also holds over the integers
+    prover_evaluate_sign(
+        builder,
+        alloc,
+        quotients_as_scalars,
+        #[cfg(test)]
+        false,
+    );
+    let (lower, upper) = remainder_bounds(mode, d, S::from(divisor / 2));
+    (
+        alloc.alloc_slice_fill_iter(signed_remainders.iter().map(|t| *t - lower)),
+        alloc.alloc_slice_fill_iter(signed_remainders.iter().map(|t| upper - *t)),
+    )
+}
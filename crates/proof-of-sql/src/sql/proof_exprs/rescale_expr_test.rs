@@ -0,0 +1,236 @@
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::*, ColumnOperationError, OwnedTable, OwnedTableTestAccessor,
+        },
+        math::decimal::{DecimalError, Precision, RoundingMode},
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        parse::ConversionError,
+        proof::{QueryError, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, DynProofExpr},
+        proof_plans::{test_utility::*, DynProofPlan},
+    },
+};
+
+/// Prove `plan` over `data` as `sxt.t`, verifying it against `verified_plan`
+fn prove_and_verify(
+    data: OwnedTable<DoryScalar>,
+    plan: impl Fn(&OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan,
+    verified_plan: impl Fn(&OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan,
+) -> Result<OwnedTable<DoryScalar>, QueryError> {
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        data,
+        0,
+        dory_prover_setup,
+    );
+    let verifiable_res = VerifiableQueryResult::<DoryEvaluationProof>::new(
+        &plan(&accessor),
+        &accessor,
+        &dory_prover_setup,
+    );
+    verifiable_res
+        .verify(&verified_plan(&accessor), &accessor, &dory_verifier_setup)
+        .map(|result| result.table)
+}
+
+// select cast(d as decimal(3, 0)) as r, cast(d as decimal(3, 0)) = 2 as is_two from sxt.t
+#[test]
+fn we_can_compare_a_rescaled_decimal_against_an_integer_under_each_rounding_mode() {
+    let t = "sxt.t".parse().unwrap();
+    let data = || owned_table([decimal75("d", 3, 1, [15_i64, 25, -15, -25, 14, 16, 0])]);
+    let cases = [
+        (
+            RoundingMode::Truncate,
+            [1_i64, 2, -1, -2, 1, 1, 0],
+            [false, true, false, false, false, false, false],
+        ),
+        (
+            RoundingMode::HalfUp,
+            [2, 3, -2, -3, 1, 2, 0],
+            [true, false, false, false, false, true, false],
+        ),
+        (
+            RoundingMode::HalfEven,
+            [2, 2, -2, -2, 1, 2, 0],
+            [true, true, false, false, false, true, false],
+        ),
+    ];
+    for (mode, rounded, is_two) in cases {
+        let plan = |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            projection(
+                vec![
+                    aliased_plan(rescale(column(t, "d", accessor), 3, 0, mode), "r"),
+                    aliased_plan(
+                        equal(
+                            rescale(column(t, "d", accessor), 3, 0, mode),
+                            const_bigint(2),
+                        ),
+                        "is_two",
+                    ),
+                ],
+                tab(t),
+            )
+        };
+        let res = prove_and_verify(data(), plan, plan).unwrap();
+        let expected_res = owned_table([decimal75("r", 3, 0, rounded), boolean("is_two", is_two)]);
+        assert_eq!(res, expected_res, "{mode:?}");
+    }
+}
+
+// select cast(d as decimal(4, 1)) as r, cast(a as decimal(5, 2)) as s from sxt.t
+#[test]
+fn we_can_rescale_by_several_digits_and_scale_up_exactly() {
+    let t = "sxt.t".parse().unwrap();
+    let data = || {
+        owned_table([
+            decimal75("d", 5, 3, [1250_i64, 1350, -1250, 1249, -1351, 0]),
+            bigint("a", [1_i64, -2, 0, 3, 4, 5]),
+        ])
+    };
+    let cases = [
+        (RoundingMode::Truncate, [12_i64, 13, -12, 12, -13, 0]),
+        (RoundingMode::HalfUp, [13, 14, -13, 12, -14, 0]),
+        (RoundingMode::HalfEven, [12, 14, -12, 12, -14, 0]),
+    ];
+    for (mode, rounded) in cases {
+        let plan = |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            projection(
+                vec![
+                    aliased_plan(rescale(column(t, "d", accessor), 4, 1, mode), "r"),
+                    aliased_plan(rescale(column(t, "a", accessor), 21, 2, mode), "s"),
+                ],
+                tab(t),
+            )
+        };
+        let res = prove_and_verify(data(), plan, plan).unwrap();
+        let expected_res = owned_table([
+            decimal75("r", 4, 1, rounded),
+            decimal75("s", 21, 2, [100_i64, -200, 0, 300, 400, 500]),
+        ]);
+        assert_eq!(res, expected_res, "{mode:?}");
+    }
+}
+
+#[test]
+fn we_cannot_verify_a_rescaled_decimal_as_rounded_with_a_different_mode() {
+    let t = "sxt.t".parse().unwrap();
+    let plan = |mode| {
+        move |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            projection(
+                vec![aliased_plan(
+                    rescale(column(t, "d", accessor), 3, 0, mode),
+                    "r",
+                )],
+                tab(t),
+            )
+        }
+    };
+    let data = || owned_table([decimal75("d", 3, 1, [15_i64, -25, 7])]);
+    for (proven, verified) in [
+        (RoundingMode::HalfUp, RoundingMode::Truncate),
+        (RoundingMode::Truncate, RoundingMode::HalfUp),
+        (RoundingMode::HalfUp, RoundingMode::HalfEven),
+        (RoundingMode::HalfEven, RoundingMode::HalfUp),
+    ] {
+        assert!(
+            prove_and_verify(data(), plan(proven), plan(verified)).is_err(),
+            "{proven:?} verified as {verified:?}"
+        );
+    }
+}
+
+// select cast(d as decimal(3, 0)) as r from sxt.t, with remainders forged by a dishonest prover
+#[test]
+fn we_cannot_verify_a_rescaled_decimal_with_a_forged_remainder() {
+    let t = "sxt.t".parse().unwrap();
+    let plan = |mode, forged_remainder_offset| {
+        move |accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            let mut expr = rescale(column(t, "d", accessor), 3, 0, mode);
+            if let DynProofExpr::Rescale(rescale) = &mut expr {
+                rescale.forged_remainder_offset = forged_remainder_offset;
+            }
+            projection(vec![aliased_plan(expr, "r")], tab(t))
+        }
+    };
+    let data = || owned_table([decimal75("d", 3, 1, [15_i64, -25, 7, 0])]);
+    for mode in [
+        RoundingMode::Truncate,
+        RoundingMode::HalfUp,
+        RoundingMode::HalfEven,
+    ] {
+        assert!(
+            prove_and_verify(data(), plan(mode, 0), plan(mode, 0)).is_ok(),
+            "{mode:?}"
+        );
+        assert!(
+            prove_and_verify(data(), plan(mode, 1), plan(mode, 0)).is_err(),
+            "{mode:?}"
+        );
+    }
+}
+
+#[test]
+fn we_cannot_rescale_to_a_precision_that_is_too_small_or_a_non_numeric_expression() {
+    let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let t = "sxt.t".parse().unwrap();
+    let accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_from_table(
+        t,
+        owned_table([
+            decimal75("d", 3, 1, [15_i64]),
+            decimal75("w", 40, 1, [15_i64]),
+            varchar("v", ["a"]),
+        ]),
+        0,
+        DoryProverPublicSetup::new(&prover_setup, 1),
+    );
+    let try_rescale = |name, precision, scale, mode| {
+        DynProofExpr::try_new_decimal_rescale(
+            column(t, name, &accessor),
+            Precision::new(precision).unwrap(),
+            scale,
+            mode,
+        )
+    };
+    // 99.5 rounds to 100, so rounding away from zero needs an extra digit
+    assert!(try_rescale("d", 2, 0, RoundingMode::Truncate).is_ok());
+    assert!(matches!(
+        try_rescale("d", 2, 0, RoundingMode::HalfUp),
+        Err(ConversionError::DecimalConversionError {
+            source: DecimalError::InvalidPrecision { .. }
+        })
+    ));
+    assert!(try_rescale("d", 3, 0, RoundingMode::HalfEven).is_ok());
+    assert!(matches!(
+        try_rescale("d", 4, 3, RoundingMode::HalfUp),
+        Err(ConversionError::DecimalConversionError {
+            source: DecimalError::InvalidPrecision { .. }
+        })
+    ));
+    assert!(matches!(
+        try_rescale("w", 40, 0, RoundingMode::HalfUp),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    assert!(matches!(
+        try_rescale("d", 1, -40, RoundingMode::Truncate),
+        Err(ConversionError::Unprovable { .. })
+    ));
+    assert!(matches!(
+        try_rescale("v", 3, 0, RoundingMode::HalfUp),
+        Err(ConversionError::ColumnOperationError {
+            source: ColumnOperationError::UnaryOperationInvalidColumnType { .. }
+        })
+    ));
+}
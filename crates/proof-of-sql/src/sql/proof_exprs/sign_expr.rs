@@ -130,6 +130,41 @@ pub fn verifier_evaluate_sign<S: Scalar>(
 ) -> Result<S, ProofError> {
     // bit_distribution
     let dist = builder.consume_bit_distribution();
+    verify_sign_decomposition(builder, &dist, eval, one_eval)
+}
+
+/// Verify the sign decomposition for a column of scalars whose absolute values are less than `2^num_bits`.
+///
+/// Unlike [`verifier_evaluate_sign`], this rejects a decomposition with any constant or varying bit
+/// at position `num_bits` or above, so the column is range checked to `(-2^num_bits, 2^num_bits)`.
+///
+/// # Panics
+/// Panics if `bit_evals.last()` is `None`.
+pub fn verifier_evaluate_bounded_sign<S: Scalar>(
+    builder: &mut VerificationBuilder<S>,
+    eval: S,
+    one_eval: S,
+    num_bits: usize,
+) -> Result<S, ProofError> {
+    let dist = builder.consume_bit_distribution();
+    if !dist.abs_values_fit_in_bits(num_bits) {
+        return Err(ProofError::VerificationError {
+            error: "bit distribution is outside of the range of the column",
+        });
+    }
+    verify_sign_decomposition(builder, &dist, eval, one_eval)
+}
+
+/// Verify the sign decomposition of a column with the bit distribution `dist`.
+///
+/// # Panics
+/// Panics if `bit_evals.last()` is `None`.
+fn verify_sign_decomposition<S: Scalar>(
+    builder: &mut VerificationBuilder<S>,
+    dist: &BitDistribution,
+    eval: S,
+    one_eval: S,
+) -> Result<S, ProofError> {
     let num_varying_bits = dist.num_varying_bits();
 
     // extract evaluations and commitmens of the multilinear extensions for the varying
@@ -145,14 +180,14 @@ pub fn verifier_evaluate_sign<S: Scalar>(
 
     // handle the special case of the sign bit being constant
     if !dist.has_varying_sign_bit() {
-        return verifier_const_sign_evaluate(&dist, eval, one_eval, &bit_evals);
+        return verifier_const_sign_evaluate(dist, eval, one_eval, &bit_evals);
     }
 
     // handle the special case of the absolute part being constant
     if dist.num_varying_bits() == 1 {
-        verify_constant_abs_decomposition(&dist, eval, one_eval, bit_evals[0])?;
+        verify_constant_abs_decomposition(dist, eval, one_eval, bit_evals[0])?;
     } else {
-        verify_bit_decomposition(builder, eval, one_eval, &bit_evals, &dist);
+        verify_bit_decomposition(builder, eval, one_eval, &bit_evals, dist);
     }
 
     Ok(*bit_evals.last().unwrap())
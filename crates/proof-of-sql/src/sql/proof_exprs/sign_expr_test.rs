@@ -1,4 +1,7 @@
-use super::{count_sign, prover_evaluate_sign, result_evaluate_sign, verifier_evaluate_sign};
+use super::{
+    count_sign, prover_evaluate_sign, result_evaluate_sign, verifier_evaluate_bounded_sign,
+    verifier_evaluate_sign,
+};
 use crate::{
     base::{bit::BitDistribution, polynomial::MultilinearExtension, scalar::Curve25519Scalar},
     sql::proof::{
@@ -111,6 +114,45 @@ fn verification_of_constant_data_fails_if_the_commitment_doesnt_match_the_bit_di
     assert!(verifier_evaluate_sign(&mut builder, data_eval, *one_eval).is_err());
 }
 
+#[test]
+fn bounded_verification_of_a_constant_decomposition_fails_if_the_data_has_too_many_bits() {
+    let data = [-(1_i128 << 70), -(1_i128 << 70), -(1_i128 << 70)];
+
+    let dists = [BitDistribution::new::<Curve25519Scalar, _>(&data)];
+    let scalars = [Curve25519Scalar::from(97), Curve25519Scalar::from(3432)];
+    let evaluation_point = [Curve25519Scalar::from(324), Curve25519Scalar::from(97)];
+    let data_eval = (&data).evaluate_at_point(&evaluation_point);
+    let verify = |num_bits| {
+        let sumcheck_random_scalars = SumcheckRandomScalars::new(&scalars, data.len(), 2);
+        let sumcheck_evaluations = SumcheckMleEvaluations::new(
+            data.len(),
+            [data.len()],
+            &evaluation_point,
+            &sumcheck_random_scalars,
+            &[],
+        );
+        let one_eval = *sumcheck_evaluations
+            .one_evaluations
+            .values()
+            .next()
+            .unwrap();
+        let mut builder = VerificationBuilder::new(
+            0,
+            sumcheck_evaluations,
+            &dists,
+            &[],
+            &[],
+            Vec::new(),
+            Vec::new(),
+        );
+        verifier_evaluate_bounded_sign(&mut builder, data_eval, one_eval, num_bits)
+    };
+
+    assert!(verify(71).is_ok());
+    assert!(verify(70).is_err());
+    assert!(verify(64).is_err());
+}
+
 #[test]
 fn we_can_compute_the_correct_sign_of_scalars_using_result_evaluate_sign_for_a_constant() {
     let data: &[Curve25519Scalar] = &[(-123).into(), (-123).into()];
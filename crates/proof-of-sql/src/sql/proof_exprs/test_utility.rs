@@ -1,10 +1,13 @@
 use super::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr};
 use crate::base::{
     database::{ColumnRef, LiteralValue, SchemaAccessor, TableRef},
-    math::{decimal::Precision, i256::I256},
+    math::{
+        decimal::{Precision, RoundingMode},
+        i256::I256,
+    },
     scalar::Scalar,
 };
-use proof_of_sql_parser::intermediate_ast::AggregationOperator;
+use proof_of_sql_parser::{intermediate_ast::AggregationOperator, posql_time::DateTruncUnit};
 
 /// # Panics
 /// Panics if:
@@ -81,6 +84,20 @@ pub fn subtract(left: DynProofExpr, right: DynProofExpr) -> DynProofExpr {
     DynProofExpr::try_new_subtract(left, right).unwrap()
 }
 
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_neg()` returns an error.
+pub fn neg(expr: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_neg(expr).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_abs()` returns an error.
+pub fn abs(expr: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_abs(expr).unwrap()
+}
+
 /// # Panics
 /// Panics if:
 /// - `DynProofExpr::try_new_multiply()` returns an error.
@@ -88,6 +105,40 @@ pub fn multiply(left: DynProofExpr, right: DynProofExpr) -> DynProofExpr {
     DynProofExpr::try_new_multiply(left, right).unwrap()
 }
 
+pub fn modulo(left: DynProofExpr, right: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_modulo(left, right).unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `Precision::new(precision)` fails, meaning the provided precision is invalid.
+/// - `DynProofExpr::try_new_decimal_rescale()` returns an error.
+pub fn rescale(expr: DynProofExpr, precision: u8, scale: i8, mode: RoundingMode) -> DynProofExpr {
+    DynProofExpr::try_new_decimal_rescale(expr, Precision::new(precision).unwrap(), scale, mode)
+        .unwrap()
+}
+
+/// # Panics
+/// Panics if:
+/// - `DynProofExpr::try_new_date_trunc()` returns an error.
+pub fn date_trunc(expr: DynProofExpr, unit: DateTruncUnit) -> DynProofExpr {
+    DynProofExpr::try_new_date_trunc(expr, unit).unwrap()
+}
+
+/// # Panics
+/// Panics if `expr` is not an integer expression that fits in a `BIGINT` or `precision` is out of range.
+pub fn hyperloglog_update_expr(expr: DynProofExpr, precision: u8) -> DynProofExpr {
+    DynProofExpr::try_new_hyperloglog_update(expr, precision).unwrap()
+}
+
+pub fn case_when(
+    condition: DynProofExpr,
+    then_expr: DynProofExpr,
+    else_expr: DynProofExpr,
+) -> DynProofExpr {
+    DynProofExpr::try_new_case(condition, then_expr, else_expr).unwrap()
+}
+
 pub fn const_bool(val: bool) -> DynProofExpr {
     DynProofExpr::new_literal(LiteralValue::Boolean(val))
 }
@@ -104,6 +155,10 @@ pub fn const_bigint(val: i64) -> DynProofExpr {
     DynProofExpr::new_literal(LiteralValue::BigInt(val))
 }
 
+pub fn const_uint64(val: u64) -> DynProofExpr {
+    DynProofExpr::new_literal(LiteralValue::UInt64(val))
+}
+
 pub fn const_int128(val: i128) -> DynProofExpr {
     DynProofExpr::new_literal(LiteralValue::Int128(val))
 }
@@ -1,14 +1,18 @@
-use super::{EmptyExec, FilterExec, GroupByExec, ProjectionExec, TableExec};
+use super::{
+    EmptyExec, FilterExec, GroupByExec, ProjectionExec, RowNumberExec, ShiftExec, TableExec,
+};
 use crate::{
     base::{
-        database::{ColumnField, ColumnRef, OwnedTable, Table, TableEvaluation, TableRef},
+        database::{
+            ColumnField, ColumnRef, OwnedTable, Table, TableEvaluation, TableRef, TableSchema,
+        },
         map::{IndexMap, IndexSet},
         proof::ProofError,
         scalar::Scalar,
     },
     sql::proof::{
         CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
-        VerificationBuilder,
+        ValidationError, VerificationBuilder,
     },
 };
 use alloc::vec::Vec;
@@ -43,4 +47,17 @@ pub enum DynProofPlan {
     ///     SELECT <result_expr1>, ..., <result_exprN> FROM <table> WHERE <where_clause>
     /// ```
     Filter(FilterExec),
+    /// Provable expressions for queries of the form, where the selected rows are numbered in the order of a result column
+    /// ```ignore
+    ///     SELECT <result_expr1>, ..., <result_exprN>, ROW_NUMBER() OVER (ORDER BY <result_exprK>) AS <alias>
+    ///     FROM <table> WHERE <where_clause>
+    /// ```
+    RowNumber(RowNumberExec),
+    /// Provable expressions for queries of the form, where each selected row takes a value of the previous or next row
+    /// in the order of a result column
+    /// ```ignore
+    ///     SELECT <result_expr1>, ..., <result_exprN>, LAG(<result_exprJ>) OVER (ORDER BY <result_exprK>) AS <alias>
+    ///     FROM <table> WHERE <where_clause>
+    /// ```
+    Shift(ShiftExec),
 }
@@ -251,7 +251,7 @@ impl ProverEvaluate for FilterExec {
 }
 
 #[allow(clippy::unnecessary_wraps, clippy::too_many_arguments)]
-fn verify_filter<S: Scalar>(
+pub(super) fn verify_filter<S: Scalar>(
     builder: &mut VerificationBuilder<S>,
     alpha: S,
     beta: S,
@@ -312,6 +312,7 @@ pub(super) fn prove_filter<'a, S: Scalar + 'a>(
     d_star[m..].fill(Zero::zero());
     slice_ops::batch_inversion(c_star);
     slice_ops::batch_inversion(&mut d_star[..m]);
+    builder.metrics_mut().record_field_inversions(n + m);
 
     builder.produce_intermediate_mle(c_star as &[_]);
     builder.produce_intermediate_mle(d_star as &[_]);
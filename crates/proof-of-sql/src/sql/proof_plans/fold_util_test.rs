@@ -1,7 +1,9 @@
 use super::{fold_columns, fold_vals};
-use crate::base::{database::Column, math::decimal::Precision, scalar::Curve25519Scalar};
+use crate::base::{
+    database::Column, math::decimal::Precision, scalar::Curve25519Scalar, slice_ops,
+};
 use bumpalo::Bump;
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 #[test]
 fn we_can_fold_columns_with_scalars() {
@@ -119,3 +121,69 @@ fn we_can_fold_vals() {
         (54321).into()
     );
 }
+
+/// `sum 1 / (alpha + sum beta^j * columns[j][i])`, the multiset sum that the group by proof balances
+fn multiset_sum(
+    alpha: Curve25519Scalar,
+    beta: Curve25519Scalar,
+    columns: &[Column<Curve25519Scalar>],
+) -> Curve25519Scalar {
+    let alloc = Bump::new();
+    let folded = alloc.alloc_slice_fill_copy(columns[0].len(), alpha);
+    fold_columns(folded, One::one(), beta, columns);
+    slice_ops::batch_inversion(folded);
+    folded.iter().copied().sum()
+}
+
+#[test]
+fn the_multiset_sum_does_not_depend_on_the_order_of_the_rows() {
+    let (alpha, beta) = (Curve25519Scalar::from(101), Curve25519Scalar::from(7));
+    let str_scalars: [Curve25519Scalar; 4] = ["a".into(), "b".into(), "c".into(), "a".into()];
+    let sum = multiset_sum(
+        alpha,
+        beta,
+        &[
+            Column::BigInt(&[1, 2, 3, 1]),
+            Column::VarChar((&["a", "b", "c", "a"], &str_scalars)),
+        ],
+    );
+    let permuted_str_scalars: [Curve25519Scalar; 4] =
+        ["c".into(), "a".into(), "a".into(), "b".into()];
+    let permuted_sum = multiset_sum(
+        alpha,
+        beta,
+        &[
+            Column::BigInt(&[3, 1, 1, 2]),
+            Column::VarChar((&["c", "a", "a", "b"], &permuted_str_scalars)),
+        ],
+    );
+    assert_eq!(sum, permuted_sum);
+}
+
+#[test]
+fn the_multiset_sum_distinguishes_multisets_that_differ_in_one_element() {
+    let (alpha, beta) = (Curve25519Scalar::from(101), Curve25519Scalar::from(7));
+    let sum = multiset_sum(alpha, beta, &[Column::BigInt(&[1, 2, 3, 1])]);
+    assert_ne!(
+        sum,
+        multiset_sum(alpha, beta, &[Column::BigInt(&[1, 2, 3, 4])])
+    );
+    // The multiplicity of each element matters, not just the set of elements.
+    assert_ne!(
+        sum,
+        multiset_sum(alpha, beta, &[Column::BigInt(&[1, 2, 3, 2])])
+    );
+    // Rows are compared as a whole, so swapping values between columns is a different multiset.
+    assert_ne!(
+        multiset_sum(
+            alpha,
+            beta,
+            &[Column::BigInt(&[1, 2]), Column::BigInt(&[3, 4])]
+        ),
+        multiset_sum(
+            alpha,
+            beta,
+            &[Column::BigInt(&[1, 4]), Column::BigInt(&[3, 2])]
+        )
+    );
+}
@@ -1,11 +1,16 @@
-use super::{fold_columns, fold_vals};
+use super::{
+    fold_columns, fold_vals,
+    group_by_rollup::{
+        aggregate_rollup, prove_rollup, verify_rollup, verify_rollup_result, RollupColumns,
+    },
+};
 use crate::{
     base::{
         database::{
             group_by_util::{aggregate_columns, AggregatedColumns},
             order_by_util::compare_indexes_by_owned_columns,
-            Column, ColumnField, ColumnRef, ColumnType, OwnedTable, Table, TableEvaluation,
-            TableRef,
+            Column, ColumnField, ColumnRef, ColumnType, OwnedColumn, OwnedTable, Table,
+            TableEvaluation, TableRef,
         },
         map::{IndexMap, IndexSet},
         proof::ProofError,
@@ -23,6 +28,7 @@ use crate::{
 use alloc::{boxed::Box, vec, vec::Vec};
 use bumpalo::Bump;
 use core::{iter, iter::repeat_with};
+use num_bigint::{BigInt, BigUint};
 use num_traits::One;
 use proof_of_sql_parser::Identifier;
 use serde::{Deserialize, Serialize};
@@ -38,13 +44,29 @@ use serde::{Deserialize, Serialize};
 /// ```
 ///
 /// Note: if `group_by_exprs` is empty, then the query is equivalent to removing the `GROUP BY` clause.
+///
+/// With several `group_by_exprs` the key of a row is the tuple of their values, and each key column is in the result.
+/// Keys are usually columns, but can be any expressions, e.g. `DATE_TRUNC('hour', ts)` to bucket rows by time.
+/// See [`GroupByExec::new_with_key_exprs`].
+/// The proof compares keys through the composite `alpha + sum beta^j * group_by_expr[j]`,
+/// where `alpha` and `beta` are challenges drawn after the result is committed to,
+/// so rows that differ in any key column have different composite keys with overwhelming probability.
+/// The verifier also checks that the keys of the result are strictly increasing, so no group appears twice.
+///
+/// Sums are always computed exactly in the scalar field. See [`GroupByExec::with_sum_overflow_flags`]
+/// for reporting whether they fit the type of the summed expression.
+///
+/// See [`GroupByExec::with_rollup`] for `GROUP BY ROLLUP(<group_by_expr1>, ..., <group_by_exprM>)`,
+/// which adds the subtotals of every prefix of the keys and the grand total.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct GroupByExec {
-    pub(super) group_by_exprs: Vec<ColumnExpr>,
+    pub(super) group_by_exprs: Vec<AliasedDynProofExpr>,
     pub(super) sum_expr: Vec<AliasedDynProofExpr>,
     pub(super) count_alias: Identifier,
     pub(super) table: TableExpr,
     pub(super) where_clause: DynProofExpr,
+    pub(super) sum_overflow_aliases: Vec<Identifier>,
+    pub(super) rollup_aliases: Vec<Identifier>,
 }
 
 impl GroupByExec {
@@ -55,6 +77,31 @@ impl GroupByExec {
         count_alias: Identifier,
         table: TableExpr,
         where_clause: DynProofExpr,
+    ) -> Self {
+        Self::new_with_key_exprs(
+            group_by_exprs
+                .into_iter()
+                .map(|column| AliasedDynProofExpr {
+                    alias: column.column_id(),
+                    expr: DynProofExpr::Column(column),
+                })
+                .collect(),
+            sum_expr,
+            count_alias,
+            table,
+            where_clause,
+        )
+    }
+
+    /// Creates a new `group_by` expression whose keys are expressions rather than columns.
+    ///
+    /// Each key is in the result under its alias.
+    pub fn new_with_key_exprs(
+        group_by_exprs: Vec<AliasedDynProofExpr>,
+        sum_expr: Vec<AliasedDynProofExpr>,
+        count_alias: Identifier,
+        table: TableExpr,
+        where_clause: DynProofExpr,
     ) -> Self {
         Self {
             group_by_exprs,
@@ -62,15 +109,121 @@ impl GroupByExec {
             count_alias,
             table,
             where_clause,
+            sum_overflow_aliases: Vec::new(),
+            rollup_aliases: Vec::new(),
+        }
+    }
+
+    /// Reports whether every sum fits the type of its summed expression.
+    ///
+    /// The sum columns of the result become [`ColumnType::Scalar`] columns holding the exact sums,
+    /// and a boolean column named `aliases[i]` is appended after the count column for the `i`-th sum.
+    /// A row of that column is `true` exactly when the sum does not fit the type of the `i`-th summed expression,
+    /// e.g. a `BIGINT` sum outside of the range of `i64`.
+    ///
+    /// The exact sums are proven and revealed in the result, so the verifier range checks them directly
+    /// and rejects a result whose flags are not set accordingly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one alias per sum.
+    #[must_use]
+    pub fn with_sum_overflow_flags(mut self, aliases: Vec<Identifier>) -> Self {
+        assert_eq!(
+            aliases.len(),
+            self.sum_expr.len(),
+            "there must be one overflow flag per sum"
+        );
+        self.sum_overflow_aliases = aliases;
+        self
+    }
+
+    /// Groups by every prefix of the keys, as `GROUP BY ROLLUP(<group_by_expr1>, ..., <group_by_exprM>)`.
+    ///
+    /// The result contains the groups by all of the keys, followed by the subtotals grouped by the first `M - 1` keys,
+    /// and so on down to the grand total over all selected rows, with proven sums and counts at every level.
+    /// Within each level the groups are ordered by their keys.
+    ///
+    /// Columns cannot hold `NULL`, so a boolean column named `aliases[j]` is appended to the result for the `j`-th key,
+    /// after any overflow flags. A row of that column is `true` exactly when the `j`-th key is aggregated away,
+    /// i.e. where SQL would return `NULL` for the key, as reported by `GROUPING(<group_by_exprj>)`.
+    /// The key itself then holds the default value of its type, e.g. `0` or `''`.
+    /// Every group has at least one row, so unlike SQL the result is empty when no rows are selected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one alias per key, or if there are no keys.
+    #[must_use]
+    pub fn with_rollup(mut self, aliases: Vec<Identifier>) -> Self {
+        assert!(
+            !self.group_by_exprs.is_empty(),
+            "a rollup must have at least one key"
+        );
+        assert_eq!(
+            aliases.len(),
+            self.group_by_exprs.len(),
+            "there must be one grouping flag per key"
+        );
+        self.rollup_aliases = aliases;
+        self
+    }
+
+    /// Whether the result contains the subtotals of every prefix of the keys
+    fn is_rollup(&self) -> bool {
+        !self.rollup_aliases.is_empty()
+    }
+
+    /// Whether the result reports the overflow of the sums
+    fn checks_sum_overflow(&self) -> bool {
+        !self.sum_overflow_aliases.is_empty()
+    }
+
+    /// The overflow flags of the sums, which are empty unless the result reports the overflow of the sums
+    fn sum_overflow_flags<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        sum_result_columns: &[&'a [S]],
+    ) -> Vec<Column<'a, S>> {
+        if !self.checks_sum_overflow() {
+            return Vec::new();
+        }
+        self.sum_expr
+            .iter()
+            .zip(sum_result_columns)
+            .map(|(aliased_expr, sums)| {
+                let data_type = aliased_expr.expr.data_type();
+                Column::Boolean(
+                    alloc.alloc_slice_fill_iter(
+                        sums.iter().map(|sum| !sum_fits_type(*sum, data_type)),
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Whether an exact sum fits the type of the summed expression
+fn sum_fits_type<S: Scalar>(sum: S, data_type: ColumnType) -> bool {
+    match data_type {
+        ColumnType::TinyInt => TryInto::<i8>::try_into(sum).is_ok(),
+        ColumnType::SmallInt => TryInto::<i16>::try_into(sum).is_ok(),
+        ColumnType::Int => TryInto::<i32>::try_into(sum).is_ok(),
+        ColumnType::BigInt => TryInto::<i64>::try_into(sum).is_ok(),
+        ColumnType::Int128 => TryInto::<i128>::try_into(sum).is_ok(),
+        ColumnType::UInt64 => TryInto::<u64>::try_into(sum).is_ok(),
+        ColumnType::Decimal75(precision, _) => {
+            Into::<BigInt>::into(sum).magnitude()
+                < &BigUint::from(10u8).pow(u32::from(precision.value()))
         }
+        _ => true,
     }
 }
 
 impl ProofPlan for GroupByExec {
     fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
         self.where_clause.count(builder)?;
-        for expr in &self.group_by_exprs {
-            expr.count(builder)?;
+        for aliased_expr in &self.group_by_exprs {
+            aliased_expr.expr.count(builder)?;
             builder.count_intermediate_mles(1);
         }
         for aliased_expr in &self.sum_expr {
@@ -79,14 +232,23 @@ impl ProofPlan for GroupByExec {
         }
         // For the count col
         builder.count_intermediate_mles(1);
-        builder.count_intermediate_mles(2);
-        builder.count_subpolynomials(3);
-        builder.count_degree(3);
+        builder.count_intermediate_mles(self.sum_overflow_aliases.len());
+        if self.is_rollup() {
+            let num_keys = self.group_by_exprs.len();
+            builder.count_intermediate_mles(num_keys);
+            builder.count_intermediate_mles(num_keys + 2);
+            builder.count_subpolynomials(2 * num_keys + 3);
+            builder.count_degree(4);
+        } else {
+            builder.count_intermediate_mles(2);
+            builder.count_subpolynomials(3);
+            builder.count_degree(3);
+        }
         builder.count_post_result_challenges(2);
         Ok(())
     }
 
-    #[allow(unused_variables)]
+    #[allow(unused_variables, clippy::too_many_lines)]
     fn verifier_evaluate<S: Scalar>(
         &self,
         builder: &mut VerificationBuilder<S>,
@@ -105,7 +267,11 @@ impl ProofPlan for GroupByExec {
         let group_by_evals = self
             .group_by_exprs
             .iter()
-            .map(|expr| expr.verifier_evaluate(builder, accessor, input_one_eval))
+            .map(|aliased_expr| {
+                aliased_expr
+                    .expr
+                    .verifier_evaluate(builder, accessor, input_one_eval)
+            })
             .collect::<Result<Vec<_>, _>>()?;
         let aggregate_evals = self
             .sum_expr
@@ -125,39 +291,74 @@ impl ProofPlan for GroupByExec {
             .take(self.sum_expr.len())
             .collect();
         let count_column_eval = builder.consume_intermediate_mle();
+        let sum_overflow_columns_evals: Vec<_> = repeat_with(|| builder.consume_intermediate_mle())
+            .take(self.sum_overflow_aliases.len())
+            .collect();
+        let rollup_columns_evals: Vec<_> = repeat_with(|| builder.consume_intermediate_mle())
+            .take(self.rollup_aliases.len())
+            .collect();
+        let output_one_eval = builder.consume_one_evaluation();
 
         let alpha = builder.consume_post_result_challenge();
         let beta = builder.consume_post_result_challenge();
 
-        verify_group_by(
-            builder,
-            alpha,
-            beta,
-            input_one_eval,
-            (group_by_evals, aggregate_evals, where_eval),
-            (
-                group_by_result_columns_evals.clone(),
-                sum_result_columns_evals.clone(),
-                count_column_eval,
-            ),
-        )?;
+        if self.is_rollup() {
+            verify_rollup(
+                builder,
+                alpha,
+                beta,
+                (input_one_eval, output_one_eval),
+                (group_by_evals, aggregate_evals, where_eval),
+                (
+                    group_by_result_columns_evals.clone(),
+                    sum_result_columns_evals.clone(),
+                    count_column_eval,
+                    rollup_columns_evals.clone(),
+                ),
+            )?;
+        } else {
+            verify_group_by(
+                builder,
+                alpha,
+                beta,
+                input_one_eval,
+                (group_by_evals, aggregate_evals, where_eval),
+                (
+                    group_by_result_columns_evals.clone(),
+                    sum_result_columns_evals.clone(),
+                    count_column_eval,
+                ),
+            )?;
+        }
         match result {
             Some(table) => {
                 let cols = self
                     .group_by_exprs
                     .iter()
-                    .map(|col| table.inner_table().get(&col.column_id()))
+                    .map(|aliased_expr| table.inner_table().get(&aliased_expr.alias))
                     .collect::<Option<Vec<_>>>()
                     .ok_or(ProofError::VerificationError {
                         error: "Result does not all correct group by columns.",
                     })?;
-                if (0..table.num_rows() - 1)
-                    .any(|i| compare_indexes_by_owned_columns(&cols, i, i + 1).is_ge())
+                if self.is_rollup() {
+                    let grouping_cols = self
+                        .rollup_aliases
+                        .iter()
+                        .map(|alias| table.inner_table().get(alias))
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or(ProofError::VerificationError {
+                            error: "Result does not contain the grouping columns of the rollup.",
+                        })?;
+                    verify_rollup_result(&cols, &grouping_cols, table.num_rows())?;
+                } else if (1..table.num_rows())
+                    .any(|i| compare_indexes_by_owned_columns(&cols, i - 1, i).is_ge())
                 {
                     Err(ProofError::VerificationError {
                         error: "Result of group by not ordered as expected.",
                     })?;
                 }
+                self.verify_group_counts(table)?;
+                self.verify_sum_overflow_flags(table)?;
             }
             None => todo!("GroupByExec currently only supported at top level of query plan."),
         }
@@ -166,8 +367,9 @@ impl ProofPlan for GroupByExec {
             .into_iter()
             .chain(sum_result_columns_evals)
             .chain(iter::once(count_column_eval))
+            .chain(sum_overflow_columns_evals)
+            .chain(rollup_columns_evals)
             .collect::<Vec<_>>();
-        let output_one_eval = builder.consume_one_evaluation();
         Ok(TableEvaluation::new(column_evals, output_one_eval))
     }
 
@@ -175,22 +377,37 @@ impl ProofPlan for GroupByExec {
     fn get_column_result_fields(&self) -> Vec<ColumnField> {
         self.group_by_exprs
             .iter()
-            .map(|col| col.get_column_field())
+            .map(|aliased_expr| ColumnField::new(aliased_expr.alias, aliased_expr.expr.data_type()))
             .chain(self.sum_expr.iter().map(|aliased_expr| {
-                ColumnField::new(aliased_expr.alias, aliased_expr.expr.data_type())
+                let data_type = if self.checks_sum_overflow() {
+                    ColumnType::Scalar
+                } else {
+                    aliased_expr.expr.data_type()
+                };
+                ColumnField::new(aliased_expr.alias, data_type)
             }))
             .chain(iter::once(ColumnField::new(
                 self.count_alias,
                 ColumnType::BigInt,
             )))
+            .chain(
+                self.sum_overflow_aliases
+                    .iter()
+                    .map(|alias| ColumnField::new(*alias, ColumnType::Boolean)),
+            )
+            .chain(
+                self.rollup_aliases
+                    .iter()
+                    .map(|alias| ColumnField::new(*alias, ColumnType::Boolean)),
+            )
             .collect()
     }
 
     fn get_column_references(&self) -> IndexSet<ColumnRef> {
         let mut columns = IndexSet::default();
 
-        for col in &self.group_by_exprs {
-            columns.insert(col.get_column_reference());
+        for aliased_expr in &self.group_by_exprs {
+            aliased_expr.expr.get_column_references(&mut columns);
         }
         for aliased_expr in &self.sum_expr {
             aliased_expr.expr.get_column_references(&mut columns);
@@ -206,6 +423,86 @@ impl ProofPlan for GroupByExec {
     }
 }
 
+impl GroupByExec {
+    /// Group the selected rows by the keys, or by every prefix of the keys for a rollup.
+    ///
+    /// Without a rollup there are no grouping columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns do not all have the length of the selection.
+    fn aggregate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        group_by_columns: &[Column<'a, S>],
+        sum_columns: &[Column<'a, S>],
+        selection: &[bool],
+    ) -> RollupColumns<'a, S> {
+        if self.is_rollup() {
+            return aggregate_rollup(alloc, group_by_columns, sum_columns, selection);
+        }
+        let AggregatedColumns {
+            group_by_columns,
+            sum_columns,
+            count_column,
+            ..
+        } = aggregate_columns(alloc, group_by_columns, sum_columns, &[], &[], selection)
+            .expect("columns should be aggregatable");
+        RollupColumns {
+            group_by_columns,
+            sum_columns,
+            count_column,
+            grouping_columns: Vec::new(),
+        }
+    }
+
+    /// Check that every group in the result has at least one row.
+    ///
+    /// A group with a count and sums of zero adds nothing to the group by argument, so without this check
+    /// the result could contain groups that do not appear in the selected rows.
+    fn verify_group_counts<S: Scalar>(&self, table: &OwnedTable<S>) -> Result<(), ProofError> {
+        let Some(OwnedColumn::BigInt(counts)) = table.inner_table().get(&self.count_alias) else {
+            Err(ProofError::VerificationError {
+                error: "Result does not contain the counts of the groups.",
+            })?
+        };
+        if counts.iter().any(|count| *count <= 0) {
+            Err(ProofError::VerificationError {
+                error: "Group by result contains a group without rows.",
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Check that the overflow flags of the result match the exact sums of the result
+    fn verify_sum_overflow_flags<S: Scalar>(
+        &self,
+        table: &OwnedTable<S>,
+    ) -> Result<(), ProofError> {
+        for (aliased_expr, alias) in self.sum_expr.iter().zip(&self.sum_overflow_aliases) {
+            let (Some(OwnedColumn::Scalar(sums)), Some(OwnedColumn::Boolean(flags))) = (
+                table.inner_table().get(&aliased_expr.alias),
+                table.inner_table().get(alias),
+            ) else {
+                Err(ProofError::VerificationError {
+                    error: "Result does not contain the sums and their overflow flags.",
+                })?
+            };
+            let data_type = aliased_expr.expr.data_type();
+            if sums
+                .iter()
+                .zip(flags)
+                .any(|(sum, flag)| *flag == sum_fits_type(*sum, data_type))
+            {
+                Err(ProofError::VerificationError {
+                    error: "Overflow flag of group by does not match the sum.",
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl ProverEvaluate for GroupByExec {
     #[tracing::instrument(name = "GroupByExec::result_evaluate", level = "debug", skip_all)]
     fn result_evaluate<'a, S: Scalar>(
@@ -227,7 +524,7 @@ impl ProverEvaluate for GroupByExec {
         let group_by_columns = self
             .group_by_exprs
             .iter()
-            .map(|expr| expr.result_evaluate(alloc, table))
+            .map(|aliased_expr| aliased_expr.expr.result_evaluate(alloc, table))
             .collect::<Vec<_>>();
         let sum_columns = self
             .sum_expr
@@ -235,13 +532,13 @@ impl ProverEvaluate for GroupByExec {
             .map(|aliased_expr| aliased_expr.expr.result_evaluate(alloc, table))
             .collect::<Vec<_>>();
         // Compute filtered_columns
-        let AggregatedColumns {
+        let RollupColumns {
             group_by_columns: group_by_result_columns,
             sum_columns: sum_result_columns,
             count_column,
-            ..
-        } = aggregate_columns(alloc, &group_by_columns, &sum_columns, &[], &[], selection)
-            .expect("columns should be aggregatable");
+            grouping_columns,
+        } = self.aggregate(alloc, &group_by_columns, &sum_columns, selection);
+        let sum_overflow_columns = self.sum_overflow_flags(alloc, &sum_result_columns);
         let sum_result_columns_iter = sum_result_columns.iter().map(|col| Column::Scalar(col));
         let res = Table::<'a, S>::try_from_iter(
             self.get_column_result_fields()
@@ -251,7 +548,9 @@ impl ProverEvaluate for GroupByExec {
                     group_by_result_columns
                         .into_iter()
                         .chain(sum_result_columns_iter)
-                        .chain(iter::once(Column::BigInt(count_column))),
+                        .chain(iter::once(Column::BigInt(count_column)))
+                        .chain(sum_overflow_columns)
+                        .chain(grouping_columns.into_iter().map(Column::Boolean)),
                 ),
         )
         .expect("Failed to create table from column references");
@@ -284,7 +583,7 @@ impl ProverEvaluate for GroupByExec {
         let group_by_columns = self
             .group_by_exprs
             .iter()
-            .map(|expr| expr.prover_evaluate(builder, alloc, table))
+            .map(|aliased_expr| aliased_expr.expr.prover_evaluate(builder, alloc, table))
             .collect::<Vec<_>>();
         let sum_columns = self
             .sum_expr
@@ -292,24 +591,28 @@ impl ProverEvaluate for GroupByExec {
             .map(|aliased_expr| aliased_expr.expr.prover_evaluate(builder, alloc, table))
             .collect::<Vec<_>>();
         // 3. Compute filtered_columns
-        let AggregatedColumns {
+        let rollup = self.aggregate(alloc, &group_by_columns, &sum_columns, selection);
+        let RollupColumns {
             group_by_columns: group_by_result_columns,
             sum_columns: sum_result_columns,
             count_column,
-            ..
-        } = aggregate_columns(alloc, &group_by_columns, &sum_columns, &[], &[], selection)
-            .expect("columns should be aggregatable");
+            grouping_columns,
+        } = &rollup;
+        let count_column = *count_column;
 
         let alpha = builder.consume_post_result_challenge();
         let beta = builder.consume_post_result_challenge();
 
         // 4. Tally results
+        let sum_overflow_columns = self.sum_overflow_flags(alloc, sum_result_columns);
         let sum_result_columns_iter = sum_result_columns.iter().map(|col| Column::Scalar(col));
         let columns = group_by_result_columns
             .clone()
             .into_iter()
             .chain(sum_result_columns_iter)
-            .chain(iter::once(Column::BigInt(count_column)));
+            .chain(iter::once(Column::BigInt(count_column)))
+            .chain(sum_overflow_columns)
+            .chain(grouping_columns.iter().map(|flags| Column::Boolean(flags)));
         let res = Table::<'a, S>::try_from_iter(
             self.get_column_result_fields()
                 .into_iter()
@@ -322,15 +625,27 @@ impl ProverEvaluate for GroupByExec {
             builder.produce_intermediate_mle(column);
         }
         // 6. Prove group by
-        prove_group_by(
-            builder,
-            alloc,
-            alpha,
-            beta,
-            (&group_by_columns, &sum_columns, selection),
-            (&group_by_result_columns, &sum_result_columns, count_column),
-            table.num_rows(),
-        );
+        if self.is_rollup() {
+            prove_rollup(
+                builder,
+                alloc,
+                alpha,
+                beta,
+                (&group_by_columns, &sum_columns, selection),
+                &rollup,
+                table.num_rows(),
+            );
+        } else {
+            prove_group_by(
+                builder,
+                alloc,
+                alpha,
+                beta,
+                (&group_by_columns, &sum_columns, selection),
+                (group_by_result_columns, sum_result_columns, count_column),
+                table.num_rows(),
+            );
+        }
         res
     }
 }
@@ -377,6 +692,12 @@ fn verify_group_by<S: Scalar>(
     Ok(())
 }
 
+/// Prove that the output groups, along with their sums and counts, are the groups of the selected input rows.
+///
+/// The argument compares two multisets through the sums
+/// `sum sel_in[i] * sum_in_fold[i] / (alpha + g_in_fold[i])` and
+/// `sum sum_out_bar_fold[i] / (alpha + g_out_bar_fold[i])`.
+/// Each row contributes its own term, so neither sum depends on the order of the rows.
 #[allow(
     clippy::missing_panics_doc,
     reason = "alpha is guaranteed to not be zero in this context"
@@ -417,6 +738,7 @@ pub fn prove_group_by<'a, S: Scalar>(
     let g_out_star = alloc.alloc_slice_copy(g_out_bar_fold);
     g_out_star[m_out..].fill(alpha.inv().expect("alpha should never be 0"));
     slice_ops::batch_inversion(&mut g_out_star[..m_out]);
+    builder.metrics_mut().record_field_inversions(n + 1 + m_out);
 
     builder.produce_intermediate_mle(g_in_star as &[_]);
     builder.produce_intermediate_mle(g_out_star as &[_]);
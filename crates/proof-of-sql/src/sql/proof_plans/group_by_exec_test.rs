@@ -1,12 +1,12 @@
-use super::test_utility::*;
+use super::{test_utility::*, DynProofPlan, GroupByExec};
 use crate::{
     base::{
         commitment::InnerProductProof,
-        database::{owned_table_utility::*, OwnedTableTestAccessor, TestAccessor},
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TestAccessor},
         scalar::Curve25519Scalar,
     },
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, ProveError, VerifiableQueryResult},
         proof_exprs::test_utility::*,
     },
 };
@@ -230,3 +230,131 @@ fn we_can_prove_a_complex_group_by_query_with_many_columns() {
     ]);
     assert_eq!(res, expected);
 }
+
+/// `select a, b, sum(c) as sum_c, count(*) as __count__ from sxt.t where d = 1 group by a, b`
+#[test]
+fn we_can_prove_a_group_by_with_two_key_columns() {
+    let data = owned_table([
+        bigint("a", [1, 1, 2, 2, 1, 2, 1, 2]),
+        bigint("b", [1, 2, 1, 2, 1, 2, 1, 1]),
+        bigint("c", [10, 20, 30, 40, 50, 60, 70, 80]),
+        bigint("d", [1, 1, 1, 1, 1, 1, 1, 0]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        cols_expr(t, &["a", "b"], &accessor),
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(t),
+        equal(column(t, "d", &accessor), const_bigint(1)),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    // Rows that share `a` but not `b`, or `b` but not `a`, are in different groups
+    let expected = owned_table([
+        bigint("a", [1, 1, 2, 2]),
+        bigint("b", [1, 2, 1, 2]),
+        bigint("sum_c", [10 + 50 + 70, 20, 30, 40 + 60]),
+        bigint("__count__", [3, 1, 1, 2]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// `select a, b, c, sum(d) as sum_d, count(*) as __count__ from sxt.t where true group by a, b, c`
+#[test]
+fn we_can_prove_a_group_by_with_three_key_columns_where_any_differing_key_splits_a_group() {
+    // Each of rows 1, 2 and 3 differs from row 0 in exactly one key column
+    let data = owned_table([
+        bigint("a", [1, 1, 1, 2, 1, 2]),
+        varchar("b", ["x", "x", "y", "x", "x", "x"]),
+        int128("c", [5, 6, 5, 5, 5, 5]),
+        bigint("d", [1, 2, 4, 8, 16, 32]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        cols_expr(t, &["a", "b", "c"], &accessor),
+        vec![sum_expr(column(t, "d", &accessor), "sum_d")],
+        "__count__",
+        tab(t),
+        const_bool(true),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [1, 1, 1, 2]),
+        varchar("b", ["x", "x", "y", "x"]),
+        int128("c", [5, 6, 5, 5]),
+        bigint("sum_d", [1 + 16, 2, 4, 8 + 32]),
+        bigint("__count__", [2, 1, 1, 2]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// `select a, sum(c) as sum_c, count(*) as __count__ from sxt.t group by a`
+/// where the sum of the group `a = 2` overflows `i64`
+#[test]
+fn we_can_prove_whether_the_sums_of_a_group_by_fit_the_summed_type() {
+    let data = owned_table([
+        bigint("a", [1, 2, 1, 2]),
+        bigint("c", [i64::MAX, i64::MAX, -1, 1]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = DynProofPlan::GroupBy(
+        GroupByExec::new(
+            cols_expr(t, &["a"], &accessor),
+            vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+            "__count__".parse().unwrap(),
+            tab(t),
+            const_bool(true),
+        )
+        .with_sum_overflow_flags(vec!["sum_c_overflow".parse().unwrap()]),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &());
+    exercise_verification(&res, &expr, &accessor, t);
+    let res = res.verify(&expr, &accessor, &()).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [1, 2]),
+        scalar(
+            "sum_c",
+            [
+                Curve25519Scalar::from(i64::MAX - 1),
+                Curve25519Scalar::from(i64::MAX) + Curve25519Scalar::from(1),
+            ],
+        ),
+        bigint("__count__", [2, 2]),
+        boolean("sum_c_overflow", [false, true]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// Without overflow flags a sum that overflows the summed type cannot be proven
+#[test]
+fn we_cannot_prove_a_group_by_sum_that_overflows_without_overflow_flags() {
+    let data = owned_table([bigint("a", [1, 1]), bigint("c", [i64::MAX, 1])]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = group_by(
+        cols_expr(t, &["a"], &accessor),
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(t),
+        const_bool(true),
+    );
+    assert_eq!(
+        VerifiableQueryResult::<InnerProductProof>::try_new(&expr, &accessor, &()).err(),
+        Some(ProveError::ResultOverflow {
+            column: "sum_c".parse().unwrap(),
+            row: 0,
+            column_type: ColumnType::BigInt,
+        })
+    );
+}
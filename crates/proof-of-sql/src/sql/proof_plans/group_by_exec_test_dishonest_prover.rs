@@ -0,0 +1,194 @@
+use super::{group_by_exec::prove_group_by, test_utility::*, GroupByExec};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::*, Column, ColumnField, ColumnRef, OwnedTable,
+            OwnedTableTestAccessor, Table, TableEvaluation, TableRef, TestAccessor,
+        },
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{
+            CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+            QueryError, VerifiableQueryResult, VerificationBuilder,
+        },
+        proof_exprs::{test_utility::*, ProofExpr},
+    },
+};
+use blitzar::proof::InnerProductProof;
+use bumpalo::Bump;
+use serde::Serialize;
+
+/// A [`GroupByExec`] whose prover appends a group without any rows to the end of the result.
+///
+/// The phantom group has a count and sums of zero, so it adds nothing to either side of the group by argument.
+#[derive(Debug, PartialEq, Serialize)]
+struct PhantomGroupByExec(GroupByExec);
+
+/// The key of the phantom group, which sorts after every key of the test data
+const PHANTOM_KEY: i64 = 1000;
+
+impl PhantomGroupByExec {
+    /// The result of the honest plan with the phantom group appended
+    fn phantom_result<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> Table<'a, S> {
+        let (honest_result, _) = self.0.result_evaluate(alloc, table_map);
+        Table::try_from_iter(
+            honest_result
+                .into_inner()
+                .into_iter()
+                .map(|(alias, column)| {
+                    let column = match column {
+                        Column::BigInt(values) if alias == self.0.count_alias => Column::BigInt(
+                            alloc.alloc_slice_fill_iter(values.iter().copied().chain([0])),
+                        ),
+                        Column::BigInt(values) => Column::BigInt(
+                            alloc
+                                .alloc_slice_fill_iter(values.iter().copied().chain([PHANTOM_KEY])),
+                        ),
+                        Column::Scalar(values) => Column::Scalar(
+                            alloc.alloc_slice_fill_iter(values.iter().copied().chain([S::ZERO])),
+                        ),
+                        _ => panic!("the test only groups by and sums BIGINT columns"),
+                    };
+                    (alias, column)
+                }),
+        )
+        .expect("Failed to create table from column references")
+    }
+}
+
+impl ProofPlan for PhantomGroupByExec {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.0.count(builder)
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        result: Option<&OwnedTable<S>>,
+        one_eval_map: &IndexMap<TableRef, S>,
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        self.0
+            .verifier_evaluate(builder, accessor, result, one_eval_map)
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        self.0.get_column_result_fields()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        self.0.get_column_references()
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        self.0.get_table_references()
+    }
+}
+
+impl ProverEvaluate for PhantomGroupByExec {
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> (Table<'a, S>, Vec<usize>) {
+        let res = self.phantom_result(alloc, table_map);
+        let num_rows = res.num_rows();
+        (res, vec![num_rows])
+    }
+
+    fn first_round_evaluate(&self, builder: &mut FirstRoundBuilder) {
+        self.0.first_round_evaluate(builder);
+    }
+
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> Table<'a, S> {
+        let table = table_map
+            .get(&self.0.table.table_ref)
+            .expect("Table not found");
+        let selection = self
+            .0
+            .where_clause
+            .prover_evaluate(builder, alloc, table)
+            .as_boolean()
+            .expect("selection is not boolean");
+        let group_by_columns = self
+            .0
+            .group_by_exprs
+            .iter()
+            .map(|aliased_expr| aliased_expr.expr.prover_evaluate(builder, alloc, table))
+            .collect::<Vec<_>>();
+        let sum_columns = self
+            .0
+            .sum_expr
+            .iter()
+            .map(|aliased_expr| aliased_expr.expr.prover_evaluate(builder, alloc, table))
+            .collect::<Vec<_>>();
+
+        let res = self.phantom_result(alloc, table_map);
+        let columns: Vec<_> = res.inner_table().values().copied().collect();
+        let num_keys = self.0.group_by_exprs.len();
+        let num_sums = self.0.sum_expr.len();
+        let sum_result_columns: Vec<_> = columns[num_keys..num_keys + num_sums]
+            .iter()
+            .map(|column| column.as_scalar().expect("sums are scalars"))
+            .collect();
+        let count_column = columns[num_keys + num_sums]
+            .as_bigint()
+            .expect("counts are BIGINT");
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+        for column in &columns {
+            builder.produce_intermediate_mle(*column);
+        }
+        prove_group_by(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            (&group_by_columns, &sum_columns, selection),
+            (&columns[..num_keys], &sum_result_columns, count_column),
+            table.num_rows(),
+        );
+        res
+    }
+}
+
+#[test]
+fn we_cannot_verify_a_group_by_with_a_phantom_group_without_rows() {
+    let data = owned_table([
+        bigint("a", [1, 2, 2, 1, 2]),
+        bigint("b", [99, 99, 99, 99, 0]),
+        bigint("c", [101, 102, 103, 104, 105]),
+    ]);
+    let t = "sxt.t".parse().unwrap();
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t, data, 0);
+    let expr = PhantomGroupByExec(GroupByExec::new(
+        cols_expr(t, &["a"], &accessor),
+        vec![sum_expr(column(t, "c", &accessor), "sum_c")],
+        "__count__".parse().unwrap(),
+        tab(t),
+        equal(column(t, "b", &accessor), const_int128(99)),
+    ));
+    let res = VerifiableQueryResult::<InnerProductProof>::new(&expr, &accessor, &());
+    assert!(matches!(
+        res.verify(&expr, &accessor, &()),
+        Err(QueryError::ProofError {
+            source: ProofError::VerificationError {
+                error: "Group by result contains a group without rows."
+            }
+        })
+    ));
+}
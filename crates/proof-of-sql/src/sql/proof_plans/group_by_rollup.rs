@@ -0,0 +1,465 @@
+//! The `GROUP BY ROLLUP` variant of [`GroupByExec`](super::GroupByExec).
+//!
+//! A rollup over the keys `k_0, ..., k_{M-1}` has `M + 1` levels. Level `l` groups the selected rows
+//! by the first `l` keys, so level `M` is the ordinary group by and level `0` is the grand total.
+//! The result is the concatenation of the levels from `M` down to `0`, and the `j`-th grouping column
+//! `f_j` of the result is `true` in the rows of the levels that aggregate `k_j` away, i.e. the levels `l <= j`.
+//!
+//! Each level is proven with the argument of the ordinary group by, restricted to the rows of the level
+//! by the indicator `f_l - f_{l-1}`, where `f_{-1} = 0` and `f_M = 1`. The keys of the result are folded
+//! with the aggregated-away keys masked out, so the same output fold serves every level.
+use super::{fold_columns, fold_vals};
+use crate::{
+    base::{
+        database::{
+            group_by_util::{aggregate_columns, AggregatedColumns},
+            order_by_util::compare_indexes_by_owned_columns,
+            union_util::column_union,
+            Column, ColumnType, LiteralValue, OwnedColumn, RowValue,
+        },
+        math::i256::I256,
+        proof::ProofError,
+        scalar::Scalar,
+        slice_ops,
+    },
+    sql::proof::{
+        FinalRoundBuilder, SumcheckSubpolynomialTerm, SumcheckSubpolynomialType,
+        VerificationBuilder,
+    },
+};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use bumpalo::Bump;
+use core::iter::{repeat, repeat_with};
+use num_traits::One;
+
+/// The result of `GROUP BY ROLLUP`, with the levels concatenated from the finest grouping to the grand total
+pub(super) struct RollupColumns<'a, S: Scalar> {
+    /// The keys, which hold the default value of their type where they are aggregated away
+    pub group_by_columns: Vec<Column<'a, S>>,
+    /// The sums of each group
+    pub sum_columns: Vec<&'a [S]>,
+    /// The number of rows in each group
+    pub count_column: &'a [i64],
+    /// For each key, whether it is aggregated away in each group
+    pub grouping_columns: Vec<&'a [bool]>,
+}
+
+/// Group the selected rows by every prefix of the keys.
+///
+/// # Panics
+///
+/// Panics if the columns do not all have the length of the selection.
+pub(super) fn aggregate_rollup<'a, S: Scalar>(
+    alloc: &'a Bump,
+    group_by_columns: &[Column<'a, S>],
+    sum_columns: &[Column<'a, S>],
+    selection: &[bool],
+) -> RollupColumns<'a, S> {
+    let levels: Vec<AggregatedColumns<'a, S>> = (0..=group_by_columns.len())
+        .rev()
+        .map(|num_keys| {
+            aggregate_columns(
+                alloc,
+                &group_by_columns[..num_keys],
+                sum_columns,
+                &[],
+                &[],
+                selection,
+            )
+            .expect("columns should be aggregatable")
+        })
+        .collect();
+    let rollup_group_by_columns = group_by_columns
+        .iter()
+        .enumerate()
+        .map(|(key_index, column)| {
+            let pieces: Vec<Column<'a, S>> = levels
+                .iter()
+                .map(|level| {
+                    level
+                        .group_by_columns
+                        .get(key_index)
+                        .copied()
+                        .unwrap_or_else(|| {
+                            default_column(alloc, column.column_type(), level.count_column.len())
+                        })
+                })
+                .collect();
+            column_union(
+                &pieces.iter().collect::<Vec<_>>(),
+                alloc,
+                column.column_type(),
+            )
+            .expect("levels of a key should have the type of the key")
+        })
+        .collect();
+    let sum_result_columns = (0..sum_columns.len())
+        .map(|sum_index| {
+            alloc.alloc_slice_copy(
+                &levels
+                    .iter()
+                    .flat_map(|level| level.sum_columns[sum_index].iter().copied())
+                    .collect::<Vec<_>>(),
+            ) as &[_]
+        })
+        .collect();
+    let count_column = alloc.alloc_slice_copy(
+        &levels
+            .iter()
+            .flat_map(|level| level.count_column.iter().copied())
+            .collect::<Vec<_>>(),
+    );
+    let grouping_columns = (0..group_by_columns.len())
+        .map(|key_index| {
+            alloc.alloc_slice_copy(
+                &levels
+                    .iter()
+                    .flat_map(|level| {
+                        repeat(key_index >= level.group_by_columns.len())
+                            .take(level.count_column.len())
+                    })
+                    .collect::<Vec<_>>(),
+            ) as &[_]
+        })
+        .collect();
+    RollupColumns {
+        group_by_columns: rollup_group_by_columns,
+        sum_columns: sum_result_columns,
+        count_column,
+        grouping_columns,
+    }
+}
+
+/// A column of `length` copies of the default value of `column_type`,
+/// which is the value of a key where it is aggregated away
+pub(super) fn default_column<S: Scalar>(
+    alloc: &Bump,
+    column_type: ColumnType,
+    length: usize,
+) -> Column<'_, S> {
+    let literal = match column_type {
+        ColumnType::Boolean => LiteralValue::Boolean(false),
+        ColumnType::TinyInt => LiteralValue::TinyInt(0),
+        ColumnType::SmallInt => LiteralValue::SmallInt(0),
+        ColumnType::Int => LiteralValue::Int(0),
+        ColumnType::BigInt => LiteralValue::BigInt(0),
+        ColumnType::UInt64 => LiteralValue::UInt64(0),
+        ColumnType::Int128 => LiteralValue::Int128(0),
+        ColumnType::VarChar => LiteralValue::VarChar(String::new()),
+        ColumnType::Decimal75(precision, scale) => {
+            LiteralValue::Decimal75(precision, scale, I256::from(0))
+        }
+        ColumnType::TimestampTZ(time_unit, timezone) => {
+            LiteralValue::TimeStampTZ(time_unit, timezone, 0)
+        }
+        ColumnType::Scalar => LiteralValue::Scalar([0; 4]),
+        ColumnType::FixedBytes(width) => LiteralValue::FixedBytes(vec![0; usize::from(width)]),
+    };
+    Column::from_literal_with_length(&literal, length, alloc)
+}
+
+/// Whether a value is the default value of its type
+pub(super) fn is_default_value<S: Scalar>(value: &RowValue<'_, S>) -> bool {
+    match value {
+        RowValue::Boolean(value) => !value,
+        RowValue::TinyInt(value) => *value == 0,
+        RowValue::SmallInt(value) => *value == 0,
+        RowValue::Int(value) => *value == 0,
+        RowValue::BigInt(value) | RowValue::TimestampTZ(_, _, value) => *value == 0,
+        RowValue::UInt64(value) => *value == 0,
+        RowValue::Int128(value) => *value == 0,
+        RowValue::VarChar(value) => value.is_empty(),
+        RowValue::Decimal75(_, _, value) | RowValue::Scalar(value) => value.is_zero(),
+        RowValue::FixedBytes(value) => value.iter().all(|byte| *byte == 0),
+    }
+}
+
+/// Check that the grouping columns of the result describe a rollup, that aggregated-away keys hold the default
+/// value of their type, and that each level is ordered by its keys without duplicates.
+///
+/// The levels must appear from the finest grouping to the grand total.
+/// Together with the proof, which shows that the groups of each level are exactly the groups of the selected rows,
+/// this shows that the result is the rollup.
+pub(super) fn verify_rollup_result<S: Scalar>(
+    group_by_columns: &[&OwnedColumn<S>],
+    grouping_columns: &[&OwnedColumn<S>],
+    num_rows: usize,
+) -> Result<(), ProofError> {
+    let grouping_columns = grouping_columns
+        .iter()
+        .map(|column| match column {
+            OwnedColumn::Boolean(flags) => Ok(flags),
+            _ => Err(ProofError::VerificationError {
+                error: "Grouping columns of rollup are not boolean.",
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut previous_level = None;
+    for row in 0..num_rows {
+        let level = grouping_columns
+            .iter()
+            .take_while(|flags| !flags[row])
+            .count();
+        if grouping_columns[level..].iter().any(|flags| !flags[row]) {
+            Err(ProofError::VerificationError {
+                error: "Grouping columns of rollup do not aggregate away a suffix of the keys.",
+            })?;
+        }
+        if group_by_columns[level..]
+            .iter()
+            .any(|column| !column.value_at(row).as_ref().is_some_and(is_default_value))
+        {
+            Err(ProofError::VerificationError {
+                error: "Aggregated-away key of rollup does not hold the default value.",
+            })?;
+        }
+        match previous_level {
+            Some(previous_level) if previous_level < level => Err(ProofError::VerificationError {
+                error: "Levels of rollup not ordered as expected.",
+            })?,
+            Some(previous_level)
+                if previous_level == level
+                    && compare_indexes_by_owned_columns(
+                        &group_by_columns[..level],
+                        row - 1,
+                        row,
+                    )
+                    .is_ge() =>
+            {
+                Err(ProofError::VerificationError {
+                    error: "Result of rollup not ordered as expected.",
+                })?;
+            }
+            _ => {}
+        }
+        previous_level = Some(level);
+    }
+    Ok(())
+}
+
+/// Verify the proof of [`prove_rollup`].
+#[allow(clippy::unnecessary_wraps)]
+pub(super) fn verify_rollup<S: Scalar>(
+    builder: &mut VerificationBuilder<S>,
+    alpha: S,
+    beta: S,
+    (input_one_eval, output_one_eval): (S, S),
+    (g_in_evals, sum_in_evals, sel_in_eval): (Vec<S>, Vec<S>, S),
+    (g_out_evals, sum_out_evals, count_out_eval, grouping_out_evals): (Vec<S>, Vec<S>, S, Vec<S>),
+) -> Result<(), ProofError> {
+    let num_levels = g_in_evals.len() + 1;
+    let g_in_star_evals: Vec<_> = repeat_with(|| builder.consume_intermediate_mle())
+        .take(num_levels)
+        .collect();
+    let g_out_star_eval = builder.consume_intermediate_mle();
+
+    // sum_in_fold = 1 + sum beta^(j+1) * sum_in[j]
+    let sum_in_fold_eval = input_one_eval + beta * fold_vals(beta, &sum_in_evals);
+    // sum_out_fold = count_out + sum beta^(j+1) * sum_out[j]
+    let sum_out_fold_eval = count_out_eval + beta * fold_vals(beta, &sum_out_evals);
+
+    // sum g_in_star[l] * sel_in * sum_in_fold - (f_l - f_{l-1}) * g_out_star * sum_out_fold = 0
+    for (level, g_in_star_eval) in g_in_star_evals.iter().enumerate() {
+        let upper_eval = grouping_out_evals.get(level).copied().unwrap_or(S::one());
+        let lower_eval = level
+            .checked_sub(1)
+            .map_or(S::zero(), |lower| grouping_out_evals[lower]);
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::ZeroSum,
+            *g_in_star_eval * sel_in_eval * sum_in_fold_eval
+                - (upper_eval - lower_eval) * g_out_star_eval * sum_out_fold_eval,
+        );
+    }
+
+    // g_in_star[l] * (alpha + sum_{j < l} beta^j * g_in[j]) - 1 = 0
+    for (level, g_in_star_eval) in g_in_star_evals.iter().enumerate() {
+        let g_in_fold_eval = alpha * input_one_eval + fold_vals(beta, &g_in_evals[..level]);
+        builder.produce_sumcheck_subpolynomial_evaluation(
+            &SumcheckSubpolynomialType::Identity,
+            *g_in_star_eval * g_in_fold_eval - input_one_eval,
+        );
+    }
+
+    // g_out_star * (alpha + sum beta^j * (1 - f_j) * g_out[j]) - 1 = 0
+    let masked_g_out_evals: Vec<_> = g_out_evals
+        .iter()
+        .zip(&grouping_out_evals)
+        .map(|(g_out_eval, grouping_eval)| *g_out_eval - *grouping_eval * *g_out_eval)
+        .collect();
+    let g_out_fold_eval = alpha * output_one_eval + fold_vals(beta, &masked_g_out_evals);
+    builder.produce_sumcheck_subpolynomial_evaluation(
+        &SumcheckSubpolynomialType::Identity,
+        g_out_star_eval * g_out_fold_eval - output_one_eval,
+    );
+
+    Ok(())
+}
+
+/// Prove that each level of the rollup, along with its sums and counts, is the grouping of the selected input rows
+/// by a prefix of the keys.
+///
+/// Unlike the ordinary group by, the result may have more rows than the input,
+/// so the constant terms are restricted to the input and the output rows respectively.
+#[allow(clippy::too_many_lines)]
+pub(super) fn prove_rollup<'a, S: Scalar>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    alloc: &'a Bump,
+    alpha: S,
+    beta: S,
+    (g_in, sum_in, sel_in): (&[Column<'a, S>], &[Column<'a, S>], &'a [bool]),
+    rollup: &RollupColumns<'a, S>,
+    n: usize,
+) {
+    let RollupColumns {
+        group_by_columns: g_out,
+        sum_columns: sum_out,
+        count_column: count_out,
+        grouping_columns: grouping_out,
+    } = rollup;
+    let m = count_out.len();
+    let input_ones = alloc.alloc_slice_fill_copy(n, true) as &[_];
+    let output_ones = alloc.alloc_slice_fill_copy(m, true) as &[_];
+
+    // sum_in_fold = 1 + sum beta^(j+1) * sum_in[j]
+    let sum_in_fold = alloc.alloc_slice_fill_copy(n, One::one());
+    fold_columns(sum_in_fold, beta, beta, sum_in);
+    let sum_in_fold = sum_in_fold as &[_];
+
+    // sum_out_fold = count_out + sum beta^(j+1) * sum_out[j]
+    let sum_out_fold = alloc.alloc_slice_fill_default(m);
+    slice_ops::slice_cast_mut(count_out, sum_out_fold);
+    fold_columns(sum_out_fold, beta, beta, sum_out);
+    let sum_out_fold = sum_out_fold as &[_];
+
+    // g_in_star[l] = (alpha + sum_{j < l} beta^j * g_in[j])^(-1)
+    let g_in_folds: Vec<&'a [S]> = (0..=g_in.len())
+        .map(|level| {
+            let g_in_fold = alloc.alloc_slice_fill_copy(n, alpha);
+            fold_columns(g_in_fold, One::one(), beta, &g_in[..level]);
+            g_in_fold as &[_]
+        })
+        .collect();
+    let g_in_stars: Vec<&'a [S]> = g_in_folds
+        .iter()
+        .map(|g_in_fold| {
+            let g_in_star = alloc.alloc_slice_copy(g_in_fold);
+            slice_ops::batch_inversion(g_in_star);
+            g_in_star as &[_]
+        })
+        .collect();
+
+    // g_out_star = (alpha + sum beta^j * (1 - f_j) * g_out[j])^(-1)
+    let masked_g_out: Vec<&'a [S]> = g_out
+        .iter()
+        .zip(grouping_out.iter())
+        .map(|(column, grouping)| {
+            alloc.alloc_slice_fill_iter(
+                column
+                    .to_scalar_with_scaling(0)
+                    .into_iter()
+                    .zip(*grouping)
+                    .map(
+                        |(value, aggregated_away)| if *aggregated_away { S::zero() } else { value },
+                    ),
+            ) as &[_]
+        })
+        .collect();
+    let g_out_star = alloc.alloc_slice_fill_copy(m, alpha);
+    fold_columns(g_out_star, One::one(), beta, &masked_g_out);
+    slice_ops::batch_inversion(g_out_star);
+    let g_out_star = g_out_star as &[_];
+    builder
+        .metrics_mut()
+        .record_field_inversions(g_in_folds.len() * n + m);
+
+    for g_in_star in &g_in_stars {
+        builder.produce_intermediate_mle(*g_in_star);
+    }
+    builder.produce_intermediate_mle(g_out_star);
+
+    // The multilinear extensions of the sumcheck are deduplicated by the addresses of their slices,
+    // and an empty slice from the allocator may share its address with another allocation.
+    // An empty result is therefore represented by static empty slices, which never do.
+    let (g_out, grouping_out, output_ones, g_out_star, sum_out_fold): (Vec<_>, Vec<_>, _, _, _) =
+        if m == 0 {
+            (
+                g_out.iter().map(|_| Column::Scalar(&[])).collect(),
+                grouping_out.iter().map(|_| &[] as &[bool]).collect(),
+                &[] as &[bool],
+                &[] as &[S],
+                &[] as &[S],
+            )
+        } else {
+            (
+                g_out.clone(),
+                grouping_out.clone(),
+                output_ones,
+                g_out_star,
+                sum_out_fold,
+            )
+        };
+
+    // sum g_in_star[l] * sel_in * sum_in_fold - (f_l - f_{l-1}) * g_out_star * sum_out_fold = 0
+    for (level, g_in_star) in g_in_stars.iter().enumerate() {
+        let mut terms: Vec<SumcheckSubpolynomialTerm<'a, S>> = vec![(
+            S::one(),
+            vec![
+                Box::new(*g_in_star),
+                Box::new(sel_in),
+                Box::new(sum_in_fold),
+            ],
+        )];
+        terms.push(match grouping_out.get(level) {
+            Some(upper) => (
+                -S::one(),
+                vec![
+                    Box::new(*upper),
+                    Box::new(g_out_star),
+                    Box::new(sum_out_fold),
+                ],
+            ),
+            None => (
+                -S::one(),
+                vec![Box::new(g_out_star), Box::new(sum_out_fold)],
+            ),
+        });
+        if let Some(lower) = level.checked_sub(1).map(|lower| grouping_out[lower]) {
+            terms.push((
+                S::one(),
+                vec![
+                    Box::new(lower),
+                    Box::new(g_out_star),
+                    Box::new(sum_out_fold),
+                ],
+            ));
+        }
+        builder.produce_sumcheck_subpolynomial(SumcheckSubpolynomialType::ZeroSum, terms);
+    }
+
+    // g_in_star[l] * (alpha + sum_{j < l} beta^j * g_in[j]) - 1 = 0
+    for (g_in_star, g_in_fold) in g_in_stars.iter().zip(&g_in_folds) {
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::one(), vec![Box::new(*g_in_star), Box::new(*g_in_fold)]),
+                (-S::one(), vec![Box::new(input_ones)]),
+            ],
+        );
+    }
+
+    // g_out_star * (alpha + sum beta^j * (1 - f_j) * g_out[j]) - 1 = 0
+    let mut terms: Vec<SumcheckSubpolynomialTerm<'a, S>> = vec![
+        (alpha, vec![Box::new(g_out_star), Box::new(output_ones)]),
+        (-S::one(), vec![Box::new(output_ones)]),
+    ];
+    let mut power = S::one();
+    for (column, grouping) in g_out.into_iter().zip(grouping_out) {
+        terms.push((power, vec![Box::new(g_out_star), Box::new(column)]));
+        terms.push((
+            -power,
+            vec![Box::new(g_out_star), Box::new(grouping), Box::new(column)],
+        ));
+        power *= beta;
+    }
+    builder.produce_sumcheck_subpolynomial(SumcheckSubpolynomialType::Identity, terms);
+}
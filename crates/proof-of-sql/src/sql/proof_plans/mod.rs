@@ -34,6 +34,22 @@ pub(crate) use group_by_exec::GroupByExec;
 
 #[cfg(all(test, feature = "blitzar"))]
 mod group_by_exec_test;
+#[cfg(all(test, feature = "blitzar"))]
+mod group_by_exec_test_dishonest_prover;
+
+mod group_by_rollup;
+#[cfg(test)]
+mod group_by_rollup_test;
+
+mod row_number_exec;
+pub(crate) use row_number_exec::RowNumberExec;
+#[cfg(test)]
+mod row_number_exec_test;
+
+mod shift_exec;
+pub(crate) use shift_exec::ShiftExec;
+#[cfg(test)]
+mod shift_exec_test;
 
 mod dyn_proof_plan;
 pub use dyn_proof_plan::DynProofPlan;
@@ -0,0 +1,409 @@
+use super::filter_exec::{prove_filter, verify_filter};
+use crate::{
+    base::{
+        database::{
+            filter_util::filter_column_by_index,
+            order_by_util::{compare_indexes_by_columns, compare_indexes_by_owned_columns},
+            Column, ColumnField, ColumnRef, ColumnType, OwnedColumn, OwnedTable, Table,
+            TableEvaluation, TableOptions, TableRef,
+        },
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{
+            CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+            VerificationBuilder,
+        },
+        proof_exprs::{AliasedDynProofExpr, DynProofExpr, ProofExpr, TableExpr},
+    },
+};
+use alloc::{vec, vec::Vec};
+use bumpalo::Bump;
+use core::iter::{self, repeat_with};
+use proof_of_sql_parser::{
+    intermediate_ast::{OrderBy, OrderByDirection},
+    Identifier,
+};
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT <result_expr1>, ..., <result_exprN>,
+///         ROW_NUMBER() OVER (ORDER BY <order_by>) AS <row_number_alias>
+///     FROM <table>
+///     WHERE <where_clause>
+/// ```
+///
+/// The selected rows are sorted by the result column named by `order_by`, and the row number column
+/// numbers them from 1 in that order.
+///
+/// The proof shows that the rows of the result are the selected rows, with the same argument as [`FilterExec`](super::FilterExec),
+/// which does not depend on the order of the rows. The result is sent in the clear, so the verifier
+/// checks directly that its keys are strictly sorted and that its row numbers are `1..=n`.
+///
+/// Requiring strictly sorted keys fixes the numbering, since any order of rows with equal keys would verify.
+/// So the keys of the selected rows must be distinct, and results with equal keys are rejected.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RowNumberExec {
+    pub(super) aliased_results: Vec<AliasedDynProofExpr>,
+    pub(super) table: TableExpr,
+    pub(super) where_clause: DynProofExpr,
+    pub(super) order_by: OrderBy,
+    pub(super) row_number_alias: Identifier,
+}
+
+impl RowNumberExec {
+    /// Creates a new row number expression.
+    ///
+    /// `order_by.expr` is the alias of the result column to sort by.
+    pub fn new(
+        aliased_results: Vec<AliasedDynProofExpr>,
+        table: TableExpr,
+        where_clause: DynProofExpr,
+        order_by: OrderBy,
+        row_number_alias: Identifier,
+    ) -> Self {
+        Self {
+            aliased_results,
+            table,
+            where_clause,
+            order_by,
+            row_number_alias,
+        }
+    }
+
+    /// The selected rows sorted by their keys, and their row numbers
+    #[allow(clippy::cast_possible_wrap)]
+    fn sort_and_number<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        columns: &[Column<'a, S>],
+        selection: &[bool],
+    ) -> (Vec<Column<'a, S>>, &'a [i64]) {
+        let indexes =
+            sorted_selected_indexes(&self.aliased_results, &self.order_by, columns, selection);
+        let sorted_columns = columns
+            .iter()
+            .map(|column| filter_column_by_index(alloc, column, &indexes))
+            .collect();
+        let row_numbers = alloc.alloc_slice_fill_with(indexes.len(), |i| i as i64 + 1);
+        (sorted_columns, row_numbers)
+    }
+
+    /// The result table made of the sorted columns and the row numbers
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns do not all have as many rows as there are row numbers.
+    fn result_table<'a, S: Scalar>(
+        &self,
+        sorted_columns: Vec<Column<'a, S>>,
+        row_numbers: &'a [i64],
+    ) -> Table<'a, S> {
+        Table::<'a, S>::try_from_iter_with_options(
+            self.get_column_result_fields()
+                .into_iter()
+                .map(|field| field.name())
+                .zip(
+                    sorted_columns
+                        .into_iter()
+                        .chain(iter::once(Column::BigInt(row_numbers))),
+                ),
+            TableOptions::new(Some(row_numbers.len())),
+        )
+        .expect("Failed to create table from iterator")
+    }
+
+    /// Check that the keys of the result are strictly sorted and that its rows are numbered from 1.
+    fn verify_sorted_row_numbers<S: Scalar>(
+        &self,
+        table: &OwnedTable<S>,
+    ) -> Result<(), ProofError> {
+        let (Some(key), Some(OwnedColumn::BigInt(row_numbers))) = (
+            table.inner_table().get(&self.order_by.expr),
+            table.inner_table().get(&self.row_number_alias),
+        ) else {
+            Err(ProofError::VerificationError {
+                error: "Result does not contain the row number key and the row numbers.",
+            })?
+        };
+        if !is_sorted(key, self.order_by.direction, table.num_rows()) {
+            Err(ProofError::VerificationError {
+                error: "Result of row number not ordered as expected.",
+            })?;
+        }
+        if !is_strictly_sorted(key, self.order_by.direction, table.num_rows()) {
+            Err(ProofError::VerificationError {
+                error: "Result of row number has rows with equal keys.",
+            })?;
+        }
+        if !row_numbers
+            .iter()
+            .zip(1_i64..)
+            .all(|(&row_number, expected)| row_number == expected)
+        {
+            Err(ProofError::VerificationError {
+                error: "Row numbers of the result are not consecutive from 1.",
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// The indexes of the selected rows, sorted by the result column `order_by`
+///
+/// The sort is stable, so rows with equal keys stay in the order of the table.
+///
+/// # Panics
+///
+/// Panics if `order_by` is not the alias of a result column.
+pub(super) fn sorted_selected_indexes<S: Scalar>(
+    aliased_results: &[AliasedDynProofExpr],
+    order_by: &OrderBy,
+    columns: &[Column<S>],
+    selection: &[bool],
+) -> Vec<usize> {
+    let key_index = aliased_results
+        .iter()
+        .position(|aliased_expr| aliased_expr.alias == order_by.expr)
+        .expect("the order by key should be a result column");
+    let key = &columns[key_index..=key_index];
+    let mut indexes: Vec<usize> = (0..selection.len()).filter(|&i| selection[i]).collect();
+    indexes.sort_by(|&i, &j| {
+        let ordering = compare_indexes_by_columns(key, i, j);
+        match order_by.direction {
+            OrderByDirection::Asc => ordering,
+            OrderByDirection::Desc => ordering.reverse(),
+        }
+    });
+    indexes
+}
+
+/// Whether the first `num_rows` rows of `key` are sorted in `direction`
+pub(super) fn is_sorted<S: Scalar>(
+    key: &OwnedColumn<S>,
+    direction: OrderByDirection,
+    num_rows: usize,
+) -> bool {
+    (1..num_rows).all(|i| {
+        let ordering = compare_indexes_by_owned_columns(&[key], i - 1, i);
+        match direction {
+            OrderByDirection::Asc => ordering.is_le(),
+            OrderByDirection::Desc => ordering.is_ge(),
+        }
+    })
+}
+
+/// Whether the first `num_rows` rows of `key` are sorted in `direction` without any equal keys
+pub(super) fn is_strictly_sorted<S: Scalar>(
+    key: &OwnedColumn<S>,
+    direction: OrderByDirection,
+    num_rows: usize,
+) -> bool {
+    (1..num_rows).all(|i| {
+        let ordering = compare_indexes_by_owned_columns(&[key], i - 1, i);
+        match direction {
+            OrderByDirection::Asc => ordering.is_lt(),
+            OrderByDirection::Desc => ordering.is_gt(),
+        }
+    })
+}
+
+impl ProofPlan for RowNumberExec {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.where_clause.count(builder)?;
+        for aliased_expr in &self.aliased_results {
+            aliased_expr.expr.count(builder)?;
+            builder.count_intermediate_mles(1);
+        }
+        // For the row number col
+        builder.count_intermediate_mles(1);
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(3);
+        builder.count_degree(3);
+        builder.count_post_result_challenges(2);
+        Ok(())
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        result: Option<&OwnedTable<S>>,
+        one_eval_map: &IndexMap<TableRef, S>,
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        // The row numbers are checked against the result, which the verifier only has for the top level plan
+        let Some(table) = result else {
+            return Err(ProofError::VerificationError {
+                error: "RowNumberExec is only supported at the top level of a query plan",
+            });
+        };
+        let input_one_eval = *one_eval_map
+            .get(&self.table.table_ref)
+            .expect("One eval not found");
+        // 1. selection
+        let selection_eval =
+            self.where_clause
+                .verifier_evaluate(builder, accessor, input_one_eval)?;
+        // 2. columns
+        let columns_evals = self
+            .aliased_results
+            .iter()
+            .map(|aliased_expr| {
+                aliased_expr
+                    .expr
+                    .verifier_evaluate(builder, accessor, input_one_eval)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // 3. sorted columns
+        let sorted_columns_evals: Vec<_> = repeat_with(|| builder.consume_intermediate_mle())
+            .take(self.aliased_results.len())
+            .collect();
+        let row_number_eval = builder.consume_intermediate_mle();
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+
+        let output_one_eval = builder.consume_one_evaluation();
+
+        verify_filter(
+            builder,
+            alpha,
+            beta,
+            input_one_eval,
+            output_one_eval,
+            &columns_evals,
+            selection_eval,
+            &sorted_columns_evals,
+        )?;
+        self.verify_sorted_row_numbers(table)?;
+
+        let output_evals = sorted_columns_evals
+            .into_iter()
+            .chain(iter::once(row_number_eval))
+            .collect();
+        Ok(TableEvaluation::new(output_evals, output_one_eval))
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        self.aliased_results
+            .iter()
+            .map(|aliased_expr| ColumnField::new(aliased_expr.alias, aliased_expr.expr.data_type()))
+            .chain(iter::once(ColumnField::new(
+                self.row_number_alias,
+                ColumnType::BigInt,
+            )))
+            .collect()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::default();
+
+        for aliased_expr in &self.aliased_results {
+            aliased_expr.expr.get_column_references(&mut columns);
+        }
+
+        self.where_clause.get_column_references(&mut columns);
+
+        columns
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        IndexSet::from_iter([self.table.table_ref])
+    }
+}
+
+impl ProverEvaluate for RowNumberExec {
+    #[tracing::instrument(name = "RowNumberExec::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> (Table<'a, S>, Vec<usize>) {
+        let table = table_map
+            .get(&self.table.table_ref)
+            .expect("Table not found");
+        // 1. selection
+        let selection_column: Column<'a, S> = self.where_clause.result_evaluate(alloc, table);
+        let selection = selection_column
+            .as_boolean()
+            .expect("selection is not boolean");
+
+        // 2. columns
+        let columns: Vec<_> = self
+            .aliased_results
+            .iter()
+            .map(|aliased_expr| aliased_expr.expr.result_evaluate(alloc, table))
+            .collect();
+
+        // 3. sort and number the selected rows
+        let (sorted_columns, row_numbers) = self.sort_and_number(alloc, &columns, selection);
+        let output_length = row_numbers.len();
+        (
+            self.result_table(sorted_columns, row_numbers),
+            vec![output_length],
+        )
+    }
+
+    fn first_round_evaluate(&self, builder: &mut FirstRoundBuilder) {
+        builder.request_post_result_challenges(2);
+    }
+
+    #[tracing::instrument(
+        name = "RowNumberExec::final_round_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> Table<'a, S> {
+        let table = table_map
+            .get(&self.table.table_ref)
+            .expect("Table not found");
+        // 1. selection
+        let selection_column: Column<'a, S> =
+            self.where_clause.prover_evaluate(builder, alloc, table);
+        let selection = selection_column
+            .as_boolean()
+            .expect("selection is not boolean");
+
+        // 2. columns
+        let columns: Vec<_> = self
+            .aliased_results
+            .iter()
+            .map(|aliased_expr| aliased_expr.expr.prover_evaluate(builder, alloc, table))
+            .collect();
+
+        // 3. sort and number the selected rows
+        let (sorted_columns, row_numbers) = self.sort_and_number(alloc, &columns, selection);
+        let output_length = row_numbers.len();
+
+        // 4. Produce MLEs
+        sorted_columns.iter().copied().for_each(|column| {
+            builder.produce_intermediate_mle(column);
+        });
+        builder.produce_intermediate_mle(Column::BigInt(row_numbers));
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+
+        // 5. Prove that the sorted rows are the selected rows
+        prove_filter::<S>(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            &columns,
+            selection,
+            &sorted_columns,
+            table.num_rows(),
+            output_length,
+        );
+        self.result_table(sorted_columns, row_numbers)
+    }
+}
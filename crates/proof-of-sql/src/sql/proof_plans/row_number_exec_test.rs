@@ -0,0 +1,250 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::{
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor, TableRef},
+        map::IndexMap,
+        proof::ProofError,
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        proof::{
+            ProofPlan, ProvableQueryResult, QueryError, QueryProof, SumcheckMleEvaluations,
+            VerificationBuilder,
+        },
+        proof_exprs::test_utility::*,
+    },
+};
+use proof_of_sql_parser::intermediate_ast::OrderByDirection;
+
+fn accessor(
+    t: TableRef,
+    setup: DoryProverPublicSetup<'_>,
+) -> OwnedTableTestAccessor<'_, DoryEvaluationProof> {
+    OwnedTableTestAccessor::new_from_table(
+        t,
+        owned_table([
+            bigint("a", [3_i64, 1, 4, 1, 5, 9, 2, 6]),
+            varchar("b", ["c", "a", "d", "b", "e", "i", "x", "f"]),
+            boolean("c", [true, true, true, false, true, true, false, true]),
+        ]),
+        0,
+        setup,
+    )
+}
+
+#[test]
+fn we_can_prove_row_numbers_in_ascending_and_descending_order() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = accessor(t, setup);
+
+    // select a, b, row_number() over (order by a) as rn from sxt.t where c
+    let plan = row_number(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        column(t, "c", &accessor),
+        "a",
+        OrderByDirection::Asc,
+        "rn",
+    );
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    let table = proof
+        .verify(&plan, &accessor, &result, &verifier_setup)
+        .unwrap()
+        .table;
+    let expected = owned_table([
+        bigint("a", [1_i64, 3, 4, 5, 6, 9]),
+        varchar("b", ["a", "c", "d", "e", "f", "i"]),
+        bigint("rn", [1_i64, 2, 3, 4, 5, 6]),
+    ]);
+    assert_eq!(table, expected);
+
+    // select b as name, a, row_number() over (order by a desc) as rn from sxt.t where a >= 3
+    let plan = row_number(
+        vec![
+            aliased_col_expr_plan(t, "b", "name", &accessor),
+            col_expr_plan(t, "a", &accessor),
+        ],
+        tab(t),
+        gte(column(t, "a", &accessor), const_bigint(3)),
+        "a",
+        OrderByDirection::Desc,
+        "rn",
+    );
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    let table = proof
+        .verify(&plan, &accessor, &result, &verifier_setup)
+        .unwrap()
+        .table;
+    let expected = owned_table([
+        varchar("name", ["i", "f", "e", "d", "c"]),
+        bigint("a", [9_i64, 6, 5, 4, 3]),
+        bigint("rn", [1_i64, 2, 3, 4, 5]),
+    ]);
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn we_can_prove_row_numbers_of_an_empty_selection() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = accessor(t, setup);
+
+    let plan = row_number(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(7)),
+        "a",
+        OrderByDirection::Asc,
+        "rn",
+    );
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    let table = proof
+        .verify(&plan, &accessor, &result, &verifier_setup)
+        .unwrap()
+        .table;
+    assert_eq!(
+        table,
+        owned_table([bigint("a", [0_i64; 0]), bigint("rn", [0_i64; 0])])
+    );
+}
+
+#[test]
+fn we_cannot_verify_row_numbers_of_rows_with_equal_keys() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = accessor(t, setup);
+
+    // select a, b, row_number() over (order by a) as rn from sxt.t where a = 1
+    let plan = row_number(
+        cols_expr_plan(t, &["a", "b"], &accessor),
+        tab(t),
+        equal(column(t, "a", &accessor), const_bigint(1)),
+        "a",
+        OrderByDirection::Asc,
+        "rn",
+    );
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    // Either numbering of the two rows with a = 1 would be consistent with the proof, so neither verifies.
+    assert!(matches!(
+        proof.verify(&plan, &accessor, &result, &verifier_setup),
+        Err(QueryError::ProofError {
+            source: ProofError::VerificationError {
+                error: "Result of row number has rows with equal keys."
+            }
+        })
+    ));
+}
+
+/// A result made up of `columns`
+fn tampered_result(columns: &[Column<DoryScalar>]) -> ProvableQueryResult {
+    ProvableQueryResult::new(columns[0].len() as u64, columns)
+}
+
+#[test]
+fn we_cannot_verify_row_numbers_that_are_tampered_with() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = accessor(t, setup);
+
+    // select a, row_number() over (order by a) as rn from sxt.t where a >= 3
+    let plan: DynProofPlan = row_number(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        gte(column(t, "a", &accessor), const_bigint(3)),
+        "a",
+        OrderByDirection::Asc,
+        "rn",
+    );
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    assert!(proof
+        .verify(&plan, &accessor, &result, &verifier_setup)
+        .is_ok());
+
+    for (a, rn) in [
+        // a row number is changed
+        ([3, 4, 5, 6, 9], [1, 2, 4, 4, 5]),
+        // row numbers that do not start from 1
+        ([3, 4, 5, 6, 9], [0, 1, 2, 3, 4]),
+        // the rows are numbered out of order
+        ([3, 5, 4, 6, 9], [1, 2, 3, 4, 5]),
+        // a selected row is replaced
+        ([3, 4, 5, 6, 10], [1, 2, 3, 4, 5]),
+    ] {
+        let tampered = tampered_result(&[Column::BigInt(&a), Column::BigInt(&rn)]);
+        assert!(proof
+            .verify(&plan, &accessor, &tampered, &verifier_setup)
+            .is_err());
+    }
+    // a selected row is dropped
+    let tampered = tampered_result(&[Column::BigInt(&[3, 4, 5, 6]), Column::BigInt(&[1, 2, 3, 4])]);
+    assert!(proof
+        .verify(&plan, &accessor, &tampered, &verifier_setup)
+        .is_err());
+    // the row numbers are not in the result
+    let tampered = tampered_result(&[Column::<DoryScalar>::BigInt(&[3, 4, 5, 6, 9])]);
+    assert!(proof
+        .verify(&plan, &accessor, &tampered, &verifier_setup)
+        .is_err());
+}
+
+#[test]
+fn we_cannot_verify_row_numbers_below_the_top_level_of_a_query_plan() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = accessor(t, setup);
+    let plan = row_number(
+        cols_expr_plan(t, &["a"], &accessor),
+        tab(t),
+        column(t, "c", &accessor),
+        "a",
+        OrderByDirection::Asc,
+        "rn",
+    );
+
+    // Without the result, as for a nested plan, there is nothing to check the row numbers against.
+    let mut builder = VerificationBuilder::<DoryScalar>::new(
+        0,
+        SumcheckMleEvaluations::default(),
+        &[],
+        &[],
+        &[],
+        Vec::new(),
+        Vec::new(),
+    );
+    assert!(matches!(
+        plan.verifier_evaluate(
+            &mut builder,
+            &IndexMap::default(),
+            None,
+            &IndexMap::default()
+        ),
+        Err(ProofError::VerificationError { .. })
+    ));
+}
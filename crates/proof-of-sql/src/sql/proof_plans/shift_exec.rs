@@ -0,0 +1,430 @@
+use super::{
+    filter_exec::{prove_filter, verify_filter},
+    group_by_rollup::{default_column, is_default_value},
+    row_number_exec::{is_sorted, is_strictly_sorted, sorted_selected_indexes},
+};
+use crate::{
+    base::{
+        database::{
+            filter_util::filter_column_by_index, union_util::column_union, Column, ColumnField,
+            ColumnRef, ColumnType, OwnedColumn, OwnedTable, Table, TableEvaluation, TableOptions,
+            TableRef,
+        },
+        map::{IndexMap, IndexSet},
+        proof::ProofError,
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{
+            CountBuilder, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+            VerificationBuilder,
+        },
+        proof_exprs::{AliasedDynProofExpr, DynProofExpr, ProofExpr, TableExpr},
+    },
+};
+use alloc::{vec, vec::Vec};
+use bumpalo::Bump;
+use core::iter::repeat_with;
+use proof_of_sql_parser::{
+    intermediate_ast::{OrderBy, ShiftDirection},
+    Identifier,
+};
+use serde::{Deserialize, Serialize};
+
+/// Provable expressions for queries of the form
+/// ```ignore
+///     SELECT <result_expr1>, ..., <result_exprN>,
+///         LAG(<source>) OVER (ORDER BY <order_by>) AS <shift_alias>
+///     FROM <table>
+///     WHERE <where_clause>
+/// ```
+/// or the same with `LEAD`.
+///
+/// The selected rows are sorted by the result column named by `order_by`, like in [`RowNumberExec`](super::RowNumberExec).
+/// The shifted column holds the value of the result column `source` in the previous row for `LAG`, or the next row
+/// for `LEAD`. Columns are not nullable, so the first row of `LAG` and the last row of `LEAD`, which have no such row,
+/// hold the default value of the type instead, and the boolean column `is_null_alias` is `true` exactly in that row.
+///
+/// The proof shows that the rows of the result are a permutation of the selected rows, with the same argument as
+/// [`FilterExec`](super::FilterExec). The result is sent in the clear, so the verifier checks the rest directly:
+/// that its keys are strictly sorted, and that each shifted value is the source value of the adjacent row.
+///
+/// As for [`RowNumberExec`](super::RowNumberExec), the keys of the selected rows must be distinct. Any order of rows
+/// with equal keys would verify and change their shifted values, so results with equal keys are rejected.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShiftExec {
+    pub(super) aliased_results: Vec<AliasedDynProofExpr>,
+    pub(super) table: TableExpr,
+    pub(super) where_clause: DynProofExpr,
+    pub(super) order_by: OrderBy,
+    pub(super) direction: ShiftDirection,
+    pub(super) source: Identifier,
+    pub(super) shift_alias: Identifier,
+    pub(super) is_null_alias: Identifier,
+}
+
+impl ShiftExec {
+    /// Creates a new shift expression.
+    ///
+    /// `order_by.expr` and `source` are the aliases of the result columns to sort by and to shift.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        aliased_results: Vec<AliasedDynProofExpr>,
+        table: TableExpr,
+        where_clause: DynProofExpr,
+        order_by: OrderBy,
+        direction: ShiftDirection,
+        source: Identifier,
+        shift_alias: Identifier,
+        is_null_alias: Identifier,
+    ) -> Self {
+        Self {
+            aliased_results,
+            table,
+            where_clause,
+            order_by,
+            direction,
+            source,
+            shift_alias,
+            is_null_alias,
+        }
+    }
+
+    /// The position of the source column among the result columns
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` is not the alias of a result column.
+    fn source_index(&self) -> usize {
+        self.aliased_results
+            .iter()
+            .position(|aliased_expr| aliased_expr.alias == self.source)
+            .expect("the shifted column should be a result column")
+    }
+
+    /// The row without an adjacent row among `num_rows` rows, if there are any rows
+    fn boundary_row(&self, num_rows: usize) -> Option<usize> {
+        match self.direction {
+            ShiftDirection::Lag => (num_rows > 0).then_some(0),
+            ShiftDirection::Lead => num_rows.checked_sub(1),
+        }
+    }
+
+    /// The row whose source value is shifted into `row`, which must not be the boundary row
+    fn adjacent_row(&self, row: usize) -> usize {
+        match self.direction {
+            ShiftDirection::Lag => row - 1,
+            ShiftDirection::Lead => row + 1,
+        }
+    }
+
+    /// The selected rows sorted by their keys, the shifted source column, and the flags of its null row
+    ///
+    /// # Panics
+    ///
+    /// Panics if the default value of the source column cannot be joined with its values, which cannot happen.
+    fn sort_and_shift<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        columns: &[Column<'a, S>],
+        selection: &[bool],
+    ) -> (Vec<Column<'a, S>>, Column<'a, S>, &'a [bool]) {
+        let indexes =
+            sorted_selected_indexes(&self.aliased_results, &self.order_by, columns, selection);
+        let sorted_columns = columns
+            .iter()
+            .map(|column| filter_column_by_index(alloc, column, &indexes))
+            .collect();
+        let source = &columns[self.source_index()];
+        let num_rows = indexes.len();
+        let boundary_row = self.boundary_row(num_rows);
+        let shifted = match boundary_row {
+            None => filter_column_by_index(alloc, source, &indexes),
+            Some(_) => {
+                let default = default_column(alloc, source.column_type(), 1);
+                let shifted = match self.direction {
+                    ShiftDirection::Lag => {
+                        filter_column_by_index(alloc, source, &indexes[..num_rows - 1])
+                    }
+                    ShiftDirection::Lead => filter_column_by_index(alloc, source, &indexes[1..]),
+                };
+                let pieces = match self.direction {
+                    ShiftDirection::Lag => [&default, &shifted],
+                    ShiftDirection::Lead => [&shifted, &default],
+                };
+                column_union(&pieces, alloc, source.column_type())
+                    .expect("the default value should have the type of the column")
+            }
+        };
+        let is_null = alloc.alloc_slice_fill_with(num_rows, |row| Some(row) == boundary_row);
+        (sorted_columns, shifted, is_null)
+    }
+
+    /// The result table made of the sorted columns, the shifted column, and its null flags
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns do not all have as many rows as there are flags.
+    fn result_table<'a, S: Scalar>(
+        &self,
+        sorted_columns: Vec<Column<'a, S>>,
+        shifted: Column<'a, S>,
+        is_null: &'a [bool],
+    ) -> Table<'a, S> {
+        Table::<'a, S>::try_from_iter_with_options(
+            self.get_column_result_fields()
+                .into_iter()
+                .map(|field| field.name())
+                .zip(
+                    sorted_columns
+                        .into_iter()
+                        .chain([shifted, Column::Boolean(is_null)]),
+                ),
+            TableOptions::new(Some(is_null.len())),
+        )
+        .expect("Failed to create table from iterator")
+    }
+
+    /// Check that the keys of the result are strictly sorted and that the shifted column is the source column
+    /// of the adjacent rows, with the default value and a null flag in the row without one.
+    fn verify_sorted_shift<S: Scalar>(&self, table: &OwnedTable<S>) -> Result<(), ProofError> {
+        let columns = table.inner_table();
+        let (Some(key), Some(source), Some(shifted), Some(OwnedColumn::Boolean(is_null))) = (
+            columns.get(&self.order_by.expr),
+            columns.get(&self.source),
+            columns.get(&self.shift_alias),
+            columns.get(&self.is_null_alias),
+        ) else {
+            Err(ProofError::VerificationError {
+                error:
+                    "Result does not contain the shift key, source, shifted column and null flags.",
+            })?
+        };
+        let num_rows = table.num_rows();
+        if !is_sorted(key, self.order_by.direction, num_rows) {
+            Err(ProofError::VerificationError {
+                error: "Result of shift not ordered as expected.",
+            })?;
+        }
+        if !is_strictly_sorted(key, self.order_by.direction, num_rows) {
+            Err(ProofError::VerificationError {
+                error: "Result of shift has rows with equal keys.",
+            })?;
+        }
+        if source.column_type() != shifted.column_type() {
+            Err(ProofError::VerificationError {
+                error: "Shifted column does not have the type of its source.",
+            })?;
+        }
+        let boundary_row = self.boundary_row(num_rows);
+        let is_shifted_correctly = |row: usize| {
+            if Some(row) == boundary_row {
+                is_null[row] && shifted.value_at(row).as_ref().is_some_and(is_default_value)
+            } else {
+                !is_null[row] && shifted.value_at(row) == source.value_at(self.adjacent_row(row))
+            }
+        };
+        if !(0..num_rows).all(is_shifted_correctly) {
+            Err(ProofError::VerificationError {
+                error: "Shifted column does not hold the values of the adjacent rows.",
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl ProofPlan for ShiftExec {
+    fn count(&self, builder: &mut CountBuilder) -> Result<(), ProofError> {
+        self.where_clause.count(builder)?;
+        for aliased_expr in &self.aliased_results {
+            aliased_expr.expr.count(builder)?;
+            builder.count_intermediate_mles(1);
+        }
+        // For the shifted column and the null flags
+        builder.count_intermediate_mles(2);
+        builder.count_intermediate_mles(2);
+        builder.count_subpolynomials(3);
+        builder.count_degree(3);
+        builder.count_post_result_challenges(2);
+        Ok(())
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut VerificationBuilder<S>,
+        accessor: &IndexMap<ColumnRef, S>,
+        result: Option<&OwnedTable<S>>,
+        one_eval_map: &IndexMap<TableRef, S>,
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        // The shifted values are checked against the result, which the verifier only has for the top level plan
+        let Some(table) = result else {
+            return Err(ProofError::VerificationError {
+                error: "ShiftExec is only supported at the top level of a query plan",
+            });
+        };
+        let input_one_eval = *one_eval_map
+            .get(&self.table.table_ref)
+            .expect("One eval not found");
+        // 1. selection
+        let selection_eval =
+            self.where_clause
+                .verifier_evaluate(builder, accessor, input_one_eval)?;
+        // 2. columns
+        let columns_evals = self
+            .aliased_results
+            .iter()
+            .map(|aliased_expr| {
+                aliased_expr
+                    .expr
+                    .verifier_evaluate(builder, accessor, input_one_eval)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // 3. sorted columns
+        let sorted_columns_evals: Vec<_> = repeat_with(|| builder.consume_intermediate_mle())
+            .take(self.aliased_results.len())
+            .collect();
+        let shifted_eval = builder.consume_intermediate_mle();
+        let is_null_eval = builder.consume_intermediate_mle();
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+
+        let output_one_eval = builder.consume_one_evaluation();
+
+        verify_filter(
+            builder,
+            alpha,
+            beta,
+            input_one_eval,
+            output_one_eval,
+            &columns_evals,
+            selection_eval,
+            &sorted_columns_evals,
+        )?;
+        self.verify_sorted_shift(table)?;
+
+        let output_evals = sorted_columns_evals
+            .into_iter()
+            .chain([shifted_eval, is_null_eval])
+            .collect();
+        Ok(TableEvaluation::new(output_evals, output_one_eval))
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        let source_type = self.aliased_results[self.source_index()].expr.data_type();
+        self.aliased_results
+            .iter()
+            .map(|aliased_expr| ColumnField::new(aliased_expr.alias, aliased_expr.expr.data_type()))
+            .chain([
+                ColumnField::new(self.shift_alias, source_type),
+                ColumnField::new(self.is_null_alias, ColumnType::Boolean),
+            ])
+            .collect()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        let mut columns = IndexSet::default();
+
+        for aliased_expr in &self.aliased_results {
+            aliased_expr.expr.get_column_references(&mut columns);
+        }
+
+        self.where_clause.get_column_references(&mut columns);
+
+        columns
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        IndexSet::from_iter([self.table.table_ref])
+    }
+}
+
+impl ProverEvaluate for ShiftExec {
+    #[tracing::instrument(name = "ShiftExec::result_evaluate", level = "debug", skip_all)]
+    fn result_evaluate<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> (Table<'a, S>, Vec<usize>) {
+        let table = table_map
+            .get(&self.table.table_ref)
+            .expect("Table not found");
+        // 1. selection
+        let selection_column: Column<'a, S> = self.where_clause.result_evaluate(alloc, table);
+        let selection = selection_column
+            .as_boolean()
+            .expect("selection is not boolean");
+
+        // 2. columns
+        let columns: Vec<_> = self
+            .aliased_results
+            .iter()
+            .map(|aliased_expr| aliased_expr.expr.result_evaluate(alloc, table))
+            .collect();
+
+        // 3. sort the selected rows and shift the source column
+        let (sorted_columns, shifted, is_null) = self.sort_and_shift(alloc, &columns, selection);
+        let output_length = is_null.len();
+        (
+            self.result_table(sorted_columns, shifted, is_null),
+            vec![output_length],
+        )
+    }
+
+    fn first_round_evaluate(&self, builder: &mut FirstRoundBuilder) {
+        builder.request_post_result_challenges(2);
+    }
+
+    #[tracing::instrument(name = "ShiftExec::final_round_evaluate", level = "debug", skip_all)]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> Table<'a, S> {
+        let table = table_map
+            .get(&self.table.table_ref)
+            .expect("Table not found");
+        // 1. selection
+        let selection_column: Column<'a, S> =
+            self.where_clause.prover_evaluate(builder, alloc, table);
+        let selection = selection_column
+            .as_boolean()
+            .expect("selection is not boolean");
+
+        // 2. columns
+        let columns: Vec<_> = self
+            .aliased_results
+            .iter()
+            .map(|aliased_expr| aliased_expr.expr.prover_evaluate(builder, alloc, table))
+            .collect();
+
+        // 3. sort the selected rows and shift the source column
+        let (sorted_columns, shifted, is_null) = self.sort_and_shift(alloc, &columns, selection);
+        let output_length = is_null.len();
+
+        // 4. Produce MLEs
+        sorted_columns.iter().copied().for_each(|column| {
+            builder.produce_intermediate_mle(column);
+        });
+        builder.produce_intermediate_mle(shifted);
+        builder.produce_intermediate_mle(Column::Boolean(is_null));
+
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+
+        // 5. Prove that the sorted rows are the selected rows
+        prove_filter::<S>(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            &columns,
+            selection,
+            &sorted_columns,
+            table.num_rows(),
+            output_length,
+        );
+        self.result_table(sorted_columns, shifted, is_null)
+    }
+}
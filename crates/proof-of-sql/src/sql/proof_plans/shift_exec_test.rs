@@ -0,0 +1,293 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::{
+        database::{owned_table_utility::*, Column, OwnedTableTestAccessor, TableRef},
+        map::IndexMap,
+        proof::ProofError,
+    },
+    proof_primitive::dory::{
+        test_rng, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        proof::{
+            ProofPlan, ProvableQueryResult, QueryError, QueryProof, SumcheckMleEvaluations,
+            VerificationBuilder,
+        },
+        proof_exprs::test_utility::*,
+    },
+};
+use proof_of_sql_parser::{
+    intermediate_ast::{OrderByDirection, ShiftDirection},
+    Identifier,
+};
+
+fn accessor(
+    t: TableRef,
+    setup: DoryProverPublicSetup<'_>,
+) -> OwnedTableTestAccessor<'_, DoryEvaluationProof> {
+    OwnedTableTestAccessor::new_from_table(
+        t,
+        owned_table([
+            bigint("ts", [3_i64, 1, 4, 2, 5, 9, 7, 6]),
+            bigint("price", [30_i64, 10, 40, 20, 50, 90, 70, 60]),
+            varchar("name", ["c", "a", "d", "b", "e", "i", "g", "f"]),
+            boolean("keep", [true, true, true, true, true, true, false, true]),
+        ]),
+        0,
+        setup,
+    )
+}
+
+/// Prove `plan` and verify it, replacing the result with `tampered` if it is given
+fn prove_and_verify(
+    plan: impl Fn(TableRef, &OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan,
+    tampered: Option<&[Column<DoryScalar>]>,
+) -> Result<crate::base::database::OwnedTable<DoryScalar>, QueryError> {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = accessor(t, setup);
+    let plan = plan(t, &accessor);
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::try_new(&plan, &accessor, &setup).unwrap();
+    let result = match tampered {
+        Some(columns) => ProvableQueryResult::new(columns[0].len() as u64, columns),
+        None => result,
+    };
+    proof
+        .verify(&plan, &accessor, &result, &verifier_setup)
+        .map(|res| res.table)
+}
+
+// select ts, price, lag(price) over (order by ts) as prev_price from sxt.t where keep
+fn lag_plan(t: TableRef, accessor: &OwnedTableTestAccessor<DoryEvaluationProof>) -> DynProofPlan {
+    shift(
+        cols_expr_plan(t, &["ts", "price"], accessor),
+        tab(t),
+        column(t, "keep", accessor),
+        "ts",
+        OrderByDirection::Asc,
+        ShiftDirection::Lag,
+        "price",
+        "prev_price",
+        "prev_price_is_null",
+    )
+}
+
+#[test]
+fn we_can_prove_lag_as_a_shift_of_the_sorted_rows() {
+    let table = prove_and_verify(lag_plan, None).unwrap();
+    let sorted_price = [10_i64, 20, 30, 40, 50, 60, 90];
+    let expected = owned_table([
+        bigint("ts", [1_i64, 2, 3, 4, 5, 6, 9]),
+        bigint("price", sorted_price),
+        bigint("prev_price", [0_i64, 10, 20, 30, 40, 50, 60]),
+        boolean(
+            "prev_price_is_null",
+            [true, false, false, false, false, false, false],
+        ),
+    ]);
+    assert_eq!(table, expected);
+    // The shifted column is a manual shift of the sorted source column.
+    let shifted: Vec<_> = core::iter::once(0)
+        .chain(sorted_price[..6].iter().copied())
+        .collect();
+    assert_eq!(
+        table.inner_table()[&"prev_price".parse::<Identifier>().unwrap()],
+        bigint("prev_price", shifted).1
+    );
+}
+
+#[test]
+fn we_can_prove_lead_in_descending_order_over_a_varchar() {
+    // select name, ts, lead(name) over (order by ts desc) as next_name from sxt.t where ts >= 4
+    let plan = |t: TableRef, accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+        shift(
+            cols_expr_plan(t, &["name", "ts"], accessor),
+            tab(t),
+            gte(column(t, "ts", accessor), const_bigint(4)),
+            "ts",
+            OrderByDirection::Desc,
+            ShiftDirection::Lead,
+            "name",
+            "next_name",
+            "next_name_is_null",
+        )
+    };
+    let table = prove_and_verify(plan, None).unwrap();
+    let expected = owned_table([
+        varchar("name", ["i", "g", "f", "e", "d"]),
+        bigint("ts", [9_i64, 7, 6, 5, 4]),
+        varchar("next_name", ["g", "f", "e", "d", ""]),
+        boolean("next_name_is_null", [false, false, false, false, true]),
+    ]);
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn we_can_prove_a_shift_of_one_row_and_of_an_empty_selection() {
+    for (threshold, expected) in [
+        (
+            9,
+            owned_table([
+                bigint("ts", [9_i64]),
+                bigint("price", [90_i64]),
+                bigint("prev_price", [0_i64]),
+                boolean("prev_price_is_null", [true]),
+            ]),
+        ),
+        (
+            10,
+            owned_table([
+                bigint("ts", [0_i64; 0]),
+                bigint("price", [0_i64; 0]),
+                bigint("prev_price", [0_i64; 0]),
+                boolean("prev_price_is_null", [false; 0]),
+            ]),
+        ),
+    ] {
+        let plan = |t: TableRef, accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+            shift(
+                cols_expr_plan(t, &["ts", "price"], accessor),
+                tab(t),
+                gte(column(t, "ts", accessor), const_bigint(threshold)),
+                "ts",
+                OrderByDirection::Asc,
+                ShiftDirection::Lag,
+                "price",
+                "prev_price",
+                "prev_price_is_null",
+            )
+        };
+        assert_eq!(prove_and_verify(plan, None).unwrap(), expected);
+    }
+}
+
+#[test]
+fn we_cannot_verify_a_shift_that_is_tampered_with() {
+    let ts = [1_i64, 2, 3, 4, 5, 6, 9];
+    let price = [10_i64, 20, 30, 40, 50, 60, 90];
+    let prev_price = [0_i64, 10, 20, 30, 40, 50, 60];
+    let is_null = [true, false, false, false, false, false, false];
+    // The honest result verifies when passed in as a replacement.
+    assert!(prove_and_verify(
+        lag_plan,
+        Some(&[
+            Column::BigInt(&ts),
+            Column::BigInt(&price),
+            Column::BigInt(&prev_price),
+            Column::Boolean(&is_null),
+        ])
+    )
+    .is_ok());
+    for (ts, price, prev_price, is_null) in [
+        // a shifted value is changed
+        (ts, price, [0, 10, 20, 35, 40, 50, 60], is_null),
+        // the values are not shifted
+        (ts, price, price, [false; 7]),
+        // the values are shifted the wrong way
+        (ts, price, [20, 30, 40, 50, 60, 90, 0], is_null),
+        // the boundary is not null
+        (ts, price, prev_price, [false; 7]),
+        // the boundary does not hold the default value
+        (ts, price, [5, 10, 20, 30, 40, 50, 60], is_null),
+        // another row is null
+        (
+            ts,
+            price,
+            prev_price,
+            [true, false, false, true, false, false, false],
+        ),
+        // the rows are out of order
+        (
+            [2, 1, 3, 4, 5, 6, 9],
+            [20, 10, 30, 40, 50, 60, 90],
+            [0, 20, 10, 30, 40, 50, 60],
+            is_null,
+        ),
+        // a selected row is replaced, consistently with the shift
+        (ts, [10, 20, 30, 40, 50, 60, 95], prev_price, is_null),
+    ] {
+        assert!(prove_and_verify(
+            lag_plan,
+            Some(&[
+                Column::BigInt(&ts),
+                Column::BigInt(&price),
+                Column::BigInt(&prev_price),
+                Column::Boolean(&is_null),
+            ])
+        )
+        .is_err());
+    }
+    // the null flags are not in the result
+    assert!(prove_and_verify(
+        lag_plan,
+        Some(&[
+            Column::BigInt(&ts),
+            Column::BigInt(&price),
+            Column::BigInt(&prev_price),
+        ])
+    )
+    .is_err());
+}
+
+// select keep, price, lag(price) over (order by keep) as prev_price from sxt.t where ts >= 4
+#[test]
+fn we_cannot_verify_a_shift_of_rows_with_equal_keys() {
+    let plan = |t: TableRef, accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+        shift(
+            cols_expr_plan(t, &["keep", "price"], accessor),
+            tab(t),
+            gte(column(t, "ts", accessor), const_bigint(4)),
+            "keep",
+            OrderByDirection::Asc,
+            ShiftDirection::Lag,
+            "price",
+            "prev_price",
+            "prev_price_is_null",
+        )
+    };
+    // The order of the rows with equal keys, and so their shifted values, would not be fixed by the proof.
+    assert!(matches!(
+        prove_and_verify(plan, None),
+        Err(QueryError::ProofError {
+            source: ProofError::VerificationError {
+                error: "Result of shift has rows with equal keys."
+            }
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_verify_shifted_values_below_the_top_level_of_a_query_plan() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let t: TableRef = "sxt.t".parse().unwrap();
+    let accessor = accessor(t, setup);
+    let plan = lag_plan(t, &accessor);
+
+    // Without the result, as for a nested plan, there is nothing to check the shifted values against.
+    let mut builder = VerificationBuilder::<DoryScalar>::new(
+        0,
+        SumcheckMleEvaluations::default(),
+        &[],
+        &[],
+        &[],
+        Vec::new(),
+        Vec::new(),
+    );
+    assert!(matches!(
+        plan.verifier_evaluate(
+            &mut builder,
+            &IndexMap::default(),
+            None,
+            &IndexMap::default()
+        ),
+        Err(ProofError::VerificationError { .. })
+    ));
+}
@@ -1,8 +1,11 @@
-use super::{DynProofPlan, FilterExec, GroupByExec, ProjectionExec, TableExec};
+use super::{
+    DynProofPlan, FilterExec, GroupByExec, ProjectionExec, RowNumberExec, ShiftExec, TableExec,
+};
 use crate::{
     base::database::{ColumnField, TableRef},
     sql::proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, TableExpr},
 };
+use proof_of_sql_parser::intermediate_ast::{OrderBy, OrderByDirection, ShiftDirection};
 
 pub fn table_exec(table_ref: TableRef, schema: Vec<ColumnField>) -> DynProofPlan {
     DynProofPlan::Table(TableExec::new(table_ref, schema))
@@ -38,3 +41,108 @@ pub fn group_by(
         where_clause,
     ))
 }
+
+/// Group by aliased expressions rather than columns
+///
+/// # Panics
+///
+/// Will panic if `count_alias` cannot be parsed as a valid identifier.
+pub fn group_by_key_exprs(
+    group_by_exprs: Vec<AliasedDynProofExpr>,
+    sum_expr: Vec<AliasedDynProofExpr>,
+    count_alias: &str,
+    table: TableExpr,
+    where_clause: DynProofExpr,
+) -> DynProofPlan {
+    DynProofPlan::GroupBy(GroupByExec::new_with_key_exprs(
+        group_by_exprs,
+        sum_expr,
+        count_alias.parse().unwrap(),
+        table,
+        where_clause,
+    ))
+}
+
+/// Group by every prefix of the keys, with one grouping flag column per key
+///
+/// # Panics
+///
+/// Will panic if `count_alias` or any of `grouping_aliases` cannot be parsed as a valid identifier,
+/// or if there is not exactly one grouping alias per key.
+pub fn group_by_rollup(
+    group_by_exprs: Vec<ColumnExpr>,
+    sum_expr: Vec<AliasedDynProofExpr>,
+    count_alias: &str,
+    grouping_aliases: &[&str],
+    table: TableExpr,
+    where_clause: DynProofExpr,
+) -> DynProofPlan {
+    DynProofPlan::GroupBy(
+        GroupByExec::new(
+            group_by_exprs,
+            sum_expr,
+            count_alias.parse().unwrap(),
+            table,
+            where_clause,
+        )
+        .with_rollup(
+            grouping_aliases
+                .iter()
+                .map(|alias| alias.parse().unwrap())
+                .collect(),
+        ),
+    )
+}
+
+/// # Panics
+///
+/// Will panic if `order_by` or `row_number_alias` cannot be parsed as a valid identifier.
+pub fn row_number(
+    results: Vec<AliasedDynProofExpr>,
+    table: TableExpr,
+    where_clause: DynProofExpr,
+    order_by: &str,
+    direction: OrderByDirection,
+    row_number_alias: &str,
+) -> DynProofPlan {
+    DynProofPlan::RowNumber(RowNumberExec::new(
+        results,
+        table,
+        where_clause,
+        OrderBy {
+            expr: order_by.parse().unwrap(),
+            direction,
+        },
+        row_number_alias.parse().unwrap(),
+    ))
+}
+
+/// # Panics
+///
+/// Will panic if `order_by`, `source`, `shift_alias` or `is_null_alias` cannot be parsed as a valid identifier.
+#[allow(clippy::too_many_arguments)]
+pub fn shift(
+    results: Vec<AliasedDynProofExpr>,
+    table: TableExpr,
+    where_clause: DynProofExpr,
+    order_by: &str,
+    order_by_direction: OrderByDirection,
+    direction: ShiftDirection,
+    source: &str,
+    shift_alias: &str,
+    is_null_alias: &str,
+) -> DynProofPlan {
+    DynProofPlan::Shift(ShiftExec::new(
+        results,
+        table,
+        where_clause,
+        OrderBy {
+            expr: order_by.parse().unwrap(),
+            direction: order_by_direction,
+        },
+        direction,
+        source.parse().unwrap(),
+        shift_alias.parse().unwrap(),
+        is_null_alias.parse().unwrap(),
+    ))
+}
@@ -1,3 +1,8 @@
 //! This module contains utilities for working with the library
 /// Parse DDLs and find bigdecimal columns
 pub mod parse;
+/// Generate and verify canonical test vectors for other verifier implementations
+#[cfg(feature = "test")]
+pub mod test_vectors;
+#[cfg(all(test, feature = "test"))]
+mod test_vectors_test;
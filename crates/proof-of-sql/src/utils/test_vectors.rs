@@ -0,0 +1,567 @@
+//! Canonical test vectors for checking that other verifier implementations agree with this one.
+//!
+//! A [`TestVectorSuite`] is a deterministic set of Dory proofs over a fixed table, one or more for each
+//! supported operator, along with the outcome that a conforming verifier must reach for each of them.
+//! The format is documented in `utils/test-vectors/README.md`.
+use crate::{
+    base::{
+        commitment::{QueryCommitments, QueryCommitmentsExt},
+        database::{
+            owned_table_utility::{bigint, boolean, decimal75, owned_table, varchar},
+            OwnedTable, OwnedTableTestAccessor, TableRef,
+        },
+        map::IndexMap,
+    },
+    proof_primitive::dory::{
+        DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar,
+        DoryVerifierPublicSetup, ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        parse::QueryExpr,
+        proof::{ProvableQueryResult, QueryProof},
+        proof_plans::DynProofPlan,
+    },
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+use std::{
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+};
+
+/// The version of the test vector format, which is bumped whenever a change to it would break a loader.
+pub const TEST_VECTOR_FORMAT_VERSION: u32 = 1;
+
+/// The seed of the `ChaCha20` generator of the public parameters of the suite
+pub const TEST_VECTOR_SEED: [u8; 32] = *b"proof-of-sql conformance vectors";
+
+/// The `max_nu` of the public parameters of the suite
+pub const TEST_VECTOR_MAX_NU: usize = 4;
+
+/// The `sigma` of the Dory setups of the suite
+pub const TEST_VECTOR_SIGMA: usize = 3;
+
+/// The commitment scheme of the suite
+const DORY_COMMITMENT_SCHEME: &str = "dory";
+
+/// The default schema of the queries of the suite
+const DEFAULT_SCHEMA: &str = "sxt";
+
+/// Errors that can occur when loading or verifying test vectors.
+#[derive(Debug, Snafu)]
+pub enum TestVectorError {
+    /// The suite is not valid JSON or does not have the expected fields.
+    #[snafu(display("failed to parse test vectors: {error}"))]
+    InvalidJson {
+        /// The underlying JSON error
+        error: serde_json::Error,
+    },
+    /// The suite was written with an unsupported format version.
+    #[snafu(display("unsupported test vector format version: {version}"))]
+    UnsupportedVersion {
+        /// The version found in the suite
+        version: u32,
+    },
+    /// The suite uses a commitment scheme other than Dory.
+    #[snafu(display("unsupported commitment scheme: {commitment_scheme}"))]
+    UnsupportedCommitmentScheme {
+        /// The commitment scheme found in the suite
+        commitment_scheme: String,
+    },
+    /// The verifier setup of the suite could not be deserialized.
+    #[snafu(display("failed to deserialize the verifier setup"))]
+    InvalidVerifierSetup,
+    /// The plan or the commitments of a vector could not be deserialized.
+    #[snafu(display("test vector {name} is malformed"))]
+    MalformedVector {
+        /// The name of the vector
+        name: String,
+    },
+    /// A vector that should be accepted was rejected.
+    #[snafu(display("test vector {name} should be accepted, but was rejected: {reason}"))]
+    UnexpectedRejection {
+        /// The name of the vector
+        name: String,
+        /// Why the vector was rejected
+        reason: String,
+    },
+    /// A vector that should be rejected was accepted.
+    #[snafu(display("test vector {name} should be rejected, but was accepted"))]
+    UnexpectedAcceptance {
+        /// The name of the vector
+        name: String,
+    },
+    /// A vector was accepted, but with a result other than the expected one.
+    #[snafu(display("test vector {name} was accepted with an unexpected result"))]
+    ResultMismatch {
+        /// The name of the vector
+        name: String,
+    },
+}
+
+/// The outcome that a conforming verifier must reach for a [`TestVector`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ExpectedOutcome {
+    /// The proof verifies, and the verified result is `table`
+    Accept {
+        /// The verified result, before any postprocessing
+        table: OwnedTable<DoryScalar>,
+    },
+    /// The proof does not verify
+    Reject,
+}
+
+/// A single proof along with everything needed to verify it.
+///
+/// The binary fields are the bytes that the verifier consumes, so that an implementation in another
+/// language does not need to reproduce the planner. They are hex encoded in JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVector {
+    /// A unique name describing what the vector covers
+    pub name: String,
+    /// The query that was planned into `plan`
+    pub sql: String,
+    /// The schema of the unqualified tables of `sql`
+    pub default_schema: String,
+    /// The `postcard` encoding of the [`QueryCommitments`] of the columns referenced by `plan`
+    #[serde(with = "hex_bytes")]
+    pub commitments: Vec<u8>,
+    /// The `postcard` encoding of the [`DynProofPlan`] of `sql`, which is also the first message of the transcript
+    #[serde(with = "hex_bytes")]
+    pub plan: Vec<u8>,
+    /// The `postcard` encoding of the [`QueryProof`]
+    #[serde(with = "hex_bytes")]
+    pub proof: Vec<u8>,
+    /// The `postcard` encoding of the [`ProvableQueryResult`]
+    #[serde(with = "hex_bytes")]
+    pub result: Vec<u8>,
+    /// Whether the proof should verify, and if so, its verified result
+    pub expected: ExpectedOutcome,
+}
+
+/// A suite of [`TestVector`]s that share a Dory setup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectorSuite {
+    /// The format version, which is [`TEST_VECTOR_FORMAT_VERSION`]
+    pub version: u32,
+    /// The commitment scheme, which is always `dory`
+    pub commitment_scheme: String,
+    /// The seed of the `ChaCha20` generator passed to [`PublicParameters::test_rand`]
+    #[serde(with = "hex_bytes")]
+    pub seed: Vec<u8>,
+    /// The `max_nu` of the public parameters
+    pub max_nu: usize,
+    /// The `sigma` of the prover and verifier setups
+    pub sigma: usize,
+    /// The compressed arkworks encoding of the [`VerifierSetup`]
+    #[serde(with = "hex_bytes")]
+    pub verifier_setup: Vec<u8>,
+    /// The tables that the proofs were generated from. Verifiers do not need these.
+    pub tables: IndexMap<TableRef, OwnedTable<DoryScalar>>,
+    /// The vectors of the suite
+    pub vectors: Vec<TestVector>,
+}
+
+/// The prices of the table of the suite
+const PRICES: [i64; 8] = [1050, -299, 0, 1999, 250, 100_000, -1, 42];
+
+/// The table that every vector of the suite is proven against, with the given prices
+fn test_vector_table(prices: [i64; 8]) -> OwnedTable<DoryScalar> {
+    owned_table([
+        bigint("id", [1, 2, 3, 4, 5, 6, 7, 8]),
+        bigint("g", [1, 2, 1, 3, 2, 1, 3, 2]),
+        bigint("score", [90, 75, 95, 60, 80, 40, 75, 55]),
+        varchar(
+            "name",
+            [
+                "Ada", "Alan", "Grace", "Edsger", "Barbara", "Ada", "Donald", "Grace",
+            ],
+        ),
+        boolean(
+            "active",
+            [true, false, true, true, false, true, false, true],
+        ),
+        decimal75("price", 10, 2, prices),
+    ])
+}
+
+/// The queries of the accepted vectors, covering each supported operator
+const ACCEPTED_QUERIES: [(&str, &str); 15] = [
+    ("table_scan", "SELECT * FROM table"),
+    ("projection", "SELECT name, id FROM table"),
+    (
+        "filter_equality",
+        "SELECT id, price FROM table WHERE name = 'Ada'",
+    ),
+    (
+        "filter_inequality_and_logic",
+        "SELECT id FROM table WHERE (score >= 60 AND NOT active) OR g = 3",
+    ),
+    (
+        "arithmetic",
+        "SELECT id + score * 2 AS s, price - 1.5 AS p, -id AS n FROM table WHERE id <= 6",
+    ),
+    (
+        "abs",
+        "SELECT ABS(score - 70) AS distance FROM table WHERE active",
+    ),
+    (
+        "string_concatenation",
+        "SELECT name || '!' AS greeting FROM table WHERE id < 4",
+    ),
+    ("empty_result", "SELECT id FROM table WHERE score > 100"),
+    (
+        "group_by",
+        "SELECT g, SUM(score) AS total, COUNT(*) AS num FROM table WHERE active GROUP BY g",
+    ),
+    (
+        "aggregate_filter",
+        "SELECT g, SUM(score) FILTER (WHERE active) AS s, COUNT(*) AS n FROM table GROUP BY g",
+    ),
+    ("count_distinct", "SELECT COUNT(DISTINCT g) AS n FROM table"),
+    (
+        "approx_count_distinct",
+        "SELECT APPROX_COUNT_DISTINCT(score) AS n FROM table",
+    ),
+    (
+        "row_number",
+        "SELECT row_number() OVER (ORDER BY score DESC) AS rank, name FROM table WHERE score >= 60",
+    ),
+    (
+        "lag",
+        "SELECT id, lag(score) OVER (ORDER BY id) AS previous FROM table",
+    ),
+    (
+        "lead",
+        "SELECT lead(name) OVER (ORDER BY score DESC) AS next, score FROM table WHERE active",
+    ),
+];
+
+/// Proves queries against the test vector table
+struct TestVectorProver<'a> {
+    table: OwnedTable<DoryScalar>,
+    accessor: OwnedTableTestAccessor<'a, DoryEvaluationProof>,
+    setup: DoryProverPublicSetup<'a>,
+}
+
+impl<'a> TestVectorProver<'a> {
+    fn new(table: OwnedTable<DoryScalar>, setup: DoryProverPublicSetup<'a>) -> Self {
+        let accessor = OwnedTableTestAccessor::new_from_table(table_ref(), table.clone(), 0, setup);
+        Self {
+            table,
+            accessor,
+            setup,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `sql` is not a provable query against the test vector table.
+    fn query(&self, sql: &str) -> QueryExpr {
+        QueryExpr::try_new(
+            sql.parse().unwrap(),
+            DEFAULT_SCHEMA.parse().unwrap(),
+            &self.accessor,
+        )
+        .unwrap()
+    }
+
+    fn prove(&self, plan: &DynProofPlan) -> (QueryProof<DoryEvaluationProof>, ProvableQueryResult) {
+        QueryProof::new(plan, &self.accessor, &self.setup)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the accessor has duplicate columns, which it never does.
+    fn commitments(&self, plan: &DynProofPlan) -> QueryCommitments<DoryCommitment> {
+        QueryCommitments::from_accessor_for_plan_with_max_bounds(plan, &self.accessor).unwrap()
+    }
+}
+
+/// The only table of the suite
+///
+/// # Panics
+///
+/// Never panics, since the table reference is valid.
+fn table_ref() -> TableRef {
+    "sxt.table".parse().unwrap()
+}
+
+/// # Panics
+///
+/// Panics if the value cannot be serialized, which does not happen for the types of the suite.
+fn to_postcard(value: &impl Serialize) -> Vec<u8> {
+    postcard::to_allocvec(value).expect("test vector fields should serialize")
+}
+
+impl TestVector {
+    /// A vector with the given plan, commitments, proof, and result, each of which may have been tampered with
+    fn new(
+        name: &str,
+        sql: &str,
+        plan: &DynProofPlan,
+        commitments: &QueryCommitments<DoryCommitment>,
+        (proof, result): (&QueryProof<DoryEvaluationProof>, &ProvableQueryResult),
+        expected: ExpectedOutcome,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            sql: sql.to_string(),
+            default_schema: DEFAULT_SCHEMA.to_string(),
+            commitments: to_postcard(commitments),
+            plan: to_postcard(plan),
+            proof: to_postcard(proof),
+            result: to_postcard(result),
+            expected,
+        }
+    }
+
+    /// Verify the vector, returning the verified result, or why it was rejected
+    ///
+    /// A proof or result that cannot be deserialized is rejected, since it comes from the prover.
+    /// A plan or commitments that cannot be deserialized make the vector itself malformed.
+    fn outcome(
+        &self,
+        setup: &DoryVerifierPublicSetup<'_>,
+    ) -> Result<Result<OwnedTable<DoryScalar>, String>, TestVectorError> {
+        let malformed = || TestVectorError::MalformedVector {
+            name: self.name.clone(),
+        };
+        let plan: DynProofPlan = postcard::from_bytes(&self.plan).map_err(|_| malformed())?;
+        let commitments: QueryCommitments<DoryCommitment> =
+            postcard::from_bytes(&self.commitments).map_err(|_| malformed())?;
+        let proof: QueryProof<DoryEvaluationProof> = match postcard::from_bytes(&self.proof) {
+            Ok(proof) => proof,
+            Err(error) => return Ok(Err(format!("invalid proof encoding: {error}"))),
+        };
+        let result: ProvableQueryResult = match postcard::from_bytes(&self.result) {
+            Ok(result) => result,
+            Err(error) => return Ok(Err(format!("invalid result encoding: {error}"))),
+        };
+        Ok(proof
+            .verify(&plan, &commitments, &result, setup)
+            .map(|verified| verified.table)
+            .map_err(|error| error.to_string()))
+    }
+
+    /// Verify the vector, checking that the outcome is the expected one.
+    ///
+    /// # Errors
+    /// Returns an error if the vector is malformed, or if its outcome is not the expected one.
+    pub fn verify(&self, setup: &DoryVerifierPublicSetup<'_>) -> Result<(), TestVectorError> {
+        let name = self.name.clone();
+        match (self.outcome(setup)?, &self.expected) {
+            (Ok(table), ExpectedOutcome::Accept { table: expected }) if table == *expected => {
+                Ok(())
+            }
+            (Ok(_), ExpectedOutcome::Accept { .. }) => {
+                Err(TestVectorError::ResultMismatch { name })
+            }
+            (Err(reason), ExpectedOutcome::Accept { .. }) => {
+                Err(TestVectorError::UnexpectedRejection { name, reason })
+            }
+            (Ok(_), ExpectedOutcome::Reject) => Err(TestVectorError::UnexpectedAcceptance { name }),
+            (Err(_), ExpectedOutcome::Reject) => Ok(()),
+        }
+    }
+}
+
+impl TestVectorSuite {
+    /// Generate the canonical suite.
+    ///
+    /// The suite is deterministic: the public parameters come from a `ChaCha20` generator seeded with
+    /// [`TEST_VECTOR_SEED`], and proofs use the Keccak-256 Fiat-Shamir transcript, which has no randomness.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the built-in queries cannot be proven, which would be a bug in the suite.
+    #[must_use]
+    pub fn generate() -> Self {
+        let public_parameters = PublicParameters::test_rand(
+            TEST_VECTOR_MAX_NU,
+            &mut ChaCha20Rng::from_seed(TEST_VECTOR_SEED),
+        );
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let verifier_setup = VerifierSetup::from(&public_parameters);
+        let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, TEST_VECTOR_SIGMA);
+        let prover = TestVectorProver::new(test_vector_table(PRICES), dory_prover_setup);
+
+        let mut vectors: Vec<TestVector> = ACCEPTED_QUERIES
+            .iter()
+            .map(|&(name, sql)| {
+                let query = prover.query(sql);
+                let plan = query.proof_expr();
+                let (proof, result) = prover.prove(plan);
+                let table = proof
+                    .verify(
+                        plan,
+                        &prover.accessor,
+                        &result,
+                        &DoryVerifierPublicSetup::new(&verifier_setup, TEST_VECTOR_SIGMA),
+                    )
+                    .expect("the built-in queries should verify")
+                    .table;
+                TestVector::new(
+                    name,
+                    sql,
+                    plan,
+                    &prover.commitments(plan),
+                    (&proof, &result),
+                    ExpectedOutcome::Accept { table },
+                )
+            })
+            .collect();
+
+        // Each rejected vector tampers with one input of an otherwise honest vector
+        let sql = "SELECT id, price FROM table WHERE name = 'Ada'";
+        let other_sql = "SELECT id, price FROM table WHERE name = 'Grace'";
+        let (query, other_query) = (prover.query(sql), prover.query(other_sql));
+        let (plan, other_plan) = (query.proof_expr(), other_query.proof_expr());
+        let (proof, result) = prover.prove(plan);
+        let (other_proof, other_result) = prover.prove(other_plan);
+        let commitments = prover.commitments(plan);
+        let mut tampered_prices = PRICES;
+        tampered_prices[0] += 1;
+        let tampered_prover =
+            TestVectorProver::new(test_vector_table(tampered_prices), dory_prover_setup);
+        vectors.extend([
+            TestVector::new(
+                "reject_tampered_result",
+                sql,
+                plan,
+                &commitments,
+                (&proof, &other_result),
+                ExpectedOutcome::Reject,
+            ),
+            TestVector::new(
+                "reject_mismatched_plan",
+                other_sql,
+                other_plan,
+                &prover.commitments(other_plan),
+                (&proof, &result),
+                ExpectedOutcome::Reject,
+            ),
+            TestVector::new(
+                "reject_mismatched_proof",
+                sql,
+                plan,
+                &commitments,
+                (&other_proof, &result),
+                ExpectedOutcome::Reject,
+            ),
+            TestVector::new(
+                "reject_tampered_commitments",
+                sql,
+                plan,
+                &tampered_prover.commitments(plan),
+                (&proof, &result),
+                ExpectedOutcome::Reject,
+            ),
+        ]);
+
+        let mut verifier_setup_bytes = Vec::new();
+        verifier_setup
+            .serialize_compressed(&mut verifier_setup_bytes)
+            .expect("the verifier setup should serialize");
+        Self {
+            version: TEST_VECTOR_FORMAT_VERSION,
+            commitment_scheme: DORY_COMMITMENT_SCHEME.to_string(),
+            seed: TEST_VECTOR_SEED.to_vec(),
+            max_nu: TEST_VECTOR_MAX_NU,
+            sigma: TEST_VECTOR_SIGMA,
+            verifier_setup: verifier_setup_bytes,
+            tables: IndexMap::from_iter([(table_ref(), prover.table)]),
+            vectors,
+        }
+    }
+
+    /// Verify every vector of the suite, checking that each has its expected outcome.
+    ///
+    /// # Errors
+    /// Returns an error if the suite has an unsupported version or commitment scheme,
+    /// or for the first vector that is malformed or does not have its expected outcome.
+    pub fn verify(&self) -> Result<(), TestVectorError> {
+        if self.version != TEST_VECTOR_FORMAT_VERSION {
+            return Err(TestVectorError::UnsupportedVersion {
+                version: self.version,
+            });
+        }
+        if self.commitment_scheme != DORY_COMMITMENT_SCHEME {
+            return Err(TestVectorError::UnsupportedCommitmentScheme {
+                commitment_scheme: self.commitment_scheme.clone(),
+            });
+        }
+        let verifier_setup = VerifierSetup::deserialize_compressed(self.verifier_setup.as_slice())
+            .map_err(|_| TestVectorError::InvalidVerifierSetup)?;
+        let setup = DoryVerifierPublicSetup::new(&verifier_setup, self.sigma);
+        self.vectors
+            .iter()
+            .try_for_each(|vector| vector.verify(&setup))
+    }
+
+    /// Serialize the suite to pretty-printed JSON.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the suite cannot be serialized, which should not happen.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("test vectors should serialize")
+    }
+
+    /// Deserialize a suite that was serialized with [`TestVectorSuite::to_json`].
+    ///
+    /// # Errors
+    /// Returns an error if the JSON is invalid or was written with an unsupported format version.
+    pub fn try_from_json(json: &str) -> Result<Self, TestVectorError> {
+        let suite: Self =
+            serde_json::from_str(json).map_err(|error| TestVectorError::InvalidJson { error })?;
+        if suite.version != TEST_VECTOR_FORMAT_VERSION {
+            return Err(TestVectorError::UnsupportedVersion {
+                version: suite.version,
+            });
+        }
+        Ok(suite)
+    }
+
+    /// Function to save `TestVectorSuite` to a file as JSON
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        fs::write(path, self.to_json() + "\n")
+    }
+
+    /// Function to load `TestVectorSuite` from a JSON file
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        Self::try_from_json(&fs::read_to_string(path)?)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
+/// Serde helpers that encode bytes as lowercase hex strings
+mod hex_bytes {
+    use core::fmt::Write;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut hex = String::with_capacity(2 * bytes.len());
+        for byte in bytes {
+            write!(hex, "{byte:02x}").expect("writing to a string should not fail");
+        }
+        serializer.serialize_str(&hex)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        if !hex.is_ascii() || hex.len() % 2 != 0 {
+            return Err(D::Error::custom("invalid hex string"));
+        }
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(D::Error::custom))
+            .collect()
+    }
+}
@@ -0,0 +1,162 @@
+use super::test_vectors::{
+    ExpectedOutcome, TestVectorError, TestVectorSuite, TEST_VECTOR_FORMAT_VERSION,
+};
+use crate::{
+    base::database::owned_table_utility::*,
+    proof_primitive::dory::{DoryVerifierPublicSetup, VerifierSetup},
+};
+use ark_serialize::CanonicalDeserialize;
+use sha2::{Digest, Sha256};
+use std::sync::OnceLock;
+
+/// The suite is slow to generate, so every test shares one
+fn suite() -> &'static TestVectorSuite {
+    static SUITE: OnceLock<TestVectorSuite> = OnceLock::new();
+    SUITE.get_or_init(TestVectorSuite::generate)
+}
+
+/// Check the outcome of a single vector of the suite
+fn verify_vector(suite: &TestVectorSuite, index: usize) -> Result<(), TestVectorError> {
+    let verifier_setup =
+        VerifierSetup::deserialize_compressed(suite.verifier_setup.as_slice()).unwrap();
+    suite.vectors[index].verify(&DoryVerifierPublicSetup::new(&verifier_setup, suite.sigma))
+}
+
+#[test]
+fn every_generated_test_vector_has_its_expected_outcome_after_a_json_round_trip() {
+    let suite = suite();
+    let loaded = TestVectorSuite::try_from_json(&suite.to_json()).unwrap();
+    assert_eq!(&loaded, suite);
+    loaded.verify().unwrap();
+    assert!(suite
+        .vectors
+        .iter()
+        .any(|vector| vector.expected == ExpectedOutcome::Reject));
+}
+
+#[test]
+fn we_can_save_and_load_test_vectors() {
+    let suite = suite();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dory_test_vectors.json");
+    suite.save(&path).unwrap();
+    assert_eq!(&TestVectorSuite::load(&path).unwrap(), suite);
+}
+
+/// Other implementations check their copy of the suite against this digest,
+/// so any change to the generated vectors must also update it and bump the format version if needed.
+#[test]
+fn the_generated_test_vectors_are_stable() {
+    assert_eq!(
+        format!("{:x}", Sha256::digest(suite().to_json().as_bytes())),
+        "c43702e5a55665407f59032ac3ff836cdef64eb0dc71231f642d13a2386b047f"
+    );
+}
+
+#[test]
+fn we_cannot_verify_corrupted_test_vectors() {
+    let suite = suite();
+    let accepted = suite
+        .vectors
+        .iter()
+        .position(|vector| vector.name == "filter_equality")
+        .unwrap();
+    let rejected = suite
+        .vectors
+        .iter()
+        .position(|vector| vector.name == "reject_tampered_result")
+        .unwrap();
+    assert!(verify_vector(suite, accepted).is_ok());
+    assert!(verify_vector(suite, rejected).is_ok());
+
+    // A flipped bit in the proof
+    let mut corrupted = suite.clone();
+    let proof = &mut corrupted.vectors[accepted].proof;
+    let middle = proof.len() / 2;
+    proof[middle] ^= 1;
+    assert!(matches!(
+        verify_vector(&corrupted, accepted),
+        Err(TestVectorError::UnexpectedRejection { .. })
+    ));
+    assert!(corrupted.verify().is_err());
+
+    // A truncated result
+    let mut corrupted = suite.clone();
+    corrupted.vectors[accepted].result.pop();
+    assert!(matches!(
+        verify_vector(&corrupted, accepted),
+        Err(TestVectorError::UnexpectedRejection { .. })
+    ));
+
+    // A different expected result
+    let mut corrupted = suite.clone();
+    corrupted.vectors[accepted].expected = ExpectedOutcome::Accept {
+        table: owned_table([bigint("id", [1]), decimal75("price", 10, 2, [1050])]),
+    };
+    assert!(matches!(
+        verify_vector(&corrupted, accepted),
+        Err(TestVectorError::ResultMismatch { .. })
+    ));
+
+    // Swapped expected outcomes
+    let mut corrupted = suite.clone();
+    corrupted.vectors[accepted].expected = ExpectedOutcome::Reject;
+    corrupted.vectors[rejected].expected = corrupted.vectors[accepted - 1].expected.clone();
+    assert!(matches!(
+        verify_vector(&corrupted, accepted),
+        Err(TestVectorError::UnexpectedAcceptance { .. })
+    ));
+    assert!(matches!(
+        verify_vector(&corrupted, rejected),
+        Err(TestVectorError::UnexpectedRejection { .. })
+    ));
+
+    // A plan that cannot be deserialized
+    let mut corrupted = suite.clone();
+    corrupted.vectors[accepted].plan.clear();
+    assert!(matches!(
+        verify_vector(&corrupted, accepted),
+        Err(TestVectorError::MalformedVector { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_load_test_vectors_with_an_unsupported_version_scheme_or_encoding() {
+    let suite = suite();
+    let mut unsupported = suite.clone();
+    unsupported.version = TEST_VECTOR_FORMAT_VERSION + 1;
+    assert!(matches!(
+        TestVectorSuite::try_from_json(&unsupported.to_json()),
+        Err(TestVectorError::UnsupportedVersion { version }) if version == TEST_VECTOR_FORMAT_VERSION + 1
+    ));
+    assert!(matches!(
+        unsupported.verify(),
+        Err(TestVectorError::UnsupportedVersion { .. })
+    ));
+
+    let mut unsupported = suite.clone();
+    unsupported.commitment_scheme = "ipa".to_string();
+    assert!(matches!(
+        unsupported.verify(),
+        Err(TestVectorError::UnsupportedCommitmentScheme { .. })
+    ));
+
+    let mut unsupported = suite.clone();
+    unsupported.verifier_setup.truncate(10);
+    assert!(matches!(
+        unsupported.verify(),
+        Err(TestVectorError::InvalidVerifierSetup)
+    ));
+
+    let json = suite.to_json();
+    let bad_hex = json.replacen("\"seed\": \"70", "\"seed\": \"7g", 1);
+    assert_ne!(bad_hex, json);
+    assert!(matches!(
+        TestVectorSuite::try_from_json(&bad_hex),
+        Err(TestVectorError::InvalidJson { .. })
+    ));
+    assert!(matches!(
+        TestVectorSuite::try_from_json("{}"),
+        Err(TestVectorError::InvalidJson { .. })
+    ));
+}
@@ -5,19 +5,27 @@ use ark_std::test_rng;
 use proof_of_sql::base::commitment::InnerProductProof;
 use proof_of_sql::{
     base::{
-        database::{owned_table_utility::*, OwnedTable, OwnedTableTestAccessor, TestAccessor},
+        commitment::{QueryCommitments, QueryCommitmentsExt, TableCommitment},
+        database::{
+            flatten_nested_table, owned_table_utility::*, tombstone_commitment_delta, Column,
+            ColumnField, ColumnType, NestedColumn, OwnedColumn, OwnedTable, OwnedTableTestAccessor,
+            TableSchema, TestAccessor,
+        },
         scalar::Curve25519Scalar,
     },
     proof_primitive::dory::{
-        DoryEvaluationProof, DoryProverPublicSetup, DoryVerifierPublicSetup,
-        DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
+        DoryCommitment, DoryEvaluationProof, DoryProverPublicSetup, DoryScalar,
+        DoryVerifierPublicSetup, DynamicDoryEvaluationProof, ProverSetup, PublicParameters,
+        VerifierSetup,
     },
     sql::{
         parse::{ConversionError, QueryExpr},
         postprocessing::apply_postprocessing_steps,
-        proof::{QueryError, QueryProof},
+        proof::{ProvableQueryResult, ProveError, QueryError, QueryProof, VerifiableQueryResult},
+        proof_exprs::hyperloglog_update,
     },
 };
+use proof_of_sql_parser::Identifier;
 
 #[test]
 #[cfg(feature = "blitzar")]
@@ -178,6 +186,81 @@ fn we_can_prove_a_basic_equality_query_with_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_can_verify_a_query_against_only_the_commitments_of_its_footprint() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let table_ref = "sxt.table".parse().unwrap();
+    let table = owned_table([
+        bigint("a", [1, 2, 3]),
+        bigint("b", [1, 0, 1]),
+        varchar("c", ["x", "y", "z"]),
+        boolean("d", [true, false, true]),
+    ]);
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(table_ref, table.clone(), 0);
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+
+    let query_commitments =
+        QueryCommitments::<DoryCommitment>::from_accessor_for_plan_with_max_bounds(
+            query.proof_expr(),
+            &accessor,
+        )
+        .unwrap();
+    let footprint_commitments = query_commitments[&table_ref].column_commitments();
+    assert_eq!(footprint_commitments.len(), 2);
+
+    // The footprint commitments match those obtained by committing to the whole table.
+    let full_commitment = TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+        &table,
+        0,
+        &dory_prover_setup,
+    );
+    assert_eq!(full_commitment.column_commitments().len(), 4);
+    for (identifier, _, commitment) in footprint_commitments {
+        assert_eq!(
+            full_commitment
+                .column_commitments()
+                .get_commitment(identifier),
+            Some(*commitment)
+        );
+    }
+
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let footprint_result = proof
+        .verify(
+            query.proof_expr(),
+            &query_commitments,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let full_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let expected_result = owned_table([bigint("a", [1, 3])]);
+    assert_eq!(footprint_result, expected_result);
+    assert_eq!(full_result, expected_result);
+}
+
 #[test]
 #[cfg(feature = "blitzar")]
 fn we_can_prove_a_basic_inequality_query_with_curve25519() {
@@ -786,10 +869,99 @@ fn we_can_prove_a_basic_group_by_query_with_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_can_prove_a_group_by_query_with_having_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 1, 2, 3, 2, 2]),
+            bigint("b", [1, 0, 4, 2, 3, 5]),
+            bigint("c", [-2, 2, 1, 0, 1, 1]),
+        ]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| -> OwnedTable<DoryScalar> {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    };
+
+    let expected_result = owned_table([bigint("a", [2]), bigint("d", [27]), bigint("e", [3])]);
+    assert_eq!(
+        prove_and_verify(
+            "SELECT a, sum(2 * b + 1) as d, count(*) as e FROM table WHERE c >= 0 group by a having count(*) >= 2"
+        ),
+        expected_result
+    );
+
+    let expected_result = owned_table([bigint("a", [1, 3])]);
+    assert_eq!(
+        prove_and_verify(
+            "SELECT a FROM table WHERE c >= 0 group by a having sum(b) <= 2 order by a"
+        ),
+        expected_result
+    );
+
+    let expected_result = owned_table([bigint("a", [0; 0]), bigint("e", [0; 0])]);
+    assert_eq!(
+        prove_and_verify(
+            "SELECT a, count(*) as e FROM table WHERE c >= 0 group by a having count(*) >= 4"
+        ),
+        expected_result
+    );
+}
+
 // Overflow checks
 #[test]
 #[cfg(feature = "blitzar")]
-fn we_can_prove_a_query_with_overflow_with_curve25519() {
+fn we_cannot_prove_a_query_with_overflow_with_curve25519() {
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([smallint("a", [i16::MAX]), smallint("b", [1_i16])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a + b as c from table".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    assert_eq!(
+        QueryProof::<InnerProductProof>::try_new(query.proof_expr(), &accessor, &()).err(),
+        Some(ProveError::ResultOverflow {
+            column: "c".parse().unwrap(),
+            row: 0,
+            column_type: ColumnType::SmallInt,
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "blitzar")]
+fn we_can_prove_a_query_with_overflow_that_fails_verification_with_curve25519() {
     let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
     accessor.add_table(
         "sxt.table".parse().unwrap(),
@@ -811,7 +983,41 @@ fn we_can_prove_a_query_with_overflow_with_curve25519() {
 }
 
 #[test]
-fn we_can_prove_a_query_with_overflow_with_dory() {
+fn we_cannot_prove_a_query_with_overflow_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [i64::MIN]), smallint("b", [1_i16])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a - b as c from table".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    assert_eq!(
+        QueryProof::<DoryEvaluationProof>::try_new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup
+        )
+        .err(),
+        Some(ProveError::ResultOverflow {
+            column: "c".parse().unwrap(),
+            row: 0,
+            column_type: ColumnType::BigInt,
+        })
+    );
+}
+
+#[test]
+fn we_can_prove_a_query_with_overflow_that_fails_verification_with_dory() {
     let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
     let prover_setup = ProverSetup::from(&public_parameters);
     let verifier_setup = VerifierSetup::from(&public_parameters);
@@ -831,6 +1037,7 @@ fn we_can_prove_a_query_with_overflow_with_dory() {
         &accessor,
     )
     .unwrap();
+    // `new` does not check the result for overflow, so the overflowing result is proven
     let (proof, serialized_result) =
         QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
     assert!(matches!(
@@ -874,3 +1081,1357 @@ fn we_can_perform_arithmetic_and_conditional_operations_on_tinyint() {
     let expected_result = owned_table([tinyint("result", [9_i8, 10])]);
     assert_eq!(owned_table_result, expected_result);
 }
+
+#[test]
+fn we_can_prove_a_query_that_excludes_tombstoned_rows_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let table_ref = "sxt.table".parse().unwrap();
+    let table = owned_table([
+        bigint("a", [1, 2, 3, 4]),
+        bigint("b", [1, 0, 1, 1]),
+        boolean("__tombstone__", [false, false, false, true]),
+    ]);
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(table_ref, table, 0);
+    let query = QueryExpr::try_new(
+        "SELECT * FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let expected_result = owned_table([bigint("a", [1, 3]), bigint("b", [1, 1])]);
+    assert_eq!(owned_table_result, expected_result);
+
+    // A prover that ignores the tombstones includes the deleted row, which the verifier rejects.
+    let mut untombstoned_accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    untombstoned_accessor.add_table(
+        table_ref,
+        owned_table([bigint("a", [1, 2, 3, 4]), bigint("b", [1, 0, 1, 1])]),
+        0,
+    );
+    let untombstoned_query = QueryExpr::try_new(
+        "SELECT * FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &untombstoned_accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+        untombstoned_query.proof_expr(),
+        &accessor,
+        &dory_prover_setup,
+    );
+    assert!(proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .is_err());
+}
+
+#[test]
+fn we_can_delete_a_row_by_updating_only_the_tombstone_commitment_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+    let before = owned_table::<DoryScalar>([
+        bigint("a", [1, 2, 3, 4]),
+        boolean("__tombstone__", [false, false, false, false]),
+    ]);
+    let after = owned_table::<DoryScalar>([
+        bigint("a", [1, 2, 3, 4]),
+        boolean("__tombstone__", [false, true, false, false]),
+    ]);
+    let offset = 2;
+    let mut commitment = TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+        &before,
+        offset,
+        &dory_prover_setup,
+    )
+    .column_commitments()
+    .get_commitment(&"__tombstone__".parse().unwrap())
+    .unwrap();
+    commitment += tombstone_commitment_delta::<DoryCommitment>(offset + 1, &dory_prover_setup);
+    assert_eq!(
+        Some(commitment),
+        TableCommitment::<DoryCommitment>::from_owned_table_with_offset(
+            &after,
+            offset,
+            &dory_prover_setup,
+        )
+        .column_commitments()
+        .get_commitment(&"__tombstone__".parse().unwrap())
+    );
+}
+
+#[test]
+fn we_can_prove_queries_with_constant_where_clauses_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [4, 5, 6])]),
+        0,
+    );
+    for (sql, expected_result) in [
+        (
+            "SELECT * FROM table WHERE TRUE",
+            owned_table([bigint("a", [1, 2, 3]), bigint("b", [4, 5, 6])]),
+        ),
+        (
+            "SELECT * FROM table WHERE FALSE",
+            owned_table([bigint("a", [0; 0]), bigint("b", [0; 0])]),
+        ),
+        (
+            "SELECT a FROM table WHERE NOT TRUE OR b = 5",
+            owned_table([bigint("a", [2])]),
+        ),
+    ] {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        assert_eq!(owned_table_result, expected_result);
+    }
+}
+
+#[test]
+fn we_can_prove_queries_with_negation_and_subtraction_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [5, -3, 0, i64::MIN]),
+            bigint("b", [1, 2, 3, 4]),
+            decimal75("c", 10, 2, [125, -300, 0, 1]),
+        ]),
+        0,
+    );
+    for (sql, expected_result) in [
+        (
+            "SELECT -a AS neg_a, -c AS neg_c FROM table WHERE b < 4",
+            owned_table([
+                bigint("neg_a", [-5, 3, 0]),
+                decimal75("neg_c", 10, 2, [-125, 300, 0]),
+            ]),
+        ),
+        (
+            "SELECT b - a AS diff, -(a - b) AS neg_diff, -a * b AS prod FROM table WHERE -a >= 0 AND b < 4",
+            owned_table([
+                bigint("diff", [5, 3]),
+                bigint("neg_diff", [5, 3]),
+                bigint("prod", [6, 0]),
+            ]),
+        ),
+        (
+            "SELECT b FROM table WHERE - -a = a AND -a <= -5",
+            owned_table([bigint("b", [1])]),
+        ),
+    ] {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        assert_eq!(owned_table_result, expected_result);
+    }
+
+    // Negating the smallest BIGINT does not fit in a BIGINT, so selecting it is an overflow.
+    let query = QueryExpr::try_new(
+        "SELECT -a AS neg_a FROM table".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    assert_eq!(
+        QueryProof::<DoryEvaluationProof>::try_new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup
+        )
+        .err(),
+        Some(ProveError::ResultOverflow {
+            column: "neg_a".parse().unwrap(),
+            row: 3,
+            column_type: ColumnType::BigInt,
+        })
+    );
+}
+
+#[test]
+fn we_can_prove_queries_with_abs_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [5, -3, 0, -7, i64::MIN]),
+            bigint("b", [1, 2, 3, 4, 5]),
+            decimal75("c", 10, 2, [125, -300, 0, 1, 2]),
+        ]),
+        0,
+    );
+    for (sql, expected_result) in [
+        (
+            "SELECT ABS(a) AS abs_a, abs(c) AS abs_c FROM table WHERE b < 5",
+            owned_table([
+                bigint("abs_a", [5, 3, 0, 7]),
+                decimal75("abs_c", 10, 2, [125, 300, 0, 1]),
+            ]),
+        ),
+        (
+            "SELECT b FROM table WHERE abs(a) <= 3 AND b < 5",
+            owned_table([bigint("b", [2, 3])]),
+        ),
+        (
+            "SELECT abs(a - b) AS dist, -abs(a) AS neg_abs FROM table WHERE abs(a) = a AND b < 5",
+            owned_table([bigint("dist", [4, 3]), bigint("neg_abs", [-5, 0])]),
+        ),
+    ] {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        assert_eq!(owned_table_result, expected_result);
+    }
+
+    // The absolute value of the smallest BIGINT does not fit in a BIGINT, so selecting it is an overflow.
+    let query = QueryExpr::try_new(
+        "SELECT abs(a) AS abs_a FROM table".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    assert_eq!(
+        QueryProof::<DoryEvaluationProof>::try_new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup
+        )
+        .err(),
+        Some(ProveError::ResultOverflow {
+            column: "abs_a".parse().unwrap(),
+            row: 4,
+            column_type: ColumnType::BigInt,
+        })
+    );
+}
+
+#[test]
+fn we_cannot_prove_a_query_whose_arithmetic_overflows_and_learn_the_row_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            int("a", [1, i32::MAX, -5, i32::MIN, 7]),
+            int("b", [2, 1, 3, 1, 1]),
+        ]),
+        0,
+    );
+    // The row is the row of the result, which is the third one when only the rows with `a <= 1` are selected
+    for (sql, column, row) in [
+        ("SELECT a, a + b AS s FROM table", "s", 1),
+        ("SELECT a - b AS d FROM table", "d", 3),
+        ("SELECT b, a - b AS d FROM table WHERE a <= 1", "d", 2),
+    ] {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        assert_eq!(
+            QueryProof::<DoryEvaluationProof>::try_new(
+                query.proof_expr(),
+                &accessor,
+                &dory_prover_setup
+            )
+            .err(),
+            Some(ProveError::ResultOverflow {
+                column: column.parse().unwrap(),
+                row,
+                column_type: ColumnType::Int,
+            })
+        );
+    }
+}
+
+#[test]
+fn we_can_prove_a_query_with_string_concatenation_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let table = |first: [&str; 5]| {
+        owned_table([
+            varchar("first", first),
+            varchar("last", ["Lovelace", "Hopper", "", "", "Turing"]),
+            bigint("b", [1, 2, 3, 4, 5]),
+        ])
+    };
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        table(["Ada", "", "Alan", "", "Alan"]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT first || ' ' || last AS name, last || '' AS same_last FROM table WHERE b < 5"
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let transformed_result: OwnedTable<DoryScalar> =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+    let expected_result = owned_table([
+        varchar("name", ["Ada Lovelace", " Hopper", "Alan ", " "]),
+        varchar("same_last", ["Lovelace", "Hopper", "", ""]),
+    ]);
+    assert_eq!(transformed_result, expected_result);
+
+    // The concatenation is computed from the proven columns, so a prover that concatenates other strings is caught.
+    let mut forged_accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    forged_accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        table(["Eve", "", "Alan", "", "Alan"]),
+        0,
+    );
+    let (forged_proof, forged_result) = QueryProof::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &forged_accessor,
+        &dory_prover_setup,
+    );
+    assert!(forged_proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &forged_result,
+            &dory_verifier_setup,
+        )
+        .is_err());
+}
+
+#[test]
+fn we_can_prove_queries_filtering_a_uuid_column_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let uuid = |last: u8| {
+        let mut bytes = *b"\x12\x3e\x45\x67\xe8\x9b\x12\xd3\xa4\x56\x42\x66\x14\x17\x40\x00";
+        bytes[15] = last;
+        bytes
+    };
+    let table = |ids: [[u8; 16]; 5]| {
+        owned_table([fixed_bytes("id", ids), bigint("n", [10, 20, 30, 40, 50])])
+    };
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        table([uuid(0), uuid(1), uuid(2), uuid(1), uuid(3)]),
+        0,
+    );
+    let prove_and_verify = |sql: &str, accessor: &OwnedTableTestAccessor<DoryEvaluationProof>| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            accessor,
+            &dory_prover_setup,
+        );
+        (query, proof, serialized_result)
+    };
+
+    // filter by an exact UUID
+    let (query, proof, serialized_result) = prove_and_verify(
+        "SELECT id, n FROM table WHERE id = x'123e4567e89b12d3a456426614174001'",
+        &accessor,
+    );
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let transformed_result: OwnedTable<DoryScalar> =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+    let expected_result =
+        owned_table([fixed_bytes("id", [uuid(1), uuid(1)]), bigint("n", [20, 40])]);
+    assert_eq!(transformed_result, expected_result);
+
+    // filter by an IN list
+    let (query, proof, serialized_result) = prove_and_verify(
+        "SELECT n FROM table WHERE id IN (x'123E4567E89B12D3A456426614174000', x'123e4567e89b12d3a456426614174003')",
+        &accessor,
+    );
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let transformed_result: OwnedTable<DoryScalar> =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+    assert_eq!(transformed_result, owned_table([bigint("n", [10, 50])]));
+
+    // a prover that filters other UUIDs than the committed ones is caught
+    let mut forged_accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    forged_accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        table([uuid(0), uuid(1), uuid(2), uuid(2), uuid(3)]),
+        0,
+    );
+    let (query, forged_proof, forged_result) = prove_and_verify(
+        "SELECT n FROM table WHERE id = x'123e4567e89b12d3a456426614174001'",
+        &forged_accessor,
+    );
+    assert!(forged_proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &forged_result,
+            &dory_verifier_setup,
+        )
+        .is_err());
+}
+
+#[test]
+fn we_cannot_compare_a_uuid_column_with_a_literal_of_another_length_or_order_it() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let mut accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(
+        DoryProverPublicSetup::new(&prover_setup, 3),
+    );
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([fixed_bytes("id", [[0_u8; 16], [1_u8; 16]])]),
+        0,
+    );
+    for (sql, literal_type) in [
+        (
+            "SELECT * FROM table WHERE id = x'123e4567e89b12d3a4564266141740'",
+            "BINARY(15)",
+        ),
+        (
+            "SELECT * FROM table WHERE id IN (x'00000000000000000000000000000000', x'00')",
+            "BINARY(1)",
+        ),
+        ("SELECT * FROM table WHERE id = 'abc'", "VARCHAR"),
+    ] {
+        assert!(matches!(
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor),
+            Err(ConversionError::DataTypeMismatch { left_type, right_type })
+                if left_type == "BINARY(16)" && right_type == literal_type
+        ));
+    }
+    assert!(matches!(
+        QueryExpr::try_new(
+            "SELECT * FROM table WHERE id >= x'00000000000000000000000000000000'"
+                .parse()
+                .unwrap(),
+            "sxt".parse().unwrap(),
+            &accessor,
+        ),
+        Err(ConversionError::DataTypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn we_can_read_the_attested_input_row_count_from_a_proof_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3, 4, 5]), bigint("b", [1, 0, 1, 0, 1])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    assert_eq!(owned_table_result, owned_table([bigint("a", [1, 3, 5])]));
+    assert_eq!(proof.input_row_count(), 5);
+
+    // A proof that claims a different input row count is rejected.
+    for input_row_count in [0, 4, 6] {
+        let mut tampered_proof = proof.clone();
+        tampered_proof.input_row_count = input_row_count;
+        assert!(tampered_proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .is_err());
+    }
+}
+
+#[test]
+fn we_can_read_the_verified_result_schema_from_the_query_data_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3]),
+            boolean("b", [true, false, true]),
+            varchar("c", ["x", "y", "z"]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT c, a AS d FROM table WHERE b".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let query_data = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap();
+    let expected_schema: TableSchema = [
+        ColumnField::new("c".parse().unwrap(), ColumnType::VarChar),
+        ColumnField::new("d".parse().unwrap(), ColumnType::BigInt),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(query_data.result_schema, expected_schema);
+
+    // The plan is bound into the transcript, so the proof does not verify against a plan with another schema.
+    for other_query in [
+        "SELECT c FROM table WHERE b",
+        "SELECT c, a AS e FROM table WHERE b",
+        "SELECT a AS d, c FROM table WHERE b",
+    ] {
+        let other_query = QueryExpr::try_new(
+            other_query.parse().unwrap(),
+            "sxt".parse().unwrap(),
+            &accessor,
+        )
+        .unwrap();
+        assert!(proof
+            .verify(
+                other_query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .is_err());
+    }
+}
+
+#[test]
+fn proofs_serialize_identically_regardless_of_the_column_order_of_the_tables_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+
+    let a = [1_i64, 2, 3, 4, 5];
+    let b = [5_i64, 0, 5, 0, 5];
+    let c = [10_i64, 20, 30, 40, 50];
+    let serialize_proof = |table: OwnedTable<DoryScalar>| {
+        let mut accessor =
+            OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+        accessor.add_table("sxt.table".parse().unwrap(), table, 0);
+        let query = QueryExpr::try_new(
+            "SELECT c, a + c AS d FROM table WHERE b = 5 AND a >= 2"
+                .parse()
+                .unwrap(),
+            "sxt".parse().unwrap(),
+            &accessor,
+        )
+        .unwrap();
+        let verifiable_result = VerifiableQueryResult::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        verifiable_result.to_envelope_bytes()
+    };
+    let bytes = serialize_proof(owned_table([
+        bigint("a", a),
+        bigint("b", b),
+        bigint("c", c),
+    ]));
+    assert_eq!(
+        bytes,
+        serialize_proof(owned_table([
+            bigint("c", c),
+            bigint("b", b),
+            bigint("a", a)
+        ]))
+    );
+    assert_eq!(
+        bytes,
+        serialize_proof(owned_table([
+            bigint("b", b),
+            bigint("a", a),
+            bigint("c", c)
+        ]))
+    );
+}
+
+#[test]
+fn we_can_prove_queries_over_an_empty_table_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let accessor_with_table = |table: OwnedTable<DoryScalar>| {
+        let mut accessor =
+            OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+        accessor.add_table("sxt.table".parse().unwrap(), table, 0);
+        accessor
+    };
+    let empty_accessor = accessor_with_table(owned_table([
+        bigint("a", [0_i64; 0]),
+        bigint("b", [0_i64; 0]),
+        boolean("c", [false; 0]),
+    ]));
+    let nonempty_accessor = accessor_with_table(owned_table([
+        bigint("a", [1_i64, 2, 3]),
+        bigint("b", [4_i64, 5, 6]),
+        boolean("c", [true, false, true]),
+    ]));
+
+    for sql in [
+        "SELECT * FROM table",
+        "SELECT a FROM table WHERE b = 5",
+        "SELECT a + b AS s, a * b AS p FROM table WHERE a >= 2 OR NOT c",
+        "SELECT a, SUM(b) AS total, COUNT(*) AS num FROM table GROUP BY a",
+        "SELECT COUNT(*) AS num FROM table WHERE c",
+        "SELECT a, b FROM table ORDER BY b DESC LIMIT 2",
+    ] {
+        let query = QueryExpr::try_new(
+            sql.parse().unwrap(),
+            "sxt".parse().unwrap(),
+            &empty_accessor,
+        )
+        .unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &empty_accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &empty_accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        assert_eq!(owned_table_result.num_rows(), 0);
+        assert_eq!(proof.input_row_count(), 0);
+
+        // A proof over the empty table cannot attest to a nonempty result.
+        let (_, nonempty_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &nonempty_accessor,
+            &dory_prover_setup,
+        );
+        assert!(proof
+            .verify(
+                query.proof_expr(),
+                &empty_accessor,
+                &nonempty_result,
+                &dory_verifier_setup,
+            )
+            .is_err());
+    }
+}
+
+#[test]
+fn we_can_prove_queries_with_folded_constants_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [10, 20, 30]), bigint("b", [4, 5, 6])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a * (1 + 0.1) AS c FROM table WHERE b >= 2 + 3"
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let prefolded_query = QueryExpr::try_new(
+        "SELECT a * (1 + 0.1) AS c FROM table WHERE b >= 5"
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    assert_eq!(
+        postcard::to_allocvec(query.proof_expr()).unwrap(),
+        postcard::to_allocvec(prefolded_query.proof_expr()).unwrap()
+    );
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    assert_eq!(
+        owned_table_result,
+        owned_table([decimal75("c", 41, 1, [220, 330])])
+    );
+}
+
+#[test]
+fn a_query_on_a_nested_field_matches_the_query_on_the_manually_flattened_column_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let (nested_table, leaf_columns) = flatten_nested_table([
+        (
+            "id".parse().unwrap(),
+            NestedColumn::Flat(OwnedColumn::BigInt(vec![1, 2, 3, 4])),
+        ),
+        (
+            "address".parse().unwrap(),
+            NestedColumn::Struct(FromIterator::from_iter([
+                (
+                    "zip".parse().unwrap(),
+                    OwnedColumn::BigInt(vec![10, 20, 30, 40]),
+                ),
+                (
+                    "city".parse().unwrap(),
+                    OwnedColumn::VarChar(["a", "b", "b", "c"].map(String::from).to_vec()),
+                ),
+            ])),
+        ),
+    ])
+    .unwrap();
+    assert_eq!(leaf_columns.len(), 2);
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table("sxt.nested".parse().unwrap(), nested_table, 0);
+    accessor.add_table(
+        "sxt.flat".parse().unwrap(),
+        owned_table([
+            bigint("id", [1, 2, 3, 4]),
+            bigint("zip", [10, 20, 30, 40]),
+            varchar("city", ["a", "b", "b", "c"]),
+        ]),
+        0,
+    );
+
+    let prove_and_verify = |sql: &str| {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table
+    };
+    let nested_result = prove_and_verify(
+        "SELECT id, address.zip AS zip FROM nested WHERE address.zip >= 20 AND address.city = 'b'",
+    );
+    let flat_result = prove_and_verify("SELECT id, zip FROM flat WHERE zip >= 20 AND city = 'b'");
+    assert_eq!(nested_result, flat_result);
+    assert_eq!(
+        nested_result,
+        owned_table([bigint("id", [2, 3]), bigint("zip", [20, 30])])
+    );
+
+    let nested_result = prove_and_verify("SELECT address.city FROM nested WHERE id = 4");
+    assert_eq!(
+        nested_result,
+        owned_table([varchar("address__city", ["c"])])
+    );
+}
+
+#[test]
+fn we_can_prove_a_count_of_distinct_values_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let heavily_duplicated: Vec<i64> = (0..40).map(|i| (i * 7) % 3 - 1).collect();
+    let distinct: Vec<i64> = (0..40).map(|i| 5 * i - 100).collect();
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("dup", heavily_duplicated.clone()),
+            bigint("uniq", distinct.clone()),
+            int("i", (0..40).map(|i| i / 4)),
+        ]),
+        0,
+    );
+    let prove_and_verify = |sql: &str| -> OwnedTable<DoryScalar> {
+        let query =
+            QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap();
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    };
+    let distinct_count = |values: &[i64]| {
+        i64::try_from(std::collections::HashSet::<&i64>::from_iter(values).len()).unwrap()
+    };
+
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(DISTINCT dup) AS n FROM table"),
+        owned_table([bigint("n", [distinct_count(&heavily_duplicated)])])
+    );
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(DISTINCT uniq) AS n FROM table"),
+        owned_table([bigint("n", [distinct_count(&distinct)])])
+    );
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(DISTINCT i) AS n FROM table WHERE dup = 0"),
+        owned_table([bigint("n", [10])])
+    );
+    // Like the other aggregations without a group by, there is no row when no rows are selected.
+    assert_eq!(
+        prove_and_verify("SELECT COUNT(DISTINCT dup) AS n FROM table WHERE uniq > 1000"),
+        owned_table([bigint("n", [0; 0])])
+    );
+}
+
+#[test]
+fn we_cannot_plan_an_unsupported_count_of_distinct_values() {
+    let public_parameters = PublicParameters::test_rand(1, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let mut accessor = OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(
+        DoryProverPublicSetup::new(&prover_setup, 1),
+    );
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])]),
+        0,
+    );
+    for sql in [
+        "SELECT COUNT(DISTINCT b) AS n FROM table",
+        "SELECT COUNT(DISTINCT a + 1) AS n FROM table",
+        "SELECT COUNT(DISTINCT a) + 1 AS n FROM table",
+        "SELECT COUNT(DISTINCT a) AS n, COUNT(*) AS c FROM table",
+        "SELECT b, COUNT(DISTINCT a) AS n FROM table GROUP BY b",
+        "SELECT COUNT(*) AS c FROM table HAVING COUNT(DISTINCT a) > 1",
+    ] {
+        assert!(
+            matches!(
+                QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor),
+                Err(ConversionError::Unprovable { .. })
+            ),
+            "{sql}"
+        );
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+#[test]
+fn we_can_prove_an_approximate_count_of_distinct_values_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    // 48 distinct values, 16 of which appear twice
+    let values: Vec<i64> = (0..64).map(|i| (i * 37) % 48 - 24).collect();
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", values.clone()),
+            int("b", (0..64).map(|i| i % 4)),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT APPROX_COUNT_DISTINCT(a) AS n FROM table WHERE b <> 3"
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+
+    // The verified sketch is exactly the set of register updates of the selected values.
+    let selected: Vec<i64> = values
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| i % 4 != 3)
+        .map(|(_, &value)| value)
+        .collect();
+    let expected_updates: Vec<i64> = selected
+        .iter()
+        .map(|&value| hyperloglog_update(value, 12))
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let bigint_column = |table: &OwnedTable<DoryScalar>, name: &str| -> Vec<i64> {
+        let OwnedColumn::BigInt(column) =
+            &table.inner_table()[&name.parse::<Identifier>().unwrap()]
+        else {
+            panic!("{name} is not a BIGINT column");
+        };
+        column.clone()
+    };
+    let updates = bigint_column(&owned_table_result, "__hyperloglog_updates__");
+    let counts = bigint_column(&owned_table_result, "__hyperloglog_update_rows__");
+    assert_eq!(updates, expected_updates);
+
+    // The estimate is within three standard errors of the number of distinct values.
+    let distinct_count = std::collections::HashSet::<&i64>::from_iter(&selected).len() as f64;
+    let transformed_result: OwnedTable<DoryScalar> =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+    let estimate = bigint_column(&transformed_result, "n")[0] as f64;
+    let standard_error = bigint_column(&transformed_result, "n_standard_error")[0] as f64;
+    assert!((estimate - distinct_count).abs() <= 3.0 * 1.04 / 64.0 * distinct_count);
+    assert!((standard_error - 1.04 / 64.0 * estimate).abs() <= 1.0);
+
+    // A sketch with a tampered rank or a missing update does not verify against the proof.
+    let mut tampered_updates = updates.clone();
+    *tampered_updates.last_mut().unwrap() += 1;
+    for (tampered_updates, tampered_counts) in [
+        (&tampered_updates[..], &counts[..]),
+        (&updates[1..], &counts[1..]),
+    ] {
+        let tampered_result = ProvableQueryResult::new(
+            tampered_updates.len() as u64,
+            &[
+                Column::<DoryScalar>::BigInt(tampered_updates),
+                Column::<DoryScalar>::BigInt(tampered_counts),
+            ],
+        );
+        assert!(proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &tampered_result,
+                &dory_verifier_setup,
+            )
+            .is_err());
+    }
+}
+
+#[test]
+fn filtered_aggregations_match_the_aggregations_of_the_filtered_rows_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            varchar("g", ["x", "y", "x", "z", "y", "x", "z", "y"]),
+            bigint("b", [1, 2, 3, 4, 5, 6, 7, 8]),
+            bigint("c", [1, -1, 0, -2, 3, 4, 5, -6]),
+        ]),
+        0,
+    );
+    let plan = |sql: &str| {
+        QueryExpr::try_new(sql.parse().unwrap(), "sxt".parse().unwrap(), &accessor).unwrap()
+    };
+    let prove_and_verify = |sql: &str| -> OwnedTable<DoryScalar> {
+        let query = plan(sql);
+        let (proof, serialized_result) = QueryProof::<DoryEvaluationProof>::new(
+            query.proof_expr(),
+            &accessor,
+            &dory_prover_setup,
+        );
+        let owned_table_result = proof
+            .verify(
+                query.proof_expr(),
+                &accessor,
+                &serialized_result,
+                &dory_verifier_setup,
+            )
+            .unwrap()
+            .table;
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap()
+    };
+
+    // Filtered aggregations next to an unfiltered count, all in one proof
+    let filtered = prove_and_verify(
+        "SELECT g, SUM(b) FILTER (WHERE c >= 0) AS s, COUNT(*) FILTER (WHERE c >= 0) AS n, COUNT(*) AS total FROM table GROUP BY g",
+    );
+    assert_eq!(
+        filtered,
+        owned_table([
+            varchar("g", ["x", "y", "z"]),
+            bigint("s", [10, 5, 7]),
+            bigint("n", [3, 1, 1]),
+            bigint("total", [3, 3, 2]),
+        ])
+    );
+    let filtered_without_total = owned_table(filtered.into_inner().into_iter().take(3));
+    assert_eq!(
+        filtered_without_total,
+        prove_and_verify("SELECT g, SUM(b) AS s, COUNT(*) AS n FROM table WHERE c >= 0 GROUP BY g")
+    );
+
+    // Filtered aggregations without a group by or an unfiltered count
+    assert_eq!(
+        prove_and_verify(
+            "SELECT SUM(b) FILTER (WHERE c >= 0) AS s, COUNT(*) FILTER (WHERE c >= 0) AS n FROM table"
+        ),
+        prove_and_verify("SELECT SUM(b) AS s, COUNT(*) AS n FROM table WHERE c >= 0")
+    );
+
+    // Unlike a WHERE clause, a filter keeps the groups without qualifying rows
+    assert_eq!(
+        prove_and_verify(
+            "SELECT g, COUNT(*) FILTER (WHERE c >= 4) AS n FROM table WHERE b <= 7 GROUP BY g"
+        ),
+        owned_table([varchar("g", ["x", "y", "z"]), bigint("n", [1, 0, 1])])
+    );
+
+    // A count of every row in place of the filtered count does not verify.
+    let query = plan("SELECT COUNT(*) FILTER (WHERE c >= 0) AS n FROM table");
+    let (proof, _) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let verify = |n: i64| {
+        proof.verify(
+            query.proof_expr(),
+            &accessor,
+            &ProvableQueryResult::new(
+                1,
+                &[Column::<DoryScalar>::BigInt(&[n]), Column::BigInt(&[8])],
+            ),
+            &dory_verifier_setup,
+        )
+    };
+    assert!(verify(5).is_ok());
+    assert!(verify(8).is_err());
+}
+
+#[test]
+fn an_order_by_with_ties_is_stable_and_other_orders_of_tied_rows_are_rejected_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let rows: Vec<(i64, i64)> = (0..32).map(|i| ((i * 5) % 3, i)).collect();
+    let accessor_with_rows = |rows: &[(i64, i64)]| {
+        let mut accessor =
+            OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+        accessor.add_table(
+            "sxt.table".parse().unwrap(),
+            owned_table([
+                bigint("k", rows.iter().map(|(k, _)| *k)),
+                bigint("i", rows.iter().map(|(_, i)| *i)),
+            ]),
+            0,
+        );
+        accessor
+    };
+    let accessor = accessor_with_rows(&rows);
+    let query = QueryExpr::try_new(
+        "SELECT k, i FROM table ORDER BY k".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let mut sorted_rows = rows.clone();
+    sorted_rows.sort_by_key(|(k, _)| *k);
+    assert_eq!(
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap(),
+        owned_table([
+            bigint("k", sorted_rows.iter().map(|(k, _)| *k)),
+            bigint("i", sorted_rows.iter().map(|(_, i)| *i)),
+        ])
+    );
+
+    // A result whose tied rows are in another order does not verify against the proof.
+    let mut swapped_rows = rows;
+    swapped_rows.swap(0, 3);
+    assert_eq!(swapped_rows[0].0, swapped_rows[3].0);
+    let (_, swapped_result) = QueryProof::<DoryEvaluationProof>::new(
+        query.proof_expr(),
+        &accessor_with_rows(&swapped_rows),
+        &dory_prover_setup,
+    );
+    assert!(proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &swapped_result,
+            &dory_verifier_setup,
+        )
+        .is_err());
+}
+
+#[test]
+fn we_can_prove_row_numbers_with_dory() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            varchar("name", ["Ada", "Alan", "Grace", "Edsger", "Barbara"]),
+            bigint("score", [90, 75, 95, 60, 80]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT row_number() OVER (ORDER BY score DESC) AS rank, name FROM table WHERE score >= 70 ORDER BY name"
+            .parse()
+            .unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, serialized_result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let owned_table_result = proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &serialized_result,
+            &dory_verifier_setup,
+        )
+        .unwrap()
+        .table;
+    let transformed_result: OwnedTable<DoryScalar> =
+        apply_postprocessing_steps(owned_table_result, query.postprocessing()).unwrap();
+    let expected_result = owned_table([
+        bigint("rank", [2, 4, 3, 1]),
+        varchar("name", ["Ada", "Alan", "Barbara", "Grace"]),
+    ]);
+    assert_eq!(transformed_result, expected_result);
+
+    // A result that ranks Ada first does not verify against the proof.
+    let forged_result = ProvableQueryResult::new(
+        4,
+        &[
+            Column::VarChar((
+                &["Ada", "Grace", "Barbara", "Alan"],
+                &[
+                    DoryScalar::from("Ada"),
+                    DoryScalar::from("Grace"),
+                    DoryScalar::from("Barbara"),
+                    DoryScalar::from("Alan"),
+                ],
+            )),
+            Column::BigInt(&[90, 95, 80, 75]),
+            Column::BigInt(&[1, 2, 3, 4]),
+        ],
+    );
+    assert!(proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &forged_result,
+            &dory_verifier_setup,
+        )
+        .is_err());
+}
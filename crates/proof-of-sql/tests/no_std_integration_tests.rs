@@ -0,0 +1,95 @@
+//! Proving and verifying with only the `alloc` core of the crate.
+//!
+//! Unlike the other integration tests, these do not need the `test` feature,
+//! so CI runs them with `--no-default-features` to check that verification works without `std`.
+#![cfg_attr(test, allow(clippy::missing_panics_doc))]
+use ark_std::test_rng;
+use proof_of_sql::{
+    base::database::{owned_table_utility::*, Column, OwnedTableTestAccessor, TestAccessor},
+    proof_primitive::dory::{
+        DoryEvaluationProof, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup,
+        ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        parse::QueryExpr,
+        proof::{ProvableQueryResult, QueryProof},
+    },
+};
+
+#[test]
+fn we_can_verify_a_serialized_dory_proof_without_std() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 4]),
+            varchar("b", ["x", "y", "x", "z"]),
+            decimal75("c", 10, 2, [100, -5, 0, 7]),
+        ]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a, c FROM table WHERE b = 'x'".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, result) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+
+    // The verifier only ever sees the serialized proof and result.
+    let proof: QueryProof<DoryEvaluationProof> =
+        postcard::from_bytes(&postcard::to_allocvec(&proof).unwrap()).unwrap();
+    let result: ProvableQueryResult =
+        postcard::from_bytes(&postcard::to_allocvec(&result).unwrap()).unwrap();
+
+    let table = proof
+        .verify(query.proof_expr(), &accessor, &result, &dory_verifier_setup)
+        .unwrap()
+        .table;
+    assert_eq!(
+        table,
+        owned_table([bigint("a", [1, 3]), decimal75("c", 10, 2, [100, 0])])
+    );
+}
+
+#[test]
+fn we_cannot_verify_a_tampered_result_without_std() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let dory_prover_setup = DoryProverPublicSetup::new(&prover_setup, 3);
+    let dory_verifier_setup = DoryVerifierPublicSetup::new(&verifier_setup, 3);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DoryEvaluationProof>::new_empty_with_setup(dory_prover_setup);
+    accessor.add_table(
+        "sxt.table".parse().unwrap(),
+        owned_table([bigint("a", [1, 2, 3]), bigint("b", [1, 0, 1])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT a FROM table WHERE b = 1".parse().unwrap(),
+        "sxt".parse().unwrap(),
+        &accessor,
+    )
+    .unwrap();
+    let (proof, _) =
+        QueryProof::<DoryEvaluationProof>::new(query.proof_expr(), &accessor, &dory_prover_setup);
+    let tampered = ProvableQueryResult::new(2, &[Column::<DoryScalar>::BigInt(&[1, 2])]);
+    assert!(proof
+        .verify(
+            query.proof_expr(),
+            &accessor,
+            &tampered,
+            &dory_verifier_setup,
+        )
+        .is_err());
+}
@@ -0,0 +1,65 @@
+//! A CLI utility to generate the canonical proof test vectors, or to check a file of them
+use clap::{Parser, Subcommand};
+use proof_of_sql::utils::test_vectors::TestVectorSuite;
+use std::{path::PathBuf, process::ExitCode};
+
+/// The file the test vectors are written to and read from by default
+const DEFAULT_PATH: &str = "dory_test_vectors.json";
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate the test vectors and write them to a file
+    Generate {
+        /// The file to write the test vectors to
+        #[arg(short, long, default_value = DEFAULT_PATH)]
+        output: PathBuf,
+    },
+    /// Check that every test vector in a file has its expected outcome
+    Verify {
+        /// The file to read the test vectors from
+        #[arg(short, long, default_value = DEFAULT_PATH)]
+        input: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Generate { output } => {
+            let suite = TestVectorSuite::generate();
+            if let Err(error) = suite.save(&output) {
+                eprintln!("Failed to write {}: {error}", output.display());
+                return ExitCode::FAILURE;
+            }
+            println!(
+                "Wrote {} test vectors to {}",
+                suite.vectors.len(),
+                output.display()
+            );
+        }
+        Command::Verify { input } => {
+            let suite = match TestVectorSuite::load(&input) {
+                Ok(suite) => suite,
+                Err(error) => {
+                    eprintln!("Failed to read {}: {error}", input.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+            if let Err(error) = suite.verify() {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+            println!(
+                "All {} test vectors have their expected outcome",
+                suite.vectors.len()
+            );
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,15 @@
+//! Feeds arbitrary strings to the query parser.
+//!
+//! Parsing must either succeed or return an error, and a parsed query must plan without panicking.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use proof_of_sql::sql::parse::QueryExpr;
+use proof_of_sql_fuzz::FuzzSchemaAccessor;
+use proof_of_sql_parser::SelectStatement;
+
+fuzz_target!(|query: &str| {
+    if let Ok(ast) = query.parse::<SelectStatement>() {
+        let _ = QueryExpr::try_new(ast, "sxt".parse().unwrap(), &FuzzSchemaAccessor);
+    }
+});
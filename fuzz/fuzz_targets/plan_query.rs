@@ -0,0 +1,246 @@
+//! Feeds arbitrary query ASTs to the planner.
+//!
+//! The ASTs are built directly rather than parsed, so they include queries the parser never produces,
+//! such as nested aggregations or wildcards in expressions.
+//! Planning must either succeed or return an error.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use bigdecimal::{num_bigint::BigInt, BigDecimal};
+use libfuzzer_sys::fuzz_target;
+use proof_of_sql::sql::parse::QueryExpr;
+use proof_of_sql_fuzz::{FuzzSchemaAccessor, COLUMNS};
+use proof_of_sql_parser::{
+    intermediate_ast::{
+        AggregationOperator, AliasedResultExpr, BinaryOperator, Expression, Literal, OrderBy,
+        OrderByDirection, SelectResultExpr, SetExpression, Slice, TableExpression, UnaryOperator,
+    },
+    posql_time::PoSQLTimestamp,
+    Identifier, SelectStatement,
+};
+
+const BINARY_OPERATORS: [BinaryOperator; 11] = [
+    BinaryOperator::Add,
+    BinaryOperator::Subtract,
+    BinaryOperator::Multiply,
+    BinaryOperator::Division,
+    BinaryOperator::Modulo,
+    BinaryOperator::Concat,
+    BinaryOperator::And,
+    BinaryOperator::Or,
+    BinaryOperator::Equal,
+    BinaryOperator::LessThanOrEqual,
+    BinaryOperator::GreaterThanOrEqual,
+];
+
+const AGGREGATION_OPERATORS: [AggregationOperator; 6] = [
+    AggregationOperator::Max,
+    AggregationOperator::Min,
+    AggregationOperator::Sum,
+    AggregationOperator::Count,
+    AggregationOperator::CountDistinct,
+    AggregationOperator::First,
+];
+
+/// Names that are not columns: the one table that exists, and a few that can only refer to aliases.
+const OTHER_NAMES: [&str; 3] = ["t", "x", "y"];
+
+/// An identifier, chosen from the columns of the schema and a few other names
+#[derive(Arbitrary, Debug)]
+struct FuzzIdentifier(u8);
+
+impl From<&FuzzIdentifier> for Identifier {
+    fn from(identifier: &FuzzIdentifier) -> Self {
+        let names = COLUMNS.map(|(name, _)| name);
+        let index = usize::from(identifier.0) % (names.len() + OTHER_NAMES.len());
+        names
+            .get(index)
+            .unwrap_or_else(|| &OTHER_NAMES[index - names.len()])
+            .parse()
+            .unwrap()
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum FuzzLiteral {
+    Boolean(bool),
+    BigInt(i64),
+    UInt64(u64),
+    Int128(i128),
+    VarChar(String),
+    Decimal(i128, i64),
+    Timestamp(i64),
+}
+
+impl From<&FuzzLiteral> for Literal {
+    fn from(literal: &FuzzLiteral) -> Self {
+        match literal {
+            FuzzLiteral::Boolean(value) => Literal::Boolean(*value),
+            FuzzLiteral::BigInt(value) => Literal::BigInt(*value),
+            FuzzLiteral::UInt64(value) => Literal::UInt64(*value),
+            FuzzLiteral::Int128(value) => Literal::Int128(*value),
+            FuzzLiteral::VarChar(value) => Literal::VarChar(value.clone()),
+            FuzzLiteral::Decimal(digits, scale) => {
+                Literal::Decimal(BigDecimal::new(BigInt::from(*digits), *scale))
+            }
+            FuzzLiteral::Timestamp(epoch) => PoSQLTimestamp::to_timestamp(*epoch)
+                .map_or(Literal::BigInt(*epoch), Literal::Timestamp),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum FuzzExpression {
+    Literal(FuzzLiteral),
+    Column(FuzzIdentifier),
+    Not(Box<FuzzExpression>),
+    Negate(Box<FuzzExpression>),
+    Abs(Box<FuzzExpression>),
+    Binary(u8, Box<FuzzExpression>, Box<FuzzExpression>),
+    Wildcard,
+    Aggregation(u8, Box<FuzzExpression>),
+    Exists(Box<FuzzSetExpression>),
+    ScalarSubquery(Box<FuzzSetExpression>),
+    Placeholder(u64),
+    Case(
+        Box<FuzzExpression>,
+        Box<FuzzExpression>,
+        Box<FuzzExpression>,
+    ),
+}
+
+impl From<&FuzzExpression> for Box<Expression> {
+    fn from(expression: &FuzzExpression) -> Self {
+        Box::new(match expression {
+            FuzzExpression::Literal(literal) => Expression::Literal(literal.into()),
+            FuzzExpression::Column(identifier) => Expression::Column(identifier.into()),
+            FuzzExpression::Not(expr) => Expression::Unary {
+                op: UnaryOperator::Not,
+                expr: expr.as_ref().into(),
+            },
+            FuzzExpression::Negate(expr) => Expression::Unary {
+                op: UnaryOperator::Negate,
+                expr: expr.as_ref().into(),
+            },
+            FuzzExpression::Abs(expr) => Expression::Unary {
+                op: UnaryOperator::Abs,
+                expr: expr.as_ref().into(),
+            },
+            FuzzExpression::Binary(op, left, right) => Expression::Binary {
+                op: BINARY_OPERATORS[usize::from(*op) % BINARY_OPERATORS.len()],
+                left: left.as_ref().into(),
+                right: right.as_ref().into(),
+            },
+            FuzzExpression::Wildcard => Expression::Wildcard,
+            FuzzExpression::Aggregation(op, expr) => Expression::Aggregation {
+                op: AGGREGATION_OPERATORS[usize::from(*op) % AGGREGATION_OPERATORS.len()],
+                expr: expr.as_ref().into(),
+            },
+            FuzzExpression::Exists(subquery) => Expression::Exists(subquery.as_ref().into()),
+            FuzzExpression::ScalarSubquery(subquery) => {
+                Expression::ScalarSubquery(subquery.as_ref().into())
+            }
+            FuzzExpression::Placeholder(index) => Expression::Placeholder(*index),
+            FuzzExpression::Case(condition, then_result, else_result) => Expression::Case {
+                condition: condition.as_ref().into(),
+                then_result: then_result.as_ref().into(),
+                else_result: else_result.as_ref().into(),
+            },
+        })
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum FuzzResultExpr {
+    All,
+    QualifiedAll(FuzzIdentifier),
+    Aliased(FuzzExpression, FuzzIdentifier),
+}
+
+impl From<&FuzzResultExpr> for SelectResultExpr {
+    fn from(result_expr: &FuzzResultExpr) -> Self {
+        match result_expr {
+            FuzzResultExpr::All => SelectResultExpr::ALL,
+            FuzzResultExpr::QualifiedAll(qualifier) => {
+                SelectResultExpr::QualifiedAll(qualifier.into())
+            }
+            FuzzResultExpr::Aliased(expr, alias) => {
+                SelectResultExpr::AliasedResultExpr(AliasedResultExpr {
+                    expr: expr.into(),
+                    alias: alias.into(),
+                })
+            }
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzSetExpression {
+    result_exprs: Vec<FuzzResultExpr>,
+    /// Each table is named by an identifier, and is in the `sxt` schema if the flag is set.
+    from: Vec<(FuzzIdentifier, bool)>,
+    where_expr: Option<FuzzExpression>,
+    group_by: Vec<FuzzIdentifier>,
+    having: Option<FuzzExpression>,
+}
+
+impl From<&FuzzSetExpression> for Box<SetExpression> {
+    fn from(set_expression: &FuzzSetExpression) -> Self {
+        Box::new(SetExpression::Query {
+            result_exprs: set_expression.result_exprs.iter().map(Into::into).collect(),
+            from: set_expression
+                .from
+                .iter()
+                .map(|(table, has_schema)| {
+                    Box::new(TableExpression::Named {
+                        table: table.into(),
+                        schema: has_schema.then(|| "sxt".parse().unwrap()),
+                    })
+                })
+                .collect(),
+            where_expr: set_expression.where_expr.as_ref().map(Into::into),
+            group_by: set_expression.group_by.iter().map(Into::into).collect(),
+            having: set_expression.having.as_ref().map(Into::into),
+        })
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzSelectStatement {
+    expr: FuzzSetExpression,
+    /// Each column is ordered descending if the flag is set.
+    order_by: Vec<(FuzzIdentifier, bool)>,
+    slice: Option<(u64, i64)>,
+}
+
+impl From<&FuzzSelectStatement> for SelectStatement {
+    fn from(statement: &FuzzSelectStatement) -> Self {
+        SelectStatement {
+            expr: (&statement.expr).into(),
+            order_by: statement
+                .order_by
+                .iter()
+                .map(|(expr, descending)| OrderBy {
+                    expr: expr.into(),
+                    direction: if *descending {
+                        OrderByDirection::Desc
+                    } else {
+                        OrderByDirection::Asc
+                    },
+                })
+                .collect(),
+            slice: statement.slice.map(|(number_rows, offset_value)| Slice {
+                number_rows,
+                offset_value,
+            }),
+        }
+    }
+}
+
+fuzz_target!(|statement: FuzzSelectStatement| {
+    let _ = QueryExpr::try_new(
+        (&statement).into(),
+        "sxt".parse().unwrap(),
+        &FuzzSchemaAccessor,
+    );
+});
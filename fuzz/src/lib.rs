@@ -0,0 +1,40 @@
+//! Shared setup for the fuzz targets.
+use proof_of_sql::base::database::{ColumnType, SchemaAccessor, TableRef};
+use proof_of_sql_parser::{
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+    Identifier,
+};
+
+/// The columns of every table, as seen by [`FuzzSchemaAccessor`]
+pub const COLUMNS: [(&str, ColumnType); 6] = [
+    ("a", ColumnType::BigInt),
+    ("b", ColumnType::Boolean),
+    ("c", ColumnType::Int),
+    ("d", ColumnType::Int128),
+    (
+        "time",
+        ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::Utc),
+    ),
+    ("name", ColumnType::VarChar),
+];
+
+/// A schema accessor in which the table `sxt.t` has the columns [`COLUMNS`] and no other table exists.
+pub struct FuzzSchemaAccessor;
+
+impl SchemaAccessor for FuzzSchemaAccessor {
+    fn lookup_column(&self, table_ref: TableRef, column_id: Identifier) -> Option<ColumnType> {
+        self.lookup_schema(table_ref)
+            .into_iter()
+            .find_map(|(name, column_type)| (name == column_id).then_some(column_type))
+    }
+
+    fn lookup_schema(&self, table_ref: TableRef) -> Vec<(Identifier, ColumnType)> {
+        if table_ref != "sxt.t".parse().unwrap() {
+            return Vec::new();
+        }
+        COLUMNS
+            .into_iter()
+            .map(|(name, column_type)| (name.parse().unwrap(), column_type))
+            .collect()
+    }
+}